@@ -0,0 +1,91 @@
+#[cfg(any(feature = "coinbase", feature = "kucoin", feature = "okex"))]
+use base64::{engine::general_purpose::STANDARD, Engine};
+#[cfg(any(feature = "binance", feature = "bybit", feature = "coinbase", feature = "kucoin", feature = "okex"))]
+use openssl::{hash::MessageDigest, pkey::PKey, sign::Signer};
+
+#[cfg(any(feature = "binance", feature = "bybit", feature = "coinbase", feature = "kucoin", feature = "okex"))]
+use super::rest_api::RestApiError;
+
+/// api credentials used to sign the private (account) streams
+#[derive(Clone, PartialEq, Eq)]
+pub struct ExchangeCredentials {
+    pub api_key:    String,
+    pub secret:     String,
+    /// required by coinbase, okex & kucoin
+    pub passphrase: Option<String>
+}
+
+impl ExchangeCredentials {
+    pub fn new(api_key: impl ToString, secret: impl ToString, passphrase: Option<String>) -> Self {
+        Self { api_key: api_key.to_string(), secret: secret.to_string(), passphrase }
+    }
+
+    /// hex encoded HMAC-SHA256 of `payload` keyed with the secret
+    #[cfg(any(feature = "binance", feature = "bybit"))]
+    pub(crate) fn sign_hex(&self, payload: &str) -> eyre::Result<String> {
+        Ok(hex::encode(hmac_sha256(self.secret.as_bytes(), payload.as_bytes())?))
+    }
+
+    /// base64 encoded HMAC-SHA256 of `payload` keyed with the secret
+    #[cfg(any(feature = "kucoin", feature = "okex"))]
+    pub(crate) fn sign_base64(&self, payload: &str) -> eyre::Result<String> {
+        Ok(STANDARD.encode(hmac_sha256(self.secret.as_bytes(), payload.as_bytes())?))
+    }
+
+    /// base64 encoded HMAC-SHA256 of `payload` keyed with the base64 decoded
+    /// secret (coinbase)
+    #[cfg(feature = "coinbase")]
+    pub(crate) fn sign_base64_decoded_secret(&self, payload: &str) -> eyre::Result<String> {
+        let secret = STANDARD.decode(&self.secret)?;
+        Ok(STANDARD.encode(hmac_sha256(&secret, payload.as_bytes())?))
+    }
+
+    /// the passphrase, erroring if it wasn't set
+    #[cfg(any(feature = "coinbase", feature = "kucoin", feature = "okex"))]
+    pub(crate) fn passphrase(&self) -> eyre::Result<&str> {
+        self.passphrase
+            .as_deref()
+            .ok_or(eyre::eyre!("a passphrase is required for this exchange"))
+    }
+}
+
+/// the credentials of an exchange, erroring if they weren't set
+#[cfg(any(feature = "binance", feature = "bybit", feature = "coinbase", feature = "kucoin", feature = "okex"))]
+pub(crate) fn require_credentials(credentials: &Option<ExchangeCredentials>) -> Result<&ExchangeCredentials, RestApiError> {
+    credentials
+        .as_ref()
+        .ok_or(RestApiError::AuthenticationError("credentials are required for the trading api".to_string()))
+}
+
+impl std::fmt::Debug for ExchangeCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExchangeCredentials")
+            .field("api_key", &self.api_key)
+            .field("secret", &"<redacted>")
+            .field("passphrase", &self.passphrase.as_ref().map(|_| "<redacted>"))
+            .finish()
+    }
+}
+
+#[cfg(any(feature = "binance", feature = "bybit", feature = "coinbase", feature = "kucoin", feature = "okex"))]
+fn hmac_sha256(key: &[u8], payload: &[u8]) -> eyre::Result<Vec<u8>> {
+    let key = PKey::hmac(key)?;
+    let mut signer = Signer::new(MessageDigest::sha256(), &key)?;
+    signer.update(payload)?;
+    Ok(signer.sign_to_vec()?)
+}
+
+#[cfg(all(test, any(feature = "binance", feature = "bybit"), any(feature = "kucoin", feature = "okex")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sign() {
+        let credentials = ExchangeCredentials::new("api-key", "key", None);
+        let payload = "The quick brown fox jumps over the lazy dog";
+
+        assert_eq!(credentials.sign_hex(payload).unwrap(), "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8");
+        assert_eq!(credentials.sign_base64(payload).unwrap(), "97yD9DBThCSxMpjmqm+xQ+9NWaFJRhdZl0edvC0aPNg=");
+        assert!(credentials.passphrase().is_err());
+    }
+}
@@ -0,0 +1,108 @@
+/// the urls an exchange is connected through
+///
+/// ws urls are the full url of the stream, rest & web urls are the base the
+/// request paths are appended to (i.e. `https://api.binance.com`)
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum EndpointKind {
+    Rest,
+    /// binance's website api, used for the currency listings
+    Web,
+    /// the (public) ws
+    Ws,
+    Private,
+    /// okx's business ws, used for the candles
+    Business
+}
+
+/// urls to connect to an exchange through instead of its defaults (i.e.
+/// testnets, regional domains or a proxy), the unset urls aren't changed
+///
+/// ws urls are the full url of the stream, rest & web urls are the base the
+/// request paths are appended to
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ExchangeEndpoints {
+    pub rest:        Option<String>,
+    /// binance's website api, used for the currency listings
+    pub web:         Option<String>,
+    /// the (public) ws
+    pub ws:          Option<String>,
+    pub private_ws:  Option<String>,
+    /// okx's business ws, used for the candles
+    pub business_ws: Option<String>
+}
+
+impl ExchangeEndpoints {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_rest(mut self, url: impl ToString) -> Self {
+        self.rest = Some(url.to_string());
+        self
+    }
+
+    pub fn with_web(mut self, url: impl ToString) -> Self {
+        self.web = Some(url.to_string());
+        self
+    }
+
+    pub fn with_ws(mut self, url: impl ToString) -> Self {
+        self.ws = Some(url.to_string());
+        self
+    }
+
+    pub fn with_private_ws(mut self, url: impl ToString) -> Self {
+        self.private_ws = Some(url.to_string());
+        self
+    }
+
+    pub fn with_business_ws(mut self, url: impl ToString) -> Self {
+        self.business_ws = Some(url.to_string());
+        self
+    }
+
+    /// binance.us, to be set on [CexExchange::Binance]
+    pub fn binance_us() -> Self {
+        Self::new()
+            .with_rest("https://api.binance.us")
+            .with_ws("wss://stream.binance.us:9443/stream")
+    }
+
+    /// bybit's testnet, to be set on [CexExchange::Bybit]
+    pub fn bybit_testnet() -> Self {
+        Self::new()
+            .with_rest("https://api-testnet.bybit.com")
+            .with_ws("wss://stream-testnet.bybit.com/v5/public/spot")
+            .with_private_ws("wss://stream-testnet.bybit.com/v5/private")
+    }
+
+    /// the url of the endpoint, `default` if it isn't set
+    pub(crate) fn url(&self, kind: EndpointKind, default: &str) -> String {
+        let url = match kind {
+            EndpointKind::Rest => &self.rest,
+            EndpointKind::Web => &self.web,
+            EndpointKind::Ws => &self.ws,
+            EndpointKind::Private => &self.private_ws,
+            EndpointKind::Business => &self.business_ws
+        };
+
+        url.as_deref()
+            .map(|url| url.trim_end_matches('/').to_string())
+            .unwrap_or_else(|| default.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endpoint_urls() {
+        let default = "wss://ws-feed.exchange.coinbase.com";
+        assert_eq!(ExchangeEndpoints::default().url(EndpointKind::Ws, default), default);
+
+        let endpoints = ExchangeEndpoints::new().with_ws("ws://127.0.0.1:8080/");
+        assert_eq!(endpoints.url(EndpointKind::Ws, default), "ws://127.0.0.1:8080");
+        assert_eq!(endpoints.url(EndpointKind::Rest, "https://api.exchange.coinbase.com"), "https://api.exchange.coinbase.com");
+    }
+}
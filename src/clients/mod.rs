@@ -1,2 +1,11 @@
 pub mod rest_api;
 pub mod ws;
+
+mod credentials;
+pub use credentials::*;
+
+mod endpoints;
+pub use endpoints::*;
+
+mod rate_limit;
+pub use rate_limit::*;
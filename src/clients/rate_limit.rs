@@ -0,0 +1,235 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, LazyLock, Mutex, RwLock},
+    time::Duration
+};
+
+use tokio::time::Instant;
+
+use crate::CexExchange;
+
+type RateLimiters = HashMap<(CexExchange, RateLimitKind), Arc<TokenBucket>>;
+
+/// the limiters, shared by every stream & client in the process (the
+/// subscribe entries only hold the limit each connection's bucket is made
+/// with)
+static RATE_LIMITERS: LazyLock<RwLock<RateLimiters>> = LazyLock::new(Default::default);
+
+/// what a limiter throttles
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum RateLimitKind {
+    /// new ws connections (including reconnects)
+    Connection,
+    /// messages sent on a ws (subscribes, unsubscribes & logins), limited per
+    /// connection
+    Subscribe,
+    Rest
+}
+
+/// `capacity` tokens, refilled evenly over `per`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub capacity: u32,
+    pub per:      Duration
+}
+
+impl RateLimit {
+    pub const fn new(capacity: u32, per: Duration) -> Self {
+        Self { capacity, per }
+    }
+
+    pub const fn per_second(capacity: u32) -> Self {
+        Self::new(capacity, Duration::from_secs(1))
+    }
+
+    pub const fn per_minute(capacity: u32) -> Self {
+        Self::new(capacity, Duration::from_secs(60))
+    }
+
+    /// never throttles
+    pub const fn unlimited() -> Self {
+        Self::new(u32::MAX, Duration::ZERO)
+    }
+
+    pub fn is_unlimited(&self) -> bool {
+        self.per.is_zero()
+    }
+
+    /// the documented limits of the exchange, the exchanges without one get
+    /// a conservative limit
+    ///
+    /// the connection & rest limits are per ip, the subscribe limit is per
+    /// connection
+    pub fn default_for(exchange: CexExchange, kind: RateLimitKind) -> Self {
+        let (connection, subscribe, rest) = match exchange {
+            // https://docs.cdp.coinbase.com/exchange/docs/websocket-rate-limits
+            #[cfg(feature = "coinbase")]
+            CexExchange::Coinbase => (Self::per_second(8), Self::per_second(8), Self::per_second(10)),
+            // https://www.okx.com/docs-v5/en/#overview-websocket-connect
+            #[cfg(feature = "okex")]
+            CexExchange::Okex => (Self::per_second(3), Self::new(480, Duration::from_secs(3600)), Self::new(20, Duration::from_secs(2))),
+            // https://docs.kraken.com/api/docs/guides/spot-ratelimits
+            #[cfg(feature = "kraken")]
+            CexExchange::Kraken => (Self::new(150, Duration::from_secs(600)), Self::per_second(10), Self::per_second(1)),
+            // https://www.bitstamp.net/api/#section/Request-limits
+            #[cfg(feature = "bitstamp")]
+            CexExchange::Bitstamp => (Self::per_second(10), Self::per_second(10), Self::new(8000, Duration::from_secs(600))),
+            // https://binance-docs.github.io/apidocs/spot/en/#limits
+            #[cfg(feature = "binance")]
+            CexExchange::Binance => (Self::new(300, Duration::from_secs(300)), Self::per_second(5), Self::per_minute(1200)),
+            // https://www.kucoin.com/docs/basic-info/request-rate-limit/websocket
+            #[cfg(feature = "kucoin")]
+            CexExchange::Kucoin => (Self::per_minute(30), Self::new(100, Duration::from_secs(10)), Self::new(2000, Duration::from_secs(30))),
+            // https://bybit-exchange.github.io/docs/v5/rate-limit
+            #[cfg(feature = "bybit")]
+            CexExchange::Bybit => (Self::new(500, Duration::from_secs(300)), Self::per_second(10), Self::new(600, Duration::from_secs(5))),
+            // https://www.gate.io/docs/developers/apiv4/#frequency-limit-rule
+            #[cfg(feature = "gateio")]
+            CexExchange::Gateio => (Self::per_second(10), Self::per_second(10), Self::new(200, Duration::from_secs(10))),
+            // https://docs.deribit.com/#rate-limits
+            #[cfg(feature = "deribit")]
+            CexExchange::Deribit => (Self::per_second(5), Self::per_second(20), Self::per_second(20)),
+            // https://www.bitget.com/api-doc/common/websocket-intro
+            #[cfg(feature = "bitget")]
+            CexExchange::Bitget => (Self::new(300, Duration::from_secs(300)), Self::per_second(10), Self::per_second(20)),
+            // https://mexcdevelop.github.io/apidocs/spot_v3_en/#limits
+            #[cfg(feature = "mexc")]
+            CexExchange::Mexc => (Self::per_second(10), Self::per_second(10), Self::new(500, Duration::from_secs(10)))
+        };
+
+        match kind {
+            RateLimitKind::Connection => connection,
+            RateLimitKind::Subscribe => subscribe,
+            RateLimitKind::Rest => rest
+        }
+    }
+}
+
+/// a token bucket, starting full
+#[derive(Debug)]
+pub struct TokenBucket {
+    limit: RateLimit,
+    state: Mutex<BucketState>
+}
+
+#[derive(Debug)]
+struct BucketState {
+    tokens:      f64,
+    refilled_at: Instant
+}
+
+impl TokenBucket {
+    pub fn new(limit: RateLimit) -> Self {
+        Self { limit, state: Mutex::new(BucketState { tokens: limit.capacity as f64, refilled_at: Instant::now() }) }
+    }
+
+    pub fn limit(&self) -> RateLimit {
+        self.limit
+    }
+
+    /// takes a token, erroring with the wait until one is available
+    pub fn try_acquire(&self) -> Result<(), Duration> {
+        if self.limit.is_unlimited() {
+            return Ok(())
+        }
+
+        let capacity = self.limit.capacity as f64;
+        let refill_rate = capacity / self.limit.per.as_secs_f64();
+
+        let mut state = self.state.lock().unwrap();
+        let now = Instant::now();
+        state.tokens = (state.tokens + (now - state.refilled_at).as_secs_f64() * refill_rate).min(capacity);
+        state.refilled_at = now;
+
+        if state.tokens >= 1.0 {
+            state.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - state.tokens) / refill_rate))
+        }
+    }
+
+    /// waits until a token is available & takes it
+    pub async fn acquire(&self) {
+        while let Err(wait) = self.try_acquire() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// the limiter of an exchange, shared by every stream & client in the process
+///
+/// the subscribe limit is per connection, each stream takes its own bucket
+/// from [subscribe_limiter]
+pub fn rate_limiter(exchange: CexExchange, kind: RateLimitKind) -> Arc<TokenBucket> {
+    if let Some(limiter) = RATE_LIMITERS.read().unwrap().get(&(exchange, kind)) {
+        return limiter.clone()
+    }
+
+    RATE_LIMITERS
+        .write()
+        .unwrap()
+        .entry((exchange, kind))
+        .or_insert_with(|| Arc::new(TokenBucket::new(RateLimit::default_for(exchange, kind))))
+        .clone()
+}
+
+/// the limit of an exchange, set by [set_rate_limit] or its default
+pub fn rate_limit(exchange: CexExchange, kind: RateLimitKind) -> RateLimit {
+    RATE_LIMITERS
+        .read()
+        .unwrap()
+        .get(&(exchange, kind))
+        .map(|limiter| limiter.limit())
+        .unwrap_or_else(|| RateLimit::default_for(exchange, kind))
+}
+
+/// a new bucket for the subscribe messages of a single connection
+pub fn subscribe_limiter(exchange: CexExchange) -> TokenBucket {
+    TokenBucket::new(rate_limit(exchange, RateLimitKind::Subscribe))
+}
+
+/// replaces the limit of an exchange for every stream & client in the
+/// process, the tokens already taken aren't carried over (a subscribe limit
+/// applies to the connections made after it's set)
+pub fn set_rate_limit(exchange: CexExchange, kind: RateLimitKind, limit: RateLimit) {
+    RATE_LIMITERS
+        .write()
+        .unwrap()
+        .insert((exchange, kind), Arc::new(TokenBucket::new(limit)));
+}
+
+/// resets the limits of an exchange to their defaults
+pub fn clear_rate_limits(exchange: CexExchange) {
+    RATE_LIMITERS
+        .write()
+        .unwrap()
+        .retain(|(exch, _), _| *exch != exchange);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_token_bucket() {
+        let bucket = TokenBucket::new(RateLimit::new(2, Duration::from_millis(200)));
+        assert!(bucket.try_acquire().is_ok());
+        assert!(bucket.try_acquire().is_ok());
+
+        let wait = bucket.try_acquire().unwrap_err();
+        assert!(wait > Duration::from_millis(90) && wait <= Duration::from_millis(100));
+
+        let start = Instant::now();
+        bucket.acquire().await;
+        bucket.acquire().await;
+        assert!(start.elapsed() >= Duration::from_millis(190));
+        assert!(bucket.try_acquire().is_err());
+    }
+
+    #[test]
+    fn test_unlimited() {
+        let bucket = TokenBucket::new(RateLimit::unlimited());
+        (0..1000).for_each(|_| assert!(bucket.try_acquire().is_ok()));
+    }
+}
@@ -5,19 +5,24 @@ pub enum RestApiError {
     #[error("failed to deserialize the message: {0}")]
     DeserializingError(#[from] serde_json::Error),
     #[error("error sending request: {0}")]
-    ReqwestError(#[from] reqwest::Error)
+    ReqwestError(#[from] reqwest::Error),
+    #[error("invalid trading pair: {0}")]
+    InvalidPair(String),
+    #[error("request is not supported by the exchange: {0}")]
+    UnsupportedRequest(String),
+    #[error("missing or invalid credentials: {0}")]
+    AuthenticationError(String),
+    #[error("the exchange rejected the request: {0}")]
+    ExchangeError(String)
 }
 
 impl RestApiError {
     #[allow(unused)]
     pub(crate) fn is_gateway_timeout(&self) -> bool {
-        match self {
-            Self::ReqwestError(err) => {
-                if let Some(code) = err.status() {
-                    return code.as_u16() == 504
-                }
+        if let Self::ReqwestError(err) = self {
+            if let Some(code) = err.status() {
+                return code.as_u16() == 504
             }
-            _ => ()
         }
         false
     }
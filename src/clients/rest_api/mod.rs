@@ -1,27 +1,107 @@
 mod errors;
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
 pub use errors::*;
 
-use crate::{exchanges::normalized::rest_api::CombinedRestApiResponse, normalized::rest_api::NormalizedRestApiRequest, Exchange};
+use crate::{
+    clients::ExchangeEndpoints,
+    exchanges::normalized::rest_api::CombinedRestApiResponse,
+    normalized::{
+        rest_api::NormalizedRestApiRequest,
+        types::{NormalizedCandleInterval, NormalizedTradingPair}
+    },
+    CexExchange, Exchange
+};
 
 #[derive(Debug, Default)]
 pub struct ExchangeApi {
-    web_client: reqwest::Client
+    web_client: reqwest::Client,
+    /// urls to call instead of the exchanges' defaults
+    endpoints:  HashMap<CexExchange, ExchangeEndpoints>
 }
 
 impl ExchangeApi {
     pub fn new() -> Self {
-        Self { web_client: reqwest::Client::new() }
+        Self::default()
+    }
+
+    /// sets the urls an exchange is called through (i.e. a testnet or a
+    /// proxy)
+    pub fn with_endpoints(mut self, exchange: CexExchange, endpoints: ExchangeEndpoints) -> Self {
+        self.endpoints.insert(exchange, endpoints);
+        self
+    }
+
+    /// the urls the exchange is called through, the defaults if they weren't
+    /// set
+    pub fn endpoints(&self, exchange: CexExchange) -> ExchangeEndpoints {
+        self.endpoints.get(&exchange).cloned().unwrap_or_default()
     }
 
     pub async fn all_currencies<E: Exchange>(&self) -> Result<CombinedRestApiResponse, RestApiError> {
-        Ok(E::rest_api_call(&E::default(), &self.web_client, NormalizedRestApiRequest::AllCurrencies)
-            .await?
-            .into())
+        self.call::<E>(NormalizedRestApiRequest::AllCurrencies)
+            .await
     }
 
     pub async fn all_instruments<E: Exchange>(&self) -> Result<CombinedRestApiResponse, RestApiError> {
-        Ok(E::rest_api_call(&E::default(), &self.web_client, NormalizedRestApiRequest::AllInstruments)
+        self.call::<E>(NormalizedRestApiRequest::AllInstruments)
+            .await
+    }
+
+    pub async fn candles<E: Exchange>(
+        &self,
+        pair: NormalizedTradingPair,
+        interval: NormalizedCandleInterval,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>
+    ) -> Result<CombinedRestApiResponse, RestApiError> {
+        self.call::<E>(NormalizedRestApiRequest::Candles { pair, interval, start, end })
+            .await
+    }
+
+    pub async fn recent_trades<E: Exchange>(&self, pair: NormalizedTradingPair, limit: usize) -> Result<CombinedRestApiResponse, RestApiError> {
+        self.call::<E>(NormalizedRestApiRequest::RecentTrades { pair, limit })
+            .await
+    }
+
+    pub async fn historical_trades<E: Exchange>(
+        &self,
+        pair: NormalizedTradingPair,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>
+    ) -> Result<CombinedRestApiResponse, RestApiError> {
+        self.call::<E>(NormalizedRestApiRequest::HistoricalTrades { pair, from, to })
+            .await
+    }
+
+    /// makes the call, each http request it sends (i.e. every page) waits
+    /// on the exchange's rest limit
+    async fn call<E: Exchange>(&self, request: NormalizedRestApiRequest) -> Result<CombinedRestApiResponse, RestApiError> {
+        let exchange = E::default()
+            .with_endpoints(self.endpoints(E::EXCHANGE))
+            .with_proxy_endpoints(self);
+        Ok(E::rest_api_call(&exchange, &self.web_client, request)
             .await?
             .into())
     }
 }
+
+/// deserializes the response of an authenticated request, returning the body
+/// as a [RestApiError::ExchangeError] if the exchange rejected it
+#[cfg(any(feature = "binance", feature = "bybit", feature = "coinbase", feature = "kucoin", feature = "okex"))]
+pub(crate) async fn signed_response<T>(response: reqwest::Response) -> Result<T, RestApiError>
+where
+    T: for<'de> serde::Deserialize<'de>
+{
+    let status = response.status();
+    let body = response.text().await?;
+
+    if status == reqwest::StatusCode::UNAUTHORIZED || status == reqwest::StatusCode::FORBIDDEN {
+        return Err(RestApiError::AuthenticationError(body))
+    } else if !status.is_success() {
+        return Err(RestApiError::ExchangeError(body))
+    }
+
+    Ok(serde_json::from_str(&body)?)
+}
@@ -2,8 +2,29 @@ use std::fmt::Debug;
 
 use serde::Deserialize;
 
+use super::{WsError, WsSequence};
 use crate::normalized::ws::CombinedWsMessage;
 
 pub trait CriticalWsMessage: for<'de> Deserialize<'de> + Into<CombinedWsMessage> + Send + Debug {
     fn make_critical(&mut self, msg: String);
+
+    /// the sequence id(s) of the message, used by the stream to detect
+    /// dropped or out-of-order updates
+    fn sequence(&self) -> Option<WsSequence> {
+        None
+    }
+
+    /// the exchange asked for the connection to be reopened (ex: before
+    /// maintenance), the stream drops the socket & reconnects without
+    /// counting it as a retry
+    fn reconnect_requested(&self) -> bool {
+        false
+    }
+
+    /// decodes a binary frame (ex: protobuf), exchanges that only send text
+    /// frames error on them
+    #[allow(clippy::result_large_err)]
+    fn decode_binary(frame: &[u8]) -> Result<Self, WsError> {
+        Err(WsError::BinaryDecodingError(format!("unexpected binary frame of {} bytes", frame.len())))
+    }
 }
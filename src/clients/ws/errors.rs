@@ -10,12 +10,20 @@ pub enum WsError {
     WebInitializationError(String),
     #[error("failed to deserialize the message: {0}")]
     DeserializingError(#[from] serde_json::Error),
+    #[error("failed to decode the binary message: {0}")]
+    BinaryDecodingError(String),
     #[error("recieved an error from the ws: {0}")]
     StreamRxError(tokio_tungstenite::tungstenite::Error),
     #[error("error sending value to the ws: {0}")]
     StreamTxError(tokio_tungstenite::tungstenite::Error),
     #[error("stream was terminated")]
-    StreamTerminated
+    StreamTerminated,
+    #[error("failed to authenticate the private stream: {0}")]
+    AuthenticationError(String),
+    #[error("invalid subscription update: {0}")]
+    InvalidSubscriptionUpdate(String),
+    #[error("no message received for {0:?}, the connection is stale")]
+    IdleTimeout(std::time::Duration)
 }
 
 impl WsError {
@@ -0,0 +1,459 @@
+use std::{
+    collections::VecDeque,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex
+    },
+    task::{Context, Poll},
+    thread::JoinHandle
+};
+
+use futures::{Stream, StreamExt};
+use tokio::sync::Notify;
+
+use super::ShutdownHandle;
+use crate::{
+    exchanges::normalized::ws::{CombinedWsMessage, NormalizedWsDataTypes},
+    normalized::types::NormalizedTradingPair,
+    CexExchange
+};
+
+/// what a bounded multithreaded stream does with a new message when it's full
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// the sending thread waits for room, holding back its streams
+    #[default]
+    Block,
+    /// the oldest queued message is dropped
+    DropOldest,
+    /// the new message is dropped
+    DropNewest,
+    /// a quote replaces the queued quote of its pair, any other message (or a
+    /// quote of a pair with nothing queued) blocks
+    CoalesceQuotes
+}
+
+/// the channel the threads of a multithreaded stream send through, unbounded
+/// by default
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FanOutConfig {
+    pub capacity: Option<usize>,
+    pub overflow: OverflowPolicy
+}
+
+impl FanOutConfig {
+    pub fn unbounded() -> Self {
+        Self::default()
+    }
+
+    pub fn bounded(capacity: usize, overflow: OverflowPolicy) -> Self {
+        Self { capacity: Some(capacity.max(1)), overflow }
+    }
+}
+
+/// the messages a bounded multithreaded stream has had to hold back or drop
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OverflowCounts {
+    pub dropped:   u64,
+    /// quotes replaced by a newer quote of their pair
+    pub coalesced: u64,
+    /// sends that waited for room
+    pub blocked:   u64
+}
+
+type QuoteKey = (CexExchange, NormalizedTradingPair);
+
+#[derive(Debug, Default)]
+struct OverflowCounters {
+    dropped:   AtomicU64,
+    coalesced: AtomicU64,
+    blocked:   AtomicU64
+}
+
+#[derive(Debug)]
+struct FanOutState {
+    queue:   VecDeque<(Option<QuoteKey>, CombinedWsMessage)>,
+    senders: usize,
+    /// the receiver was dropped
+    closed:  bool
+}
+
+#[derive(Debug)]
+struct FanOutShared {
+    config:   FanOutConfig,
+    state:    Mutex<FanOutState>,
+    counters: OverflowCounters,
+    /// wakes the receiver
+    sent:     Notify,
+    /// wakes the blocked senders
+    received: Notify
+}
+
+pub(crate) fn fan_out_channel(config: FanOutConfig) -> (FanOutSender, FanOutReceiver) {
+    let shared = Arc::new(FanOutShared {
+        config,
+        state: Mutex::new(FanOutState { queue: VecDeque::new(), senders: 1, closed: false }),
+        counters: OverflowCounters::default(),
+        sent: Notify::new(),
+        received: Notify::new()
+    });
+
+    (FanOutSender { shared: shared.clone() }, FanOutReceiver { shared })
+}
+
+/// the pair of the message if it's a quote, cloned & normalized so only done
+/// when coalescing
+fn quote_key(msg: &CombinedWsMessage) -> Option<QuoteKey> {
+    match msg.clone().normalize() {
+        NormalizedWsDataTypes::Quote(quote) => Some((quote.exchange, quote.pair)),
+        _ => None
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct FanOutSender {
+    shared: Arc<FanOutShared>
+}
+
+impl FanOutSender {
+    /// queues the message as the overflow policy allows, erroring with it if
+    /// the receiver was dropped
+    pub(crate) async fn send(&self, msg: CombinedWsMessage) -> Result<(), CombinedWsMessage> {
+        let config = self.shared.config;
+        let counters = &self.shared.counters;
+        let key = if config.overflow == OverflowPolicy::CoalesceQuotes { quote_key(&msg) } else { None };
+        let mut blocked = false;
+
+        loop {
+            let received = self.shared.received.notified();
+            tokio::pin!(received);
+            received.as_mut().enable();
+
+            {
+                let mut state = self.shared.state.lock().unwrap();
+                if state.closed {
+                    return Err(msg)
+                }
+
+                if config
+                    .capacity
+                    .is_none_or(|capacity| state.queue.len() < capacity)
+                {
+                    state.queue.push_back((key, msg));
+                    drop(state);
+                    self.shared.sent.notify_one();
+                    return Ok(())
+                }
+
+                match config.overflow {
+                    OverflowPolicy::Block => (),
+                    OverflowPolicy::DropOldest => {
+                        state.queue.pop_front();
+                        state.queue.push_back((key, msg));
+                        counters.dropped.fetch_add(1, Ordering::Relaxed);
+                        return Ok(())
+                    }
+                    OverflowPolicy::DropNewest => {
+                        counters.dropped.fetch_add(1, Ordering::Relaxed);
+                        return Ok(())
+                    }
+                    OverflowPolicy::CoalesceQuotes => {
+                        if let Some(queued) = key.as_ref().and_then(|key| {
+                            state
+                                .queue
+                                .iter_mut()
+                                .find(|(k, _)| k.as_ref() == Some(key))
+                        }) {
+                            queued.1 = msg;
+                            counters.coalesced.fetch_add(1, Ordering::Relaxed);
+                            return Ok(())
+                        }
+                    }
+                }
+            }
+
+            if !blocked {
+                blocked = true;
+                counters.blocked.fetch_add(1, Ordering::Relaxed);
+            }
+            received.await;
+        }
+    }
+}
+
+impl Clone for FanOutSender {
+    fn clone(&self) -> Self {
+        self.shared.state.lock().unwrap().senders += 1;
+        Self { shared: self.shared.clone() }
+    }
+}
+
+impl Drop for FanOutSender {
+    fn drop(&mut self) {
+        let mut state = self.shared.state.lock().unwrap();
+        state.senders -= 1;
+        if state.senders == 0 {
+            drop(state);
+            self.shared.sent.notify_one();
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct FanOutReceiver {
+    shared: Arc<FanOutShared>
+}
+
+impl FanOutReceiver {
+    /// the next message, `None` once every sender is dropped & the queue is
+    /// empty
+    pub(crate) async fn recv(&self) -> Option<CombinedWsMessage> {
+        loop {
+            let sent = self.shared.sent.notified();
+            tokio::pin!(sent);
+            sent.as_mut().enable();
+
+            {
+                let mut state = self.shared.state.lock().unwrap();
+                if let Some((_, msg)) = state.queue.pop_front() {
+                    drop(state);
+                    self.shared.received.notify_one();
+                    return Some(msg)
+                }
+
+                if state.senders == 0 {
+                    return None
+                }
+            }
+
+            sent.await;
+        }
+    }
+
+    fn overflow_counts(&self) -> OverflowCounts {
+        let counters = &self.shared.counters;
+        OverflowCounts {
+            dropped:   counters.dropped.load(Ordering::Relaxed),
+            coalesced: counters.coalesced.load(Ordering::Relaxed),
+            blocked:   counters.blocked.load(Ordering::Relaxed)
+        }
+    }
+}
+
+impl Drop for FanOutReceiver {
+    fn drop(&mut self) {
+        let mut state = self.shared.state.lock().unwrap();
+        state.closed = true;
+        state.queue.clear();
+        drop(state);
+        self.shared.received.notify_waiters();
+    }
+}
+
+/// the messages of streams running on their own threads, ending once every
+/// thread has stopped
+pub struct MultithreadedWsStream {
+    receiver: Arc<FanOutReceiver>,
+    messages: Pin<Box<dyn Stream<Item = CombinedWsMessage> + Send>>,
+    threads:  Vec<JoinHandle<()>>,
+    shutdown: ShutdownHandle
+}
+
+impl MultithreadedWsStream {
+    pub(crate) fn new(receiver: FanOutReceiver, threads: Vec<JoinHandle<()>>, shutdown: ShutdownHandle) -> Self {
+        let receiver = Arc::new(receiver);
+        let messages =
+            Box::pin(futures::stream::unfold(receiver.clone(), |receiver| async move { receiver.recv().await.map(|msg| (msg, receiver)) }));
+
+        Self { receiver, messages, threads, shutdown }
+    }
+
+    pub async fn recv(&mut self) -> Option<CombinedWsMessage> {
+        self.next().await
+    }
+
+    pub fn overflow_counts(&self) -> OverflowCounts {
+        self.receiver.overflow_counts()
+    }
+
+    /// the handle to stop the streams of every thread
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        self.shutdown.clone()
+    }
+
+    /// closes every stream, returning the messages received until they were
+    /// closed once all the threads are joined
+    pub async fn shutdown(mut self) -> eyre::Result<Vec<CombinedWsMessage>> {
+        self.shutdown.shutdown();
+
+        let mut remaining = Vec::new();
+        while let Some(msg) = self.recv().await {
+            remaining.push(msg);
+        }
+
+        // every thread has dropped its sender, so they're done
+        let threads = std::mem::take(&mut self.threads);
+        tokio::task::spawn_blocking(move || join_threads(threads)).await??;
+
+        Ok(remaining)
+    }
+
+    /// the threads still running
+    pub fn running_threads(&self) -> usize {
+        self.threads
+            .iter()
+            .filter(|thread| !thread.is_finished())
+            .count()
+    }
+
+    /// joins the threads that have stopped, returning the panics of any that
+    /// crashed
+    pub fn thread_errors(&mut self) -> Vec<eyre::Report> {
+        let (finished, running) = std::mem::take(&mut self.threads)
+            .into_iter()
+            .partition::<Vec<_>, _>(|thread| thread.is_finished());
+        self.threads = running;
+
+        finished.into_iter().filter_map(join_thread).collect()
+    }
+
+    /// closes every stream & blocks until every thread has stopped, dropping
+    /// the messages not received yet & returning the first panic
+    pub fn join(self) -> eyre::Result<()> {
+        let Self { receiver, messages, threads, shutdown } = self;
+        shutdown.shutdown();
+        drop(messages);
+        drop(receiver);
+
+        join_threads(threads)
+    }
+}
+
+/// joins every thread (even after one panicked), returning the first panic
+fn join_threads(threads: Vec<JoinHandle<()>>) -> eyre::Result<()> {
+    let panics = threads
+        .into_iter()
+        .filter_map(join_thread)
+        .collect::<Vec<_>>();
+
+    panics.into_iter().next().map_or(Ok(()), Err)
+}
+
+fn join_thread(thread: JoinHandle<()>) -> Option<eyre::Report> {
+    let panic = thread.join().err()?;
+    let msg = panic
+        .downcast_ref::<&str>()
+        .map(ToString::to_string)
+        .or_else(|| panic.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string());
+
+    Some(eyre::eyre!("multithreaded stream thread panicked: {msg}"))
+}
+
+impl Stream for MultithreadedWsStream {
+    type Item = CombinedWsMessage;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().messages.poll_next_unpin(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    fn msg(i: usize) -> CombinedWsMessage {
+        CombinedWsMessage::Disconnect {
+            exchange:    CexExchange::iter().next().unwrap(),
+            message:     i.to_string(),
+            raw_message: String::new(),
+            bad_pair:    None
+        }
+    }
+
+    fn message_id(msg: CombinedWsMessage) -> String {
+        match msg {
+            CombinedWsMessage::Disconnect { message, .. } => message,
+            _ => unreachable!()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_drop_policies() {
+        let (tx, rx) = fan_out_channel(FanOutConfig::bounded(2, OverflowPolicy::DropOldest));
+        for i in 0..5 {
+            tx.send(msg(i)).await.unwrap();
+        }
+        drop(tx);
+
+        let mut received = Vec::new();
+        while let Some(msg) = rx.recv().await {
+            received.push(message_id(msg));
+        }
+        assert_eq!(received, vec!["3", "4"]);
+        assert_eq!(rx.overflow_counts(), OverflowCounts { dropped: 3, ..Default::default() });
+
+        let (tx, rx) = fan_out_channel(FanOutConfig::bounded(2, OverflowPolicy::DropNewest));
+        for i in 0..5 {
+            tx.send(msg(i)).await.unwrap();
+        }
+        assert_eq!(message_id(rx.recv().await.unwrap()), "0");
+        assert_eq!(message_id(rx.recv().await.unwrap()), "1");
+        assert_eq!(rx.overflow_counts().dropped, 3);
+    }
+
+    #[tokio::test]
+    async fn test_block_policy() {
+        let (tx, rx) = fan_out_channel(FanOutConfig::bounded(1, OverflowPolicy::Block));
+        let sender = tokio::spawn(async move {
+            for i in 0..3 {
+                tx.send(msg(i)).await.unwrap();
+            }
+        });
+
+        let mut received = Vec::new();
+        while let Some(msg) = rx.recv().await {
+            received.push(message_id(msg));
+        }
+        sender.await.unwrap();
+
+        assert_eq!(received, vec!["0", "1", "2"]);
+        assert_eq!(rx.overflow_counts().dropped, 0);
+    }
+
+    #[tokio::test]
+    async fn test_closed_receiver() {
+        let (tx, rx) = fan_out_channel(FanOutConfig::bounded(1, OverflowPolicy::Block));
+        tx.send(msg(0)).await.unwrap();
+
+        let sender = tokio::spawn(async move { tx.send(msg(1)).await.is_err() });
+        tokio::task::yield_now().await;
+        drop(rx);
+
+        assert!(sender.await.unwrap());
+    }
+
+    #[test]
+    fn test_join_after_panic() {
+        let joined = Arc::new(AtomicU64::new(0));
+        let mut threads = vec![std::thread::spawn(|| panic!("crashed"))];
+        threads.extend((0..3).map(|_| {
+            let joined = joined.clone();
+            std::thread::spawn(move || {
+                std::thread::sleep(std::time::Duration::from_millis(100));
+                joined.fetch_add(1, Ordering::SeqCst);
+            })
+        }));
+
+        let (_tx, rx) = fan_out_channel(FanOutConfig::unbounded());
+        let stream = MultithreadedWsStream::new(rx, threads, ShutdownHandle::new());
+
+        let err = stream.join().unwrap_err();
+        assert!(err.to_string().contains("crashed"));
+        // the threads after the panicked one were joined, not detached
+        assert_eq!(joined.load(Ordering::SeqCst), 3);
+    }
+}
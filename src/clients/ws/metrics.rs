@@ -0,0 +1,68 @@
+use std::{
+    fmt::Debug,
+    sync::{Arc, LazyLock, RwLock},
+    time::Duration
+};
+
+use chrono::{DateTime, Utc};
+
+use crate::{exchanges::normalized::ws::CombinedWsMessage, normalized::types::NormalizedTradingPair, CexExchange};
+
+/// the recorder every stream in the process reports to
+static METRICS: LazyLock<RwLock<Option<Arc<dyn WsMetrics>>>> = LazyLock::new(Default::default);
+
+/// receives the health metrics of every [super::WsStream] (including the
+/// streams of a [super::MutliWsStream] or a multithreaded stream)
+///
+/// the methods are called on the polling task, so they should be cheap
+pub trait WsMetrics: Debug + Send + Sync {
+    /// a message of the channel was received, `channel` being the name of the
+    /// normalized data type (ex: `trades`)
+    fn message_received(&self, _exchange: CexExchange, _channel: &'static str) {}
+
+    /// a frame couldn't be deserialized
+    fn deserialization_failed(&self, _exchange: CexExchange) {}
+
+    /// the stream is reconnecting, `attempt` being the number of consecutive
+    /// reconnects
+    fn reconnect_attempt(&self, _exchange: CexExchange, _attempt: u64) {}
+
+    /// the stream reconnected
+    fn reconnected(&self, _exchange: CexExchange) {}
+
+    /// the pair was rejected by the exchange & removed from the stream's
+    /// subscription
+    fn bad_pair_removed(&self, _exchange: CexExchange, _pair: &NormalizedTradingPair) {}
+
+    /// the time between the exchange's timestamp of the message & it being
+    /// received
+    fn latency(&self, _exchange: CexExchange, _channel: &'static str, _latency: Duration) {}
+}
+
+/// reports the metrics of every stream in the process to `metrics`
+pub fn set_metrics_recorder(metrics: Arc<dyn WsMetrics>) {
+    *METRICS.write().unwrap() = Some(metrics);
+}
+
+pub fn clear_metrics_recorder() {
+    *METRICS.write().unwrap() = None;
+}
+
+pub(crate) fn metrics_recorder() -> Option<Arc<dyn WsMetrics>> {
+    METRICS.read().unwrap().clone()
+}
+
+/// reports the channel & latency of a received message, it's normalized so
+/// only done when a recorder is set
+pub(crate) fn record_message(metrics: &dyn WsMetrics, exchange: CexExchange, msg: CombinedWsMessage, received_at: DateTime<Utc>) {
+    let data = msg.normalize();
+    let channel = data.channel();
+    metrics.message_received(exchange, channel);
+
+    if let Some(latency) = data
+        .exchange_time()
+        .and_then(|time| (received_at - time).to_std().ok())
+    {
+        metrics.latency(exchange, channel, latency);
+    }
+}
@@ -8,3 +8,35 @@ pub use single::*;
 
 mod critical;
 pub use critical::*;
+
+mod sequence;
+pub use sequence::*;
+
+mod recorder;
+pub use recorder::*;
+
+mod replay;
+pub use replay::*;
+
+mod subscription;
+pub use subscription::*;
+
+mod reconnect;
+pub use reconnect::*;
+
+mod fanout;
+pub use fanout::*;
+
+mod shutdown;
+pub use shutdown::*;
+
+mod metrics;
+pub use metrics::*;
+
+mod received;
+pub(crate) use received::*;
+
+#[cfg(feature = "metrics-prometheus")]
+mod prometheus;
+#[cfg(feature = "metrics-prometheus")]
+pub use prometheus::*;
@@ -1,30 +1,74 @@
 use std::{
     fmt::Debug,
     pin::Pin,
-    task::{Context, Poll}
+    task::{Context, Poll},
+    thread::JoinHandle,
+    time::Duration
 };
 
 use futures::{Stream, StreamExt};
-use tokio::{runtime::Handle, sync::mpsc::UnboundedReceiver};
+use tokio::runtime::Handle;
 
-use super::{errors::WsError, WsStream};
-use crate::{exchanges::normalized::ws::CombinedWsMessage, Exchange};
+use super::{
+    errors::WsError, fan_out_channel, FanOutConfig, FanOutSender, FrameRecorder, MultithreadedWsStream, OverflowPolicy, ReconnectPolicy,
+    ShutdownHandle, WsStream, WsSubscriptionHandle
+};
+use crate::{clients::ExchangeEndpoints, exchanges::normalized::ws::CombinedWsMessage, Exchange};
 
 pub struct MutliWsStream {
     combined_streams: Pin<Box<dyn Stream<Item = CombinedWsMessage> + Send>>,
-    stream_count:     usize
+    stream_count:     usize,
+    handle:           WsSubscriptionHandle,
+    shutdown:         ShutdownHandle
 }
 
 impl MutliWsStream {
+    fn new<T>(ws_streams: Vec<WsStream<T>>) -> Self
+    where
+        T: Exchange + Unpin + Debug + Send + 'static
+    {
+        let handle = ws_streams
+            .iter()
+            .map(WsStream::subscription_handle)
+            .reduce(WsSubscriptionHandle::combine)
+            .unwrap_or_default();
+        let shutdown = ws_streams
+            .iter()
+            .map(WsStream::shutdown_handle)
+            .reduce(ShutdownHandle::combine)
+            .unwrap_or_default();
+
+        let stream_count = ws_streams.len();
+        let combined_streams = Box::pin(futures::stream::select_all(ws_streams));
+
+        Self { combined_streams, stream_count, handle, shutdown }
+    }
+
     pub fn combine_other(self, other: Self) -> Self {
         let combined_streams = Box::pin(futures::stream::select_all(vec![self.combined_streams, other.combined_streams]));
 
-        Self { combined_streams, stream_count: self.stream_count + other.stream_count }
+        Self {
+            combined_streams,
+            stream_count: self.stream_count + other.stream_count,
+            handle: self.handle.combine(other.handle),
+            shutdown: self.shutdown.combine(other.shutdown)
+        }
     }
 
     pub fn stream_count(&self) -> usize {
         self.stream_count
     }
+
+    /// the handle to add or remove pairs of the running streams
+    pub fn subscription_handle(&self) -> WsSubscriptionHandle {
+        self.handle.clone()
+    }
+
+    /// the handle to stop the streams, the multistream ends once all of them
+    /// are closed
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        self.shutdown.clone()
+    }
 }
 
 impl Stream for MutliWsStream {
@@ -49,7 +93,12 @@ impl Stream for MutliWsStream {
 }
 
 pub struct MutliWsStreamBuilder<T> {
-    exchanges: Vec<T>
+    exchanges:        Vec<T>,
+    recorder:         Option<FrameRecorder>,
+    idle_timeout:     Option<Duration>,
+    reconnect_policy: ReconnectPolicy,
+    fan_out:          FanOutConfig,
+    shutdown:         Option<ShutdownHandle>
 }
 
 impl<T> MutliWsStreamBuilder<T>
@@ -57,13 +106,79 @@ where
     T: Exchange + Unpin + Debug + Send + 'static
 {
     pub fn new(exchanges: Vec<T>) -> Self {
-        Self { exchanges }
+        Self {
+            exchanges,
+            recorder: None,
+            idle_timeout: None,
+            reconnect_policy: ReconnectPolicy::default(),
+            fan_out: FanOutConfig::default(),
+            shutdown: None
+        }
+    }
+
+    /// connects every stream through the urls of `endpoints`
+    pub fn with_endpoints(mut self, endpoints: ExchangeEndpoints) -> Self {
+        self.exchanges = self
+            .exchanges
+            .into_iter()
+            .map(|exchange| exchange.with_endpoints(endpoints.clone()))
+            .collect();
+        self
+    }
+
+    /// records every raw text & binary frame received by the streams, except
+    /// the heartbeat replies
+    pub fn with_recorder(mut self, recorder: FrameRecorder) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    /// reconnects a stream if it receives nothing for `timeout`
+    pub fn with_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_timeout = Some(timeout);
+        self
+    }
+
+    /// how the streams reconnect after they're dropped
+    pub fn with_reconnect_policy(mut self, reconnect_policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = reconnect_policy;
+        self
+    }
+
+    /// bounds the channel of [Self::spawn_multithreaded] to `capacity`
+    /// messages, handling the overflow with `overflow`
+    pub fn with_bounded_channel(mut self, capacity: usize, overflow: OverflowPolicy) -> Self {
+        self.fan_out = FanOutConfig::bounded(capacity, overflow);
+        self
+    }
+
+    /// stops every stream when `shutdown` is called on the handle, otherwise
+    /// each stream has its own handle
+    pub fn with_shutdown_handle(mut self, shutdown: ShutdownHandle) -> Self {
+        self.shutdown = Some(shutdown);
+        self
+    }
+
+    fn new_stream(&self, exchange: T) -> WsStream<T> {
+        let mut stream = WsStream::new(exchange).with_reconnect_policy(self.reconnect_policy);
+        if let Some(shutdown) = self.shutdown.as_ref() {
+            stream = stream.with_shutdown_handle(shutdown.clone());
+        }
+        if let Some(recorder) = self.recorder.as_ref() {
+            stream = stream.with_recorder(recorder.clone());
+        }
+        if let Some(timeout) = self.idle_timeout {
+            stream = stream.with_idle_timeout(timeout);
+        }
+
+        stream
     }
 
-    pub async fn build_multistream(self, max_retries: Option<u64>) -> Result<MutliWsStream, WsError> {
-        let ws_streams = futures::stream::iter(self.exchanges)
+    pub async fn build_multistream(self) -> Result<MutliWsStream, WsError> {
+        let this = &self;
+        let ws_streams = futures::stream::iter(self.exchanges.clone())
             .map(|exch| async move {
-                let mut stream = WsStream::new(exch, max_retries);
+                let mut stream = this.new_stream(exch);
                 stream.connect().await?;
                 Ok::<_, WsError>(stream)
             })
@@ -73,52 +188,55 @@ where
             .into_iter()
             .collect::<Result<Vec<_>, _>>()?;
 
-        let stream_count = ws_streams.len();
-        let combined_streams = Box::pin(futures::stream::select_all(ws_streams));
-
-        Ok(MutliWsStream { combined_streams, stream_count })
+        Ok(MutliWsStream::new(ws_streams))
     }
 
-    pub fn build_multistream_unconnected(self, max_retries: Option<u64>) -> MutliWsStream {
+    pub fn build_multistream_unconnected(self) -> MutliWsStream {
         let ws_streams = self
             .exchanges
-            .into_iter()
-            .map(|exch| WsStream::new(exch, max_retries))
+            .iter()
+            .map(|exch| self.new_stream(exch.clone()))
             .collect::<Vec<_>>();
 
-        let stream_count = ws_streams.len();
-        let combined_streams = Box::pin(futures::stream::select_all(ws_streams));
+        MutliWsStream::new(ws_streams)
+    }
+
+    pub fn spawn_multithreaded(self, num_threads: usize, handle: Handle) -> MultithreadedWsStream {
+        let (tx, rx) = fan_out_channel(self.fan_out);
+        let shutdown = self.shutdown.clone().unwrap_or_default();
+        let threads = self.spawn_into(tx, shutdown.clone(), num_threads, handle);
 
-        MutliWsStream { combined_streams, stream_count }
+        MultithreadedWsStream::new(rx, threads, shutdown)
     }
 
-    pub fn spawn_multithreaded(self, num_threads: usize, max_retries: Option<u64>, handle: Handle) -> UnboundedReceiver<CombinedWsMessage> {
+    /// spawns the streams over `num_threads` threads, each sending into `tx`
+    /// & stopped by `shutdown`
+    pub(crate) fn spawn_into(self, tx: FanOutSender, shutdown: ShutdownHandle, num_threads: usize, handle: Handle) -> Vec<JoinHandle<()>> {
         let chunk_size = if self.exchanges.len() < num_threads + 1 { 1 } else { self.exchanges.len() / num_threads + 1 };
         let exchange_chunks = self.exchanges.chunks(chunk_size);
 
-        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
-
-        exchange_chunks.into_iter().for_each(|exchanges| {
-            let handle = handle.clone();
-            let exchanges = exchanges.to_vec();
-            let tx = tx.clone();
-            std::thread::spawn(move || {
-                let this_new = Self { exchanges };
-                let mut ms = this_new.build_multistream_unconnected(max_retries);
-
-                let fut = async move {
-                    while let Some(val) = ms.next().await {
-                        tx.send(val)?;
-                    }
-
-                    Ok::<(), eyre::Report>(())
-                };
-
-                handle.block_on(fut)?;
-                Ok::<(), eyre::Report>(())
-            });
-        });
-
-        rx
+        exchange_chunks
+            .into_iter()
+            .map(|exchanges| {
+                let handle = handle.clone();
+                let exchanges = exchanges.to_vec();
+                let tx = tx.clone();
+                let (recorder, idle_timeout, reconnect_policy, fan_out) =
+                    (self.recorder.clone(), self.idle_timeout, self.reconnect_policy, self.fan_out);
+                let shutdown = Some(shutdown.clone());
+                std::thread::spawn(move || {
+                    let this_new = Self { exchanges, recorder, idle_timeout, reconnect_policy, fan_out, shutdown };
+                    let mut ms = this_new.build_multistream_unconnected();
+
+                    handle.block_on(async move {
+                        while let Some(val) = ms.next().await {
+                            if tx.send(val).await.is_err() {
+                                break
+                            }
+                        }
+                    });
+                })
+            })
+            .collect()
     }
 }
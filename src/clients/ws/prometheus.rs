@@ -0,0 +1,214 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Write,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    time::Duration
+};
+
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+    task::JoinHandle
+};
+use tracing::{debug, warn};
+
+use super::WsMetrics;
+use crate::{normalized::types::NormalizedTradingPair, CexExchange};
+
+/// the upper bounds (in seconds) of the latency histogram's buckets
+const LATENCY_BUCKETS: [f64; 12] = [0.001, 0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+/// a [WsMetrics] recorder rendering the metrics in the prometheus text format,
+/// cloning it shares the same metrics
+///
+/// ```ignore
+/// let metrics = PrometheusMetrics::default();
+/// set_metrics_recorder(Arc::new(metrics.clone()));
+/// metrics.serve(([127, 0, 0, 1], 9100).into()).await?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct PrometheusMetrics {
+    state: Arc<Mutex<MetricsState>>
+}
+
+#[derive(Debug, Default)]
+struct MetricsState {
+    messages:                 BTreeMap<(String, &'static str), u64>,
+    deserialization_failures: BTreeMap<String, u64>,
+    reconnect_attempts:       BTreeMap<String, u64>,
+    reconnects:               BTreeMap<String, u64>,
+    bad_pairs_removed:        BTreeMap<String, u64>,
+    latencies:                BTreeMap<(String, &'static str), Histogram>
+}
+
+#[derive(Debug, Default)]
+struct Histogram {
+    buckets: [u64; LATENCY_BUCKETS.len()],
+    count:   u64,
+    sum:     f64
+}
+
+impl Histogram {
+    fn observe(&mut self, value: f64) {
+        LATENCY_BUCKETS
+            .iter()
+            .zip(self.buckets.iter_mut())
+            .filter(|(bound, _)| value <= **bound)
+            .for_each(|(_, bucket)| *bucket += 1);
+        self.count += 1;
+        self.sum += value;
+    }
+}
+
+impl PrometheusMetrics {
+    /// the metrics in the prometheus text exposition format
+    pub fn render(&self) -> String {
+        let state = self.state.lock().unwrap();
+        let mut out = String::new();
+
+        writeln!(out, "# HELP cex_ws_messages_total messages received per exchange & channel").unwrap();
+        writeln!(out, "# TYPE cex_ws_messages_total counter").unwrap();
+        state
+            .messages
+            .iter()
+            .for_each(|((exchange, channel), count)| {
+                writeln!(out, "cex_ws_messages_total{{exchange=\"{exchange}\",channel=\"{channel}\"}} {count}").unwrap();
+            });
+
+        [
+            ("cex_ws_deserialization_failures_total", "frames that couldn't be deserialized", &state.deserialization_failures),
+            ("cex_ws_reconnect_attempts_total", "reconnects attempted", &state.reconnect_attempts),
+            ("cex_ws_reconnects_total", "successful reconnects", &state.reconnects),
+            ("cex_ws_bad_pairs_removed_total", "pairs rejected by the exchange & removed", &state.bad_pairs_removed)
+        ]
+        .into_iter()
+        .for_each(|(name, help, counters)| {
+            writeln!(out, "# HELP {name} {help}").unwrap();
+            writeln!(out, "# TYPE {name} counter").unwrap();
+            counters.iter().for_each(|(exchange, count)| {
+                writeln!(out, "{name}{{exchange=\"{exchange}\"}} {count}").unwrap();
+            });
+        });
+
+        writeln!(out, "# HELP cex_ws_latency_seconds the time between the exchange's timestamp of a message & it being received").unwrap();
+        writeln!(out, "# TYPE cex_ws_latency_seconds histogram").unwrap();
+        state
+            .latencies
+            .iter()
+            .for_each(|((exchange, channel), histogram)| {
+                let labels = format!("exchange=\"{exchange}\",channel=\"{channel}\"");
+                LATENCY_BUCKETS
+                    .iter()
+                    .zip(histogram.buckets.iter())
+                    .for_each(|(bound, count)| {
+                        writeln!(out, "cex_ws_latency_seconds_bucket{{{labels},le=\"{bound}\"}} {count}").unwrap();
+                    });
+                writeln!(out, "cex_ws_latency_seconds_bucket{{{labels},le=\"+Inf\"}} {}", histogram.count).unwrap();
+                writeln!(out, "cex_ws_latency_seconds_sum{{{labels}}} {}", histogram.sum).unwrap();
+                writeln!(out, "cex_ws_latency_seconds_count{{{labels}}} {}", histogram.count).unwrap();
+            });
+
+        out
+    }
+
+    /// serves [Self::render] to every request on `addr`, returning the bound
+    /// address & the task serving it
+    pub async fn serve(&self, addr: SocketAddr) -> std::io::Result<(SocketAddr, JoinHandle<()>)> {
+        let listener = TcpListener::bind(addr).await?;
+        let local_addr = listener.local_addr()?;
+        debug!(target: "cex-exchanges::metrics", "serving the prometheus metrics on {local_addr}");
+
+        let this = self.clone();
+        let task = tokio::spawn(async move {
+            loop {
+                let mut socket = match listener.accept().await {
+                    Ok((socket, _)) => socket,
+                    Err(e) => {
+                        warn!(target: "cex-exchanges::metrics", "failed to accept a metrics connection: {e}");
+                        continue
+                    }
+                };
+
+                let body = this.render();
+                tokio::spawn(async move {
+                    // the request is ignored, every path returns the metrics
+                    let mut buf = [0u8; 1024];
+                    let _ = socket.read(&mut buf).await;
+
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                        body.len()
+                    );
+                    let _ = socket.write_all(response.as_bytes()).await;
+                    let _ = socket.shutdown().await;
+                });
+            }
+        });
+
+        Ok((local_addr, task))
+    }
+
+    fn increment(counters: &mut BTreeMap<String, u64>, exchange: CexExchange) {
+        *counters.entry(exchange.to_string()).or_default() += 1;
+    }
+}
+
+impl WsMetrics for PrometheusMetrics {
+    fn message_received(&self, exchange: CexExchange, channel: &'static str) {
+        *self
+            .state
+            .lock()
+            .unwrap()
+            .messages
+            .entry((exchange.to_string(), channel))
+            .or_default() += 1;
+    }
+
+    fn deserialization_failed(&self, exchange: CexExchange) {
+        Self::increment(&mut self.state.lock().unwrap().deserialization_failures, exchange);
+    }
+
+    fn reconnect_attempt(&self, exchange: CexExchange, _attempt: u64) {
+        Self::increment(&mut self.state.lock().unwrap().reconnect_attempts, exchange);
+    }
+
+    fn reconnected(&self, exchange: CexExchange) {
+        Self::increment(&mut self.state.lock().unwrap().reconnects, exchange);
+    }
+
+    fn bad_pair_removed(&self, exchange: CexExchange, _pair: &NormalizedTradingPair) {
+        Self::increment(&mut self.state.lock().unwrap().bad_pairs_removed, exchange);
+    }
+
+    fn latency(&self, exchange: CexExchange, channel: &'static str, latency: Duration) {
+        self.state
+            .lock()
+            .unwrap()
+            .latencies
+            .entry((exchange.to_string(), channel))
+            .or_default()
+            .observe(latency.as_secs_f64());
+    }
+}
+
+#[cfg(all(test, feature = "binance"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let metrics = PrometheusMetrics::default();
+        metrics.message_received(CexExchange::Binance, "trades");
+        metrics.message_received(CexExchange::Binance, "trades");
+        metrics.reconnected(CexExchange::Binance);
+        metrics.latency(CexExchange::Binance, "trades", Duration::from_millis(20));
+
+        let rendered = metrics.render();
+        assert!(rendered.contains("cex_ws_messages_total{exchange=\"binance\",channel=\"trades\"} 2"));
+        assert!(rendered.contains("cex_ws_reconnects_total{exchange=\"binance\"} 1"));
+        assert!(rendered.contains("cex_ws_latency_seconds_bucket{exchange=\"binance\",channel=\"trades\",le=\"0.01\"} 0"));
+        assert!(rendered.contains("cex_ws_latency_seconds_bucket{exchange=\"binance\",channel=\"trades\",le=\"0.025\"} 1"));
+        assert!(rendered.contains("cex_ws_latency_seconds_count{exchange=\"binance\",channel=\"trades\"} 1"));
+    }
+}
@@ -0,0 +1,37 @@
+use std::cell::Cell;
+
+use chrono::{DateTime, Utc};
+
+thread_local! {
+    /// the time the frame being deserialized on this thread was received
+    static RECEIVED_AT: Cell<Option<DateTime<Utc>>> = const { Cell::new(None) };
+}
+
+/// the time the frame being deserialized was received, used as the serde
+/// default of the exchanges' `received_at` fields
+///
+/// it's the current time outside of [with_received_at] (ex: rest responses)
+pub(crate) fn received_at() -> DateTime<Utc> {
+    RECEIVED_AT.with(|at| at.get()).unwrap_or_else(Utc::now)
+}
+
+/// runs `f` (the deserialization of a frame) with [received_at] returning
+/// `at`
+pub(crate) fn with_received_at<R>(at: DateTime<Utc>, f: impl FnOnce() -> R) -> R {
+    let prev = RECEIVED_AT.with(|received| received.replace(Some(at)));
+    let res = f();
+    RECEIVED_AT.with(|received| received.set(prev));
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_received_at() {
+        let at = DateTime::from_timestamp_millis(1717000000000).unwrap();
+        assert_eq!(with_received_at(at, received_at), at);
+        assert_ne!(received_at(), at);
+    }
+}
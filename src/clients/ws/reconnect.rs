@@ -0,0 +1,130 @@
+use std::time::Duration;
+
+use rand::Rng;
+
+/// how a stream reconnects after its connection is dropped, the first
+/// reconnect is immediate & every failed one after it waits exponentially
+/// longer
+///
+/// the attempts are reset once a message is received on the new connection
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReconnectPolicy {
+    /// the wait before the 2nd consecutive reconnect
+    pub initial_delay: Duration,
+    /// the wait is multiplied by this after every failed reconnect
+    pub multiplier:    f64,
+    pub max_delay:     Duration,
+    /// the wait is randomized by up to this fraction of it (0.0 - 1.0), so
+    /// streams dropped together don't all reconnect at once
+    pub jitter:        f64,
+    /// the stream ends after this many consecutive reconnects, `None` retries
+    /// forever
+    pub max_attempts:  Option<u64>
+}
+
+impl ReconnectPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// reconnects immediately & forever
+    pub fn no_backoff() -> Self {
+        Self { initial_delay: Duration::ZERO, multiplier: 1.0, max_delay: Duration::ZERO, jitter: 0.0, max_attempts: None }
+    }
+
+    pub fn with_initial_delay(mut self, initial_delay: Duration) -> Self {
+        self.initial_delay = initial_delay;
+        self
+    }
+
+    pub fn with_multiplier(mut self, multiplier: f64) -> Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    pub fn with_jitter(mut self, jitter: f64) -> Self {
+        self.jitter = jitter.clamp(0.0, 1.0);
+        self
+    }
+
+    pub fn with_max_attempts(mut self, max_attempts: u64) -> Self {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
+    /// if the stream can make the reconnect after `attempt` consecutive ones
+    pub fn can_reconnect(&self, attempt: u64) -> bool {
+        self.max_attempts.is_none_or(|max| attempt < max)
+    }
+
+    /// the wait before the reconnect after `attempt` consecutive ones
+    pub fn delay(&self, attempt: u64) -> Duration {
+        if attempt == 0 {
+            return Duration::ZERO
+        }
+
+        let exponent = i32::try_from(attempt - 1).unwrap_or(i32::MAX);
+        let delay = (self.initial_delay.as_secs_f64() * self.multiplier.powi(exponent)).min(self.max_delay.as_secs_f64());
+        let jitter = if self.jitter > 0.0 { rand::thread_rng().gen_range(-self.jitter..=self.jitter) } else { 0.0 };
+
+        Duration::from_secs_f64((delay * (1.0 + jitter)).clamp(0.0, self.max_delay.as_secs_f64()))
+    }
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(500),
+            multiplier:    2.0,
+            max_delay:     Duration::from_secs(30),
+            jitter:        0.2,
+            max_attempts:  None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay() {
+        let policy = ReconnectPolicy::new()
+            .with_initial_delay(Duration::from_secs(1))
+            .with_multiplier(2.0)
+            .with_max_delay(Duration::from_secs(10))
+            .with_jitter(0.0);
+
+        let delays = (0..7)
+            .map(|attempt| policy.delay(attempt).as_secs())
+            .collect::<Vec<_>>();
+        assert_eq!(delays, vec![0, 1, 2, 4, 8, 10, 10]);
+        assert_eq!(policy.delay(u64::MAX), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_jitter() {
+        let policy = ReconnectPolicy::new()
+            .with_initial_delay(Duration::from_secs(4))
+            .with_max_delay(Duration::from_secs(60))
+            .with_jitter(0.25);
+
+        (0..100).for_each(|_| {
+            let delay = policy.delay(1);
+            assert!(delay >= Duration::from_secs(3) && delay <= Duration::from_secs(5));
+        });
+    }
+
+    #[test]
+    fn test_max_attempts() {
+        let policy = ReconnectPolicy::new().with_max_attempts(3);
+        assert!(policy.can_reconnect(2));
+        assert!(!policy.can_reconnect(3));
+        assert!(ReconnectPolicy::new().can_reconnect(u64::MAX));
+    }
+}
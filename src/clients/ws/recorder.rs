@@ -0,0 +1,118 @@
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufRead, BufReader, LineWriter, Write},
+    path::Path,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex
+    }
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use tokio_tungstenite::tungstenite::Message;
+use tracing::error;
+
+use crate::CexExchange;
+
+/// a raw frame as it was received from the exchange
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RecordedFrame {
+    pub exchange:      CexExchange,
+    /// the connection (of the recorder) the frame was received on, a new id
+    /// is given on each reconnect
+    pub connection_id: u64,
+    pub received_at:   DateTime<Utc>,
+    pub frame:         RecordedFrameData
+}
+
+/// the payload of a recorded frame, written as `{"kind":"text","data":".."}`
+/// or `{"kind":"binary","data":"<base64>"}`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "kind", content = "data", rename_all = "snake_case")]
+pub enum RecordedFrameData {
+    Text(String),
+    Binary(#[serde(serialize_with = "serialize_base64", deserialize_with = "deserialize_base64")] Vec<u8>)
+}
+
+impl RecordedFrameData {
+    /// the data frames of the stream, `None` for the control frames
+    pub(crate) fn from_message(message: &Message) -> Option<Self> {
+        match message {
+            Message::Text(frame) => Some(Self::Text(frame.clone())),
+            Message::Binary(frame) => Some(Self::Binary(frame.clone())),
+            _ => None
+        }
+    }
+}
+
+impl From<RecordedFrameData> for Message {
+    fn from(value: RecordedFrameData) -> Self {
+        match value {
+            RecordedFrameData::Text(frame) => Message::Text(frame),
+            RecordedFrameData::Binary(frame) => Message::Binary(frame)
+        }
+    }
+}
+
+fn serialize_base64<S>(data: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer
+{
+    serializer.serialize_str(&STANDARD.encode(data))
+}
+
+fn deserialize_base64<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>
+{
+    let data = String::deserialize(deserializer)?;
+    STANDARD.decode(data).map_err(serde::de::Error::custom)
+}
+
+impl RecordedFrame {
+    /// reads the frames written by a [FrameRecorder]
+    pub fn read_file(path: impl AsRef<Path>) -> std::io::Result<Vec<Self>> {
+        BufReader::new(File::open(path)?)
+            .lines()
+            .filter(|line| !matches!(line, Ok(l) if l.trim().is_empty()))
+            .map(|line| serde_json::from_str(&line?).map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e)))
+            .collect()
+    }
+}
+
+/// writes every raw frame received by the streams it's set on (except the
+/// heartbeat replies) to an append-only file, with a frame (as json) on each
+/// line
+///
+/// the connection ids restart from 0 in each process
+#[derive(Debug, Clone)]
+pub struct FrameRecorder {
+    writer:         Arc<Mutex<LineWriter<File>>>,
+    connection_ids: Arc<AtomicU64>
+}
+
+impl FrameRecorder {
+    /// appends to `path`, creating it if it doesn't exist
+    pub fn new(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+
+        Ok(Self { writer: Arc::new(Mutex::new(LineWriter::new(file))), connection_ids: Arc::new(AtomicU64::new(0)) })
+    }
+
+    pub(crate) fn next_connection_id(&self) -> u64 {
+        self.connection_ids.fetch_add(1, Ordering::Relaxed)
+    }
+
+    pub(crate) fn record(&self, exchange: CexExchange, connection_id: u64, frame: RecordedFrameData, received_at: DateTime<Utc>) {
+        let recorded = RecordedFrame { exchange, connection_id, received_at, frame };
+
+        let write = serde_json::to_string(&recorded)
+            .map_err(std::io::Error::from)
+            .and_then(|line| writeln!(self.writer.lock().unwrap(), "{line}"));
+        if let Err(e) = write {
+            error!(target: "cex-exchanges::recorder", "failed to record the {exchange} frame: {:?}", e);
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use std::{
+    collections::VecDeque,
+    future::Future,
+    path::Path,
+    pin::Pin,
+    task::{ready, Context, Poll}
+};
+
+use chrono::{DateTime, Utc};
+use futures::Stream;
+use tokio::time::Sleep;
+
+use super::{with_received_at, RecordedFrame};
+use crate::exchanges::normalized::ws::CombinedWsMessage;
+
+/// replays recorded frames through the exchanges' deserializers, in the
+/// order they were recorded
+///
+/// the sequences aren't checked, so no gap events or order book snapshots
+/// are emitted
+pub struct ReplayStream {
+    frames:           VecDeque<RecordedFrame>,
+    original_pace:    bool,
+    last_received_at: Option<DateTime<Utc>>,
+    delay:            Option<Pin<Box<Sleep>>>
+}
+
+impl ReplayStream {
+    pub fn new(frames: Vec<RecordedFrame>) -> Self {
+        Self { frames: frames.into(), original_pace: false, last_received_at: None, delay: None }
+    }
+
+    /// replays the frames written by a [super::FrameRecorder]
+    pub fn from_file(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        Ok(Self::new(RecordedFrame::read_file(path)?))
+    }
+
+    /// waits between the frames as long as between when they were received,
+    /// they're replayed as fast as possible otherwise
+    pub fn at_original_pace(mut self) -> Self {
+        self.original_pace = true;
+        self
+    }
+
+    pub fn remaining_frames(&self) -> usize {
+        self.frames.len()
+    }
+}
+
+impl Stream for ReplayStream {
+    type Item = CombinedWsMessage;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+
+        loop {
+            if let Some(delay) = this.delay.as_mut() {
+                ready!(delay.as_mut().poll(cx));
+                this.delay = None;
+            }
+
+            let Some(frame) = this.frames.pop_front() else { return Poll::Ready(None) };
+
+            let gap = this
+                .last_received_at
+                .replace(frame.received_at)
+                .and_then(|last| (frame.received_at - last).to_std().ok())
+                .filter(|gap| this.original_pace && !gap.is_zero());
+            if let Some(gap) = gap {
+                this.delay = Some(Box::pin(tokio::time::sleep(gap)));
+                this.frames.push_front(frame);
+                continue
+            }
+
+            let (exchange, received_at) = (frame.exchange, frame.received_at);
+            return Poll::Ready(Some(with_received_at(received_at, || exchange.deserialize_ws_message(frame.frame.into()))))
+        }
+    }
+}
@@ -0,0 +1,380 @@
+use std::collections::HashMap;
+
+use crate::normalized::types::NormalizedTradingPair;
+
+/// the sequence id(s) of a ws message for a single channel & pair
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WsSequence {
+    /// name of the channel the sequence is tracked for
+    pub channel: &'static str,
+    pub pair:    NormalizedTradingPair,
+    pub kind:    SequenceKind,
+    /// if a gap in this channel requires a snapshot to recover (depth
+    /// channels)
+    pub resync:  bool
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SequenceKind {
+    /// replaces the tracked sequence, `None` if the snapshot doesn't carry a
+    /// sequence id (the next update becomes the start of the sequence)
+    Snapshot(Option<u64>),
+    /// each update covers the ids `first..=last` and the next update must
+    /// start at `last + 1`
+    Contiguous { first: u64, last: u64 },
+    /// ids only need to increase, they can skip values
+    Increasing(u64),
+    /// each update carries the id of the previous update
+    Linked { prev: u64, id: u64 }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SequenceCheck {
+    InOrder,
+    /// the update is already covered by the tracked sequence (ex: a depth
+    /// update older than the snapshot)
+    Stale,
+    /// an update was dropped or arrived out of order
+    Gap {
+        expected: u64,
+        received: u64
+    },
+    /// the channel needs a snapshot before the updates can be used (first
+    /// update of a depth channel)
+    NeedsSnapshot,
+    /// the channel is waiting for a snapshot
+    Resyncing
+}
+
+/// what the stream does with a message after its sequence is checked
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Sequenced<M> {
+    /// forwarded downstream
+    Message(M),
+    Gap {
+        channel:  &'static str,
+        pair:     NormalizedTradingPair,
+        expected: u64,
+        received: u64
+    },
+    /// the channel needs a new snapshot, its updates are buffered until the
+    /// snapshot is applied
+    Resync {
+        channel: &'static str,
+        pair:    NormalizedTradingPair
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum SequenceState {
+    Last(u64),
+    /// waiting for the next update to start the sequence
+    Untracked,
+    /// waiting for a snapshot
+    Resyncing
+}
+
+type SequenceKey = (&'static str, NormalizedTradingPair);
+
+/// tracks the sequence ids per channel & pair of a single stream, buffering
+/// the updates of depth channels while their snapshot is fetched
+#[derive(Debug)]
+pub(crate) struct SequenceTracker<M> {
+    sequences: HashMap<SequenceKey, SequenceState>,
+    buffered:  HashMap<SequenceKey, Vec<(WsSequence, M)>>
+}
+
+impl<M> Default for SequenceTracker<M> {
+    fn default() -> Self {
+        Self { sequences: HashMap::new(), buffered: HashMap::new() }
+    }
+}
+
+impl<M> SequenceTracker<M> {
+    /// checks the sequence of the message, appending what the stream should
+    /// do with it to `out`
+    ///
+    /// updates of a resyncing depth channel are held back & replayed once the
+    /// snapshot is applied, dropping the ones it already covers (binance's
+    /// local order book procedure)
+    pub(crate) fn process(&mut self, seq: WsSequence, msg: M, out: &mut Vec<Sequenced<M>>) {
+        match self.check(&seq) {
+            SequenceCheck::InOrder => {
+                out.push(Sequenced::Message(msg));
+                if matches!(seq.kind, SequenceKind::Snapshot(_)) {
+                    self.replay(seq.channel, &seq.pair, out);
+                }
+            }
+            SequenceCheck::Stale => (),
+            SequenceCheck::Resyncing => self.buffer(seq, msg),
+            SequenceCheck::NeedsSnapshot => {
+                out.push(Sequenced::Resync { channel: seq.channel, pair: seq.pair.clone() });
+                self.buffer(seq, msg);
+            }
+            SequenceCheck::Gap { expected, received } => {
+                out.push(Sequenced::Gap { channel: seq.channel, pair: seq.pair.clone(), expected, received });
+                if seq.resync {
+                    out.push(Sequenced::Resync { channel: seq.channel, pair: seq.pair.clone() });
+                    self.buffer(seq, msg);
+                } else {
+                    out.push(Sequenced::Message(msg));
+                }
+            }
+        }
+    }
+
+    fn buffer(&mut self, seq: WsSequence, msg: M) {
+        self.buffered
+            .entry((seq.channel, seq.pair.clone()))
+            .or_default()
+            .push((seq, msg));
+    }
+
+    /// re-checks the updates buffered while the channel was resyncing
+    fn replay(&mut self, channel: &'static str, pair: &NormalizedTradingPair, out: &mut Vec<Sequenced<M>>) {
+        let Some(buffered) = self.buffered.remove(&(channel, pair.clone())) else { return };
+        for (seq, msg) in buffered {
+            self.process(seq, msg, out);
+        }
+    }
+
+    pub(crate) fn check(&mut self, seq: &WsSequence) -> SequenceCheck {
+        let key = (seq.channel, seq.pair.clone());
+
+        let (first, last) = match seq.kind {
+            SequenceKind::Snapshot(id) => {
+                self.sequences.insert(
+                    key,
+                    id.map(SequenceState::Last)
+                        .unwrap_or(SequenceState::Untracked)
+                );
+                return SequenceCheck::InOrder
+            }
+            SequenceKind::Contiguous { first, last } => (first, last),
+            SequenceKind::Increasing(id) => (id, id),
+            SequenceKind::Linked { prev, id } => (prev, id)
+        };
+
+        let prev = match self.sequences.get(&key) {
+            Some(SequenceState::Last(prev)) => *prev,
+            Some(SequenceState::Resyncing) => return SequenceCheck::Resyncing,
+            Some(SequenceState::Untracked) => {
+                self.sequences.insert(key, SequenceState::Last(last));
+                return SequenceCheck::InOrder
+            }
+            None if seq.resync => {
+                self.sequences.insert(key, SequenceState::Resyncing);
+                return SequenceCheck::NeedsSnapshot
+            }
+            None => {
+                self.sequences.insert(key, SequenceState::Last(last));
+                return SequenceCheck::InOrder
+            }
+        };
+
+        let gap = match seq.kind {
+            SequenceKind::Contiguous { .. } => {
+                if last <= prev {
+                    // already covered by a previous update or snapshot
+                    return SequenceCheck::Stale
+                }
+                (first > prev + 1).then_some((prev + 1, first))
+            }
+            SequenceKind::Increasing(_) => {
+                if last < prev {
+                    return SequenceCheck::Gap { expected: prev, received: last }
+                }
+                None
+            }
+            SequenceKind::Linked { .. } => {
+                if last <= prev {
+                    return SequenceCheck::Stale
+                }
+                (first != prev).then_some((prev, first))
+            }
+            SequenceKind::Snapshot(_) => unreachable!()
+        };
+
+        if let Some((expected, received)) = gap {
+            if seq.resync {
+                self.sequences.insert(key, SequenceState::Resyncing);
+            } else {
+                self.sequences.insert(key, SequenceState::Last(last));
+            }
+            SequenceCheck::Gap { expected, received }
+        } else {
+            self.sequences.insert(key, SequenceState::Last(last));
+            SequenceCheck::InOrder
+        }
+    }
+
+    /// stops tracking the channel & pair, dropping its buffered updates, used
+    /// when a resync failed (so the next update requests a new snapshot)
+    pub(crate) fn reset(&mut self, channel: &'static str, pair: &NormalizedTradingPair) {
+        let key = (channel, pair.clone());
+        self.sequences.remove(&key);
+        self.buffered.remove(&key);
+    }
+
+    /// stops tracking every channel of the pair, used when the pair is
+    /// unsubscribed
+    ///
+    /// the pair is matched on its symbol, the pair of the update & the
+    /// exchange's messages not always having the same delimiter
+    pub(crate) fn reset_pair(&mut self, pair: &NormalizedTradingPair) {
+        let symbol = Self::symbol(pair);
+        self.sequences
+            .retain(|(_, tracked), _| Self::symbol(tracked) != symbol);
+        self.buffered
+            .retain(|(_, tracked), _| Self::symbol(tracked) != symbol);
+    }
+
+    fn symbol(pair: &NormalizedTradingPair) -> String {
+        pair.make_pair()
+            .chars()
+            .filter(char::is_ascii_alphanumeric)
+            .collect()
+    }
+
+    /// starts the sequence from the next update, used when the exchange
+    /// doesn't provide a snapshot
+    pub(crate) fn untrack(&mut self, channel: &'static str, pair: &NormalizedTradingPair, out: &mut Vec<Sequenced<M>>) {
+        self.sequences
+            .insert((channel, pair.clone()), SequenceState::Untracked);
+        self.replay(channel, pair, out);
+    }
+}
+
+#[cfg(all(test, feature = "binance"))]
+mod tests {
+    use super::*;
+    use crate::CexExchange;
+
+    fn sequence(kind: SequenceKind, resync: bool) -> WsSequence {
+        WsSequence { channel: "test", pair: NormalizedTradingPair::new_base_quote(CexExchange::Binance, "ETH", "USDT", None, None), kind, resync }
+    }
+
+    #[test]
+    fn test_contiguous_gap_and_resync() {
+        let mut tracker = SequenceTracker::<()>::default();
+
+        let first = sequence(SequenceKind::Contiguous { first: 1, last: 5 }, true);
+        assert_eq!(tracker.check(&first), SequenceCheck::NeedsSnapshot);
+        assert_eq!(tracker.check(&sequence(SequenceKind::Contiguous { first: 6, last: 8 }, true)), SequenceCheck::Resyncing);
+
+        assert_eq!(tracker.check(&sequence(SequenceKind::Snapshot(Some(7)), true)), SequenceCheck::InOrder);
+        assert_eq!(tracker.check(&sequence(SequenceKind::Contiguous { first: 6, last: 7 }, true)), SequenceCheck::Stale);
+        assert_eq!(tracker.check(&sequence(SequenceKind::Contiguous { first: 6, last: 10 }, true)), SequenceCheck::InOrder);
+        assert_eq!(
+            tracker.check(&sequence(SequenceKind::Contiguous { first: 13, last: 15 }, true)),
+            SequenceCheck::Gap { expected: 11, received: 13 }
+        );
+        assert_eq!(tracker.check(&sequence(SequenceKind::Contiguous { first: 16, last: 17 }, true)), SequenceCheck::Resyncing);
+    }
+
+    #[test]
+    fn test_increasing_and_linked() {
+        let mut tracker = SequenceTracker::<()>::default();
+
+        assert_eq!(tracker.check(&sequence(SequenceKind::Increasing(10), false)), SequenceCheck::InOrder);
+        assert_eq!(tracker.check(&sequence(SequenceKind::Increasing(15), false)), SequenceCheck::InOrder);
+        assert_eq!(tracker.check(&sequence(SequenceKind::Increasing(12), false)), SequenceCheck::Gap { expected: 15, received: 12 });
+        assert_eq!(tracker.check(&sequence(SequenceKind::Increasing(16), false)), SequenceCheck::InOrder);
+
+        let mut tracker = SequenceTracker::<()>::default();
+        assert_eq!(tracker.check(&sequence(SequenceKind::Snapshot(Some(100)), true)), SequenceCheck::InOrder);
+        assert_eq!(tracker.check(&sequence(SequenceKind::Linked { prev: 100, id: 104 }, true)), SequenceCheck::InOrder);
+        assert_eq!(tracker.check(&sequence(SequenceKind::Linked { prev: 106, id: 110 }, true)), SequenceCheck::Gap { expected: 104, received: 106 });
+        assert_eq!(tracker.check(&sequence(SequenceKind::Linked { prev: 110, id: 112 }, true)), SequenceCheck::Resyncing);
+
+        assert_eq!(tracker.check(&sequence(SequenceKind::Snapshot(None), true)), SequenceCheck::InOrder);
+        assert_eq!(tracker.check(&sequence(SequenceKind::Linked { prev: 200, id: 201 }, true)), SequenceCheck::InOrder);
+        assert_eq!(tracker.check(&sequence(SequenceKind::Linked { prev: 201, id: 205 }, true)), SequenceCheck::InOrder);
+    }
+
+    #[test]
+    fn test_buffered_resync() {
+        // the message is the last id it covers
+        fn process(tracker: &mut SequenceTracker<u64>, kind: SequenceKind, out: &mut Vec<Sequenced<u64>>) {
+            let id = match kind {
+                SequenceKind::Snapshot(id) => id.unwrap(),
+                SequenceKind::Contiguous { last, .. } => last,
+                _ => unreachable!()
+            };
+            tracker.process(sequence(kind, true), id, out);
+        }
+
+        let mut tracker = SequenceTracker::default();
+        let mut out = Vec::new();
+
+        process(&mut tracker, SequenceKind::Snapshot(Some(10)), &mut out);
+        process(&mut tracker, SequenceKind::Contiguous { first: 11, last: 12 }, &mut out);
+        // 13..=14 was dropped
+        process(&mut tracker, SequenceKind::Contiguous { first: 15, last: 16 }, &mut out);
+        process(&mut tracker, SequenceKind::Contiguous { first: 17, last: 18 }, &mut out);
+        process(&mut tracker, SequenceKind::Contiguous { first: 19, last: 21 }, &mut out);
+        process(&mut tracker, SequenceKind::Contiguous { first: 22, last: 23 }, &mut out);
+
+        let pair = sequence(SequenceKind::Snapshot(None), true).pair;
+        assert_eq!(
+            out,
+            vec![
+                Sequenced::Message(10),
+                Sequenced::Message(12),
+                Sequenced::Gap { channel: "test", pair: pair.clone(), expected: 13, received: 15 },
+                Sequenced::Resync { channel: "test", pair: pair.clone() }
+            ]
+        );
+
+        // the snapshot covers up to 20, only the updates after it are replayed
+        out.clear();
+        process(&mut tracker, SequenceKind::Snapshot(Some(20)), &mut out);
+        assert_eq!(out, vec![Sequenced::Message(20), Sequenced::Message(21), Sequenced::Message(23)]);
+
+        out.clear();
+        process(&mut tracker, SequenceKind::Contiguous { first: 24, last: 25 }, &mut out);
+        assert_eq!(out, vec![Sequenced::Message(25)]);
+    }
+
+    #[test]
+    fn test_buffered_resync_gap_after_snapshot() {
+        let mut tracker = SequenceTracker::default();
+        let mut out = Vec::new();
+
+        tracker.process(sequence(SequenceKind::Contiguous { first: 5, last: 6 }, true), 6, &mut out);
+        tracker.process(sequence(SequenceKind::Contiguous { first: 7, last: 8 }, true), 8, &mut out);
+        tracker.process(sequence(SequenceKind::Contiguous { first: 12, last: 13 }, true), 13, &mut out);
+
+        let pair = sequence(SequenceKind::Snapshot(None), true).pair;
+        assert_eq!(out, vec![Sequenced::Resync { channel: "test", pair: pair.clone() }]);
+
+        // the buffered updates don't continue from the snapshot, so it's resynced again
+        out.clear();
+        tracker.process(sequence(SequenceKind::Snapshot(Some(9)), true), 9, &mut out);
+        assert_eq!(
+            out,
+            vec![
+                Sequenced::Message(9),
+                Sequenced::Gap { channel: "test", pair: pair.clone(), expected: 10, received: 12 },
+                Sequenced::Resync { channel: "test", pair }
+            ]
+        );
+    }
+
+    #[test]
+    fn test_reset_pair() {
+        let mut tracker = SequenceTracker::default();
+        let mut out = Vec::new();
+
+        tracker.process(sequence(SequenceKind::Snapshot(Some(10)), true), 10, &mut out);
+        tracker.process(sequence(SequenceKind::Contiguous { first: 11, last: 12 }, true), 12, &mut out);
+        assert_eq!(out, vec![Sequenced::Message(10), Sequenced::Message(12)]);
+
+        // unsubscribed with a delimiter the messages' pair doesn't have, then
+        // subscribed again: the old sequence isn't a gap, a new snapshot is needed
+        tracker.reset_pair(&NormalizedTradingPair::new_base_quote(CexExchange::Binance, "ETH", "USDT", Some('_'), None));
+        out.clear();
+        tracker.process(sequence(SequenceKind::Contiguous { first: 40, last: 41 }, true), 41, &mut out);
+        assert_eq!(out, vec![Sequenced::Resync { channel: "test", pair: sequence(SequenceKind::Snapshot(None), true).pair }]);
+    }
+}
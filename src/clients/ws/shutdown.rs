@@ -0,0 +1,64 @@
+use std::{future::Future, pin::Pin, sync::Arc};
+
+use tokio::sync::watch;
+
+pub(crate) type ShutdownSignal = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// stops running stream(s), each unsubscribes (where the exchange supports
+/// it) & sends a close frame, then ends once the exchange closes the socket
+///
+/// the messages already received are still yielded before the stream ends
+#[derive(Debug, Clone)]
+pub struct ShutdownHandle {
+    streams: Vec<Arc<watch::Sender<bool>>>
+}
+
+impl ShutdownHandle {
+    pub fn new() -> Self {
+        Self { streams: vec![Arc::new(watch::Sender::new(false))] }
+    }
+
+    pub(crate) fn combine(mut self, other: Self) -> Self {
+        other.streams.into_iter().for_each(|stream| {
+            if !self.streams.iter().any(|s| Arc::ptr_eq(s, &stream)) {
+                self.streams.push(stream)
+            }
+        });
+        self
+    }
+
+    pub fn shutdown(&self) {
+        self.streams.iter().for_each(|stream| {
+            stream.send_replace(true);
+        });
+    }
+
+    pub fn is_shutdown(&self) -> bool {
+        self.streams.iter().any(|stream| *stream.borrow())
+    }
+
+    /// resolves once shutdown is requested
+    pub(crate) fn signal(&self) -> ShutdownSignal {
+        let mut rxs = self
+            .streams
+            .iter()
+            .map(|stream| stream.subscribe())
+            .collect::<Vec<_>>();
+
+        // the stream keeps its handle, so the senders are never dropped
+        Box::pin(async move {
+            let requested = rxs.iter_mut().map(|rx| {
+                Box::pin(async move {
+                    let _ = rx.wait_for(|shutdown| *shutdown).await;
+                })
+            });
+            futures::future::select_all(requested).await;
+        })
+    }
+}
+
+impl Default for ShutdownHandle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,95 +1,492 @@
 use std::{
+    collections::VecDeque,
     fmt::Debug,
     pin::Pin,
-    task::{Context, Poll}
+    sync::Arc,
+    task::{Context, Poll},
+    time::Duration
 };
 
-use futures::{Future, FutureExt, SinkExt, Stream, StreamExt};
-use tokio::net::TcpStream;
+use chrono::{DateTime, Utc};
+use futures::{stream::FuturesUnordered, Future, FutureExt, SinkExt, Stream, StreamExt};
+use tokio::{
+    net::TcpStream,
+    sync::mpsc::UnboundedReceiver,
+    time::{Instant, Interval, MissedTickBehavior, Sleep}
+};
 use tokio_tungstenite::{tungstenite::Message, MaybeTlsStream, WebSocketStream};
-use tracing::{error, trace};
+use tracing::{debug, error, trace, warn};
 
-use super::WsError;
+use super::{
+    metrics_recorder, record_message, with_received_at, FrameRecorder, ReconnectPolicy, RecordedFrameData, SequenceTracker, Sequenced,
+    ShutdownHandle, ShutdownSignal, WsError, WsSubscriptionHandle, WsSubscriptionRequest
+};
 use crate::{
-    clients::ws::critical::CriticalWsMessage,
+    clients::{rate_limiter, rest_api::RestApiError, subscribe_limiter, ws::critical::CriticalWsMessage, RateLimitKind, TokenBucket},
     exchanges::normalized::ws::{CombinedWsMessage, MessageOrPing},
+    normalized::types::NormalizedTradingPair,
     Exchange
 };
-type ReconnectFuture = Option<Pin<Box<dyn Future<Output = Result<WebSocketStream<MaybeTlsStream<TcpStream>>, WsError>> + Send>>>;
+/// how long a shutting down stream waits for the exchange to close the socket
+const CLOSE_TIMEOUT: Duration = Duration::from_secs(5);
+
+type ConnectFuture = Pin<Box<dyn Future<Output = Result<WebSocketStream<MaybeTlsStream<TcpStream>>, WsError>> + Send>>;
+
+type ReconnectFuture = Option<ConnectFuture>;
+
+/// a message with the time it was received (or fetched for a snapshot)
+type Received<M> = (M, DateTime<Utc>);
+
+type ResyncFuture<M> = Pin<Box<dyn Future<Output = (&'static str, NormalizedTradingPair, Result<Option<Received<M>>, RestApiError>)> + Send>>;
 
 type StreamConn = Pin<Box<WebSocketStream<MaybeTlsStream<TcpStream>>>>;
 
-pub struct WsStream<T> {
-    exchange:      T,
-    stream:        Option<StreamConn>,
-    reconnect_fut: ReconnectFuture,
-    max_retries:   Option<u64>,
-    retry_count:   u64
+pub struct WsStream<T: Exchange> {
+    exchange:           T,
+    stream:             Option<StreamConn>,
+    reconnect_fut:      ReconnectFuture,
+    reconnect_policy:   ReconnectPolicy,
+    /// consecutive reconnects since a message was last received
+    reconnect_attempts: u64,
+    sequences:          SequenceTracker<Received<T::WsMessage>>,
+    /// order book snapshots being fetched to resync a depth channel
+    resyncs:            FuturesUnordered<ResyncFuture<T::WsMessage>>,
+    /// messages (& sequence gap events) waiting to be returned
+    pending:            VecDeque<CombinedWsMessage>,
+    recorder:           Option<FrameRecorder>,
+    /// id of the current connection in the recorder
+    connection_id:      u64,
+    handle:             WsSubscriptionHandle,
+    /// subscription changes sent by the handle
+    updates:            UnboundedReceiver<WsSubscriptionRequest>,
+    /// subscription frames waiting on the exchange's subscribe limit
+    outgoing:           VecDeque<Message>,
+    /// heartbeats, pongs & the closing frames waiting for the socket to take
+    /// them
+    unsent:             VecDeque<Message>,
+    /// the subscribe limit of the current connection, a new one is taken on
+    /// every (re)connection
+    subscribe_limiter:  Arc<TokenBucket>,
+    outgoing_delay:     Option<Pin<Box<Sleep>>>,
+    /// ticks when the exchange's application level ping is due
+    heartbeat:          Option<(Interval, Message)>,
+    /// reconnects if nothing is received for this long
+    idle_timeout:       Option<Duration>,
+    idle:               Option<Pin<Box<Sleep>>>,
+    shutdown:           ShutdownHandle,
+    /// made from the handle on the first poll
+    shutdown_signal:    Option<ShutdownSignal>,
+    /// set once shutdown is requested, ending the stream if the exchange
+    /// hasn't closed the socket by then
+    close_deadline:     Option<Pin<Box<Sleep>>>
 }
 
 impl<T> WsStream<T>
 where
     T: Exchange + Send
 {
-    pub fn new(exchange: T, max_retries: Option<u64>) -> Self {
-        Self { exchange, stream: None, reconnect_fut: None, max_retries, retry_count: 0 }
+    pub fn new(exchange: T) -> Self {
+        let (handle, updates) = WsSubscriptionHandle::new(T::EXCHANGE);
+        Self {
+            exchange,
+            stream: None,
+            reconnect_fut: None,
+            reconnect_policy: ReconnectPolicy::default(),
+            reconnect_attempts: 0,
+            sequences: SequenceTracker::default(),
+            resyncs: FuturesUnordered::new(),
+            pending: VecDeque::new(),
+            recorder: None,
+            connection_id: 0,
+            handle,
+            updates,
+            outgoing: VecDeque::new(),
+            unsent: VecDeque::new(),
+            subscribe_limiter: Arc::new(subscribe_limiter(T::EXCHANGE)),
+            outgoing_delay: None,
+            heartbeat: None,
+            idle_timeout: None,
+            idle: None,
+            shutdown: ShutdownHandle::new(),
+            shutdown_signal: None,
+            close_deadline: None
+        }
+    }
+
+    /// stops the stream when `shutdown` is called on the handle (or any of
+    /// its clones)
+    pub fn with_shutdown_handle(mut self, shutdown: ShutdownHandle) -> Self {
+        self.shutdown = shutdown;
+        self.shutdown_signal = None;
+        self
+    }
+
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        self.shutdown.clone()
+    }
+
+    /// the handle to add or remove pairs of the running stream
+    pub fn subscription_handle(&self) -> WsSubscriptionHandle {
+        self.handle.clone()
+    }
+
+    /// records every raw text & binary frame received, except the heartbeat
+    /// replies
+    pub fn with_recorder(mut self, recorder: FrameRecorder) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    pub fn with_reconnect_policy(mut self, reconnect_policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = reconnect_policy;
+        self
+    }
+
+    /// drops the connection & reconnects if no frame (including pings) is
+    /// received for `timeout`, for sockets that stay open but go silent
+    pub fn with_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_timeout = Some(timeout);
+        self
     }
 
     pub async fn connect(&mut self) -> Result<(), WsError> {
-        let ws = self.exchange.make_ws_connection().await;
+        rate_limiter(T::EXCHANGE, RateLimitKind::Connection)
+            .acquire()
+            .await;
+        let limiter = Arc::new(subscribe_limiter(T::EXCHANGE));
+        let ws = self.exchange.make_ws_connection(&limiter).await;
         if let Err(e) = ws {
             error!(target: "cex-exchanges::live-stream", "error connecting to the {} websocket stream: {:?}", T::EXCHANGE, e);
             return Err(e)
         }
 
-        self.stream = Some(Box::pin(ws?));
+        self.subscribe_limiter = limiter;
+        self.set_stream(ws?);
         Ok(())
     }
 
-    fn handle_incoming(message: Message) -> Result<MessageOrPing<T>, (WsError, String)> {
+    fn set_stream(&mut self, stream: WebSocketStream<MaybeTlsStream<TcpStream>>) {
+        if let Some(recorder) = self.recorder.as_ref() {
+            self.connection_id = recorder.next_connection_id();
+        }
+
+        self.stream = Some(Box::pin(stream));
+
+        self.heartbeat = self.exchange.heartbeat().map(|(period, frame)| {
+            let mut heartbeat = tokio::time::interval_at(Instant::now() + period, period);
+            heartbeat.set_missed_tick_behavior(MissedTickBehavior::Delay);
+            (heartbeat, frame)
+        });
+        self.idle = self
+            .idle_timeout
+            .map(|timeout| Box::pin(tokio::time::sleep(timeout)));
+
+        // the new connection was made with the updated subscription
+        self.outgoing.clear();
+        self.outgoing_delay = None;
+        self.unsent.clear();
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn handle_incoming(message: Message, received_at: DateTime<Utc>) -> Result<MessageOrPing<T>, (WsError, String)> {
         match message {
             Message::Text(msg) => {
                 trace!(target: "cex-exchanges::live-stream", "recieved new message for the {} stream: {}",T::EXCHANGE, msg);
 
-                let mut des_msg = serde_json::from_str::<T::WsMessage>(&msg).map_err(|e| (e.into(), msg.clone()))?;
-                des_msg.make_critical(msg);
-                Ok(MessageOrPing::new_message(des_msg))
+                Ok(MessageOrPing::new_message(with_received_at(received_at, || deserialize_ws_frame::<T>(msg))?))
             }
             Message::Ping(_) => Ok(MessageOrPing::new_ping()),
-            Message::Binary(_) => panic!("Exchange: {} - Message::Binary", T::EXCHANGE),
-            Message::Pong(_) => panic!("Exchange: {} - Message::Pong", T::EXCHANGE),
+            Message::Binary(frame) => {
+                trace!(target: "cex-exchanges::live-stream", "recieved new binary message for the {} stream: {} bytes",T::EXCHANGE, frame.len());
+
+                Ok(MessageOrPing::new_message(with_received_at(received_at, || decode_ws_binary_frame::<T>(frame))?))
+            }
+            Message::Pong(_) => Ok(MessageOrPing::new_pong()),
             Message::Close(_) => Ok(MessageOrPing::new_close()),
             Message::Frame(_) => panic!("Exchange: {} - Message::Frame", T::EXCHANGE)
         }
     }
 
-    fn flush_sink_queue(stream: &mut StreamConn, cx: &mut Context<'_>) -> Result<(), WsError> {
-        loop {
-            match stream.poll_ready_unpin(cx) {
-                Poll::Ready(Ok(_)) => return Ok(()),
-                Poll::Ready(Err(e)) => return Err(WsError::StreamTxError(e)),
-                Poll::Pending => ()
+    /// ready once the socket can take a frame, pending (waking the task when
+    /// it can) while it's still sending the previous ones
+    fn flush_sink_queue(stream: &mut StreamConn, cx: &mut Context<'_>) -> Poll<Result<(), WsError>> {
+        stream.poll_ready_unpin(cx).map_err(WsError::StreamTxError)
+    }
+
+    /// sends the frame on a socket made ready by [Self::flush_sink_queue]
+    #[allow(clippy::result_large_err)]
+    fn send_frame(stream: &mut StreamConn, frame: Message, cx: &mut Context<'_>) -> Result<(), WsError> {
+        stream
+            .start_send_unpin(frame)
+            .map_err(WsError::StreamTxError)?;
+
+        match stream.poll_flush_unpin(cx) {
+            Poll::Ready(Err(e)) => Err(WsError::StreamTxError(e)),
+            _ => Ok(())
+        }
+    }
+
+    /// sends the exchange's heartbeat when it's due & drops the connection if
+    /// it's been silent for longer than the idle timeout
+    fn handle_timers(&mut self, cx: &mut Context<'_>) -> Option<Poll<Option<CombinedWsMessage>>> {
+        self.stream.as_ref()?;
+
+        if let Some((heartbeat, frame)) = self.heartbeat.as_mut() {
+            while heartbeat.poll_tick(cx).is_ready() {
+                trace!(target: "cex-exchanges::live-stream", "sending a heartbeat on the {} stream", T::EXCHANGE);
+                self.unsent.push_back(frame.clone());
             }
         }
+
+        if let (Some(idle), Some(timeout)) = (self.idle.as_mut(), self.idle_timeout) {
+            if idle.as_mut().poll(cx).is_ready() {
+                warn!(target: "cex-exchanges::live-stream", "the {} stream received nothing for {:?}, reconnecting", T::EXCHANGE, timeout);
+                self.stream = None;
+                self.idle = None;
+                cx.waker().wake_by_ref();
+                return Some(self.handle_retry(WsError::IdleTimeout(timeout).normalized_with_exchange(T::EXCHANGE, None)))
+            }
+        }
+
+        None
+    }
+
+    /// unsubscribes & sends a close frame once shutdown is requested, ending
+    /// the stream when the socket is closed
+    fn handle_shutdown(&mut self, cx: &mut Context<'_>) -> Option<Poll<Option<CombinedWsMessage>>> {
+        if self.close_deadline.is_none() {
+            let signal = self
+                .shutdown_signal
+                .get_or_insert_with(|| self.shutdown.signal());
+            if signal.as_mut().poll(cx).is_pending() {
+                return None
+            }
+
+            debug!(target: "cex-exchanges::live-stream", "shutting down the {} stream", T::EXCHANGE);
+            self.close_deadline = Some(Box::pin(tokio::time::sleep(CLOSE_TIMEOUT)));
+            self.reconnect_fut = None;
+            self.heartbeat = None;
+            self.idle = None;
+            self.outgoing.clear();
+            self.outgoing_delay = None;
+
+            if self.stream.is_some() {
+                self.unsent.extend(
+                    self.exchange
+                        .unsubscribe_all()
+                        .into_iter()
+                        .chain([Message::Close(None)])
+                );
+            }
+        }
+
+        let timed_out = self
+            .close_deadline
+            .as_mut()
+            .is_some_and(|deadline| deadline.as_mut().poll(cx).is_ready());
+        if self.stream.is_none() || timed_out {
+            self.stream = None;
+            return Some(Poll::Ready(None))
+        }
+
+        None
     }
 
     fn handle_retry(&mut self, msg: CombinedWsMessage) -> Poll<Option<CombinedWsMessage>> {
-        let is_bad_pair = match self.handle_bad_pair(&msg) {
-            Some(true) => return Poll::Ready(None),
-            Some(false) => false,
-            None => true
+        if self.handle_bad_pair(&msg) == Some(true) {
+            return Poll::Ready(None)
+        }
+
+        Poll::Ready(Some(msg))
+    }
+
+    /// the next connection, made after waiting out the policy's delay
+    fn reconnect(&mut self) -> ConnectFuture
+    where
+        T: 'static
+    {
+        let delay = self.reconnect_policy.delay(self.reconnect_attempts);
+        self.reconnect_attempts += 1;
+        if let Some(metrics) = metrics_recorder() {
+            metrics.reconnect_attempt(T::EXCHANGE, self.reconnect_attempts);
+        }
+        if !delay.is_zero() {
+            warn!(target: "cex-exchanges::live-stream", "reconnecting the {} stream in {:?} (attempt {})", T::EXCHANGE, delay, self.reconnect_attempts);
+        }
+
+        let exchange = self.exchange.clone();
+        let limiter = Arc::new(subscribe_limiter(T::EXCHANGE));
+        self.subscribe_limiter = limiter.clone();
+        Box::pin(async move {
+            tokio::time::sleep(delay).await;
+            rate_limiter(T::EXCHANGE, RateLimitKind::Connection)
+                .acquire()
+                .await;
+            exchange.make_owned_ws_connection(limiter).await
+        })
+    }
+
+    /// checks the sequence of the message, queueing it (or the buffered
+    /// updates it releases) with any gap events & starting a snapshot resync
+    /// for depth channels
+    fn handle_sequence(&mut self, msg: T::WsMessage, received_at: DateTime<Utc>)
+    where
+        T: 'static
+    {
+        let Some(sequence) = msg.sequence() else {
+            self.queue_message(msg, received_at);
+            return
         };
-        if let Some(retries) = self.max_retries {
-            if !is_bad_pair {
-                self.retry_count += 1;
+
+        let mut sequenced = Vec::new();
+        self.sequences
+            .process(sequence, (msg, received_at), &mut sequenced);
+        self.handle_sequenced(sequenced);
+    }
+
+    fn handle_sequenced(&mut self, sequenced: Vec<Sequenced<Received<T::WsMessage>>>)
+    where
+        T: 'static
+    {
+        for event in sequenced {
+            match event {
+                Sequenced::Message((msg, received_at)) => self.queue_message(msg, received_at),
+                Sequenced::Gap { channel, pair, expected, received } => {
+                    warn!(target: "cex-exchanges::live-stream", "sequence gap in the {} {} channel for {:?} - expected: {expected}, received: {received}", T::EXCHANGE, channel, pair);
+                    self.pending.push_back(CombinedWsMessage::SequenceGap {
+                        exchange: T::EXCHANGE,
+                        pair,
+                        channel: channel.to_string(),
+                        expected,
+                        received
+                    });
+                }
+                Sequenced::Resync { channel, pair } => {
+                    if let Some(frames) = self.exchange.resubscribe_depth(channel, &pair) {
+                        debug!(target: "cex-exchanges::live-stream", "resubscribing to the {} {} channel for {:?}", T::EXCHANGE, channel, pair);
+                        self.outgoing.extend(frames);
+                        continue
+                    }
+
+                    let exchange = self.exchange.clone();
+                    self.resyncs.push(Box::pin(async move {
+                        let snapshot = exchange.order_book_snapshot(pair.clone()).await;
+                        (channel, pair, snapshot.map(|snapshot| snapshot.map(|snapshot| (snapshot, Utc::now()))))
+                    }));
+                }
             }
+        }
+    }
 
-            if self.retry_count > retries {
-                return Poll::Ready(None)
+    fn queue_message(&mut self, msg: T::WsMessage, received_at: DateTime<Utc>) {
+        let msg: CombinedWsMessage = msg.into();
+        if let Some(metrics) = metrics_recorder() {
+            record_message(metrics.as_ref(), T::EXCHANGE, msg.clone(), received_at);
+        }
+        self.pending.push_back(msg);
+    }
+
+    /// applies the update to the exchange's subscription & queues its frames
+    /// for the live socket, a pending reconnect is restarted to use the new
+    /// subscription
+    ///
+    /// the result is sent back to the handle, a rejected update leaves the
+    /// stream unchanged
+    fn handle_subscription_update(
+        &mut self,
+        (update, reply): WsSubscriptionRequest,
+        cx: &mut Context<'_>
+    ) -> Option<Poll<Option<CombinedWsMessage>>> {
+        let unsubscribed = (!update.is_subscribe()).then(|| update.channel().pairs().to_vec());
+        let frames = match self.exchange.update_subscription(update) {
+            Ok(frames) => frames,
+            Err(e) => {
+                debug!(target: "cex-exchanges::live-stream", "the {} stream rejected a subscription update: {:?}", T::EXCHANGE, e);
+                let _ = reply.send(Err(e));
+                return None
             }
+        };
+        let _ = reply.send(Ok(()));
+
+        // the pairs' sequences start over if they're subscribed again
+        unsubscribed
+            .into_iter()
+            .flatten()
+            .for_each(|pair| self.sequences.reset_pair(&pair));
+
+        if self.stream.is_none() {
+            self.reconnect_fut = None;
+            return None
         }
 
-        Poll::Ready(Some(msg))
+        self.outgoing.extend(frames);
+        self.handle_outgoing(cx)
+    }
+
+    /// sends the queued subscription frames as fast as the exchange's
+    /// subscribe limit allows
+    fn handle_outgoing(&mut self, cx: &mut Context<'_>) -> Option<Poll<Option<CombinedWsMessage>>> {
+        let stream = self.stream.as_mut()?;
+
+        if let Some(delay) = self.outgoing_delay.as_mut() {
+            if delay.as_mut().poll(cx).is_pending() {
+                return None
+            }
+            self.outgoing_delay = None;
+        }
+
+        while !self.outgoing.is_empty() {
+            // the frame stays queued (without taking a token) until the
+            // socket can take it
+            match Self::flush_sink_queue(stream, cx) {
+                Poll::Ready(Ok(())) => (),
+                Poll::Ready(Err(e)) => {
+                    self.stream = None;
+                    return Some(self.handle_retry(e.normalized_with_exchange(T::EXCHANGE, None)))
+                }
+                Poll::Pending => return None
+            }
+
+            if let Err(wait) = self.subscribe_limiter.try_acquire() {
+                let mut delay = Box::pin(tokio::time::sleep(wait));
+                let _ = delay.as_mut().poll(cx);
+                self.outgoing_delay = Some(delay);
+                return None
+            }
+
+            let frame = self.outgoing.pop_front().unwrap();
+            if let Err(e) = Self::send_frame(stream, frame, cx) {
+                self.stream = None;
+                return Some(self.handle_retry(e.normalized_with_exchange(T::EXCHANGE, None)))
+            }
+        }
+
+        None
+    }
+
+    /// sends the queued heartbeats, pongs & closing frames while the socket
+    /// can take them, the rest stay queued until it wakes the task
+    fn handle_unsent(&mut self, cx: &mut Context<'_>) -> Option<Poll<Option<CombinedWsMessage>>> {
+        let stream = self.stream.as_mut()?;
+
+        while !self.unsent.is_empty() {
+            let sent = match Self::flush_sink_queue(stream, cx) {
+                Poll::Ready(Ok(())) => Self::send_frame(stream, self.unsent.pop_front().unwrap(), cx),
+                Poll::Ready(Err(e)) => Err(e),
+                Poll::Pending => return None
+            };
+
+            if let Err(e) = sent {
+                self.stream = None;
+                if self.close_deadline.is_some() {
+                    debug!(target: "cex-exchanges::live-stream", "error closing the {} stream: {:?}", T::EXCHANGE, e);
+                    return Some(Poll::Ready(None))
+                }
+                return Some(self.handle_retry(e.normalized_with_exchange(T::EXCHANGE, None)))
+            }
+        }
+
+        None
     }
 
     /// Some(true) => subscription is empty
@@ -97,13 +494,44 @@ where
     /// None => no bad pair found
     fn handle_bad_pair(&mut self, msg: &CombinedWsMessage) -> Option<bool> {
         if let Some(p) = msg.bad_pair() {
-            return Some(self.exchange.remove_bad_pair(p.clone()));
+            if let Some(metrics) = metrics_recorder() {
+                metrics.bad_pair_removed(T::EXCHANGE, &p);
+            }
+            return Some(self.exchange.remove_bad_pair(p));
         }
 
         None
     }
 }
 
+/// deserializes a raw text frame of the exchange, erroring with the frame
+#[allow(clippy::result_large_err)]
+pub(crate) fn deserialize_ws_frame<T: Exchange>(frame: String) -> Result<T::WsMessage, (WsError, String)> {
+    let mut des_msg = serde_json::from_str::<T::WsMessage>(&frame).map_err(|e| (e.into(), frame.clone()))?;
+    des_msg.make_critical(frame);
+    Ok(des_msg)
+}
+
+/// decodes a raw binary frame of the exchange, erroring with the hex encoded
+/// frame
+#[allow(clippy::result_large_err)]
+pub(crate) fn decode_ws_binary_frame<T: Exchange>(frame: Vec<u8>) -> Result<T::WsMessage, (WsError, String)> {
+    <T::WsMessage as CriticalWsMessage>::decode_binary(&frame).map_err(|e| (e, hex::encode(&frame)))
+}
+
+/// deserializes a text or binary frame of the exchange
+#[allow(clippy::result_large_err)]
+pub(crate) fn deserialize_ws_message<T: Exchange>(frame: Message) -> Result<T::WsMessage, (WsError, String)> {
+    match frame {
+        Message::Binary(frame) => decode_ws_binary_frame::<T>(frame),
+        frame => deserialize_ws_frame::<T>(
+            frame
+                .into_text()
+                .map_err(|e| (WsError::StreamRxError(e), String::new()))?
+        )
+    }
+}
+
 impl<T> Stream for WsStream<T>
 where
     T: Exchange + Debug + Send + Unpin + 'static,
@@ -114,33 +542,126 @@ where
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         let this = self.get_mut();
 
+        if let Some(msg) = this.pending.pop_front() {
+            return this.handle_retry(msg)
+        }
+
+        if let Some(res) = this.handle_shutdown(cx) {
+            return res
+        }
+
+        while let Poll::Ready(Some(request)) = this.updates.poll_recv(cx) {
+            if let Some(res) = this.handle_subscription_update(request, cx) {
+                return res
+            }
+        }
+
+        if let Some(res) = this.handle_outgoing(cx) {
+            return res
+        }
+
+        if let Poll::Ready(Some((channel, pair, snapshot))) = this.resyncs.poll_next_unpin(cx) {
+            match snapshot {
+                Ok(Some((snapshot, fetched_at))) => this.handle_sequence(snapshot, fetched_at),
+                Ok(None) => {
+                    let mut sequenced = Vec::new();
+                    this.sequences.untrack(channel, &pair, &mut sequenced);
+                    this.handle_sequenced(sequenced);
+                }
+                Err(e) => {
+                    error!(target: "cex-exchanges::live-stream", "error fetching the {} order book snapshot for {:?}: {:?}", T::EXCHANGE, pair, e);
+                    this.sequences.reset(channel, &pair);
+                }
+            }
+
+            if let Some(msg) = this.pending.pop_front() {
+                return this.handle_retry(msg)
+            }
+        }
+
+        if let Some(res) = this.handle_timers(cx) {
+            return res
+        }
+
+        if let Some(res) = this.handle_unsent(cx) {
+            return res
+        }
+
         if let Some(stream) = this.stream.as_mut() {
             if let Poll::Ready(val) = stream.poll_next_unpin(cx) {
+                // taken before parsing, so it's only the time the frame was received
+                let received_at = Utc::now();
+                if let (Some(idle), Some(timeout), Some(Ok(_))) = (this.idle.as_mut(), this.idle_timeout, val.as_ref()) {
+                    idle.as_mut().reset(Instant::now() + timeout);
+                }
+
+                if let Some(Ok(Message::Text(frame))) = val.as_ref() {
+                    if this.exchange.is_heartbeat_reply(frame) {
+                        trace!(target: "cex-exchanges::live-stream", "recieved a heartbeat reply on the {} stream", T::EXCHANGE);
+                        cx.waker().wake_by_ref();
+                        return Poll::Pending
+                    }
+                }
+
+                // the heartbeat replies aren't recorded, they'd replay as
+                // unparsed frames
+                if let (Some(recorder), Some(Ok(frame))) = (this.recorder.as_ref(), val.as_ref()) {
+                    if let Some(frame) = RecordedFrameData::from_message(frame) {
+                        recorder.record(T::EXCHANGE, this.connection_id, frame, received_at);
+                    }
+                }
+
                 match val {
-                    Some(Ok(msg)) => match Self::handle_incoming(msg) {
-                        Ok(MessageOrPing::Message(d)) => return this.handle_retry(d.into()),
-                        Ok(MessageOrPing::Ping) => {
-                            if let Err(e) = Self::flush_sink_queue(stream, cx) {
-                                this.stream = None;
-                                return this.handle_retry(e.normalized_with_exchange(T::EXCHANGE, None))
-                            } else if let Err(e) = stream.start_send_unpin(Message::Pong(vec![])) {
+                    Some(Ok(msg)) => match Self::handle_incoming(msg, received_at) {
+                        Ok(MessageOrPing::Message(d)) => {
+                            this.reconnect_attempts = 0;
+                            if d.reconnect_requested() {
+                                warn!(target: "cex-exchanges::live-stream", "the {} stream requested a reconnect", T::EXCHANGE);
                                 this.stream = None;
-                                return this.handle_retry(WsError::StreamTxError(e).normalized_with_exchange(T::EXCHANGE, None))
+                                cx.waker().wake_by_ref();
+                                return Poll::Ready(Some(d.into()))
+                            }
+
+                            this.handle_sequence(d, received_at);
+                            if let Some(msg) = this.pending.pop_front() {
+                                return this.handle_retry(msg)
                             }
 
+                            // buffered behind a resync
+                            cx.waker().wake_by_ref();
+                            return Poll::Pending
+                        }
+                        Ok(MessageOrPing::Ping) => {
+                            this.unsent.push_back(Message::Pong(vec![]));
+                            cx.waker().wake_by_ref();
+
+                            return Poll::Pending;
+                        }
+                        Ok(MessageOrPing::Pong) => {
+                            trace!(target: "cex-exchanges::live-stream", "recieved a pong on the {} stream", T::EXCHANGE);
+                            cx.waker().wake_by_ref();
+
                             return Poll::Pending;
                         }
                         Ok(MessageOrPing::Close) => {
                             this.stream = None;
+                            cx.waker().wake_by_ref();
 
                             return Poll::Pending;
                         }
                         Err((e, raw_msg)) => {
                             this.stream = None;
+                            if let Some(metrics) = metrics_recorder() {
+                                metrics.deserialization_failed(T::EXCHANGE);
+                            }
 
                             return this.handle_retry(e.normalized_with_exchange(T::EXCHANGE, Some(raw_msg)));
                         }
                     },
+                    Some(Err(_)) | None if this.close_deadline.is_some() => {
+                        this.stream = None;
+                        return Poll::Ready(None)
+                    }
                     Some(Err(e)) => {
                         this.stream = None;
                         return this.handle_retry(WsError::StreamRxError(e).normalized_with_exchange(T::EXCHANGE, None))
@@ -154,19 +675,27 @@ where
         } else if let Some(reconnect) = this.reconnect_fut.as_mut() {
             match reconnect.poll_unpin(cx) {
                 Poll::Ready(Ok(new_stream)) => {
-                    this.stream = Some(Box::pin(new_stream));
+                    if let Some(metrics) = metrics_recorder() {
+                        metrics.reconnected(T::EXCHANGE);
+                    }
+                    this.set_stream(new_stream);
                     this.reconnect_fut = None;
                     cx.waker().wake_by_ref();
                     return Poll::Pending;
                 }
                 Poll::Ready(Err(e)) => {
-                    this.reconnect_fut = Some(Box::pin(this.exchange.clone().make_owned_ws_connection()));
+                    this.reconnect_fut = None;
                     return this.handle_retry(e.normalized_with_exchange(T::EXCHANGE, None))
                 }
                 Poll::Pending => ()
             }
         } else {
-            this.reconnect_fut = Some(Box::pin(this.exchange.clone().make_owned_ws_connection()));
+            if !this.reconnect_policy.can_reconnect(this.reconnect_attempts) {
+                error!(target: "cex-exchanges::live-stream", "the {} stream failed to reconnect {} times, ending it", T::EXCHANGE, this.reconnect_attempts);
+                return Poll::Ready(None)
+            }
+
+            this.reconnect_fut = Some(this.reconnect());
 
             cx.waker().wake_by_ref();
             return Poll::Pending;
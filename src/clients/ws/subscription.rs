@@ -0,0 +1,171 @@
+use std::{
+    future::Future,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc
+    }
+};
+
+use tokio::sync::{
+    mpsc::{UnboundedReceiver, UnboundedSender},
+    oneshot
+};
+
+use super::WsError;
+use crate::{
+    normalized::{
+        types::RawTradingPair,
+        ws::{NormalizedWsChannelKinds, NormalizedWsChannels}
+    },
+    CexExchange
+};
+
+/// a change to the channels of a running stream
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum WsSubscriptionUpdate {
+    Subscribe(NormalizedWsChannels),
+    Unsubscribe(NormalizedWsChannels)
+}
+
+impl WsSubscriptionUpdate {
+    pub fn channel(&self) -> &NormalizedWsChannels {
+        match self {
+            WsSubscriptionUpdate::Subscribe(channel) => channel,
+            WsSubscriptionUpdate::Unsubscribe(channel) => channel
+        }
+    }
+
+    pub fn is_subscribe(&self) -> bool {
+        matches!(self, WsSubscriptionUpdate::Subscribe(_))
+    }
+
+    /// the exchange's channel of the update
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn exchange_channel<C>(&self) -> Result<C, WsError>
+    where
+        C: TryFrom<NormalizedWsChannels, Error = eyre::Report>
+    {
+        self.channel()
+            .clone()
+            .try_into()
+            .map_err(|e: eyre::Report| WsError::InvalidSubscriptionUpdate(e.to_string()))
+    }
+}
+
+/// an update sent to a stream, with the channel the stream replies on once
+/// it's applied (or rejected)
+pub(crate) type WsSubscriptionRequest = (WsSubscriptionUpdate, oneshot::Sender<Result<(), WsError>>);
+
+/// changes the channels of running stream(s), the update is sent on the live
+/// socket & kept in the stream's subscription so it survives reconnects
+///
+/// new pairs are spread across the streams of the exchange that can take the
+/// channel (ex: okx's trades are only on its business ws), removed pairs are
+/// unsubscribed from every stream that has them
+///
+/// the streams apply the updates when they're polled, so they must be polled
+/// while the update is awaited
+#[derive(Debug, Clone, Default)]
+pub struct WsSubscriptionHandle {
+    streams: Vec<(CexExchange, UnboundedSender<WsSubscriptionRequest>)>,
+    /// the next stream to add pairs to
+    next:    Arc<AtomicUsize>
+}
+
+#[allow(clippy::result_large_err)]
+impl WsSubscriptionHandle {
+    pub(crate) fn new(exchange: CexExchange) -> (Self, UnboundedReceiver<WsSubscriptionRequest>) {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        (Self { streams: vec![(exchange, tx)], next: Arc::new(AtomicUsize::new(0)) }, rx)
+    }
+
+    pub(crate) fn combine(mut self, other: Self) -> Self {
+        self.streams.extend(other.streams);
+        self
+    }
+
+    pub fn add_pairs(
+        &self,
+        exchange: CexExchange,
+        kind: NormalizedWsChannelKinds,
+        pairs: &[RawTradingPair]
+    ) -> impl Future<Output = Result<(), WsError>> + '_ {
+        self.subscribe(exchange, NormalizedWsChannels::new_with_pairs(exchange, kind, pairs))
+    }
+
+    pub fn remove_pairs(
+        &self,
+        exchange: CexExchange,
+        kind: NormalizedWsChannelKinds,
+        pairs: &[RawTradingPair]
+    ) -> impl Future<Output = Result<(), WsError>> + '_ {
+        self.unsubscribe(exchange, NormalizedWsChannels::new_with_pairs(exchange, kind, pairs))
+    }
+
+    /// adds the pairs of the channel, used over [Self::add_pairs] to set the
+    /// depth or candle interval
+    ///
+    /// it's sent to the next stream of the exchange, moving on to the
+    /// following ones while they reject it
+    pub async fn subscribe(&self, exchange: CexExchange, channel: NormalizedWsChannels) -> Result<(), WsError> {
+        Self::check_channel(&channel)?;
+
+        let streams = self.open_streams(exchange);
+        let start = self.next.fetch_add(1, Ordering::Relaxed);
+
+        let mut res = Err(WsError::StreamTerminated);
+        for i in 0..streams.len() {
+            let stream = streams[(start + i) % streams.len()];
+            res = Self::send(stream, WsSubscriptionUpdate::Subscribe(channel.clone())).await;
+            if res.is_ok() {
+                break
+            }
+        }
+
+        res
+    }
+
+    /// removes the pairs of the channel from every stream of the exchange,
+    /// it's only an error if no stream took the update
+    pub async fn unsubscribe(&self, exchange: CexExchange, channel: NormalizedWsChannels) -> Result<(), WsError> {
+        Self::check_channel(&channel)?;
+
+        let mut res = Err(WsError::StreamTerminated);
+        for stream in self.open_streams(exchange) {
+            let stream_res = Self::send(stream, WsSubscriptionUpdate::Unsubscribe(channel.clone())).await;
+            if res.is_err() {
+                res = stream_res;
+            }
+        }
+
+        res
+    }
+
+    /// waits for the stream to apply the update
+    async fn send(stream: &UnboundedSender<WsSubscriptionRequest>, update: WsSubscriptionUpdate) -> Result<(), WsError> {
+        let (tx, rx) = oneshot::channel();
+        stream
+            .send((update, tx))
+            .map_err(|_| WsError::StreamTerminated)?;
+
+        rx.await.map_err(|_| WsError::StreamTerminated)?
+    }
+
+    fn open_streams(&self, exchange: CexExchange) -> Vec<&UnboundedSender<WsSubscriptionRequest>> {
+        self.streams
+            .iter()
+            .filter(|(exch, tx)| *exch == exchange && !tx.is_closed())
+            .map(|(_, tx)| tx)
+            .collect()
+    }
+
+    /// the private account stream is authenticated when connecting, so it
+    /// can't be changed on a running stream
+    fn check_channel(channel: &NormalizedWsChannels) -> Result<(), WsError> {
+        if matches!(channel, NormalizedWsChannels::Account(_)) {
+            return Err(WsError::InvalidSubscriptionUpdate("the account channel can't be changed on a running stream".to_string()))
+        }
+
+        Ok(())
+    }
+}
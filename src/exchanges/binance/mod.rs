@@ -1,6 +1,6 @@
 mod pairs;
 
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 
 use futures::SinkExt;
 pub use pairs::*;
@@ -8,46 +8,110 @@ pub use pairs::*;
 pub mod rest_api;
 pub mod ws;
 
+use chrono::{DateTime, Duration, Utc};
 use reqwest::header;
 use serde::Deserialize;
 use tokio::net::TcpStream;
 use tokio_tungstenite::{tungstenite::Message, MaybeTlsStream, WebSocketStream};
-use tracing::{debug, error, info, trace, warn};
+use tracing::{debug, error, info};
 
 use self::{
-    rest_api::{BinanceAllInstruments, BinanceAllSymbols, BinanceRestApiResponse, BinanceSymbol},
-    ws::{BinanceSubscription, BinanceWsMessage}
+    rest_api::{
+        BinanceAggTrade, BinanceAllAggTrades, BinanceAllInstruments, BinanceAllKlines, BinanceAllSymbols, BinanceListenKey, BinanceOpenOrder,
+        BinanceOrderResponse, BinanceRestApiResponse, BinanceRestKline, BinanceSymbol
+    },
+    ws::{
+        channels::{BinanceDepthSnapshot, BinanceOrderBook, BinanceWsChannel},
+        BinanceSubscription, BinanceWsMessage
+    }
 };
 use crate::{
-    clients::{rest_api::RestApiError, ws::WsError},
-    exchanges::Exchange,
-    normalized::{rest_api::NormalizedRestApiRequest, types::NormalizedTradingPair},
+    clients::{
+        rate_limiter, require_credentials,
+        rest_api::{signed_response, RestApiError},
+        ws::{WsError, WsSubscriptionUpdate},
+        EndpointKind, ExchangeCredentials, ExchangeEndpoints, RateLimitKind, TokenBucket
+    },
+    exchanges::{
+        coin_listings::{get_listed_coins, BASE_LISTINGS_URL},
+        Exchange, ExchangeTrading
+    },
+    normalized::{
+        rest_api::NormalizedRestApiRequest,
+        types::{
+            NormalizedCandleInterval, NormalizedOrderAck, NormalizedOrderRequest, NormalizedOrderSide, NormalizedOrderType, NormalizedOrderUpdate,
+            NormalizedTimeInForce, NormalizedTradingPair
+        }
+    },
     CexExchange
 };
 
 const WSS_URL: &str = "wss://stream.binance.com:443/stream";
-const BASE_REST_API_URL: &str = "https://api.binance.com/api/v3";
-const ALL_SYMBOLS_URL: &str = "https://www.binance.com/bapi/composite/v1/public/promo/cmc/cryptocurrency/listings/latest";
+const BASE_REST_API_URL: &str = "https://api.binance.com";
+const MAX_KLINES_PER_REQUEST: usize = 1000;
+const MAX_AGG_TRADES_PER_REQUEST: usize = 1000;
+/// how long (millis) a signed request is valid for after its timestamp
+const RECV_WINDOW: u64 = 5000;
 
 #[derive(Debug, Default, Clone)]
 pub struct Binance {
-    subscription: BinanceSubscription
+    subscription: BinanceSubscription,
+    credentials:  Option<ExchangeCredentials>,
+    endpoints:    ExchangeEndpoints
 }
 
 impl Binance {
+    fn rest_url(&self) -> String {
+        self.endpoints.url(EndpointKind::Rest, BASE_REST_API_URL)
+    }
+
+    fn web_url(&self) -> String {
+        self.endpoints.url(EndpointKind::Web, BASE_LISTINGS_URL)
+    }
+
+    fn ws_url(&self) -> String {
+        self.endpoints.url(EndpointKind::Ws, WSS_URL)
+    }
+
     pub fn new_ws_subscription(subscription: BinanceSubscription) -> Self {
-        Self { subscription }
+        Self { subscription, credentials: None, endpoints: ExchangeEndpoints::default() }
+    }
+
+    /// sets the credentials used for the user data stream
+    pub fn with_credentials(mut self, credentials: ExchangeCredentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    /// creates (or gets the active) listen key of the user data stream.
+    ///
+    /// the key expires after 60 minutes without a keepalive, when the stream
+    /// gets the `listenKeyExpired` event it reconnects with a new key
+    pub async fn get_listen_key(&self, web_client: &reqwest::Client, credentials: &ExchangeCredentials) -> Result<String, RestApiError> {
+        rate_limiter(CexExchange::Binance, RateLimitKind::Rest)
+            .acquire()
+            .await;
+        let response: BinanceListenKey = web_client
+            .post(format!("{}/api/v3/userDataStream", self.rest_url()))
+            .header("X-MBX-APIKEY", &credentials.api_key)
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        Ok(response.listen_key)
     }
 
-    pub async fn get_all_instruments(web_client: &reqwest::Client) -> Result<BinanceAllInstruments, RestApiError> {
-        let instruments: BinanceAllInstruments = Self::simple_rest_api_request(web_client, format!("{BASE_REST_API_URL}/exchangeInfo"), None).await?;
+    pub async fn get_all_instruments(&self, web_client: &reqwest::Client) -> Result<BinanceAllInstruments, RestApiError> {
+        let instruments: BinanceAllInstruments =
+            Self::simple_rest_api_request(web_client, format!("{}/api/v3/exchangeInfo", self.rest_url()), None).await?;
         info!(target: "cex-exchanges::binance", "found {} instruments", instruments.instruments.len());
 
         Ok(instruments)
     }
 
-    pub async fn get_all_symbols(web_client: &reqwest::Client) -> Result<BinanceAllSymbols, RestApiError> {
-        let instruments: BinanceAllInstruments = Self::get_all_instruments(web_client).await?;
+    pub async fn get_all_symbols(&self, web_client: &reqwest::Client) -> Result<BinanceAllSymbols, RestApiError> {
+        let instruments: BinanceAllInstruments = self.get_all_instruments(web_client).await?;
         debug!(target: "cex-exchanges::binance", "got {} instruments to filter symbols", instruments.instruments.len());
 
         let pos_symbols = instruments
@@ -59,60 +123,144 @@ impl Binance {
 
         debug!(target: "cex-exchanges::binance", "got {} symbols from instruments", pos_symbols.len());
 
-        let mut query_start = 1;
-        let mut symbols = HashMap::new();
-        let mut err_count = 5;
-        loop {
-            debug!(target: "cex-exchanges::binance", "starting symbols iteration {query_start}");
-            let symbols_iteration = match Self::symbols_iteration(web_client, query_start).await {
-                Ok(vals) => {
-                    if vals.is_empty() {
-                        trace!(target: "cex-exchanges::binance", "no symbols found in valid call - breaking loop");
-                        break
-                    }
-                    vals
-                }
-                Err(e) => {
-                    if !e.is_gateway_timeout() {
-                        err_count -= 1;
-                        if err_count == 0 {
-                            return Err(e)
-                        }
-                    }
-
-                    warn!(target: "cex-exchanges::binance", "error getting symbols, {err_count} retries remaining - {:?}", e);
-                    tokio::time::sleep(std::time::Duration::from_secs(2)).await;
-                    continue;
-                }
-            };
-
-            symbols_iteration
-                .into_iter()
-                .filter(|sym| pos_symbols.contains(&sym.symbol))
-                .for_each(|sym| {
-                    symbols
-                        .entry(sym.symbol.clone())
-                        .and_modify(|curr_sym: &mut BinanceSymbol| {
-                            if sym.cmc_rank < curr_sym.cmc_rank {
-                                *curr_sym = sym.clone();
-                            }
-                        })
-                        .or_insert(sym.clone());
-                });
-
-            query_start += 5000;
+        let symbols: Vec<BinanceSymbol> = get_listed_coins(web_client, CexExchange::Binance, &self.web_url(), &pos_symbols).await?;
+        info!(target: "cex-exchanges::binance", "found {} valid symbols", symbols.len());
+
+        Ok(BinanceAllSymbols { symbols })
+    }
+
+    /// pages through `/klines` for all klines starting in `start..end`
+    pub async fn get_klines(
+        &self,
+        web_client: &reqwest::Client,
+        pair: BinanceTradingPair,
+        interval: NormalizedCandleInterval,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>
+    ) -> Result<BinanceAllKlines, RestApiError> {
+        let mut klines: Vec<BinanceRestKline> = Vec::new();
+        let mut page_start = start.timestamp_millis();
+        while page_start < end.timestamp_millis() {
+            let url = format!(
+                "{}/api/v3/klines?symbol={pair}&interval={interval}&startTime={page_start}&endTime={}&limit={MAX_KLINES_PER_REQUEST}",
+                self.rest_url(),
+                end.timestamp_millis() - 1
+            );
+            let page: Vec<BinanceRestKline> = Self::simple_rest_api_request(web_client, url, None).await?;
+
+            let Some(last) = page.last() else { break };
+            page_start = last.open_time as i64 + interval.duration().num_milliseconds();
+
+            let is_last_page = page.len() < MAX_KLINES_PER_REQUEST;
+            klines.extend(page);
+            if is_last_page {
+                break
+            }
         }
 
-        info!(target: "cex-exchanges::binance", "found {} valid symbols", symbols.values().len());
+        Ok(BinanceAllKlines { pair, interval, klines })
+    }
+
+    /// the latest `limit` aggregate trades (max 1000)
+    pub async fn get_recent_agg_trades(
+        &self,
+        web_client: &reqwest::Client,
+        pair: BinanceTradingPair,
+        limit: usize
+    ) -> Result<BinanceAllAggTrades, RestApiError> {
+        let url = format!("{}/api/v3/aggTrades?symbol={pair}&limit={}", self.rest_url(), limit.min(MAX_AGG_TRADES_PER_REQUEST));
+        let trades = Self::simple_rest_api_request(web_client, url, None).await?;
 
-        Ok(BinanceAllSymbols { symbols: symbols.values().cloned().collect::<Vec<_>>() })
+        Ok(BinanceAllAggTrades { pair, trades })
     }
 
-    async fn symbols_iteration(web_client: &reqwest::Client, query_start: u64) -> Result<Vec<BinanceSymbol>, RestApiError> {
-        let url = format!("{ALL_SYMBOLS_URL}?limit=5000&start={query_start}");
-        let iter_symbols: BinanceAllSymbols =
-            Self::simple_rest_api_request(web_client, url, Some((header::CONTENT_ENCODING, "gzip, deflate, br".parse().unwrap()))).await?;
-        Ok(iter_symbols.symbols)
+    /// pages through `/aggTrades` for all aggregate trades in `from..to`.
+    ///
+    /// the time range of a request can't be over an hour, so the first trade
+    /// is found by walking through the range hour by hour, after which the
+    /// pages continue by trade id
+    pub async fn get_historical_agg_trades(
+        &self,
+        web_client: &reqwest::Client,
+        pair: BinanceTradingPair,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>
+    ) -> Result<BinanceAllAggTrades, RestApiError> {
+        let mut trades: Vec<BinanceAggTrade> = Vec::new();
+
+        let mut window_start = from;
+        while trades.is_empty() && window_start < to {
+            let window_end = (window_start + Duration::hours(1)).min(to);
+            let url = format!(
+                "{}/api/v3/aggTrades?symbol={pair}&startTime={}&endTime={}&limit={MAX_AGG_TRADES_PER_REQUEST}",
+                self.rest_url(),
+                window_start.timestamp_millis(),
+                window_end.timestamp_millis() - 1
+            );
+            trades = Self::simple_rest_api_request(web_client, url, None).await?;
+            window_start = window_end;
+        }
+
+        while let Some(last) = trades.last() {
+            if last.trade_time as i64 >= to.timestamp_millis() {
+                break
+            }
+
+            let url =
+                format!("{}/api/v3/aggTrades?symbol={pair}&fromId={}&limit={MAX_AGG_TRADES_PER_REQUEST}", self.rest_url(), last.agg_trade_id + 1);
+            let page: Vec<BinanceAggTrade> = Self::simple_rest_api_request(web_client, url, None).await?;
+
+            let is_last_page = page.len() < MAX_AGG_TRADES_PER_REQUEST;
+            trades.extend(page);
+            if is_last_page {
+                break
+            }
+        }
+
+        trades.retain(|t| (from.timestamp_millis()..to.timestamp_millis()).contains(&(t.trade_time as i64)));
+
+        Ok(BinanceAllAggTrades { pair, trades })
+    }
+
+    pub async fn get_order_book(&self, web_client: &reqwest::Client, pair: &BinanceTradingPair) -> Result<BinanceOrderBook, RestApiError> {
+        let url = format!("{}/api/v3/depth?symbol={pair}&limit=1000", self.rest_url());
+        Self::simple_rest_api_request(web_client, url, None).await
+    }
+
+    /// sends a request signed with the `HMAC-SHA256` of its query string
+    pub async fn signed_rest_api_request<T>(
+        &self,
+        web_client: &reqwest::Client,
+        credentials: &ExchangeCredentials,
+        method: reqwest::Method,
+        endpoint: &str,
+        mut params: Vec<(&str, String)>
+    ) -> Result<T, RestApiError>
+    where
+        T: for<'de> Deserialize<'de>
+    {
+        rate_limiter(CexExchange::Binance, RateLimitKind::Rest)
+            .acquire()
+            .await;
+        params.push(("recvWindow", RECV_WINDOW.to_string()));
+        params.push(("timestamp", Utc::now().timestamp_millis().to_string()));
+
+        let query = params
+            .into_iter()
+            .map(|(k, v)| format!("{k}={v}"))
+            .collect::<Vec<_>>()
+            .join("&");
+        let signature = credentials
+            .sign_hex(&query)
+            .map_err(|e| RestApiError::AuthenticationError(e.to_string()))?;
+
+        let response = web_client
+            .request(method, format!("{}/api/v3{endpoint}?{query}&signature={signature}", self.rest_url()))
+            .header("X-MBX-APIKEY", &credentials.api_key)
+            .send()
+            .await?;
+
+        signed_response(response).await
     }
 
     pub async fn simple_rest_api_request<T>(
@@ -123,6 +271,9 @@ impl Binance {
     where
         T: for<'de> Deserialize<'de>
     {
+        rate_limiter(CexExchange::Binance, RateLimitKind::Rest)
+            .acquire()
+            .await;
         let mut builder = web_client
             .get(&url)
             .header("Content-Type", "application/json");
@@ -143,16 +294,45 @@ impl Exchange for Binance {
 
     const EXCHANGE: CexExchange = CexExchange::Binance;
 
+    fn with_endpoints(mut self, endpoints: ExchangeEndpoints) -> Self {
+        self.endpoints = endpoints;
+        self
+    }
+
     fn remove_bad_pair(&mut self, bad_pair: NormalizedTradingPair) -> bool {
         let pair = bad_pair.try_into().unwrap();
         self.subscription.remove_pair(&pair)
     }
 
-    async fn make_ws_connection(&self) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, WsError> {
-        let (mut ws, _) = tokio_tungstenite::connect_async(WSS_URL).await?;
+    async fn make_ws_connection(&self, subscribe_limiter: &TokenBucket) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, WsError> {
+        let listen_key = if self.subscription.has_user_data() {
+            let credentials = self
+                .credentials
+                .as_ref()
+                .ok_or(WsError::AuthenticationError("credentials are required for the user data stream".to_string()))?;
+
+            Some(
+                self.get_listen_key(&reqwest::Client::new(), credentials)
+                    .await
+                    .map_err(|e| WsError::AuthenticationError(e.to_string()))?
+            )
+        } else {
+            None
+        };
 
-        let sub_message = serde_json::to_string(&self.subscription)?;
-        ws.send(Message::Text(sub_message)).await?;
+        let (mut ws, _) = tokio_tungstenite::connect_async(self.ws_url()).await?;
+
+        if self.subscription.has_public_streams() {
+            let sub_message = serde_json::to_string(&self.subscription)?;
+            subscribe_limiter.acquire().await;
+            ws.send(Message::Text(sub_message)).await?;
+        }
+
+        if let Some(listen_key) = listen_key {
+            let sub_message = serde_json::json!({ "method": "SUBSCRIBE", "params": [listen_key], "id": 2 }).to_string();
+            subscribe_limiter.acquire().await;
+            ws.send(Message::Text(sub_message)).await?;
+        }
 
         Ok(ws)
     }
@@ -162,13 +342,39 @@ impl Exchange for Binance {
         web_client: &reqwest::Client,
         api_channel: NormalizedRestApiRequest
     ) -> Result<BinanceRestApiResponse, RestApiError> {
-        let api_response = match api_channel {
-            NormalizedRestApiRequest::AllCurrencies => Self::get_all_symbols(web_client)
+        let api_response = match api_channel.clone() {
+            NormalizedRestApiRequest::AllCurrencies => self
+                .get_all_symbols(web_client)
                 .await
-                .map(|v| BinanceRestApiResponse::Symbols(v)),
-            NormalizedRestApiRequest::AllInstruments => Self::get_all_instruments(web_client)
+                .map(BinanceRestApiResponse::Symbols),
+            NormalizedRestApiRequest::AllInstruments => self
+                .get_all_instruments(web_client)
                 .await
-                .map(|v| BinanceRestApiResponse::Instruments(v))
+                .map(BinanceRestApiResponse::Instruments),
+            NormalizedRestApiRequest::Candles { pair, interval, start, end } => {
+                let pair: BinanceTradingPair = pair
+                    .try_into()
+                    .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+                self.get_klines(web_client, pair, interval, start, end)
+                    .await
+                    .map(BinanceRestApiResponse::Klines)
+            }
+            NormalizedRestApiRequest::RecentTrades { pair, limit } => {
+                let pair: BinanceTradingPair = pair
+                    .try_into()
+                    .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+                self.get_recent_agg_trades(web_client, pair, limit)
+                    .await
+                    .map(BinanceRestApiResponse::AggTrades)
+            }
+            NormalizedRestApiRequest::HistoricalTrades { pair, from, to } => {
+                let pair: BinanceTradingPair = pair
+                    .try_into()
+                    .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+                self.get_historical_agg_trades(web_client, pair, from, to)
+                    .await
+                    .map(BinanceRestApiResponse::AggTrades)
+            }
         };
 
         if let Err(e) = api_response.as_ref() {
@@ -177,4 +383,158 @@ impl Exchange for Binance {
 
         api_response
     }
+
+    async fn order_book_snapshot(self, pair: NormalizedTradingPair) -> Result<Option<BinanceWsMessage>, RestApiError> {
+        let pair: BinanceTradingPair = pair
+            .try_into()
+            .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+
+        let book = self.get_order_book(&reqwest::Client::new(), &pair).await?;
+
+        Ok(Some(BinanceWsMessage::DepthSnapshot(BinanceDepthSnapshot { pair, local_update_time: Utc::now(), book })))
+    }
+
+    fn update_subscription(&mut self, update: WsSubscriptionUpdate) -> Result<Vec<Message>, WsError> {
+        let channel: BinanceWsChannel = update.exchange_channel()?;
+        let requests = if update.is_subscribe() { self.subscription.subscribe(channel) } else { self.subscription.unsubscribe(channel) };
+
+        let messages = requests
+            .into_iter()
+            .map(|request| serde_json::to_string(&request).map(Message::Text))
+            .collect::<Result<_, _>>()?;
+
+        Ok(messages)
+    }
+
+    fn unsubscribe_all(&self) -> Vec<Message> {
+        self.subscription
+            .unsubscribe_all()
+            .and_then(|request| serde_json::to_string(&request).ok())
+            .map(Message::Text)
+            .into_iter()
+            .collect()
+    }
+}
+
+impl ExchangeTrading for Binance {
+    async fn place_order(&self, web_client: &reqwest::Client, order: NormalizedOrderRequest) -> Result<NormalizedOrderAck, RestApiError> {
+        let credentials = require_credentials(&self.credentials)?;
+        let pair: BinanceTradingPair = order
+            .pair
+            .clone()
+            .try_into()
+            .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+        let price = order
+            .limit_price()
+            .map_err(|e| RestApiError::UnsupportedRequest(e.to_string()))?;
+
+        let order_type = match (order.order_type, order.limit_time_in_force()) {
+            (NormalizedOrderType::Market, _) => "MARKET",
+            (_, Some(NormalizedTimeInForce::PostOnly)) => "LIMIT_MAKER",
+            _ => "LIMIT"
+        };
+        let side = match order.side {
+            NormalizedOrderSide::Buy => "BUY",
+            NormalizedOrderSide::Sell => "SELL"
+        };
+
+        let mut params = vec![
+            ("symbol", pair.0),
+            ("side", side.to_string()),
+            ("type", order_type.to_string()),
+            ("quantity", order.amount.to_string()),
+            ("newOrderRespType", "RESULT".to_string()),
+        ];
+        if let Some(price) = price {
+            params.push(("price", price.to_string()));
+        }
+        match order.limit_time_in_force() {
+            Some(NormalizedTimeInForce::GoodTilCanceled) => params.push(("timeInForce", "GTC".to_string())),
+            Some(NormalizedTimeInForce::ImmediateOrCancel) => params.push(("timeInForce", "IOC".to_string())),
+            Some(NormalizedTimeInForce::FillOrKill) => params.push(("timeInForce", "FOK".to_string())),
+            Some(NormalizedTimeInForce::PostOnly) | None => ()
+        }
+        if let Some(client_order_id) = order.client_order_id {
+            params.push(("newClientOrderId", client_order_id));
+        }
+
+        let response: BinanceOrderResponse = self
+            .signed_rest_api_request(web_client, credentials, reqwest::Method::POST, "/order", params)
+            .await?;
+
+        Ok(response.normalize())
+    }
+
+    async fn cancel_order(
+        &self,
+        web_client: &reqwest::Client,
+        pair: NormalizedTradingPair,
+        order_id: String
+    ) -> Result<NormalizedOrderAck, RestApiError> {
+        let credentials = require_credentials(&self.credentials)?;
+        let pair: BinanceTradingPair = pair
+            .try_into()
+            .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+
+        let params = vec![("symbol", pair.0), ("orderId", order_id)];
+        let response: BinanceOrderResponse = self
+            .signed_rest_api_request(web_client, credentials, reqwest::Method::DELETE, "/order", params)
+            .await?;
+
+        Ok(response.normalize())
+    }
+
+    async fn cancel_all(&self, web_client: &reqwest::Client, pair: Option<NormalizedTradingPair>) -> Result<Vec<NormalizedOrderAck>, RestApiError> {
+        let credentials = require_credentials(&self.credentials)?;
+
+        // binance only cancels all orders of a single symbol
+        let pairs = match pair {
+            Some(pair) => vec![pair],
+            None => self
+                .get_open_orders(web_client, None)
+                .await?
+                .into_iter()
+                .map(|o| o.pair)
+                .collect::<HashSet<_>>()
+                .into_iter()
+                .collect()
+        };
+
+        let mut acks = Vec::new();
+        for pair in pairs {
+            let pair: BinanceTradingPair = pair
+                .try_into()
+                .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+
+            let params = vec![("symbol", pair.0)];
+            let response: Vec<BinanceOrderResponse> = self
+                .signed_rest_api_request(web_client, credentials, reqwest::Method::DELETE, "/openOrders", params)
+                .await?;
+            acks.extend(response.into_iter().map(|r| r.normalize()));
+        }
+
+        Ok(acks)
+    }
+
+    async fn get_open_orders(
+        &self,
+        web_client: &reqwest::Client,
+        pair: Option<NormalizedTradingPair>
+    ) -> Result<Vec<NormalizedOrderUpdate>, RestApiError> {
+        let credentials = require_credentials(&self.credentials)?;
+
+        let mut params = Vec::new();
+        if let Some(pair) = pair {
+            let pair: BinanceTradingPair = pair
+                .try_into()
+                .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+            params.push(("symbol", pair.0));
+        }
+
+        let response: Vec<BinanceOpenOrder> = self
+            .signed_rest_api_request(web_client, credentials, reqwest::Method::GET, "/openOrders", params)
+            .await?;
+
+        Ok(response.into_iter().map(|o| o.normalize()).collect())
+    }
 }
@@ -0,0 +1,77 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+use crate::{
+    binance::BinanceTradingPair,
+    clients::ws::received_at,
+    normalized::{
+        rest_api::NormalizedRestApiDataTypes,
+        types::{NormalizedNumber, NormalizedTrade, TradeSide}
+    },
+    CexExchange
+};
+
+/// aggregate trades of a single pair from `/aggTrades`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct BinanceAllAggTrades {
+    pub pair:   BinanceTradingPair,
+    pub trades: Vec<BinanceAggTrade>
+}
+
+impl BinanceAllAggTrades {
+    pub fn normalize(self) -> Vec<NormalizedTrade> {
+        self.trades
+            .into_iter()
+            .map(|t| t.normalize(&self.pair))
+            .collect()
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for BinanceAllAggTrades {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match other {
+            NormalizedRestApiDataTypes::Trades(other_trades) => self.clone().normalize() == *other_trades,
+            _ => false
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct BinanceAggTrade {
+    #[serde(rename = "a")]
+    pub agg_trade_id:          u64,
+    #[serde(rename = "p")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub price:                 NormalizedNumber,
+    #[serde(rename = "q")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub quantity:              NormalizedNumber,
+    #[serde(rename = "f")]
+    pub first_trade_id:        u64,
+    #[serde(rename = "l")]
+    pub last_trade_id:         u64,
+    #[serde(rename = "T")]
+    pub trade_time:            u64,
+    #[serde(rename = "m")]
+    pub is_buyer_market_maker: bool,
+    #[serde(default = "received_at")]
+    pub received_at:           DateTime<Utc>
+}
+
+impl BinanceAggTrade {
+    pub fn normalize(self, pair: &BinanceTradingPair) -> NormalizedTrade {
+        NormalizedTrade {
+            exchange:      CexExchange::Binance,
+            pair:          pair.normalize(),
+            exchange_time: DateTime::from_timestamp_millis(self.trade_time as i64),
+            received_at:   self.received_at,
+            // the buyer being the maker, the seller is the taker
+            side:          if self.is_buyer_market_maker { TradeSide::Sell } else { TradeSide::Buy },
+            price:         self.price,
+            amount:        self.quantity,
+            trade_id:      Some(self.agg_trade_id.to_string())
+        }
+    }
+}
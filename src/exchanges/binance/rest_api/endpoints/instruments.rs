@@ -130,7 +130,9 @@ impl BinanceInstrument {
                         base_asset_symbol:  self.base_asset.clone(),
                         quote_asset_symbol: self.quote_asset.clone(),
                         active:             (&self.status == "TRADING"),
-                        futures_expiry:     None
+                        futures_expiry:     None,
+                        option_strike:      None,
+                        option_type:        None
                     })
                 } else {
                     None
@@ -155,7 +157,9 @@ impl PartialEq<NormalizedInstrument> for BinanceInstrument {
             && other.base_asset_symbol == *self.base_asset
             && other.quote_asset_symbol == *self.quote_asset
             && other.active == (&self.status == "TRADING")
-            && other.futures_expiry.is_none();
+            && other.futures_expiry.is_none()
+            && other.option_strike.is_none()
+            && other.option_type.is_none();
 
         if !equals {
             warn!(target: "cex-exchanges::binance", "binance instrument: {:?}", self);
@@ -0,0 +1,85 @@
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+use crate::{
+    binance::BinanceTradingPair,
+    normalized::{
+        rest_api::NormalizedRestApiDataTypes,
+        types::{NormalizedCandle, NormalizedCandleInterval, NormalizedNumber}
+    },
+    CexExchange
+};
+
+/// klines of a single pair & interval from `/klines`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct BinanceAllKlines {
+    pub pair:     BinanceTradingPair,
+    pub interval: NormalizedCandleInterval,
+    pub klines:   Vec<BinanceRestKline>
+}
+
+impl BinanceAllKlines {
+    pub fn normalize(self) -> Vec<NormalizedCandle> {
+        self.klines
+            .into_iter()
+            .map(|k| k.normalize(&self.pair, self.interval))
+            .collect()
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for BinanceAllKlines {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match other {
+            NormalizedRestApiDataTypes::Candles(other_candles) => self.clone().normalize() == *other_candles,
+            _ => false
+        }
+    }
+}
+
+/// a single kline, sent as an array by the api
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct BinanceRestKline {
+    pub open_time:              u64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub open:                   NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub high:                   NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub low:                    NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub close:                  NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub volume:                 NormalizedNumber,
+    pub close_time:             u64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub quote_volume:           NormalizedNumber,
+    pub trade_count:            u64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub taker_buy_base_volume:  NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub taker_buy_quote_volume: NormalizedNumber,
+    pub unused:                 String
+}
+
+impl BinanceRestKline {
+    pub fn normalize(self, pair: &BinanceTradingPair, interval: NormalizedCandleInterval) -> NormalizedCandle {
+        let start_time = DateTime::from_timestamp_millis(self.open_time as i64).unwrap();
+
+        NormalizedCandle {
+            exchange: CexExchange::Binance,
+            pair: pair.normalize(),
+            interval,
+            start_time,
+            end_time: interval.end_time(start_time),
+            open: self.open,
+            high: self.high,
+            low: self.low,
+            close: self.close,
+            volume: self.volume,
+            trade_count: Some(self.trade_count),
+            closed: None
+        }
+    }
+}
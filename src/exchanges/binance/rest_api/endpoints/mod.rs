@@ -3,3 +3,15 @@ pub use symbols::*;
 
 mod instruments;
 pub use instruments::*;
+
+mod klines;
+pub use klines::*;
+
+mod agg_trades;
+pub use agg_trades::*;
+
+mod user_data_stream;
+pub use user_data_stream::*;
+
+mod orders;
+pub use orders::*;
@@ -0,0 +1,78 @@
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+use crate::{
+    binance::{ws::channels::binance_order_status, BinanceTradingPair},
+    normalized::types::{NormalizedNumber, NormalizedOrderAck, NormalizedOrderSide, NormalizedOrderUpdate},
+    CexExchange
+};
+
+/// response of `POST /api/v3/order`, `DELETE /api/v3/order` &
+/// `DELETE /api/v3/openOrders`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BinanceOrderResponse {
+    pub symbol:          BinanceTradingPair,
+    #[serde(rename = "orderId")]
+    pub order_id:        u64,
+    #[serde(rename = "clientOrderId")]
+    pub client_order_id: String,
+    pub status:          String
+}
+
+impl BinanceOrderResponse {
+    pub fn normalize(self) -> NormalizedOrderAck {
+        NormalizedOrderAck {
+            exchange:        CexExchange::Binance,
+            pair:            Some(self.symbol.normalize()),
+            order_id:        self.order_id.to_string(),
+            client_order_id: Some(self.client_order_id),
+            status:          Some(binance_order_status(&self.status))
+        }
+    }
+}
+
+/// order of `GET /api/v3/openOrders`
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BinanceOpenOrder {
+    pub symbol:          BinanceTradingPair,
+    #[serde(rename = "orderId")]
+    pub order_id:        u64,
+    #[serde(rename = "clientOrderId")]
+    pub client_order_id: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub price:           NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "origQty")]
+    pub quantity:        NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "executedQty")]
+    pub filled_quantity: NormalizedNumber,
+    pub status:          String,
+    #[serde(rename = "type")]
+    pub order_type:      String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub side:            NormalizedOrderSide,
+    #[serde(rename = "updateTime")]
+    pub update_time:     u64
+}
+
+impl BinanceOpenOrder {
+    pub fn normalize(self) -> NormalizedOrderUpdate {
+        NormalizedOrderUpdate {
+            exchange:        CexExchange::Binance,
+            pair:            self.symbol.normalize(),
+            order_id:        self.order_id.to_string(),
+            client_order_id: Some(self.client_order_id),
+            side:            self.side,
+            order_type:      self.order_type.to_lowercase(),
+            status:          binance_order_status(&self.status),
+            price:           (self.order_type != "MARKET").then_some(self.price),
+            amount:          Some(self.quantity),
+            filled_amount:   Some(self.filled_quantity),
+            time:            DateTime::from_timestamp_millis(self.update_time as i64).unwrap(),
+            last_fill:       None
+        }
+    }
+}
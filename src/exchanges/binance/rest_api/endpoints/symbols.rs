@@ -7,6 +7,7 @@ use serde_with::serde_as;
 use tracing::warn;
 
 use crate::{
+    exchanges::coin_listings::ListedCoin,
     normalized::{
         rest_api::NormalizedRestApiDataTypes,
         types::{BlockchainCurrency, NormalizedCurrency}
@@ -149,6 +150,16 @@ impl BinanceSymbol {
     }
 }
 
+impl ListedCoin for BinanceSymbol {
+    fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    fn rank(&self) -> u64 {
+        self.cmc_rank
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
 pub struct BinanceAllSymbolsQuote {
     #[serde(rename = "USD")]
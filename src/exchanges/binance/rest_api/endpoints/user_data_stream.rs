@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+/// response of `POST /api/v3/userDataStream`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BinanceListenKey {
+    #[serde(rename = "listenKey")]
+    pub listen_key: String
+}
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use super::{BinanceAllInstruments, BinanceAllSymbols, BinanceInstrument, BinanceSymbol};
+use super::{BinanceAllAggTrades, BinanceAllInstruments, BinanceAllKlines, BinanceAllSymbols, BinanceInstrument, BinanceSymbol};
 use crate::exchanges::normalized::rest_api::NormalizedRestApiDataTypes;
 
 #[serde_with::serde_as]
@@ -8,14 +8,18 @@ use crate::exchanges::normalized::rest_api::NormalizedRestApiDataTypes;
 #[serde(rename_all = "snake_case", tag = "type")]
 pub enum BinanceRestApiResponse {
     Symbols(BinanceAllSymbols),
-    Instruments(BinanceAllInstruments)
+    Instruments(BinanceAllInstruments),
+    Klines(BinanceAllKlines),
+    AggTrades(BinanceAllAggTrades)
 }
 
 impl BinanceRestApiResponse {
     pub fn normalize(self) -> NormalizedRestApiDataTypes {
         match self {
             BinanceRestApiResponse::Symbols(v) => NormalizedRestApiDataTypes::AllCurrencies(v.normalize()),
-            BinanceRestApiResponse::Instruments(v) => NormalizedRestApiDataTypes::AllInstruments(v.normalize())
+            BinanceRestApiResponse::Instruments(v) => NormalizedRestApiDataTypes::AllInstruments(v.normalize()),
+            BinanceRestApiResponse::Klines(v) => NormalizedRestApiDataTypes::Candles(v.normalize()),
+            BinanceRestApiResponse::AggTrades(v) => NormalizedRestApiDataTypes::Trades(v.normalize())
         }
     }
 
@@ -49,7 +53,9 @@ impl PartialEq<NormalizedRestApiDataTypes> for BinanceRestApiResponse {
     fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
         match self {
             BinanceRestApiResponse::Symbols(vals) => vals == other,
-            BinanceRestApiResponse::Instruments(vals) => vals == other
+            BinanceRestApiResponse::Instruments(vals) => vals == other,
+            BinanceRestApiResponse::Klines(vals) => vals == other,
+            BinanceRestApiResponse::AggTrades(vals) => vals == other
         }
     }
 }
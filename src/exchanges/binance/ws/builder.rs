@@ -4,7 +4,7 @@ use super::{
 };
 use crate::{
     binance::Binance,
-    clients::{rest_api::ExchangeApi, ws::MutliWsStreamBuilder},
+    clients::{rest_api::ExchangeApi, ws::MutliWsStreamBuilder, ExchangeCredentials},
     normalized::ws::NormalizedWsChannels
 };
 
@@ -17,7 +17,9 @@ const MAX_BINANCE_WS_CONNS_PER_STREAM: usize = 1024;
 
 #[derive(Debug, Clone, Default)]
 pub struct BinanceWsBuilder {
-    pub channels: Vec<BinanceWsChannel>
+    pub channels:    Vec<BinanceWsChannel>,
+    /// required for [BinanceWsChannel::UserData]
+    pub credentials: Option<ExchangeCredentials>
 }
 
 impl BinanceWsBuilder {
@@ -27,16 +29,30 @@ impl BinanceWsBuilder {
         self
     }
 
+    /// sets the credentials used for the user data stream
+    pub fn with_credentials(mut self, credentials: ExchangeCredentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    fn new_exchange(&self, subscription: BinanceSubscription) -> Binance {
+        let exchange = Binance::new_ws_subscription(subscription);
+        match self.credentials.clone() {
+            Some(credentials) => exchange.with_credentials(credentials),
+            None => exchange
+        }
+    }
+
     /// builds a single ws instance of [Binance], handling all channels on 1
     /// stream
     pub fn build_single(self) -> Binance {
         let mut subscription = BinanceSubscription::new();
 
         self.channels
-            .into_iter()
-            .for_each(|c| subscription.add_channel(c));
+            .iter()
+            .for_each(|c| subscription.add_channel(c.clone()));
 
-        Binance::new_ws_subscription(subscription)
+        self.new_exchange(subscription)
     }
 
     /// builds many ws instances of the [Binance] as the inner streams of
@@ -55,7 +71,7 @@ impl BinanceWsBuilder {
                 chk.iter()
                     .for_each(|ch| subscription.add_channel(ch.clone()));
 
-                Binance::new_ws_subscription(subscription)
+                self.new_exchange(subscription)
             })
             .collect();
 
@@ -78,7 +94,7 @@ impl BinanceWsBuilder {
                 chk.iter()
                     .for_each(|ch| subscription.add_channel(ch.clone()));
 
-                Binance::new_ws_subscription(subscription)
+                self.new_exchange(subscription)
             })
             .collect();
 
@@ -91,12 +107,12 @@ impl BinanceWsBuilder {
 
         let all_streams = this
             .channels
-            .into_iter()
+            .iter()
             .map(|ch| {
                 let mut subscription = BinanceSubscription::new();
-                subscription.add_channel(ch);
+                subscription.add_channel(ch.clone());
 
-                Binance::new_ws_subscription(subscription)
+                this.new_exchange(subscription)
             })
             .collect::<Vec<_>>();
 
@@ -123,21 +139,28 @@ impl BinanceWsBuilder {
         chunks.into_iter().for_each(|chk| {
             let all_channels = channels
                 .iter()
-                .map(|ch| match ch {
-                    BinanceWsChannelKind::Trade => BinanceWsChannel::Trade(chk.to_vec()),
-                    BinanceWsChannelKind::BookTicker => BinanceWsChannel::BookTicker(chk.to_vec())
+                .filter_map(|ch| match ch {
+                    BinanceWsChannelKind::Trade => Some(BinanceWsChannel::Trade(chk.to_vec())),
+                    BinanceWsChannelKind::BookTicker => Some(BinanceWsChannel::BookTicker(chk.to_vec())),
+                    BinanceWsChannelKind::Depth => Some(BinanceWsChannel::Depth(chk.to_vec())),
+                    BinanceWsChannelKind::Kline(interval) => Some(BinanceWsChannel::Kline { pairs: chk.to_vec(), interval: *interval }),
+                    BinanceWsChannelKind::UserData => None
                 })
                 .collect::<Vec<_>>();
 
             this.channels.extend(all_channels);
         });
 
+        if channels.contains(&BinanceWsChannelKind::UserData) {
+            this.channels.push(BinanceWsChannel::UserData);
+        }
+
         Ok(this)
     }
 
     /// makes the builder from the normalized builder's map
-    pub(crate) fn make_from_normalized_map(map: Vec<NormalizedWsChannels>) -> eyre::Result<Self> {
-        let mut this = Self { channels: Vec::new() };
+    pub(crate) fn make_from_normalized_map(map: Vec<NormalizedWsChannels>, credentials: Option<ExchangeCredentials>) -> eyre::Result<Self> {
+        let mut this = Self { channels: Vec::new(), credentials };
 
         map.into_iter().try_for_each(|channel| {
             let this_channel: BinanceWsChannel = channel.try_into()?;
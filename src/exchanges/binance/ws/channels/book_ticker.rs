@@ -3,7 +3,12 @@ use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 use tracing::warn;
 
-use crate::{binance::BinanceTradingPair, normalized::types::NormalizedQuote, CexExchange};
+use crate::{
+    binance::BinanceTradingPair,
+    clients::ws::received_at,
+    normalized::types::{NormalizedNumber, NormalizedQuote},
+    CexExchange
+};
 
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
@@ -12,33 +17,35 @@ pub struct BinanceBookTicker {
     pub pair:                BinanceTradingPair,
     #[serde(rename = "A")]
     #[serde_as(as = "DisplayFromStr")]
-    pub best_ask_amt:        f64,
+    pub best_ask_amt:        NormalizedNumber,
     #[serde(rename = "a")]
     #[serde_as(as = "DisplayFromStr")]
-    pub best_ask_price:      f64,
+    pub best_ask_price:      NormalizedNumber,
     #[serde(rename = "B")]
     #[serde_as(as = "DisplayFromStr")]
-    pub best_bid_amt:        f64,
+    pub best_bid_amt:        NormalizedNumber,
     #[serde(rename = "b")]
     #[serde_as(as = "DisplayFromStr")]
-    pub best_bid_price:      f64,
+    pub best_bid_price:      NormalizedNumber,
     #[serde(rename = "u")]
     pub orderbook_update_id: u64,
-    #[serde(default = "Utc::now")]
-    pub local_update_time:   DateTime<Utc>
+    #[serde(default = "received_at")]
+    pub received_at:         DateTime<Utc>
 }
 
 impl BinanceBookTicker {
     pub fn normalize(self) -> NormalizedQuote {
         NormalizedQuote {
-            exchange:   CexExchange::Binance,
-            pair:       self.pair.normalize(),
-            time:       self.local_update_time,
-            ask_amount: self.best_ask_amt,
-            ask_price:  self.best_ask_price,
-            bid_amount: self.best_bid_amt,
-            bid_price:  self.best_bid_price,
-            quote_id:   Some(self.orderbook_update_id.to_string())
+            exchange:      CexExchange::Binance,
+            pair:          self.pair.normalize(),
+            // the spot book ticker has no event time
+            exchange_time: None,
+            received_at:   self.received_at,
+            ask_amount:    self.best_ask_amt,
+            ask_price:     self.best_ask_price,
+            bid_amount:    self.best_bid_amt,
+            bid_price:     self.best_bid_price,
+            quote_id:      Some(self.orderbook_update_id.to_string())
         }
     }
 }
@@ -47,7 +54,8 @@ impl PartialEq<NormalizedQuote> for BinanceBookTicker {
     fn eq(&self, other: &NormalizedQuote) -> bool {
         let equals = other.exchange == CexExchange::Binance
             && other.pair == self.pair.normalize()
-            && other.time == self.local_update_time
+            && other.exchange_time.is_none()
+            && other.received_at == self.received_at
             && other.ask_amount == self.best_ask_amt
             && other.ask_price == self.best_ask_price
             && other.bid_amount == self.best_bid_amt
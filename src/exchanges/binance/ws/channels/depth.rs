@@ -0,0 +1,153 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use tracing::warn;
+
+use crate::{
+    binance::BinanceTradingPair,
+    normalized::types::{NormalizedBookLevel, NormalizedNumber, NormalizedOrderBookUpdate, NormalizedOrderBookUpdateKind},
+    CexExchange
+};
+
+/// diff. depth stream (`<symbol>@depth@100ms`)
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
+pub struct BinanceDepthUpdate {
+    #[serde(rename = "s")]
+    pub pair:            BinanceTradingPair,
+    #[serde(rename = "E")]
+    pub event_time:      u64,
+    #[serde(rename = "U")]
+    pub first_update_id: u64,
+    #[serde(rename = "u")]
+    pub last_update_id:  u64,
+    #[serde(rename = "b")]
+    #[serde_as(as = "Vec<(DisplayFromStr, DisplayFromStr)>")]
+    pub bids:            Vec<(NormalizedNumber, NormalizedNumber)>,
+    #[serde(rename = "a")]
+    #[serde_as(as = "Vec<(DisplayFromStr, DisplayFromStr)>")]
+    pub asks:            Vec<(NormalizedNumber, NormalizedNumber)>
+}
+
+impl BinanceDepthUpdate {
+    pub fn normalize(self) -> NormalizedOrderBookUpdate {
+        NormalizedOrderBookUpdate {
+            exchange:        CexExchange::Binance,
+            pair:            self.pair.normalize(),
+            time:            DateTime::from_timestamp_millis(self.event_time as i64).unwrap(),
+            kind:            NormalizedOrderBookUpdateKind::Delta,
+            bids:            self
+                .bids
+                .into_iter()
+                .map(|(p, a)| NormalizedBookLevel::new(p, a))
+                .collect(),
+            asks:            self
+                .asks
+                .into_iter()
+                .map(|(p, a)| NormalizedBookLevel::new(p, a))
+                .collect(),
+            first_update_id: Some(self.first_update_id),
+            last_update_id:  Some(self.last_update_id)
+        }
+    }
+}
+
+impl PartialEq<NormalizedOrderBookUpdate> for BinanceDepthUpdate {
+    fn eq(&self, other: &NormalizedOrderBookUpdate) -> bool {
+        let equals = other.exchange == CexExchange::Binance
+            && other.pair == self.pair.normalize()
+            && other.time == DateTime::from_timestamp_millis(self.event_time as i64).unwrap()
+            && other.kind == NormalizedOrderBookUpdateKind::Delta
+            && other
+                .bids
+                .iter()
+                .map(|l| (l.price, l.amount))
+                .eq(self.bids.iter().copied())
+            && other
+                .asks
+                .iter()
+                .map(|l| (l.price, l.amount))
+                .eq(self.asks.iter().copied())
+            && other.first_update_id == Some(self.first_update_id)
+            && other.last_update_id == Some(self.last_update_id);
+
+        if !equals {
+            warn!(target: "cex-exchanges::binance", "binance depth update: {:?}", self);
+            warn!(target: "cex-exchanges::binance", "normalized order book: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+/// order book snapshot from the rest api (`/depth`), used to (re)sync the diff.
+/// depth stream
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
+pub struct BinanceDepthSnapshot {
+    pub pair:              BinanceTradingPair,
+    pub local_update_time: DateTime<Utc>,
+    pub book:              BinanceOrderBook
+}
+
+impl BinanceDepthSnapshot {
+    pub fn normalize(self) -> NormalizedOrderBookUpdate {
+        NormalizedOrderBookUpdate {
+            exchange:        CexExchange::Binance,
+            pair:            self.pair.normalize(),
+            time:            self.local_update_time,
+            kind:            NormalizedOrderBookUpdateKind::Snapshot,
+            bids:            self
+                .book
+                .bids
+                .into_iter()
+                .map(|(p, a)| NormalizedBookLevel::new(p, a))
+                .collect(),
+            asks:            self
+                .book
+                .asks
+                .into_iter()
+                .map(|(p, a)| NormalizedBookLevel::new(p, a))
+                .collect(),
+            first_update_id: None,
+            last_update_id:  Some(self.book.last_update_id)
+        }
+    }
+}
+
+impl PartialEq<NormalizedOrderBookUpdate> for BinanceDepthSnapshot {
+    fn eq(&self, other: &NormalizedOrderBookUpdate) -> bool {
+        let equals = other.exchange == CexExchange::Binance
+            && other.pair == self.pair.normalize()
+            && other.time == self.local_update_time
+            && other.kind == NormalizedOrderBookUpdateKind::Snapshot
+            && other
+                .bids
+                .iter()
+                .map(|l| (l.price, l.amount))
+                .eq(self.book.bids.iter().copied())
+            && other
+                .asks
+                .iter()
+                .map(|l| (l.price, l.amount))
+                .eq(self.book.asks.iter().copied())
+            && other.last_update_id == Some(self.book.last_update_id);
+
+        if !equals {
+            warn!(target: "cex-exchanges::binance", "binance depth snapshot: {:?}", self);
+            warn!(target: "cex-exchanges::binance", "normalized order book: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
+pub struct BinanceOrderBook {
+    #[serde(rename = "lastUpdateId")]
+    pub last_update_id: u64,
+    #[serde_as(as = "Vec<(DisplayFromStr, DisplayFromStr)>")]
+    pub bids:           Vec<(NormalizedNumber, NormalizedNumber)>,
+    #[serde_as(as = "Vec<(DisplayFromStr, DisplayFromStr)>")]
+    pub asks:           Vec<(NormalizedNumber, NormalizedNumber)>
+}
@@ -0,0 +1,97 @@
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use tracing::warn;
+
+use crate::{
+    binance::BinanceTradingPair,
+    normalized::types::{NormalizedCandle, NormalizedCandleInterval, NormalizedNumber},
+    CexExchange
+};
+
+/// kline/candlestick stream (`<symbol>@kline_<interval>`)
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
+pub struct BinanceKline {
+    #[serde(rename = "s")]
+    pub pair:       BinanceTradingPair,
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "k")]
+    pub kline:      BinanceKlineInner
+}
+
+impl BinanceKline {
+    pub fn normalize(self) -> NormalizedCandle {
+        let start_time = DateTime::from_timestamp_millis(self.kline.start_time as i64).unwrap();
+
+        NormalizedCandle {
+            exchange: CexExchange::Binance,
+            pair: self.pair.normalize(),
+            interval: self.kline.interval,
+            start_time,
+            end_time: self.kline.interval.end_time(start_time),
+            open: self.kline.open,
+            high: self.kline.high,
+            low: self.kline.low,
+            close: self.kline.close,
+            volume: self.kline.volume,
+            trade_count: Some(self.kline.trade_count),
+            closed: Some(self.kline.is_closed)
+        }
+    }
+}
+
+impl PartialEq<NormalizedCandle> for BinanceKline {
+    fn eq(&self, other: &NormalizedCandle) -> bool {
+        let equals = other.exchange == CexExchange::Binance
+            && other.pair == self.pair.normalize()
+            && other.interval == self.kline.interval
+            && other.start_time == DateTime::from_timestamp_millis(self.kline.start_time as i64).unwrap()
+            && other.open == self.kline.open
+            && other.high == self.kline.high
+            && other.low == self.kline.low
+            && other.close == self.kline.close
+            && other.volume == self.kline.volume
+            && other.trade_count == Some(self.kline.trade_count)
+            && other.closed == Some(self.kline.is_closed);
+
+        if !equals {
+            warn!(target: "cex-exchanges::binance", "binance kline: {:?}", self);
+            warn!(target: "cex-exchanges::binance", "normalized candle: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
+pub struct BinanceKlineInner {
+    #[serde(rename = "t")]
+    pub start_time:  u64,
+    #[serde(rename = "T")]
+    pub close_time:  u64,
+    #[serde(rename = "i")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval:    NormalizedCandleInterval,
+    #[serde(rename = "o")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub open:        NormalizedNumber,
+    #[serde(rename = "h")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub high:        NormalizedNumber,
+    #[serde(rename = "l")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub low:         NormalizedNumber,
+    #[serde(rename = "c")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub close:       NormalizedNumber,
+    #[serde(rename = "v")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub volume:      NormalizedNumber,
+    #[serde(rename = "n")]
+    pub trade_count: u64,
+    #[serde(rename = "x")]
+    pub is_closed:   bool
+}
@@ -1,16 +1,25 @@
 mod book_ticker;
 pub use book_ticker::*;
 
+mod depth;
+pub use depth::*;
+
+mod kline;
+pub use kline::*;
+
 mod trades;
 use std::fmt::Display;
 
 pub use trades::*;
 
+mod user_data;
+pub use user_data::*;
+
 use crate::{
     exchanges::{
         binance::pairs::BinanceTradingPair,
         normalized::{
-            types::{NormalizedTradingPair, RawTradingPair},
+            types::{NormalizedCandleInterval, NormalizedTradingPair, RawTradingPair},
             ws::NormalizedWsChannels
         }
     },
@@ -20,7 +29,14 @@ use crate::{
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum BinanceWsChannel {
     Trade(Vec<BinanceTradingPair>),
-    BookTicker(Vec<BinanceTradingPair>)
+    BookTicker(Vec<BinanceTradingPair>),
+    Depth(Vec<BinanceTradingPair>),
+    Kline {
+        pairs:    Vec<BinanceTradingPair>,
+        interval: NormalizedCandleInterval
+    },
+    /// private account stream, requires credentials
+    UserData
 }
 
 impl BinanceWsChannel {
@@ -46,6 +62,28 @@ impl BinanceWsChannel {
         Self::new_from_normalized(normalized, BinanceWsChannel::BookTicker(Vec::new()))
     }
 
+    /// builds the diff. depth channel from a vec of raw trading
+    /// pairs return an error if the symbol is incorrectly formatted
+    pub fn new_depth(pairs: Vec<RawTradingPair>) -> eyre::Result<Self> {
+        let normalized = pairs
+            .into_iter()
+            .map(|pair| pair.get_normalized_pair(CexExchange::Binance))
+            .collect();
+
+        Self::new_from_normalized(normalized, BinanceWsChannel::Depth(Vec::new()))
+    }
+
+    /// builds the kline channel from a vec of raw trading pairs
+    /// return an error if the symbol is incorrectly formatted
+    pub fn new_kline(pairs: Vec<RawTradingPair>, interval: NormalizedCandleInterval) -> eyre::Result<Self> {
+        let normalized = pairs
+            .into_iter()
+            .map(|pair| pair.get_normalized_pair(CexExchange::Binance))
+            .collect();
+
+        Self::new_from_normalized(normalized, BinanceWsChannel::Kline { pairs: Vec::new(), interval })
+    }
+
     pub(crate) fn new_from_normalized(pairs: Vec<NormalizedTradingPair>, kind: BinanceWsChannel) -> eyre::Result<Self> {
         match kind {
             BinanceWsChannel::Trade(_) => Ok(BinanceWsChannel::Trade(
@@ -59,14 +97,31 @@ impl BinanceWsChannel {
                     .into_iter()
                     .map(TryInto::try_into)
                     .collect::<Result<_, _>>()?
-            ))
+            )),
+            BinanceWsChannel::Depth(_) => Ok(BinanceWsChannel::Depth(
+                pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<_, _>>()?
+            )),
+            BinanceWsChannel::Kline { interval, .. } => Ok(BinanceWsChannel::Kline {
+                pairs: pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<_, _>>()?,
+                interval
+            }),
+            BinanceWsChannel::UserData => Ok(BinanceWsChannel::UserData)
         }
     }
 
     pub fn count_entries(&self) -> usize {
         match self {
             BinanceWsChannel::Trade(vals) => vals.len(),
-            BinanceWsChannel::BookTicker(vals) => vals.len()
+            BinanceWsChannel::BookTicker(vals) => vals.len(),
+            BinanceWsChannel::Depth(vals) => vals.len(),
+            BinanceWsChannel::Kline { pairs, .. } => pairs.len(),
+            BinanceWsChannel::UserData => 1
         }
     }
 }
@@ -75,7 +130,10 @@ impl Display for BinanceWsChannel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             BinanceWsChannel::Trade(_) => write!(f, "trade"),
-            BinanceWsChannel::BookTicker(_) => write!(f, "bookTicker")
+            BinanceWsChannel::BookTicker(_) => write!(f, "bookTicker"),
+            BinanceWsChannel::Depth(_) => write!(f, "depth@100ms"),
+            BinanceWsChannel::Kline { interval, .. } => write!(f, "kline_{interval}"),
+            BinanceWsChannel::UserData => write!(f, "userData")
         }
     }
 }
@@ -87,6 +145,9 @@ impl TryFrom<String> for BinanceWsChannel {
         match value.to_lowercase().as_str() {
             "trade" => Ok(Self::Trade(Vec::new())),
             "bookticker" => Ok(Self::BookTicker(Vec::new())),
+            "depth" | "depth@100ms" => Ok(Self::Depth(Vec::new())),
+            kline if kline.starts_with("kline_") => Ok(Self::Kline { pairs: Vec::new(), interval: kline.trim_start_matches("kline_").parse()? }),
+            "userdata" => Ok(Self::UserData),
             _ => Err(eyre::ErrReport::msg(format!("channel is not valid: {value}")))
         }
     }
@@ -113,6 +174,23 @@ impl TryFrom<NormalizedWsChannels> for BinanceWsChannel {
 
                 Ok(BinanceWsChannel::BookTicker(norm_pairs))
             }
+            NormalizedWsChannels::OrderBook { pairs, .. } => {
+                let norm_pairs = pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, Self::Error>>()?;
+
+                Ok(BinanceWsChannel::Depth(norm_pairs))
+            }
+            NormalizedWsChannels::Candles { pairs, interval } => {
+                let norm_pairs = pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, Self::Error>>()?;
+
+                Ok(BinanceWsChannel::Kline { pairs: norm_pairs, interval })
+            }
+            NormalizedWsChannels::Account(_) => Ok(BinanceWsChannel::UserData)
         }
     }
 }
@@ -120,14 +198,20 @@ impl TryFrom<NormalizedWsChannels> for BinanceWsChannel {
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum BinanceWsChannelKind {
     Trade,
-    BookTicker
+    BookTicker,
+    Depth,
+    Kline(NormalizedCandleInterval),
+    UserData
 }
 
 impl Display for BinanceWsChannelKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             BinanceWsChannelKind::Trade => write!(f, "trade"),
-            BinanceWsChannelKind::BookTicker => write!(f, "bookTicker")
+            BinanceWsChannelKind::BookTicker => write!(f, "bookTicker"),
+            BinanceWsChannelKind::Depth => write!(f, "depth@100ms"),
+            BinanceWsChannelKind::Kline(interval) => write!(f, "kline_{interval}"),
+            BinanceWsChannelKind::UserData => write!(f, "userData")
         }
     }
 }
@@ -136,7 +220,10 @@ impl From<&BinanceWsChannel> for BinanceWsChannelKind {
     fn from(value: &BinanceWsChannel) -> Self {
         match value {
             BinanceWsChannel::Trade(_) => BinanceWsChannelKind::Trade,
-            BinanceWsChannel::BookTicker(_) => BinanceWsChannelKind::BookTicker
+            BinanceWsChannel::BookTicker(_) => BinanceWsChannelKind::BookTicker,
+            BinanceWsChannel::Depth(_) => BinanceWsChannelKind::Depth,
+            BinanceWsChannel::Kline { interval, .. } => BinanceWsChannelKind::Kline(*interval),
+            BinanceWsChannel::UserData => BinanceWsChannelKind::UserData
         }
     }
 }
@@ -1,10 +1,15 @@
-use chrono::DateTime;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 use tracing::warn;
 
 use crate::{
-    exchanges::{binance::pairs::BinanceTradingPair, normalized::types::NormalizedTrade},
+    clients::ws::received_at,
+    exchanges::{
+        binance::pairs::BinanceTradingPair,
+        normalized::types::{NormalizedTrade, TradeSide}
+    },
+    normalized::types::NormalizedNumber,
     CexExchange
 };
 
@@ -15,10 +20,10 @@ pub struct BinanceTrade {
     pub pair:                  BinanceTradingPair,
     #[serde(rename = "p")]
     #[serde_as(as = "DisplayFromStr")]
-    pub price:                 f64,
+    pub price:                 NormalizedNumber,
     #[serde(rename = "q")]
     #[serde_as(as = "DisplayFromStr")]
-    pub quantity:              f64,
+    pub quantity:              NormalizedNumber,
     #[serde(rename = "t")]
     pub trade_id:              u64,
     #[serde(rename = "a")]
@@ -26,19 +31,23 @@ pub struct BinanceTrade {
     #[serde(rename = "m")]
     pub is_buyer_market_maker: bool,
     #[serde(rename = "T")]
-    pub trade_time:            u64
+    pub trade_time:            u64,
+    #[serde(default = "received_at")]
+    pub received_at:           DateTime<Utc>
 }
 
 impl BinanceTrade {
     pub fn normalize(self) -> NormalizedTrade {
         NormalizedTrade {
-            exchange: CexExchange::Binance,
-            pair:     self.pair.normalize(),
-            time:     DateTime::from_timestamp_millis(self.trade_time as i64).unwrap(),
-            side:     if self.is_buyer_market_maker { "buy".to_string() } else { "sell".to_string() },
-            price:    self.price,
-            amount:   self.quantity,
-            trade_id: Some(self.trade_id.to_string())
+            exchange:      CexExchange::Binance,
+            pair:          self.pair.normalize(),
+            exchange_time: DateTime::from_timestamp_millis(self.trade_time as i64),
+            received_at:   self.received_at,
+            // the buyer being the maker, the seller is the taker
+            side:          if self.is_buyer_market_maker { TradeSide::Sell } else { TradeSide::Buy },
+            price:         self.price,
+            amount:        self.quantity,
+            trade_id:      Some(self.trade_id.to_string())
         }
     }
 }
@@ -47,8 +56,9 @@ impl PartialEq<NormalizedTrade> for BinanceTrade {
     fn eq(&self, other: &NormalizedTrade) -> bool {
         let equals = other.exchange == CexExchange::Binance
             && other.pair == self.pair.normalize()
-            && other.time == DateTime::from_timestamp_millis(self.trade_time as i64).unwrap()
-            && other.side == if self.is_buyer_market_maker { "buy".to_string() } else { "sell".to_string() }
+            && other.exchange_time == DateTime::from_timestamp_millis(self.trade_time as i64)
+            && other.received_at == self.received_at
+            && other.side == if self.is_buyer_market_maker { TradeSide::Sell } else { TradeSide::Buy }
             && other.price == self.price
             && other.amount == self.quantity
             && other.trade_id.as_ref().unwrap() == &self.trade_id.to_string();
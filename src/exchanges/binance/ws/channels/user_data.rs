@@ -0,0 +1,173 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use tracing::warn;
+
+use crate::{
+    exchanges::{
+        binance::pairs::BinanceTradingPair,
+        normalized::types::{NormalizedBalanceUpdate, NormalizedFill, NormalizedOrderSide, NormalizedOrderStatus, NormalizedOrderUpdate}
+    },
+    normalized::types::NormalizedNumber,
+    CexExchange
+};
+
+/// `outboundAccountPosition` event, sent with the balances of the assets
+/// that changed
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BinanceAccountPosition {
+    #[serde(rename = "E")]
+    pub event_time:  u64,
+    #[serde(rename = "u")]
+    pub update_time: u64,
+    #[serde(rename = "B")]
+    pub balances:    Vec<BinanceAccountBalance>
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BinanceAccountBalance {
+    #[serde(rename = "a")]
+    pub asset:  String,
+    #[serde(rename = "f")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub free:   NormalizedNumber,
+    #[serde(rename = "l")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub locked: NormalizedNumber
+}
+
+impl BinanceAccountPosition {
+    pub fn normalize(self) -> Vec<NormalizedBalanceUpdate> {
+        let time = DateTime::from_timestamp_millis(self.update_time as i64).unwrap();
+
+        self.balances
+            .into_iter()
+            .map(|b| NormalizedBalanceUpdate { exchange: CexExchange::Binance, symbol: b.asset, available: b.free, locked: b.locked, time })
+            .collect()
+    }
+}
+
+impl PartialEq<Vec<NormalizedBalanceUpdate>> for BinanceAccountPosition {
+    fn eq(&self, other: &Vec<NormalizedBalanceUpdate>) -> bool {
+        let equals = self.clone().normalize() == *other;
+
+        if !equals {
+            warn!(target: "cex-exchanges::binance", "binance account position: {:?}", self);
+            warn!(target: "cex-exchanges::binance", "normalized balance updates: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+/// `executionReport` event, sent on every change of an order
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BinanceExecutionReport {
+    #[serde(rename = "s")]
+    pub pair:                BinanceTradingPair,
+    #[serde(rename = "c")]
+    pub client_order_id:     String,
+    #[serde(rename = "S")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub side:                NormalizedOrderSide,
+    #[serde(rename = "o")]
+    pub order_type:          String,
+    #[serde(rename = "q")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub quantity:            NormalizedNumber,
+    #[serde(rename = "p")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub price:               NormalizedNumber,
+    /// NEW, CANCELED, REPLACED, REJECTED, TRADE, EXPIRED, TRADE_PREVENTION
+    #[serde(rename = "x")]
+    pub execution_type:      String,
+    #[serde(rename = "X")]
+    pub order_status:        String,
+    #[serde(rename = "i")]
+    pub order_id:            u64,
+    #[serde(rename = "l")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub last_quantity:       NormalizedNumber,
+    #[serde(rename = "z")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub cumulative_quantity: NormalizedNumber,
+    #[serde(rename = "L")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub last_price:          NormalizedNumber,
+    #[serde(rename = "n")]
+    #[serde_as(as = "DisplayFromStr")]
+    pub commission:          NormalizedNumber,
+    #[serde(rename = "N")]
+    pub commission_asset:    Option<String>,
+    #[serde(rename = "T")]
+    pub transaction_time:    u64,
+    /// -1 if the update isn't a trade
+    #[serde(rename = "t")]
+    pub trade_id:            i64,
+    #[serde(rename = "m")]
+    pub is_maker:            bool
+}
+
+impl BinanceExecutionReport {
+    pub fn normalize(self) -> NormalizedOrderUpdate {
+        let time = DateTime::from_timestamp_millis(self.transaction_time as i64).unwrap();
+        NormalizedOrderUpdate {
+            exchange: CexExchange::Binance,
+            pair: self.pair.normalize(),
+            order_id: self.order_id.to_string(),
+            client_order_id: Some(self.client_order_id.clone()),
+            side: self.side,
+            order_type: self.order_type.to_lowercase(),
+            status: binance_order_status(&self.order_status),
+            price: (self.order_type != "MARKET").then_some(self.price),
+            amount: Some(self.quantity),
+            filled_amount: Some(self.cumulative_quantity),
+            time,
+            last_fill: (self.execution_type == "TRADE").then(|| self.fill(time))
+        }
+    }
+
+    fn fill(&self, time: DateTime<Utc>) -> NormalizedFill {
+        NormalizedFill {
+            exchange: CexExchange::Binance,
+            pair: self.pair.normalize(),
+            order_id: self.order_id.to_string(),
+            trade_id: self.trade_id.to_string(),
+            side: self.side,
+            price: self.last_price,
+            amount: self.last_quantity,
+            fee: Some(self.commission),
+            fee_symbol: self.commission_asset.clone(),
+            is_maker: Some(self.is_maker),
+            time
+        }
+    }
+}
+
+/// normalizes the status of an order, shared with the rest api
+pub(crate) fn binance_order_status(status: &str) -> NormalizedOrderStatus {
+    match status {
+        "PARTIALLY_FILLED" => NormalizedOrderStatus::PartiallyFilled,
+        "FILLED" => NormalizedOrderStatus::Filled,
+        "CANCELED" => NormalizedOrderStatus::Canceled,
+        "REJECTED" => NormalizedOrderStatus::Rejected,
+        "EXPIRED" | "EXPIRED_IN_MATCH" => NormalizedOrderStatus::Expired,
+        // NEW & PENDING_CANCEL
+        _ => NormalizedOrderStatus::New
+    }
+}
+
+impl PartialEq<NormalizedOrderUpdate> for BinanceExecutionReport {
+    fn eq(&self, other: &NormalizedOrderUpdate) -> bool {
+        let equals = self.clone().normalize() == *other;
+
+        if !equals {
+            warn!(target: "cex-exchanges::binance", "binance execution report: {:?}", self);
+            warn!(target: "cex-exchanges::binance", "normalized order update: {:?}", other);
+        }
+
+        equals
+    }
+}
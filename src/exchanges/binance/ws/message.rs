@@ -2,8 +2,14 @@ use eyre::Ok;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use super::channels::{BinanceBookTicker, BinanceTrade};
-use crate::{clients::ws::CriticalWsMessage, exchanges::normalized::ws::NormalizedWsDataTypes, CexExchange};
+use super::channels::{
+    BinanceAccountPosition, BinanceBookTicker, BinanceDepthSnapshot, BinanceDepthUpdate, BinanceExecutionReport, BinanceKline, BinanceTrade
+};
+use crate::{
+    clients::ws::{CriticalWsMessage, SequenceKind, WsSequence},
+    exchanges::normalized::ws::NormalizedWsDataTypes,
+    CexExchange
+};
 
 #[serde_with::serde_as]
 #[derive(Debug, Clone, Serialize)]
@@ -11,7 +17,22 @@ use crate::{clients::ws::CriticalWsMessage, exchanges::normalized::ws::Normalize
 pub enum BinanceWsMessage {
     Trade(BinanceTrade),
     BookTicker(BinanceBookTicker),
-    SuscriptionResponse { result: Option<String>, id: u64 }
+    Depth(BinanceDepthUpdate),
+    /// not sent over the ws, fetched from the rest api to resync the depth
+    /// channel
+    DepthSnapshot(BinanceDepthSnapshot),
+    Kline(BinanceKline),
+    AccountPosition(BinanceAccountPosition),
+    ExecutionReport(BinanceExecutionReport),
+    /// user data events that aren't normalized (ex: `balanceUpdate`)
+    UserDataEvent {
+        event: String,
+        value: Value
+    },
+    SuscriptionResponse {
+        result: Option<String>,
+        id:     u64
+    }
 }
 
 impl BinanceWsMessage {
@@ -33,6 +54,15 @@ impl BinanceWsMessage {
             } else if data_type.contains("@bookTicker") {
                 let book_ticker: BinanceBookTicker = serde_json::from_value(data.clone())?;
                 Ok(Self::BookTicker(book_ticker))
+            } else if data_type.contains("@depth") {
+                let depth: BinanceDepthUpdate = serde_json::from_value(data.clone())?;
+                Ok(Self::Depth(depth))
+            } else if data_type.contains("@kline") {
+                let kline: BinanceKline = serde_json::from_value(data.clone())?;
+                Ok(Self::Kline(kline))
+            } else if !data_type.contains('@') {
+                // the user data stream is named after the listen key
+                Self::try_deserialize_user_data(data)
             } else {
                 Err(eyre::ErrReport::msg(format!("Event type '{data_type}' cannot be deserialized")))
             }
@@ -52,6 +82,24 @@ impl BinanceWsMessage {
     }
 }
 
+impl BinanceWsMessage {
+    fn try_deserialize_user_data(data: Value) -> eyre::Result<Self> {
+        let event = data
+            .get("e")
+            .and_then(|e| e.as_str())
+            .ok_or(eyre::ErrReport::msg("Could not find 'e' (event type) field in Binance user data message".to_string()))?
+            .to_string();
+
+        match event.as_str() {
+            "outboundAccountPosition" => Ok(Self::AccountPosition(serde_json::from_value(data)?)),
+            "executionReport" => Ok(Self::ExecutionReport(serde_json::from_value(data)?)),
+            // errors so the stream reconnects with a new listen key
+            "listenKeyExpired" => Err(eyre::ErrReport::msg("Binance listen key expired".to_string())),
+            _ => Ok(Self::UserDataEvent { event, value: data })
+        }
+    }
+}
+
 impl<'de> Deserialize<'de> for BinanceWsMessage {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -68,6 +116,14 @@ impl BinanceWsMessage {
         match self {
             BinanceWsMessage::Trade(v) => NormalizedWsDataTypes::Trade(v.normalize()),
             BinanceWsMessage::BookTicker(v) => NormalizedWsDataTypes::Quote(v.normalize()),
+            BinanceWsMessage::Depth(v) => NormalizedWsDataTypes::OrderBook(v.normalize()),
+            BinanceWsMessage::DepthSnapshot(v) => NormalizedWsDataTypes::OrderBook(v.normalize()),
+            BinanceWsMessage::Kline(v) => NormalizedWsDataTypes::Candle(v.normalize()),
+            BinanceWsMessage::AccountPosition(v) => NormalizedWsDataTypes::BalanceUpdates(v.normalize()),
+            BinanceWsMessage::ExecutionReport(v) => NormalizedWsDataTypes::OrderUpdate(v.normalize()),
+            BinanceWsMessage::UserDataEvent { event, value } => {
+                NormalizedWsDataTypes::Other { exchange: CexExchange::Binance, kind: event, value: value.to_string() }
+            }
             BinanceWsMessage::SuscriptionResponse { result, id } => NormalizedWsDataTypes::Other {
                 exchange: CexExchange::Binance,
                 kind:     "SUBSCRIBE".to_string(),
@@ -82,6 +138,12 @@ impl PartialEq<NormalizedWsDataTypes> for BinanceWsMessage {
         match (self, other) {
             (BinanceWsMessage::Trade(this), NormalizedWsDataTypes::Trade(that)) => this == that,
             (BinanceWsMessage::BookTicker(this), NormalizedWsDataTypes::Quote(that)) => this == that,
+            (BinanceWsMessage::Depth(this), NormalizedWsDataTypes::OrderBook(that)) => this == that,
+            (BinanceWsMessage::DepthSnapshot(this), NormalizedWsDataTypes::OrderBook(that)) => this == that,
+            (BinanceWsMessage::Kline(this), NormalizedWsDataTypes::Candle(that)) => this == that,
+            (BinanceWsMessage::AccountPosition(this), NormalizedWsDataTypes::BalanceUpdates(that)) => this == that,
+            (BinanceWsMessage::ExecutionReport(this), NormalizedWsDataTypes::OrderUpdate(that)) => this == that,
+            (BinanceWsMessage::UserDataEvent { .. }, NormalizedWsDataTypes::Other { .. }) => true,
             (BinanceWsMessage::SuscriptionResponse { .. }, NormalizedWsDataTypes::Other { .. }) => true,
             _ => false
         }
@@ -89,4 +151,28 @@ impl PartialEq<NormalizedWsDataTypes> for BinanceWsMessage {
 }
 impl CriticalWsMessage for BinanceWsMessage {
     fn make_critical(&mut self, _msg: String) {}
+
+    fn sequence(&self) -> Option<WsSequence> {
+        match self {
+            BinanceWsMessage::BookTicker(v) => Some(WsSequence {
+                channel: "bookTicker",
+                pair:    v.pair.normalize(),
+                kind:    SequenceKind::Increasing(v.orderbook_update_id),
+                resync:  false
+            }),
+            BinanceWsMessage::Depth(v) => Some(WsSequence {
+                channel: "depth",
+                pair:    v.pair.normalize(),
+                kind:    SequenceKind::Contiguous { first: v.first_update_id, last: v.last_update_id },
+                resync:  true
+            }),
+            BinanceWsMessage::DepthSnapshot(v) => Some(WsSequence {
+                channel: "depth",
+                pair:    v.pair.normalize(),
+                kind:    SequenceKind::Snapshot(Some(v.book.last_update_id)),
+                resync:  true
+            }),
+            _ => None
+        }
+    }
 }
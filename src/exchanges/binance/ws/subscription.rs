@@ -7,17 +7,25 @@ use crate::binance::BinanceTradingPair;
 
 #[derive(Debug, Clone, Serialize)]
 pub struct BinanceSubscription {
-    method: String,
-    params: Vec<BinanceSubscriptionInner>,
-    id:     u64
+    method:    String,
+    params:    Vec<BinanceSubscriptionInner>,
+    id:        u64,
+    /// the user data stream is subscribed to separately with a listen key
+    #[serde(skip)]
+    user_data: bool
 }
 
 impl BinanceSubscription {
     pub fn new() -> Self {
-        BinanceSubscription { method: "SUBSCRIBE".to_string(), params: Vec::new(), id: 1 }
+        BinanceSubscription { method: "SUBSCRIBE".to_string(), params: Vec::new(), id: 1, user_data: false }
     }
 
     pub fn add_channel(&mut self, channel: BinanceWsChannel) {
+        if channel == BinanceWsChannel::UserData {
+            self.user_data = true;
+            return
+        }
+
         let new: Vec<BinanceSubscriptionInner> = channel.into();
         self.params.extend(new);
     }
@@ -25,7 +33,55 @@ impl BinanceSubscription {
     pub fn remove_pair(&mut self, pair: &BinanceTradingPair) -> bool {
         self.params.retain(|p| &p.trading_pair != pair);
 
-        self.params.is_empty()
+        self.params.is_empty() && !self.user_data
+    }
+
+    /// adds the streams of the channel, returning the request to subscribe to
+    /// the new ones
+    pub(crate) fn subscribe(&mut self, channel: BinanceWsChannel) -> Option<Self> {
+        let new = Vec::<BinanceSubscriptionInner>::from(channel)
+            .into_iter()
+            .filter(|p| !self.params.contains(p))
+            .collect::<Vec<_>>();
+        self.params.extend(new.clone());
+
+        (!new.is_empty()).then(|| BinanceSubscription { method: "SUBSCRIBE".to_string(), params: new, id: 1, user_data: false })
+    }
+
+    /// removes the streams of the channel, returning the request to
+    /// unsubscribe from the ones that were subscribed to
+    pub(crate) fn unsubscribe(&mut self, channel: BinanceWsChannel) -> Option<Self> {
+        let removed = Vec::<BinanceSubscriptionInner>::from(channel)
+            .into_iter()
+            .filter(|p| self.params.contains(p))
+            .collect::<Vec<_>>();
+        self.params.retain(|p| !removed.contains(p));
+
+        (!removed.is_empty()).then(|| BinanceSubscription {
+            method:    "UNSUBSCRIBE".to_string(),
+            params:    removed,
+            id:        1,
+            user_data: false
+        })
+    }
+
+    /// the request to unsubscribe from every public stream
+    pub(crate) fn unsubscribe_all(&self) -> Option<Self> {
+        self.has_public_streams().then(|| BinanceSubscription {
+            method:    "UNSUBSCRIBE".to_string(),
+            params:    self.params.clone(),
+            id:        1,
+            user_data: false
+        })
+    }
+
+    /// if there are public streams to subscribe to
+    pub(crate) fn has_public_streams(&self) -> bool {
+        !self.params.is_empty()
+    }
+
+    pub(crate) fn has_user_data(&self) -> bool {
+        self.user_data
     }
 }
 
@@ -35,7 +91,7 @@ impl Default for BinanceSubscription {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 struct BinanceSubscriptionInner {
     channel:      BinanceWsChannelKind,
     trading_pair: BinanceTradingPair
@@ -64,7 +120,18 @@ impl From<BinanceWsChannel> for Vec<BinanceSubscriptionInner> {
                 .into_iter()
                 .collect::<HashSet<_>>()
                 .into_iter()
-                .collect()
+                .collect(),
+            BinanceWsChannel::Depth(pairs) => pairs
+                .into_iter()
+                .collect::<HashSet<_>>()
+                .into_iter()
+                .collect(),
+            BinanceWsChannel::Kline { pairs, .. } => pairs
+                .into_iter()
+                .collect::<HashSet<_>>()
+                .into_iter()
+                .collect(),
+            BinanceWsChannel::UserData => Vec::new()
         };
 
         all_pairs
@@ -0,0 +1,164 @@
+mod pairs;
+pub use pairs::*;
+
+pub mod rest_api;
+pub mod ws;
+
+use std::time::Duration;
+
+use futures::SinkExt;
+use serde::Deserialize;
+use tokio::net::TcpStream;
+use tokio_tungstenite::{tungstenite::Message, MaybeTlsStream, WebSocketStream};
+use tracing::{error, info};
+
+use self::{
+    rest_api::{BitgetAllCurrencies, BitgetAllInstruments, BitgetRestApiResponse},
+    ws::{channels::BitgetWsChannel, BitgetSubscription, BitgetWsMessage}
+};
+use crate::{
+    clients::{
+        rate_limiter,
+        rest_api::RestApiError,
+        ws::{WsError, WsSubscriptionUpdate},
+        EndpointKind, ExchangeEndpoints, RateLimitKind, TokenBucket
+    },
+    normalized::{rest_api::NormalizedRestApiRequest, types::NormalizedTradingPair},
+    CexExchange, Exchange
+};
+
+const WSS_URL: &str = "wss://ws.bitget.com/v2/ws/public";
+const BASE_REST_API_URL: &str = "https://api.bitget.com";
+/// the connection is closed if no `ping` is sent for 2 minutes
+/// (https://www.bitget.com/api-doc/common/websocket-intro)
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Default, Clone)]
+pub struct Bitget {
+    subscription: BitgetSubscription,
+    endpoints:    ExchangeEndpoints
+}
+
+impl Bitget {
+    fn rest_url(&self) -> String {
+        self.endpoints.url(EndpointKind::Rest, BASE_REST_API_URL)
+    }
+
+    fn ws_url(&self) -> String {
+        self.endpoints.url(EndpointKind::Ws, WSS_URL)
+    }
+
+    pub fn new_ws_subscription(subscription: BitgetSubscription) -> Self {
+        Self { subscription, endpoints: ExchangeEndpoints::default() }
+    }
+
+    pub async fn get_all_currencies(&self, web_client: &reqwest::Client) -> Result<BitgetAllCurrencies, RestApiError> {
+        let currencies: BitgetAllCurrencies =
+            Self::simple_rest_api_request(web_client, format!("{}/api/v2/spot/public/coins", self.rest_url())).await?;
+        info!(target: "cex-exchanges::bitget", "found {} currencies", currencies.currencies.len());
+        Ok(currencies)
+    }
+
+    /// the spot symbols
+    pub async fn get_all_instruments(&self, web_client: &reqwest::Client) -> Result<BitgetAllInstruments, RestApiError> {
+        let instruments: BitgetAllInstruments =
+            Self::simple_rest_api_request(web_client, format!("{}/api/v2/spot/public/symbols", self.rest_url())).await?;
+        info!(target: "cex-exchanges::bitget", "found {} instruments", instruments.instruments.len());
+        Ok(instruments)
+    }
+
+    pub async fn simple_rest_api_request<T>(web_client: &reqwest::Client, url: String) -> Result<T, RestApiError>
+    where
+        T: for<'de> Deserialize<'de>
+    {
+        rate_limiter(CexExchange::Bitget, RateLimitKind::Rest)
+            .acquire()
+            .await;
+        let data = web_client.get(&url).send().await?.json().await?;
+        Ok(data)
+    }
+}
+
+impl Exchange for Bitget {
+    type RestApiResult = BitgetRestApiResponse;
+    type WsMessage = BitgetWsMessage;
+
+    const EXCHANGE: CexExchange = CexExchange::Bitget;
+
+    fn with_endpoints(mut self, endpoints: ExchangeEndpoints) -> Self {
+        self.endpoints = endpoints;
+        self
+    }
+
+    fn remove_bad_pair(&mut self, bad_pair: NormalizedTradingPair) -> bool {
+        let pair: BitgetTradingPair = bad_pair.try_into().unwrap();
+        self.subscription.remove_pair(&pair)
+    }
+
+    async fn make_ws_connection(&self, subscribe_limiter: &TokenBucket) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, WsError> {
+        let (mut ws, _) = tokio_tungstenite::connect_async(self.ws_url()).await?;
+
+        subscribe_limiter.acquire().await;
+        ws.send(Message::Text(serde_json::to_string(&self.subscription)?))
+            .await?;
+
+        Ok(ws)
+    }
+
+    async fn rest_api_call(
+        &self,
+        web_client: &reqwest::Client,
+        api_channel: NormalizedRestApiRequest
+    ) -> Result<BitgetRestApiResponse, RestApiError> {
+        let api_response = match api_channel.clone() {
+            NormalizedRestApiRequest::AllCurrencies => self
+                .get_all_currencies(web_client)
+                .await
+                .map(BitgetRestApiResponse::Currencies),
+            NormalizedRestApiRequest::AllInstruments => self
+                .get_all_instruments(web_client)
+                .await
+                .map(BitgetRestApiResponse::Instruments),
+            NormalizedRestApiRequest::Candles { .. }
+            | NormalizedRestApiRequest::RecentTrades { .. }
+            | NormalizedRestApiRequest::HistoricalTrades { .. } => {
+                Err(RestApiError::UnsupportedRequest(format!("bitget does not support {:?}", api_channel)))
+            }
+        };
+
+        if let Err(e) = api_response.as_ref() {
+            error!(target: "cex-exchanges::bitget", "error calling rest-api endpoint {:?} -- {:?}", api_channel, e);
+        }
+
+        api_response
+    }
+
+    fn update_subscription(&mut self, update: WsSubscriptionUpdate) -> Result<Vec<Message>, WsError> {
+        let channel: BitgetWsChannel = update.exchange_channel()?;
+        let requests = if update.is_subscribe() { self.subscription.subscribe(channel) } else { self.subscription.unsubscribe(channel) };
+
+        let messages = requests
+            .into_iter()
+            .map(|request| serde_json::to_string(&request).map(Message::Text))
+            .collect::<Result<_, _>>()?;
+
+        Ok(messages)
+    }
+
+    fn unsubscribe_all(&self) -> Vec<Message> {
+        self.subscription
+            .unsubscribe_all()
+            .and_then(|request| serde_json::to_string(&request).ok())
+            .map(Message::Text)
+            .into_iter()
+            .collect()
+    }
+
+    fn heartbeat(&self) -> Option<(Duration, Message)> {
+        Some((HEARTBEAT_INTERVAL, Message::Text("ping".to_string())))
+    }
+
+    fn is_heartbeat_reply(&self, frame: &str) -> bool {
+        frame == "pong"
+    }
+}
@@ -0,0 +1,151 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::{serde_as, DefaultOnNull, DisplayFromStr};
+use tracing::warn;
+
+use crate::{
+    bitget::rest_api::bitget_data,
+    normalized::{
+        rest_api::NormalizedRestApiDataTypes,
+        types::{BlockchainCurrency, NormalizedCurrency}
+    },
+    CexExchange
+};
+
+#[derive(Debug, Clone, Serialize, PartialEq, PartialOrd)]
+pub struct BitgetAllCurrencies {
+    pub currencies: Vec<BitgetCurrency>
+}
+
+impl BitgetAllCurrencies {
+    pub fn normalize(self) -> Vec<NormalizedCurrency> {
+        self.currencies
+            .into_iter()
+            .map(BitgetCurrency::normalize)
+            .collect()
+    }
+}
+
+impl<'de> Deserialize<'de> for BitgetAllCurrencies {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        let val = Value::deserialize(deserializer)?;
+        let data = bitget_data(val).map_err(serde::de::Error::custom)?;
+
+        let currencies = serde_json::from_value(data).map_err(serde::de::Error::custom)?;
+
+        Ok(BitgetAllCurrencies { currencies })
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for BitgetAllCurrencies {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match other {
+            NormalizedRestApiDataTypes::AllCurrencies(other_currs) => {
+                let this_currencies = self
+                    .currencies
+                    .iter()
+                    .map(|curr| &curr.coin)
+                    .collect::<HashSet<_>>();
+
+                self.currencies.len() == other_currs.len()
+                    && other_currs
+                        .iter()
+                        .all(|curr| this_currencies.contains(&curr.symbol))
+            }
+            _ => false
+        }
+    }
+}
+
+/// a coin of `/api/v2/spot/public/coins`
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+#[serde(rename_all = "camelCase")]
+pub struct BitgetCurrency {
+    #[serde_as(as = "DisplayFromStr")]
+    pub coin_id:  u64,
+    /// ex: BTC
+    pub coin:     String,
+    /// if the coin can be transferred between accounts
+    #[serde_as(as = "DisplayFromStr")]
+    pub transfer: bool,
+    #[serde_as(as = "DefaultOnNull")]
+    pub chains:   Vec<BitgetCurrencyChain>
+}
+
+impl BitgetCurrency {
+    pub fn status(&self) -> &'static str {
+        if self.transfer {
+            "enabled"
+        } else {
+            "disabled"
+        }
+    }
+
+    pub fn normalize(self) -> NormalizedCurrency {
+        NormalizedCurrency {
+            exchange:     CexExchange::Bitget,
+            status:       self.status().to_string(),
+            name:         self.coin.clone(),
+            symbol:       self.coin,
+            display_name: None,
+            blockchains:  self.chains.into_iter().map(Into::into).collect()
+        }
+    }
+}
+
+impl PartialEq<NormalizedCurrency> for BitgetCurrency {
+    fn eq(&self, other: &NormalizedCurrency) -> bool {
+        let equals = other.exchange == CexExchange::Bitget
+            && other.symbol == self.coin
+            && other.name == self.coin
+            && other.display_name.is_none()
+            && other.status == self.status()
+            && self
+                .chains
+                .iter()
+                .all(|c| other.blockchains.contains(&c.clone().into()));
+
+        if !equals {
+            warn!(target: "cex-exchanges::bitget", "bitget currency: {:?}", self);
+            warn!(target: "cex-exchanges::bitget", "normalized currency: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+#[serde(rename_all = "camelCase")]
+pub struct BitgetCurrencyChain {
+    /// ex: BTC, ERC20, TRC20
+    pub chain:               String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub need_tag:            bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub withdrawable:        bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub rechargeable:        bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub withdraw_fee:        f64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub min_withdraw_amount: f64,
+    pub contract_address:    Option<String>
+}
+
+impl From<BitgetCurrencyChain> for BlockchainCurrency {
+    fn from(val: BitgetCurrencyChain) -> Self {
+        BlockchainCurrency {
+            blockchain:       val.chain.parse().unwrap(),
+            address:          val.contract_address.filter(|addr| !addr.is_empty()),
+            is_wrapped:       false,
+            wrapped_currency: None
+        }
+    }
+}
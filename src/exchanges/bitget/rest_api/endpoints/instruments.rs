@@ -0,0 +1,127 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::{serde_as, DisplayFromStr};
+use tracing::warn;
+
+use crate::{
+    bitget::{rest_api::bitget_data, BitgetTradingPair},
+    normalized::{
+        rest_api::NormalizedRestApiDataTypes,
+        types::{NormalizedInstrument, NormalizedTradingType}
+    },
+    CexExchange
+};
+
+#[derive(Debug, Clone, Serialize, PartialEq, PartialOrd)]
+pub struct BitgetAllInstruments {
+    pub instruments: Vec<BitgetInstrument>
+}
+
+impl BitgetAllInstruments {
+    pub fn normalize(self) -> Vec<NormalizedInstrument> {
+        self.instruments
+            .into_iter()
+            .map(BitgetInstrument::normalize)
+            .collect()
+    }
+}
+
+impl<'de> Deserialize<'de> for BitgetAllInstruments {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        let val = Value::deserialize(deserializer)?;
+        let data = bitget_data(val).map_err(serde::de::Error::custom)?;
+
+        let instruments = serde_json::from_value(data).map_err(serde::de::Error::custom)?;
+
+        Ok(BitgetAllInstruments { instruments })
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for BitgetAllInstruments {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match other {
+            NormalizedRestApiDataTypes::AllInstruments(other_instrs) => {
+                self.instruments.len() == other_instrs.len()
+                    && self
+                        .instruments
+                        .iter()
+                        .zip(other_instrs.iter())
+                        .all(|(this, that)| this == that)
+            }
+            _ => false
+        }
+    }
+}
+
+/// a spot symbol of `/api/v2/spot/public/symbols`
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+#[serde(rename_all = "camelCase")]
+pub struct BitgetInstrument {
+    pub symbol:             BitgetTradingPair,
+    pub base_coin:          String,
+    pub quote_coin:         String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub min_trade_amount:   f64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub max_trade_amount:   f64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub taker_fee_rate:     f64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub maker_fee_rate:     f64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub price_precision:    u32,
+    #[serde_as(as = "DisplayFromStr")]
+    pub quantity_precision: u32,
+    /// 'online', 'offline', 'gray' (pre-market) or 'halt'
+    pub status:             String
+}
+
+impl BitgetInstrument {
+    pub fn is_active(&self) -> bool {
+        self.status == "online"
+    }
+
+    pub fn normalize(self) -> NormalizedInstrument {
+        NormalizedInstrument {
+            exchange:           CexExchange::Bitget,
+            trading_pair:       self
+                .symbol
+                .normalize_with(&self.base_coin, &self.quote_coin),
+            trading_type:       NormalizedTradingType::Spot,
+            active:             self.is_active(),
+            base_asset_symbol:  self.base_coin,
+            quote_asset_symbol: self.quote_coin,
+            futures_expiry:     None,
+            option_strike:      None,
+            option_type:        None
+        }
+    }
+}
+
+impl PartialEq<NormalizedInstrument> for BitgetInstrument {
+    fn eq(&self, other: &NormalizedInstrument) -> bool {
+        let equals = other.exchange == CexExchange::Bitget
+            && other.trading_pair
+                == self
+                    .symbol
+                    .normalize_with(&self.base_coin, &self.quote_coin)
+            && other.trading_type == NormalizedTradingType::Spot
+            && other.base_asset_symbol == self.base_coin
+            && other.quote_asset_symbol == self.quote_coin
+            && other.active == self.is_active()
+            && other.futures_expiry.is_none()
+            && other.option_strike.is_none()
+            && other.option_type.is_none();
+
+        if !equals {
+            warn!(target: "cex-exchanges::bitget", "bitget instrument: {:?}", self);
+            warn!(target: "cex-exchanges::bitget", "normalized instrument: {:?}", other);
+        }
+
+        equals
+    }
+}
@@ -0,0 +1,5 @@
+mod currencies;
+pub use currencies::*;
+
+mod instruments;
+pub use instruments::*;
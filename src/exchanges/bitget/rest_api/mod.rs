@@ -0,0 +1,22 @@
+mod endpoints;
+pub use endpoints::*;
+
+mod response;
+pub use response::*;
+use serde_json::Value;
+
+/// the success code of every response
+const BITGET_SUCCESS_CODE: &str = "00000";
+
+/// the `data` of a response, every response is wrapped in
+/// `{"code": "00000", "msg": "success", "requestTime": .., "data": ..}`
+pub(crate) fn bitget_data(val: Value) -> eyre::Result<Value> {
+    let code = val.get("code").and_then(|c| c.as_str());
+    if code != Some(BITGET_SUCCESS_CODE) {
+        return Err(eyre::ErrReport::msg(format!("bitget returned the error: {:?}", val)))
+    }
+
+    val.get("data")
+        .cloned()
+        .ok_or(eyre::ErrReport::msg(format!("could not get field 'data' in {:?}", val)))
+}
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use super::{BitgetAllCurrencies, BitgetAllInstruments, BitgetCurrency, BitgetInstrument};
+use crate::normalized::rest_api::NormalizedRestApiDataTypes;
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum BitgetRestApiResponse {
+    Currencies(BitgetAllCurrencies),
+    Instruments(BitgetAllInstruments)
+}
+
+impl BitgetRestApiResponse {
+    pub fn normalize(self) -> NormalizedRestApiDataTypes {
+        match self {
+            BitgetRestApiResponse::Currencies(v) => NormalizedRestApiDataTypes::AllCurrencies(v.normalize()),
+            BitgetRestApiResponse::Instruments(v) => NormalizedRestApiDataTypes::AllInstruments(v.normalize())
+        }
+    }
+
+    pub fn take_currencies(self) -> Option<Vec<BitgetCurrency>> {
+        match self {
+            BitgetRestApiResponse::Currencies(val) => Some(val.currencies),
+            _ => None
+        }
+    }
+
+    pub fn take_instruments(self, active_only: bool) -> Option<Vec<BitgetInstrument>> {
+        let instruments = match self {
+            BitgetRestApiResponse::Instruments(val) => val.instruments,
+            _ => return None
+        };
+
+        if active_only {
+            Some(
+                instruments
+                    .into_iter()
+                    .filter(|instr| instr.is_active())
+                    .collect::<Vec<_>>()
+            )
+        } else {
+            Some(instruments)
+        }
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for BitgetRestApiResponse {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match self {
+            BitgetRestApiResponse::Currencies(vals) => vals == other,
+            BitgetRestApiResponse::Instruments(vals) => vals == other
+        }
+    }
+}
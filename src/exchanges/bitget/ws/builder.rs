@@ -0,0 +1,147 @@
+use super::{
+    channels::{BitgetWsChannel, BitgetWsChannelKind},
+    BitgetSubscription
+};
+use crate::{
+    bitget::Bitget,
+    clients::{rest_api::ExchangeApi, ws::MutliWsStreamBuilder},
+    normalized::ws::NormalizedWsChannels
+};
+
+/// There is a limit of 100 connections per IP.
+const MAX_BITGET_STREAMS: usize = 50;
+/// A connection can subscribe to 1000 channels, but fewer than 50 keeps it
+/// stable.
+const MAX_BITGET_WS_CONNS_PER_STREAM: usize = 50;
+
+#[derive(Debug, Clone, Default)]
+pub struct BitgetWsBuilder {
+    pub channels: Vec<BitgetWsChannel>
+}
+
+impl BitgetWsBuilder {
+    /// adds a channel to the builder
+    pub fn add_channel(mut self, channel: BitgetWsChannel) -> Self {
+        self.channels.push(channel);
+        self
+    }
+
+    /// builds a single ws instance of [Bitget], handling all channels on 1
+    /// stream
+    pub fn build_single(self) -> Bitget {
+        let mut sub = BitgetSubscription::new();
+        self.channels.into_iter().for_each(|c| sub.add_channel(c));
+
+        Bitget::new_ws_subscription(sub)
+    }
+
+    /// builds many ws instances of the [Bitget] as the inner streams of
+    /// [MutliWsStreamBuilder], splitting the channels into different streams,
+    /// each with size # channels / `MAX_BITGET_STREAMS` (50),
+    ///
+    /// WARNING: too many channels may break the stream
+    pub fn build_many_distributed(self) -> eyre::Result<MutliWsStreamBuilder<Bitget>> {
+        let stream_size = if self.channels.len() <= MAX_BITGET_STREAMS { 1 } else { self.channels.len() / MAX_BITGET_STREAMS };
+
+        let chunks = self.channels.chunks(stream_size).collect::<Vec<_>>();
+
+        let split_exchange = chunks
+            .into_iter()
+            .map(|chk| {
+                let mut subscription = BitgetSubscription::new();
+                chk.iter()
+                    .for_each(|ch| subscription.add_channel(ch.clone()));
+
+                Bitget::new_ws_subscription(subscription)
+            })
+            .collect();
+
+        Ok(MutliWsStreamBuilder::new(split_exchange))
+    }
+
+    /// builds many ws instances of the [Bitget] as the inner streams of
+    /// [MutliWsStreamBuilder], splitting the channels into different streams,
+    /// each of size 50
+    pub fn build_many_packed(self, connections_per_stream: Option<usize>) -> eyre::Result<MutliWsStreamBuilder<Bitget>> {
+        let chunks = self
+            .channels
+            .chunks(connections_per_stream.unwrap_or(MAX_BITGET_WS_CONNS_PER_STREAM))
+            .collect::<Vec<_>>();
+
+        let split_exchange = chunks
+            .into_iter()
+            .map(|chk| {
+                let mut subscription = BitgetSubscription::new();
+                chk.iter()
+                    .for_each(|ch| subscription.add_channel(ch.clone()));
+
+                Bitget::new_ws_subscription(subscription)
+            })
+            .collect();
+
+        Ok(MutliWsStreamBuilder::new(split_exchange))
+    }
+
+    /// builds a mutlistream channel from all active instruments
+    pub async fn build_from_all_instruments(
+        channels: &[BitgetWsChannelKind],
+        connections_per_stream: Option<usize>
+    ) -> eyre::Result<MutliWsStreamBuilder<Bitget>> {
+        let this = Self::build_from_all_instruments_util(channels, connections_per_stream).await?;
+
+        let all_streams = this
+            .channels
+            .into_iter()
+            .map(|ch| {
+                let mut subscription = BitgetSubscription::new();
+                subscription.add_channel(ch);
+
+                Bitget::new_ws_subscription(subscription)
+            })
+            .collect::<Vec<_>>();
+
+        Ok(MutliWsStreamBuilder::new(all_streams))
+    }
+
+    async fn build_from_all_instruments_util(channels: &[BitgetWsChannelKind], connections_per_stream: Option<usize>) -> eyre::Result<Self> {
+        let mut this = Self::default();
+
+        let all_symbols = ExchangeApi::new()
+            .all_instruments::<Bitget>()
+            .await?
+            .take_bitget_instruments(true)
+            .unwrap()
+            .into_iter()
+            .map(|val| val.symbol)
+            .collect::<Vec<_>>();
+
+        let chunks = all_symbols.chunks(connections_per_stream.unwrap_or(MAX_BITGET_WS_CONNS_PER_STREAM));
+
+        chunks.into_iter().for_each(|chk| {
+            let all_channels = channels
+                .iter()
+                .map(|ch| match ch {
+                    BitgetWsChannelKind::Trade => BitgetWsChannel::Trade(chk.to_vec()),
+                    BitgetWsChannelKind::BookTicker => BitgetWsChannel::BookTicker(chk.to_vec())
+                })
+                .collect::<Vec<_>>();
+
+            this.channels.extend(all_channels);
+        });
+
+        Ok(this)
+    }
+
+    /// makes the builder from the normalized builder's map
+    pub(crate) fn make_from_normalized_map(map: Vec<NormalizedWsChannels>) -> eyre::Result<Self> {
+        let mut this = Self::default();
+
+        map.into_iter().try_for_each(|channel| {
+            let this_channel: BitgetWsChannel = channel.try_into()?;
+            this = this.clone().add_channel(this_channel);
+            Ok(()) as eyre::Result<()>
+        })?;
+
+        Ok(this)
+    }
+}
@@ -0,0 +1,116 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use tracing::warn;
+
+use super::BitgetChannelArg;
+use crate::{
+    clients::ws::received_at,
+    exchanges::{bitget::pairs::BitgetTradingPair, normalized::types::NormalizedQuote},
+    normalized::types::NormalizedNumber,
+    CexExchange
+};
+
+/// the `books1` channel, a snapshot of the best bid & ask on every change
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct BitgetBookTicker {
+    pub action: String,
+    pub arg:    BitgetChannelArg,
+    pub data:   Vec<BitgetBookTickerData>,
+    pub ts:     u64
+}
+
+impl BitgetBookTicker {
+    pub fn normalize(self) -> Vec<NormalizedQuote> {
+        let pair = self.arg.inst_id;
+        self.data
+            .into_iter()
+            .filter_map(|inner| inner.normalize(&pair))
+            .collect()
+    }
+}
+
+impl PartialEq<Vec<NormalizedQuote>> for BitgetBookTicker {
+    fn eq(&self, other: &Vec<NormalizedQuote>) -> bool {
+        let quotes = self
+            .data
+            .iter()
+            .filter(|inner| !inner.bids.is_empty() && !inner.asks.is_empty())
+            .collect::<Vec<_>>();
+        let equals = quotes.len() == other.len()
+            && quotes
+                .into_iter()
+                .zip(other.iter())
+                .all(|(this, that)| this.eq_with_pair(that, &self.arg.inst_id));
+
+        if !equals {
+            warn!(target: "cex-exchanges::bitget", "bitget book ticker: {:?}", self);
+            warn!(target: "cex-exchanges::bitget", "normalized quotes: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct BitgetBookTickerData {
+    pub bids:        Vec<BitgetBookLevel>,
+    pub asks:        Vec<BitgetBookLevel>,
+    pub checksum:    i64,
+    pub seq:         Option<u64>,
+    #[serde_as(as = "DisplayFromStr")]
+    pub ts:          u64,
+    #[serde(default = "received_at")]
+    pub received_at: DateTime<Utc>
+}
+
+impl BitgetBookTickerData {
+    /// `None` if either side of the book is empty
+    pub fn normalize(self, pair: &BitgetTradingPair) -> Option<NormalizedQuote> {
+        let (bid, ask) = (self.bids.first()?, self.asks.first()?);
+
+        Some(NormalizedQuote {
+            exchange:      CexExchange::Bitget,
+            pair:          pair.normalize(),
+            exchange_time: DateTime::from_timestamp_millis(self.ts as i64),
+            received_at:   self.received_at,
+            ask_amount:    ask.amount,
+            ask_price:     ask.price,
+            bid_amount:    bid.amount,
+            bid_price:     bid.price,
+            quote_id:      self.seq.map(|seq| seq.to_string())
+        })
+    }
+
+    fn eq_with_pair(&self, other: &NormalizedQuote, pair: &BitgetTradingPair) -> bool {
+        let (Some(bid), Some(ask)) = (self.bids.first(), self.asks.first()) else { return false };
+
+        other.exchange == CexExchange::Bitget
+            && other.pair == pair.normalize()
+            && other.exchange_time == DateTime::from_timestamp_millis(self.ts as i64)
+            && other.received_at == self.received_at
+            && other.ask_amount == ask.amount
+            && other.ask_price == ask.price
+            && other.bid_amount == bid.amount
+            && other.bid_price == bid.price
+            && other.quote_id == self.seq.map(|seq| seq.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, PartialOrd)]
+pub struct BitgetBookLevel {
+    pub price:  NormalizedNumber,
+    pub amount: NormalizedNumber
+}
+
+impl<'de> Deserialize<'de> for BitgetBookLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        let (price, amount) = <(String, String)>::deserialize(deserializer)?;
+
+        Ok(Self { price: price.parse().map_err(serde::de::Error::custom)?, amount: amount.parse().map_err(serde::de::Error::custom)? })
+    }
+}
@@ -0,0 +1,153 @@
+use std::fmt::Display;
+
+mod trades;
+pub use trades::*;
+
+mod book_ticker;
+pub use book_ticker::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    exchanges::{
+        bitget::pairs::BitgetTradingPair,
+        normalized::{
+            types::{NormalizedTradingPair, RawTradingPair},
+            ws::NormalizedWsChannels
+        }
+    },
+    CexExchange
+};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum BitgetWsChannel {
+    Trade(Vec<BitgetTradingPair>),
+    /// the best bid & ask (`books1`)
+    BookTicker(Vec<BitgetTradingPair>)
+}
+
+impl BitgetWsChannel {
+    /// builds trade channel from a vec of raw trading pairs
+    /// return an error if the symbol is incorrectly formatted
+    pub fn new_trade(pairs: Vec<RawTradingPair>) -> eyre::Result<Self> {
+        let normalized = pairs
+            .into_iter()
+            .map(|pair| pair.get_normalized_pair(CexExchange::Bitget))
+            .collect();
+
+        Self::new_from_kind(normalized, BitgetWsChannel::Trade(Vec::new()))
+    }
+
+    /// builds the book ticker channel from a vec of raw trading
+    /// pairs return an error if the symbol is incorrectly formatted
+    pub fn new_book_ticker(pairs: Vec<RawTradingPair>) -> eyre::Result<Self> {
+        let normalized = pairs
+            .into_iter()
+            .map(|pair| pair.get_normalized_pair(CexExchange::Bitget))
+            .collect();
+
+        Self::new_from_kind(normalized, BitgetWsChannel::BookTicker(Vec::new()))
+    }
+
+    fn new_from_kind(pairs: Vec<NormalizedTradingPair>, kind: BitgetWsChannel) -> eyre::Result<Self> {
+        let pairs = pairs
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+
+        match kind {
+            BitgetWsChannel::Trade(_) => Ok(BitgetWsChannel::Trade(pairs)),
+            BitgetWsChannel::BookTicker(_) => Ok(BitgetWsChannel::BookTicker(pairs))
+        }
+    }
+
+    pub(crate) fn pairs(&self) -> &[BitgetTradingPair] {
+        match self {
+            BitgetWsChannel::Trade(pairs) => pairs,
+            BitgetWsChannel::BookTicker(pairs) => pairs
+        }
+    }
+}
+
+impl Display for BitgetWsChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        BitgetWsChannelKind::from(self).fmt(f)
+    }
+}
+
+impl TryFrom<String> for BitgetWsChannel {
+    type Error = eyre::Report;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.to_lowercase().as_str() {
+            "trade" => Ok(Self::Trade(Vec::new())),
+            "books1" | "book_ticker" => Ok(Self::BookTicker(Vec::new())),
+            _ => Err(eyre::ErrReport::msg(format!("channel is not valid: {value}")))
+        }
+    }
+}
+
+impl TryFrom<NormalizedWsChannels> for BitgetWsChannel {
+    type Error = eyre::ErrReport;
+
+    fn try_from(value: NormalizedWsChannels) -> Result<Self, Self::Error> {
+        match value {
+            NormalizedWsChannels::Trades(pairs) => {
+                let norm_pairs = pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, Self::Error>>()?;
+
+                Ok(BitgetWsChannel::Trade(norm_pairs))
+            }
+
+            NormalizedWsChannels::Quotes(pairs) => {
+                let norm_pairs = pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, Self::Error>>()?;
+
+                Ok(BitgetWsChannel::BookTicker(norm_pairs))
+            }
+
+            NormalizedWsChannels::OrderBook { .. } => Err(eyre::ErrReport::msg("bitget does not support the order book ws channel")),
+
+            NormalizedWsChannels::Candles { .. } => Err(eyre::ErrReport::msg("bitget does not support the candles ws channel")),
+
+            NormalizedWsChannels::Account(_) => Err(eyre::ErrReport::msg("bitget does not support the account ws channel"))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum BitgetWsChannelKind {
+    Trade,
+    BookTicker
+}
+
+impl Display for BitgetWsChannelKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BitgetWsChannelKind::Trade => write!(f, "trade"),
+            BitgetWsChannelKind::BookTicker => write!(f, "books1")
+        }
+    }
+}
+
+impl From<&BitgetWsChannel> for BitgetWsChannelKind {
+    fn from(value: &BitgetWsChannel) -> Self {
+        match value {
+            BitgetWsChannel::Trade(_) => BitgetWsChannelKind::Trade,
+            BitgetWsChannel::BookTicker(_) => BitgetWsChannelKind::BookTicker
+        }
+    }
+}
+
+/// the `arg` of every push, subscription ack & error
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+#[serde(rename_all = "camelCase")]
+pub struct BitgetChannelArg {
+    /// ex: SPOT
+    pub inst_type: String,
+    pub channel:   String,
+    pub inst_id:   BitgetTradingPair
+}
@@ -0,0 +1,96 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use tracing::warn;
+
+use super::BitgetChannelArg;
+use crate::{
+    clients::ws::received_at,
+    exchanges::{
+        bitget::pairs::BitgetTradingPair,
+        normalized::types::{NormalizedTrade, TradeSide}
+    },
+    normalized::types::NormalizedNumber,
+    CexExchange
+};
+
+/// the `trade` channel
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct BitgetTrade {
+    /// 'snapshot' (the recent trades, on subscribing) or 'update'
+    pub action: String,
+    pub arg:    BitgetChannelArg,
+    pub data:   Vec<BitgetTradeData>,
+    pub ts:     u64
+}
+
+impl BitgetTrade {
+    pub fn normalize(self) -> Vec<NormalizedTrade> {
+        let pair = self.arg.inst_id;
+        self.data
+            .into_iter()
+            .map(|inner| inner.normalize(&pair))
+            .collect()
+    }
+}
+
+impl PartialEq<Vec<NormalizedTrade>> for BitgetTrade {
+    fn eq(&self, other: &Vec<NormalizedTrade>) -> bool {
+        let equals = self.data.len() == other.len()
+            && self
+                .data
+                .iter()
+                .zip(other.iter())
+                .all(|(this, that)| this.eq_with_pair(that, &self.arg.inst_id));
+
+        if !equals {
+            warn!(target: "cex-exchanges::bitget", "bitget trade: {:?}", self);
+            warn!(target: "cex-exchanges::bitget", "normalized trades: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+#[serde(rename_all = "camelCase")]
+pub struct BitgetTradeData {
+    #[serde_as(as = "DisplayFromStr")]
+    pub ts:          u64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub price:       NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub size:        NormalizedNumber,
+    /// 'buy' or 'sell'
+    pub side:        String,
+    pub trade_id:    String,
+    #[serde(default = "received_at")]
+    pub received_at: DateTime<Utc>
+}
+
+impl BitgetTradeData {
+    pub fn normalize(self, pair: &BitgetTradingPair) -> NormalizedTrade {
+        NormalizedTrade {
+            exchange:      CexExchange::Bitget,
+            pair:          pair.normalize(),
+            exchange_time: DateTime::from_timestamp_millis(self.ts as i64),
+            received_at:   self.received_at,
+            side:          TradeSide::from_taker_side(&self.side),
+            price:         self.price,
+            amount:        self.size,
+            trade_id:      Some(self.trade_id)
+        }
+    }
+
+    fn eq_with_pair(&self, other: &NormalizedTrade, pair: &BitgetTradingPair) -> bool {
+        other.exchange == CexExchange::Bitget
+            && other.pair == pair.normalize()
+            && other.exchange_time == DateTime::from_timestamp_millis(self.ts as i64)
+            && other.received_at == self.received_at
+            && other.side == TradeSide::from_taker_side(&self.side)
+            && other.price == self.price
+            && other.amount == self.size
+            && other.trade_id.as_ref() == Some(&self.trade_id)
+    }
+}
@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::channels::{BitgetBookTicker, BitgetTrade};
+use crate::{bitget::BitgetTradingPair, clients::ws::CriticalWsMessage, exchanges::normalized::ws::NormalizedWsDataTypes, CexExchange};
+
+/// the error code of a subscription to a channel or pair that doesn't exist
+const BITGET_CHANNEL_NOT_EXIST_CODE: i64 = 30001;
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case", tag = "data")]
+pub enum BitgetWsMessage {
+    Trade(BitgetTrade),
+    BookTicker(BitgetBookTicker),
+    Subscribe(Value),
+    Error { code: i64, error: String, raw_msg: String, bad_pair: Option<BitgetTradingPair> }
+}
+
+impl BitgetWsMessage {
+    fn try_deserialize(value: Value) -> eyre::Result<Self> {
+        if let Some(event) = value.get("event") {
+            let event = event
+                .as_str()
+                .ok_or(eyre::ErrReport::msg("Could not convert 'event' field in Bitget ws message to &str".to_string()))?;
+
+            return match event {
+                "subscribe" | "unsubscribe" => Ok(Self::Subscribe(value)),
+                "error" => {
+                    let code = value
+                        .get("code")
+                        .and_then(|c| c.as_i64())
+                        .unwrap_or_default();
+                    let error = value
+                        .get("msg")
+                        .and_then(|m| m.as_str())
+                        .unwrap_or("unknown error")
+                        .to_string();
+                    let bad_pair = (code == BITGET_CHANNEL_NOT_EXIST_CODE)
+                        .then(|| BitgetTradingPair::parse_for_bad_pair(&error))
+                        .flatten();
+
+                    Ok(Self::Error { code, error, raw_msg: String::new(), bad_pair })
+                }
+                _ => Err(eyre::ErrReport::msg(format!("Event '{event}' cannot be deserialized")))
+            }
+        }
+
+        let channel = value
+            .get("arg")
+            .and_then(|arg| arg.get("channel"))
+            .and_then(|c| c.as_str())
+            .ok_or(eyre::ErrReport::msg("Could not find 'event' or 'arg.channel' field in Bitget ws message".to_string()))?;
+
+        match channel {
+            "trade" => Ok(Self::Trade(serde_json::from_value(value)?)),
+            "books1" => Ok(Self::BookTicker(serde_json::from_value(value)?)),
+            _ => Err(eyre::ErrReport::msg(format!("Channel type '{channel}' cannot be deserialized")))
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for BitgetWsMessage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        let map = Value::deserialize(deserializer)?;
+
+        Self::try_deserialize(map).map_err(serde::de::Error::custom)
+    }
+}
+
+impl BitgetWsMessage {
+    pub fn normalize(self) -> NormalizedWsDataTypes {
+        match self {
+            BitgetWsMessage::Trade(v) => NormalizedWsDataTypes::Trades(v.normalize()),
+            BitgetWsMessage::BookTicker(v) => NormalizedWsDataTypes::Quotes(v.normalize()),
+            BitgetWsMessage::Subscribe(v) => {
+                NormalizedWsDataTypes::Other { exchange: CexExchange::Bitget, kind: "Subscribe".to_string(), value: format!("{:?}", v) }
+            }
+            BitgetWsMessage::Error { code, error, raw_msg, bad_pair } => {
+                if let Some(bp) = bad_pair {
+                    NormalizedWsDataTypes::RemovedPair {
+                        exchange:    CexExchange::Bitget,
+                        bad_pair:    bp.normalize(),
+                        raw_message: format!("{code} - {error} - {raw_msg}")
+                    }
+                } else {
+                    NormalizedWsDataTypes::Other { exchange: CexExchange::Bitget, kind: format!("{code} - {error}"), value: raw_msg }
+                }
+            }
+        }
+    }
+}
+
+impl PartialEq<NormalizedWsDataTypes> for BitgetWsMessage {
+    fn eq(&self, other: &NormalizedWsDataTypes) -> bool {
+        match (self, other) {
+            (BitgetWsMessage::Trade(this), NormalizedWsDataTypes::Trades(that)) => this == that,
+            (BitgetWsMessage::BookTicker(this), NormalizedWsDataTypes::Quotes(that)) => this == that,
+            (BitgetWsMessage::Subscribe(_), NormalizedWsDataTypes::Other { .. }) => true,
+            (BitgetWsMessage::Error { .. }, NormalizedWsDataTypes::Other { .. }) => true,
+            (BitgetWsMessage::Error { .. }, NormalizedWsDataTypes::RemovedPair { .. }) => true,
+            _ => false
+        }
+    }
+}
+
+impl CriticalWsMessage for BitgetWsMessage {
+    fn make_critical(&mut self, msg: String) {
+        if let BitgetWsMessage::Error { raw_msg, .. } = self {
+            *raw_msg = msg;
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use std::collections::HashSet;
+
+use serde::Serialize;
+
+use super::channels::{BitgetChannelArg, BitgetWsChannel, BitgetWsChannelKind};
+use crate::bitget::BitgetTradingPair;
+
+/// the instrument type of the spot channels
+const BITGET_SPOT_INST_TYPE: &str = "SPOT";
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct BitgetSubscription {
+    op:   String,
+    args: Vec<BitgetChannelArg>
+}
+
+impl BitgetSubscription {
+    pub fn new() -> Self {
+        BitgetSubscription { op: "subscribe".to_string(), args: Vec::new() }
+    }
+
+    pub fn add_channel(&mut self, channel: BitgetWsChannel) {
+        self.args.extend(Self::channel_args(channel));
+    }
+
+    /// adds the pairs of the channel, returning the request to subscribe to
+    /// the new ones
+    pub(crate) fn subscribe(&mut self, channel: BitgetWsChannel) -> Option<Self> {
+        let new = Self::channel_args(channel)
+            .into_iter()
+            .filter(|p| !self.args.contains(p))
+            .collect::<Vec<_>>();
+        self.args.extend(new.clone());
+
+        (!new.is_empty()).then(|| BitgetSubscription { op: "subscribe".to_string(), args: new })
+    }
+
+    /// removes the pairs of the channel, returning the request to
+    /// unsubscribe from the ones that were subscribed to
+    pub(crate) fn unsubscribe(&mut self, channel: BitgetWsChannel) -> Option<Self> {
+        let removed = Self::channel_args(channel)
+            .into_iter()
+            .filter(|p| self.args.contains(p))
+            .collect::<Vec<_>>();
+        self.args.retain(|p| !removed.contains(p));
+
+        (!removed.is_empty()).then(|| BitgetSubscription { op: "unsubscribe".to_string(), args: removed })
+    }
+
+    /// the request to unsubscribe from every channel
+    pub(crate) fn unsubscribe_all(&self) -> Option<Self> {
+        (!self.args.is_empty()).then(|| BitgetSubscription { op: "unsubscribe".to_string(), args: self.args.clone() })
+    }
+
+    fn channel_args(channel: BitgetWsChannel) -> Vec<BitgetChannelArg> {
+        let kind = BitgetWsChannelKind::from(&channel);
+        let pairs = channel.pairs().iter().cloned().collect::<HashSet<_>>();
+
+        pairs
+            .into_iter()
+            .map(|pair| BitgetChannelArg { inst_type: BITGET_SPOT_INST_TYPE.to_string(), channel: kind.to_string(), inst_id: pair })
+            .collect()
+    }
+
+    pub fn remove_pair(&mut self, pair: &BitgetTradingPair) -> bool {
+        self.args.retain(|arg| &arg.inst_id != pair);
+
+        self.args.is_empty()
+    }
+}
@@ -0,0 +1,133 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{exchanges::normalized::types::NormalizedTradingPair, CexExchange};
+
+/// channel prefixes of the ws, followed by the pair (ex: live_trades_btcusd)
+const CHANNEL_PREFIXES: [&str; 2] = ["live_trades_", "order_book_"];
+
+/// the lowercase url symbol, ex: btcusd
+#[derive(Debug, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct BitstampTradingPair(pub(crate) String);
+
+impl BitstampTradingPair {
+    pub fn new_checked(s: &str) -> eyre::Result<Self> {
+        s.to_string().try_into()
+    }
+
+    pub fn is_valid(s: &str) -> bool {
+        !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric())
+    }
+
+    pub fn normalize(&self) -> NormalizedTradingPair {
+        NormalizedTradingPair::new_no_base_quote(CexExchange::Bitstamp, &self.0)
+    }
+
+    pub fn normalize_with(&self, base: &str, quote: &str) -> NormalizedTradingPair {
+        NormalizedTradingPair::new_base_quote(CexExchange::Bitstamp, base, quote, None, None)
+    }
+
+    /// the pair of a ws channel name, ex: live_trades_btcusd -> btcusd
+    pub fn from_channel(channel: &str) -> Option<Self> {
+        CHANNEL_PREFIXES
+            .iter()
+            .find_map(|prefix| channel.strip_prefix(prefix))
+            .and_then(|pair| Self::try_from(pair).ok())
+    }
+
+    pub fn parse_for_bad_pair(value: &str) -> Option<Self> {
+        let value = value.replace(['\'', '"', ',', ':'], " ");
+        value.split(' ').find_map(Self::from_channel)
+    }
+}
+
+impl Display for BitstampTradingPair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Serialize for BitstampTradingPair {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for BitstampTradingPair {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        let s = String::deserialize(deserializer)?;
+
+        Ok(BitstampTradingPair(s))
+    }
+}
+
+impl TryFrom<NormalizedTradingPair> for BitstampTradingPair {
+    type Error = eyre::Report;
+
+    fn try_from(value: NormalizedTradingPair) -> Result<Self, Self::Error> {
+        if let Some((base, quote)) = value.base_quote() {
+            return Ok(BitstampTradingPair(format!("{}{}", base, quote).to_lowercase()))
+        }
+
+        if let (Some(raw_pair), delim) = (value.pair(), value.delimiter()) {
+            if let Ok(v) = Self::new_checked(raw_pair) {
+                return Ok(v)
+            }
+
+            if let Some(d) = delim {
+                let mut split = raw_pair.split(d);
+                return Ok(BitstampTradingPair(format!("{}{}", split.next().unwrap(), split.next().unwrap()).to_lowercase()));
+            }
+
+            let new_str = raw_pair.replace(['_', '-', '/'], "");
+            if let Ok(this) = Self::new_checked(&new_str) {
+                return Ok(this)
+            }
+
+            return Err(eyre::ErrReport::msg(format!("INVALID Bitstamp trading pair '{raw_pair}'")))
+        }
+
+        Err(eyre::ErrReport::msg(format!("INVALID Bitstamp trading pair '{:?}'", value)))
+    }
+}
+
+impl TryFrom<&str> for BitstampTradingPair {
+    type Error = eyre::Report;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if Self::is_valid(value) {
+            Ok(BitstampTradingPair(value.to_lowercase()))
+        } else {
+            Err(eyre::ErrReport::msg(format!("INVALID Bitstamp trading pair '{value}' is not alphanumeric")))
+        }
+    }
+}
+
+impl TryFrom<String> for BitstampTradingPair {
+    type Error = eyre::Report;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.as_str().try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_for_bad_pair() {
+        let test_str = r#"{"event":"bts:error","channel":"live_trades_btcxyz","data":{"code":null,"message":"Bad subscription string."}}"#;
+
+        let calculated = BitstampTradingPair::parse_for_bad_pair(test_str);
+
+        assert_eq!(calculated, Some(BitstampTradingPair("btcxyz".to_string())))
+    }
+}
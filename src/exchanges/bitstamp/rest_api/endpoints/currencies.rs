@@ -0,0 +1,135 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::{
+    normalized::{
+        rest_api::NormalizedRestApiDataTypes,
+        types::{BlockchainCurrency, NormalizedCurrency}
+    },
+    CexExchange
+};
+
+#[derive(Debug, Clone, Serialize, PartialEq, PartialOrd)]
+pub struct BitstampAllCurrencies {
+    pub currencies: Vec<BitstampCurrency>
+}
+
+impl BitstampAllCurrencies {
+    pub fn normalize(self) -> Vec<NormalizedCurrency> {
+        self.currencies
+            .into_iter()
+            .map(BitstampCurrency::normalize)
+            .collect()
+    }
+}
+
+impl<'de> Deserialize<'de> for BitstampAllCurrencies {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        let currencies = Vec::<BitstampCurrency>::deserialize(deserializer)?;
+
+        Ok(BitstampAllCurrencies { currencies })
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for BitstampAllCurrencies {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match other {
+            NormalizedRestApiDataTypes::AllCurrencies(other_currs) => {
+                let this_currencies = self
+                    .currencies
+                    .iter()
+                    .map(|curr| (&curr.name, &curr.currency))
+                    .collect::<HashSet<_>>();
+
+                self.currencies.len() == other_currs.len()
+                    && other_currs
+                        .iter()
+                        .all(|curr| this_currencies.contains(&(&curr.name, &curr.symbol)))
+            }
+            _ => false
+        }
+    }
+}
+
+/// a currency of `/api/v2/currencies/`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct BitstampCurrency {
+    /// ex: Bitcoin
+    pub name:       String,
+    /// ex: BTC
+    pub currency:   String,
+    /// 'crypto' or 'fiat'
+    #[serde(rename = "type")]
+    pub kind:       String,
+    pub decimals:   u32,
+    /// 'Enabled' or 'Disabled'
+    #[serde(default)]
+    pub deposit:    Option<String>,
+    /// 'Enabled' or 'Disabled'
+    #[serde(default)]
+    pub withdrawal: Option<String>,
+    #[serde(default)]
+    pub networks:   Vec<BitstampCurrencyNetwork>
+}
+
+impl BitstampCurrency {
+    /// 'enabled' if both deposits & withdrawals are, otherwise 'disabled'
+    pub fn status(&self) -> String {
+        let is_enabled = |s: &Option<String>| s.as_deref() == Some("Enabled");
+
+        if is_enabled(&self.deposit) && is_enabled(&self.withdrawal) {
+            "enabled".to_string()
+        } else {
+            "disabled".to_string()
+        }
+    }
+
+    pub fn normalize(self) -> NormalizedCurrency {
+        NormalizedCurrency {
+            exchange:     CexExchange::Bitstamp,
+            status:       self.status(),
+            symbol:       self.currency,
+            name:         self.name,
+            display_name: None,
+            blockchains:  self.networks.into_iter().map(Into::into).collect()
+        }
+    }
+}
+
+impl PartialEq<NormalizedCurrency> for BitstampCurrency {
+    fn eq(&self, other: &NormalizedCurrency) -> bool {
+        let equals = other.exchange == CexExchange::Bitstamp
+            && other.symbol == self.currency
+            && other.name == self.name
+            && other.display_name.is_none()
+            && other.status == self.status()
+            && self
+                .networks
+                .iter()
+                .all(|c| other.blockchains.contains(&c.clone().into()));
+
+        if !equals {
+            warn!(target: "cex-exchanges::bitstamp", "bitstamp currency: {:?}", self);
+            warn!(target: "cex-exchanges::bitstamp", "normalized currency: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct BitstampCurrencyNetwork {
+    /// ex: ethereum
+    pub network: String
+}
+
+impl From<BitstampCurrencyNetwork> for BlockchainCurrency {
+    fn from(val: BitstampCurrencyNetwork) -> Self {
+        BlockchainCurrency { blockchain: val.network.parse().unwrap(), address: None, is_wrapped: false, wrapped_currency: None }
+    }
+}
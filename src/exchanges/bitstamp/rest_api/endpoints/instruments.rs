@@ -0,0 +1,126 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::{
+    bitstamp::BitstampTradingPair,
+    normalized::{
+        rest_api::NormalizedRestApiDataTypes,
+        types::{NormalizedInstrument, NormalizedTradingType}
+    },
+    CexExchange
+};
+
+#[derive(Debug, Clone, Serialize, PartialEq, PartialOrd)]
+pub struct BitstampAllInstruments {
+    pub instruments: Vec<BitstampInstrument>
+}
+
+impl BitstampAllInstruments {
+    pub fn normalize(self) -> Vec<NormalizedInstrument> {
+        self.instruments
+            .into_iter()
+            .map(BitstampInstrument::normalize)
+            .collect()
+    }
+}
+
+impl<'de> Deserialize<'de> for BitstampAllInstruments {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        let instruments = Vec::<BitstampInstrument>::deserialize(deserializer)?;
+
+        Ok(BitstampAllInstruments { instruments })
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for BitstampAllInstruments {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match other {
+            NormalizedRestApiDataTypes::AllInstruments(other_instrs) => {
+                let this_instruments = self
+                    .instruments
+                    .iter()
+                    .map(|instr| {
+                        let (base, quote) = instr.base_quote();
+                        (base.to_string(), quote.to_string(), instr.url_symbol.normalize_with(base, quote))
+                    })
+                    .collect::<HashSet<_>>();
+
+                self.instruments.len() == other_instrs.len()
+                    && other_instrs.iter().all(|instr| {
+                        this_instruments.contains(&(instr.base_asset_symbol.clone(), instr.quote_asset_symbol.clone(), instr.trading_pair.clone()))
+                    })
+            }
+            _ => false
+        }
+    }
+}
+
+/// a pair of `/api/v2/trading-pairs-info/`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct BitstampInstrument {
+    /// ex: BTC/USD
+    pub name:             String,
+    /// ex: btcusd
+    pub url_symbol:       BitstampTradingPair,
+    pub base_decimals:    u32,
+    pub counter_decimals: u32,
+    /// ex: 10 USD
+    pub minimum_order:    String,
+    /// 'Enabled' or 'Disabled'
+    pub trading:          String,
+    pub description:      String
+}
+
+impl BitstampInstrument {
+    pub fn is_active(&self) -> bool {
+        &self.trading == "Enabled"
+    }
+
+    /// the (base, quote) of the `name`
+    pub fn base_quote(&self) -> (&str, &str) {
+        self.name.split_once('/').unwrap_or((&self.name, ""))
+    }
+
+    pub fn normalize(self) -> NormalizedInstrument {
+        let (base, quote) = self.base_quote();
+
+        NormalizedInstrument {
+            exchange:           CexExchange::Bitstamp,
+            trading_pair:       self.url_symbol.normalize_with(base, quote),
+            trading_type:       NormalizedTradingType::Spot,
+            base_asset_symbol:  base.to_string(),
+            quote_asset_symbol: quote.to_string(),
+            active:             self.is_active(),
+            futures_expiry:     None,
+            option_strike:      None,
+            option_type:        None
+        }
+    }
+}
+
+impl PartialEq<NormalizedInstrument> for BitstampInstrument {
+    fn eq(&self, other: &NormalizedInstrument) -> bool {
+        let (base, quote) = self.base_quote();
+        let equals = other.exchange == CexExchange::Bitstamp
+            && other.trading_pair == self.url_symbol.normalize_with(base, quote)
+            && other.trading_type == NormalizedTradingType::Spot
+            && other.base_asset_symbol == base
+            && other.quote_asset_symbol == quote
+            && other.active == self.is_active()
+            && other.futures_expiry.is_none()
+            && other.option_strike.is_none()
+            && other.option_type.is_none();
+
+        if !equals {
+            warn!(target: "cex-exchanges::bitstamp", "bitstamp instrument: {:?}", self);
+            warn!(target: "cex-exchanges::bitstamp", "normalized instrument: {:?}", other);
+        }
+
+        equals
+    }
+}
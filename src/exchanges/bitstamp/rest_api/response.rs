@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use super::{BitstampAllCurrencies, BitstampAllInstruments, BitstampCurrency, BitstampInstrument};
+use crate::normalized::rest_api::NormalizedRestApiDataTypes;
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum BitstampRestApiResponse {
+    Currencies(BitstampAllCurrencies),
+    Instruments(BitstampAllInstruments)
+}
+
+impl BitstampRestApiResponse {
+    pub fn normalize(self) -> NormalizedRestApiDataTypes {
+        match self {
+            BitstampRestApiResponse::Currencies(v) => NormalizedRestApiDataTypes::AllCurrencies(v.normalize()),
+            BitstampRestApiResponse::Instruments(v) => NormalizedRestApiDataTypes::AllInstruments(v.normalize())
+        }
+    }
+
+    pub fn take_currencies(self) -> Option<Vec<BitstampCurrency>> {
+        match self {
+            BitstampRestApiResponse::Currencies(val) => Some(val.currencies),
+            _ => None
+        }
+    }
+
+    pub fn take_instruments(self, active_only: bool) -> Option<Vec<BitstampInstrument>> {
+        let instruments = match self {
+            BitstampRestApiResponse::Instruments(val) => val.instruments,
+            _ => return None
+        };
+
+        if active_only {
+            Some(
+                instruments
+                    .into_iter()
+                    .filter(|instr| instr.is_active())
+                    .collect::<Vec<_>>()
+            )
+        } else {
+            Some(instruments)
+        }
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for BitstampRestApiResponse {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match self {
+            BitstampRestApiResponse::Currencies(vals) => vals == other,
+            BitstampRestApiResponse::Instruments(vals) => vals == other
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use super::{
+    channels::{BitstampWsChannel, BitstampWsChannelKind},
+    BitstampSubscription
+};
+use crate::{
+    bitstamp::Bitstamp,
+    clients::{rest_api::ExchangeApi, ws::MutliWsStreamBuilder},
+    normalized::ws::NormalizedWsChannels
+};
+
+/// max number of streams the channels are distributed over
+const MAX_BITSTAMP_STREAMS: usize = 8;
+/// max number of channels subscribed to on a single connection
+const MAX_BITSTAMP_WS_CONNS_PER_STREAM: usize = 50;
+
+#[derive(Debug, Clone, Default)]
+pub struct BitstampWsBuilder {
+    pub channels: Vec<BitstampWsChannel>
+}
+
+impl BitstampWsBuilder {
+    /// adds a channel to the builder
+    pub fn add_channel(mut self, channel: BitstampWsChannel) -> Self {
+        self.channels.push(channel);
+        self
+    }
+
+    /// builds a single ws instance of [Bitstamp], handling all channels on 1
+    /// stream
+    pub fn build_single(self) -> Bitstamp {
+        let mut sub = BitstampSubscription::new();
+        self.channels.into_iter().for_each(|c| sub.add_channel(c));
+
+        Bitstamp::new_ws_subscription(sub)
+    }
+
+    /// builds many ws instances of the [Bitstamp] as the inner streams of
+    /// [MutliWsStreamBuilder], splitting the channels into different streams,
+    /// each with size # channels / `MAX_BITSTAMP_STREAMS` (8),
+    ///
+    /// WARNING: too many channels may break the stream
+    pub fn build_many_distributed(self) -> eyre::Result<MutliWsStreamBuilder<Bitstamp>> {
+        let stream_size = if self.channels.len() <= MAX_BITSTAMP_STREAMS { 1 } else { self.channels.len() / MAX_BITSTAMP_STREAMS };
+
+        let chunks = self.channels.chunks(stream_size).collect::<Vec<_>>();
+
+        let split_exchange = chunks
+            .into_iter()
+            .map(|chk| {
+                let mut subscription = BitstampSubscription::new();
+                chk.iter()
+                    .for_each(|ch| subscription.add_channel(ch.clone()));
+
+                Bitstamp::new_ws_subscription(subscription)
+            })
+            .collect();
+
+        Ok(MutliWsStreamBuilder::new(split_exchange))
+    }
+
+    /// builds many ws instances of the [Bitstamp] as the inner streams of
+    /// [MutliWsStreamBuilder], splitting the channels into different streams,
+    /// each of size 50
+    pub fn build_many_packed(self, connections_per_stream: Option<usize>) -> eyre::Result<MutliWsStreamBuilder<Bitstamp>> {
+        let chunks = self
+            .channels
+            .chunks(connections_per_stream.unwrap_or(MAX_BITSTAMP_WS_CONNS_PER_STREAM))
+            .collect::<Vec<_>>();
+
+        let split_exchange = chunks
+            .into_iter()
+            .map(|chk| {
+                let mut subscription = BitstampSubscription::new();
+                chk.iter()
+                    .for_each(|ch| subscription.add_channel(ch.clone()));
+
+                Bitstamp::new_ws_subscription(subscription)
+            })
+            .collect();
+
+        Ok(MutliWsStreamBuilder::new(split_exchange))
+    }
+
+    /// builds a mutlistream channel from all active instruments
+    pub async fn build_from_all_instruments(
+        channels: &[BitstampWsChannelKind],
+        connections_per_stream: Option<usize>
+    ) -> eyre::Result<MutliWsStreamBuilder<Bitstamp>> {
+        let this = Self::build_from_all_instruments_util(channels, connections_per_stream).await?;
+
+        let all_streams = this
+            .channels
+            .into_iter()
+            .map(|ch| {
+                let mut subscription = BitstampSubscription::new();
+                subscription.add_channel(ch);
+
+                Bitstamp::new_ws_subscription(subscription)
+            })
+            .collect::<Vec<_>>();
+
+        Ok(MutliWsStreamBuilder::new(all_streams))
+    }
+
+    async fn build_from_all_instruments_util(channels: &[BitstampWsChannelKind], connections_per_stream: Option<usize>) -> eyre::Result<Self> {
+        let mut this = Self::default();
+
+        let all_symbols = ExchangeApi::new()
+            .all_instruments::<Bitstamp>()
+            .await?
+            .take_bitstamp_instruments(true)
+            .unwrap()
+            .into_iter()
+            .map(|val| val.url_symbol)
+            .collect::<Vec<_>>();
+
+        let chunks = all_symbols.chunks(connections_per_stream.unwrap_or(MAX_BITSTAMP_WS_CONNS_PER_STREAM));
+
+        chunks.into_iter().for_each(|chk| {
+            let all_channels = channels
+                .iter()
+                .map(|ch| match ch {
+                    BitstampWsChannelKind::LiveTrades => BitstampWsChannel::LiveTrades(chk.to_vec()),
+                    BitstampWsChannelKind::OrderBook => BitstampWsChannel::OrderBook(chk.to_vec())
+                })
+                .collect::<Vec<_>>();
+
+            this.channels.extend(all_channels);
+        });
+
+        Ok(this)
+    }
+
+    /// makes the builder from the normalized builder's map
+    pub(crate) fn make_from_normalized_map(map: Vec<NormalizedWsChannels>) -> eyre::Result<Self> {
+        let mut this = Self::default();
+
+        map.into_iter().try_for_each(|channel| {
+            let this_channel: BitstampWsChannel = channel.try_into()?;
+            this = this.clone().add_channel(this_channel);
+            Ok(()) as eyre::Result<()>
+        })?;
+
+        Ok(this)
+    }
+}
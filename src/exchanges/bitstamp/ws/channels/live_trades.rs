@@ -0,0 +1,92 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use tracing::warn;
+
+use crate::{
+    clients::ws::received_at,
+    exchanges::{
+        bitstamp::pairs::BitstampTradingPair,
+        normalized::types::{NormalizedTrade, TradeSide}
+    },
+    normalized::types::NormalizedNumber,
+    CexExchange
+};
+
+/// a `live_trades_*` trade, the pair is taken from the channel name
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct BitstampTrade {
+    pub pair:        BitstampTradingPair,
+    pub data:        BitstampTradeData,
+    #[serde(default = "received_at")]
+    pub received_at: DateTime<Utc>
+}
+
+impl BitstampTrade {
+    pub fn normalize(self) -> NormalizedTrade {
+        NormalizedTrade {
+            exchange:      CexExchange::Bitstamp,
+            pair:          self.pair.normalize(),
+            exchange_time: Some(self.data.time()),
+            received_at:   self.received_at,
+            side:          self.data.side(),
+            price:         self.data.price,
+            amount:        self.data.amount,
+            trade_id:      Some(self.data.id.to_string())
+        }
+    }
+}
+
+impl PartialEq<NormalizedTrade> for BitstampTrade {
+    fn eq(&self, other: &NormalizedTrade) -> bool {
+        let equals = other.exchange == CexExchange::Bitstamp
+            && other.pair == self.pair.normalize()
+            && other.exchange_time == Some(self.data.time())
+            && other.received_at == self.received_at
+            && other.side == self.data.side()
+            && other.price == self.data.price
+            && other.amount == self.data.amount
+            && other.trade_id == Some(self.data.id.to_string());
+
+        if !equals {
+            warn!(target: "cex-exchanges::bitstamp", "bitstamp trade: {:?}", self);
+            warn!(target: "cex-exchanges::bitstamp", "normalized trade: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct BitstampTradeData {
+    pub id:             u64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub microtimestamp: i64,
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "amount_str")]
+    pub amount:         NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "price_str")]
+    pub price:          NormalizedNumber,
+    /// 0 - buy, 1 - sell
+    #[serde(rename = "type")]
+    pub kind:           u8,
+    pub buy_order_id:   u64,
+    pub sell_order_id:  u64
+}
+
+impl BitstampTradeData {
+    fn time(&self) -> DateTime<Utc> {
+        DateTime::<Utc>::from_timestamp_micros(self.microtimestamp).unwrap()
+    }
+
+    /// the type is the taker's side
+    fn side(&self) -> TradeSide {
+        match self.kind {
+            0 => TradeSide::Buy,
+            1 => TradeSide::Sell,
+            _ => TradeSide::Unknown
+        }
+    }
+}
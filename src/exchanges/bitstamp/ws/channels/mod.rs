@@ -0,0 +1,143 @@
+use std::fmt::Display;
+
+mod live_trades;
+pub use live_trades::*;
+
+mod order_book;
+pub use order_book::*;
+
+use crate::{
+    exchanges::{
+        bitstamp::pairs::BitstampTradingPair,
+        normalized::{
+            types::{NormalizedTradingPair, RawTradingPair},
+            ws::NormalizedWsChannels
+        }
+    },
+    CexExchange
+};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum BitstampWsChannel {
+    LiveTrades(Vec<BitstampTradingPair>),
+    /// the top [BITSTAMP_BOOK_DEPTH] levels of the book, as a snapshot on
+    /// each change
+    OrderBook(Vec<BitstampTradingPair>)
+}
+
+impl BitstampWsChannel {
+    /// builds live trades channel from a vec of raw trading pairs
+    /// return an error if the symbol is incorrectly formatted
+    pub fn new_live_trades(pairs: Vec<RawTradingPair>) -> eyre::Result<Self> {
+        let normalized = pairs
+            .into_iter()
+            .map(|pair| pair.get_normalized_pair(CexExchange::Bitstamp))
+            .collect();
+
+        Self::new_from_kind(normalized, BitstampWsChannel::LiveTrades(Vec::new()))
+    }
+
+    /// builds order book channel from a vec of raw trading pairs
+    /// return an error if the symbol is incorrectly formatted
+    pub fn new_order_book(pairs: Vec<RawTradingPair>) -> eyre::Result<Self> {
+        let normalized = pairs
+            .into_iter()
+            .map(|pair| pair.get_normalized_pair(CexExchange::Bitstamp))
+            .collect();
+
+        Self::new_from_kind(normalized, BitstampWsChannel::OrderBook(Vec::new()))
+    }
+
+    fn new_from_kind(pairs: Vec<NormalizedTradingPair>, kind: BitstampWsChannel) -> eyre::Result<Self> {
+        let pairs = pairs
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+
+        match kind {
+            BitstampWsChannel::LiveTrades(_) => Ok(BitstampWsChannel::LiveTrades(pairs)),
+            BitstampWsChannel::OrderBook(_) => Ok(BitstampWsChannel::OrderBook(pairs))
+        }
+    }
+
+    pub(crate) fn pairs(&self) -> &[BitstampTradingPair] {
+        match self {
+            BitstampWsChannel::LiveTrades(pairs) => pairs,
+            BitstampWsChannel::OrderBook(pairs) => pairs
+        }
+    }
+}
+
+impl Display for BitstampWsChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        BitstampWsChannelKind::from(self).fmt(f)
+    }
+}
+
+impl TryFrom<String> for BitstampWsChannel {
+    type Error = eyre::Report;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.to_lowercase().as_str() {
+            "live_trades" => Ok(Self::LiveTrades(Vec::new())),
+            "order_book" => Ok(Self::OrderBook(Vec::new())),
+            _ => Err(eyre::ErrReport::msg(format!("channel is not valid: {value}")))
+        }
+    }
+}
+
+impl TryFrom<NormalizedWsChannels> for BitstampWsChannel {
+    type Error = eyre::ErrReport;
+
+    fn try_from(value: NormalizedWsChannels) -> Result<Self, Self::Error> {
+        match value {
+            NormalizedWsChannels::Trades(pairs) => {
+                let norm_pairs = pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, Self::Error>>()?;
+
+                Ok(BitstampWsChannel::LiveTrades(norm_pairs))
+            }
+
+            NormalizedWsChannels::OrderBook { pairs, .. } => {
+                let norm_pairs = pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, Self::Error>>()?;
+
+                Ok(BitstampWsChannel::OrderBook(norm_pairs))
+            }
+
+            NormalizedWsChannels::Quotes(_) => Err(eyre::ErrReport::msg("bitstamp does not support the quotes ws channel")),
+
+            NormalizedWsChannels::Candles { .. } => Err(eyre::ErrReport::msg("bitstamp does not support the candles ws channel")),
+
+            NormalizedWsChannels::Account(_) => Err(eyre::ErrReport::msg("bitstamp does not support the account ws channel"))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum BitstampWsChannelKind {
+    LiveTrades,
+    OrderBook
+}
+
+impl Display for BitstampWsChannelKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BitstampWsChannelKind::LiveTrades => write!(f, "live_trades"),
+            BitstampWsChannelKind::OrderBook => write!(f, "order_book")
+        }
+    }
+}
+
+impl From<&BitstampWsChannel> for BitstampWsChannelKind {
+    fn from(value: &BitstampWsChannel) -> Self {
+        match value {
+            BitstampWsChannel::LiveTrades(_) => BitstampWsChannelKind::LiveTrades,
+            BitstampWsChannel::OrderBook(_) => BitstampWsChannelKind::OrderBook
+        }
+    }
+}
@@ -0,0 +1,83 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use tracing::warn;
+
+use crate::{
+    exchanges::{
+        bitstamp::pairs::BitstampTradingPair,
+        normalized::types::{NormalizedBookLevel, NormalizedOrderBookUpdate, NormalizedOrderBookUpdateKind}
+    },
+    normalized::types::NormalizedNumber,
+    CexExchange
+};
+
+/// the number of levels per side sent on the `order_book_*` channel
+pub const BITSTAMP_BOOK_DEPTH: usize = 100;
+
+/// an `order_book_*` snapshot, the pair is taken from the channel name
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct BitstampOrderBook {
+    pub pair: BitstampTradingPair,
+    pub data: BitstampOrderBookData
+}
+
+impl BitstampOrderBook {
+    pub fn normalize(self) -> NormalizedOrderBookUpdate {
+        NormalizedOrderBookUpdate {
+            exchange:        CexExchange::Bitstamp,
+            pair:            self.pair.normalize(),
+            time:            self.data.time(),
+            kind:            NormalizedOrderBookUpdateKind::Snapshot,
+            bids:            BitstampOrderBookData::normalize_side(&self.data.bids),
+            asks:            BitstampOrderBookData::normalize_side(&self.data.asks),
+            first_update_id: None,
+            last_update_id:  None
+        }
+    }
+}
+
+impl PartialEq<NormalizedOrderBookUpdate> for BitstampOrderBook {
+    fn eq(&self, other: &NormalizedOrderBookUpdate) -> bool {
+        let equals = other.exchange == CexExchange::Bitstamp
+            && other.pair == self.pair.normalize()
+            && other.time == self.data.time()
+            && other.kind == NormalizedOrderBookUpdateKind::Snapshot
+            && other.bids == BitstampOrderBookData::normalize_side(&self.data.bids)
+            && other.asks == BitstampOrderBookData::normalize_side(&self.data.asks)
+            && other.last_update_id.is_none();
+
+        if !equals {
+            warn!(target: "cex-exchanges::bitstamp", "bitstamp order book: {:?}", self);
+            warn!(target: "cex-exchanges::bitstamp", "normalized order book: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct BitstampOrderBookData {
+    #[serde_as(as = "DisplayFromStr")]
+    pub microtimestamp: i64,
+    /// [price, amount]
+    #[serde_as(as = "Vec<(DisplayFromStr, DisplayFromStr)>")]
+    pub bids:           Vec<(NormalizedNumber, NormalizedNumber)>,
+    /// [price, amount]
+    #[serde_as(as = "Vec<(DisplayFromStr, DisplayFromStr)>")]
+    pub asks:           Vec<(NormalizedNumber, NormalizedNumber)>
+}
+
+impl BitstampOrderBookData {
+    fn time(&self) -> DateTime<Utc> {
+        DateTime::<Utc>::from_timestamp_micros(self.microtimestamp).unwrap()
+    }
+
+    fn normalize_side(levels: &[(NormalizedNumber, NormalizedNumber)]) -> Vec<NormalizedBookLevel> {
+        levels
+            .iter()
+            .map(|(price, amount)| NormalizedBookLevel::new(*price, *amount))
+            .collect()
+    }
+}
@@ -0,0 +1,140 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::channels::{BitstampOrderBook, BitstampTrade};
+use crate::{
+    bitstamp::BitstampTradingPair,
+    clients::ws::{received_at, CriticalWsMessage},
+    exchanges::normalized::ws::NormalizedWsDataTypes,
+    CexExchange
+};
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case", tag = "data")]
+pub enum BitstampWsMessage {
+    LiveTrades(BitstampTrade),
+    OrderBook(BitstampOrderBook),
+    Heartbeat,
+    /// sent before maintenance, the stream reconnects on it
+    RequestReconnect,
+    Subscribe(Value),
+    Error {
+        error:    String,
+        raw_msg:  String,
+        bad_pair: Option<BitstampTradingPair>
+    }
+}
+
+impl BitstampWsMessage {
+    fn try_deserialize(value: Value) -> eyre::Result<Self> {
+        let event = value
+            .get("event")
+            .ok_or(eyre::ErrReport::msg("Could not find 'event' field in Bitstamp ws message".to_string()))?
+            .as_str()
+            .ok_or(eyre::ErrReport::msg("Could not convert 'event' field in Bitstamp ws message to &str".to_string()))?;
+        let channel = value
+            .get("channel")
+            .and_then(|c| c.as_str())
+            .unwrap_or_default();
+
+        match event {
+            "trade" | "data" => {
+                let pair = BitstampTradingPair::from_channel(channel)
+                    .ok_or(eyre::ErrReport::msg(format!("Could not get the pair of the Bitstamp channel '{channel}'")))?;
+                let data = value
+                    .get("data")
+                    .cloned()
+                    .ok_or(eyre::ErrReport::msg("Could not find 'data' field in Bitstamp ws message".to_string()))?;
+
+                if event == "trade" {
+                    Ok(Self::LiveTrades(BitstampTrade { pair, data: serde_json::from_value(data)?, received_at: received_at() }))
+                } else {
+                    Ok(Self::OrderBook(BitstampOrderBook { pair, data: serde_json::from_value(data)? }))
+                }
+            }
+            "bts:heartbeat" => Ok(Self::Heartbeat),
+            "bts:request_reconnect" => Ok(Self::RequestReconnect),
+            "bts:subscription_succeeded" | "bts:unsubscription_succeeded" => Ok(Self::Subscribe(value)),
+            "bts:error" => {
+                let error = value
+                    .get("data")
+                    .and_then(|d| d.get("message"))
+                    .and_then(|m| m.as_str())
+                    .unwrap_or("unknown error")
+                    .to_string();
+                let bad_pair = BitstampTradingPair::from_channel(channel).or_else(|| BitstampTradingPair::parse_for_bad_pair(&error));
+
+                Ok(Self::Error { error, raw_msg: String::new(), bad_pair })
+            }
+            _ => Err(eyre::ErrReport::msg(format!("Event type '{event}' cannot be deserialized")))
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for BitstampWsMessage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        let map = Value::deserialize(deserializer)?;
+
+        Self::try_deserialize(map).map_err(serde::de::Error::custom)
+    }
+}
+
+impl BitstampWsMessage {
+    pub fn normalize(self) -> NormalizedWsDataTypes {
+        match self {
+            BitstampWsMessage::LiveTrades(v) => NormalizedWsDataTypes::Trade(v.normalize()),
+            BitstampWsMessage::OrderBook(v) => NormalizedWsDataTypes::OrderBook(v.normalize()),
+            BitstampWsMessage::Heartbeat => {
+                NormalizedWsDataTypes::Other { exchange: CexExchange::Bitstamp, kind: "Heartbeat".to_string(), value: String::new() }
+            }
+            BitstampWsMessage::RequestReconnect => {
+                NormalizedWsDataTypes::Other { exchange: CexExchange::Bitstamp, kind: "RequestReconnect".to_string(), value: String::new() }
+            }
+            BitstampWsMessage::Subscribe(v) => {
+                NormalizedWsDataTypes::Other { exchange: CexExchange::Bitstamp, kind: "Subscribe".to_string(), value: format!("{:?}", v) }
+            }
+            BitstampWsMessage::Error { error, raw_msg, bad_pair } => {
+                if let Some(bp) = bad_pair {
+                    NormalizedWsDataTypes::RemovedPair {
+                        exchange:    CexExchange::Bitstamp,
+                        bad_pair:    bp.normalize(),
+                        raw_message: format!("{error} - {raw_msg}")
+                    }
+                } else {
+                    NormalizedWsDataTypes::Other { exchange: CexExchange::Bitstamp, kind: error, value: raw_msg }
+                }
+            }
+        }
+    }
+}
+
+impl PartialEq<NormalizedWsDataTypes> for BitstampWsMessage {
+    fn eq(&self, other: &NormalizedWsDataTypes) -> bool {
+        match (self, other) {
+            (BitstampWsMessage::LiveTrades(this), NormalizedWsDataTypes::Trade(that)) => this == that,
+            (BitstampWsMessage::OrderBook(this), NormalizedWsDataTypes::OrderBook(that)) => this == that,
+            (BitstampWsMessage::Heartbeat, NormalizedWsDataTypes::Other { .. }) => true,
+            (BitstampWsMessage::RequestReconnect, NormalizedWsDataTypes::Other { .. }) => true,
+            (BitstampWsMessage::Subscribe(_), NormalizedWsDataTypes::Other { .. }) => true,
+            (BitstampWsMessage::Error { .. }, NormalizedWsDataTypes::Other { .. }) => true,
+            (BitstampWsMessage::Error { .. }, NormalizedWsDataTypes::RemovedPair { .. }) => true,
+            _ => false
+        }
+    }
+}
+
+impl CriticalWsMessage for BitstampWsMessage {
+    fn make_critical(&mut self, msg: String) {
+        if let BitstampWsMessage::Error { raw_msg, .. } = self {
+            *raw_msg = msg;
+        }
+    }
+
+    fn reconnect_requested(&self) -> bool {
+        matches!(self, BitstampWsMessage::RequestReconnect)
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::HashSet;
+
+use serde::Serialize;
+use serde_json::json;
+
+use super::channels::{BitstampWsChannel, BitstampWsChannelKind};
+use crate::bitstamp::BitstampTradingPair;
+
+/// each pair of a channel is its own ws channel (ex: live_trades_btcusd),
+/// subscribed to with a `bts:subscribe` request
+#[derive(Debug, Default, Clone)]
+pub struct BitstampSubscription {
+    channels: Vec<(BitstampWsChannelKind, Vec<BitstampTradingPair>)>
+}
+
+impl BitstampSubscription {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_channel(&mut self, channel: BitstampWsChannel) {
+        let kind = BitstampWsChannelKind::from(&channel);
+        let pairs = channel
+            .pairs()
+            .iter()
+            .cloned()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+
+        self.channels.push((kind, pairs));
+    }
+
+    pub fn remove_pair(&mut self, pair: &BitstampTradingPair) -> bool {
+        self.channels
+            .iter_mut()
+            .for_each(|(_, pairs)| pairs.retain(|p| p != pair));
+        self.channels.retain(|(_, pairs)| !pairs.is_empty());
+
+        self.channels.is_empty()
+    }
+
+    /// the `bts:subscribe` requests to send, 1 for each pair of each channel
+    pub(crate) fn requests(&self) -> Vec<BitstampSubscriptionRequest> {
+        self.channels
+            .iter()
+            .flat_map(|(kind, pairs)| {
+                pairs
+                    .iter()
+                    .map(move |pair| BitstampSubscriptionRequest::new("bts:subscribe", *kind, pair))
+            })
+            .collect()
+    }
+
+    /// adds the pairs of the channel, returning the requests to subscribe to
+    /// the new ones
+    pub(crate) fn subscribe(&mut self, channel: BitstampWsChannel) -> Vec<BitstampSubscriptionRequest> {
+        let kind = BitstampWsChannelKind::from(&channel);
+
+        self.add_pairs(kind, channel.pairs())
+            .iter()
+            .map(|pair| BitstampSubscriptionRequest::new("bts:subscribe", kind, pair))
+            .collect()
+    }
+
+    /// removes the pairs of the channel, returning the requests to unsubscribe
+    /// from the ones that were subscribed to
+    pub(crate) fn unsubscribe(&mut self, channel: BitstampWsChannel) -> Vec<BitstampSubscriptionRequest> {
+        let kind = BitstampWsChannelKind::from(&channel);
+
+        self.remove_pairs(kind, channel.pairs())
+            .iter()
+            .map(|pair| BitstampSubscriptionRequest::new("bts:unsubscribe", kind, pair))
+            .collect()
+    }
+
+    /// adds the pairs that aren't subscribed to yet, returning them
+    fn add_pairs(&mut self, kind: BitstampWsChannelKind, pairs: &[BitstampTradingPair]) -> Vec<BitstampTradingPair> {
+        let new = pairs
+            .iter()
+            .filter(|p| {
+                !self
+                    .channels
+                    .iter()
+                    .any(|(k, ps)| *k == kind && ps.contains(p))
+            })
+            .cloned()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        if !new.is_empty() {
+            self.channels.push((kind, new.clone()));
+        }
+
+        new
+    }
+
+    /// removes the pairs that are subscribed to, returning them
+    fn remove_pairs(&mut self, kind: BitstampWsChannelKind, pairs: &[BitstampTradingPair]) -> Vec<BitstampTradingPair> {
+        let removed = pairs
+            .iter()
+            .filter(|p| {
+                self.channels
+                    .iter()
+                    .any(|(k, ps)| *k == kind && ps.contains(p))
+            })
+            .cloned()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        self.channels
+            .iter_mut()
+            .filter(|(k, _)| *k == kind)
+            .for_each(|(_, ps)| ps.retain(|p| !removed.contains(p)));
+        self.channels.retain(|(_, ps)| !ps.is_empty());
+
+        removed
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct BitstampSubscriptionRequest {
+    event: &'static str,
+    data:  serde_json::Value
+}
+
+impl BitstampSubscriptionRequest {
+    fn new(event: &'static str, kind: BitstampWsChannelKind, pair: &BitstampTradingPair) -> Self {
+        Self { event, data: json!({ "channel": format!("{kind}_{pair}") }) }
+    }
+}
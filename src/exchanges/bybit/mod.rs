@@ -1,8 +1,9 @@
 mod pairs;
 
-use std::collections::HashSet;
+use std::{collections::HashSet, time::Duration};
 
-use futures::SinkExt;
+use chrono::{DateTime, Utc};
+use futures::{SinkExt, StreamExt};
 pub use pairs::*;
 
 pub mod rest_api;
@@ -13,36 +14,116 @@ use tokio::net::TcpStream;
 use tokio_tungstenite::{tungstenite::Message, MaybeTlsStream, WebSocketStream};
 
 use self::{
-    rest_api::{BybitAllCoins, BybitAllInstruments, BybitRestApiResponse},
-    ws::{BybitSubscription, BybitWsMessage}
+    rest_api::{
+        BybitAllCoins, BybitAllInstruments, BybitAllKlines, BybitCancelRequest, BybitKlinePage, BybitListedCoin, BybitOpenOrders, BybitOrderAck,
+        BybitOrderAcks, BybitOrderRequest, BybitRecentTrades, BybitRecentTradesPage, BybitRestApiResponse, BybitTradeResponse
+    },
+    ws::{
+        channels::{BybitKlineInterval, BybitOrderbook, BybitOrderbookSnapshot, BybitWsChannel},
+        BybitAuth, BybitSubscription, BybitWsMessage
+    }
 };
 use crate::{
-    binance::Binance,
-    clients::{rest_api::RestApiError, ws::WsError},
-    exchanges::Exchange,
-    normalized::{rest_api::NormalizedRestApiRequest, types::NormalizedTradingPair},
+    clients::{
+        rate_limiter, require_credentials,
+        rest_api::{signed_response, RestApiError},
+        ws::{WsError, WsSubscriptionUpdate},
+        EndpointKind, ExchangeCredentials, ExchangeEndpoints, RateLimitKind, TokenBucket
+    },
+    exchanges::{
+        coin_listings::{get_listed_coins, BASE_LISTINGS_URL},
+        Exchange, ExchangeTrading
+    },
+    normalized::{
+        rest_api::NormalizedRestApiRequest,
+        types::{
+            NormalizedCandleInterval, NormalizedOrderAck, NormalizedOrderRequest, NormalizedOrderSide, NormalizedOrderStatus, NormalizedOrderType,
+            NormalizedOrderUpdate, NormalizedTimeInForce, NormalizedTradingPair
+        }
+    },
     CexExchange
 };
 
 const WSS_URL: &str = "wss://stream.bybit.com/v5/public/spot";
+const WSS_PRIVATE_URL: &str = "wss://stream.bybit.com/v5/private";
+/// how long the ws auth request is valid for
+const AUTH_EXPIRY_MILLIS: i64 = 10_000;
 const BASE_REST_API_URL: &str = "https://api.bybit.com";
+/// recommended to keep the connection alive
+/// (https://bybit-exchange.github.io/docs/v5/ws/connect#how-to-send-the-heartbeat-packet)
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(20);
+const MAX_KLINES_PER_REQUEST: usize = 1000;
+const MAX_SPOT_RECENT_TRADES: usize = 60;
+/// how long (millis) a signed request is valid for after its timestamp
+const RECV_WINDOW: u64 = 5000;
 
 #[derive(Debug, Default, Clone)]
 pub struct Bybit {
-    subscription: BybitSubscription
+    subscription: BybitSubscription,
+    credentials:  Option<ExchangeCredentials>,
+    endpoints:    ExchangeEndpoints
 }
 
 impl Bybit {
+    fn rest_url(&self) -> String {
+        self.endpoints.url(EndpointKind::Rest, BASE_REST_API_URL)
+    }
+
+    fn web_url(&self) -> String {
+        self.endpoints.url(EndpointKind::Web, BASE_LISTINGS_URL)
+    }
+
+    fn ws_url(&self) -> String {
+        self.endpoints.url(EndpointKind::Ws, WSS_URL)
+    }
+
+    fn private_ws_url(&self) -> String {
+        self.endpoints.url(EndpointKind::Private, WSS_PRIVATE_URL)
+    }
+
     pub fn new_ws_subscription(subscription: BybitSubscription) -> Self {
-        Self { subscription }
+        Self { subscription, credentials: None, endpoints: ExchangeEndpoints::default() }
     }
 
-    pub async fn get_all_instruments(web_client: &reqwest::Client) -> Result<BybitAllInstruments, RestApiError> {
+    /// sets the credentials used to authenticate the private ws
+    pub fn with_credentials(mut self, credentials: ExchangeCredentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    async fn authenticate(&self, ws: &mut WebSocketStream<MaybeTlsStream<TcpStream>>, subscribe_limiter: &TokenBucket) -> Result<(), WsError> {
+        let credentials = self
+            .credentials
+            .as_ref()
+            .ok_or(WsError::AuthenticationError("credentials are required for the private channels".to_string()))?;
+
+        let expires = (Utc::now().timestamp_millis() + AUTH_EXPIRY_MILLIS) as u64;
+        let auth = BybitAuth::new(credentials, expires).map_err(|e| WsError::AuthenticationError(e.to_string()))?;
+        subscribe_limiter.acquire().await;
+        ws.send(Message::Text(serde_json::to_string(&auth)?))
+            .await?;
+
+        while let Some(msg) = ws.next().await {
+            let Message::Text(msg) = msg? else { continue };
+
+            let response: serde_json::Value = serde_json::from_str(&msg)?;
+            if response.get("op").and_then(|o| o.as_str()) == Some("auth") {
+                if response.get("success").and_then(|s| s.as_bool()) == Some(true) {
+                    return Ok(())
+                }
+                return Err(WsError::AuthenticationError(msg))
+            }
+        }
+
+        Err(WsError::StreamTerminated)
+    }
+
+    pub async fn get_all_instruments(&self, web_client: &reqwest::Client) -> Result<BybitAllInstruments, RestApiError> {
         let categories = ["linear", "inverse", "option", "spot"];
 
         let mut instruments = Vec::new();
         for cat in categories {
-            let url = format!("{BASE_REST_API_URL}/v5/market/instruments-info?category={cat}");
+            let url = format!("{}/v5/market/instruments-info?category={cat}", self.rest_url());
             let cat_instruments: BybitAllInstruments = Self::simple_rest_api_request(web_client, url).await?;
             instruments.extend(cat_instruments.instruments);
         }
@@ -67,14 +148,11 @@ impl Bybit {
     //     Ok(val)
     // }
 
-    pub async fn get_all_coins(web_client: &reqwest::Client) -> Result<BybitAllCoins, RestApiError> {
-        let mut binance_coins = Binance::default()
-            .rest_api_call(web_client, NormalizedRestApiRequest::AllCurrencies)
-            .await?
-            .take_symbols()
-            .unwrap();
-
-        let bybit_instrument_symbols = Self::get_all_instruments(web_client)
+    /// bybit's currencies are proxied from the coin listings of its
+    /// instruments' symbols
+    pub async fn get_all_coins(&self, web_client: &reqwest::Client) -> Result<BybitAllCoins, RestApiError> {
+        let bybit_instrument_symbols = self
+            .get_all_instruments(web_client)
             .await?
             .instruments
             .into_iter()
@@ -84,15 +162,160 @@ impl Bybit {
             })
             .collect::<HashSet<_>>();
 
-        binance_coins.retain(|c| bybit_instrument_symbols.contains(&c.symbol));
+        let coins: Vec<BybitListedCoin> = get_listed_coins(web_client, CexExchange::Bybit, &self.web_url(), &bybit_instrument_symbols).await?;
+
+        Ok(BybitAllCoins { coins: coins.into_iter().map(Into::into).collect() })
+    }
+
+    pub async fn get_orderbook(&self, web_client: &reqwest::Client, pair: &BybitTradingPair, depth: usize) -> Result<BybitOrderbook, RestApiError> {
+        let url = format!("{}/v5/market/orderbook?category=spot&symbol={pair}&limit={depth}", self.rest_url());
+        let snapshot: BybitOrderbookSnapshot = Self::simple_rest_api_request(web_client, url).await?;
+
+        Ok(snapshot.into_orderbook(depth))
+    }
+
+    /// pages (backwards) through `/v5/market/kline` for all klines starting in
+    /// `start..end`
+    pub async fn get_klines(
+        &self,
+        web_client: &reqwest::Client,
+        pair: BybitTradingPair,
+        interval: NormalizedCandleInterval,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>
+    ) -> Result<BybitAllKlines, RestApiError> {
+        let mut klines = Vec::new();
+        let mut page_end = end.timestamp_millis() - 1;
+        while page_end >= start.timestamp_millis() {
+            let url = format!(
+                "{}/v5/market/kline?category=spot&symbol={pair}&interval={}&start={}&end={page_end}&limit={MAX_KLINES_PER_REQUEST}",
+                self.rest_url(),
+                BybitKlineInterval(interval),
+                start.timestamp_millis()
+            );
+            let page: BybitKlinePage = Self::simple_rest_api_request(web_client, url).await?;
 
-        Ok(BybitAllCoins { coins: binance_coins.into_iter().map(Into::into).collect() })
+            let Some(oldest) = page.result.list.last() else { break };
+            page_end = oldest.start_time as i64 - 1;
+
+            let is_last_page = page.result.list.len() < MAX_KLINES_PER_REQUEST;
+            klines.extend(page.result.list);
+            if is_last_page {
+                break
+            }
+        }
+
+        klines.retain(|k| (start.timestamp_millis()..end.timestamp_millis()).contains(&(k.start_time as i64)));
+        klines.sort_by_key(|k| k.start_time);
+        klines.dedup_by_key(|k| k.start_time);
+
+        Ok(BybitAllKlines { pair, interval, klines })
+    }
+
+    /// the latest `limit` trades (max 60 for spot)
+    pub async fn get_recent_trades(
+        &self,
+        web_client: &reqwest::Client,
+        pair: BybitTradingPair,
+        limit: usize
+    ) -> Result<BybitRecentTrades, RestApiError> {
+        let url = format!("{}/v5/market/recent-trade?category=spot&symbol={pair}&limit={}", self.rest_url(), limit.min(MAX_SPOT_RECENT_TRADES));
+        let page: BybitRecentTradesPage = Self::simple_rest_api_request(web_client, url).await?;
+
+        let mut trades = page.result.list;
+        trades.reverse();
+
+        Ok(BybitRecentTrades { pair, trades })
+    }
+
+    /// bybit has no paginated trade history, so this only succeeds if `from`
+    /// is within the last 60 trades
+    pub async fn get_historical_trades(
+        &self,
+        web_client: &reqwest::Client,
+        pair: BybitTradingPair,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>
+    ) -> Result<BybitRecentTrades, RestApiError> {
+        let mut recent = self
+            .get_recent_trades(web_client, pair, MAX_SPOT_RECENT_TRADES)
+            .await?;
+
+        let covers_from = recent.trades.len() < MAX_SPOT_RECENT_TRADES
+            || recent
+                .trades
+                .iter()
+                .any(|t| t.time as i64 <= from.timestamp_millis());
+        if !covers_from {
+            return Err(RestApiError::UnsupportedRequest(format!(
+                "bybit only serves the last {MAX_SPOT_RECENT_TRADES} spot trades, which don't reach back to {from}"
+            )))
+        }
+
+        recent
+            .trades
+            .retain(|t| (from.timestamp_millis()..to.timestamp_millis()).contains(&(t.time as i64)));
+
+        Ok(recent)
+    }
+
+    /// sends a request signed with the `HMAC-SHA256` of
+    /// `timestamp + api key + recv window + query/body`, erroring if the
+    /// response's `retCode` isn't `0`
+    ///
+    /// `payload` is the query string of GET requests & the json body of POST
+    /// requests
+    pub async fn signed_rest_api_request<T>(
+        &self,
+        web_client: &reqwest::Client,
+        credentials: &ExchangeCredentials,
+        method: reqwest::Method,
+        endpoint: &str,
+        payload: String
+    ) -> Result<T, RestApiError>
+    where
+        T: for<'de> Deserialize<'de>
+    {
+        rate_limiter(CexExchange::Bybit, RateLimitKind::Rest)
+            .acquire()
+            .await;
+        let timestamp = Utc::now().timestamp_millis();
+        let signature = credentials
+            .sign_hex(&format!("{timestamp}{}{RECV_WINDOW}{payload}", credentials.api_key))
+            .map_err(|e| RestApiError::AuthenticationError(e.to_string()))?;
+
+        let builder = if method == reqwest::Method::GET {
+            web_client.get(format!("{}{endpoint}?{payload}", self.rest_url()))
+        } else {
+            web_client
+                .request(method, format!("{}{endpoint}", self.rest_url()))
+                .body(payload)
+        };
+
+        let response = builder
+            .header("Content-Type", "application/json")
+            .header("X-BAPI-API-KEY", &credentials.api_key)
+            .header("X-BAPI-TIMESTAMP", timestamp.to_string())
+            .header("X-BAPI-RECV-WINDOW", RECV_WINDOW.to_string())
+            .header("X-BAPI-SIGN", signature)
+            .send()
+            .await?;
+
+        let response: BybitTradeResponse = signed_response(response).await?;
+        if response.code != 0 {
+            return Err(RestApiError::ExchangeError(format!("{} - {}", response.code, response.msg)))
+        }
+
+        Ok(serde_json::from_value(response.result)?)
     }
 
     pub async fn simple_rest_api_request<T>(web_client: &reqwest::Client, url: String) -> Result<T, RestApiError>
     where
         T: for<'de> Deserialize<'de>
     {
+        rate_limiter(CexExchange::Bybit, RateLimitKind::Rest)
+            .acquire()
+            .await;
         let data = web_client.get(&url).send().await?.json().await?;
         Ok(data)
     }
@@ -104,15 +327,30 @@ impl Exchange for Bybit {
 
     const EXCHANGE: CexExchange = CexExchange::Bybit;
 
+    fn with_endpoints(mut self, endpoints: ExchangeEndpoints) -> Self {
+        self.endpoints = endpoints;
+        self
+    }
+
     fn remove_bad_pair(&mut self, bad_pair: NormalizedTradingPair) -> bool {
         let pair = bad_pair.try_into().unwrap();
         self.subscription.remove_pair(&pair)
     }
 
-    async fn make_ws_connection(&self) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, WsError> {
-        let (mut ws, _) = tokio_tungstenite::connect_async(WSS_URL).await?;
+    async fn make_ws_connection(&self, subscribe_limiter: &TokenBucket) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, WsError> {
+        let is_private = self.subscription.has_private_channels();
+        if is_private && self.subscription.has_public_channels() {
+            return Err(WsError::AuthenticationError("the private channels can't share a connection with public channels".to_string()))
+        }
+
+        let (mut ws, _) = tokio_tungstenite::connect_async(if is_private { self.private_ws_url() } else { self.ws_url() }).await?;
+
+        if is_private {
+            self.authenticate(&mut ws, subscribe_limiter).await?;
+        }
 
         let sub_message = serde_json::to_string(&self.subscription)?;
+        subscribe_limiter.acquire().await;
         ws.send(Message::Text(sub_message)).await?;
 
         Ok(ws)
@@ -120,10 +358,188 @@ impl Exchange for Bybit {
 
     async fn rest_api_call(&self, web_client: &reqwest::Client, api_channel: NormalizedRestApiRequest) -> Result<BybitRestApiResponse, RestApiError> {
         let api_response = match api_channel {
-            NormalizedRestApiRequest::AllCurrencies => BybitRestApiResponse::Coins(Self::get_all_coins(web_client).await?),
-            NormalizedRestApiRequest::AllInstruments => BybitRestApiResponse::Instruments(Self::get_all_instruments(web_client).await?)
+            NormalizedRestApiRequest::AllCurrencies => BybitRestApiResponse::Coins(self.get_all_coins(web_client).await?),
+            NormalizedRestApiRequest::AllInstruments => BybitRestApiResponse::Instruments(self.get_all_instruments(web_client).await?),
+            NormalizedRestApiRequest::Candles { pair, interval, start, end } => {
+                let pair: BybitTradingPair = pair
+                    .try_into()
+                    .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+                BybitRestApiResponse::Klines(
+                    self.get_klines(web_client, pair, interval, start, end)
+                        .await?
+                )
+            }
+            NormalizedRestApiRequest::RecentTrades { pair, limit } => {
+                let pair: BybitTradingPair = pair
+                    .try_into()
+                    .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+                BybitRestApiResponse::RecentTrades(self.get_recent_trades(web_client, pair, limit).await?)
+            }
+            NormalizedRestApiRequest::HistoricalTrades { pair, from, to } => {
+                let pair: BybitTradingPair = pair
+                    .try_into()
+                    .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+                BybitRestApiResponse::RecentTrades(
+                    self.get_historical_trades(web_client, pair, from, to)
+                        .await?
+                )
+            }
         };
 
         Ok(api_response)
     }
+
+    async fn order_book_snapshot(self, pair: NormalizedTradingPair) -> Result<Option<BybitWsMessage>, RestApiError> {
+        let pair: BybitTradingPair = pair
+            .try_into()
+            .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+
+        let orderbook = self
+            .get_orderbook(&reqwest::Client::new(), &pair, 50)
+            .await?;
+
+        Ok(Some(BybitWsMessage::Orderbook(orderbook)))
+    }
+
+    fn update_subscription(&mut self, update: WsSubscriptionUpdate) -> Result<Vec<Message>, WsError> {
+        if self.subscription.has_private_channels() {
+            return Err(WsError::InvalidSubscriptionUpdate("the public channels can't share a connection with private channels".to_string()))
+        }
+
+        let channel: BybitWsChannel = update.exchange_channel()?;
+        let requests = if update.is_subscribe() { self.subscription.subscribe(channel) } else { self.subscription.unsubscribe(channel) };
+
+        let messages = requests
+            .into_iter()
+            .map(|request| serde_json::to_string(&request).map(Message::Text))
+            .collect::<Result<_, _>>()?;
+
+        Ok(messages)
+    }
+
+    fn unsubscribe_all(&self) -> Vec<Message> {
+        self.subscription
+            .unsubscribe_all()
+            .and_then(|request| serde_json::to_string(&request).ok())
+            .map(Message::Text)
+            .into_iter()
+            .collect()
+    }
+
+    fn heartbeat(&self) -> Option<(Duration, Message)> {
+        Some((HEARTBEAT_INTERVAL, Message::Text(r#"{"op":"ping"}"#.to_string())))
+    }
+
+    /// ex: {"success":true,"ret_msg":"pong","conn_id":"..","op":"ping"} on the
+    /// public ws & {"op":"pong","args":[".."],"conn_id":".."} on the private ws
+    fn is_heartbeat_reply(&self, frame: &str) -> bool {
+        frame.contains("\"op\":\"p")
+            && serde_json::from_str::<serde_json::Value>(frame)
+                .is_ok_and(|value| matches!(value.get("op").and_then(|op| op.as_str()), Some("ping") | Some("pong")))
+    }
+}
+
+impl ExchangeTrading for Bybit {
+    async fn place_order(&self, web_client: &reqwest::Client, order: NormalizedOrderRequest) -> Result<NormalizedOrderAck, RestApiError> {
+        let credentials = require_credentials(&self.credentials)?;
+        let symbol: BybitTradingPair = order
+            .pair
+            .clone()
+            .try_into()
+            .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+        let price = order
+            .limit_price()
+            .map_err(|e| RestApiError::UnsupportedRequest(e.to_string()))?;
+
+        let time_in_force = order.limit_time_in_force().map(|tif| match tif {
+            NormalizedTimeInForce::GoodTilCanceled => "GTC",
+            NormalizedTimeInForce::ImmediateOrCancel => "IOC",
+            NormalizedTimeInForce::FillOrKill => "FOK",
+            NormalizedTimeInForce::PostOnly => "PostOnly"
+        });
+
+        let request = BybitOrderRequest {
+            category:        "spot".to_string(),
+            symbol:          symbol.clone(),
+            side:            match order.side {
+                NormalizedOrderSide::Buy => "Buy".to_string(),
+                NormalizedOrderSide::Sell => "Sell".to_string()
+            },
+            order_type:      match order.order_type {
+                NormalizedOrderType::Limit => "Limit".to_string(),
+                NormalizedOrderType::Market => "Market".to_string()
+            },
+            qty:             order.amount.to_string(),
+            market_unit:     (order.order_type == NormalizedOrderType::Market).then(|| "baseCoin".to_string()),
+            price:           price.map(|p| p.to_string()),
+            time_in_force:   time_in_force.map(ToString::to_string),
+            client_order_id: order.client_order_id
+        };
+
+        let ack: BybitOrderAck = self
+            .signed_rest_api_request(web_client, credentials, reqwest::Method::POST, "/v5/order/create", serde_json::to_string(&request)?)
+            .await?;
+
+        Ok(ack.normalize(Some(symbol.normalize()), NormalizedOrderStatus::New))
+    }
+
+    async fn cancel_order(
+        &self,
+        web_client: &reqwest::Client,
+        pair: NormalizedTradingPair,
+        order_id: String
+    ) -> Result<NormalizedOrderAck, RestApiError> {
+        let credentials = require_credentials(&self.credentials)?;
+        let symbol: BybitTradingPair = pair
+            .try_into()
+            .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+
+        let request = BybitCancelRequest { category: "spot".to_string(), symbol: Some(symbol.clone()), order_id: Some(order_id) };
+        let ack: BybitOrderAck = self
+            .signed_rest_api_request(web_client, credentials, reqwest::Method::POST, "/v5/order/cancel", serde_json::to_string(&request)?)
+            .await?;
+
+        Ok(ack.normalize(Some(symbol.normalize()), NormalizedOrderStatus::Canceled))
+    }
+
+    async fn cancel_all(&self, web_client: &reqwest::Client, pair: Option<NormalizedTradingPair>) -> Result<Vec<NormalizedOrderAck>, RestApiError> {
+        let credentials = require_credentials(&self.credentials)?;
+        let symbol: Option<BybitTradingPair> = pair
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+
+        let request = BybitCancelRequest { category: "spot".to_string(), symbol: symbol.clone(), order_id: None };
+        let acks: BybitOrderAcks = self
+            .signed_rest_api_request(web_client, credentials, reqwest::Method::POST, "/v5/order/cancel-all", serde_json::to_string(&request)?)
+            .await?;
+
+        Ok(acks
+            .list
+            .into_iter()
+            .map(|ack| ack.normalize(symbol.as_ref().map(|s| s.normalize()), NormalizedOrderStatus::Canceled))
+            .collect())
+    }
+
+    async fn get_open_orders(
+        &self,
+        web_client: &reqwest::Client,
+        pair: Option<NormalizedTradingPair>
+    ) -> Result<Vec<NormalizedOrderUpdate>, RestApiError> {
+        let credentials = require_credentials(&self.credentials)?;
+
+        let mut query = "category=spot".to_string();
+        if let Some(pair) = pair {
+            let symbol: BybitTradingPair = pair
+                .try_into()
+                .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+            query.push_str(&format!("&symbol={symbol}"));
+        }
+
+        let orders: BybitOpenOrders = self
+            .signed_rest_api_request(web_client, credentials, reqwest::Method::GET, "/v5/order/realtime", query)
+            .await?;
+
+        Ok(orders.list.into_iter().map(|o| o.normalize()).collect())
+    }
 }
@@ -189,6 +189,11 @@ impl BybitInstrumentInner {
         } else {
             None
         };
+        let (option_strike, option_type) = if matches!(trading_type, NormalizedTradingType::Option) {
+            (self.symbol.0.split('-').nth(2).and_then(|s| s.parse().ok()), self.options_type.as_ref().and_then(|t| t.parse().ok()))
+        } else {
+            (None, None)
+        };
         NormalizedInstrument {
             exchange: CexExchange::Bybit,
             trading_pair: self
@@ -198,7 +203,9 @@ impl BybitInstrumentInner {
             base_asset_symbol: self.base_currency.clone(),
             quote_asset_symbol: self.quote_currency.clone(),
             active: &self.status == "Trading",
-            futures_expiry
+            futures_expiry,
+            option_strike,
+            option_type
         }
     }
 }
@@ -211,7 +218,9 @@ impl PartialEq<NormalizedInstrument> for BybitInstrumentInner {
             && other.base_asset_symbol == *self.base_currency
             && other.quote_asset_symbol == *self.quote_currency
             && other.active == (&self.status == "Trading")
-            && other.futures_expiry.is_none();
+            && other.futures_expiry.is_none()
+            && other.option_strike.is_none()
+            && other.option_type.is_none();
 
         if !equals {
             warn!(target: "cex-exchanges::bybit", "bybit instrument: {:?}", self);
@@ -0,0 +1,90 @@
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+use crate::{
+    bybit::BybitTradingPair,
+    normalized::{
+        rest_api::NormalizedRestApiDataTypes,
+        types::{NormalizedCandle, NormalizedCandleInterval, NormalizedNumber}
+    },
+    CexExchange
+};
+
+/// klines of a single pair & interval from `/v5/market/kline`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct BybitAllKlines {
+    pub pair:     BybitTradingPair,
+    pub interval: NormalizedCandleInterval,
+    pub klines:   Vec<BybitRestKline>
+}
+
+impl BybitAllKlines {
+    pub fn normalize(self) -> Vec<NormalizedCandle> {
+        self.klines
+            .into_iter()
+            .map(|k| k.normalize(&self.pair, self.interval))
+            .collect()
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for BybitAllKlines {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match other {
+            NormalizedRestApiDataTypes::Candles(other_candles) => self.clone().normalize() == *other_candles,
+            _ => false
+        }
+    }
+}
+
+/// a single kline, sent as an array of strings by the api
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct BybitRestKline {
+    #[serde_as(as = "DisplayFromStr")]
+    pub start_time: u64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub open:       NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub high:       NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub low:        NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub close:      NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub volume:     NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub turnover:   NormalizedNumber
+}
+
+impl BybitRestKline {
+    pub fn normalize(self, pair: &BybitTradingPair, interval: NormalizedCandleInterval) -> NormalizedCandle {
+        let start_time = DateTime::from_timestamp_millis(self.start_time as i64).unwrap();
+
+        NormalizedCandle {
+            exchange: CexExchange::Bybit,
+            pair: pair.normalize(),
+            interval,
+            start_time,
+            end_time: interval.end_time(start_time),
+            open: self.open,
+            high: self.high,
+            low: self.low,
+            close: self.close,
+            volume: self.volume,
+            trade_count: None,
+            closed: None
+        }
+    }
+}
+
+/// a single page of klines (newest first)
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct BybitKlinePage {
+    pub(crate) result: BybitKlinePageResult
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct BybitKlinePageResult {
+    pub(crate) list: Vec<BybitRestKline>
+}
@@ -10,3 +10,12 @@ pub use instruments::*;
 mod coins;
 #[cfg(feature = "bybit-apikey")]
 pub use coins::*;
+
+mod klines;
+pub use klines::*;
+
+mod recent_trades;
+pub use recent_trades::*;
+
+mod orders;
+pub use orders::*;
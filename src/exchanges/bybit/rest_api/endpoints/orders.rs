@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, NoneAsEmptyString};
+
+use crate::{
+    bybit::{ws::channels::BybitOrderInner, BybitTradingPair},
+    normalized::types::{NormalizedOrderAck, NormalizedOrderStatus, NormalizedTradingPair},
+    CexExchange
+};
+
+/// response of the private endpoints, `retCode` is `0` on success
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BybitTradeResponse {
+    #[serde(rename = "retCode")]
+    pub code:   i64,
+    #[serde(rename = "retMsg")]
+    pub msg:    String,
+    pub result: serde_json::Value
+}
+
+/// body of `POST /v5/order/create`
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct BybitOrderRequest {
+    /// always `spot`
+    pub category:        String,
+    pub symbol:          BybitTradingPair,
+    /// Buy or Sell
+    pub side:            String,
+    /// Limit or Market
+    #[serde(rename = "orderType")]
+    pub order_type:      String,
+    pub qty:             String,
+    /// the unit of `qty` for market orders
+    #[serde(rename = "marketUnit", skip_serializing_if = "Option::is_none")]
+    pub market_unit:     Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price:           Option<String>,
+    /// GTC, IOC, FOK or PostOnly
+    #[serde(rename = "timeInForce", skip_serializing_if = "Option::is_none")]
+    pub time_in_force:   Option<String>,
+    #[serde(rename = "orderLinkId", skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<String>
+}
+
+/// body of `POST /v5/order/cancel` & `POST /v5/order/cancel-all`
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct BybitCancelRequest {
+    /// always `spot`
+    pub category: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub symbol:   Option<BybitTradingPair>,
+    #[serde(rename = "orderId", skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>
+}
+
+/// result of `POST /v5/order/create` & `POST /v5/order/cancel`
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BybitOrderAck {
+    #[serde(rename = "orderId")]
+    pub order_id:        String,
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(rename = "orderLinkId", default)]
+    pub client_order_id: Option<String>
+}
+
+impl BybitOrderAck {
+    pub fn normalize(self, pair: Option<NormalizedTradingPair>, status: NormalizedOrderStatus) -> NormalizedOrderAck {
+        NormalizedOrderAck {
+            exchange: CexExchange::Bybit,
+            pair,
+            order_id: self.order_id,
+            client_order_id: self.client_order_id,
+            status: Some(status)
+        }
+    }
+}
+
+/// result of `POST /v5/order/cancel-all`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BybitOrderAcks {
+    pub list: Vec<BybitOrderAck>
+}
+
+/// result of `GET /v5/order/realtime`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct BybitOpenOrders {
+    pub list: Vec<BybitOrderInner>
+}
@@ -5,7 +5,7 @@ use serde_with::serde_as;
 use tracing::warn;
 
 use crate::{
-    binance::rest_api::{BinanceSymbol, BinanceSymbolPlatform},
+    exchanges::coin_listings::ListedCoin,
     normalized::{
         rest_api::NormalizedRestApiDataTypes,
         types::{BlockchainCurrency, NormalizedCurrency}
@@ -89,9 +89,27 @@ impl BybitCoin {
     }
 }
 
-impl From<BinanceSymbol> for BybitCoin {
-    fn from(value: BinanceSymbol) -> Self {
-        Self { symbol: value.symbol, name: value.name, platform: value.platform.map(Into::into) }
+/// a [BybitCoin] in the coin listings
+#[derive(Debug, Clone, Deserialize)]
+pub struct BybitListedCoin {
+    #[serde(flatten)]
+    coin:     BybitCoin,
+    cmc_rank: u64
+}
+
+impl ListedCoin for BybitListedCoin {
+    fn symbol(&self) -> &str {
+        &self.coin.symbol
+    }
+
+    fn rank(&self) -> u64 {
+        self.cmc_rank
+    }
+}
+
+impl From<BybitListedCoin> for BybitCoin {
+    fn from(value: BybitListedCoin) -> Self {
+        value.coin
     }
 }
 
@@ -103,12 +121,6 @@ pub struct BybitProxyCoinPlatform {
     pub token_address: String
 }
 
-impl From<BinanceSymbolPlatform> for BybitProxyCoinPlatform {
-    fn from(value: BinanceSymbolPlatform) -> Self {
-        Self { symbol: value.symbol, name: value.name, token_address: value.token_address }
-    }
-}
-
 impl PartialEq<NormalizedCurrency> for BybitCoin {
     fn eq(&self, other: &NormalizedCurrency) -> bool {
         let blockchains = self.parse_blockchain().map(|p| vec![p]).unwrap_or_default();
@@ -0,0 +1,83 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+use crate::{
+    bybit::BybitTradingPair,
+    clients::ws::received_at,
+    normalized::{
+        rest_api::NormalizedRestApiDataTypes,
+        types::{NormalizedNumber, NormalizedTrade, TradeSide}
+    },
+    CexExchange
+};
+
+/// trades of a single pair from `/v5/market/recent-trade`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct BybitRecentTrades {
+    pub pair:   BybitTradingPair,
+    pub trades: Vec<BybitRestTrade>
+}
+
+impl BybitRecentTrades {
+    pub fn normalize(self) -> Vec<NormalizedTrade> {
+        self.trades
+            .into_iter()
+            .map(BybitRestTrade::normalize)
+            .collect()
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for BybitRecentTrades {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match other {
+            NormalizedRestApiDataTypes::Trades(other_trades) => self.clone().normalize() == *other_trades,
+            _ => false
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct BybitRestTrade {
+    #[serde(rename = "execId")]
+    pub trade_id:       String,
+    pub symbol:         BybitTradingPair,
+    #[serde_as(as = "DisplayFromStr")]
+    pub price:          NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub size:           NormalizedNumber,
+    pub side:           String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub time:           u64,
+    #[serde(rename = "isBlockTrade")]
+    pub is_block_trade: bool,
+    #[serde(default = "received_at")]
+    pub received_at:    DateTime<Utc>
+}
+
+impl BybitRestTrade {
+    pub fn normalize(self) -> NormalizedTrade {
+        NormalizedTrade {
+            exchange:      CexExchange::Bybit,
+            pair:          self.symbol.normalize(),
+            exchange_time: DateTime::from_timestamp_millis(self.time as i64),
+            received_at:   self.received_at,
+            side:          TradeSide::from_taker_side(&self.side),
+            price:         self.price,
+            amount:        self.size,
+            trade_id:      Some(self.trade_id)
+        }
+    }
+}
+
+/// the trades of a `/v5/market/recent-trade` request (newest first)
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct BybitRecentTradesPage {
+    pub(crate) result: BybitRecentTradesPageResult
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct BybitRecentTradesPageResult {
+    pub(crate) list: Vec<BybitRestTrade>
+}
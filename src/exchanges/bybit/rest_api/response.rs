@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use super::{BybitAllCoins, BybitAllInstruments, BybitCoin, BybitInstrument};
+use super::{BybitAllCoins, BybitAllInstruments, BybitAllKlines, BybitCoin, BybitInstrument, BybitRecentTrades};
 use crate::exchanges::normalized::rest_api::NormalizedRestApiDataTypes;
 
 #[serde_with::serde_as]
@@ -8,14 +8,18 @@ use crate::exchanges::normalized::rest_api::NormalizedRestApiDataTypes;
 #[serde(rename_all = "snake_case", tag = "type")]
 pub enum BybitRestApiResponse {
     Coins(BybitAllCoins),
-    Instruments(BybitAllInstruments)
+    Instruments(BybitAllInstruments),
+    Klines(BybitAllKlines),
+    RecentTrades(BybitRecentTrades)
 }
 
 impl BybitRestApiResponse {
     pub fn normalize(self) -> NormalizedRestApiDataTypes {
         match self {
             BybitRestApiResponse::Coins(v) => NormalizedRestApiDataTypes::AllCurrencies(v.normalize()),
-            BybitRestApiResponse::Instruments(v) => NormalizedRestApiDataTypes::AllInstruments(v.normalize())
+            BybitRestApiResponse::Instruments(v) => NormalizedRestApiDataTypes::AllInstruments(v.normalize()),
+            BybitRestApiResponse::Klines(v) => NormalizedRestApiDataTypes::Candles(v.normalize()),
+            BybitRestApiResponse::RecentTrades(v) => NormalizedRestApiDataTypes::Trades(v.normalize())
         }
     }
 
@@ -49,7 +53,9 @@ impl PartialEq<NormalizedRestApiDataTypes> for BybitRestApiResponse {
     fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
         match self {
             BybitRestApiResponse::Coins(vals) => vals == other,
-            BybitRestApiResponse::Instruments(vals) => vals == other
+            BybitRestApiResponse::Instruments(vals) => vals == other,
+            BybitRestApiResponse::Klines(vals) => vals == other,
+            BybitRestApiResponse::RecentTrades(vals) => vals == other
         }
     }
 }
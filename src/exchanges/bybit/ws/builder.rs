@@ -4,7 +4,7 @@ use super::{
 };
 use crate::{
     bybit::{Bybit, BybitTradingType},
-    clients::{rest_api::ExchangeApi, ws::MutliWsStreamBuilder},
+    clients::{rest_api::ExchangeApi, ws::MutliWsStreamBuilder, ExchangeCredentials},
     normalized::ws::NormalizedWsChannels
 };
 
@@ -15,7 +15,9 @@ const MAX_BYBIT_WS_CONNS_PER_STREAM: usize = 10;
 
 #[derive(Debug, Clone, Default)]
 pub struct BybitWsBuilder {
-    pub channels: Vec<BybitWsChannel>
+    pub channels:    Vec<BybitWsChannel>,
+    /// required for [BybitWsChannel::Account]
+    pub credentials: Option<ExchangeCredentials>
 }
 
 impl BybitWsBuilder {
@@ -25,16 +27,52 @@ impl BybitWsBuilder {
         self
     }
 
+    /// sets the credentials used to authenticate the private ws
+    pub fn with_credentials(mut self, credentials: ExchangeCredentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    fn new_exchange(&self, subscription: BybitSubscription) -> Bybit {
+        let exchange = Bybit::new_ws_subscription(subscription);
+        match self.credentials.clone() {
+            Some(credentials) => exchange.with_credentials(credentials),
+            None => exchange
+        }
+    }
+
+    /// the private topics are on a different url, so they are split out onto
+    /// their own stream
+    fn split_private(&self) -> (Vec<BybitWsChannel>, Option<Bybit>) {
+        let (private, public): (Vec<_>, Vec<_>) = self
+            .channels
+            .iter()
+            .cloned()
+            .partition(|ch| ch.is_private());
+        if private.is_empty() {
+            return (public, None)
+        }
+
+        let mut subscription = BybitSubscription::new();
+        private
+            .into_iter()
+            .for_each(|ch| subscription.add_channel(ch));
+
+        (public, Some(self.new_exchange(subscription)))
+    }
+
     /// builds a single ws instance of [Bybit], handling all channels on 1
     /// stream
+    ///
+    /// the private topics can't share the stream with public channels
     pub fn build_single(self) -> Bybit {
         let mut subscription = BybitSubscription::new();
 
         self.channels
-            .into_iter()
-            .for_each(|c| subscription.add_channel(c));
+            .iter()
+            .for_each(|c| subscription.add_channel(c.clone()));
 
-        Bybit::new_ws_subscription(subscription)
+        self.new_exchange(subscription)
     }
 
     /// builds many ws instances of the [Bybit] as the inner streams of
@@ -43,9 +81,10 @@ impl BybitWsBuilder {
     ///
     /// WARNING: too many channels may break the stream
     pub fn build_many_distributed(self) -> eyre::Result<MutliWsStreamBuilder<Bybit>> {
-        let stream_size = if self.channels.len() <= MAX_BYBIT_STREAMS { 1 } else { self.channels.len() / MAX_BYBIT_STREAMS };
+        let (channels, private_exchange) = self.split_private();
+        let stream_size = if channels.len() <= MAX_BYBIT_STREAMS { 1 } else { channels.len() / MAX_BYBIT_STREAMS };
 
-        let chunks = self.channels.chunks(stream_size).collect::<Vec<_>>();
+        let chunks = channels.chunks(stream_size).collect::<Vec<_>>();
 
         let split_exchange = chunks
             .into_iter()
@@ -54,8 +93,9 @@ impl BybitWsBuilder {
                 chk.iter()
                     .for_each(|ch| subscription.add_channel(ch.clone()));
 
-                Bybit::new_ws_subscription(subscription)
+                self.new_exchange(subscription)
             })
+            .chain(private_exchange)
             .collect();
 
         Ok(MutliWsStreamBuilder::new(split_exchange))
@@ -65,8 +105,8 @@ impl BybitWsBuilder {
     /// [MutliWsStreamBuilder], splitting the channels into different streams,
     /// each of size 1024
     pub fn build_many_packed(self, connections_per_stream: Option<usize>) -> eyre::Result<MutliWsStreamBuilder<Bybit>> {
-        let chunks = self
-            .channels
+        let (channels, private_exchange) = self.split_private();
+        let chunks = channels
             .chunks(connections_per_stream.unwrap_or(MAX_BYBIT_WS_CONNS_PER_STREAM))
             .collect::<Vec<_>>();
 
@@ -77,8 +117,9 @@ impl BybitWsBuilder {
                 chk.iter()
                     .for_each(|ch| subscription.add_channel(ch.clone()));
 
-                Bybit::new_ws_subscription(subscription)
+                self.new_exchange(subscription)
             })
+            .chain(private_exchange)
             .collect();
 
         Ok(MutliWsStreamBuilder::new(split_exchange))
@@ -90,12 +131,12 @@ impl BybitWsBuilder {
 
         let all_streams = this
             .channels
-            .into_iter()
+            .iter()
             .map(|ch| {
                 let mut subscription = BybitSubscription::new();
-                subscription.add_channel(ch);
+                subscription.add_channel(ch.clone());
 
-                Bybit::new_ws_subscription(subscription)
+                this.new_exchange(subscription)
             })
             .collect::<Vec<_>>();
 
@@ -122,21 +163,28 @@ impl BybitWsBuilder {
         chunks.into_iter().for_each(|chk| {
             let all_channels = channels
                 .iter()
-                .map(|ch| match ch {
-                    BybitWsChannelKind::Trade => BybitWsChannel::Trade(chk.to_vec()),
-                    BybitWsChannelKind::OrderbookL1 => BybitWsChannel::OrderbookL1(chk.to_vec())
+                .filter_map(|ch| match ch {
+                    BybitWsChannelKind::Trade => Some(BybitWsChannel::Trade(chk.to_vec())),
+                    BybitWsChannelKind::OrderbookL1 => Some(BybitWsChannel::OrderbookL1(chk.to_vec())),
+                    BybitWsChannelKind::Orderbook => Some(BybitWsChannel::Orderbook(chk.to_vec())),
+                    BybitWsChannelKind::Kline(interval) => Some(BybitWsChannel::Kline { pairs: chk.to_vec(), interval: *interval }),
+                    BybitWsChannelKind::Account => None
                 })
                 .collect::<Vec<_>>();
 
             this.channels.extend(all_channels);
         });
 
+        if channels.contains(&BybitWsChannelKind::Account) {
+            this.channels.push(BybitWsChannel::Account);
+        }
+
         Ok(this)
     }
 
     /// makes the builder from the normalized builder's map
-    pub(crate) fn make_from_normalized_map(map: Vec<NormalizedWsChannels>) -> eyre::Result<Self> {
-        let mut this = Self { channels: Vec::new() };
+    pub(crate) fn make_from_normalized_map(map: Vec<NormalizedWsChannels>, credentials: Option<ExchangeCredentials>) -> eyre::Result<Self> {
+        let mut this = Self { channels: Vec::new(), credentials };
 
         map.into_iter().try_for_each(|channel| {
             let this_channel: BybitWsChannel = channel.try_into()?;
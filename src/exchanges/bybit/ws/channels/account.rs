@@ -0,0 +1,223 @@
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr, NoneAsEmptyString};
+use tracing::warn;
+
+use crate::{
+    exchanges::{
+        bybit::pairs::BybitTradingPair,
+        normalized::types::{NormalizedBalanceUpdate, NormalizedFill, NormalizedOrderSide, NormalizedOrderStatus, NormalizedOrderUpdate}
+    },
+    normalized::types::NormalizedNumber,
+    CexExchange
+};
+
+/// `wallet` topic, sent with the balances of the coins that changed
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BybitWallet {
+    pub topic:         String,
+    #[serde(rename = "creationTime")]
+    pub creation_time: u64,
+    pub data:          Vec<BybitWalletAccount>
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BybitWalletAccount {
+    #[serde(rename = "accountType")]
+    pub account_type: String,
+    pub coin:         Vec<BybitWalletCoin>
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BybitWalletCoin {
+    pub coin:           String,
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "walletBalance")]
+    pub wallet_balance: NormalizedNumber,
+    /// empty for some account types
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(default)]
+    pub locked:         Option<NormalizedNumber>
+}
+
+impl BybitWallet {
+    pub fn normalize(self) -> Vec<NormalizedBalanceUpdate> {
+        let time = DateTime::from_timestamp_millis(self.creation_time as i64).unwrap();
+
+        self.data
+            .into_iter()
+            .flat_map(|acc| acc.coin)
+            .map(|c| {
+                let locked = c.locked.unwrap_or_default();
+                NormalizedBalanceUpdate { exchange: CexExchange::Bybit, symbol: c.coin, available: c.wallet_balance - locked, locked, time }
+            })
+            .collect()
+    }
+}
+
+impl PartialEq<Vec<NormalizedBalanceUpdate>> for BybitWallet {
+    fn eq(&self, other: &Vec<NormalizedBalanceUpdate>) -> bool {
+        let equals = self.clone().normalize() == *other;
+
+        if !equals {
+            warn!(target: "cex-exchanges::bybit", "bybit wallet: {:?}", self);
+            warn!(target: "cex-exchanges::bybit", "normalized balance updates: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+/// `order` topic
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BybitOrder {
+    pub topic: String,
+    pub data:  Vec<BybitOrderInner>
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BybitOrderInner {
+    pub symbol:          BybitTradingPair,
+    #[serde(rename = "orderId")]
+    pub order_id:        String,
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(rename = "orderLinkId")]
+    pub client_order_id: Option<String>,
+    #[serde_as(as = "DisplayFromStr")]
+    pub side:            NormalizedOrderSide,
+    #[serde(rename = "orderType")]
+    pub order_type:      String,
+    #[serde(rename = "orderStatus")]
+    pub order_status:    String,
+    /// empty or 0 for market orders
+    #[serde_as(as = "NoneAsEmptyString")]
+    pub price:           Option<NormalizedNumber>,
+    #[serde_as(as = "DisplayFromStr")]
+    pub qty:             NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "cumExecQty")]
+    pub cum_exec_qty:    NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "updatedTime")]
+    pub updated_time:    u64
+}
+
+impl BybitOrder {
+    pub fn normalize(self) -> Vec<NormalizedOrderUpdate> {
+        self.data.into_iter().map(|o| o.normalize()).collect()
+    }
+}
+
+impl BybitOrderInner {
+    pub fn normalize(self) -> NormalizedOrderUpdate {
+        let status = match self.order_status.as_str() {
+            "PartiallyFilled" => NormalizedOrderStatus::PartiallyFilled,
+            "Filled" => NormalizedOrderStatus::Filled,
+            "Cancelled" | "PartiallyFilledCanceled" | "Deactivated" => NormalizedOrderStatus::Canceled,
+            "Rejected" => NormalizedOrderStatus::Rejected,
+            // New, Untriggered & Triggered
+            _ => NormalizedOrderStatus::New
+        };
+
+        NormalizedOrderUpdate {
+            exchange: CexExchange::Bybit,
+            pair: self.symbol.normalize(),
+            order_id: self.order_id,
+            client_order_id: self.client_order_id,
+            side: self.side,
+            order_type: self.order_type.to_lowercase(),
+            status,
+            price: self.price.filter(|p| *p != NormalizedNumber::default()),
+            amount: Some(self.qty),
+            filled_amount: Some(self.cum_exec_qty),
+            time: DateTime::from_timestamp_millis(self.updated_time as i64).unwrap(),
+            last_fill: None
+        }
+    }
+}
+
+impl PartialEq<Vec<NormalizedOrderUpdate>> for BybitOrder {
+    fn eq(&self, other: &Vec<NormalizedOrderUpdate>) -> bool {
+        let equals = self.clone().normalize() == *other;
+
+        if !equals {
+            warn!(target: "cex-exchanges::bybit", "bybit order: {:?}", self);
+            warn!(target: "cex-exchanges::bybit", "normalized order updates: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+/// `execution` topic
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BybitExecution {
+    pub topic: String,
+    pub data:  Vec<BybitExecutionInner>
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BybitExecutionInner {
+    pub symbol:       BybitTradingPair,
+    #[serde(rename = "orderId")]
+    pub order_id:     String,
+    #[serde(rename = "execId")]
+    pub exec_id:      String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub side:         NormalizedOrderSide,
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "execPrice")]
+    pub exec_price:   NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "execQty")]
+    pub exec_qty:     NormalizedNumber,
+    /// positive for fees charged, negative for rebates
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(rename = "execFee", default)]
+    pub exec_fee:     Option<NormalizedNumber>,
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(rename = "feeCurrency", default)]
+    pub fee_currency: Option<String>,
+    #[serde(rename = "isMaker")]
+    pub is_maker:     bool,
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "execTime")]
+    pub exec_time:    u64
+}
+
+impl BybitExecution {
+    pub fn normalize(self) -> Vec<NormalizedFill> {
+        self.data
+            .into_iter()
+            .map(|e| NormalizedFill {
+                exchange:   CexExchange::Bybit,
+                pair:       e.symbol.normalize(),
+                order_id:   e.order_id,
+                trade_id:   e.exec_id,
+                side:       e.side,
+                price:      e.exec_price,
+                amount:     e.exec_qty,
+                fee:        e.exec_fee,
+                fee_symbol: e.fee_currency,
+                is_maker:   Some(e.is_maker),
+                time:       DateTime::from_timestamp_millis(e.exec_time as i64).unwrap()
+            })
+            .collect()
+    }
+}
+
+impl PartialEq<Vec<NormalizedFill>> for BybitExecution {
+    fn eq(&self, other: &Vec<NormalizedFill>) -> bool {
+        let equals = self.clone().normalize() == *other;
+
+        if !equals {
+            warn!(target: "cex-exchanges::bybit", "bybit execution: {:?}", self);
+            warn!(target: "cex-exchanges::bybit", "normalized fills: {:?}", other);
+        }
+
+        equals
+    }
+}
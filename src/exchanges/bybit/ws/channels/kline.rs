@@ -0,0 +1,153 @@
+use std::{fmt::Display, str::FromStr};
+
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use tracing::warn;
+
+use crate::{
+    bybit::BybitTradingPair,
+    normalized::types::{NormalizedCandle, NormalizedCandleInterval, NormalizedNumber},
+    CexExchange
+};
+
+/// kline channel (`kline.<interval>.<symbol>`), the pair is taken from the
+/// topic
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
+pub struct BybitKline {
+    pub topic:             String,
+    #[serde(rename = "type")]
+    pub kind:              String,
+    #[serde(rename = "ts")]
+    pub request_timestamp: u64,
+    pub data:              Vec<BybitKlineInner>
+}
+
+impl BybitKline {
+    pub fn pair(&self) -> BybitTradingPair {
+        BybitTradingPair(
+            self.topic
+                .rsplit('.')
+                .next()
+                .unwrap_or_default()
+                .to_string()
+        )
+    }
+
+    pub fn normalize(self) -> Vec<NormalizedCandle> {
+        let pair = self.pair();
+        self.data
+            .into_iter()
+            .map(|inner| inner.normalize(&pair))
+            .collect()
+    }
+}
+
+impl PartialEq<Vec<NormalizedCandle>> for BybitKline {
+    fn eq(&self, other: &Vec<NormalizedCandle>) -> bool {
+        let pair = self.pair();
+        let equals = self
+            .data
+            .iter()
+            .all(|inner| other.contains(&inner.clone().normalize(&pair)));
+
+        if !equals {
+            warn!(target: "cex-exchanges::bybit", "bybit kline: {:?}", self);
+            warn!(target: "cex-exchanges::bybit", "normalized candles: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
+pub struct BybitKlineInner {
+    pub start:     u64,
+    pub end:       u64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub interval:  BybitKlineInterval,
+    #[serde_as(as = "DisplayFromStr")]
+    pub open:      NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub close:     NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub high:      NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub low:       NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub volume:    NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub turnover:  NormalizedNumber,
+    pub confirm:   bool,
+    pub timestamp: u64
+}
+
+impl BybitKlineInner {
+    fn normalize(self, pair: &BybitTradingPair) -> NormalizedCandle {
+        let start_time = DateTime::from_timestamp_millis(self.start as i64).unwrap();
+
+        NormalizedCandle {
+            exchange: CexExchange::Bybit,
+            pair: pair.normalize(),
+            interval: self.interval.0,
+            start_time,
+            end_time: self.interval.0.end_time(start_time),
+            open: self.open,
+            high: self.high,
+            low: self.low,
+            close: self.close,
+            volume: self.volume,
+            trade_count: None,
+            closed: Some(self.confirm)
+        }
+    }
+}
+
+/// the interval as used in bybit's topics & requests
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
+pub struct BybitKlineInterval(pub NormalizedCandleInterval);
+
+impl Display for BybitKlineInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            NormalizedCandleInterval::OneMinute => write!(f, "1"),
+            NormalizedCandleInterval::ThreeMinutes => write!(f, "3"),
+            NormalizedCandleInterval::FiveMinutes => write!(f, "5"),
+            NormalizedCandleInterval::FifteenMinutes => write!(f, "15"),
+            NormalizedCandleInterval::ThirtyMinutes => write!(f, "30"),
+            NormalizedCandleInterval::OneHour => write!(f, "60"),
+            NormalizedCandleInterval::TwoHours => write!(f, "120"),
+            NormalizedCandleInterval::FourHours => write!(f, "240"),
+            NormalizedCandleInterval::SixHours => write!(f, "360"),
+            NormalizedCandleInterval::TwelveHours => write!(f, "720"),
+            NormalizedCandleInterval::OneDay => write!(f, "D"),
+            NormalizedCandleInterval::OneWeek => write!(f, "W")
+        }
+    }
+}
+
+impl FromStr for BybitKlineInterval {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let interval = match s {
+            "1" => NormalizedCandleInterval::OneMinute,
+            "3" => NormalizedCandleInterval::ThreeMinutes,
+            "5" => NormalizedCandleInterval::FiveMinutes,
+            "15" => NormalizedCandleInterval::FifteenMinutes,
+            "30" => NormalizedCandleInterval::ThirtyMinutes,
+            "60" => NormalizedCandleInterval::OneHour,
+            "120" => NormalizedCandleInterval::TwoHours,
+            "240" => NormalizedCandleInterval::FourHours,
+            "360" => NormalizedCandleInterval::SixHours,
+            "720" => NormalizedCandleInterval::TwelveHours,
+            "D" => NormalizedCandleInterval::OneDay,
+            "W" => NormalizedCandleInterval::OneWeek,
+            _ => return Err(eyre::ErrReport::msg(format!("'{s}' is not a valid bybit kline interval")))
+        };
+
+        Ok(Self(interval))
+    }
+}
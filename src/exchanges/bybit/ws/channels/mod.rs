@@ -1,3 +1,9 @@
+mod account;
+pub use account::*;
+
+mod kline;
+pub use kline::*;
+
 mod orderbook;
 pub use orderbook::*;
 
@@ -10,7 +16,7 @@ use crate::{
     exchanges::{
         bybit::pairs::BybitTradingPair,
         normalized::{
-            types::{NormalizedTradingPair, RawTradingPair},
+            types::{NormalizedCandleInterval, NormalizedTradingPair, RawTradingPair},
             ws::NormalizedWsChannels
         }
     },
@@ -20,7 +26,14 @@ use crate::{
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum BybitWsChannel {
     Trade(Vec<BybitTradingPair>),
-    OrderbookL1(Vec<BybitTradingPair>)
+    OrderbookL1(Vec<BybitTradingPair>),
+    Orderbook(Vec<BybitTradingPair>),
+    Kline {
+        pairs:    Vec<BybitTradingPair>,
+        interval: NormalizedCandleInterval
+    },
+    /// private `wallet`, `order` & `execution` topics, requires credentials
+    Account
 }
 
 impl BybitWsChannel {
@@ -46,6 +59,33 @@ impl BybitWsChannel {
         Self::new_from_normalized(normalized, BybitWsChannel::OrderbookL1(Vec::new()))
     }
 
+    /// builds the 50 level orderbook channel from a vec of raw trading
+    /// pairs return an error if the symbol is incorrectly formatted
+    pub fn new_orderbook(pairs: Vec<RawTradingPair>) -> eyre::Result<Self> {
+        let normalized = pairs
+            .into_iter()
+            .map(|pair| pair.get_normalized_pair(CexExchange::Bybit))
+            .collect();
+
+        Self::new_from_normalized(normalized, BybitWsChannel::Orderbook(Vec::new()))
+    }
+
+    /// builds the kline channel from a vec of raw trading pairs
+    /// return an error if the symbol is incorrectly formatted
+    pub fn new_kline(pairs: Vec<RawTradingPair>, interval: NormalizedCandleInterval) -> eyre::Result<Self> {
+        let normalized = pairs
+            .into_iter()
+            .map(|pair| pair.get_normalized_pair(CexExchange::Bybit))
+            .collect();
+
+        Self::new_from_normalized(normalized, BybitWsChannel::Kline { pairs: Vec::new(), interval })
+    }
+
+    /// if the channel requires authentication
+    pub fn is_private(&self) -> bool {
+        matches!(self, BybitWsChannel::Account)
+    }
+
     pub(crate) fn new_from_normalized(pairs: Vec<NormalizedTradingPair>, kind: BybitWsChannel) -> eyre::Result<Self> {
         match kind {
             BybitWsChannel::Trade(_) => Ok(BybitWsChannel::Trade(
@@ -59,14 +99,31 @@ impl BybitWsChannel {
                     .into_iter()
                     .map(TryInto::try_into)
                     .collect::<Result<_, _>>()?
-            ))
+            )),
+            BybitWsChannel::Orderbook(_) => Ok(BybitWsChannel::Orderbook(
+                pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<_, _>>()?
+            )),
+            BybitWsChannel::Kline { interval, .. } => Ok(BybitWsChannel::Kline {
+                pairs: pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<_, _>>()?,
+                interval
+            }),
+            BybitWsChannel::Account => Ok(BybitWsChannel::Account)
         }
     }
 
     pub fn count_entries(&self) -> usize {
         match self {
             BybitWsChannel::Trade(vals) => vals.len(),
-            BybitWsChannel::OrderbookL1(vals) => vals.len()
+            BybitWsChannel::OrderbookL1(vals) => vals.len(),
+            BybitWsChannel::Orderbook(vals) => vals.len(),
+            BybitWsChannel::Kline { pairs, .. } => pairs.len(),
+            BybitWsChannel::Account => 1
         }
     }
 }
@@ -75,7 +132,10 @@ impl Display for BybitWsChannel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             BybitWsChannel::Trade(_) => write!(f, "trade"),
-            BybitWsChannel::OrderbookL1(_) => write!(f, "orderbook.1")
+            BybitWsChannel::OrderbookL1(_) => write!(f, "orderbook.1"),
+            BybitWsChannel::Orderbook(_) => write!(f, "orderbook.50"),
+            BybitWsChannel::Kline { interval, .. } => write!(f, "kline.{}", BybitKlineInterval(*interval)),
+            BybitWsChannel::Account => write!(f, "account")
         }
     }
 }
@@ -87,6 +147,12 @@ impl TryFrom<String> for BybitWsChannel {
         match value.to_lowercase().as_str() {
             "trade" | "publicTrade" => Ok(Self::Trade(Vec::new())),
             "orderbook.1" | "quote" => Ok(Self::OrderbookL1(Vec::new())),
+            "orderbook.50" | "orderbook" => Ok(Self::Orderbook(Vec::new())),
+            "account" => Ok(Self::Account),
+            kline if kline.starts_with("kline.") => {
+                let interval: BybitKlineInterval = value.trim_start_matches("kline.").parse()?;
+                Ok(Self::Kline { pairs: Vec::new(), interval: interval.0 })
+            }
             _ => Err(eyre::ErrReport::msg(format!("channel is not valid: {value}")))
         }
     }
@@ -114,6 +180,26 @@ impl TryFrom<NormalizedWsChannels> for BybitWsChannel {
 
                 Ok(BybitWsChannel::OrderbookL1(norm_pairs))
             }
+
+            NormalizedWsChannels::OrderBook { pairs, .. } => {
+                let norm_pairs = pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, Self::Error>>()?;
+
+                Ok(BybitWsChannel::Orderbook(norm_pairs))
+            }
+
+            NormalizedWsChannels::Candles { pairs, interval } => {
+                let norm_pairs = pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, Self::Error>>()?;
+
+                Ok(BybitWsChannel::Kline { pairs: norm_pairs, interval })
+            }
+
+            NormalizedWsChannels::Account(_) => Ok(BybitWsChannel::Account)
         }
     }
 }
@@ -121,14 +207,20 @@ impl TryFrom<NormalizedWsChannels> for BybitWsChannel {
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum BybitWsChannelKind {
     Trade,
-    OrderbookL1
+    OrderbookL1,
+    Orderbook,
+    Kline(NormalizedCandleInterval),
+    Account
 }
 
 impl Display for BybitWsChannelKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             BybitWsChannelKind::Trade => write!(f, "publicTrade"),
-            BybitWsChannelKind::OrderbookL1 => write!(f, "orderbook.1")
+            BybitWsChannelKind::OrderbookL1 => write!(f, "orderbook.1"),
+            BybitWsChannelKind::Orderbook => write!(f, "orderbook.50"),
+            BybitWsChannelKind::Kline(interval) => write!(f, "kline.{}", BybitKlineInterval(*interval)),
+            BybitWsChannelKind::Account => write!(f, "account")
         }
     }
 }
@@ -137,7 +229,10 @@ impl From<&BybitWsChannel> for BybitWsChannelKind {
     fn from(value: &BybitWsChannel) -> Self {
         match value {
             BybitWsChannel::Trade(_) => BybitWsChannelKind::Trade,
-            BybitWsChannel::OrderbookL1(_) => BybitWsChannelKind::OrderbookL1
+            BybitWsChannel::OrderbookL1(_) => BybitWsChannelKind::OrderbookL1,
+            BybitWsChannel::Orderbook(_) => BybitWsChannelKind::Orderbook,
+            BybitWsChannel::Kline { interval, .. } => BybitWsChannelKind::Kline(*interval),
+            BybitWsChannel::Account => BybitWsChannelKind::Account
         }
     }
 }
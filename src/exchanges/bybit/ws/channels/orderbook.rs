@@ -3,7 +3,12 @@ use serde::{Deserialize, Serialize};
 use serde_with::serde_as;
 use tracing::warn;
 
-use crate::{bybit::BybitTradingPair, normalized::types::NormalizedQuote, CexExchange};
+use crate::{
+    bybit::BybitTradingPair,
+    clients::ws::received_at,
+    normalized::types::{NormalizedBookLevel, NormalizedNumber, NormalizedOrderBookUpdate, NormalizedOrderBookUpdateKind, NormalizedQuote},
+    CexExchange
+};
 
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
@@ -15,7 +20,9 @@ pub struct BybitOrderbook {
     pub request_timestamp: u64,
     #[serde(rename = "cts")]
     pub timestamp:         u64,
-    pub data:              BybitOrderbookInner
+    pub data:              BybitOrderbookInner,
+    #[serde(default = "received_at")]
+    pub received_at:       DateTime<Utc>
 }
 
 impl BybitOrderbook {
@@ -24,19 +31,52 @@ impl BybitOrderbook {
         let ask = self.data.best_ask.first();
         if let (Some(b), Some(a)) = (bid.as_ref(), ask.as_ref()) {
             Some(NormalizedQuote {
-                exchange:   CexExchange::Bybit,
-                pair:       self.data.symbol.normalize(),
-                time:       DateTime::<Utc>::from_timestamp_millis(self.timestamp as i64).unwrap(),
-                ask_amount: a.amount,
-                ask_price:  a.price,
-                bid_amount: b.amount,
-                bid_price:  b.price,
-                quote_id:   Some(self.data.update_id.to_string())
+                exchange:      CexExchange::Bybit,
+                pair:          self.data.symbol.normalize(),
+                exchange_time: DateTime::<Utc>::from_timestamp_millis(self.timestamp as i64),
+                received_at:   self.received_at,
+                ask_amount:    a.amount,
+                ask_price:     a.price,
+                bid_amount:    b.amount,
+                bid_price:     b.price,
+                quote_id:      Some(self.data.update_id.to_string())
             })
         } else {
             None
         }
     }
+
+    /// normalizes all levels of the book
+    pub fn normalize_book(self) -> NormalizedOrderBookUpdate {
+        NormalizedOrderBookUpdate {
+            exchange:        CexExchange::Bybit,
+            pair:            self.data.symbol.normalize(),
+            time:            DateTime::<Utc>::from_timestamp_millis(self.timestamp as i64).unwrap(),
+            kind:            self.update_kind(),
+            bids:            self
+                .data
+                .best_bid
+                .into_iter()
+                .map(|l| NormalizedBookLevel::new(l.price, l.amount))
+                .collect(),
+            asks:            self
+                .data
+                .best_ask
+                .into_iter()
+                .map(|l| NormalizedBookLevel::new(l.price, l.amount))
+                .collect(),
+            first_update_id: Some(self.data.update_id),
+            last_update_id:  Some(self.data.update_id)
+        }
+    }
+
+    fn update_kind(&self) -> NormalizedOrderBookUpdateKind {
+        if &self.kind == "snapshot" {
+            NormalizedOrderBookUpdateKind::Snapshot
+        } else {
+            NormalizedOrderBookUpdateKind::Delta
+        }
+    }
 }
 
 impl PartialEq<Vec<NormalizedQuote>> for BybitOrderbook {
@@ -58,7 +98,8 @@ impl PartialEq<Vec<NormalizedQuote>> for BybitOrderbook {
             };
             other_data.exchange == CexExchange::Bybit
                 && other_data.pair == self.data.symbol.normalize()
-                && other_data.time == DateTime::<Utc>::from_timestamp_millis(self.timestamp as i64).unwrap()
+                && other_data.exchange_time == DateTime::<Utc>::from_timestamp_millis(self.timestamp as i64)
+                && other_data.received_at == self.received_at
                 && other_data.quote_id == Some(self.data.update_id.to_string())
                 && data_bids
         });
@@ -72,6 +113,66 @@ impl PartialEq<Vec<NormalizedQuote>> for BybitOrderbook {
     }
 }
 
+impl PartialEq<NormalizedOrderBookUpdate> for BybitOrderbook {
+    fn eq(&self, other: &NormalizedOrderBookUpdate) -> bool {
+        let equals = other.exchange == CexExchange::Bybit
+            && other.pair == self.data.symbol.normalize()
+            && other.time == DateTime::<Utc>::from_timestamp_millis(self.timestamp as i64).unwrap()
+            && other.kind == self.update_kind()
+            && other
+                .bids
+                .iter()
+                .map(|l| (l.price, l.amount))
+                .eq(self.data.best_bid.iter().map(|l| (l.price, l.amount)))
+            && other
+                .asks
+                .iter()
+                .map(|l| (l.price, l.amount))
+                .eq(self.data.best_ask.iter().map(|l| (l.price, l.amount)))
+            && other.last_update_id == Some(self.data.update_id);
+
+        if !equals {
+            warn!(target: "cex-exchanges::bybit", "bybit orderbook: {:?}", self);
+            warn!(target: "cex-exchanges::bybit", "normalized order book: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+/// order book snapshot from the rest api (`/v5/market/orderbook`), used to
+/// resync the `orderbook.50` channel
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
+pub struct BybitOrderbookSnapshot {
+    pub result: BybitOrderbookSnapshotResult
+}
+
+impl BybitOrderbookSnapshot {
+    pub fn into_orderbook(self, depth: usize) -> BybitOrderbook {
+        BybitOrderbook {
+            topic:             format!("orderbook.{depth}.{}", self.result.data.symbol),
+            kind:              "snapshot".to_string(),
+            request_timestamp: self.result.timestamp,
+            timestamp:         self
+                .result
+                .matching_timestamp
+                .unwrap_or(self.result.timestamp),
+            data:              self.result.data,
+            received_at:       received_at()
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
+pub struct BybitOrderbookSnapshotResult {
+    #[serde(rename = "ts")]
+    pub timestamp:          u64,
+    #[serde(rename = "cts", default)]
+    pub matching_timestamp: Option<u64>,
+    #[serde(flatten)]
+    pub data:               BybitOrderbookInner
+}
+
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
 pub struct BybitOrderbookInner {
@@ -89,8 +190,8 @@ pub struct BybitOrderbookInner {
 
 #[derive(Debug, Serialize, Clone, PartialEq, PartialOrd)]
 pub struct BybitBidAsk {
-    pub price:  f64,
-    pub amount: f64
+    pub price:  NormalizedNumber,
+    pub amount: NormalizedNumber
 }
 
 impl<'de> Deserialize<'de> for BybitBidAsk {
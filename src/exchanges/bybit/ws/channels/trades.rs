@@ -4,7 +4,12 @@ use serde_with::{serde_as, DisplayFromStr};
 use tracing::warn;
 
 use crate::{
-    exchanges::{bybit::pairs::BybitTradingPair, normalized::types::NormalizedTrade},
+    clients::ws::received_at,
+    exchanges::{
+        bybit::pairs::BybitTradingPair,
+        normalized::types::{NormalizedTrade, TradeSide}
+    },
+    normalized::types::NormalizedNumber,
     CexExchange
 };
 
@@ -16,7 +21,9 @@ pub struct BybitTrade {
     pub kind:              String,
     #[serde(rename = "ts")]
     pub request_timestamp: u64,
-    pub data:              Vec<BybitTradeInner>
+    pub data:              Vec<BybitTradeInner>,
+    #[serde(default = "received_at")]
+    pub received_at:       DateTime<Utc>
 }
 
 impl BybitTrade {
@@ -24,13 +31,14 @@ impl BybitTrade {
         self.data
             .into_iter()
             .map(|inner| NormalizedTrade {
-                exchange: CexExchange::Bybit,
-                pair:     inner.pair.normalize(),
-                time:     DateTime::<Utc>::from_timestamp_millis(inner.timestamp as i64).unwrap(),
-                side:     inner.side.to_lowercase(),
-                price:    inner.price,
-                amount:   inner.amount,
-                trade_id: Some(inner.trade_id.to_string())
+                exchange:      CexExchange::Bybit,
+                pair:          inner.pair.normalize(),
+                exchange_time: DateTime::<Utc>::from_timestamp_millis(inner.timestamp as i64),
+                received_at:   self.received_at,
+                side:          TradeSide::from_taker_side(&inner.side),
+                price:         inner.price,
+                amount:        inner.amount,
+                trade_id:      Some(inner.trade_id.to_string())
             })
             .collect()
     }
@@ -42,8 +50,9 @@ impl PartialEq<Vec<NormalizedTrade>> for BybitTrade {
             other.iter().any(|other_data| {
                 let equals = other_data.exchange == CexExchange::Bybit
                     && other_data.pair == inner.pair.normalize()
-                    && other_data.time == DateTime::<Utc>::from_timestamp_millis(inner.timestamp as i64).unwrap()
-                    && other_data.side == inner.side.to_lowercase()
+                    && other_data.exchange_time == DateTime::<Utc>::from_timestamp_millis(inner.timestamp as i64)
+                    && other_data.received_at == self.received_at
+                    && other_data.side == TradeSide::from_taker_side(&inner.side)
                     && other_data.price == inner.price
                     && other_data.amount == inner.amount
                     && other_data.trade_id.as_ref().unwrap() == &inner.trade_id.to_string();
@@ -71,10 +80,10 @@ pub struct BybitTradeInner {
     pub side: String,
     #[serde_as(as = "DisplayFromStr")]
     #[serde(rename = "v")]
-    pub amount: f64,
+    pub amount: NormalizedNumber,
     #[serde_as(as = "DisplayFromStr")]
     #[serde(rename = "p")]
-    pub price: f64,
+    pub price: NormalizedNumber,
     #[serde(rename = "L")]
     pub direction_of_price_change: Option<String>,
     #[serde_as(as = "DisplayFromStr")]
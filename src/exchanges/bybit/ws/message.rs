@@ -2,8 +2,12 @@ use eyre::Ok;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use super::channels::{BybitOrderbook, BybitTrade};
-use crate::{clients::ws::CriticalWsMessage, exchanges::normalized::ws::NormalizedWsDataTypes, CexExchange};
+use super::channels::{BybitExecution, BybitKline, BybitOrder, BybitOrderbook, BybitTrade, BybitWallet};
+use crate::{
+    clients::ws::{CriticalWsMessage, SequenceKind, WsSequence},
+    exchanges::normalized::ws::NormalizedWsDataTypes,
+    CexExchange
+};
 
 #[serde_with::serde_as]
 #[derive(Debug, Clone, Serialize)]
@@ -11,19 +15,45 @@ use crate::{clients::ws::CriticalWsMessage, exchanges::normalized::ws::Normalize
 pub enum BybitWsMessage {
     Trade(BybitTrade),
     OrderbookL1(BybitOrderbook),
+    Orderbook(BybitOrderbook),
+    Kline(BybitKline),
+    Wallet(BybitWallet),
+    Order(BybitOrder),
+    Execution(BybitExecution),
     SuscriptionResponse { id: String, msg: String }
 }
 
 impl BybitWsMessage {
     fn try_deserialize(value: Value) -> eyre::Result<Self> {
+        match value.get("topic").and_then(|t| t.as_str()) {
+            Some("wallet") => return Ok(Self::Wallet(serde_json::from_value(value)?)),
+            Some("order") => return Ok(Self::Order(serde_json::from_value(value)?)),
+            Some("execution") => return Ok(Self::Execution(serde_json::from_value(value)?)),
+            _ => ()
+        }
+
+        let is_kline = value
+            .get("topic")
+            .and_then(|t| t.as_str())
+            .map(|t| t.starts_with("kline."))
+            .unwrap_or_default();
+        if is_kline {
+            return Ok(Self::Kline(serde_json::from_value(value)?))
+        }
+
         let try_trade = serde_json::from_value(value.clone());
         if try_trade.is_ok() {
             return Ok(Self::Trade(try_trade?))
         }
 
-        let try_ticker = serde_json::from_value(value.clone());
-        if try_ticker.is_ok() {
-            return Ok(Self::OrderbookL1(try_ticker?))
+        let try_orderbook: Result<BybitOrderbook, _> = serde_json::from_value(value.clone());
+        if try_orderbook.is_ok() {
+            let orderbook = try_orderbook?;
+            if orderbook.topic.starts_with("orderbook.1.") {
+                return Ok(Self::OrderbookL1(orderbook))
+            } else {
+                return Ok(Self::Orderbook(orderbook))
+            }
         }
 
         let conn_id = value.get("conn_id");
@@ -55,6 +85,11 @@ impl BybitWsMessage {
         match self {
             BybitWsMessage::Trade(v) => NormalizedWsDataTypes::Trades(v.normalize()),
             BybitWsMessage::OrderbookL1(v) => NormalizedWsDataTypes::Quotes(v.normalize().map(|v| vec![v]).unwrap_or_default()),
+            BybitWsMessage::Orderbook(v) => NormalizedWsDataTypes::OrderBook(v.normalize_book()),
+            BybitWsMessage::Kline(v) => NormalizedWsDataTypes::Candles(v.normalize()),
+            BybitWsMessage::Wallet(v) => NormalizedWsDataTypes::BalanceUpdates(v.normalize()),
+            BybitWsMessage::Order(v) => NormalizedWsDataTypes::OrderUpdates(v.normalize()),
+            BybitWsMessage::Execution(v) => NormalizedWsDataTypes::Fills(v.normalize()),
             BybitWsMessage::SuscriptionResponse { id, msg } => NormalizedWsDataTypes::Other {
                 exchange: CexExchange::Bybit,
                 kind:     "subscribe".to_string(),
@@ -69,6 +104,11 @@ impl PartialEq<NormalizedWsDataTypes> for BybitWsMessage {
         match (self, other) {
             (BybitWsMessage::Trade(this), NormalizedWsDataTypes::Trades(that)) => this == that,
             (BybitWsMessage::OrderbookL1(this), NormalizedWsDataTypes::Quotes(that)) => this == that,
+            (BybitWsMessage::Orderbook(this), NormalizedWsDataTypes::OrderBook(that)) => this == that,
+            (BybitWsMessage::Kline(this), NormalizedWsDataTypes::Candles(that)) => this == that,
+            (BybitWsMessage::Wallet(this), NormalizedWsDataTypes::BalanceUpdates(that)) => this == that,
+            (BybitWsMessage::Order(this), NormalizedWsDataTypes::OrderUpdates(that)) => this == that,
+            (BybitWsMessage::Execution(this), NormalizedWsDataTypes::Fills(that)) => this == that,
             (BybitWsMessage::SuscriptionResponse { .. }, NormalizedWsDataTypes::Other { .. }) => true,
             _ => false
         }
@@ -77,4 +117,25 @@ impl PartialEq<NormalizedWsDataTypes> for BybitWsMessage {
 
 impl CriticalWsMessage for BybitWsMessage {
     fn make_critical(&mut self, _msg: String) {}
+
+    fn sequence(&self) -> Option<WsSequence> {
+        match self {
+            BybitWsMessage::OrderbookL1(v) => Some(WsSequence {
+                channel: "orderbook.1",
+                pair:    v.data.symbol.normalize(),
+                kind:    SequenceKind::Increasing(v.data.cross_sequence),
+                resync:  false
+            }),
+            BybitWsMessage::Orderbook(v) => {
+                let kind = if &v.kind == "snapshot" {
+                    SequenceKind::Snapshot(Some(v.data.update_id))
+                } else {
+                    SequenceKind::Contiguous { first: v.data.update_id, last: v.data.update_id }
+                };
+
+                Some(WsSequence { channel: "orderbook.50", pair: v.data.symbol.normalize(), kind, resync: true })
+            }
+            _ => None
+        }
+    }
 }
@@ -3,7 +3,9 @@ use std::collections::HashSet;
 use serde::Serialize;
 
 use super::channels::{BybitWsChannel, BybitWsChannelKind};
-use crate::bybit::BybitTradingPair;
+use crate::{bybit::BybitTradingPair, clients::ExchangeCredentials};
+
+const PRIVATE_ACCOUNT_TOPICS: [&str; 3] = ["wallet", "order", "execution"];
 
 #[derive(Debug, Default, Clone, Serialize)]
 pub struct BybitSubscription {
@@ -22,16 +24,55 @@ impl BybitSubscription {
     }
 
     pub fn remove_pair(&mut self, pair: &BybitTradingPair) -> bool {
-        self.args.retain(|p| &p.trading_pair != pair);
+        self.args.retain(|p| p.trading_pair.as_ref() != Some(pair));
 
         self.args.is_empty()
     }
+
+    /// adds the topics of the channel, returning the request to subscribe to
+    /// the new ones
+    pub(crate) fn subscribe(&mut self, channel: BybitWsChannel) -> Option<Self> {
+        let new = Vec::<BybitSubscriptionInner>::from(channel)
+            .into_iter()
+            .filter(|p| !self.args.contains(p))
+            .collect::<Vec<_>>();
+        self.args.extend(new.clone());
+
+        (!new.is_empty()).then(|| BybitSubscription { op: "subscribe".to_string(), args: new })
+    }
+
+    /// removes the topics of the channel, returning the request to
+    /// unsubscribe from the ones that were subscribed to
+    pub(crate) fn unsubscribe(&mut self, channel: BybitWsChannel) -> Option<Self> {
+        let removed = Vec::<BybitSubscriptionInner>::from(channel)
+            .into_iter()
+            .filter(|p| self.args.contains(p))
+            .collect::<Vec<_>>();
+        self.args.retain(|p| !removed.contains(p));
+
+        (!removed.is_empty()).then(|| BybitSubscription { op: "unsubscribe".to_string(), args: removed })
+    }
+
+    /// the request to unsubscribe from every channel
+    pub(crate) fn unsubscribe_all(&self) -> Option<Self> {
+        (!self.args.is_empty()).then(|| BybitSubscription { op: "unsubscribe".to_string(), args: self.args.clone() })
+    }
+
+    pub(crate) fn has_private_channels(&self) -> bool {
+        self.args.iter().any(|a| a.private_topic.is_some())
+    }
+
+    pub(crate) fn has_public_channels(&self) -> bool {
+        self.args.iter().any(|a| a.private_topic.is_none())
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 struct BybitSubscriptionInner {
-    channel:      BybitWsChannelKind,
-    trading_pair: BybitTradingPair
+    channel:       BybitWsChannelKind,
+    trading_pair:  Option<BybitTradingPair>,
+    /// private topics aren't suffixed with a pair
+    private_topic: Option<&'static str>
 }
 
 impl Serialize for BybitSubscriptionInner {
@@ -39,7 +80,11 @@ impl Serialize for BybitSubscriptionInner {
     where
         S: serde::Serializer
     {
-        format!("{}.{}", self.channel, self.trading_pair.0.to_uppercase()).serialize(serializer)
+        match (self.private_topic, &self.trading_pair) {
+            (Some(topic), _) => topic.serialize(serializer),
+            (None, Some(pair)) => format!("{}.{}", self.channel, pair.0.to_uppercase()).serialize(serializer),
+            (None, None) => self.channel.to_string().serialize(serializer)
+        }
     }
 }
 
@@ -47,6 +92,13 @@ impl From<BybitWsChannel> for Vec<BybitSubscriptionInner> {
     fn from(val: BybitWsChannel) -> Self {
         let channel = (&val).into();
 
+        if val == BybitWsChannel::Account {
+            return PRIVATE_ACCOUNT_TOPICS
+                .into_iter()
+                .map(|topic| BybitSubscriptionInner { channel, trading_pair: None, private_topic: Some(topic) })
+                .collect()
+        }
+
         let all_pairs: Vec<_> = match val {
             BybitWsChannel::Trade(pairs) => pairs
                 .into_iter()
@@ -57,12 +109,39 @@ impl From<BybitWsChannel> for Vec<BybitSubscriptionInner> {
                 .into_iter()
                 .collect::<HashSet<_>>()
                 .into_iter()
-                .collect()
+                .collect(),
+            BybitWsChannel::Orderbook(pairs) => pairs
+                .into_iter()
+                .collect::<HashSet<_>>()
+                .into_iter()
+                .collect(),
+            BybitWsChannel::Kline { pairs, .. } => pairs
+                .into_iter()
+                .collect::<HashSet<_>>()
+                .into_iter()
+                .collect(),
+            BybitWsChannel::Account => Vec::new()
         };
 
         all_pairs
             .into_iter()
-            .map(|p| BybitSubscriptionInner { channel, trading_pair: p })
+            .map(|p| BybitSubscriptionInner { channel, trading_pair: Some(p), private_topic: None })
             .collect()
     }
 }
+
+/// `auth` request sent before subscribing to the private topics
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct BybitAuth {
+    op:   String,
+    args: (String, u64, String)
+}
+
+impl BybitAuth {
+    /// `expires` is the unix time (millis) the request stops being valid
+    pub(crate) fn new(credentials: &ExchangeCredentials, expires: u64) -> eyre::Result<Self> {
+        let signature = credentials.sign_hex(&format!("GET/realtime{expires}"))?;
+
+        Ok(Self { op: "auth".to_string(), args: (credentials.api_key.clone(), expires, signature) })
+    }
+}
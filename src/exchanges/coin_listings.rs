@@ -0,0 +1,113 @@
+use std::collections::{HashMap, HashSet};
+
+use reqwest::header;
+use serde::Deserialize;
+use tracing::{debug, trace, warn};
+
+use crate::{
+    clients::{rate_limiter, rest_api::RestApiError, RateLimitKind},
+    CexExchange
+};
+
+/// binance's web api, it proxies coinmarketcap's coin listings
+pub(crate) const BASE_LISTINGS_URL: &str = "https://www.binance.com";
+const LISTINGS_PAGE_SIZE: u64 = 5000;
+const MAX_LISTINGS_ERRORS: usize = 5;
+
+/// a coin in the listings
+pub(crate) trait ListedCoin: for<'de> Deserialize<'de> {
+    fn symbol(&self) -> &str;
+
+    /// the coin with the lowest rank is kept when a symbol is listed more than
+    /// once
+    fn rank(&self) -> u64;
+}
+
+/// pages through the listings for the coins of `symbols`, the requests count
+/// against the rest rate limit of `exchange`
+pub(crate) async fn get_listed_coins<C: ListedCoin>(
+    web_client: &reqwest::Client,
+    exchange: CexExchange,
+    web_url: &str,
+    symbols: &HashSet<String>
+) -> Result<Vec<C>, RestApiError> {
+    let mut query_start = 1;
+    let mut coins: HashMap<String, C> = HashMap::new();
+    let mut err_count = MAX_LISTINGS_ERRORS;
+    loop {
+        debug!(target: "cex-exchanges::listings", "starting {exchange} listings iteration {query_start}");
+        let page = match listings_page::<C>(web_client, exchange, web_url, query_start).await {
+            Ok(vals) => {
+                if vals.is_empty() {
+                    trace!(target: "cex-exchanges::listings", "no coins found in valid call - breaking loop");
+                    break
+                }
+                vals
+            }
+            Err(e) => {
+                if !e.is_gateway_timeout() {
+                    err_count -= 1;
+                    if err_count == 0 {
+                        return Err(e)
+                    }
+                }
+
+                warn!(target: "cex-exchanges::listings", "error getting {exchange} listings, {err_count} retries remaining - {:?}", e);
+                tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+                continue;
+            }
+        };
+
+        page.into_iter()
+            .filter(|coin| symbols.contains(coin.symbol()))
+            .for_each(|coin| match coins.get(coin.symbol()) {
+                Some(curr) if curr.rank() <= coin.rank() => (),
+                _ => {
+                    coins.insert(coin.symbol().to_string(), coin);
+                }
+            });
+
+        query_start += LISTINGS_PAGE_SIZE;
+    }
+
+    Ok(coins.into_values().collect())
+}
+
+async fn listings_page<C: ListedCoin>(
+    web_client: &reqwest::Client,
+    exchange: CexExchange,
+    web_url: &str,
+    query_start: u64
+) -> Result<Vec<C>, RestApiError> {
+    rate_limiter(exchange, RateLimitKind::Rest).acquire().await;
+    let url = format!("{web_url}/bapi/composite/v1/public/promo/cmc/cryptocurrency/listings/latest?limit={LISTINGS_PAGE_SIZE}&start={query_start}");
+    let page: ListingsPage<C> = web_client
+        .get(&url)
+        .header("Content-Type", "application/json")
+        .header(header::CONTENT_ENCODING, "gzip, deflate, br")
+        .send()
+        .await?
+        .json()
+        .await?;
+
+    Ok(page.data.body.data)
+}
+
+/// `{ "data": { "body": { "data": [..] } } }`
+#[derive(Deserialize)]
+#[serde(bound = "C: ListedCoin")]
+struct ListingsPage<C> {
+    data: ListingsBody<C>
+}
+
+#[derive(Deserialize)]
+#[serde(bound = "C: ListedCoin")]
+struct ListingsBody<C> {
+    body: ListingsData<C>
+}
+
+#[derive(Deserialize)]
+#[serde(bound = "C: ListedCoin")]
+struct ListingsData<C> {
+    data: Vec<C>
+}
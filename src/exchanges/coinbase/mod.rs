@@ -6,8 +6,12 @@ pub use pairs::*;
 pub mod rest_api;
 pub mod ws;
 
+use chrono::{DateTime, Duration, SecondsFormat, Utc};
 use futures::SinkExt;
-use rest_api::{CoinbaseAllCurrencies, CoinbaseAllProducts};
+use rest_api::{
+    CoinbaseAllCandles, CoinbaseAllCurrencies, CoinbaseAllProducts, CoinbaseAllTrades, CoinbaseCandle, CoinbaseOrder, CoinbaseOrderRequest,
+    CoinbaseTrade
+};
 use serde::Deserialize;
 use tokio::net::TcpStream;
 use tokio_tungstenite::{tungstenite::Message, MaybeTlsStream, WebSocketStream};
@@ -15,43 +19,201 @@ use tracing::{error, info};
 
 use self::{
     rest_api::CoinbaseRestApiResponse,
-    ws::{CoinbaseSubscription, CoinbaseWsMessage}
+    ws::{channels::CoinbaseWsChannel, CoinbaseSubscription, CoinbaseWsMessage}
 };
 use crate::{
-    clients::{rest_api::RestApiError, ws::WsError},
-    normalized::{rest_api::NormalizedRestApiRequest, types::NormalizedTradingPair},
-    CexExchange, Exchange
+    clients::{
+        rate_limiter, require_credentials,
+        rest_api::{signed_response, RestApiError},
+        ws::{WsError, WsSubscriptionUpdate},
+        EndpointKind, ExchangeCredentials, ExchangeEndpoints, RateLimitKind, TokenBucket
+    },
+    normalized::{
+        rest_api::NormalizedRestApiRequest,
+        types::{
+            NormalizedCandleInterval, NormalizedOrderAck, NormalizedOrderRequest, NormalizedOrderStatus, NormalizedOrderUpdate,
+            NormalizedTimeInForce, NormalizedTradingPair
+        }
+    },
+    CexExchange, Exchange, ExchangeTrading
 };
 
 const WSS_URL: &str = "wss://ws-feed.exchange.coinbase.com";
 const BASE_REST_API_URL: &str = "https://api.exchange.coinbase.com";
+const MAX_CANDLES_PER_REQUEST: i64 = 300;
+const MAX_TRADES_PER_REQUEST: usize = 1000;
 
 #[derive(Debug, Default, Clone)]
 pub struct Coinbase {
-    subscription: CoinbaseSubscription
+    subscription: CoinbaseSubscription,
+    credentials:  Option<ExchangeCredentials>,
+    endpoints:    ExchangeEndpoints
 }
 
 impl Coinbase {
+    fn rest_url(&self) -> String {
+        self.endpoints.url(EndpointKind::Rest, BASE_REST_API_URL)
+    }
+
+    fn ws_url(&self) -> String {
+        self.endpoints.url(EndpointKind::Ws, WSS_URL)
+    }
+
     pub fn new_ws_subscription(subscription: CoinbaseSubscription) -> Self {
-        Self { subscription }
+        Self { subscription, credentials: None, endpoints: ExchangeEndpoints::default() }
     }
 
-    pub async fn get_all_currencies(web_client: &reqwest::Client) -> Result<CoinbaseAllCurrencies, RestApiError> {
-        let currencies: CoinbaseAllCurrencies = Self::simple_rest_api_request(web_client, format!("{BASE_REST_API_URL}/currencies")).await?;
+    /// sets the credentials used to sign the user channel subscription
+    pub fn with_credentials(mut self, credentials: ExchangeCredentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    pub async fn get_all_currencies(&self, web_client: &reqwest::Client) -> Result<CoinbaseAllCurrencies, RestApiError> {
+        let currencies: CoinbaseAllCurrencies = Self::simple_rest_api_request(web_client, format!("{}/currencies", self.rest_url())).await?;
         info!(target: "cex-exchanges::coinbase", "found {} currencies", currencies.currencies.len());
         Ok(currencies)
     }
 
-    pub async fn get_all_products(web_client: &reqwest::Client) -> Result<CoinbaseAllProducts, RestApiError> {
-        let products: CoinbaseAllProducts = Self::simple_rest_api_request(web_client, format!("{BASE_REST_API_URL}/products")).await?;
+    pub async fn get_all_products(&self, web_client: &reqwest::Client) -> Result<CoinbaseAllProducts, RestApiError> {
+        let products: CoinbaseAllProducts = Self::simple_rest_api_request(web_client, format!("{}/products", self.rest_url())).await?;
         info!(target: "cex-exchanges::coinbase", "found {} products", products.products.len());
         Ok(products)
     }
 
+    /// pages through `/products/<id>/candles` in windows of 300 candles for
+    /// all candles starting in `start..end`
+    pub async fn get_candles(
+        &self,
+        web_client: &reqwest::Client,
+        product_id: CoinbaseTradingPair,
+        interval: NormalizedCandleInterval,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>
+    ) -> Result<CoinbaseAllCandles, RestApiError> {
+        let granularity = CoinbaseAllCandles::granularity(interval)
+            .ok_or(RestApiError::UnsupportedRequest(format!("coinbase does not support {interval} candles")))?;
+        let window = Duration::seconds(granularity as i64 * MAX_CANDLES_PER_REQUEST);
+
+        let mut candles: Vec<CoinbaseCandle> = Vec::new();
+        let mut window_start = start;
+        while window_start < end {
+            // both `start` & `end` are inclusive
+            let window_end = (window_start + window).min(end) - Duration::seconds(1);
+            let url = format!(
+                "{}/products/{}/candles?granularity={granularity}&start={}&end={}",
+                self.rest_url(),
+                product_id.0,
+                window_start.to_rfc3339_opts(SecondsFormat::Secs, true),
+                window_end.to_rfc3339_opts(SecondsFormat::Secs, true)
+            );
+            let page: Vec<CoinbaseCandle> = Self::simple_rest_api_request(web_client, url).await?;
+            candles.extend(page);
+
+            window_start += window;
+        }
+
+        candles.retain(|c| (start.timestamp()..end.timestamp()).contains(&(c.start_time as i64)));
+        candles.sort_by_key(|c| c.start_time);
+        candles.dedup_by_key(|c| c.start_time);
+
+        Ok(CoinbaseAllCandles { product_id, interval, candles })
+    }
+
+    /// the latest `limit` trades (max 1000)
+    pub async fn get_recent_trades(
+        &self,
+        web_client: &reqwest::Client,
+        product_id: CoinbaseTradingPair,
+        limit: usize
+    ) -> Result<CoinbaseAllTrades, RestApiError> {
+        let url = format!("{}/products/{}/trades?limit={}", self.rest_url(), product_id.0, limit.min(MAX_TRADES_PER_REQUEST));
+        let mut trades: Vec<CoinbaseTrade> = Self::simple_rest_api_request(web_client, url).await?;
+        trades.reverse();
+
+        Ok(CoinbaseAllTrades { product_id, trades })
+    }
+
+    /// pages (backwards from the latest trade) through
+    /// `/products/<id>/trades` for all trades in `from..to`
+    pub async fn get_historical_trades(
+        &self,
+        web_client: &reqwest::Client,
+        product_id: CoinbaseTradingPair,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>
+    ) -> Result<CoinbaseAllTrades, RestApiError> {
+        let mut trades: Vec<CoinbaseTrade> = Vec::new();
+        let mut after: Option<u64> = None;
+        loop {
+            let mut url = format!("{}/products/{}/trades?limit={MAX_TRADES_PER_REQUEST}", self.rest_url(), product_id.0);
+            if let Some(id) = after {
+                url.push_str(&format!("&after={id}"));
+            }
+            let page: Vec<CoinbaseTrade> = Self::simple_rest_api_request(web_client, url).await?;
+
+            let Some(oldest) = page.last() else { break };
+            after = Some(oldest.trade_id);
+
+            let is_last_page = page.len() < MAX_TRADES_PER_REQUEST || oldest.time < from;
+            trades.extend(page.into_iter().filter(|t| t.time >= from && t.time < to));
+            if is_last_page {
+                break
+            }
+        }
+
+        trades.sort_by_key(|t| t.trade_id);
+        trades.dedup_by_key(|t| t.trade_id);
+
+        Ok(CoinbaseAllTrades { product_id, trades })
+    }
+
+    /// sends a request signed with the `HMAC-SHA256` of
+    /// `timestamp + method + path + body`, keyed with the decoded secret
+    pub async fn signed_rest_api_request<T>(
+        &self,
+        web_client: &reqwest::Client,
+        credentials: &ExchangeCredentials,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<String>
+    ) -> Result<T, RestApiError>
+    where
+        T: for<'de> Deserialize<'de>
+    {
+        rate_limiter(CexExchange::Coinbase, RateLimitKind::Rest)
+            .acquire()
+            .await;
+        let timestamp = Utc::now().timestamp().to_string();
+        let body = body.unwrap_or_default();
+        let signed = || -> eyre::Result<(String, &str)> {
+            Ok((credentials.sign_base64_decoded_secret(&format!("{timestamp}{method}{path}{body}"))?, credentials.passphrase()?))
+        };
+        let (signature, passphrase) = signed().map_err(|e| RestApiError::AuthenticationError(e.to_string()))?;
+
+        let response = web_client
+            .request(method.clone(), format!("{}{path}", self.rest_url()))
+            .header("Content-Type", "application/json")
+            .header("Accept", "application/json")
+            .header("User-Agent", "rust")
+            .header("CB-ACCESS-KEY", &credentials.api_key)
+            .header("CB-ACCESS-SIGN", signature)
+            .header("CB-ACCESS-TIMESTAMP", &timestamp)
+            .header("CB-ACCESS-PASSPHRASE", passphrase)
+            .body(body)
+            .send()
+            .await?;
+
+        signed_response(response).await
+    }
+
     pub async fn simple_rest_api_request<T>(web_client: &reqwest::Client, url: String) -> Result<T, RestApiError>
     where
         T: for<'de> Deserialize<'de> + Debug
     {
+        rate_limiter(CexExchange::Coinbase, RateLimitKind::Rest)
+            .acquire()
+            .await;
         let data = web_client
             .get(&url)
             .header("Content-Type", "application/json")
@@ -73,15 +235,40 @@ impl Exchange for Coinbase {
 
     const EXCHANGE: CexExchange = CexExchange::Coinbase;
 
+    fn with_endpoints(mut self, endpoints: ExchangeEndpoints) -> Self {
+        self.endpoints = endpoints;
+        self
+    }
+
     fn remove_bad_pair(&mut self, bad_pair: NormalizedTradingPair) -> bool {
         let pair: CoinbaseTradingPair = bad_pair.try_into().unwrap();
         self.subscription.remove_pair(&pair)
     }
 
-    async fn make_ws_connection(&self) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, WsError> {
-        let (mut ws, _) = tokio_tungstenite::connect_async(WSS_URL).await?;
+    async fn make_ws_connection(&self, subscribe_limiter: &TokenBucket) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, WsError> {
+        let subscription = if self.subscription.has_private_channels() {
+            // matches of the user's orders are only told apart from public
+            // matches when they are on their own connection
+            if self.subscription.has_public_channels() {
+                return Err(WsError::AuthenticationError("the user channel can't share a connection with public channels".to_string()))
+            }
+
+            let credentials = self
+                .credentials
+                .as_ref()
+                .ok_or(WsError::AuthenticationError("credentials are required for the user channel".to_string()))?;
+
+            self.subscription
+                .signed(credentials, Utc::now().timestamp())
+                .map_err(|e| WsError::AuthenticationError(e.to_string()))?
+        } else {
+            self.subscription.clone()
+        };
+
+        let (mut ws, _) = tokio_tungstenite::connect_async(self.ws_url()).await?;
 
-        let sub_message = serde_json::to_string(&self.subscription)?;
+        let sub_message = serde_json::to_string(&subscription)?;
+        subscribe_limiter.acquire().await;
         ws.send(Message::Text(sub_message)).await?;
 
         Ok(ws)
@@ -92,13 +279,39 @@ impl Exchange for Coinbase {
         web_client: &reqwest::Client,
         api_channel: NormalizedRestApiRequest
     ) -> Result<CoinbaseRestApiResponse, RestApiError> {
-        let api_response = match api_channel {
-            NormalizedRestApiRequest::AllCurrencies => Self::get_all_currencies(web_client)
+        let api_response = match api_channel.clone() {
+            NormalizedRestApiRequest::AllCurrencies => self
+                .get_all_currencies(web_client)
                 .await
-                .map(|v| CoinbaseRestApiResponse::Currencies(v)),
-            NormalizedRestApiRequest::AllInstruments => Self::get_all_products(web_client)
+                .map(CoinbaseRestApiResponse::Currencies),
+            NormalizedRestApiRequest::AllInstruments => self
+                .get_all_products(web_client)
                 .await
-                .map(|v| CoinbaseRestApiResponse::Products(v))
+                .map(CoinbaseRestApiResponse::Products),
+            NormalizedRestApiRequest::Candles { pair, interval, start, end } => {
+                let product_id: CoinbaseTradingPair = pair
+                    .try_into()
+                    .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+                self.get_candles(web_client, product_id, interval, start, end)
+                    .await
+                    .map(CoinbaseRestApiResponse::Candles)
+            }
+            NormalizedRestApiRequest::RecentTrades { pair, limit } => {
+                let product_id: CoinbaseTradingPair = pair
+                    .try_into()
+                    .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+                self.get_recent_trades(web_client, product_id, limit)
+                    .await
+                    .map(CoinbaseRestApiResponse::Trades)
+            }
+            NormalizedRestApiRequest::HistoricalTrades { pair, from, to } => {
+                let product_id: CoinbaseTradingPair = pair
+                    .try_into()
+                    .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+                self.get_historical_trades(web_client, product_id, from, to)
+                    .await
+                    .map(CoinbaseRestApiResponse::Trades)
+            }
         };
 
         if let Err(e) = api_response.as_ref() {
@@ -107,4 +320,133 @@ impl Exchange for Coinbase {
 
         api_response
     }
+
+    fn update_subscription(&mut self, update: WsSubscriptionUpdate) -> Result<Vec<Message>, WsError> {
+        if self.subscription.has_private_channels() {
+            return Err(WsError::InvalidSubscriptionUpdate("the public channels can't share a connection with the user channel".to_string()))
+        }
+
+        let channel: CoinbaseWsChannel = update.exchange_channel()?;
+        let requests = if update.is_subscribe() { self.subscription.subscribe(channel) } else { self.subscription.unsubscribe(channel) };
+
+        let messages = requests
+            .into_iter()
+            .map(|request| serde_json::to_string(&request).map(Message::Text))
+            .collect::<Result<_, _>>()?;
+
+        Ok(messages)
+    }
+}
+
+impl ExchangeTrading for Coinbase {
+    async fn place_order(&self, web_client: &reqwest::Client, order: NormalizedOrderRequest) -> Result<NormalizedOrderAck, RestApiError> {
+        let credentials = require_credentials(&self.credentials)?;
+        let product_id: CoinbaseTradingPair = order
+            .pair
+            .clone()
+            .try_into()
+            .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+        let price = order
+            .limit_price()
+            .map_err(|e| RestApiError::UnsupportedRequest(e.to_string()))?;
+
+        let (time_in_force, post_only) = match order.limit_time_in_force() {
+            Some(NormalizedTimeInForce::GoodTilCanceled) => (Some("GTC"), None),
+            Some(NormalizedTimeInForce::ImmediateOrCancel) => (Some("IOC"), None),
+            Some(NormalizedTimeInForce::FillOrKill) => (Some("FOK"), None),
+            Some(NormalizedTimeInForce::PostOnly) => (Some("GTC"), Some(true)),
+            None => (None, None)
+        };
+
+        let request = CoinbaseOrderRequest {
+            product_id,
+            side: order.side.to_string(),
+            order_type: order.order_type.to_string(),
+            price: price.map(|p| p.to_string()),
+            size: order.amount.to_string(),
+            time_in_force: time_in_force.map(ToString::to_string),
+            post_only,
+            client_oid: order.client_order_id
+        };
+
+        let response: CoinbaseOrder = self
+            .signed_rest_api_request(web_client, credentials, reqwest::Method::POST, "/orders", Some(serde_json::to_string(&request)?))
+            .await?;
+
+        Ok(response.normalize_ack())
+    }
+
+    async fn cancel_order(
+        &self,
+        web_client: &reqwest::Client,
+        pair: NormalizedTradingPair,
+        order_id: String
+    ) -> Result<NormalizedOrderAck, RestApiError> {
+        let credentials = require_credentials(&self.credentials)?;
+        let product_id: CoinbaseTradingPair = pair
+            .try_into()
+            .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+
+        let path = format!("/orders/{order_id}?product_id={}", product_id.0);
+        let canceled_id: String = self
+            .signed_rest_api_request(web_client, credentials, reqwest::Method::DELETE, &path, None)
+            .await?;
+
+        Ok(NormalizedOrderAck {
+            exchange:        CexExchange::Coinbase,
+            pair:            Some(product_id.normalize()),
+            order_id:        canceled_id,
+            client_order_id: None,
+            status:          Some(NormalizedOrderStatus::Canceled)
+        })
+    }
+
+    async fn cancel_all(&self, web_client: &reqwest::Client, pair: Option<NormalizedTradingPair>) -> Result<Vec<NormalizedOrderAck>, RestApiError> {
+        let credentials = require_credentials(&self.credentials)?;
+        let product_id: Option<CoinbaseTradingPair> = pair
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+
+        let path = match &product_id {
+            Some(p) => format!("/orders?product_id={}", p.0),
+            None => "/orders".to_string()
+        };
+        let canceled_ids: Vec<String> = self
+            .signed_rest_api_request(web_client, credentials, reqwest::Method::DELETE, &path, None)
+            .await?;
+
+        Ok(canceled_ids
+            .into_iter()
+            .map(|order_id| NormalizedOrderAck {
+                exchange: CexExchange::Coinbase,
+                pair: product_id.as_ref().map(|p| p.normalize()),
+                order_id,
+                client_order_id: None,
+                status: Some(NormalizedOrderStatus::Canceled)
+            })
+            .collect())
+    }
+
+    async fn get_open_orders(
+        &self,
+        web_client: &reqwest::Client,
+        pair: Option<NormalizedTradingPair>
+    ) -> Result<Vec<NormalizedOrderUpdate>, RestApiError> {
+        let credentials = require_credentials(&self.credentials)?;
+
+        let mut path = "/orders?limit=1000&status=open&status=pending&status=active".to_string();
+        if let Some(pair) = pair {
+            let product_id: CoinbaseTradingPair = pair
+                .try_into()
+                .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+            path.push_str(&format!("&product_id={}", product_id.0));
+        }
+
+        let orders: Vec<CoinbaseOrder> = self
+            .signed_rest_api_request(web_client, credentials, reqwest::Method::GET, &path, None)
+            .await?;
+
+        Ok(orders.into_iter().map(|o| o.normalize()).collect())
+    }
 }
@@ -107,7 +107,7 @@ mod tests {
     fn test_parse_for_bad_pair() {
         let test_str = r#"failed to deserialize the message: missing field `error` - {"type":"error","message":"Failed to subscribe","reason":"LOOM-USDC is delisted"}"#;
 
-        let calculated = CoinbaseTradingPair::parse_for_bad_pair(&test_str);
+        let calculated = CoinbaseTradingPair::parse_for_bad_pair(test_str);
 
         assert_eq!(calculated, Some(CoinbaseTradingPair("LOOM-USDC".to_string())))
     }
@@ -0,0 +1,84 @@
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    coinbase::CoinbaseTradingPair,
+    normalized::{
+        rest_api::NormalizedRestApiDataTypes,
+        types::{NormalizedCandle, NormalizedCandleInterval, NormalizedNumber}
+    },
+    CexExchange
+};
+
+/// candles of a single product & granularity from `/products/<id>/candles`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct CoinbaseAllCandles {
+    pub product_id: CoinbaseTradingPair,
+    pub interval:   NormalizedCandleInterval,
+    pub candles:    Vec<CoinbaseCandle>
+}
+
+impl CoinbaseAllCandles {
+    pub fn normalize(self) -> Vec<NormalizedCandle> {
+        self.candles
+            .into_iter()
+            .map(|c| c.normalize(&self.product_id, self.interval))
+            .collect()
+    }
+
+    /// the granularity (in seconds) of the interval, coinbase only supports
+    /// 1m, 5m, 15m, 1h, 6h & 1d candles
+    pub fn granularity(interval: NormalizedCandleInterval) -> Option<u64> {
+        match interval {
+            NormalizedCandleInterval::OneMinute
+            | NormalizedCandleInterval::FiveMinutes
+            | NormalizedCandleInterval::FifteenMinutes
+            | NormalizedCandleInterval::OneHour
+            | NormalizedCandleInterval::SixHours
+            | NormalizedCandleInterval::OneDay => Some(interval.as_secs()),
+            _ => None
+        }
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for CoinbaseAllCandles {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match other {
+            NormalizedRestApiDataTypes::Candles(other_candles) => self.clone().normalize() == *other_candles,
+            _ => false
+        }
+    }
+}
+
+/// a single candle, sent as an array by the api
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct CoinbaseCandle {
+    /// seconds
+    pub start_time: u64,
+    pub low:        NormalizedNumber,
+    pub high:       NormalizedNumber,
+    pub open:       NormalizedNumber,
+    pub close:      NormalizedNumber,
+    pub volume:     NormalizedNumber
+}
+
+impl CoinbaseCandle {
+    pub fn normalize(self, product_id: &CoinbaseTradingPair, interval: NormalizedCandleInterval) -> NormalizedCandle {
+        let start_time = DateTime::from_timestamp(self.start_time as i64, 0).unwrap();
+
+        NormalizedCandle {
+            exchange: CexExchange::Coinbase,
+            pair: product_id.normalize(),
+            interval,
+            start_time,
+            end_time: interval.end_time(start_time),
+            open: self.open,
+            high: self.high,
+            low: self.low,
+            close: self.close,
+            volume: self.volume,
+            trade_count: None,
+            closed: None
+        }
+    }
+}
@@ -3,3 +3,12 @@ pub use currencies::*;
 
 mod products;
 pub use products::*;
+
+mod candles;
+pub use candles::*;
+
+mod trades;
+pub use trades::*;
+
+mod orders;
+pub use orders::*;
@@ -0,0 +1,98 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+use crate::{
+    coinbase::CoinbaseTradingPair,
+    normalized::types::{NormalizedNumber, NormalizedOrderAck, NormalizedOrderSide, NormalizedOrderStatus, NormalizedOrderUpdate},
+    CexExchange
+};
+
+/// body of `POST /orders`
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct CoinbaseOrderRequest {
+    pub product_id:    CoinbaseTradingPair,
+    pub side:          String,
+    #[serde(rename = "type")]
+    pub order_type:    String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price:         Option<String>,
+    pub size:          String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub post_only:     Option<bool>,
+    /// must be a UUID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_oid:    Option<String>
+}
+
+/// response of `POST /orders` & order of `GET /orders`
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CoinbaseOrder {
+    pub id:          String,
+    pub product_id:  CoinbaseTradingPair,
+    #[serde_as(as = "DisplayFromStr")]
+    pub side:        NormalizedOrderSide,
+    #[serde(rename = "type")]
+    pub order_type:  String,
+    /// not set for market orders
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub price:       Option<NormalizedNumber>,
+    /// not set for market orders placed with `funds`
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub size:        Option<NormalizedNumber>,
+    #[serde_as(as = "DisplayFromStr")]
+    pub filled_size: NormalizedNumber,
+    /// pending, open, active, done or rejected
+    pub status:      String,
+    #[serde(default)]
+    pub done_reason: Option<String>,
+    #[serde(default)]
+    pub client_oid:  Option<String>,
+    pub created_at:  DateTime<Utc>,
+    #[serde(default)]
+    pub done_at:     Option<DateTime<Utc>>
+}
+
+impl CoinbaseOrder {
+    pub fn normalize(self) -> NormalizedOrderUpdate {
+        NormalizedOrderUpdate {
+            exchange:        CexExchange::Coinbase,
+            pair:            self.product_id.normalize(),
+            order_id:        self.id.clone(),
+            client_order_id: self.client_oid.clone(),
+            side:            self.side,
+            order_type:      self.order_type.clone(),
+            status:          self.status(),
+            price:           self.price,
+            amount:          self.size,
+            filled_amount:   Some(self.filled_size),
+            time:            self.done_at.unwrap_or(self.created_at),
+            last_fill:       None
+        }
+    }
+
+    pub fn normalize_ack(self) -> NormalizedOrderAck {
+        NormalizedOrderAck {
+            exchange:        CexExchange::Coinbase,
+            pair:            Some(self.product_id.normalize()),
+            order_id:        self.id.clone(),
+            client_order_id: self.client_oid.clone(),
+            status:          Some(self.status())
+        }
+    }
+
+    fn status(&self) -> NormalizedOrderStatus {
+        match (self.status.as_str(), self.done_reason.as_deref()) {
+            ("rejected", _) => NormalizedOrderStatus::Rejected,
+            ("done", Some("filled")) => NormalizedOrderStatus::Filled,
+            ("done", _) => NormalizedOrderStatus::Canceled,
+            _ if self.filled_size > NormalizedNumber::default() => NormalizedOrderStatus::PartiallyFilled,
+            _ => NormalizedOrderStatus::New
+        }
+    }
+}
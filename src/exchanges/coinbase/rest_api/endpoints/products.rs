@@ -99,7 +99,9 @@ impl CoinbaseProduct {
             base_asset_symbol:  self.base_currency.clone(),
             quote_asset_symbol: self.quote_currency.clone(),
             active:             !self.trading_disabled,
-            futures_expiry:     None
+            futures_expiry:     None,
+            option_strike:      None,
+            option_type:        None
         }];
 
         if self.margin_enabled {
@@ -110,7 +112,9 @@ impl CoinbaseProduct {
                 base_asset_symbol:  self.base_currency,
                 quote_asset_symbol: self.quote_currency,
                 active:             !self.trading_disabled,
-                futures_expiry:     None
+                futures_expiry:     None,
+                option_strike:      None,
+                option_type:        None
             });
         }
 
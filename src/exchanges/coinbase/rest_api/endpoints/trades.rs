@@ -0,0 +1,68 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+use crate::{
+    clients::ws::received_at,
+    coinbase::CoinbaseTradingPair,
+    normalized::{
+        rest_api::NormalizedRestApiDataTypes,
+        types::{NormalizedNumber, NormalizedTrade, TradeSide}
+    },
+    CexExchange
+};
+
+/// trades of a single product from `/products/<id>/trades`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct CoinbaseAllTrades {
+    pub product_id: CoinbaseTradingPair,
+    pub trades:     Vec<CoinbaseTrade>
+}
+
+impl CoinbaseAllTrades {
+    pub fn normalize(self) -> Vec<NormalizedTrade> {
+        self.trades
+            .into_iter()
+            .map(|t| t.normalize(&self.product_id))
+            .collect()
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for CoinbaseAllTrades {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match other {
+            NormalizedRestApiDataTypes::Trades(other_trades) => self.clone().normalize() == *other_trades,
+            _ => false
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct CoinbaseTrade {
+    pub trade_id:    u64,
+    pub time:        DateTime<Utc>,
+    #[serde_as(as = "DisplayFromStr")]
+    pub price:       NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub size:        NormalizedNumber,
+    /// side of the maker order
+    pub side:        String,
+    #[serde(default = "received_at")]
+    pub received_at: DateTime<Utc>
+}
+
+impl CoinbaseTrade {
+    pub fn normalize(self, product_id: &CoinbaseTradingPair) -> NormalizedTrade {
+        NormalizedTrade {
+            exchange:      CexExchange::Coinbase,
+            pair:          product_id.normalize(),
+            exchange_time: Some(self.time),
+            received_at:   self.received_at,
+            side:          TradeSide::from_maker_side(&self.side),
+            price:         self.price,
+            amount:        self.size,
+            trade_id:      Some(self.trade_id.to_string())
+        }
+    }
+}
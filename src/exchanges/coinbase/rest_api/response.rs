@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use super::{CoinbaseAllCurrencies, CoinbaseAllProducts, CoinbaseCurrency, CoinbaseProduct};
+use super::{CoinbaseAllCandles, CoinbaseAllCurrencies, CoinbaseAllProducts, CoinbaseAllTrades, CoinbaseCurrency, CoinbaseProduct};
 use crate::normalized::rest_api::NormalizedRestApiDataTypes;
 
 #[serde_with::serde_as]
@@ -8,14 +8,18 @@ use crate::normalized::rest_api::NormalizedRestApiDataTypes;
 #[serde(rename_all = "snake_case", tag = "type")]
 pub enum CoinbaseRestApiResponse {
     Currencies(CoinbaseAllCurrencies),
-    Products(CoinbaseAllProducts)
+    Products(CoinbaseAllProducts),
+    Candles(CoinbaseAllCandles),
+    Trades(CoinbaseAllTrades)
 }
 
 impl CoinbaseRestApiResponse {
     pub fn normalize(self) -> NormalizedRestApiDataTypes {
         match self {
             CoinbaseRestApiResponse::Currencies(v) => NormalizedRestApiDataTypes::AllCurrencies(v.normalize()),
-            CoinbaseRestApiResponse::Products(v) => NormalizedRestApiDataTypes::AllInstruments(v.normalize())
+            CoinbaseRestApiResponse::Products(v) => NormalizedRestApiDataTypes::AllInstruments(v.normalize()),
+            CoinbaseRestApiResponse::Candles(v) => NormalizedRestApiDataTypes::Candles(v.normalize()),
+            CoinbaseRestApiResponse::Trades(v) => NormalizedRestApiDataTypes::Trades(v.normalize())
         }
     }
 
@@ -49,7 +53,9 @@ impl PartialEq<NormalizedRestApiDataTypes> for CoinbaseRestApiResponse {
     fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
         match self {
             CoinbaseRestApiResponse::Currencies(vals) => vals == other,
-            CoinbaseRestApiResponse::Products(vals) => vals == other
+            CoinbaseRestApiResponse::Products(vals) => vals == other,
+            CoinbaseRestApiResponse::Candles(vals) => vals == other,
+            CoinbaseRestApiResponse::Trades(vals) => vals == other
         }
     }
 }
@@ -3,7 +3,7 @@ use super::{
     CoinbaseSubscription
 };
 use crate::{
-    clients::{rest_api::ExchangeApi, ws::MutliWsStreamBuilder},
+    clients::{rest_api::ExchangeApi, ws::MutliWsStreamBuilder, ExchangeCredentials},
     coinbase::Coinbase,
     normalized::ws::NormalizedWsChannels
 };
@@ -15,7 +15,9 @@ const MAX_COINBASE_WS_CONNS_PER_STREAM: usize = 100;
 
 #[derive(Debug, Clone, Default)]
 pub struct CoinbaseWsBuilder {
-    pub channels: Vec<CoinbaseWsChannel>
+    pub channels:    Vec<CoinbaseWsChannel>,
+    /// required for [CoinbaseWsChannel::User]
+    pub credentials: Option<ExchangeCredentials>
 }
 
 impl CoinbaseWsBuilder {
@@ -25,13 +27,51 @@ impl CoinbaseWsBuilder {
         self
     }
 
+    /// sets the credentials used to sign the user channel subscription
+    pub fn with_credentials(mut self, credentials: ExchangeCredentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    fn new_exchange(&self, subscription: CoinbaseSubscription) -> Coinbase {
+        let exchange = Coinbase::new_ws_subscription(subscription);
+        match self.credentials.clone() {
+            Some(credentials) => exchange.with_credentials(credentials),
+            None => exchange
+        }
+    }
+
+    /// the private channels are signed, so they are split out onto their own
+    /// stream
+    fn split_private(&self) -> (Vec<CoinbaseWsChannel>, Option<Coinbase>) {
+        let (private, public): (Vec<_>, Vec<_>) = self
+            .channels
+            .iter()
+            .cloned()
+            .partition(|ch| ch.is_private());
+        if private.is_empty() {
+            return (public, None)
+        }
+
+        let mut subscription = CoinbaseSubscription::new();
+        private
+            .into_iter()
+            .for_each(|ch| subscription.add_channel(ch));
+
+        (public, Some(self.new_exchange(subscription)))
+    }
+
     /// builds a single ws instance of [Coinbase], handling all channels on 1
     /// stream
+    ///
+    /// the user channel can't share the stream with public channels
     pub fn build_single(self) -> Coinbase {
         let mut sub = CoinbaseSubscription::new();
-        self.channels.into_iter().for_each(|c| sub.add_channel(c));
+        self.channels
+            .iter()
+            .for_each(|c| sub.add_channel(c.clone()));
 
-        Coinbase::new_ws_subscription(sub)
+        self.new_exchange(sub)
     }
 
     /// builds many ws instances of the [Coinbase] as the inner streams of
@@ -40,9 +80,10 @@ impl CoinbaseWsBuilder {
     ///
     /// WARNING: too many channels may break the stream
     pub fn build_many_distributed(self) -> eyre::Result<MutliWsStreamBuilder<Coinbase>> {
-        let stream_size = if self.channels.len() <= MAX_COINBASE_STREAMS { 1 } else { self.channels.len() / MAX_COINBASE_STREAMS };
+        let (channels, private_exchange) = self.split_private();
+        let stream_size = if channels.len() <= MAX_COINBASE_STREAMS { 1 } else { channels.len() / MAX_COINBASE_STREAMS };
 
-        let chunks = self.channels.chunks(stream_size).collect::<Vec<_>>();
+        let chunks = channels.chunks(stream_size).collect::<Vec<_>>();
 
         let split_exchange = chunks
             .into_iter()
@@ -51,8 +92,9 @@ impl CoinbaseWsBuilder {
                 chk.iter()
                     .for_each(|ch| subscription.add_channel(ch.clone()));
 
-                Coinbase::new_ws_subscription(subscription)
+                self.new_exchange(subscription)
             })
+            .chain(private_exchange)
             .collect();
 
         Ok(MutliWsStreamBuilder::new(split_exchange))
@@ -62,8 +104,8 @@ impl CoinbaseWsBuilder {
     /// [MutliWsStreamBuilder], splitting the channels into different streams,
     /// each of size 1024
     pub fn build_many_packed(self, connections_per_stream: Option<usize>) -> eyre::Result<MutliWsStreamBuilder<Coinbase>> {
-        let chunks = self
-            .channels
+        let (channels, private_exchange) = self.split_private();
+        let chunks = channels
             .chunks(connections_per_stream.unwrap_or(MAX_COINBASE_WS_CONNS_PER_STREAM))
             .collect::<Vec<_>>();
 
@@ -74,8 +116,9 @@ impl CoinbaseWsBuilder {
                 chk.iter()
                     .for_each(|ch| subscription.add_channel(ch.clone()));
 
-                Coinbase::new_ws_subscription(subscription)
+                self.new_exchange(subscription)
             })
+            .chain(private_exchange)
             .collect();
 
         Ok(MutliWsStreamBuilder::new(split_exchange))
@@ -90,12 +133,12 @@ impl CoinbaseWsBuilder {
 
         let all_streams = this
             .channels
-            .into_iter()
+            .iter()
             .map(|ch| {
                 let mut subscription = CoinbaseSubscription::new();
-                subscription.add_channel(ch);
+                subscription.add_channel(ch.clone());
 
-                Coinbase::new_ws_subscription(subscription)
+                this.new_exchange(subscription)
             })
             .collect::<Vec<_>>();
 
@@ -124,7 +167,9 @@ impl CoinbaseWsBuilder {
                 .map(|ch| match ch {
                     CoinbaseWsChannelKind::Matches => CoinbaseWsChannel::Matches(chk.to_vec()),
                     CoinbaseWsChannelKind::Ticker => CoinbaseWsChannel::Ticker(chk.to_vec()),
-                    CoinbaseWsChannelKind::Status => CoinbaseWsChannel::Status
+                    CoinbaseWsChannelKind::Level2 => CoinbaseWsChannel::Level2(chk.to_vec()),
+                    CoinbaseWsChannelKind::Status => CoinbaseWsChannel::Status,
+                    CoinbaseWsChannelKind::User => CoinbaseWsChannel::User(chk.to_vec())
                 })
                 .collect::<Vec<_>>();
 
@@ -135,8 +180,8 @@ impl CoinbaseWsBuilder {
     }
 
     /// makes the builder from the normalized builder's map
-    pub(crate) fn make_from_normalized_map(map: Vec<NormalizedWsChannels>) -> eyre::Result<Self> {
-        let mut this = Self { channels: Vec::new() };
+    pub(crate) fn make_from_normalized_map(map: Vec<NormalizedWsChannels>, credentials: Option<ExchangeCredentials>) -> eyre::Result<Self> {
+        let mut this = Self { channels: Vec::new(), credentials };
 
         map.into_iter().try_for_each(|channel| {
             let this_channel: CoinbaseWsChannel = channel.try_into()?;
@@ -178,11 +223,11 @@ mod tests {
             .ws_exchanges
             .get(&CexExchange::Coinbase)
             .unwrap()
-            .iter()
-            .map(|(_, vals)| vals.to_owned())
+            .values()
+            .map(|vals| vals.to_owned())
             .collect::<Vec<_>>();
 
-        let calculated_builder = CoinbaseWsBuilder::make_from_normalized_map(map).unwrap();
+        let calculated_builder = CoinbaseWsBuilder::make_from_normalized_map(map, None).unwrap();
 
         let expected_trade_channel = CoinbaseWsChannel::Matches(vec![
             CoinbaseTradingPair("WBTC-USD".to_string()),
@@ -193,7 +238,7 @@ mod tests {
         let expected_quote_channel =
             CoinbaseWsChannel::Ticker(vec![CoinbaseTradingPair("ETH-USD".to_string()), CoinbaseTradingPair("PEPE-USD".to_string())]);
 
-        let expected_builder = CoinbaseWsBuilder { channels: vec![expected_quote_channel, expected_trade_channel] };
+        let expected_builder = CoinbaseWsBuilder { channels: vec![expected_quote_channel, expected_trade_channel], credentials: None };
 
         let assert1 = calculated_builder
             .channels
@@ -0,0 +1,136 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use tracing::warn;
+
+use crate::{
+    exchanges::{
+        coinbase::pairs::CoinbaseTradingPair,
+        normalized::types::{NormalizedBookLevel, NormalizedOrderBookUpdate, NormalizedOrderBookUpdateKind}
+    },
+    normalized::types::NormalizedNumber,
+    CexExchange
+};
+
+/// initial state of the book, sent after subscribing to the `level2` channel
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CoinbaseLevel2Snapshot {
+    pub product_id: CoinbaseTradingPair,
+    #[serde_as(as = "Vec<(DisplayFromStr, DisplayFromStr)>")]
+    pub bids:       Vec<(NormalizedNumber, NormalizedNumber)>,
+    #[serde_as(as = "Vec<(DisplayFromStr, DisplayFromStr)>")]
+    pub asks:       Vec<(NormalizedNumber, NormalizedNumber)>,
+    #[serde(default = "Utc::now")]
+    pub time:       DateTime<Utc>
+}
+
+impl CoinbaseLevel2Snapshot {
+    pub fn normalize(self) -> NormalizedOrderBookUpdate {
+        NormalizedOrderBookUpdate {
+            exchange:        CexExchange::Coinbase,
+            pair:            self.product_id.normalize(),
+            time:            self.time,
+            kind:            NormalizedOrderBookUpdateKind::Snapshot,
+            bids:            self
+                .bids
+                .into_iter()
+                .map(|(p, a)| NormalizedBookLevel::new(p, a))
+                .collect(),
+            asks:            self
+                .asks
+                .into_iter()
+                .map(|(p, a)| NormalizedBookLevel::new(p, a))
+                .collect(),
+            first_update_id: None,
+            last_update_id:  None
+        }
+    }
+}
+
+impl PartialEq<NormalizedOrderBookUpdate> for CoinbaseLevel2Snapshot {
+    fn eq(&self, other: &NormalizedOrderBookUpdate) -> bool {
+        let equals = other.exchange == CexExchange::Coinbase
+            && other.pair == self.product_id.normalize()
+            && other.time == self.time
+            && other.kind == NormalizedOrderBookUpdateKind::Snapshot
+            && other
+                .bids
+                .iter()
+                .map(|l| (l.price, l.amount))
+                .eq(self.bids.iter().copied())
+            && other
+                .asks
+                .iter()
+                .map(|l| (l.price, l.amount))
+                .eq(self.asks.iter().copied());
+
+        if !equals {
+            warn!(target: "cex-exchanges::coinbase", "coinbase level2 snapshot: {:?}", self);
+            warn!(target: "cex-exchanges::coinbase", "normalized order book: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+/// changes to the book, each change is (side, price, size)
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CoinbaseLevel2Update {
+    pub product_id: CoinbaseTradingPair,
+    #[serde_as(as = "Vec<(_, DisplayFromStr, DisplayFromStr)>")]
+    pub changes:    Vec<(String, NormalizedNumber, NormalizedNumber)>,
+    pub time:       DateTime<Utc>
+}
+
+impl CoinbaseLevel2Update {
+    pub fn normalize(self) -> NormalizedOrderBookUpdate {
+        let (bids, asks) = self.split_changes();
+
+        NormalizedOrderBookUpdate {
+            exchange: CexExchange::Coinbase,
+            pair: self.product_id.normalize(),
+            time: self.time,
+            kind: NormalizedOrderBookUpdateKind::Delta,
+            bids,
+            asks,
+            first_update_id: None,
+            last_update_id: None
+        }
+    }
+
+    fn split_changes(&self) -> (Vec<NormalizedBookLevel>, Vec<NormalizedBookLevel>) {
+        let mut bids = Vec::new();
+        let mut asks = Vec::new();
+        self.changes.iter().for_each(|(side, price, amount)| {
+            if side.to_lowercase() == "buy" {
+                bids.push(NormalizedBookLevel::new(*price, *amount));
+            } else {
+                asks.push(NormalizedBookLevel::new(*price, *amount));
+            }
+        });
+
+        (bids, asks)
+    }
+}
+
+impl PartialEq<NormalizedOrderBookUpdate> for CoinbaseLevel2Update {
+    fn eq(&self, other: &NormalizedOrderBookUpdate) -> bool {
+        let (bids, asks) = self.split_changes();
+
+        let equals = other.exchange == CexExchange::Coinbase
+            && other.pair == self.product_id.normalize()
+            && other.time == self.time
+            && other.kind == NormalizedOrderBookUpdateKind::Delta
+            && other.bids == bids
+            && other.asks == asks;
+
+        if !equals {
+            warn!(target: "cex-exchanges::coinbase", "coinbase level2 update: {:?}", self);
+            warn!(target: "cex-exchanges::coinbase", "normalized order book: {:?}", other);
+        }
+
+        equals
+    }
+}
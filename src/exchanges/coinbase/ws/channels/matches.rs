@@ -4,7 +4,12 @@ use serde_with::{serde_as, DisplayFromStr};
 use tracing::warn;
 
 use crate::{
-    exchanges::{coinbase::pairs::CoinbaseTradingPair, normalized::types::NormalizedTrade},
+    clients::ws::received_at,
+    exchanges::{
+        coinbase::pairs::CoinbaseTradingPair,
+        normalized::types::{NormalizedFill, NormalizedOrderSide, NormalizedTrade, TradeSide}
+    },
+    normalized::types::NormalizedNumber,
     CexExchange
 };
 
@@ -18,32 +23,95 @@ pub struct CoinbaseMatches {
     pub time:           DateTime<Utc>,
     pub product_id:     CoinbaseTradingPair,
     #[serde_as(as = "DisplayFromStr")]
-    pub size:           f64,
+    pub size:           NormalizedNumber,
     #[serde_as(as = "DisplayFromStr")]
-    pub price:          f64,
-    pub side:           String
+    pub price:          NormalizedNumber,
+    /// side of the maker order
+    #[serde_as(as = "DisplayFromStr")]
+    pub side:           NormalizedOrderSide,
+    /// only set on matches of the authenticated user (user channel)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_id:        Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maker_user_id:  Option<String>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub maker_fee_rate: Option<NormalizedNumber>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub taker_fee_rate: Option<NormalizedNumber>,
+    #[serde(default = "received_at")]
+    pub received_at:    DateTime<Utc>
 }
 
 impl CoinbaseMatches {
     pub fn normalize(self) -> NormalizedTrade {
         NormalizedTrade {
+            exchange:      CexExchange::Coinbase,
+            pair:          self.product_id.normalize(),
+            exchange_time: Some(self.time),
+            received_at:   self.received_at,
+            side:          TradeSide::from(self.side.opposite()),
+            price:         self.price,
+            amount:        self.size,
+            trade_id:      Some(self.trade_id.to_string())
+        }
+    }
+}
+
+impl CoinbaseMatches {
+    /// if the match is a fill of the authenticated user's order
+    pub fn is_user_match(&self) -> bool {
+        self.user_id.is_some()
+    }
+
+    /// the match from the side of the authenticated user's order
+    pub fn normalize_fill(self) -> NormalizedFill {
+        let is_maker = self.maker_user_id.is_some() && self.maker_user_id == self.user_id;
+
+        let (order_id, side, fee_rate) = if is_maker {
+            (self.maker_order_id, self.side, self.maker_fee_rate)
+        } else {
+            (self.taker_order_id, self.side.opposite(), self.taker_fee_rate)
+        };
+
+        NormalizedFill {
             exchange: CexExchange::Coinbase,
-            pair:     self.product_id.normalize(),
-            time:     self.time,
-            side:     self.side.to_lowercase(),
-            price:    self.price,
-            amount:   self.size,
-            trade_id: Some(self.trade_id.to_string())
+            pair: self.product_id.normalize(),
+            order_id,
+            trade_id: self.trade_id.to_string(),
+            side,
+            price: self.price,
+            amount: self.size,
+            fee: fee_rate.map(|rate| rate * self.price * self.size),
+            // fees are paid in the quote currency
+            fee_symbol: self.product_id.0.split('-').nth(1).map(ToString::to_string),
+            is_maker: Some(is_maker),
+            time: self.time
         }
     }
 }
 
+impl PartialEq<NormalizedFill> for CoinbaseMatches {
+    fn eq(&self, other: &NormalizedFill) -> bool {
+        let equals = self.clone().normalize_fill() == *other;
+
+        if !equals {
+            warn!(target: "cex-exchanges::coinbase", "coinbase user matches: {:?}", self);
+            warn!(target: "cex-exchanges::coinbase", "normalized fill: {:?}", other);
+        }
+
+        equals
+    }
+}
+
 impl PartialEq<NormalizedTrade> for CoinbaseMatches {
     fn eq(&self, other: &NormalizedTrade) -> bool {
         let equals = other.exchange == CexExchange::Coinbase
             && other.pair == self.product_id.normalize()
-            && other.time == self.time
-            && other.side == self.side.to_lowercase()
+            && other.exchange_time == Some(self.time)
+            && other.received_at == self.received_at
+            && other.side == TradeSide::from(self.side.opposite())
             && other.price == self.price
             && other.amount == self.size
             && other.trade_id.as_ref().unwrap() == &self.trade_id.to_string();
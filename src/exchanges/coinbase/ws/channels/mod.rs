@@ -9,6 +9,12 @@ pub use ticker::*;
 mod status;
 pub use status::*;
 
+mod level2;
+pub use level2::*;
+
+mod user;
+pub use user::*;
+
 use crate::{
     exchanges::{
         coinbase::pairs::CoinbaseTradingPair,
@@ -24,7 +30,10 @@ use crate::{
 pub enum CoinbaseWsChannel {
     Status,
     Matches(Vec<CoinbaseTradingPair>),
-    Ticker(Vec<CoinbaseTradingPair>)
+    Ticker(Vec<CoinbaseTradingPair>),
+    Level2(Vec<CoinbaseTradingPair>),
+    /// private orders & fills of the given pairs, requires credentials
+    User(Vec<CoinbaseTradingPair>)
 }
 
 impl CoinbaseWsChannel {
@@ -50,6 +59,33 @@ impl CoinbaseWsChannel {
         Self::new_from_kind(normalized, CoinbaseWsChannel::Ticker(Vec::new()))
     }
 
+    /// builds level2 channel from a vec of raw trading pairs
+    /// return an error if the symbol is incorrectly formatted
+    pub fn new_level2(pairs: Vec<RawTradingPair>) -> eyre::Result<Self> {
+        let normalized = pairs
+            .into_iter()
+            .map(|pair| pair.get_normalized_pair(CexExchange::Coinbase))
+            .collect();
+
+        Self::new_from_kind(normalized, CoinbaseWsChannel::Level2(Vec::new()))
+    }
+
+    /// builds the private user channel from a vec of raw trading pairs
+    /// return an error if the symbol is incorrectly formatted
+    pub fn new_user(pairs: Vec<RawTradingPair>) -> eyre::Result<Self> {
+        let normalized = pairs
+            .into_iter()
+            .map(|pair| pair.get_normalized_pair(CexExchange::Coinbase))
+            .collect();
+
+        Self::new_from_kind(normalized, CoinbaseWsChannel::User(Vec::new()))
+    }
+
+    /// if the channel requires an authenticated subscription
+    pub fn is_private(&self) -> bool {
+        matches!(self, CoinbaseWsChannel::User(_))
+    }
+
     fn new_from_kind(pairs: Vec<NormalizedTradingPair>, kind: CoinbaseWsChannel) -> eyre::Result<Self> {
         match kind {
             CoinbaseWsChannel::Matches(_) => Ok(CoinbaseWsChannel::Matches(
@@ -64,6 +100,18 @@ impl CoinbaseWsChannel {
                     .map(TryInto::try_into)
                     .collect::<Result<_, _>>()?
             )),
+            CoinbaseWsChannel::Level2(_) => Ok(CoinbaseWsChannel::Level2(
+                pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<_, _>>()?
+            )),
+            CoinbaseWsChannel::User(_) => Ok(CoinbaseWsChannel::User(
+                pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<_, _>>()?
+            )),
             CoinbaseWsChannel::Status => Ok(CoinbaseWsChannel::Status)
         }
     }
@@ -74,7 +122,9 @@ impl Display for CoinbaseWsChannel {
         match self {
             CoinbaseWsChannel::Status => write!(f, "status"),
             CoinbaseWsChannel::Matches(_) => write!(f, "matches"),
-            CoinbaseWsChannel::Ticker(_) => write!(f, "ticker")
+            CoinbaseWsChannel::Ticker(_) => write!(f, "ticker"),
+            CoinbaseWsChannel::Level2(_) => write!(f, "level2"),
+            CoinbaseWsChannel::User(_) => write!(f, "user")
         }
     }
 }
@@ -87,6 +137,8 @@ impl TryFrom<String> for CoinbaseWsChannel {
             "status" => Ok(Self::Status),
             "matches" => Ok(Self::Matches(Vec::new())),
             "ticker" => Ok(Self::Ticker(Vec::new())),
+            "level2" => Ok(Self::Level2(Vec::new())),
+            "user" => Ok(Self::User(Vec::new())),
             _ => Err(eyre::ErrReport::msg(format!("channel is not valid: {value}")))
         }
     }
@@ -114,6 +166,28 @@ impl TryFrom<NormalizedWsChannels> for CoinbaseWsChannel {
 
                 Ok(CoinbaseWsChannel::Ticker(norm_pairs))
             }
+
+            NormalizedWsChannels::OrderBook { pairs, .. } => {
+                let norm_pairs = pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, Self::Error>>()?;
+
+                Ok(CoinbaseWsChannel::Level2(norm_pairs))
+            }
+
+            NormalizedWsChannels::Candles { .. } => {
+                Err(eyre::ErrReport::msg("coinbase does not have a candles ws channel, use the candles rest api request"))
+            }
+
+            NormalizedWsChannels::Account(pairs) => {
+                let norm_pairs = pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, Self::Error>>()?;
+
+                Ok(CoinbaseWsChannel::User(norm_pairs))
+            }
         }
     }
 }
@@ -122,7 +196,9 @@ impl TryFrom<NormalizedWsChannels> for CoinbaseWsChannel {
 pub enum CoinbaseWsChannelKind {
     Matches,
     Ticker,
-    Status
+    Level2,
+    Status,
+    User
 }
 
 impl Display for CoinbaseWsChannelKind {
@@ -130,7 +206,9 @@ impl Display for CoinbaseWsChannelKind {
         match self {
             CoinbaseWsChannelKind::Matches => write!(f, "matches"),
             CoinbaseWsChannelKind::Ticker => write!(f, "ticker"),
-            CoinbaseWsChannelKind::Status => write!(f, "status")
+            CoinbaseWsChannelKind::Level2 => write!(f, "level2"),
+            CoinbaseWsChannelKind::Status => write!(f, "status"),
+            CoinbaseWsChannelKind::User => write!(f, "user")
         }
     }
 }
@@ -140,7 +218,9 @@ impl From<&CoinbaseWsChannel> for CoinbaseWsChannelKind {
         match value {
             CoinbaseWsChannel::Matches(_) => CoinbaseWsChannelKind::Matches,
             CoinbaseWsChannel::Ticker(_) => CoinbaseWsChannelKind::Ticker,
-            CoinbaseWsChannel::Status => CoinbaseWsChannelKind::Status
+            CoinbaseWsChannel::Level2(_) => CoinbaseWsChannelKind::Level2,
+            CoinbaseWsChannel::Status => CoinbaseWsChannelKind::Status,
+            CoinbaseWsChannel::User(_) => CoinbaseWsChannelKind::User
         }
     }
 }
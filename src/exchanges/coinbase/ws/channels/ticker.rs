@@ -4,7 +4,9 @@ use serde_with::{serde_as, DisplayFromStr};
 use tracing::warn;
 
 use crate::{
+    clients::ws::received_at,
     exchanges::{coinbase::pairs::CoinbaseTradingPair, normalized::types::NormalizedQuote},
+    normalized::types::NormalizedNumber,
     CexExchange
 };
 
@@ -14,42 +16,45 @@ pub struct CoinbaseTicker {
     pub sequence:      Option<u64>,
     pub product_id:    CoinbaseTradingPair,
     #[serde_as(as = "DisplayFromStr")]
-    pub price:         f64,
+    pub price:         NormalizedNumber,
     #[serde_as(as = "DisplayFromStr")]
-    pub open_24h:      f64,
+    pub open_24h:      NormalizedNumber,
     #[serde_as(as = "DisplayFromStr")]
-    pub low_24h:       f64,
+    pub low_24h:       NormalizedNumber,
     #[serde_as(as = "DisplayFromStr")]
-    pub high_24h:      f64,
+    pub high_24h:      NormalizedNumber,
     #[serde_as(as = "DisplayFromStr")]
-    pub volume_30d:    f64,
+    pub volume_30d:    NormalizedNumber,
     #[serde_as(as = "DisplayFromStr")]
-    pub best_bid:      f64,
+    pub best_bid:      NormalizedNumber,
     #[serde_as(as = "DisplayFromStr")]
-    pub best_bid_size: f64,
+    pub best_bid_size: NormalizedNumber,
     #[serde_as(as = "DisplayFromStr")]
-    pub best_ask:      f64,
+    pub best_ask:      NormalizedNumber,
     #[serde_as(as = "DisplayFromStr")]
-    pub best_ask_size: f64,
+    pub best_ask_size: NormalizedNumber,
     pub side:          Option<String>,
-    #[serde(default = "Utc::now")]
-    pub time:          DateTime<Utc>,
+    #[serde(default)]
+    pub time:          Option<DateTime<Utc>>,
     pub trade_id:      Option<u64>,
     #[serde_as(as = "Option<DisplayFromStr>")]
-    pub last_size:     Option<f64>
+    pub last_size:     Option<NormalizedNumber>,
+    #[serde(default = "received_at")]
+    pub received_at:   DateTime<Utc>
 }
 
 impl CoinbaseTicker {
     pub fn normalize(self) -> NormalizedQuote {
         NormalizedQuote {
-            exchange:   CexExchange::Coinbase,
-            pair:       self.product_id.normalize(),
-            time:       self.time,
-            ask_amount: self.best_ask_size,
-            ask_price:  self.best_ask,
-            bid_amount: self.best_bid_size,
-            bid_price:  self.best_bid,
-            quote_id:   self.trade_id.map(|t| t.to_string())
+            exchange:      CexExchange::Coinbase,
+            pair:          self.product_id.normalize(),
+            exchange_time: self.time,
+            received_at:   self.received_at,
+            ask_amount:    self.best_ask_size,
+            ask_price:     self.best_ask,
+            bid_amount:    self.best_bid_size,
+            bid_price:     self.best_bid,
+            quote_id:      self.trade_id.map(|t| t.to_string())
         }
     }
 }
@@ -58,7 +63,8 @@ impl PartialEq<NormalizedQuote> for CoinbaseTicker {
     fn eq(&self, other: &NormalizedQuote) -> bool {
         let equals = other.exchange == CexExchange::Coinbase
             && other.pair == self.product_id.normalize()
-            && other.time == self.time
+            && other.exchange_time == self.time
+            && other.received_at == self.received_at
             && other.ask_amount == self.best_ask_size
             && other.ask_price == self.best_ask
             && other.bid_amount == self.best_bid_size
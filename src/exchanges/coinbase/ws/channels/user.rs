@@ -0,0 +1,85 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use tracing::warn;
+
+use crate::{
+    exchanges::{
+        coinbase::pairs::CoinbaseTradingPair,
+        normalized::types::{NormalizedOrderSide, NormalizedOrderStatus, NormalizedOrderUpdate}
+    },
+    normalized::types::NormalizedNumber,
+    CexExchange
+};
+
+/// `received`, `open` & `done` messages of the user channel, each only
+/// carries some of the order's fields
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CoinbaseUserOrder {
+    pub time:           DateTime<Utc>,
+    pub product_id:     CoinbaseTradingPair,
+    pub sequence:       u64,
+    pub order_id:       String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub side:           NormalizedOrderSide,
+    /// `received` only
+    #[serde(default)]
+    pub order_type:     Option<String>,
+    /// `received` only
+    #[serde(default)]
+    pub client_oid:     Option<String>,
+    /// not set for market orders
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub price:          Option<NormalizedNumber>,
+    /// `received` only, not set for market orders placed with `funds`
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub size:           Option<NormalizedNumber>,
+    /// `open` & `done` only
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub remaining_size: Option<NormalizedNumber>,
+    /// `done` only - filled or canceled
+    #[serde(default)]
+    pub reason:         Option<String>
+}
+
+impl CoinbaseUserOrder {
+    pub fn normalize(self) -> NormalizedOrderUpdate {
+        let status = match self.reason.as_deref() {
+            Some("filled") => NormalizedOrderStatus::Filled,
+            Some(_) => NormalizedOrderStatus::Canceled,
+            None => NormalizedOrderStatus::New
+        };
+
+        NormalizedOrderUpdate {
+            exchange: CexExchange::Coinbase,
+            pair: self.product_id.normalize(),
+            order_id: self.order_id,
+            client_order_id: self.client_oid,
+            side: self.side,
+            order_type: self.order_type.unwrap_or_default(),
+            status,
+            price: self.price,
+            amount: self.size,
+            filled_amount: None,
+            time: self.time,
+            last_fill: None
+        }
+    }
+}
+
+impl PartialEq<NormalizedOrderUpdate> for CoinbaseUserOrder {
+    fn eq(&self, other: &NormalizedOrderUpdate) -> bool {
+        let equals = self.clone().normalize() == *other;
+
+        if !equals {
+            warn!(target: "cex-exchanges::coinbase", "coinbase user order: {:?}", self);
+            warn!(target: "cex-exchanges::coinbase", "normalized order update: {:?}", other);
+        }
+
+        equals
+    }
+}
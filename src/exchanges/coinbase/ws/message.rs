@@ -1,4 +1,4 @@
-use super::channels::{CoinbaseMatches, CoinbaseStatus, CoinbaseTicker};
+use super::channels::{CoinbaseLevel2Snapshot, CoinbaseLevel2Update, CoinbaseMatches, CoinbaseStatus, CoinbaseTicker, CoinbaseUserOrder};
 use crate::{clients::ws::CriticalWsMessage, coinbase::CoinbaseTradingPair, exchanges::normalized::ws::NormalizedWsDataTypes, CexExchange};
 
 #[serde_with::serde_as]
@@ -8,7 +8,20 @@ pub enum CoinbaseWsMessage {
     #[serde(alias = "last_match", alias = "match")]
     Matches(CoinbaseMatches),
     Ticker(CoinbaseTicker),
+    Snapshot(CoinbaseLevel2Snapshot),
+    #[serde(rename = "l2update")]
+    Level2Update(CoinbaseLevel2Update),
     Status(CoinbaseStatus),
+    /// user channel
+    Received(CoinbaseUserOrder),
+    /// user channel
+    Open(CoinbaseUserOrder),
+    /// user channel
+    Done(CoinbaseUserOrder),
+    /// user channel
+    Change(serde_json::Value),
+    /// user channel
+    Activate(serde_json::Value),
     Subscriptions(serde_json::Value),
     Error(CoinbaseError)
 }
@@ -16,11 +29,23 @@ pub enum CoinbaseWsMessage {
 impl CoinbaseWsMessage {
     pub fn normalize(self) -> NormalizedWsDataTypes {
         match self {
+            CoinbaseWsMessage::Matches(v) if v.is_user_match() => NormalizedWsDataTypes::Fill(v.normalize_fill()),
             CoinbaseWsMessage::Matches(v) => NormalizedWsDataTypes::Trade(v.normalize()),
             CoinbaseWsMessage::Ticker(v) => NormalizedWsDataTypes::Quote(v.normalize()),
+            CoinbaseWsMessage::Snapshot(v) => NormalizedWsDataTypes::OrderBook(v.normalize()),
+            CoinbaseWsMessage::Level2Update(v) => NormalizedWsDataTypes::OrderBook(v.normalize()),
             CoinbaseWsMessage::Status(v) => {
                 NormalizedWsDataTypes::Other { exchange: CexExchange::Coinbase, kind: "Status".to_string(), value: format!("{:?}", v) }
             }
+            CoinbaseWsMessage::Received(v) => NormalizedWsDataTypes::OrderUpdate(v.normalize()),
+            CoinbaseWsMessage::Open(v) => NormalizedWsDataTypes::OrderUpdate(v.normalize()),
+            CoinbaseWsMessage::Done(v) => NormalizedWsDataTypes::OrderUpdate(v.normalize()),
+            CoinbaseWsMessage::Change(v) => {
+                NormalizedWsDataTypes::Other { exchange: CexExchange::Coinbase, kind: "Change".to_string(), value: format!("{:?}", v) }
+            }
+            CoinbaseWsMessage::Activate(v) => {
+                NormalizedWsDataTypes::Other { exchange: CexExchange::Coinbase, kind: "Activate".to_string(), value: format!("{:?}", v) }
+            }
             CoinbaseWsMessage::Subscriptions(v) => {
                 NormalizedWsDataTypes::Other { exchange: CexExchange::Coinbase, kind: "Subscriptions".to_string(), value: format!("{:?}", v) }
             }
@@ -35,7 +60,15 @@ impl PartialEq<NormalizedWsDataTypes> for CoinbaseWsMessage {
     fn eq(&self, other: &NormalizedWsDataTypes) -> bool {
         match (self, other) {
             (CoinbaseWsMessage::Matches(this), NormalizedWsDataTypes::Trade(that)) => this == that,
+            (CoinbaseWsMessage::Matches(this), NormalizedWsDataTypes::Fill(that)) => this == that,
+            (CoinbaseWsMessage::Received(this), NormalizedWsDataTypes::OrderUpdate(that)) => this == that,
+            (CoinbaseWsMessage::Open(this), NormalizedWsDataTypes::OrderUpdate(that)) => this == that,
+            (CoinbaseWsMessage::Done(this), NormalizedWsDataTypes::OrderUpdate(that)) => this == that,
+            (CoinbaseWsMessage::Change(_), NormalizedWsDataTypes::Other { .. }) => true,
+            (CoinbaseWsMessage::Activate(_), NormalizedWsDataTypes::Other { .. }) => true,
             (CoinbaseWsMessage::Ticker(this), NormalizedWsDataTypes::Quote(that)) => this == that,
+            (CoinbaseWsMessage::Snapshot(this), NormalizedWsDataTypes::OrderBook(that)) => this == that,
+            (CoinbaseWsMessage::Level2Update(this), NormalizedWsDataTypes::OrderBook(that)) => this == that,
             (CoinbaseWsMessage::Status(_), NormalizedWsDataTypes::Other { .. }) => true,
             (CoinbaseWsMessage::Subscriptions(_), NormalizedWsDataTypes::Other { .. }) => true,
             (CoinbaseWsMessage::Error { .. }, NormalizedWsDataTypes::Other { .. }) => true,
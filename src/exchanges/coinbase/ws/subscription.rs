@@ -3,13 +3,16 @@ use std::collections::HashSet;
 use serde::Serialize;
 
 use super::channels::CoinbaseWsChannel;
-use crate::coinbase::CoinbaseTradingPair;
+use crate::{clients::ExchangeCredentials, coinbase::CoinbaseTradingPair};
 
 #[derive(Debug, Clone, Serialize)]
 pub struct CoinbaseSubscription {
     #[serde(rename = "type")]
     sub_name: String,
-    channels: Vec<CoinbaseSubscriptionInner>
+    channels: Vec<CoinbaseSubscriptionInner>,
+    /// only set when subscribing to the user channel
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    auth:     Option<CoinbaseSubscriptionAuth>
 }
 
 impl Default for CoinbaseSubscription {
@@ -20,7 +23,7 @@ impl Default for CoinbaseSubscription {
 
 impl CoinbaseSubscription {
     pub fn new() -> Self {
-        CoinbaseSubscription { sub_name: "subscribe".to_string(), channels: Vec::new() }
+        CoinbaseSubscription { sub_name: "subscribe".to_string(), channels: Vec::new(), auth: None }
     }
 
     pub fn remove_pair(&mut self, pair: &CoinbaseTradingPair) -> bool {
@@ -31,12 +34,83 @@ impl CoinbaseSubscription {
     }
 
     pub fn new_single_channel(channel: CoinbaseWsChannel) -> Self {
-        CoinbaseSubscription { sub_name: "subscribe".to_string(), channels: vec![channel.into()] }
+        CoinbaseSubscription { sub_name: "subscribe".to_string(), channels: vec![channel.into()], auth: None }
     }
 
     pub(crate) fn add_channel(&mut self, channel: CoinbaseWsChannel) {
         self.channels.push(channel.into());
     }
+
+    /// adds the products of the channel, returning the request to subscribe to
+    /// the new ones
+    pub(crate) fn subscribe(&mut self, channel: CoinbaseWsChannel) -> Option<Self> {
+        let mut new = CoinbaseSubscriptionInner::from(channel);
+        new.product_ids.retain(|p| !self.has_product(&new.name, p));
+        if new.product_ids.is_empty() {
+            return None
+        }
+
+        self.channels.push(new.clone());
+        Some(CoinbaseSubscription { sub_name: "subscribe".to_string(), channels: vec![new], auth: None })
+    }
+
+    /// removes the products of the channel, returning the request to
+    /// unsubscribe from the ones that were subscribed to
+    pub(crate) fn unsubscribe(&mut self, channel: CoinbaseWsChannel) -> Option<Self> {
+        let mut removed = CoinbaseSubscriptionInner::from(channel);
+        removed
+            .product_ids
+            .retain(|p| self.has_product(&removed.name, p));
+        if removed.product_ids.is_empty() {
+            return None
+        }
+
+        self.channels
+            .iter_mut()
+            .filter(|ch| ch.name == removed.name)
+            .for_each(|ch| ch.product_ids.retain(|p| !removed.product_ids.contains(p)));
+        self.channels
+            .retain(|ch| ch.name != removed.name || !ch.product_ids.is_empty());
+
+        Some(CoinbaseSubscription { sub_name: "unsubscribe".to_string(), channels: vec![removed], auth: None })
+    }
+
+    fn has_product(&self, name: &str, product: &CoinbaseTradingPair) -> bool {
+        self.channels
+            .iter()
+            .any(|ch| ch.name == name && ch.product_ids.contains(product))
+    }
+
+    pub(crate) fn has_private_channels(&self) -> bool {
+        self.channels.iter().any(|ch| ch.name == "user")
+    }
+
+    pub(crate) fn has_public_channels(&self) -> bool {
+        self.channels.iter().any(|ch| ch.name != "user")
+    }
+
+    /// signs the subscription, the signature is over
+    /// `timestamp + GET + /users/self/verify`
+    pub(crate) fn signed(&self, credentials: &ExchangeCredentials, timestamp: i64) -> eyre::Result<Self> {
+        let signature = credentials.sign_base64_decoded_secret(&format!("{timestamp}GET/users/self/verify"))?;
+
+        let auth = CoinbaseSubscriptionAuth {
+            signature,
+            key: credentials.api_key.clone(),
+            passphrase: credentials.passphrase()?.to_string(),
+            timestamp: timestamp.to_string()
+        };
+
+        Ok(Self { auth: Some(auth), ..self.clone() })
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct CoinbaseSubscriptionAuth {
+    signature:  String,
+    key:        String,
+    passphrase: String,
+    timestamp:  String
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -75,6 +149,22 @@ impl From<CoinbaseWsChannel> for CoinbaseSubscriptionInner {
                     .collect::<HashSet<_>>()
                     .into_iter()
                     .collect()
+            },
+            CoinbaseWsChannel::Level2(pairs) => CoinbaseSubscriptionInner {
+                name,
+                product_ids: pairs
+                    .into_iter()
+                    .collect::<HashSet<_>>()
+                    .into_iter()
+                    .collect()
+            },
+            CoinbaseWsChannel::User(pairs) => CoinbaseSubscriptionInner {
+                name,
+                product_ids: pairs
+                    .into_iter()
+                    .collect::<HashSet<_>>()
+                    .into_iter()
+                    .collect()
             }
         }
     }
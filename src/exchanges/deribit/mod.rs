@@ -0,0 +1,173 @@
+mod pairs;
+pub use pairs::*;
+
+pub mod rest_api;
+pub mod ws;
+
+use futures::SinkExt;
+use rest_api::{DeribitAllCurrencies, DeribitAllInstruments, DeribitOrderBookSnapshot};
+use serde::Deserialize;
+use tokio::net::TcpStream;
+use tokio_tungstenite::{tungstenite::Message, MaybeTlsStream, WebSocketStream};
+use tracing::{error, info};
+
+use self::{
+    rest_api::DeribitRestApiResponse,
+    ws::{
+        channels::{DeribitBook, DeribitWsChannel, DERIBIT_BOOK_SNAPSHOT_DEPTH},
+        DeribitSubscription, DeribitWsMessage
+    }
+};
+use crate::{
+    clients::{
+        rate_limiter,
+        rest_api::RestApiError,
+        ws::{WsError, WsSubscriptionUpdate},
+        EndpointKind, ExchangeEndpoints, RateLimitKind, TokenBucket
+    },
+    normalized::{rest_api::NormalizedRestApiRequest, types::NormalizedTradingPair},
+    CexExchange, Exchange
+};
+
+const WSS_URL: &str = "wss://www.deribit.com/ws/api/v2";
+const BASE_REST_API_URL: &str = "https://www.deribit.com";
+
+/// the currencies & kinds of the instruments returned by
+/// [Deribit::get_all_instruments]
+const INSTRUMENT_CURRENCIES: [&str; 2] = ["BTC", "ETH"];
+const INSTRUMENT_KINDS: [&str; 2] = ["future", "option"];
+
+#[derive(Debug, Default, Clone)]
+pub struct Deribit {
+    subscription: DeribitSubscription,
+    endpoints:    ExchangeEndpoints
+}
+
+impl Deribit {
+    fn rest_url(&self) -> String {
+        self.endpoints.url(EndpointKind::Rest, BASE_REST_API_URL)
+    }
+
+    fn ws_url(&self) -> String {
+        self.endpoints.url(EndpointKind::Ws, WSS_URL)
+    }
+
+    pub fn new_ws_subscription(subscription: DeribitSubscription) -> Self {
+        Self { subscription, endpoints: ExchangeEndpoints::default() }
+    }
+
+    pub async fn get_all_currencies(&self, web_client: &reqwest::Client) -> Result<DeribitAllCurrencies, RestApiError> {
+        let currencies: DeribitAllCurrencies =
+            Self::simple_rest_api_request(web_client, format!("{}/api/v2/public/get_currencies", self.rest_url())).await?;
+        info!(target: "cex-exchanges::deribit", "found {} currencies", currencies.currencies.len());
+        Ok(currencies)
+    }
+
+    /// the unexpired futures (including perpetuals) & options of BTC & ETH
+    pub async fn get_all_instruments(&self, web_client: &reqwest::Client) -> Result<DeribitAllInstruments, RestApiError> {
+        let mut instruments = DeribitAllInstruments::default();
+        for currency in INSTRUMENT_CURRENCIES {
+            for kind in INSTRUMENT_KINDS {
+                let url = format!("{}/api/v2/public/get_instruments?currency={currency}&kind={kind}&expired=false", self.rest_url());
+                let page: DeribitAllInstruments = Self::simple_rest_api_request(web_client, url).await?;
+                instruments.instruments.extend(page.instruments);
+            }
+        }
+
+        info!(target: "cex-exchanges::deribit", "found {} instruments", instruments.instruments.len());
+        Ok(instruments)
+    }
+
+    pub async fn get_order_book(&self, web_client: &reqwest::Client, pair: &DeribitTradingPair) -> Result<DeribitOrderBookSnapshot, RestApiError> {
+        let url = format!("{}/api/v2/public/get_order_book?instrument_name={pair}&depth={DERIBIT_BOOK_SNAPSHOT_DEPTH}", self.rest_url());
+        Self::simple_rest_api_request(web_client, url).await
+    }
+
+    pub async fn simple_rest_api_request<T>(web_client: &reqwest::Client, url: String) -> Result<T, RestApiError>
+    where
+        T: for<'de> Deserialize<'de>
+    {
+        rate_limiter(CexExchange::Deribit, RateLimitKind::Rest)
+            .acquire()
+            .await;
+        let data = web_client.get(&url).send().await?.json().await?;
+        Ok(data)
+    }
+}
+
+impl Exchange for Deribit {
+    type RestApiResult = DeribitRestApiResponse;
+    type WsMessage = DeribitWsMessage;
+
+    const EXCHANGE: CexExchange = CexExchange::Deribit;
+
+    fn with_endpoints(mut self, endpoints: ExchangeEndpoints) -> Self {
+        self.endpoints = endpoints;
+        self
+    }
+
+    fn remove_bad_pair(&mut self, bad_pair: NormalizedTradingPair) -> bool {
+        let pair: DeribitTradingPair = bad_pair.try_into().unwrap();
+        self.subscription.remove_pair(&pair)
+    }
+
+    async fn make_ws_connection(&self, subscribe_limiter: &TokenBucket) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, WsError> {
+        let (mut ws, _) = tokio_tungstenite::connect_async(self.ws_url()).await?;
+
+        subscribe_limiter.acquire().await;
+        ws.send(Message::Text(serde_json::to_string(&self.subscription.request())?))
+            .await?;
+
+        Ok(ws)
+    }
+
+    async fn rest_api_call(
+        &self,
+        web_client: &reqwest::Client,
+        api_channel: NormalizedRestApiRequest
+    ) -> Result<DeribitRestApiResponse, RestApiError> {
+        let api_response = match api_channel.clone() {
+            NormalizedRestApiRequest::AllCurrencies => self
+                .get_all_currencies(web_client)
+                .await
+                .map(DeribitRestApiResponse::Currencies),
+            NormalizedRestApiRequest::AllInstruments => self
+                .get_all_instruments(web_client)
+                .await
+                .map(DeribitRestApiResponse::Instruments),
+            NormalizedRestApiRequest::Candles { .. }
+            | NormalizedRestApiRequest::RecentTrades { .. }
+            | NormalizedRestApiRequest::HistoricalTrades { .. } => {
+                Err(RestApiError::UnsupportedRequest(format!("deribit does not support {:?}", api_channel)))
+            }
+        };
+
+        if let Err(e) = api_response.as_ref() {
+            error!(target: "cex-exchanges::deribit", "error calling rest-api endpoint {:?} -- {:?}", api_channel, e);
+        }
+
+        api_response
+    }
+
+    async fn order_book_snapshot(self, pair: NormalizedTradingPair) -> Result<Option<DeribitWsMessage>, RestApiError> {
+        let pair: DeribitTradingPair = pair
+            .try_into()
+            .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+
+        let book = self.get_order_book(&reqwest::Client::new(), &pair).await?;
+
+        Ok(Some(DeribitWsMessage::Book(DeribitBook::from(book))))
+    }
+
+    fn update_subscription(&mut self, update: WsSubscriptionUpdate) -> Result<Vec<Message>, WsError> {
+        let channel: DeribitWsChannel = update.exchange_channel()?;
+        let requests = if update.is_subscribe() { self.subscription.subscribe(channel) } else { self.subscription.unsubscribe(channel) };
+
+        let messages = requests
+            .into_iter()
+            .map(|request| serde_json::to_string(&request).map(Message::Text))
+            .collect::<Result<_, _>>()?;
+
+        Ok(messages)
+    }
+}
@@ -0,0 +1,143 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{exchanges::normalized::types::NormalizedTradingPair, CexExchange};
+
+/// channel prefixes of the ws, followed by the instrument name & interval
+/// (ex: trades.BTC-PERPETUAL.100ms)
+const CHANNEL_PREFIXES: [&str; 3] = ["trades", "ticker", "book"];
+
+/// the instrument name, ex: BTC-PERPETUAL, BTC-27DEC24, BTC-27DEC24-100000-C,
+/// ETH_USDC
+#[derive(Debug, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct DeribitTradingPair(pub(crate) String);
+
+impl DeribitTradingPair {
+    pub fn new_checked(s: &str) -> eyre::Result<Self> {
+        s.to_string().try_into()
+    }
+
+    pub fn is_valid(s: &str) -> bool {
+        s.chars().next().is_some_and(|c| c.is_ascii_alphabetic())
+            && s.contains(['-', '_'])
+            && s.chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    }
+
+    pub fn normalize(&self) -> NormalizedTradingPair {
+        NormalizedTradingPair::new_no_base_quote(CexExchange::Deribit, &self.0)
+    }
+
+    /// the instrument of a ws channel name, ex: trades.BTC-PERPETUAL.100ms ->
+    /// BTC-PERPETUAL
+    pub fn from_channel(channel: &str) -> Option<Self> {
+        let mut split = channel.split('.');
+        if !CHANNEL_PREFIXES.contains(&split.next()?) {
+            return None
+        }
+
+        split.next().and_then(|pair| Self::try_from(pair).ok())
+    }
+
+    pub fn parse_for_bad_pair(value: &str) -> Option<Self> {
+        let value = value.replace(['\'', '"', ',', ':', '[', ']'], " ");
+        value.split(' ').find_map(Self::from_channel)
+    }
+}
+
+impl Display for DeribitTradingPair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Serialize for DeribitTradingPair {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for DeribitTradingPair {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        let s = String::deserialize(deserializer)?;
+
+        Ok(DeribitTradingPair(s))
+    }
+}
+
+impl TryFrom<NormalizedTradingPair> for DeribitTradingPair {
+    type Error = eyre::Report;
+
+    fn try_from(value: NormalizedTradingPair) -> Result<Self, Self::Error> {
+        if let Some(raw_pair) = value.pair() {
+            if let Ok(v) = Self::new_checked(raw_pair) {
+                return Ok(v)
+            }
+
+            let new_str = raw_pair.replace('/', "_");
+            if let Ok(this) = Self::new_checked(&new_str) {
+                return Ok(this)
+            }
+        }
+
+        if let Some((base, quote)) = value.base_quote() {
+            return Ok(DeribitTradingPair(format!("{}_{}", base.to_uppercase(), quote.to_uppercase())))
+        }
+
+        Err(eyre::ErrReport::msg(format!("INVALID Deribit trading pair '{:?}'", value)))
+    }
+}
+
+impl TryFrom<&str> for DeribitTradingPair {
+    type Error = eyre::Report;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if Self::is_valid(value) {
+            Ok(DeribitTradingPair(value.to_uppercase()))
+        } else {
+            Err(eyre::ErrReport::msg(format!("INVALID Deribit trading pair '{value}' is not an instrument name split by '-' or '_'")))
+        }
+    }
+}
+
+impl TryFrom<String> for DeribitTradingPair {
+    type Error = eyre::Report;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.as_str().try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::normalized::types::RawTradingPair;
+
+    #[test]
+    fn test_denormalize() {
+        let pair = RawTradingPair::new_raw("BTC-PERPETUAL", '-').get_normalized_pair(CexExchange::Deribit);
+        assert_eq!(DeribitTradingPair::try_from(pair).unwrap(), DeribitTradingPair("BTC-PERPETUAL".to_string()));
+
+        let pair = RawTradingPair::new_no_delim("btc-27dec24-100000-c").get_normalized_pair(CexExchange::Deribit);
+        assert_eq!(DeribitTradingPair::try_from(pair).unwrap(), DeribitTradingPair("BTC-27DEC24-100000-C".to_string()));
+
+        let pair = RawTradingPair::new_base_quote("eth", "usdc", None).get_normalized_pair(CexExchange::Deribit);
+        assert_eq!(DeribitTradingPair::try_from(pair).unwrap(), DeribitTradingPair("ETH_USDC".to_string()));
+    }
+
+    #[test]
+    fn test_parse_for_bad_pair() {
+        let test_str = r#"{"jsonrpc":"2.0","id":1,"error":{"message":"Invalid params","data":{"reason":"wrong format","param":"trades.BTC-XYZ.100ms"},"code":-32602}}"#;
+
+        let calculated = DeribitTradingPair::parse_for_bad_pair(test_str);
+
+        assert_eq!(calculated, Some(DeribitTradingPair("BTC-XYZ".to_string())))
+    }
+}
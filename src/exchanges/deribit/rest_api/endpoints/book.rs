@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    deribit::{
+        rest_api::deribit_result,
+        ws::channels::{DeribitBook, DeribitBookData, DeribitBookLevel},
+        DeribitTradingPair
+    },
+    normalized::types::NormalizedNumber
+};
+
+/// the book of `/api/v2/public/get_order_book`, used to resync the ws `book`
+/// channel
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct DeribitOrderBookSnapshot {
+    pub instrument_name: DeribitTradingPair,
+    pub timestamp:       u64,
+    pub change_id:       u64,
+    pub bids:            Vec<(NormalizedNumber, NormalizedNumber)>,
+    pub asks:            Vec<(NormalizedNumber, NormalizedNumber)>
+}
+
+impl<'de> Deserialize<'de> for DeribitOrderBookSnapshot {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        #[derive(Deserialize)]
+        struct Inner {
+            instrument_name: DeribitTradingPair,
+            timestamp:       u64,
+            change_id:       u64,
+            bids:            Vec<(NormalizedNumber, NormalizedNumber)>,
+            asks:            Vec<(NormalizedNumber, NormalizedNumber)>
+        }
+
+        let val = Value::deserialize(deserializer)?;
+        let result = deribit_result(val).map_err(serde::de::Error::custom)?;
+
+        let Inner { instrument_name, timestamp, change_id, bids, asks } = serde_json::from_value(result).map_err(serde::de::Error::custom)?;
+
+        Ok(DeribitOrderBookSnapshot { instrument_name, timestamp, change_id, bids, asks })
+    }
+}
+
+impl From<DeribitOrderBookSnapshot> for DeribitBook {
+    fn from(value: DeribitOrderBookSnapshot) -> Self {
+        let to_levels = |levels: Vec<(NormalizedNumber, NormalizedNumber)>| {
+            levels
+                .into_iter()
+                .map(|(price, amount)| DeribitBookLevel { action: "new".to_string(), price, amount })
+                .collect()
+        };
+
+        DeribitBook {
+            channel: format!("book.{}.100ms", value.instrument_name),
+            data:    DeribitBookData {
+                kind:            "snapshot".to_string(),
+                instrument_name: value.instrument_name,
+                timestamp:       value.timestamp,
+                change_id:       value.change_id,
+                prev_change_id:  None,
+                bids:            to_levels(value.bids),
+                asks:            to_levels(value.asks)
+            }
+        }
+    }
+}
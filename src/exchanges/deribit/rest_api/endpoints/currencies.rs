@@ -0,0 +1,114 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::warn;
+
+use crate::{
+    deribit::rest_api::deribit_result,
+    normalized::{rest_api::NormalizedRestApiDataTypes, types::NormalizedCurrency},
+    CexExchange
+};
+
+#[derive(Debug, Clone, Serialize, PartialEq, PartialOrd)]
+pub struct DeribitAllCurrencies {
+    pub currencies: Vec<DeribitCurrency>
+}
+
+impl DeribitAllCurrencies {
+    pub fn normalize(self) -> Vec<NormalizedCurrency> {
+        self.currencies
+            .into_iter()
+            .map(DeribitCurrency::normalize)
+            .collect()
+    }
+}
+
+impl<'de> Deserialize<'de> for DeribitAllCurrencies {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        let val = Value::deserialize(deserializer)?;
+        let result = deribit_result(val).map_err(serde::de::Error::custom)?;
+
+        let currencies = serde_json::from_value(result).map_err(serde::de::Error::custom)?;
+
+        Ok(DeribitAllCurrencies { currencies })
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for DeribitAllCurrencies {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match other {
+            NormalizedRestApiDataTypes::AllCurrencies(other_currs) => {
+                let this_currencies = self
+                    .currencies
+                    .iter()
+                    .map(|curr| (&curr.currency_long, &curr.currency))
+                    .collect::<HashSet<_>>();
+
+                self.currencies.len() == other_currs.len()
+                    && other_currs
+                        .iter()
+                        .all(|curr| this_currencies.contains(&(&curr.name, &curr.symbol)))
+            }
+            _ => false
+        }
+    }
+}
+
+/// a currency of `/api/v2/public/get_currencies`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct DeribitCurrency {
+    /// ex: BTC
+    pub currency:              String,
+    /// ex: Bitcoin
+    pub currency_long:         String,
+    /// ex: BITCOIN
+    pub coin_type:             String,
+    pub fee_precision:         u32,
+    pub min_confirmations:     Option<u64>,
+    pub withdrawal_fee:        f64,
+    pub min_withdrawal_fee:    Option<f64>,
+    #[serde(default)]
+    pub withdrawal_priorities: Vec<DeribitWithdrawalPriority>
+}
+
+impl DeribitCurrency {
+    pub fn normalize(self) -> NormalizedCurrency {
+        NormalizedCurrency {
+            exchange:     CexExchange::Deribit,
+            symbol:       self.currency,
+            name:         self.currency_long,
+            display_name: None,
+            status:       "enabled".to_string(),
+            blockchains:  Vec::new()
+        }
+    }
+}
+
+impl PartialEq<NormalizedCurrency> for DeribitCurrency {
+    fn eq(&self, other: &NormalizedCurrency) -> bool {
+        let equals = other.exchange == CexExchange::Deribit
+            && other.symbol == self.currency
+            && other.name == self.currency_long
+            && other.display_name.is_none()
+            && &other.status == "enabled"
+            && other.blockchains.is_empty();
+
+        if !equals {
+            warn!(target: "cex-exchanges::deribit", "deribit currency: {:?}", self);
+            warn!(target: "cex-exchanges::deribit", "normalized currency: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct DeribitWithdrawalPriority {
+    /// ex: very_low, high
+    pub name:  String,
+    pub value: f64
+}
@@ -0,0 +1,143 @@
+use chrono::{DateTime, NaiveDate};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::warn;
+
+use crate::{
+    deribit::{rest_api::deribit_result, DeribitTradingPair},
+    normalized::{
+        rest_api::NormalizedRestApiDataTypes,
+        types::{NormalizedInstrument, NormalizedNumber, NormalizedOptionType, NormalizedTradingType}
+    },
+    CexExchange
+};
+
+#[derive(Debug, Clone, Default, Serialize, PartialEq, PartialOrd)]
+pub struct DeribitAllInstruments {
+    pub instruments: Vec<DeribitInstrument>
+}
+
+impl DeribitAllInstruments {
+    pub fn normalize(self) -> Vec<NormalizedInstrument> {
+        self.instruments
+            .into_iter()
+            .map(DeribitInstrument::normalize)
+            .collect()
+    }
+}
+
+impl<'de> Deserialize<'de> for DeribitAllInstruments {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        let val = Value::deserialize(deserializer)?;
+        let result = deribit_result(val).map_err(serde::de::Error::custom)?;
+
+        let instruments = serde_json::from_value(result).map_err(serde::de::Error::custom)?;
+
+        Ok(DeribitAllInstruments { instruments })
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for DeribitAllInstruments {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match other {
+            NormalizedRestApiDataTypes::AllInstruments(other_instrs) => {
+                self.instruments.len() == other_instrs.len()
+                    && self
+                        .instruments
+                        .iter()
+                        .zip(other_instrs.iter())
+                        .all(|(this, that)| this == that)
+            }
+            _ => false
+        }
+    }
+}
+
+/// an instrument of `/api/v2/public/get_instruments`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct DeribitInstrument {
+    pub instrument_name:      DeribitTradingPair,
+    pub instrument_id:        u64,
+    /// 'future', 'option', 'spot', 'future_combo' or 'option_combo'
+    pub kind:                 String,
+    pub base_currency:        String,
+    pub quote_currency:       String,
+    pub counter_currency:     String,
+    pub settlement_currency:  Option<String>,
+    /// 'perpetual', 'day', 'week', 'month' or 'quarter'
+    pub settlement_period:    Option<String>,
+    pub is_active:            bool,
+    pub creation_timestamp:   u64,
+    pub expiration_timestamp: u64,
+    /// only set on options
+    pub strike:               Option<NormalizedNumber>,
+    /// only set on options
+    pub option_type:          Option<NormalizedOptionType>,
+    pub tick_size:            f64,
+    pub min_trade_amount:     f64,
+    pub contract_size:        f64,
+    pub maker_commission:     f64,
+    pub taker_commission:     f64
+}
+
+impl DeribitInstrument {
+    pub fn is_active(&self) -> bool {
+        self.is_active
+    }
+
+    pub fn trading_type(&self) -> NormalizedTradingType {
+        match self.kind.as_str() {
+            "spot" => NormalizedTradingType::Spot,
+            "option" => NormalizedTradingType::Option,
+            "future" if self.settlement_period.as_deref() == Some("perpetual") => NormalizedTradingType::Perpetual,
+            "future" => NormalizedTradingType::Futures,
+            _ => NormalizedTradingType::Other
+        }
+    }
+
+    /// the expiry of dated futures & options
+    pub fn expiry(&self) -> Option<NaiveDate> {
+        matches!(self.trading_type(), NormalizedTradingType::Futures | NormalizedTradingType::Option)
+            .then(|| DateTime::from_timestamp_millis(self.expiration_timestamp as i64))
+            .flatten()
+            .map(|t| t.date_naive())
+    }
+
+    pub fn normalize(self) -> NormalizedInstrument {
+        NormalizedInstrument {
+            exchange:           CexExchange::Deribit,
+            trading_pair:       self.instrument_name.normalize(),
+            trading_type:       self.trading_type(),
+            base_asset_symbol:  self.base_currency.clone(),
+            quote_asset_symbol: self.quote_currency.clone(),
+            active:             self.is_active,
+            futures_expiry:     self.expiry(),
+            option_strike:      self.strike,
+            option_type:        self.option_type
+        }
+    }
+}
+
+impl PartialEq<NormalizedInstrument> for DeribitInstrument {
+    fn eq(&self, other: &NormalizedInstrument) -> bool {
+        let equals = other.exchange == CexExchange::Deribit
+            && other.trading_pair == self.instrument_name.normalize()
+            && other.trading_type == self.trading_type()
+            && other.base_asset_symbol == self.base_currency
+            && other.quote_asset_symbol == self.quote_currency
+            && other.active == self.is_active
+            && other.futures_expiry == self.expiry()
+            && other.option_strike == self.strike
+            && other.option_type == self.option_type;
+
+        if !equals {
+            warn!(target: "cex-exchanges::deribit", "deribit instrument: {:?}", self);
+            warn!(target: "cex-exchanges::deribit", "normalized instrument: {:?}", other);
+        }
+
+        equals
+    }
+}
@@ -0,0 +1,8 @@
+mod currencies;
+pub use currencies::*;
+
+mod instruments;
+pub use instruments::*;
+
+mod book;
+pub use book::*;
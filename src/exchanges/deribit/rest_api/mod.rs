@@ -0,0 +1,18 @@
+mod endpoints;
+pub use endpoints::*;
+
+mod response;
+pub use response::*;
+use serde_json::Value;
+
+/// the `result` of a response, every response is wrapped in the json-rpc
+/// `{"jsonrpc": "2.0", "result": .., "error": {..}}`
+pub(crate) fn deribit_result(val: Value) -> eyre::Result<Value> {
+    if let Some(error) = val.get("error") {
+        return Err(eyre::ErrReport::msg(format!("deribit returned the error: {:?}", error)))
+    }
+
+    val.get("result")
+        .cloned()
+        .ok_or(eyre::ErrReport::msg(format!("could not get field 'result' in {:?}", val)))
+}
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use super::{DeribitAllCurrencies, DeribitAllInstruments, DeribitCurrency, DeribitInstrument};
+use crate::normalized::rest_api::NormalizedRestApiDataTypes;
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum DeribitRestApiResponse {
+    Currencies(DeribitAllCurrencies),
+    Instruments(DeribitAllInstruments)
+}
+
+impl DeribitRestApiResponse {
+    pub fn normalize(self) -> NormalizedRestApiDataTypes {
+        match self {
+            DeribitRestApiResponse::Currencies(v) => NormalizedRestApiDataTypes::AllCurrencies(v.normalize()),
+            DeribitRestApiResponse::Instruments(v) => NormalizedRestApiDataTypes::AllInstruments(v.normalize())
+        }
+    }
+
+    pub fn take_currencies(self) -> Option<Vec<DeribitCurrency>> {
+        match self {
+            DeribitRestApiResponse::Currencies(val) => Some(val.currencies),
+            _ => None
+        }
+    }
+
+    pub fn take_instruments(self, active_only: bool) -> Option<Vec<DeribitInstrument>> {
+        let instruments = match self {
+            DeribitRestApiResponse::Instruments(val) => val.instruments,
+            _ => return None
+        };
+
+        if active_only {
+            Some(
+                instruments
+                    .into_iter()
+                    .filter(|instr| instr.is_active())
+                    .collect::<Vec<_>>()
+            )
+        } else {
+            Some(instruments)
+        }
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for DeribitRestApiResponse {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match self {
+            DeribitRestApiResponse::Currencies(vals) => vals == other,
+            DeribitRestApiResponse::Instruments(vals) => vals == other
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use super::{
+    channels::{DeribitWsChannel, DeribitWsChannelKind},
+    DeribitSubscription
+};
+use crate::{
+    clients::{rest_api::ExchangeApi, ws::MutliWsStreamBuilder},
+    deribit::Deribit,
+    normalized::ws::NormalizedWsChannels
+};
+
+/// max number of streams the channels are distributed over
+const MAX_DERIBIT_STREAMS: usize = 8;
+/// max number of channels subscribed to on a single connection
+const MAX_DERIBIT_WS_CONNS_PER_STREAM: usize = 50;
+
+#[derive(Debug, Clone, Default)]
+pub struct DeribitWsBuilder {
+    pub channels: Vec<DeribitWsChannel>
+}
+
+impl DeribitWsBuilder {
+    /// adds a channel to the builder
+    pub fn add_channel(mut self, channel: DeribitWsChannel) -> Self {
+        self.channels.push(channel);
+        self
+    }
+
+    /// builds a single ws instance of [Deribit], handling all channels on 1
+    /// stream
+    pub fn build_single(self) -> Deribit {
+        let mut sub = DeribitSubscription::new();
+        self.channels.into_iter().for_each(|c| sub.add_channel(c));
+
+        Deribit::new_ws_subscription(sub)
+    }
+
+    /// builds many ws instances of the [Deribit] as the inner streams of
+    /// [MutliWsStreamBuilder], splitting the channels into different streams,
+    /// each with size # channels / `MAX_DERIBIT_STREAMS` (8),
+    ///
+    /// WARNING: too many channels may break the stream
+    pub fn build_many_distributed(self) -> eyre::Result<MutliWsStreamBuilder<Deribit>> {
+        let stream_size = if self.channels.len() <= MAX_DERIBIT_STREAMS { 1 } else { self.channels.len() / MAX_DERIBIT_STREAMS };
+
+        let chunks = self.channels.chunks(stream_size).collect::<Vec<_>>();
+
+        let split_exchange = chunks
+            .into_iter()
+            .map(|chk| {
+                let mut subscription = DeribitSubscription::new();
+                chk.iter()
+                    .for_each(|ch| subscription.add_channel(ch.clone()));
+
+                Deribit::new_ws_subscription(subscription)
+            })
+            .collect();
+
+        Ok(MutliWsStreamBuilder::new(split_exchange))
+    }
+
+    /// builds many ws instances of the [Deribit] as the inner streams of
+    /// [MutliWsStreamBuilder], splitting the channels into different streams,
+    /// each of size 50
+    pub fn build_many_packed(self, connections_per_stream: Option<usize>) -> eyre::Result<MutliWsStreamBuilder<Deribit>> {
+        let chunks = self
+            .channels
+            .chunks(connections_per_stream.unwrap_or(MAX_DERIBIT_WS_CONNS_PER_STREAM))
+            .collect::<Vec<_>>();
+
+        let split_exchange = chunks
+            .into_iter()
+            .map(|chk| {
+                let mut subscription = DeribitSubscription::new();
+                chk.iter()
+                    .for_each(|ch| subscription.add_channel(ch.clone()));
+
+                Deribit::new_ws_subscription(subscription)
+            })
+            .collect();
+
+        Ok(MutliWsStreamBuilder::new(split_exchange))
+    }
+
+    /// builds a mutlistream channel from all active instruments
+    pub async fn build_from_all_instruments(
+        channels: &[DeribitWsChannelKind],
+        connections_per_stream: Option<usize>
+    ) -> eyre::Result<MutliWsStreamBuilder<Deribit>> {
+        let this = Self::build_from_all_instruments_util(channels, connections_per_stream).await?;
+
+        let all_streams = this
+            .channels
+            .into_iter()
+            .map(|ch| {
+                let mut subscription = DeribitSubscription::new();
+                subscription.add_channel(ch);
+
+                Deribit::new_ws_subscription(subscription)
+            })
+            .collect::<Vec<_>>();
+
+        Ok(MutliWsStreamBuilder::new(all_streams))
+    }
+
+    async fn build_from_all_instruments_util(channels: &[DeribitWsChannelKind], connections_per_stream: Option<usize>) -> eyre::Result<Self> {
+        let mut this = Self::default();
+
+        let all_symbols = ExchangeApi::new()
+            .all_instruments::<Deribit>()
+            .await?
+            .take_deribit_instruments(true)
+            .unwrap()
+            .into_iter()
+            .map(|val| val.instrument_name)
+            .collect::<Vec<_>>();
+
+        let chunks = all_symbols.chunks(connections_per_stream.unwrap_or(MAX_DERIBIT_WS_CONNS_PER_STREAM));
+
+        chunks.into_iter().for_each(|chk| {
+            let all_channels = channels
+                .iter()
+                .map(|ch| match ch {
+                    DeribitWsChannelKind::Trades => DeribitWsChannel::Trades(chk.to_vec()),
+                    DeribitWsChannelKind::Ticker => DeribitWsChannel::Ticker(chk.to_vec()),
+                    DeribitWsChannelKind::Book => DeribitWsChannel::Book(chk.to_vec())
+                })
+                .collect::<Vec<_>>();
+
+            this.channels.extend(all_channels);
+        });
+
+        Ok(this)
+    }
+
+    /// makes the builder from the normalized builder's map
+    pub(crate) fn make_from_normalized_map(map: Vec<NormalizedWsChannels>) -> eyre::Result<Self> {
+        let mut this = Self::default();
+
+        map.into_iter().try_for_each(|channel| {
+            let this_channel: DeribitWsChannel = channel.try_into()?;
+            this = this.clone().add_channel(this_channel);
+            Ok(()) as eyre::Result<()>
+        })?;
+
+        Ok(this)
+    }
+}
@@ -0,0 +1,103 @@
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::{
+    exchanges::{
+        deribit::pairs::DeribitTradingPair,
+        normalized::types::{NormalizedBookLevel, NormalizedOrderBookUpdate, NormalizedOrderBookUpdateKind}
+    },
+    normalized::types::NormalizedNumber,
+    CexExchange
+};
+
+/// the number of levels per side fetched from the rest api to resync the
+/// `book` channel
+pub const DERIBIT_BOOK_SNAPSHOT_DEPTH: usize = 1000;
+
+/// the `book.{instrument}.100ms` channel, the first message is a 'snapshot'
+/// of the full book followed by 'change's
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DeribitBook {
+    /// ex: book.BTC-PERPETUAL.100ms
+    pub channel: String,
+    pub data:    DeribitBookData
+}
+
+impl DeribitBook {
+    pub fn normalize(self) -> NormalizedOrderBookUpdate {
+        NormalizedOrderBookUpdate {
+            exchange:        CexExchange::Deribit,
+            pair:            self.data.instrument_name.normalize(),
+            time:            DateTime::from_timestamp_millis(self.data.timestamp as i64).unwrap(),
+            kind:            self.data.update_kind(),
+            bids:            DeribitBookData::normalize_side(&self.data.bids),
+            asks:            DeribitBookData::normalize_side(&self.data.asks),
+            first_update_id: None,
+            last_update_id:  Some(self.data.change_id)
+        }
+    }
+}
+
+impl PartialEq<NormalizedOrderBookUpdate> for DeribitBook {
+    fn eq(&self, other: &NormalizedOrderBookUpdate) -> bool {
+        let equals = other.exchange == CexExchange::Deribit
+            && other.pair == self.data.instrument_name.normalize()
+            && other.time == DateTime::from_timestamp_millis(self.data.timestamp as i64).unwrap()
+            && other.kind == self.data.update_kind()
+            && other.bids == DeribitBookData::normalize_side(&self.data.bids)
+            && other.asks == DeribitBookData::normalize_side(&self.data.asks)
+            && other.last_update_id == Some(self.data.change_id);
+
+        if !equals {
+            warn!(target: "cex-exchanges::deribit", "deribit book: {:?}", self);
+            warn!(target: "cex-exchanges::deribit", "normalized order book: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DeribitBookData {
+    /// 'snapshot' or 'change'
+    #[serde(rename = "type")]
+    pub kind:            String,
+    pub instrument_name: DeribitTradingPair,
+    pub timestamp:       u64,
+    pub change_id:       u64,
+    /// the `change_id` of the previous message, not set on snapshots
+    pub prev_change_id:  Option<u64>,
+    pub bids:            Vec<DeribitBookLevel>,
+    pub asks:            Vec<DeribitBookLevel>
+}
+
+impl DeribitBookData {
+    pub fn is_snapshot(&self) -> bool {
+        &self.kind == "snapshot"
+    }
+
+    fn update_kind(&self) -> NormalizedOrderBookUpdateKind {
+        if self.is_snapshot() {
+            NormalizedOrderBookUpdateKind::Snapshot
+        } else {
+            NormalizedOrderBookUpdateKind::Delta
+        }
+    }
+
+    fn normalize_side(levels: &[DeribitBookLevel]) -> Vec<NormalizedBookLevel> {
+        levels
+            .iter()
+            .map(|level| NormalizedBookLevel::new(level.price, level.amount))
+            .collect()
+    }
+}
+
+/// ex: ["new", 67000.5, 1200.0], the amount of a 'delete' is 0
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DeribitBookLevel {
+    /// 'new', 'change' or 'delete'
+    pub action: String,
+    pub price:  NormalizedNumber,
+    pub amount: NormalizedNumber
+}
@@ -0,0 +1,175 @@
+use std::fmt::Display;
+
+mod trades;
+pub use trades::*;
+
+mod ticker;
+pub use ticker::*;
+
+mod book;
+pub use book::*;
+
+use crate::{
+    exchanges::{
+        deribit::pairs::DeribitTradingPair,
+        normalized::{
+            types::{NormalizedTradingPair, RawTradingPair},
+            ws::NormalizedWsChannels
+        }
+    },
+    CexExchange
+};
+
+/// the update interval of the channels, 'raw' requires an authorized
+/// connection
+pub const DERIBIT_CHANNEL_INTERVAL: &str = "100ms";
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum DeribitWsChannel {
+    Trades(Vec<DeribitTradingPair>),
+    /// best bid & offer, mark price & (for options) the greeks
+    Ticker(Vec<DeribitTradingPair>),
+    /// the full depth book, a snapshot followed by changes
+    Book(Vec<DeribitTradingPair>)
+}
+
+impl DeribitWsChannel {
+    /// builds trades channel from a vec of raw trading pairs
+    /// return an error if the symbol is incorrectly formatted
+    pub fn new_trades(pairs: Vec<RawTradingPair>) -> eyre::Result<Self> {
+        let normalized = pairs
+            .into_iter()
+            .map(|pair| pair.get_normalized_pair(CexExchange::Deribit))
+            .collect();
+
+        Self::new_from_kind(normalized, DeribitWsChannel::Trades(Vec::new()))
+    }
+
+    /// builds ticker channel from a vec of raw trading pairs
+    /// return an error if the symbol is incorrectly formatted
+    pub fn new_ticker(pairs: Vec<RawTradingPair>) -> eyre::Result<Self> {
+        let normalized = pairs
+            .into_iter()
+            .map(|pair| pair.get_normalized_pair(CexExchange::Deribit))
+            .collect();
+
+        Self::new_from_kind(normalized, DeribitWsChannel::Ticker(Vec::new()))
+    }
+
+    /// builds book channel from a vec of raw trading pairs
+    /// return an error if the symbol is incorrectly formatted
+    pub fn new_book(pairs: Vec<RawTradingPair>) -> eyre::Result<Self> {
+        let normalized = pairs
+            .into_iter()
+            .map(|pair| pair.get_normalized_pair(CexExchange::Deribit))
+            .collect();
+
+        Self::new_from_kind(normalized, DeribitWsChannel::Book(Vec::new()))
+    }
+
+    fn new_from_kind(pairs: Vec<NormalizedTradingPair>, kind: DeribitWsChannel) -> eyre::Result<Self> {
+        let pairs = pairs
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+
+        match kind {
+            DeribitWsChannel::Trades(_) => Ok(DeribitWsChannel::Trades(pairs)),
+            DeribitWsChannel::Ticker(_) => Ok(DeribitWsChannel::Ticker(pairs)),
+            DeribitWsChannel::Book(_) => Ok(DeribitWsChannel::Book(pairs))
+        }
+    }
+
+    pub(crate) fn pairs(&self) -> &[DeribitTradingPair] {
+        match self {
+            DeribitWsChannel::Trades(pairs) => pairs,
+            DeribitWsChannel::Ticker(pairs) => pairs,
+            DeribitWsChannel::Book(pairs) => pairs
+        }
+    }
+}
+
+impl Display for DeribitWsChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        DeribitWsChannelKind::from(self).fmt(f)
+    }
+}
+
+impl TryFrom<String> for DeribitWsChannel {
+    type Error = eyre::Report;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.to_lowercase().as_str() {
+            "trades" => Ok(Self::Trades(Vec::new())),
+            "ticker" => Ok(Self::Ticker(Vec::new())),
+            "book" => Ok(Self::Book(Vec::new())),
+            _ => Err(eyre::ErrReport::msg(format!("channel is not valid: {value}")))
+        }
+    }
+}
+
+impl TryFrom<NormalizedWsChannels> for DeribitWsChannel {
+    type Error = eyre::ErrReport;
+
+    fn try_from(value: NormalizedWsChannels) -> Result<Self, Self::Error> {
+        match value {
+            NormalizedWsChannels::Trades(pairs) => {
+                let norm_pairs = pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, Self::Error>>()?;
+
+                Ok(DeribitWsChannel::Trades(norm_pairs))
+            }
+
+            NormalizedWsChannels::Quotes(pairs) => {
+                let norm_pairs = pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, Self::Error>>()?;
+
+                Ok(DeribitWsChannel::Ticker(norm_pairs))
+            }
+
+            NormalizedWsChannels::OrderBook { pairs, .. } => {
+                let norm_pairs = pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, Self::Error>>()?;
+
+                Ok(DeribitWsChannel::Book(norm_pairs))
+            }
+
+            NormalizedWsChannels::Candles { .. } => Err(eyre::ErrReport::msg("deribit does not support the candles ws channel")),
+
+            NormalizedWsChannels::Account(_) => Err(eyre::ErrReport::msg("deribit does not support the account ws channel"))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum DeribitWsChannelKind {
+    Trades,
+    Ticker,
+    Book
+}
+
+impl Display for DeribitWsChannelKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DeribitWsChannelKind::Trades => write!(f, "trades"),
+            DeribitWsChannelKind::Ticker => write!(f, "ticker"),
+            DeribitWsChannelKind::Book => write!(f, "book")
+        }
+    }
+}
+
+impl From<&DeribitWsChannel> for DeribitWsChannelKind {
+    fn from(value: &DeribitWsChannel) -> Self {
+        match value {
+            DeribitWsChannel::Trades(_) => DeribitWsChannelKind::Trades,
+            DeribitWsChannel::Ticker(_) => DeribitWsChannelKind::Ticker,
+            DeribitWsChannel::Book(_) => DeribitWsChannelKind::Book
+        }
+    }
+}
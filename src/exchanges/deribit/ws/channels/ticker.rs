@@ -0,0 +1,110 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::{
+    clients::ws::received_at,
+    exchanges::{deribit::pairs::DeribitTradingPair, normalized::types::NormalizedQuote},
+    normalized::types::NormalizedNumber,
+    CexExchange
+};
+
+/// the `ticker.{instrument}.100ms` channel
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeribitTicker {
+    /// ex: ticker.BTC-PERPETUAL.100ms
+    pub channel: String,
+    pub data:    DeribitTickerData
+}
+
+impl DeribitTicker {
+    pub fn normalize(self) -> NormalizedQuote {
+        self.data.normalize()
+    }
+}
+
+impl PartialEq<NormalizedQuote> for DeribitTicker {
+    fn eq(&self, other: &NormalizedQuote) -> bool {
+        let equals = &self.data == other;
+
+        if !equals {
+            warn!(target: "cex-exchanges::deribit", "deribit ticker: {:?}", self);
+            warn!(target: "cex-exchanges::deribit", "normalized quote: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeribitTickerData {
+    pub instrument_name:  DeribitTradingPair,
+    pub timestamp:        u64,
+    /// 'open' or 'closed'
+    pub state:            String,
+    /// `None` if that side of the book is empty
+    pub best_bid_price:   Option<NormalizedNumber>,
+    pub best_bid_amount:  NormalizedNumber,
+    /// `None` if that side of the book is empty
+    pub best_ask_price:   Option<NormalizedNumber>,
+    pub best_ask_amount:  NormalizedNumber,
+    pub last_price:       Option<NormalizedNumber>,
+    pub mark_price:       f64,
+    pub index_price:      f64,
+    pub open_interest:    f64,
+    /// only set on perpetuals
+    pub funding_8h:       Option<f64>,
+    /// only set on options
+    pub underlying_price: Option<f64>,
+    /// only set on options
+    pub underlying_index: Option<String>,
+    /// only set on options
+    pub mark_iv:          Option<f64>,
+    /// only set on options
+    pub bid_iv:           Option<f64>,
+    /// only set on options
+    pub ask_iv:           Option<f64>,
+    /// only set on options
+    pub greeks:           Option<DeribitGreeks>,
+    #[serde(default = "received_at")]
+    pub received_at:      DateTime<Utc>
+}
+
+impl DeribitTickerData {
+    pub fn normalize(self) -> NormalizedQuote {
+        NormalizedQuote {
+            exchange:      CexExchange::Deribit,
+            pair:          self.instrument_name.normalize(),
+            exchange_time: DateTime::from_timestamp_millis(self.timestamp as i64),
+            received_at:   self.received_at,
+            ask_amount:    self.best_ask_amount,
+            ask_price:     self.best_ask_price.unwrap_or_default(),
+            bid_amount:    self.best_bid_amount,
+            bid_price:     self.best_bid_price.unwrap_or_default(),
+            quote_id:      None
+        }
+    }
+}
+
+impl PartialEq<NormalizedQuote> for DeribitTickerData {
+    fn eq(&self, other: &NormalizedQuote) -> bool {
+        other.exchange == CexExchange::Deribit
+            && other.pair == self.instrument_name.normalize()
+            && other.exchange_time == DateTime::from_timestamp_millis(self.timestamp as i64)
+            && other.received_at == self.received_at
+            && other.ask_amount == self.best_ask_amount
+            && other.ask_price == self.best_ask_price.unwrap_or_default()
+            && other.bid_amount == self.best_bid_amount
+            && other.bid_price == self.best_bid_price.unwrap_or_default()
+            && other.quote_id.is_none()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct DeribitGreeks {
+    pub delta: f64,
+    pub gamma: f64,
+    pub vega:  f64,
+    pub theta: f64,
+    pub rho:   f64
+}
@@ -0,0 +1,99 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::{
+    clients::ws::received_at,
+    exchanges::{
+        deribit::pairs::DeribitTradingPair,
+        normalized::types::{NormalizedTrade, TradeSide}
+    },
+    normalized::types::NormalizedNumber,
+    CexExchange
+};
+
+/// the `trades.{instrument}.100ms` channel
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DeribitTrades {
+    /// ex: trades.BTC-PERPETUAL.100ms
+    pub channel: String,
+    pub data:    Vec<DeribitTradeData>
+}
+
+impl DeribitTrades {
+    pub fn normalize(self) -> Vec<NormalizedTrade> {
+        self.data
+            .into_iter()
+            .map(DeribitTradeData::normalize)
+            .collect()
+    }
+}
+
+impl PartialEq<Vec<NormalizedTrade>> for DeribitTrades {
+    fn eq(&self, other: &Vec<NormalizedTrade>) -> bool {
+        let equals = self.data.len() == other.len()
+            && self
+                .data
+                .iter()
+                .zip(other.iter())
+                .all(|(this, that)| this == that);
+
+        if !equals {
+            warn!(target: "cex-exchanges::deribit", "deribit trades: {:?}", self);
+            warn!(target: "cex-exchanges::deribit", "normalized trades: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DeribitTradeData {
+    pub instrument_name: DeribitTradingPair,
+    pub trade_id:        String,
+    pub trade_seq:       u64,
+    pub timestamp:       u64,
+    /// 'buy' or 'sell'
+    pub direction:       String,
+    pub price:           NormalizedNumber,
+    /// usd for inverse instruments, the base currency otherwise
+    pub amount:          NormalizedNumber,
+    pub mark_price:      NormalizedNumber,
+    pub index_price:     NormalizedNumber,
+    pub tick_direction:  u8,
+    /// implied volatility of option trades
+    pub iv:              Option<f64>,
+    /// 'M' (maker), 'T' (taker) or 'MT' (both) if the trade was a liquidation
+    pub liquidation:     Option<String>,
+    pub block_trade_id:  Option<String>,
+    #[serde(default = "received_at")]
+    pub received_at:     DateTime<Utc>
+}
+
+impl DeribitTradeData {
+    pub fn normalize(self) -> NormalizedTrade {
+        NormalizedTrade {
+            exchange:      CexExchange::Deribit,
+            pair:          self.instrument_name.normalize(),
+            exchange_time: DateTime::from_timestamp_millis(self.timestamp as i64),
+            received_at:   self.received_at,
+            side:          TradeSide::from_taker_side(&self.direction),
+            price:         self.price,
+            amount:        self.amount,
+            trade_id:      Some(self.trade_id)
+        }
+    }
+}
+
+impl PartialEq<NormalizedTrade> for DeribitTradeData {
+    fn eq(&self, other: &NormalizedTrade) -> bool {
+        other.exchange == CexExchange::Deribit
+            && other.pair == self.instrument_name.normalize()
+            && other.exchange_time == DateTime::from_timestamp_millis(self.timestamp as i64)
+            && other.received_at == self.received_at
+            && other.side == TradeSide::from_taker_side(&self.direction)
+            && other.price == self.price
+            && other.amount == self.amount
+            && other.trade_id.as_ref() == Some(&self.trade_id)
+    }
+}
@@ -0,0 +1,146 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::channels::{DeribitBook, DeribitTicker, DeribitTrades};
+use crate::{
+    clients::ws::{CriticalWsMessage, SequenceKind, WsSequence},
+    deribit::DeribitTradingPair,
+    exchanges::normalized::ws::NormalizedWsDataTypes,
+    CexExchange
+};
+
+#[allow(clippy::large_enum_variant)]
+#[serde_with::serde_as]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case", tag = "data")]
+pub enum DeribitWsMessage {
+    Trades(DeribitTrades),
+    Ticker(DeribitTicker),
+    Book(DeribitBook),
+    Heartbeat(Value),
+    Subscribe(Value),
+    Error { error: String, raw_msg: String, bad_pair: Option<DeribitTradingPair> }
+}
+
+impl DeribitWsMessage {
+    fn try_deserialize(value: Value) -> eyre::Result<Self> {
+        if let Some(method) = value.get("method") {
+            let method = method
+                .as_str()
+                .ok_or(eyre::ErrReport::msg("Could not convert 'method' field in Deribit ws message to &str".to_string()))?;
+            let params = value
+                .get("params")
+                .cloned()
+                .ok_or(eyre::ErrReport::msg("Could not find 'params' field in Deribit ws message".to_string()))?;
+
+            return match method {
+                "subscription" => {
+                    let channel = params
+                        .get("channel")
+                        .and_then(|c| c.as_str())
+                        .ok_or(eyre::ErrReport::msg("Could not find 'channel' field in Deribit ws message".to_string()))?;
+
+                    match channel.split('.').next() {
+                        Some("trades") => Ok(Self::Trades(serde_json::from_value(params)?)),
+                        Some("ticker") => Ok(Self::Ticker(serde_json::from_value(params)?)),
+                        Some("book") => Ok(Self::Book(serde_json::from_value(params)?)),
+                        _ => Err(eyre::ErrReport::msg(format!("Channel type '{channel}' cannot be deserialized")))
+                    }
+                }
+                "heartbeat" => Ok(Self::Heartbeat(params)),
+                _ => Err(eyre::ErrReport::msg(format!("Method '{method}' cannot be deserialized")))
+            }
+        }
+
+        if let Some(error) = value.get("error") {
+            let message = error
+                .get("message")
+                .and_then(|m| m.as_str())
+                .unwrap_or("unknown error");
+            let bad_pair = DeribitTradingPair::parse_for_bad_pair(&error.to_string());
+
+            return Ok(Self::Error { error: message.to_string(), raw_msg: String::new(), bad_pair })
+        }
+
+        if value.get("result").is_some() {
+            return Ok(Self::Subscribe(value))
+        }
+
+        Err(eyre::ErrReport::msg("Could not find 'method', 'result' or 'error' field in Deribit ws message".to_string()))
+    }
+}
+
+impl<'de> Deserialize<'de> for DeribitWsMessage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        let map = Value::deserialize(deserializer)?;
+
+        Self::try_deserialize(map).map_err(serde::de::Error::custom)
+    }
+}
+
+impl DeribitWsMessage {
+    pub fn normalize(self) -> NormalizedWsDataTypes {
+        match self {
+            DeribitWsMessage::Trades(v) => NormalizedWsDataTypes::Trades(v.normalize()),
+            DeribitWsMessage::Ticker(v) => NormalizedWsDataTypes::Quote(v.normalize()),
+            DeribitWsMessage::Book(v) => NormalizedWsDataTypes::OrderBook(v.normalize()),
+            DeribitWsMessage::Heartbeat(v) => {
+                NormalizedWsDataTypes::Other { exchange: CexExchange::Deribit, kind: "Heartbeat".to_string(), value: format!("{:?}", v) }
+            }
+            DeribitWsMessage::Subscribe(v) => {
+                NormalizedWsDataTypes::Other { exchange: CexExchange::Deribit, kind: "Subscribe".to_string(), value: format!("{:?}", v) }
+            }
+            DeribitWsMessage::Error { error, raw_msg, bad_pair } => {
+                if let Some(bp) = bad_pair {
+                    NormalizedWsDataTypes::RemovedPair {
+                        exchange:    CexExchange::Deribit,
+                        bad_pair:    bp.normalize(),
+                        raw_message: format!("{error} - {raw_msg}")
+                    }
+                } else {
+                    NormalizedWsDataTypes::Other { exchange: CexExchange::Deribit, kind: error, value: raw_msg }
+                }
+            }
+        }
+    }
+}
+
+impl PartialEq<NormalizedWsDataTypes> for DeribitWsMessage {
+    fn eq(&self, other: &NormalizedWsDataTypes) -> bool {
+        match (self, other) {
+            (DeribitWsMessage::Trades(this), NormalizedWsDataTypes::Trades(that)) => this == that,
+            (DeribitWsMessage::Ticker(this), NormalizedWsDataTypes::Quote(that)) => this == that,
+            (DeribitWsMessage::Book(this), NormalizedWsDataTypes::OrderBook(that)) => this == that,
+            (DeribitWsMessage::Heartbeat(_), NormalizedWsDataTypes::Other { .. }) => true,
+            (DeribitWsMessage::Subscribe(_), NormalizedWsDataTypes::Other { .. }) => true,
+            (DeribitWsMessage::Error { .. }, NormalizedWsDataTypes::Other { .. }) => true,
+            (DeribitWsMessage::Error { .. }, NormalizedWsDataTypes::RemovedPair { .. }) => true,
+            _ => false
+        }
+    }
+}
+
+impl CriticalWsMessage for DeribitWsMessage {
+    fn make_critical(&mut self, msg: String) {
+        if let DeribitWsMessage::Error { raw_msg, .. } = self {
+            *raw_msg = msg;
+        }
+    }
+
+    fn sequence(&self) -> Option<WsSequence> {
+        if let DeribitWsMessage::Book(book) = self {
+            let kind = if book.data.is_snapshot() {
+                SequenceKind::Snapshot(Some(book.data.change_id))
+            } else {
+                SequenceKind::Linked { prev: book.data.prev_change_id?, id: book.data.change_id }
+            };
+
+            return Some(WsSequence { channel: "book", pair: book.data.instrument_name.normalize(), kind, resync: true })
+        }
+
+        None
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::HashSet;
+
+use serde::Serialize;
+
+use super::channels::{DeribitWsChannel, DeribitWsChannelKind, DERIBIT_CHANNEL_INTERVAL};
+use crate::deribit::DeribitTradingPair;
+
+/// the json-rpc ws takes a single `public/subscribe` request with the names of
+/// all channels
+#[derive(Debug, Default, Clone)]
+pub struct DeribitSubscription {
+    channels: Vec<(DeribitWsChannelKind, Vec<DeribitTradingPair>)>
+}
+
+impl DeribitSubscription {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_channel(&mut self, channel: DeribitWsChannel) {
+        let kind = DeribitWsChannelKind::from(&channel);
+        let pairs = channel
+            .pairs()
+            .iter()
+            .cloned()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+
+        self.channels.push((kind, pairs));
+    }
+
+    pub fn remove_pair(&mut self, pair: &DeribitTradingPair) -> bool {
+        self.channels
+            .iter_mut()
+            .for_each(|(_, pairs)| pairs.retain(|p| p != pair));
+        self.channels.retain(|(_, pairs)| !pairs.is_empty());
+
+        self.channels.is_empty()
+    }
+
+    /// the `public/subscribe` request to send, ex: trades.BTC-PERPETUAL.100ms
+    pub(crate) fn request(&self) -> DeribitSubscriptionRequest {
+        let channels = self
+            .channels
+            .iter()
+            .flat_map(|(kind, pairs)| pairs.iter().map(move |pair| (*kind, pair)));
+
+        DeribitSubscriptionRequest::new("public/subscribe", channels)
+    }
+
+    /// adds the pairs of the channel, returning the request to subscribe to
+    /// the new ones
+    pub(crate) fn subscribe(&mut self, channel: DeribitWsChannel) -> Option<DeribitSubscriptionRequest> {
+        let kind = DeribitWsChannelKind::from(&channel);
+        let new = self.add_pairs(kind, channel.pairs());
+
+        (!new.is_empty()).then(|| DeribitSubscriptionRequest::new("public/subscribe", new.iter().map(|pair| (kind, pair))))
+    }
+
+    /// removes the pairs of the channel, returning the request to unsubscribe
+    /// from the ones that were subscribed to
+    pub(crate) fn unsubscribe(&mut self, channel: DeribitWsChannel) -> Option<DeribitSubscriptionRequest> {
+        let kind = DeribitWsChannelKind::from(&channel);
+        let removed = self.remove_pairs(kind, channel.pairs());
+
+        (!removed.is_empty()).then(|| DeribitSubscriptionRequest::new("public/unsubscribe", removed.iter().map(|pair| (kind, pair))))
+    }
+
+    /// adds the pairs that aren't subscribed to yet, returning them
+    fn add_pairs(&mut self, kind: DeribitWsChannelKind, pairs: &[DeribitTradingPair]) -> Vec<DeribitTradingPair> {
+        let new = pairs
+            .iter()
+            .filter(|p| {
+                !self
+                    .channels
+                    .iter()
+                    .any(|(k, ps)| *k == kind && ps.contains(p))
+            })
+            .cloned()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        if !new.is_empty() {
+            self.channels.push((kind, new.clone()));
+        }
+
+        new
+    }
+
+    /// removes the pairs that are subscribed to, returning them
+    fn remove_pairs(&mut self, kind: DeribitWsChannelKind, pairs: &[DeribitTradingPair]) -> Vec<DeribitTradingPair> {
+        let removed = pairs
+            .iter()
+            .filter(|p| {
+                self.channels
+                    .iter()
+                    .any(|(k, ps)| *k == kind && ps.contains(p))
+            })
+            .cloned()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        self.channels
+            .iter_mut()
+            .filter(|(k, _)| *k == kind)
+            .for_each(|(_, ps)| ps.retain(|p| !removed.contains(p)));
+        self.channels.retain(|(_, ps)| !ps.is_empty());
+
+        removed
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct DeribitSubscriptionRequest {
+    jsonrpc: &'static str,
+    id:      u64,
+    method:  &'static str,
+    params:  DeribitSubscriptionParams
+}
+
+impl DeribitSubscriptionRequest {
+    fn new<'a>(method: &'static str, channels: impl Iterator<Item = (DeribitWsChannelKind, &'a DeribitTradingPair)>) -> Self {
+        let channels = channels
+            .map(|(kind, pair)| format!("{kind}.{pair}.{DERIBIT_CHANNEL_INTERVAL}"))
+            .collect();
+
+        Self { jsonrpc: "2.0", id: 1, method, params: DeribitSubscriptionParams { channels } }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct DeribitSubscriptionParams {
+    channels: Vec<String>
+}
@@ -0,0 +1,142 @@
+mod pairs;
+pub use pairs::*;
+
+pub mod rest_api;
+pub mod ws;
+
+use futures::SinkExt;
+use rest_api::{GateioAllCurrencies, GateioAllInstruments};
+use serde::Deserialize;
+use tokio::net::TcpStream;
+use tokio_tungstenite::{tungstenite::Message, MaybeTlsStream, WebSocketStream};
+use tracing::{error, info};
+
+use self::{
+    rest_api::GateioRestApiResponse,
+    ws::{channels::GateioWsChannel, GateioSubscription, GateioWsMessage}
+};
+use crate::{
+    clients::{
+        rate_limiter,
+        rest_api::RestApiError,
+        ws::{WsError, WsSubscriptionUpdate},
+        EndpointKind, ExchangeEndpoints, RateLimitKind, TokenBucket
+    },
+    normalized::{rest_api::NormalizedRestApiRequest, types::NormalizedTradingPair},
+    CexExchange, Exchange
+};
+
+const WSS_URL: &str = "wss://api.gateio.ws/ws/v4/";
+const BASE_REST_API_URL: &str = "https://api.gateio.ws/api/v4";
+
+#[derive(Debug, Default, Clone)]
+pub struct Gateio {
+    subscription: GateioSubscription,
+    endpoints:    ExchangeEndpoints
+}
+
+impl Gateio {
+    fn rest_url(&self) -> String {
+        self.endpoints.url(EndpointKind::Rest, BASE_REST_API_URL)
+    }
+
+    fn ws_url(&self) -> String {
+        self.endpoints.url(EndpointKind::Ws, WSS_URL)
+    }
+
+    pub fn new_ws_subscription(subscription: GateioSubscription) -> Self {
+        Self { subscription, endpoints: ExchangeEndpoints::default() }
+    }
+
+    pub async fn get_all_currencies(&self, web_client: &reqwest::Client) -> Result<GateioAllCurrencies, RestApiError> {
+        let currencies: GateioAllCurrencies = Self::simple_rest_api_request(web_client, format!("{}/spot/currencies", self.rest_url())).await?;
+        info!(target: "cex-exchanges::gateio", "found {} currencies", currencies.currencies.len());
+        Ok(currencies)
+    }
+
+    pub async fn get_all_instruments(&self, web_client: &reqwest::Client) -> Result<GateioAllInstruments, RestApiError> {
+        let instruments: GateioAllInstruments = Self::simple_rest_api_request(web_client, format!("{}/spot/currency_pairs", self.rest_url())).await?;
+        info!(target: "cex-exchanges::gateio", "found {} instruments", instruments.instruments.len());
+        Ok(instruments)
+    }
+
+    pub async fn simple_rest_api_request<T>(web_client: &reqwest::Client, url: String) -> Result<T, RestApiError>
+    where
+        T: for<'de> Deserialize<'de>
+    {
+        rate_limiter(CexExchange::Gateio, RateLimitKind::Rest)
+            .acquire()
+            .await;
+        let data = web_client.get(&url).send().await?.json().await?;
+        Ok(data)
+    }
+}
+
+impl Exchange for Gateio {
+    type RestApiResult = GateioRestApiResponse;
+    type WsMessage = GateioWsMessage;
+
+    const EXCHANGE: CexExchange = CexExchange::Gateio;
+
+    fn with_endpoints(mut self, endpoints: ExchangeEndpoints) -> Self {
+        self.endpoints = endpoints;
+        self
+    }
+
+    fn remove_bad_pair(&mut self, bad_pair: NormalizedTradingPair) -> bool {
+        let pair: GateioTradingPair = bad_pair.try_into().unwrap();
+        self.subscription.remove_pair(&pair)
+    }
+
+    async fn make_ws_connection(&self, subscribe_limiter: &TokenBucket) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, WsError> {
+        let (mut ws, _) = tokio_tungstenite::connect_async(self.ws_url()).await?;
+
+        for request in self.subscription.requests() {
+            subscribe_limiter.acquire().await;
+            ws.send(Message::Text(serde_json::to_string(&request)?))
+                .await?;
+        }
+
+        Ok(ws)
+    }
+
+    async fn rest_api_call(
+        &self,
+        web_client: &reqwest::Client,
+        api_channel: NormalizedRestApiRequest
+    ) -> Result<GateioRestApiResponse, RestApiError> {
+        let api_response = match api_channel.clone() {
+            NormalizedRestApiRequest::AllCurrencies => self
+                .get_all_currencies(web_client)
+                .await
+                .map(GateioRestApiResponse::Currencies),
+            NormalizedRestApiRequest::AllInstruments => self
+                .get_all_instruments(web_client)
+                .await
+                .map(GateioRestApiResponse::Instruments),
+            NormalizedRestApiRequest::Candles { .. }
+            | NormalizedRestApiRequest::RecentTrades { .. }
+            | NormalizedRestApiRequest::HistoricalTrades { .. } => {
+                Err(RestApiError::UnsupportedRequest(format!("gateio does not support {:?}", api_channel)))
+            }
+        };
+
+        if let Err(e) = api_response.as_ref() {
+            error!(target: "cex-exchanges::gateio", "error calling rest-api endpoint {:?} -- {:?}", api_channel, e);
+        }
+
+        api_response
+    }
+
+    fn update_subscription(&mut self, update: WsSubscriptionUpdate) -> Result<Vec<Message>, WsError> {
+        let channel: GateioWsChannel = update.exchange_channel()?;
+        let requests = if update.is_subscribe() { self.subscription.subscribe(channel) } else { self.subscription.unsubscribe(channel) };
+
+        let messages = requests
+            .into_iter()
+            .map(|request| serde_json::to_string(&request).map(Message::Text))
+            .collect::<Result<_, _>>()?;
+
+        Ok(messages)
+    }
+}
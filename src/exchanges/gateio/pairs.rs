@@ -0,0 +1,137 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{exchanges::normalized::types::NormalizedTradingPair, CexExchange};
+
+/// ex: BTC_USDT
+#[derive(Debug, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct GateioTradingPair(pub(crate) String);
+
+impl GateioTradingPair {
+    pub fn new_checked(s: &str) -> eyre::Result<Self> {
+        s.to_string().try_into()
+    }
+
+    pub fn is_valid(s: &str) -> bool {
+        let is_asset = |a: &str| !a.is_empty() && a.chars().all(|c| c.is_ascii_alphanumeric());
+
+        matches!(s.split_once('_'), Some((base, quote)) if is_asset(base) && is_asset(quote))
+    }
+
+    pub fn normalize(&self) -> NormalizedTradingPair {
+        match self.0.split_once('_') {
+            Some((base, quote)) => NormalizedTradingPair::new_base_quote(CexExchange::Gateio, base, quote, Some('_'), None),
+            None => NormalizedTradingPair::new_no_base_quote(CexExchange::Gateio, &self.0)
+        }
+    }
+
+    pub fn parse_for_bad_pair(value: &str) -> Option<Self> {
+        let value = value.replace(['\'', '"', ',', ':'], " ");
+        value.split(' ').find_map(|v| Self::try_from(v).ok())
+    }
+}
+
+impl Display for GateioTradingPair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Serialize for GateioTradingPair {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for GateioTradingPair {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        let s = String::deserialize(deserializer)?;
+
+        Ok(GateioTradingPair(s))
+    }
+}
+
+impl TryFrom<NormalizedTradingPair> for GateioTradingPair {
+    type Error = eyre::Report;
+
+    fn try_from(value: NormalizedTradingPair) -> Result<Self, Self::Error> {
+        if let Some((base, quote)) = value.base_quote() {
+            return Ok(GateioTradingPair(format!("{}_{}", base.to_uppercase(), quote.to_uppercase())))
+        }
+
+        if let (Some(raw_pair), delim) = (value.pair(), value.delimiter()) {
+            if let Ok(v) = Self::new_checked(raw_pair) {
+                return Ok(v)
+            }
+
+            if let Some(d) = delim {
+                let mut split = raw_pair.split(d);
+                return Ok(GateioTradingPair(format!("{}_{}", split.next().unwrap().to_uppercase(), split.next().unwrap().to_uppercase())));
+            }
+
+            let new_str = raw_pair.replace(['-', '/'], "_");
+            if let Ok(this) = Self::new_checked(&new_str) {
+                return Ok(this)
+            }
+
+            return Err(eyre::ErrReport::msg(format!("INVALID Gateio trading pair '{raw_pair}'")))
+        }
+
+        Err(eyre::ErrReport::msg(format!("INVALID Gateio trading pair '{:?}'", value)))
+    }
+}
+
+impl TryFrom<&str> for GateioTradingPair {
+    type Error = eyre::Report;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if Self::is_valid(value) {
+            Ok(GateioTradingPair(value.to_uppercase()))
+        } else {
+            Err(eyre::ErrReport::msg(format!("INVALID Gateio trading pair '{value}' is not 2 alphanumeric assets split by a '_'")))
+        }
+    }
+}
+
+impl TryFrom<String> for GateioTradingPair {
+    type Error = eyre::Report;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.as_str().try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::normalized::types::RawTradingPair;
+
+    #[test]
+    fn test_denormalize() {
+        let expected = GateioTradingPair("ETH_USDT".to_string());
+
+        let pair = RawTradingPair::new_raw("eth-usdt", '-').get_normalized_pair(CexExchange::Gateio);
+        assert_eq!(GateioTradingPair::try_from(pair).unwrap(), expected);
+
+        let pair = RawTradingPair::new_no_delim("ETH/USDT").get_normalized_pair(CexExchange::Gateio);
+        assert_eq!(GateioTradingPair::try_from(pair).unwrap(), expected);
+
+        assert_eq!(expected.normalize(), NormalizedTradingPair::new_base_quote(CexExchange::Gateio, "ETH", "USDT", Some('_'), None));
+    }
+
+    #[test]
+    fn test_parse_for_bad_pair() {
+        let test_str = r#"{"code":2,"message":"unknown currency pair: GT_XYZ"}"#;
+
+        let calculated = GateioTradingPair::parse_for_bad_pair(test_str);
+
+        assert_eq!(calculated, Some(GateioTradingPair("GT_XYZ".to_string())))
+    }
+}
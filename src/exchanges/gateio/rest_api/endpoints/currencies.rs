@@ -0,0 +1,145 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, NoneAsEmptyString};
+use tracing::warn;
+
+use crate::{
+    normalized::{
+        rest_api::NormalizedRestApiDataTypes,
+        types::{BlockchainCurrency, NormalizedCurrency}
+    },
+    CexExchange
+};
+
+#[derive(Debug, Clone, Serialize, PartialEq, PartialOrd)]
+pub struct GateioAllCurrencies {
+    pub currencies: Vec<GateioCurrency>
+}
+
+impl GateioAllCurrencies {
+    pub fn normalize(self) -> Vec<NormalizedCurrency> {
+        self.currencies
+            .into_iter()
+            .map(GateioCurrency::normalize)
+            .collect()
+    }
+}
+
+impl<'de> Deserialize<'de> for GateioAllCurrencies {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        let currencies = Vec::<GateioCurrency>::deserialize(deserializer)?;
+
+        Ok(GateioAllCurrencies { currencies })
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for GateioAllCurrencies {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match other {
+            NormalizedRestApiDataTypes::AllCurrencies(other_currs) => {
+                let this_currencies = self
+                    .currencies
+                    .iter()
+                    .map(|curr| curr.currency.clone())
+                    .collect::<HashSet<_>>();
+
+                self.currencies.len() == other_currs.len()
+                    && other_currs
+                        .iter()
+                        .all(|curr| this_currencies.contains(&curr.symbol))
+            }
+            _ => false
+        }
+    }
+}
+
+/// a currency of `/spot/currencies`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct GateioCurrency {
+    /// ex: GT
+    pub currency:          String,
+    /// ex: GateToken
+    #[serde(default)]
+    pub name:              Option<String>,
+    pub delisted:          bool,
+    pub withdraw_disabled: bool,
+    pub withdraw_delayed:  bool,
+    pub deposit_disabled:  bool,
+    pub trade_disabled:    bool,
+    #[serde(default)]
+    pub chains:            Vec<GateioCurrencyChain>
+}
+
+impl GateioCurrency {
+    /// 'delisted', 'trade_disabled' or 'tradable'
+    pub fn status(&self) -> String {
+        if self.delisted {
+            "delisted".to_string()
+        } else if self.trade_disabled {
+            "trade_disabled".to_string()
+        } else {
+            "tradable".to_string()
+        }
+    }
+
+    pub fn normalize(self) -> NormalizedCurrency {
+        NormalizedCurrency {
+            exchange:     CexExchange::Gateio,
+            status:       self.status(),
+            name:         self.name.unwrap_or_else(|| self.currency.clone()),
+            symbol:       self.currency,
+            display_name: None,
+            blockchains:  self.chains.into_iter().map(Into::into).collect()
+        }
+    }
+}
+
+impl PartialEq<NormalizedCurrency> for GateioCurrency {
+    fn eq(&self, other: &NormalizedCurrency) -> bool {
+        let equals = other.exchange == CexExchange::Gateio
+            && other.symbol == self.currency
+            && other.name == *self.name.as_ref().unwrap_or(&self.currency)
+            && other.display_name.is_none()
+            && other.status == self.status()
+            && self
+                .chains
+                .iter()
+                .all(|c| other.blockchains.contains(&c.clone().into()));
+
+        if !equals {
+            warn!(target: "cex-exchanges::gateio", "gateio currency: {:?}", self);
+            warn!(target: "cex-exchanges::gateio", "normalized currency: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct GateioCurrencyChain {
+    /// ex: ETH
+    pub name:              String,
+    /// the token contract address, empty for a native asset
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(default)]
+    pub addr:              Option<String>,
+    pub withdraw_disabled: bool,
+    pub withdraw_delayed:  bool,
+    pub deposit_disabled:  bool
+}
+
+impl From<GateioCurrencyChain> for BlockchainCurrency {
+    fn from(val: GateioCurrencyChain) -> Self {
+        BlockchainCurrency {
+            blockchain:       val.name.parse().unwrap(),
+            address:          val.addr,
+            is_wrapped:       false,
+            wrapped_currency: None
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use tracing::warn;
+
+use crate::{
+    gateio::GateioTradingPair,
+    normalized::{
+        rest_api::NormalizedRestApiDataTypes,
+        types::{NormalizedInstrument, NormalizedTradingType}
+    },
+    CexExchange
+};
+
+#[derive(Debug, Clone, Serialize, PartialEq, PartialOrd)]
+pub struct GateioAllInstruments {
+    pub instruments: Vec<GateioInstrument>
+}
+
+impl GateioAllInstruments {
+    pub fn normalize(self) -> Vec<NormalizedInstrument> {
+        self.instruments
+            .into_iter()
+            .map(GateioInstrument::normalize)
+            .collect()
+    }
+}
+
+impl<'de> Deserialize<'de> for GateioAllInstruments {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        let instruments = Vec::<GateioInstrument>::deserialize(deserializer)?;
+
+        Ok(GateioAllInstruments { instruments })
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for GateioAllInstruments {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match other {
+            NormalizedRestApiDataTypes::AllInstruments(other_instrs) => {
+                let this_instruments = self
+                    .instruments
+                    .iter()
+                    .map(|instr| (instr.base.clone(), instr.quote.clone(), instr.id.normalize()))
+                    .collect::<HashSet<_>>();
+
+                self.instruments.len() == other_instrs.len()
+                    && other_instrs.iter().all(|instr| {
+                        this_instruments.contains(&(instr.base_asset_symbol.clone(), instr.quote_asset_symbol.clone(), instr.trading_pair.clone()))
+                    })
+            }
+            _ => false
+        }
+    }
+}
+
+/// a spot pair of `/spot/currency_pairs`
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct GateioInstrument {
+    /// ex: ETH_USDT
+    pub id:               GateioTradingPair,
+    pub base:             String,
+    pub quote:            String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub fee:              f64,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub min_base_amount:  Option<f64>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub min_quote_amount: Option<f64>,
+    pub amount_precision: u32,
+    pub precision:        u32,
+    /// 'untradable', 'buyable', 'sellable' or 'tradable'
+    pub trade_status:     String,
+    pub sell_start:       u64,
+    pub buy_start:        u64
+}
+
+impl GateioInstrument {
+    pub fn is_active(&self) -> bool {
+        &self.trade_status == "tradable"
+    }
+
+    pub fn normalize(self) -> NormalizedInstrument {
+        NormalizedInstrument {
+            exchange:           CexExchange::Gateio,
+            trading_pair:       self.id.normalize(),
+            trading_type:       NormalizedTradingType::Spot,
+            active:             self.is_active(),
+            base_asset_symbol:  self.base,
+            quote_asset_symbol: self.quote,
+            futures_expiry:     None,
+            option_strike:      None,
+            option_type:        None
+        }
+    }
+}
+
+impl PartialEq<NormalizedInstrument> for GateioInstrument {
+    fn eq(&self, other: &NormalizedInstrument) -> bool {
+        let equals = other.exchange == CexExchange::Gateio
+            && other.trading_pair == self.id.normalize()
+            && other.trading_type == NormalizedTradingType::Spot
+            && other.base_asset_symbol == self.base
+            && other.quote_asset_symbol == self.quote
+            && other.active == self.is_active()
+            && other.futures_expiry.is_none()
+            && other.option_strike.is_none()
+            && other.option_type.is_none();
+
+        if !equals {
+            warn!(target: "cex-exchanges::gateio", "gateio instrument: {:?}", self);
+            warn!(target: "cex-exchanges::gateio", "normalized instrument: {:?}", other);
+        }
+
+        equals
+    }
+}
@@ -0,0 +1,5 @@
+mod endpoints;
+pub use endpoints::*;
+
+mod response;
+pub use response::*;
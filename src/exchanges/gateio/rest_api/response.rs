@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use super::{GateioAllCurrencies, GateioAllInstruments, GateioCurrency, GateioInstrument};
+use crate::normalized::rest_api::NormalizedRestApiDataTypes;
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum GateioRestApiResponse {
+    Currencies(GateioAllCurrencies),
+    Instruments(GateioAllInstruments)
+}
+
+impl GateioRestApiResponse {
+    pub fn normalize(self) -> NormalizedRestApiDataTypes {
+        match self {
+            GateioRestApiResponse::Currencies(v) => NormalizedRestApiDataTypes::AllCurrencies(v.normalize()),
+            GateioRestApiResponse::Instruments(v) => NormalizedRestApiDataTypes::AllInstruments(v.normalize())
+        }
+    }
+
+    pub fn take_currencies(self) -> Option<Vec<GateioCurrency>> {
+        match self {
+            GateioRestApiResponse::Currencies(val) => Some(val.currencies),
+            _ => None
+        }
+    }
+
+    pub fn take_instruments(self, active_only: bool) -> Option<Vec<GateioInstrument>> {
+        let instruments = match self {
+            GateioRestApiResponse::Instruments(val) => val.instruments,
+            _ => return None
+        };
+
+        if active_only {
+            Some(
+                instruments
+                    .into_iter()
+                    .filter(|instr| instr.is_active())
+                    .collect::<Vec<_>>()
+            )
+        } else {
+            Some(instruments)
+        }
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for GateioRestApiResponse {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match self {
+            GateioRestApiResponse::Currencies(vals) => vals == other,
+            GateioRestApiResponse::Instruments(vals) => vals == other
+        }
+    }
+}
@@ -0,0 +1,75 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use tracing::warn;
+
+use crate::{
+    clients::ws::received_at,
+    exchanges::{gateio::pairs::GateioTradingPair, normalized::types::NormalizedQuote},
+    normalized::types::NormalizedNumber,
+    CexExchange
+};
+
+/// the `result` of a `spot.book_ticker` update, sent on each change of the
+/// best bid & offer
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct GateioBookTicker {
+    #[serde(rename = "t")]
+    pub timestamp:   u64,
+    #[serde(rename = "u")]
+    pub update_id:   u64,
+    #[serde(rename = "s")]
+    pub pair:        GateioTradingPair,
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "b")]
+    pub bid_price:   NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "B")]
+    pub bid_amount:  NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "a")]
+    pub ask_price:   NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "A")]
+    pub ask_amount:  NormalizedNumber,
+    #[serde(default = "received_at")]
+    pub received_at: DateTime<Utc>
+}
+
+impl GateioBookTicker {
+    pub fn normalize(self) -> NormalizedQuote {
+        NormalizedQuote {
+            exchange:      CexExchange::Gateio,
+            pair:          self.pair.normalize(),
+            exchange_time: DateTime::<Utc>::from_timestamp_millis(self.timestamp as i64),
+            received_at:   self.received_at,
+            ask_amount:    self.ask_amount,
+            ask_price:     self.ask_price,
+            bid_amount:    self.bid_amount,
+            bid_price:     self.bid_price,
+            quote_id:      Some(self.update_id.to_string())
+        }
+    }
+}
+
+impl PartialEq<NormalizedQuote> for GateioBookTicker {
+    fn eq(&self, other: &NormalizedQuote) -> bool {
+        let equals = other.exchange == CexExchange::Gateio
+            && other.pair == self.pair.normalize()
+            && other.exchange_time == DateTime::<Utc>::from_timestamp_millis(self.timestamp as i64)
+            && other.received_at == self.received_at
+            && other.ask_amount == self.ask_amount
+            && other.ask_price == self.ask_price
+            && other.bid_amount == self.bid_amount
+            && other.bid_price == self.bid_price
+            && other.quote_id == Some(self.update_id.to_string());
+
+        if !equals {
+            warn!(target: "cex-exchanges::gateio", "gateio book ticker: {:?}", self);
+            warn!(target: "cex-exchanges::gateio", "normalized quote: {:?}", other);
+        }
+
+        equals
+    }
+}
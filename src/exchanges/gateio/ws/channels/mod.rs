@@ -0,0 +1,142 @@
+use std::fmt::Display;
+
+mod trades;
+pub use trades::*;
+
+mod book_ticker;
+pub use book_ticker::*;
+
+use crate::{
+    exchanges::{
+        gateio::pairs::GateioTradingPair,
+        normalized::{
+            types::{NormalizedTradingPair, RawTradingPair},
+            ws::NormalizedWsChannels
+        }
+    },
+    CexExchange
+};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum GateioWsChannel {
+    Trades(Vec<GateioTradingPair>),
+    /// best bid & offer updates
+    BookTicker(Vec<GateioTradingPair>)
+}
+
+impl GateioWsChannel {
+    /// builds trades channel from a vec of raw trading pairs
+    /// return an error if the symbol is incorrectly formatted
+    pub fn new_trades(pairs: Vec<RawTradingPair>) -> eyre::Result<Self> {
+        let normalized = pairs
+            .into_iter()
+            .map(|pair| pair.get_normalized_pair(CexExchange::Gateio))
+            .collect();
+
+        Self::new_from_kind(normalized, GateioWsChannel::Trades(Vec::new()))
+    }
+
+    /// builds book ticker channel from a vec of raw trading pairs
+    /// return an error if the symbol is incorrectly formatted
+    pub fn new_book_ticker(pairs: Vec<RawTradingPair>) -> eyre::Result<Self> {
+        let normalized = pairs
+            .into_iter()
+            .map(|pair| pair.get_normalized_pair(CexExchange::Gateio))
+            .collect();
+
+        Self::new_from_kind(normalized, GateioWsChannel::BookTicker(Vec::new()))
+    }
+
+    fn new_from_kind(pairs: Vec<NormalizedTradingPair>, kind: GateioWsChannel) -> eyre::Result<Self> {
+        let pairs = pairs
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+
+        match kind {
+            GateioWsChannel::Trades(_) => Ok(GateioWsChannel::Trades(pairs)),
+            GateioWsChannel::BookTicker(_) => Ok(GateioWsChannel::BookTicker(pairs))
+        }
+    }
+
+    pub(crate) fn pairs(&self) -> &[GateioTradingPair] {
+        match self {
+            GateioWsChannel::Trades(pairs) => pairs,
+            GateioWsChannel::BookTicker(pairs) => pairs
+        }
+    }
+}
+
+impl Display for GateioWsChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        GateioWsChannelKind::from(self).fmt(f)
+    }
+}
+
+impl TryFrom<String> for GateioWsChannel {
+    type Error = eyre::Report;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.to_lowercase().as_str() {
+            "spot.trades" => Ok(Self::Trades(Vec::new())),
+            "spot.book_ticker" => Ok(Self::BookTicker(Vec::new())),
+            _ => Err(eyre::ErrReport::msg(format!("channel is not valid: {value}")))
+        }
+    }
+}
+
+impl TryFrom<NormalizedWsChannels> for GateioWsChannel {
+    type Error = eyre::ErrReport;
+
+    fn try_from(value: NormalizedWsChannels) -> Result<Self, Self::Error> {
+        match value {
+            NormalizedWsChannels::Trades(pairs) => {
+                let norm_pairs = pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, Self::Error>>()?;
+
+                Ok(GateioWsChannel::Trades(norm_pairs))
+            }
+
+            NormalizedWsChannels::Quotes(pairs) => {
+                let norm_pairs = pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, Self::Error>>()?;
+
+                Ok(GateioWsChannel::BookTicker(norm_pairs))
+            }
+
+            NormalizedWsChannels::OrderBook { .. } => Err(eyre::ErrReport::msg("gateio does not support the order book ws channel")),
+
+            NormalizedWsChannels::Candles { .. } => Err(eyre::ErrReport::msg("gateio does not support the candles ws channel")),
+
+            NormalizedWsChannels::Account(_) => Err(eyre::ErrReport::msg("gateio does not support the account ws channel"))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum GateioWsChannelKind {
+    Trades,
+    BookTicker
+}
+
+impl Display for GateioWsChannelKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GateioWsChannelKind::Trades => write!(f, "spot.trades"),
+            GateioWsChannelKind::BookTicker => write!(f, "spot.book_ticker")
+        }
+    }
+}
+
+impl From<&GateioWsChannel> for GateioWsChannelKind {
+    fn from(value: &GateioWsChannel) -> Self {
+        match value {
+            GateioWsChannel::Trades(_) => GateioWsChannelKind::Trades,
+            GateioWsChannel::BookTicker(_) => GateioWsChannelKind::BookTicker
+        }
+    }
+}
@@ -0,0 +1,71 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use tracing::warn;
+
+use crate::{
+    clients::ws::received_at,
+    exchanges::{
+        gateio::pairs::GateioTradingPair,
+        normalized::types::{NormalizedTrade, TradeSide}
+    },
+    normalized::types::NormalizedNumber,
+    CexExchange
+};
+
+/// the `result` of a `spot.trades` update
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct GateioTrade {
+    pub id:             u64,
+    /// unix ms with a fractional part, ex: 1606292218213.4578
+    #[serde_as(as = "DisplayFromStr")]
+    pub create_time_ms: f64,
+    pub side:           String,
+    pub currency_pair:  GateioTradingPair,
+    #[serde_as(as = "DisplayFromStr")]
+    pub amount:         NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub price:          NormalizedNumber,
+    #[serde(default = "received_at")]
+    pub received_at:    DateTime<Utc>
+}
+
+impl GateioTrade {
+    fn time(&self) -> DateTime<Utc> {
+        DateTime::<Utc>::from_timestamp_micros((self.create_time_ms * 1000.0) as i64).unwrap()
+    }
+
+    pub fn normalize(self) -> NormalizedTrade {
+        NormalizedTrade {
+            exchange:      CexExchange::Gateio,
+            pair:          self.currency_pair.normalize(),
+            exchange_time: Some(self.time()),
+            received_at:   self.received_at,
+            side:          TradeSide::from_taker_side(&self.side),
+            price:         self.price,
+            amount:        self.amount,
+            trade_id:      Some(self.id.to_string())
+        }
+    }
+}
+
+impl PartialEq<NormalizedTrade> for GateioTrade {
+    fn eq(&self, other: &NormalizedTrade) -> bool {
+        let equals = other.exchange == CexExchange::Gateio
+            && other.pair == self.currency_pair.normalize()
+            && other.exchange_time == Some(self.time())
+            && other.received_at == self.received_at
+            && other.side == TradeSide::from_taker_side(&self.side)
+            && other.price == self.price
+            && other.amount == self.amount
+            && other.trade_id == Some(self.id.to_string());
+
+        if !equals {
+            warn!(target: "cex-exchanges::gateio", "gateio trade: {:?}", self);
+            warn!(target: "cex-exchanges::gateio", "normalized trade: {:?}", other);
+        }
+
+        equals
+    }
+}
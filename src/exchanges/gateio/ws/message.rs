@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::channels::{GateioBookTicker, GateioTrade};
+use crate::{clients::ws::CriticalWsMessage, exchanges::normalized::ws::NormalizedWsDataTypes, gateio::GateioTradingPair, CexExchange};
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case", tag = "data")]
+pub enum GateioWsMessage {
+    Trades(GateioTrade),
+    BookTicker(GateioBookTicker),
+    Pong,
+    Subscribe(Value),
+    Error { error: String, raw_msg: String, bad_pair: Option<GateioTradingPair> }
+}
+
+impl GateioWsMessage {
+    fn try_deserialize(value: Value) -> eyre::Result<Self> {
+        let channel = value
+            .get("channel")
+            .ok_or(eyre::ErrReport::msg("Could not find 'channel' field in Gateio ws message".to_string()))?
+            .as_str()
+            .ok_or(eyre::ErrReport::msg("Could not convert 'channel' field in Gateio ws message to &str".to_string()))?;
+
+        if channel == "spot.pong" {
+            return Ok(Self::Pong)
+        }
+
+        if let Some(error) = value.get("error").filter(|e| !e.is_null()) {
+            let error = error
+                .get("message")
+                .and_then(|m| m.as_str())
+                .map(ToString::to_string)
+                .unwrap_or_else(|| error.to_string());
+            let bad_pair = GateioTradingPair::parse_for_bad_pair(&error);
+
+            return Ok(Self::Error { error, raw_msg: String::new(), bad_pair })
+        }
+
+        let event = value
+            .get("event")
+            .ok_or(eyre::ErrReport::msg("Could not find 'event' field in Gateio ws message".to_string()))?
+            .as_str()
+            .ok_or(eyre::ErrReport::msg("Could not convert 'event' field in Gateio ws message to &str".to_string()))?;
+
+        if event != "update" {
+            return Ok(Self::Subscribe(value))
+        }
+
+        let result = value
+            .get("result")
+            .cloned()
+            .ok_or(eyre::ErrReport::msg("Could not find 'result' field in Gateio ws message".to_string()))?;
+
+        match channel {
+            "spot.trades" => Ok(Self::Trades(serde_json::from_value(result)?)),
+            "spot.book_ticker" => Ok(Self::BookTicker(serde_json::from_value(result)?)),
+            _ => Err(eyre::ErrReport::msg(format!("Channel type '{channel}' cannot be deserialized")))
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for GateioWsMessage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        let map = Value::deserialize(deserializer)?;
+
+        Self::try_deserialize(map).map_err(serde::de::Error::custom)
+    }
+}
+
+impl GateioWsMessage {
+    pub fn normalize(self) -> NormalizedWsDataTypes {
+        match self {
+            GateioWsMessage::Trades(v) => NormalizedWsDataTypes::Trade(v.normalize()),
+            GateioWsMessage::BookTicker(v) => NormalizedWsDataTypes::Quote(v.normalize()),
+            GateioWsMessage::Pong => {
+                NormalizedWsDataTypes::Other { exchange: CexExchange::Gateio, kind: "Pong".to_string(), value: String::new() }
+            }
+            GateioWsMessage::Subscribe(v) => {
+                NormalizedWsDataTypes::Other { exchange: CexExchange::Gateio, kind: "Subscribe".to_string(), value: format!("{:?}", v) }
+            }
+            GateioWsMessage::Error { error, raw_msg, bad_pair } => {
+                if let Some(bp) = bad_pair {
+                    NormalizedWsDataTypes::RemovedPair {
+                        exchange:    CexExchange::Gateio,
+                        bad_pair:    bp.normalize(),
+                        raw_message: format!("{error} - {raw_msg}")
+                    }
+                } else {
+                    NormalizedWsDataTypes::Other { exchange: CexExchange::Gateio, kind: error, value: raw_msg }
+                }
+            }
+        }
+    }
+}
+
+impl PartialEq<NormalizedWsDataTypes> for GateioWsMessage {
+    fn eq(&self, other: &NormalizedWsDataTypes) -> bool {
+        match (self, other) {
+            (GateioWsMessage::Trades(this), NormalizedWsDataTypes::Trade(that)) => this == that,
+            (GateioWsMessage::BookTicker(this), NormalizedWsDataTypes::Quote(that)) => this == that,
+            (GateioWsMessage::Pong, NormalizedWsDataTypes::Other { .. }) => true,
+            (GateioWsMessage::Subscribe(_), NormalizedWsDataTypes::Other { .. }) => true,
+            (GateioWsMessage::Error { .. }, NormalizedWsDataTypes::Other { .. }) => true,
+            (GateioWsMessage::Error { .. }, NormalizedWsDataTypes::RemovedPair { .. }) => true,
+            _ => false
+        }
+    }
+}
+
+impl CriticalWsMessage for GateioWsMessage {
+    fn make_critical(&mut self, msg: String) {
+        if let GateioWsMessage::Error { raw_msg, .. } = self {
+            *raw_msg = msg;
+        }
+    }
+}
@@ -0,0 +1,10 @@
+mod builder;
+pub use builder::*;
+
+pub mod channels;
+
+mod message;
+pub use message::*;
+
+mod subscription;
+pub use subscription::*;
@@ -0,0 +1,124 @@
+use std::collections::HashSet;
+
+use chrono::Utc;
+use serde::Serialize;
+
+use super::channels::{GateioWsChannel, GateioWsChannelKind};
+use crate::gateio::GateioTradingPair;
+
+/// the v4 ws takes a `subscribe` request per channel
+#[derive(Debug, Default, Clone)]
+pub struct GateioSubscription {
+    channels: Vec<(GateioWsChannelKind, Vec<GateioTradingPair>)>
+}
+
+impl GateioSubscription {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_channel(&mut self, channel: GateioWsChannel) {
+        let kind = GateioWsChannelKind::from(&channel);
+        let pairs = channel
+            .pairs()
+            .iter()
+            .cloned()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+
+        self.channels.push((kind, pairs));
+    }
+
+    pub fn remove_pair(&mut self, pair: &GateioTradingPair) -> bool {
+        self.channels
+            .iter_mut()
+            .for_each(|(_, pairs)| pairs.retain(|p| p != pair));
+        self.channels.retain(|(_, pairs)| !pairs.is_empty());
+
+        self.channels.is_empty()
+    }
+
+    /// the `subscribe` requests to send, 1 for each channel
+    pub(crate) fn requests(&self) -> Vec<GateioSubscriptionRequest> {
+        self.channels
+            .iter()
+            .map(|(kind, pairs)| GateioSubscriptionRequest::new(*kind, "subscribe", pairs.clone()))
+            .collect()
+    }
+
+    /// adds the pairs of the channel, returning the request to subscribe to
+    /// the new ones
+    pub(crate) fn subscribe(&mut self, channel: GateioWsChannel) -> Option<GateioSubscriptionRequest> {
+        let kind = GateioWsChannelKind::from(&channel);
+        let new = self.add_pairs(kind, channel.pairs());
+
+        (!new.is_empty()).then(|| GateioSubscriptionRequest::new(kind, "subscribe", new))
+    }
+
+    /// removes the pairs of the channel, returning the request to unsubscribe
+    /// from the ones that were subscribed to
+    pub(crate) fn unsubscribe(&mut self, channel: GateioWsChannel) -> Option<GateioSubscriptionRequest> {
+        let kind = GateioWsChannelKind::from(&channel);
+        let removed = self.remove_pairs(kind, channel.pairs());
+
+        (!removed.is_empty()).then(|| GateioSubscriptionRequest::new(kind, "unsubscribe", removed))
+    }
+
+    /// adds the pairs that aren't subscribed to yet, returning them
+    fn add_pairs(&mut self, kind: GateioWsChannelKind, pairs: &[GateioTradingPair]) -> Vec<GateioTradingPair> {
+        let new = pairs
+            .iter()
+            .filter(|p| {
+                !self
+                    .channels
+                    .iter()
+                    .any(|(k, ps)| *k == kind && ps.contains(p))
+            })
+            .cloned()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        if !new.is_empty() {
+            self.channels.push((kind, new.clone()));
+        }
+
+        new
+    }
+
+    /// removes the pairs that are subscribed to, returning them
+    fn remove_pairs(&mut self, kind: GateioWsChannelKind, pairs: &[GateioTradingPair]) -> Vec<GateioTradingPair> {
+        let removed = pairs
+            .iter()
+            .filter(|p| {
+                self.channels
+                    .iter()
+                    .any(|(k, ps)| *k == kind && ps.contains(p))
+            })
+            .cloned()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect::<Vec<_>>();
+        self.channels
+            .iter_mut()
+            .filter(|(k, _)| *k == kind)
+            .for_each(|(_, ps)| ps.retain(|p| !removed.contains(p)));
+        self.channels.retain(|(_, ps)| !ps.is_empty());
+
+        removed
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct GateioSubscriptionRequest {
+    time:    u64,
+    channel: String,
+    event:   &'static str,
+    payload: Vec<GateioTradingPair>
+}
+
+impl GateioSubscriptionRequest {
+    fn new(kind: GateioWsChannelKind, event: &'static str, payload: Vec<GateioTradingPair>) -> Self {
+        Self { time: Utc::now().timestamp() as u64, channel: kind.to_string(), event, payload }
+    }
+}
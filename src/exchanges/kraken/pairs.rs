@@ -0,0 +1,167 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{exchanges::normalized::types::NormalizedTradingPair, CexExchange};
+
+/// kraken's legacy asset codes, prefixed with 'X' (crypto) or 'Z' (fiat)
+const LEGACY_ASSETS: [&str; 17] =
+    ["XETC", "XETH", "XLTC", "XMLN", "XREP", "XXBT", "XXDG", "XXLM", "XXMR", "XXRP", "XZEC", "ZAUD", "ZCAD", "ZEUR", "ZGBP", "ZJPY", "ZUSD"];
+
+/// the ws (v2) symbol, ex: BTC/USD
+///
+/// the rest api's legacy names (ex: XBT/USD, XXBTZUSD) are also normalized
+#[derive(Debug, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+pub struct KrakenTradingPair(pub(crate) String);
+
+impl KrakenTradingPair {
+    pub fn new_checked(s: &str) -> eyre::Result<Self> {
+        s.to_string().try_into()
+    }
+
+    pub fn is_valid(s: &str) -> bool {
+        let is_asset = |a: &str| !a.is_empty() && a.chars().all(|c| c.is_ascii_alphanumeric() || c == '.');
+
+        matches!(s.split_once('/'), Some((base, quote)) if is_asset(base) && is_asset(quote))
+    }
+
+    pub fn normalize(&self) -> NormalizedTradingPair {
+        match self.base_quote() {
+            Some((base, quote)) => NormalizedTradingPair::new_base_quote(CexExchange::Kraken, &base, &quote, Some('/'), None),
+            None => NormalizedTradingPair::new_no_base_quote(CexExchange::Kraken, &self.0)
+        }
+    }
+
+    /// the normalized (base, quote) of either the ws symbol or a legacy
+    /// name made of 2 prefixed assets (ex: XXBTZUSD)
+    fn base_quote(&self) -> Option<(String, String)> {
+        if let Some((base, quote)) = self.0.split_once('/') {
+            return Some((Self::normalize_asset(base), Self::normalize_asset(quote)))
+        }
+
+        let (base, quote) = self.0.split_at_checked(4)?;
+        (LEGACY_ASSETS.contains(&base) && LEGACY_ASSETS.contains(&quote)).then(|| (Self::normalize_asset(base), Self::normalize_asset(quote)))
+    }
+
+    /// strips the legacy 'X'/'Z' prefix and renames XBT -> BTC & XDG -> DOGE
+    pub fn normalize_asset(asset: &str) -> String {
+        let asset = asset.to_uppercase();
+        let unprefixed = if LEGACY_ASSETS.contains(&asset.as_str()) { &asset[1..] } else { &asset };
+
+        match unprefixed {
+            "XBT" => "BTC".to_string(),
+            "XDG" => "DOGE".to_string(),
+            _ => unprefixed.to_string()
+        }
+    }
+
+    pub fn parse_for_bad_pair(value: &str) -> Option<Self> {
+        let value = value.replace(['\'', '"', ','], " ");
+        value.split(' ').find_map(|v| Self::try_from(v).ok())
+    }
+}
+
+impl Display for KrakenTradingPair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Serialize for KrakenTradingPair {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for KrakenTradingPair {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        let s = String::deserialize(deserializer)?;
+
+        Ok(KrakenTradingPair(s))
+    }
+}
+
+impl TryFrom<NormalizedTradingPair> for KrakenTradingPair {
+    type Error = eyre::Report;
+
+    fn try_from(value: NormalizedTradingPair) -> Result<Self, Self::Error> {
+        if let Some((base, quote)) = value.base_quote() {
+            return Ok(KrakenTradingPair(format!("{}/{}", Self::normalize_asset(base), Self::normalize_asset(quote))))
+        }
+
+        if let (Some(raw_pair), delim) = (value.pair(), value.delimiter()) {
+            if let Ok(v) = Self::new_checked(raw_pair) {
+                return Ok(v)
+            }
+
+            if let Some(d) = delim {
+                let mut split = raw_pair.split(d);
+                return Ok(KrakenTradingPair(format!("{}/{}", split.next().unwrap().to_uppercase(), split.next().unwrap().to_uppercase())));
+            }
+
+            let new_str = raw_pair.replace(['_', '-'], "/");
+            if let Ok(this) = Self::new_checked(&new_str) {
+                return Ok(this)
+            }
+
+            return Err(eyre::ErrReport::msg(format!("INVALID Kraken trading pair '{raw_pair}'")))
+        }
+
+        Err(eyre::ErrReport::msg(format!("INVALID Kraken trading pair '{:?}'", value)))
+    }
+}
+
+impl TryFrom<&str> for KrakenTradingPair {
+    type Error = eyre::Report;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if Self::is_valid(value) {
+            Ok(KrakenTradingPair(value.to_uppercase()))
+        } else {
+            Err(eyre::ErrReport::msg(format!("INVALID Kraken trading pair '{value}' is not 2 alphanumeric assets split by a '/'")))
+        }
+    }
+}
+
+impl TryFrom<String> for KrakenTradingPair {
+    type Error = eyre::Report;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.as_str().try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::normalized::types::RawTradingPair;
+
+    #[test]
+    fn test_normalize_legacy_names() {
+        let expected = NormalizedTradingPair::new_base_quote(CexExchange::Kraken, "BTC", "USD", Some('/'), None);
+
+        assert_eq!(KrakenTradingPair("BTC/USD".to_string()).normalize(), expected);
+        assert_eq!(KrakenTradingPair("XBT/USD".to_string()).normalize(), expected);
+        assert_eq!(KrakenTradingPair("XXBTZUSD".to_string()).normalize(), expected);
+        assert_eq!(KrakenTradingPair::normalize_asset("XXDG"), "DOGE");
+        assert_eq!(KrakenTradingPair::normalize_asset("XTZ"), "XTZ");
+
+        let pair = RawTradingPair::new_raw("XBT_USD", '_').get_normalized_pair(CexExchange::Kraken);
+        assert_eq!(KrakenTradingPair::try_from(pair).unwrap(), KrakenTradingPair("BTC/USD".to_string()));
+    }
+
+    #[test]
+    fn test_parse_for_bad_pair() {
+        let test_str = r#"{"error":"Currency pair not supported BTC/XYZ","method":"subscribe","success":false,"symbol":"BTC/XYZ"}"#;
+
+        let calculated = KrakenTradingPair::parse_for_bad_pair(test_str);
+
+        assert_eq!(calculated, Some(KrakenTradingPair("BTC/XYZ".to_string())))
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tracing::warn;
+
+use crate::{
+    kraken::{rest_api::kraken_result, KrakenTradingPair},
+    normalized::{rest_api::NormalizedRestApiDataTypes, types::NormalizedCurrency},
+    CexExchange
+};
+
+#[derive(Debug, Clone, Serialize, PartialEq, PartialOrd)]
+pub struct KrakenAllCurrencies {
+    pub currencies: Vec<KrakenCurrency>
+}
+
+impl KrakenAllCurrencies {
+    pub fn normalize(self) -> Vec<NormalizedCurrency> {
+        self.currencies
+            .into_iter()
+            .map(KrakenCurrency::normalize)
+            .collect()
+    }
+}
+
+impl<'de> Deserialize<'de> for KrakenAllCurrencies {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        let val = Value::deserialize(deserializer)?;
+        let result = kraken_result(val).map_err(serde::de::Error::custom)?;
+
+        let currencies = result
+            .into_iter()
+            .map(|(id, mut currency)| {
+                if let Some(obj) = currency.as_object_mut() {
+                    obj.insert("id".to_string(), Value::String(id));
+                }
+                serde_json::from_value(currency)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(serde::de::Error::custom)?;
+
+        Ok(KrakenAllCurrencies { currencies })
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for KrakenAllCurrencies {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match other {
+            NormalizedRestApiDataTypes::AllCurrencies(other_currs) => {
+                let this_currencies = self
+                    .currencies
+                    .iter()
+                    .map(|curr| KrakenTradingPair::normalize_asset(&curr.altname))
+                    .collect::<HashSet<_>>();
+
+                self.currencies.len() == other_currs.len()
+                    && other_currs
+                        .iter()
+                        .all(|curr| this_currencies.contains(&curr.symbol))
+            }
+            _ => false
+        }
+    }
+}
+
+/// an asset of `/0/public/Assets`, the `id` is the key of the asset in the
+/// response (ex: XXBT)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct KrakenCurrency {
+    pub id:               String,
+    /// ex: XBT
+    pub altname:          String,
+    pub aclass:           String,
+    pub decimals:         u32,
+    pub display_decimals: u32,
+    pub collateral_value: Option<f64>,
+    pub margin_rate:      Option<f64>,
+    pub status:           String
+}
+
+impl KrakenCurrency {
+    pub fn normalize(self) -> NormalizedCurrency {
+        NormalizedCurrency {
+            exchange:     CexExchange::Kraken,
+            symbol:       KrakenTradingPair::normalize_asset(&self.altname),
+            name:         self.altname,
+            display_name: None,
+            status:       self.status,
+            blockchains:  Vec::new()
+        }
+    }
+}
+
+impl PartialEq<NormalizedCurrency> for KrakenCurrency {
+    fn eq(&self, other: &NormalizedCurrency) -> bool {
+        let equals = other.exchange == CexExchange::Kraken
+            && other.symbol == KrakenTradingPair::normalize_asset(&self.altname)
+            && other.name == self.altname
+            && other.display_name.is_none()
+            && other.status == self.status
+            && other.blockchains.is_empty();
+
+        if !equals {
+            warn!(target: "cex-exchanges::kraken", "kraken currency: {:?}", self);
+            warn!(target: "cex-exchanges::kraken", "normalized currency: {:?}", other);
+        }
+
+        equals
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use serde_with::{serde_as, DisplayFromStr};
+use tracing::warn;
+
+use crate::{
+    kraken::{rest_api::kraken_result, KrakenTradingPair},
+    normalized::{
+        rest_api::NormalizedRestApiDataTypes,
+        types::{NormalizedInstrument, NormalizedTradingType}
+    },
+    CexExchange
+};
+
+#[derive(Debug, Clone, Serialize, PartialEq, PartialOrd)]
+pub struct KrakenAllInstruments {
+    pub instruments: Vec<KrakenInstrument>
+}
+
+impl KrakenAllInstruments {
+    pub fn normalize(self) -> Vec<NormalizedInstrument> {
+        self.instruments
+            .into_iter()
+            .flat_map(KrakenInstrument::normalize)
+            .collect()
+    }
+}
+
+impl<'de> Deserialize<'de> for KrakenAllInstruments {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        let val = Value::deserialize(deserializer)?;
+        let result = kraken_result(val).map_err(serde::de::Error::custom)?;
+
+        let instruments = result
+            .into_iter()
+            .map(|(id, mut instrument)| {
+                if let Some(obj) = instrument.as_object_mut() {
+                    obj.insert("id".to_string(), Value::String(id));
+                }
+                serde_json::from_value(instrument)
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(serde::de::Error::custom)?;
+
+        Ok(KrakenAllInstruments { instruments })
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for KrakenAllInstruments {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match other {
+            NormalizedRestApiDataTypes::AllInstruments(other_instrs) => {
+                let this_instruments = self
+                    .instruments
+                    .iter()
+                    .map(|instr| {
+                        (KrakenTradingPair::normalize_asset(&instr.base), KrakenTradingPair::normalize_asset(&instr.quote), instr.pair().normalize())
+                    })
+                    .collect::<HashSet<_>>();
+
+                other_instrs.iter().all(|instr| {
+                    this_instruments.contains(&(instr.base_asset_symbol.clone(), instr.quote_asset_symbol.clone(), instr.trading_pair.clone()))
+                })
+            }
+            _ => false
+        }
+    }
+}
+
+/// a pair of `/0/public/AssetPairs`, the `id` is the key of the pair in the
+/// response (ex: XXBTZUSD)
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct KrakenInstrument {
+    pub id:            String,
+    /// ex: XBTUSD
+    pub altname:       String,
+    /// ex: XBT/USD
+    pub wsname:        Option<String>,
+    /// ex: XXBT
+    pub base:          String,
+    /// ex: ZUSD
+    pub quote:         String,
+    pub pair_decimals: u32,
+    pub lot_decimals:  u32,
+    pub cost_decimals: u32,
+    #[serde(default)]
+    pub leverage_buy:  Vec<u64>,
+    #[serde(default)]
+    pub leverage_sell: Vec<u64>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub ordermin:      Option<f64>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub costmin:       Option<f64>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub tick_size:     Option<f64>,
+    /// 'online', 'cancel_only', 'post_only', 'limit_only' or 'reduce_only'
+    pub status:        String
+}
+
+impl KrakenInstrument {
+    /// the ws symbol of the pair (ex: BTC/USD)
+    pub fn pair(&self) -> KrakenTradingPair {
+        KrakenTradingPair(format!("{}/{}", KrakenTradingPair::normalize_asset(&self.base), KrakenTradingPair::normalize_asset(&self.quote)))
+    }
+
+    pub fn is_active(&self) -> bool {
+        &self.status == "online"
+    }
+
+    pub fn normalize(self) -> Vec<NormalizedInstrument> {
+        let mut instruments = vec![NormalizedInstrument {
+            exchange:           CexExchange::Kraken,
+            trading_pair:       self.pair().normalize(),
+            trading_type:       NormalizedTradingType::Spot,
+            base_asset_symbol:  KrakenTradingPair::normalize_asset(&self.base),
+            quote_asset_symbol: KrakenTradingPair::normalize_asset(&self.quote),
+            active:             self.is_active(),
+            futures_expiry:     None,
+            option_strike:      None,
+            option_type:        None
+        }];
+
+        if !self.leverage_buy.is_empty() || !self.leverage_sell.is_empty() {
+            instruments.push(NormalizedInstrument {
+                exchange:           CexExchange::Kraken,
+                trading_pair:       self.pair().normalize(),
+                trading_type:       NormalizedTradingType::Margin,
+                base_asset_symbol:  KrakenTradingPair::normalize_asset(&self.base),
+                quote_asset_symbol: KrakenTradingPair::normalize_asset(&self.quote),
+                active:             self.is_active(),
+                futures_expiry:     None,
+                option_strike:      None,
+                option_type:        None
+            });
+        }
+
+        instruments
+    }
+}
+
+impl PartialEq<NormalizedInstrument> for KrakenInstrument {
+    fn eq(&self, other: &NormalizedInstrument) -> bool {
+        let is_margin = !self.leverage_buy.is_empty() || !self.leverage_sell.is_empty();
+        let equals = other.exchange == CexExchange::Kraken
+            && other.trading_pair == self.pair().normalize()
+            && other.base_asset_symbol == KrakenTradingPair::normalize_asset(&self.base)
+            && other.quote_asset_symbol == KrakenTradingPair::normalize_asset(&self.quote)
+            && other.active == self.is_active()
+            && (other.trading_type == NormalizedTradingType::Spot || (other.trading_type == NormalizedTradingType::Margin && is_margin));
+
+        if !equals {
+            warn!(target: "cex-exchanges::kraken", "kraken instrument: {:?}", self);
+            warn!(target: "cex-exchanges::kraken", "normalized instrument: {:?}", other);
+        }
+
+        equals
+    }
+}
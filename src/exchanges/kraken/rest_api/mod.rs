@@ -0,0 +1,23 @@
+mod endpoints;
+pub use endpoints::*;
+
+mod response;
+pub use response::*;
+use serde_json::{Map, Value};
+
+/// the `result` object of a response, every response is wrapped in
+/// `{"error": [..], "result": {..}}`
+pub(crate) fn kraken_result(val: Value) -> eyre::Result<Map<String, Value>> {
+    let errors = val
+        .get("error")
+        .and_then(|e| e.as_array())
+        .filter(|e| !e.is_empty());
+    if let Some(errors) = errors {
+        return Err(eyre::ErrReport::msg(format!("kraken returned the errors: {:?}", errors)))
+    }
+
+    val.get("result")
+        .and_then(|r| r.as_object())
+        .cloned()
+        .ok_or(eyre::ErrReport::msg(format!("could not get field 'result' in {:?}", val)))
+}
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use super::{KrakenAllCurrencies, KrakenAllInstruments, KrakenCurrency, KrakenInstrument};
+use crate::normalized::rest_api::NormalizedRestApiDataTypes;
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum KrakenRestApiResponse {
+    Currencies(KrakenAllCurrencies),
+    Instruments(KrakenAllInstruments)
+}
+
+impl KrakenRestApiResponse {
+    pub fn normalize(self) -> NormalizedRestApiDataTypes {
+        match self {
+            KrakenRestApiResponse::Currencies(v) => NormalizedRestApiDataTypes::AllCurrencies(v.normalize()),
+            KrakenRestApiResponse::Instruments(v) => NormalizedRestApiDataTypes::AllInstruments(v.normalize())
+        }
+    }
+
+    pub fn take_currencies(self) -> Option<Vec<KrakenCurrency>> {
+        match self {
+            KrakenRestApiResponse::Currencies(val) => Some(val.currencies),
+            _ => None
+        }
+    }
+
+    pub fn take_instruments(self, active_only: bool) -> Option<Vec<KrakenInstrument>> {
+        let instruments = match self {
+            KrakenRestApiResponse::Instruments(val) => val.instruments,
+            _ => return None
+        };
+
+        if active_only {
+            Some(
+                instruments
+                    .into_iter()
+                    .filter(|instr| instr.is_active())
+                    .collect::<Vec<_>>()
+            )
+        } else {
+            Some(instruments)
+        }
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for KrakenRestApiResponse {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match self {
+            KrakenRestApiResponse::Currencies(vals) => vals == other,
+            KrakenRestApiResponse::Instruments(vals) => vals == other
+        }
+    }
+}
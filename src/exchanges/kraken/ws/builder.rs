@@ -0,0 +1,147 @@
+use super::{
+    channels::{KrakenWsChannel, KrakenWsChannelKind},
+    KrakenSubscription
+};
+use crate::{
+    clients::{rest_api::ExchangeApi, ws::MutliWsStreamBuilder},
+    kraken::Kraken,
+    normalized::ws::NormalizedWsChannels
+};
+
+/// max number of streams the channels are distributed over
+const MAX_KRAKEN_STREAMS: usize = 8;
+/// max number of channels subscribed to on a single connection
+const MAX_KRAKEN_WS_CONNS_PER_STREAM: usize = 50;
+
+#[derive(Debug, Clone, Default)]
+pub struct KrakenWsBuilder {
+    pub channels: Vec<KrakenWsChannel>
+}
+
+impl KrakenWsBuilder {
+    /// adds a channel to the builder
+    pub fn add_channel(mut self, channel: KrakenWsChannel) -> Self {
+        self.channels.push(channel);
+        self
+    }
+
+    /// builds a single ws instance of [Kraken], handling all channels on 1
+    /// stream
+    pub fn build_single(self) -> Kraken {
+        let mut sub = KrakenSubscription::new();
+        self.channels.into_iter().for_each(|c| sub.add_channel(c));
+
+        Kraken::new_ws_subscription(sub)
+    }
+
+    /// builds many ws instances of the [Kraken] as the inner streams of
+    /// [MutliWsStreamBuilder], splitting the channels into different streams,
+    /// each with size # channels / `MAX_KRAKEN_STREAMS` (8),
+    ///
+    /// WARNING: too many channels may break the stream
+    pub fn build_many_distributed(self) -> eyre::Result<MutliWsStreamBuilder<Kraken>> {
+        let stream_size = if self.channels.len() <= MAX_KRAKEN_STREAMS { 1 } else { self.channels.len() / MAX_KRAKEN_STREAMS };
+
+        let chunks = self.channels.chunks(stream_size).collect::<Vec<_>>();
+
+        let split_exchange = chunks
+            .into_iter()
+            .map(|chk| {
+                let mut subscription = KrakenSubscription::new();
+                chk.iter()
+                    .for_each(|ch| subscription.add_channel(ch.clone()));
+
+                Kraken::new_ws_subscription(subscription)
+            })
+            .collect();
+
+        Ok(MutliWsStreamBuilder::new(split_exchange))
+    }
+
+    /// builds many ws instances of the [Kraken] as the inner streams of
+    /// [MutliWsStreamBuilder], splitting the channels into different streams,
+    /// each of size 50
+    pub fn build_many_packed(self, connections_per_stream: Option<usize>) -> eyre::Result<MutliWsStreamBuilder<Kraken>> {
+        let chunks = self
+            .channels
+            .chunks(connections_per_stream.unwrap_or(MAX_KRAKEN_WS_CONNS_PER_STREAM))
+            .collect::<Vec<_>>();
+
+        let split_exchange = chunks
+            .into_iter()
+            .map(|chk| {
+                let mut subscription = KrakenSubscription::new();
+                chk.iter()
+                    .for_each(|ch| subscription.add_channel(ch.clone()));
+
+                Kraken::new_ws_subscription(subscription)
+            })
+            .collect();
+
+        Ok(MutliWsStreamBuilder::new(split_exchange))
+    }
+
+    /// builds a mutlistream channel from all active instruments
+    pub async fn build_from_all_instruments(
+        channels: &[KrakenWsChannelKind],
+        connections_per_stream: Option<usize>
+    ) -> eyre::Result<MutliWsStreamBuilder<Kraken>> {
+        let this = Self::build_from_all_instruments_util(channels, connections_per_stream).await?;
+
+        let all_streams = this
+            .channels
+            .into_iter()
+            .map(|ch| {
+                let mut subscription = KrakenSubscription::new();
+                subscription.add_channel(ch);
+
+                Kraken::new_ws_subscription(subscription)
+            })
+            .collect::<Vec<_>>();
+
+        Ok(MutliWsStreamBuilder::new(all_streams))
+    }
+
+    async fn build_from_all_instruments_util(channels: &[KrakenWsChannelKind], connections_per_stream: Option<usize>) -> eyre::Result<Self> {
+        let mut this = Self::default();
+
+        let all_symbols = ExchangeApi::new()
+            .all_instruments::<Kraken>()
+            .await?
+            .take_kraken_instruments(true)
+            .unwrap()
+            .into_iter()
+            .map(|val| val.pair())
+            .collect::<Vec<_>>();
+
+        let chunks = all_symbols.chunks(connections_per_stream.unwrap_or(MAX_KRAKEN_WS_CONNS_PER_STREAM));
+
+        chunks.into_iter().for_each(|chk| {
+            let all_channels = channels
+                .iter()
+                .map(|ch| match ch {
+                    KrakenWsChannelKind::Trade => KrakenWsChannel::Trade(chk.to_vec()),
+                    KrakenWsChannelKind::Ticker => KrakenWsChannel::Ticker(chk.to_vec()),
+                    KrakenWsChannelKind::Book => KrakenWsChannel::Book(chk.to_vec())
+                })
+                .collect::<Vec<_>>();
+
+            this.channels.extend(all_channels);
+        });
+
+        Ok(this)
+    }
+
+    /// makes the builder from the normalized builder's map
+    pub(crate) fn make_from_normalized_map(map: Vec<NormalizedWsChannels>) -> eyre::Result<Self> {
+        let mut this = Self::default();
+
+        map.into_iter().try_for_each(|channel| {
+            let this_channel: KrakenWsChannel = channel.try_into()?;
+            this = this.clone().add_channel(this_channel);
+            Ok(()) as eyre::Result<()>
+        })?;
+
+        Ok(this)
+    }
+}
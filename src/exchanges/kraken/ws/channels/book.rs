@@ -0,0 +1,92 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::{
+    exchanges::{
+        kraken::pairs::KrakenTradingPair,
+        normalized::types::{NormalizedBookLevel, NormalizedOrderBookUpdate, NormalizedOrderBookUpdateKind}
+    },
+    normalized::types::NormalizedNumber,
+    CexExchange
+};
+
+/// the number of levels per side subscribed to on the `book` channel
+pub const KRAKEN_BOOK_DEPTH: usize = 100;
+
+/// the `book` channel, the action is taken from the outer message
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct KrakenBook {
+    /// 'snapshot' or 'update'
+    pub kind: String,
+    pub data: KrakenBookData
+}
+
+impl KrakenBook {
+    pub fn normalize(self) -> NormalizedOrderBookUpdate {
+        NormalizedOrderBookUpdate {
+            exchange:        CexExchange::Kraken,
+            pair:            self.data.symbol.normalize(),
+            time:            self.data.timestamp,
+            kind:            self.update_kind(),
+            bids:            KrakenBookData::normalize_side(&self.data.bids),
+            asks:            KrakenBookData::normalize_side(&self.data.asks),
+            first_update_id: None,
+            last_update_id:  None
+        }
+    }
+
+    fn update_kind(&self) -> NormalizedOrderBookUpdateKind {
+        if &self.kind == "snapshot" {
+            NormalizedOrderBookUpdateKind::Snapshot
+        } else {
+            NormalizedOrderBookUpdateKind::Delta
+        }
+    }
+}
+
+impl PartialEq<NormalizedOrderBookUpdate> for KrakenBook {
+    fn eq(&self, other: &NormalizedOrderBookUpdate) -> bool {
+        let equals = other.exchange == CexExchange::Kraken
+            && other.pair == self.data.symbol.normalize()
+            && other.time == self.data.timestamp
+            && other.kind == self.update_kind()
+            && other.bids == KrakenBookData::normalize_side(&self.data.bids)
+            && other.asks == KrakenBookData::normalize_side(&self.data.asks)
+            && other.last_update_id.is_none();
+
+        if !equals {
+            warn!(target: "cex-exchanges::kraken", "kraken book: {:?}", self);
+            warn!(target: "cex-exchanges::kraken", "normalized order book: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct KrakenBookData {
+    pub symbol:    KrakenTradingPair,
+    pub bids:      Vec<KrakenBookLevel>,
+    pub asks:      Vec<KrakenBookLevel>,
+    /// crc32 of the top 10 levels of the book after the update
+    pub checksum:  u64,
+    /// snapshots don't carry a timestamp, so it's the time it was received
+    #[serde(default = "Utc::now")]
+    pub timestamp: DateTime<Utc>
+}
+
+impl KrakenBookData {
+    fn normalize_side(levels: &[KrakenBookLevel]) -> Vec<NormalizedBookLevel> {
+        levels
+            .iter()
+            .map(|level| NormalizedBookLevel::new(level.price, level.qty))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct KrakenBookLevel {
+    pub price: NormalizedNumber,
+    pub qty:   NormalizedNumber
+}
@@ -0,0 +1,171 @@
+use std::fmt::Display;
+
+mod trade;
+pub use trade::*;
+
+mod ticker;
+pub use ticker::*;
+
+mod book;
+pub use book::*;
+
+use crate::{
+    exchanges::{
+        kraken::pairs::KrakenTradingPair,
+        normalized::{
+            types::{NormalizedTradingPair, RawTradingPair},
+            ws::NormalizedWsChannels
+        }
+    },
+    CexExchange
+};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum KrakenWsChannel {
+    Trade(Vec<KrakenTradingPair>),
+    /// best bid & offer updates
+    Ticker(Vec<KrakenTradingPair>),
+    /// the top [KRAKEN_BOOK_DEPTH] levels of the book
+    Book(Vec<KrakenTradingPair>)
+}
+
+impl KrakenWsChannel {
+    /// builds trade channel from a vec of raw trading pairs
+    /// return an error if the symbol is incorrectly formatted
+    pub fn new_trade(pairs: Vec<RawTradingPair>) -> eyre::Result<Self> {
+        let normalized = pairs
+            .into_iter()
+            .map(|pair| pair.get_normalized_pair(CexExchange::Kraken))
+            .collect();
+
+        Self::new_from_kind(normalized, KrakenWsChannel::Trade(Vec::new()))
+    }
+
+    /// builds ticker channel from a vec of raw trading pairs
+    /// return an error if the symbol is incorrectly formatted
+    pub fn new_ticker(pairs: Vec<RawTradingPair>) -> eyre::Result<Self> {
+        let normalized = pairs
+            .into_iter()
+            .map(|pair| pair.get_normalized_pair(CexExchange::Kraken))
+            .collect();
+
+        Self::new_from_kind(normalized, KrakenWsChannel::Ticker(Vec::new()))
+    }
+
+    /// builds book channel from a vec of raw trading pairs
+    /// return an error if the symbol is incorrectly formatted
+    pub fn new_book(pairs: Vec<RawTradingPair>) -> eyre::Result<Self> {
+        let normalized = pairs
+            .into_iter()
+            .map(|pair| pair.get_normalized_pair(CexExchange::Kraken))
+            .collect();
+
+        Self::new_from_kind(normalized, KrakenWsChannel::Book(Vec::new()))
+    }
+
+    fn new_from_kind(pairs: Vec<NormalizedTradingPair>, kind: KrakenWsChannel) -> eyre::Result<Self> {
+        let pairs = pairs
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+
+        match kind {
+            KrakenWsChannel::Trade(_) => Ok(KrakenWsChannel::Trade(pairs)),
+            KrakenWsChannel::Ticker(_) => Ok(KrakenWsChannel::Ticker(pairs)),
+            KrakenWsChannel::Book(_) => Ok(KrakenWsChannel::Book(pairs))
+        }
+    }
+
+    pub(crate) fn pairs(&self) -> &[KrakenTradingPair] {
+        match self {
+            KrakenWsChannel::Trade(pairs) => pairs,
+            KrakenWsChannel::Ticker(pairs) => pairs,
+            KrakenWsChannel::Book(pairs) => pairs
+        }
+    }
+}
+
+impl Display for KrakenWsChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        KrakenWsChannelKind::from(self).fmt(f)
+    }
+}
+
+impl TryFrom<String> for KrakenWsChannel {
+    type Error = eyre::Report;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.to_lowercase().as_str() {
+            "trade" => Ok(Self::Trade(Vec::new())),
+            "ticker" => Ok(Self::Ticker(Vec::new())),
+            "book" => Ok(Self::Book(Vec::new())),
+            _ => Err(eyre::ErrReport::msg(format!("channel is not valid: {value}")))
+        }
+    }
+}
+
+impl TryFrom<NormalizedWsChannels> for KrakenWsChannel {
+    type Error = eyre::ErrReport;
+
+    fn try_from(value: NormalizedWsChannels) -> Result<Self, Self::Error> {
+        match value {
+            NormalizedWsChannels::Trades(pairs) => {
+                let norm_pairs = pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, Self::Error>>()?;
+
+                Ok(KrakenWsChannel::Trade(norm_pairs))
+            }
+
+            NormalizedWsChannels::Quotes(pairs) => {
+                let norm_pairs = pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, Self::Error>>()?;
+
+                Ok(KrakenWsChannel::Ticker(norm_pairs))
+            }
+
+            NormalizedWsChannels::OrderBook { pairs, .. } => {
+                let norm_pairs = pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, Self::Error>>()?;
+
+                Ok(KrakenWsChannel::Book(norm_pairs))
+            }
+
+            NormalizedWsChannels::Candles { .. } => Err(eyre::ErrReport::msg("kraken does not support the candles ws channel")),
+
+            NormalizedWsChannels::Account(_) => Err(eyre::ErrReport::msg("kraken does not support the account ws channel"))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum KrakenWsChannelKind {
+    Trade,
+    Ticker,
+    Book
+}
+
+impl Display for KrakenWsChannelKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            KrakenWsChannelKind::Trade => write!(f, "trade"),
+            KrakenWsChannelKind::Ticker => write!(f, "ticker"),
+            KrakenWsChannelKind::Book => write!(f, "book")
+        }
+    }
+}
+
+impl From<&KrakenWsChannel> for KrakenWsChannelKind {
+    fn from(value: &KrakenWsChannel) -> Self {
+        match value {
+            KrakenWsChannel::Trade(_) => KrakenWsChannelKind::Trade,
+            KrakenWsChannel::Ticker(_) => KrakenWsChannelKind::Ticker,
+            KrakenWsChannel::Book(_) => KrakenWsChannelKind::Book
+        }
+    }
+}
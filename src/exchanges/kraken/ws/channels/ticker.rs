@@ -0,0 +1,95 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::{
+    clients::ws::received_at,
+    exchanges::{kraken::pairs::KrakenTradingPair, normalized::types::NormalizedQuote},
+    normalized::types::NormalizedNumber,
+    CexExchange
+};
+
+/// the `ticker` channel, sent on each change of the best bid & offer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KrakenTicker {
+    /// 'snapshot' or 'update'
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub data: Vec<KrakenTickerData>
+}
+
+impl KrakenTicker {
+    pub fn normalize(self) -> Vec<NormalizedQuote> {
+        self.data
+            .into_iter()
+            .map(KrakenTickerData::normalize)
+            .collect()
+    }
+}
+
+impl PartialEq<Vec<NormalizedQuote>> for KrakenTicker {
+    fn eq(&self, other: &Vec<NormalizedQuote>) -> bool {
+        let equals = self.data.len() == other.len()
+            && self
+                .data
+                .iter()
+                .zip(other.iter())
+                .all(|(this, that)| this == that);
+
+        if !equals {
+            warn!(target: "cex-exchanges::kraken", "kraken ticker: {:?}", self);
+            warn!(target: "cex-exchanges::kraken", "normalized quotes: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KrakenTickerData {
+    pub symbol:      KrakenTradingPair,
+    pub bid:         NormalizedNumber,
+    pub bid_qty:     NormalizedNumber,
+    pub ask:         NormalizedNumber,
+    pub ask_qty:     NormalizedNumber,
+    pub last:        NormalizedNumber,
+    pub volume:      NormalizedNumber,
+    pub vwap:        NormalizedNumber,
+    pub low:         NormalizedNumber,
+    pub high:        NormalizedNumber,
+    pub change:      NormalizedNumber,
+    pub change_pct:  NormalizedNumber,
+    #[serde(default = "received_at")]
+    pub received_at: DateTime<Utc>
+}
+
+impl KrakenTickerData {
+    pub fn normalize(self) -> NormalizedQuote {
+        NormalizedQuote {
+            exchange:      CexExchange::Kraken,
+            pair:          self.symbol.normalize(),
+            // the ticker doesn't carry a timestamp
+            exchange_time: None,
+            received_at:   self.received_at,
+            ask_amount:    self.ask_qty,
+            ask_price:     self.ask,
+            bid_amount:    self.bid_qty,
+            bid_price:     self.bid,
+            quote_id:      None
+        }
+    }
+}
+
+impl PartialEq<NormalizedQuote> for KrakenTickerData {
+    fn eq(&self, other: &NormalizedQuote) -> bool {
+        other.exchange == CexExchange::Kraken
+            && other.pair == self.symbol.normalize()
+            && other.exchange_time.is_none()
+            && other.received_at == self.received_at
+            && other.ask_amount == self.ask_qty
+            && other.ask_price == self.ask
+            && other.bid_amount == self.bid_qty
+            && other.bid_price == self.bid
+            && other.quote_id.is_none()
+    }
+}
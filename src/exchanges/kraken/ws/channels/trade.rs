@@ -0,0 +1,92 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::{
+    clients::ws::received_at,
+    exchanges::{
+        kraken::pairs::KrakenTradingPair,
+        normalized::types::{NormalizedTrade, TradeSide}
+    },
+    normalized::types::NormalizedNumber,
+    CexExchange
+};
+
+/// the `trade` channel, the first message of each pair is a 'snapshot' of
+/// the latest trades
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct KrakenTrade {
+    /// 'snapshot' or 'update'
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub data: Vec<KrakenTradeData>
+}
+
+impl KrakenTrade {
+    pub fn normalize(self) -> Vec<NormalizedTrade> {
+        self.data
+            .into_iter()
+            .map(KrakenTradeData::normalize)
+            .collect()
+    }
+}
+
+impl PartialEq<Vec<NormalizedTrade>> for KrakenTrade {
+    fn eq(&self, other: &Vec<NormalizedTrade>) -> bool {
+        let equals = self.data.len() == other.len()
+            && self
+                .data
+                .iter()
+                .zip(other.iter())
+                .all(|(this, that)| this == that);
+
+        if !equals {
+            warn!(target: "cex-exchanges::kraken", "kraken trade: {:?}", self);
+            warn!(target: "cex-exchanges::kraken", "normalized trades: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct KrakenTradeData {
+    pub symbol:      KrakenTradingPair,
+    pub side:        String,
+    pub price:       NormalizedNumber,
+    pub qty:         NormalizedNumber,
+    /// 'limit' or 'market'
+    pub ord_type:    String,
+    pub trade_id:    u64,
+    pub timestamp:   DateTime<Utc>,
+    #[serde(default = "received_at")]
+    pub received_at: DateTime<Utc>
+}
+
+impl KrakenTradeData {
+    pub fn normalize(self) -> NormalizedTrade {
+        NormalizedTrade {
+            exchange:      CexExchange::Kraken,
+            pair:          self.symbol.normalize(),
+            exchange_time: Some(self.timestamp),
+            received_at:   self.received_at,
+            side:          TradeSide::from_taker_side(&self.side),
+            price:         self.price,
+            amount:        self.qty,
+            trade_id:      Some(self.trade_id.to_string())
+        }
+    }
+}
+
+impl PartialEq<NormalizedTrade> for KrakenTradeData {
+    fn eq(&self, other: &NormalizedTrade) -> bool {
+        other.exchange == CexExchange::Kraken
+            && other.pair == self.symbol.normalize()
+            && other.exchange_time == Some(self.timestamp)
+            && other.received_at == self.received_at
+            && other.side == TradeSide::from_taker_side(&self.side)
+            && other.price == self.price
+            && other.amount == self.qty
+            && other.trade_id == Some(self.trade_id.to_string())
+    }
+}
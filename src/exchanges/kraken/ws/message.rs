@@ -0,0 +1,142 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::channels::{KrakenBook, KrakenBookData, KrakenTicker, KrakenTrade};
+use crate::{clients::ws::CriticalWsMessage, exchanges::normalized::ws::NormalizedWsDataTypes, kraken::KrakenTradingPair, CexExchange};
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case", tag = "data")]
+pub enum KrakenWsMessage {
+    Trade(KrakenTrade),
+    Ticker(KrakenTicker),
+    Book(KrakenBook),
+    Status(Value),
+    Heartbeat,
+    Subscribe(Value),
+    Error { error: String, raw_msg: String, bad_pair: Option<KrakenTradingPair> }
+}
+
+impl KrakenWsMessage {
+    fn try_deserialize(value: Value) -> eyre::Result<Self> {
+        if let Some(channel) = value.get("channel") {
+            let channel = channel
+                .as_str()
+                .ok_or(eyre::ErrReport::msg("Could not convert 'channel' field in Kraken ws message to &str".to_string()))?;
+
+            match channel {
+                "trade" => Ok(Self::Trade(serde_json::from_value(value)?)),
+                "ticker" => Ok(Self::Ticker(serde_json::from_value(value)?)),
+                "book" => {
+                    let kind = value
+                        .get("type")
+                        .and_then(|t| t.as_str())
+                        .ok_or(eyre::ErrReport::msg("Could not find 'type' field in Kraken ws message".to_string()))?
+                        .to_string();
+                    let data: Vec<KrakenBookData> = serde_json::from_value(
+                        value
+                            .get("data")
+                            .cloned()
+                            .ok_or(eyre::ErrReport::msg("Could not find 'data' field in Kraken ws message".to_string()))?
+                    )?;
+                    let data = data
+                        .into_iter()
+                        .next()
+                        .ok_or(eyre::ErrReport::msg("Empty 'data' field in Kraken book message".to_string()))?;
+
+                    Ok(Self::Book(KrakenBook { kind, data }))
+                }
+                "status" => Ok(Self::Status(value)),
+                "heartbeat" => Ok(Self::Heartbeat),
+                _ => Err(eyre::ErrReport::msg(format!("Channel type '{channel}' cannot be deserialized")))
+            }
+        } else {
+            let method = value
+                .get("method")
+                .ok_or(eyre::ErrReport::msg("Could not find 'method' field in Kraken ws message".to_string()))?
+                .as_str()
+                .ok_or(eyre::ErrReport::msg("Could not convert 'method' field in Kraken ws message to &str".to_string()))?;
+
+            if value.get("success").and_then(|s| s.as_bool()) == Some(true) {
+                return Ok(Self::Subscribe(value))
+            }
+
+            let error = value
+                .get("error")
+                .and_then(|e| e.as_str())
+                .ok_or(eyre::ErrReport::msg(format!("Kraken '{method}' request failed without an error message")))?;
+            let bad_pair = value
+                .get("symbol")
+                .and_then(|s| s.as_str())
+                .and_then(|s| KrakenTradingPair::try_from(s).ok())
+                .or_else(|| KrakenTradingPair::parse_for_bad_pair(error));
+
+            Ok(Self::Error { error: error.to_string(), raw_msg: String::new(), bad_pair })
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for KrakenWsMessage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        let map = Value::deserialize(deserializer)?;
+
+        Self::try_deserialize(map).map_err(serde::de::Error::custom)
+    }
+}
+
+impl KrakenWsMessage {
+    pub fn normalize(self) -> NormalizedWsDataTypes {
+        match self {
+            KrakenWsMessage::Trade(v) => NormalizedWsDataTypes::Trades(v.normalize()),
+            KrakenWsMessage::Ticker(v) => NormalizedWsDataTypes::Quotes(v.normalize()),
+            KrakenWsMessage::Book(v) => NormalizedWsDataTypes::OrderBook(v.normalize()),
+            KrakenWsMessage::Status(v) => {
+                NormalizedWsDataTypes::Other { exchange: CexExchange::Kraken, kind: "Status".to_string(), value: format!("{:?}", v) }
+            }
+            KrakenWsMessage::Heartbeat => {
+                NormalizedWsDataTypes::Other { exchange: CexExchange::Kraken, kind: "Heartbeat".to_string(), value: String::new() }
+            }
+            KrakenWsMessage::Subscribe(v) => {
+                NormalizedWsDataTypes::Other { exchange: CexExchange::Kraken, kind: "Subscribe".to_string(), value: format!("{:?}", v) }
+            }
+            KrakenWsMessage::Error { error, raw_msg, bad_pair } => {
+                if let Some(bp) = bad_pair {
+                    NormalizedWsDataTypes::RemovedPair {
+                        exchange:    CexExchange::Kraken,
+                        bad_pair:    bp.normalize(),
+                        raw_message: format!("{error} - {raw_msg}")
+                    }
+                } else {
+                    NormalizedWsDataTypes::Other { exchange: CexExchange::Kraken, kind: error, value: raw_msg }
+                }
+            }
+        }
+    }
+}
+
+impl PartialEq<NormalizedWsDataTypes> for KrakenWsMessage {
+    fn eq(&self, other: &NormalizedWsDataTypes) -> bool {
+        match (self, other) {
+            (KrakenWsMessage::Trade(this), NormalizedWsDataTypes::Trades(that)) => this == that,
+            (KrakenWsMessage::Ticker(this), NormalizedWsDataTypes::Quotes(that)) => this == that,
+            (KrakenWsMessage::Book(this), NormalizedWsDataTypes::OrderBook(that)) => this == that,
+            (KrakenWsMessage::Status(_), NormalizedWsDataTypes::Other { .. }) => true,
+            (KrakenWsMessage::Heartbeat, NormalizedWsDataTypes::Other { .. }) => true,
+            (KrakenWsMessage::Subscribe(_), NormalizedWsDataTypes::Other { .. }) => true,
+            (KrakenWsMessage::Error { .. }, NormalizedWsDataTypes::Other { .. }) => true,
+            (KrakenWsMessage::Error { .. }, NormalizedWsDataTypes::RemovedPair { .. }) => true,
+            _ => false
+        }
+    }
+}
+
+impl CriticalWsMessage for KrakenWsMessage {
+    fn make_critical(&mut self, msg: String) {
+        if let KrakenWsMessage::Error { raw_msg, .. } = self {
+            *raw_msg = msg;
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use std::collections::HashSet;
+
+use serde::Serialize;
+
+use super::channels::{KrakenWsChannel, KrakenWsChannelKind, KRAKEN_BOOK_DEPTH};
+use crate::kraken::KrakenTradingPair;
+
+/// the v2 ws takes a `subscribe` request per channel
+#[derive(Debug, Default, Clone)]
+pub struct KrakenSubscription {
+    params: Vec<KrakenSubscriptionParams>
+}
+
+impl KrakenSubscription {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add_channel(&mut self, channel: KrakenWsChannel) {
+        self.params.push(channel.into());
+    }
+
+    pub fn remove_pair(&mut self, pair: &KrakenTradingPair) -> bool {
+        self.params
+            .iter_mut()
+            .for_each(|p| p.symbol.retain(|s| s != pair));
+        self.params.retain(|p| !p.symbol.is_empty());
+
+        self.params.is_empty()
+    }
+
+    /// the `subscribe` requests to send, 1 for each channel
+    pub(crate) fn requests(&self) -> Vec<KrakenSubscriptionRequest> {
+        self.params
+            .iter()
+            .map(|params| KrakenSubscriptionRequest { method: "subscribe", params: params.clone() })
+            .collect()
+    }
+
+    /// the `unsubscribe` requests of every channel
+    pub(crate) fn unsubscribe_requests(&self) -> Vec<KrakenSubscriptionRequest> {
+        self.params
+            .iter()
+            .map(|params| KrakenSubscriptionRequest { method: "unsubscribe", params: params.clone() })
+            .collect()
+    }
+
+    /// adds the pairs of the channel, returning the request to subscribe to
+    /// the new ones
+    pub(crate) fn subscribe(&mut self, channel: KrakenWsChannel) -> Option<KrakenSubscriptionRequest> {
+        let mut params = KrakenSubscriptionParams::from(channel);
+        params.symbol.retain(|s| {
+            !self
+                .params
+                .iter()
+                .any(|p| p.channel == params.channel && p.symbol.contains(s))
+        });
+        if params.symbol.is_empty() {
+            return None
+        }
+
+        self.params.push(params.clone());
+        Some(KrakenSubscriptionRequest { method: "subscribe", params })
+    }
+
+    /// removes the pairs of the channel, returning the request to unsubscribe
+    /// from the ones that were subscribed to
+    pub(crate) fn unsubscribe(&mut self, channel: KrakenWsChannel) -> Option<KrakenSubscriptionRequest> {
+        let mut params = KrakenSubscriptionParams::from(channel);
+        params.symbol.retain(|s| {
+            self.params
+                .iter()
+                .any(|p| p.channel == params.channel && p.symbol.contains(s))
+        });
+        if params.symbol.is_empty() {
+            return None
+        }
+
+        self.params
+            .iter_mut()
+            .filter(|p| p.channel == params.channel)
+            .for_each(|p| p.symbol.retain(|s| !params.symbol.contains(s)));
+        self.params.retain(|p| !p.symbol.is_empty());
+
+        Some(KrakenSubscriptionRequest { method: "unsubscribe", params })
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct KrakenSubscriptionRequest {
+    method: &'static str,
+    params: KrakenSubscriptionParams
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct KrakenSubscriptionParams {
+    #[serde(serialize_with = "serialize_channel")]
+    channel:       KrakenWsChannelKind,
+    symbol:        Vec<KrakenTradingPair>,
+    /// only set on the book channel
+    #[serde(skip_serializing_if = "Option::is_none")]
+    depth:         Option<usize>,
+    /// only set on the ticker channel, to be sent the best bid & offer
+    /// changes instead of the trades
+    #[serde(skip_serializing_if = "Option::is_none")]
+    event_trigger: Option<&'static str>
+}
+
+fn serialize_channel<S>(channel: &KrakenWsChannelKind, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer
+{
+    channel.to_string().serialize(serializer)
+}
+
+impl From<KrakenWsChannel> for KrakenSubscriptionParams {
+    fn from(value: KrakenWsChannel) -> Self {
+        let channel = KrakenWsChannelKind::from(&value);
+        let symbol = value
+            .pairs()
+            .iter()
+            .cloned()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+
+        match channel {
+            KrakenWsChannelKind::Trade => KrakenSubscriptionParams { channel, symbol, depth: None, event_trigger: None },
+            KrakenWsChannelKind::Ticker => KrakenSubscriptionParams { channel, symbol, depth: None, event_trigger: Some("bbo") },
+            KrakenWsChannelKind::Book => KrakenSubscriptionParams { channel, symbol, depth: Some(KRAKEN_BOOK_DEPTH), event_trigger: None }
+        }
+    }
+}
@@ -1,41 +1,178 @@
 mod pairs;
 
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc
+    },
+    time::Duration
+};
+
+use chrono::{DateTime, Utc};
 use futures::SinkExt;
 pub use pairs::*;
+use reqwest::header::HeaderMap;
 
 pub mod rest_api;
 pub mod ws;
 
+use rand::Rng;
 use serde::Deserialize;
 use tokio::net::TcpStream;
 use tokio_tungstenite::{tungstenite::Message, MaybeTlsStream, WebSocketStream};
 
 use self::{
-    rest_api::KucoinRestApiResponse,
-    ws::{KucoinMultiSubscription, KucoinSubscription, KucoinWsEndpointResponse, KucoinWsMessage}
+    rest_api::{
+        KucoinAllCandles, KucoinCanceledOrders, KucoinCandlesPage, KucoinOpenOrdersPage, KucoinOrderRequest, KucoinPlacedOrder,
+        KucoinRestApiResponse, KucoinTradeHistories, KucoinTradeHistoriesPage, KucoinTradeResponse
+    },
+    ws::{
+        channels::{KucoinCandleInterval, KucoinLevel2Snapshot, KucoinOrderBook, KucoinOrderBookResponse, KucoinWsChannel},
+        KucoinMultiSubscription, KucoinSubscription, KucoinWsEndpointResponse, KucoinWsMessage
+    }
 };
 use crate::{
-    clients::{rest_api::RestApiError, ws::WsError},
-    exchanges::Exchange,
-    normalized::{rest_api::NormalizedRestApiRequest, types::NormalizedTradingPair},
+    clients::{
+        rate_limiter, require_credentials,
+        rest_api::{signed_response, RestApiError},
+        ws::{WsError, WsSubscriptionUpdate},
+        EndpointKind, ExchangeCredentials, ExchangeEndpoints, RateLimitKind, TokenBucket
+    },
+    exchanges::{Exchange, ExchangeTrading},
+    normalized::{
+        rest_api::NormalizedRestApiRequest,
+        types::{
+            NormalizedCandleInterval, NormalizedOrderAck, NormalizedOrderRequest, NormalizedOrderStatus, NormalizedOrderUpdate,
+            NormalizedTimeInForce, NormalizedTradingPair
+        }
+    },
     CexExchange
 };
 
 const BASE_REST_API_URL: &str = "https://api.kucoin.com";
+const MAX_CANDLES_PER_REQUEST: usize = 1500;
+const MAX_TRADE_HISTORIES: usize = 100;
+const MAX_OPEN_ORDERS_PER_PAGE: usize = 500;
 
 #[derive(Debug, Default, Clone)]
 pub struct Kucoin {
-    subscriptions: Vec<KucoinSubscription>
+    subscriptions: Vec<KucoinSubscription>,
+    credentials:   Option<ExchangeCredentials>,
+    /// millis between the `ping`s, given with the ws endpoint (shared with the
+    /// clone making the connection)
+    ping_interval: Arc<AtomicU64>,
+    endpoints:     ExchangeEndpoints
 }
 
 impl Kucoin {
+    fn rest_url(&self) -> String {
+        self.endpoints.url(EndpointKind::Rest, BASE_REST_API_URL)
+    }
+
     pub fn new_ws_subscription(subscription: KucoinMultiSubscription) -> Self {
-        Self { subscriptions: subscription.all_subscriptions() }
+        Self {
+            subscriptions: subscription.all_subscriptions(),
+            credentials:   None,
+            ping_interval: Arc::default(),
+            endpoints:     ExchangeEndpoints::default()
+        }
     }
 
-    pub async fn get_websocket_endpoint() -> Result<KucoinWsEndpointResponse, WsError> {
+    /// sets the credentials used to get the private ws token
+    pub fn with_credentials(mut self, credentials: ExchangeCredentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    /// gets the endpoint & token of the private ws, the request is signed
+    /// with a v2 api key
+    pub async fn get_private_websocket_endpoint(&self, credentials: &ExchangeCredentials) -> Result<KucoinWsEndpointResponse, WsError> {
+        let endpoint = "/api/v1/bullet-private";
+        let headers =
+            Self::signed_headers(credentials, &reqwest::Method::POST, endpoint, "").map_err(|e| WsError::AuthenticationError(e.to_string()))?;
+
+        rate_limiter(CexExchange::Kucoin, RateLimitKind::Rest)
+            .acquire()
+            .await;
         let data: KucoinWsEndpointResponse = reqwest::Client::new()
-            .post(&format!("{BASE_REST_API_URL}/api/v1/bullet-public"))
+            .post(format!("{}{endpoint}", self.rest_url()))
+            .headers(headers)
+            .send()
+            .await
+            .map_err(|e| WsError::AuthenticationError(e.to_string()))?
+            .json()
+            .await
+            .map_err(|e| WsError::AuthenticationError(e.to_string()))?;
+
+        Ok(data)
+    }
+
+    /// headers of a request signed with the `HMAC-SHA256` of
+    /// `timestamp + method + endpoint + body`, the passphrase is signed as
+    /// well (v2 api keys)
+    fn signed_headers(credentials: &ExchangeCredentials, method: &reqwest::Method, endpoint: &str, body: &str) -> eyre::Result<HeaderMap> {
+        let timestamp = Utc::now().timestamp_millis();
+
+        let mut headers = HeaderMap::new();
+        headers.insert("KC-API-KEY", credentials.api_key.parse()?);
+        headers.insert(
+            "KC-API-SIGN",
+            credentials
+                .sign_base64(&format!("{timestamp}{method}{endpoint}{body}"))?
+                .parse()?
+        );
+        headers.insert("KC-API-TIMESTAMP", timestamp.into());
+        headers.insert(
+            "KC-API-PASSPHRASE",
+            credentials
+                .sign_base64(credentials.passphrase()?)?
+                .parse()?
+        );
+        headers.insert("KC-API-KEY-VERSION", "2".parse()?);
+
+        Ok(headers)
+    }
+
+    /// sends a signed request, erroring if the response's `code` isn't
+    /// `200000`
+    pub async fn signed_rest_api_request<T>(
+        &self,
+        web_client: &reqwest::Client,
+        credentials: &ExchangeCredentials,
+        method: reqwest::Method,
+        endpoint: &str,
+        body: Option<String>
+    ) -> Result<T, RestApiError>
+    where
+        T: for<'de> Deserialize<'de>
+    {
+        rate_limiter(CexExchange::Kucoin, RateLimitKind::Rest)
+            .acquire()
+            .await;
+        let body = body.unwrap_or_default();
+        let headers = Self::signed_headers(credentials, &method, endpoint, &body).map_err(|e| RestApiError::AuthenticationError(e.to_string()))?;
+
+        let response = web_client
+            .request(method, format!("{}{endpoint}", self.rest_url()))
+            .headers(headers)
+            .header("Content-Type", "application/json")
+            .body(body)
+            .send()
+            .await?;
+
+        let response: KucoinTradeResponse<T> = signed_response(response).await?;
+        match response {
+            KucoinTradeResponse { code, data: Some(data), .. } if code == "200000" => Ok(data),
+            KucoinTradeResponse { code, msg, .. } => Err(RestApiError::ExchangeError(format!("{code} - {}", msg.unwrap_or_default())))
+        }
+    }
+
+    pub async fn get_websocket_endpoint(&self) -> Result<KucoinWsEndpointResponse, WsError> {
+        rate_limiter(CexExchange::Kucoin, RateLimitKind::Rest)
+            .acquire()
+            .await;
+        let data: KucoinWsEndpointResponse = reqwest::Client::new()
+            .post(format!("{}/api/v1/bullet-public", self.rest_url()))
             .send()
             .await
             .map_err(|e| WsError::WebInitializationError(e.to_string()))?
@@ -46,10 +183,107 @@ impl Kucoin {
         Ok(data)
     }
 
+    pub async fn get_orderbook(&self, web_client: &reqwest::Client, pair: &KucoinTradingPair) -> Result<KucoinOrderBook, RestApiError> {
+        let url = format!("{}/api/v1/market/orderbook/level2_100?symbol={}", self.rest_url(), pair.0);
+        let response: KucoinOrderBookResponse = Self::simple_rest_api_request(web_client, url).await?;
+
+        Ok(response.data)
+    }
+
+    /// pages (backwards) through `/api/v1/market/candles` for all candles
+    /// starting in `start..end`
+    pub async fn get_candles(
+        &self,
+        web_client: &reqwest::Client,
+        symbol: KucoinTradingPair,
+        interval: NormalizedCandleInterval,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>
+    ) -> Result<KucoinAllCandles, RestApiError> {
+        let mut candles = Vec::new();
+        let mut page_end = end.timestamp() - 1;
+        while page_end >= start.timestamp() {
+            let url = format!(
+                "{}/api/v1/market/candles?type={}&symbol={}&startAt={}&endAt={page_end}",
+                self.rest_url(),
+                KucoinCandleInterval(interval),
+                symbol.0,
+                start.timestamp()
+            );
+            let page: KucoinCandlesPage = Self::simple_rest_api_request(web_client, url).await?;
+
+            let Some(oldest) = page.data.last() else { break };
+            page_end = oldest.start_time as i64 - 1;
+
+            let is_last_page = page.data.len() < MAX_CANDLES_PER_REQUEST;
+            candles.extend(page.data);
+            if is_last_page {
+                break
+            }
+        }
+
+        candles.retain(|c| (start.timestamp()..end.timestamp()).contains(&(c.start_time as i64)));
+        candles.sort_by_key(|c| c.start_time);
+        candles.dedup_by_key(|c| c.start_time);
+
+        Ok(KucoinAllCandles { symbol, interval, candles })
+    }
+
+    /// the latest `limit` trades (max 100)
+    pub async fn get_trade_histories(
+        &self,
+        web_client: &reqwest::Client,
+        symbol: KucoinTradingPair,
+        limit: usize
+    ) -> Result<KucoinTradeHistories, RestApiError> {
+        let url = format!("{}/api/v1/market/histories?symbol={}", self.rest_url(), symbol.0);
+        let page: KucoinTradeHistoriesPage = Self::simple_rest_api_request(web_client, url).await?;
+
+        let mut trades = page.data;
+        trades.sort_by_key(|t| std::cmp::Reverse(t.time));
+        trades.truncate(limit);
+        trades.reverse();
+
+        Ok(KucoinTradeHistories { symbol, trades })
+    }
+
+    /// kucoin has no paginated trade history, so this only succeeds if `from`
+    /// is within the last 100 trades
+    pub async fn get_historical_trades(
+        &self,
+        web_client: &reqwest::Client,
+        symbol: KucoinTradingPair,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>
+    ) -> Result<KucoinTradeHistories, RestApiError> {
+        let mut histories = self
+            .get_trade_histories(web_client, symbol, MAX_TRADE_HISTORIES)
+            .await?;
+
+        let from_nanos = from.timestamp_nanos_opt().unwrap_or_default() as u64;
+        let to_nanos = to.timestamp_nanos_opt().unwrap_or(i64::MAX) as u64;
+
+        let covers_from = histories.trades.len() < MAX_TRADE_HISTORIES || histories.trades.iter().any(|t| t.time <= from_nanos);
+        if !covers_from {
+            return Err(RestApiError::UnsupportedRequest(format!(
+                "kucoin only serves the last {MAX_TRADE_HISTORIES} trades, which don't reach back to {from}"
+            )))
+        }
+
+        histories
+            .trades
+            .retain(|t| (from_nanos..to_nanos).contains(&t.time));
+
+        Ok(histories)
+    }
+
     pub async fn simple_rest_api_request<T>(web_client: &reqwest::Client, url: String) -> Result<T, RestApiError>
     where
         T: for<'de> Deserialize<'de>
     {
+        rate_limiter(CexExchange::Kucoin, RateLimitKind::Rest)
+            .acquire()
+            .await;
         let data = web_client.get(&url).send().await?.json().await?;
         Ok(data)
     }
@@ -61,6 +295,11 @@ impl Exchange for Kucoin {
 
     const EXCHANGE: CexExchange = CexExchange::Kucoin;
 
+    fn with_endpoints(mut self, endpoints: ExchangeEndpoints) -> Self {
+        self.endpoints = endpoints;
+        self
+    }
+
     fn remove_bad_pair(&mut self, bad_pair: NormalizedTradingPair) -> bool {
         let pair = bad_pair.try_into().unwrap();
         self.subscriptions.retain_mut(|sub| !sub.remove_pair(&pair));
@@ -68,19 +307,37 @@ impl Exchange for Kucoin {
         self.subscriptions.is_empty()
     }
 
-    async fn make_ws_connection(&self) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, WsError> {
-        let dyn_url = Self::get_websocket_endpoint().await?;
+    async fn make_ws_connection(&self, subscribe_limiter: &TokenBucket) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, WsError> {
+        let is_private = self.subscriptions.iter().any(|sub| sub.is_private());
+
+        let dyn_url = if is_private {
+            if self.subscriptions.iter().any(|sub| !sub.is_private()) {
+                return Err(WsError::AuthenticationError("the private topics can't share a connection with public topics".to_string()))
+            }
+
+            let credentials = self
+                .credentials
+                .as_ref()
+                .ok_or(WsError::AuthenticationError("credentials are required for the private topics".to_string()))?;
+            self.get_private_websocket_endpoint(credentials).await?
+        } else {
+            self.get_websocket_endpoint().await?
+        };
 
         let wss_endpoint = dyn_url
             .get_ws_endpoint()
             .ok_or(WsError::WebInitializationError("no websocket endpoints for Kucoin".to_string()))?;
         let wss_token = dyn_url.get_token();
+        if let Some(interval) = dyn_url.get_ping_interval() {
+            self.ping_interval.store(interval, Ordering::Relaxed);
+        }
 
         let wss_url = format!("{wss_endpoint}?token={wss_token}");
         let (mut ws, _) = tokio_tungstenite::connect_async(&wss_url).await?;
 
         for sub in self.subscriptions.iter() {
             let sub_message = serde_json::to_string(&sub)?;
+            subscribe_limiter.acquire().await;
             ws.send(Message::Text(sub_message)).await?;
         }
 
@@ -94,13 +351,216 @@ impl Exchange for Kucoin {
     ) -> Result<KucoinRestApiResponse, RestApiError> {
         let api_response = match api_channel {
             NormalizedRestApiRequest::AllCurrencies => {
-                KucoinRestApiResponse::Currencies(Self::simple_rest_api_request(web_client, format!("{BASE_REST_API_URL}/api/v3/currencies")).await?)
+                KucoinRestApiResponse::Currencies(Self::simple_rest_api_request(web_client, format!("{}/api/v3/currencies", self.rest_url())).await?)
             }
             NormalizedRestApiRequest::AllInstruments => {
-                KucoinRestApiResponse::Symbols(Self::simple_rest_api_request(web_client, format!("{BASE_REST_API_URL}/api/v2/symbols")).await?)
+                KucoinRestApiResponse::Symbols(Self::simple_rest_api_request(web_client, format!("{}/api/v2/symbols", self.rest_url())).await?)
+            }
+            NormalizedRestApiRequest::Candles { pair, interval, start, end } => {
+                let symbol: KucoinTradingPair = pair
+                    .try_into()
+                    .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+                KucoinRestApiResponse::Candles(
+                    self.get_candles(web_client, symbol, interval, start, end)
+                        .await?
+                )
+            }
+            NormalizedRestApiRequest::RecentTrades { pair, limit } => {
+                let symbol: KucoinTradingPair = pair
+                    .try_into()
+                    .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+                KucoinRestApiResponse::TradeHistories(self.get_trade_histories(web_client, symbol, limit).await?)
+            }
+            NormalizedRestApiRequest::HistoricalTrades { pair, from, to } => {
+                let symbol: KucoinTradingPair = pair
+                    .try_into()
+                    .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+                KucoinRestApiResponse::TradeHistories(
+                    self.get_historical_trades(web_client, symbol, from, to)
+                        .await?
+                )
             }
         };
 
         Ok(api_response)
     }
+
+    async fn order_book_snapshot(self, pair: NormalizedTradingPair) -> Result<Option<KucoinWsMessage>, RestApiError> {
+        let symbol: KucoinTradingPair = pair
+            .try_into()
+            .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+
+        let data = self.get_orderbook(&reqwest::Client::new(), &symbol).await?;
+
+        Ok(Some(KucoinWsMessage::Level2Snapshot(KucoinLevel2Snapshot { symbol, data })))
+    }
+
+    fn update_subscription(&mut self, update: WsSubscriptionUpdate) -> Result<Vec<Message>, WsError> {
+        if self.subscriptions.iter().any(|sub| sub.is_private()) {
+            return Err(WsError::InvalidSubscriptionUpdate("kucoin private topics can't be changed on a running stream".to_string()))
+        }
+
+        let channel: KucoinWsChannel = update.exchange_channel()?;
+        let request = if update.is_subscribe() {
+            let sub = KucoinSubscription::subscribe(&self.subscriptions, channel);
+            self.subscriptions.extend(sub.clone());
+            sub
+        } else {
+            KucoinSubscription::unsubscribe(&mut self.subscriptions, channel)
+        };
+
+        let messages = request
+            .into_iter()
+            .map(|request| serde_json::to_string(&request).map(Message::Text))
+            .collect::<Result<_, _>>()?;
+
+        Ok(messages)
+    }
+
+    /// the id is only echoed back, so it's set once per connection
+    fn heartbeat(&self) -> Option<(Duration, Message)> {
+        let interval = self.ping_interval.load(Ordering::Relaxed);
+        (interval > 0).then(|| {
+            (Duration::from_millis(interval), Message::Text(format!(r#"{{"id":"{}","type":"ping"}}"#, Utc::now().timestamp_millis())))
+        })
+    }
+
+    /// ex: {"id":"1545910590801","type":"pong"}
+    fn is_heartbeat_reply(&self, frame: &str) -> bool {
+        frame.contains("pong")
+            && serde_json::from_str::<serde_json::Value>(frame).is_ok_and(|value| value.get("type").and_then(|t| t.as_str()) == Some("pong"))
+    }
+}
+
+impl ExchangeTrading for Kucoin {
+    async fn place_order(&self, web_client: &reqwest::Client, order: NormalizedOrderRequest) -> Result<NormalizedOrderAck, RestApiError> {
+        let credentials = require_credentials(&self.credentials)?;
+        let symbol: KucoinTradingPair = order
+            .pair
+            .clone()
+            .try_into()
+            .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+        let price = order
+            .limit_price()
+            .map_err(|e| RestApiError::UnsupportedRequest(e.to_string()))?;
+
+        let (time_in_force, post_only) = match order.limit_time_in_force() {
+            Some(NormalizedTimeInForce::GoodTilCanceled) => (Some("GTC"), None),
+            Some(NormalizedTimeInForce::ImmediateOrCancel) => (Some("IOC"), None),
+            Some(NormalizedTimeInForce::FillOrKill) => (Some("FOK"), None),
+            Some(NormalizedTimeInForce::PostOnly) => (Some("GTC"), Some(true)),
+            None => (None, None)
+        };
+        let client_order_id = order
+            .client_order_id
+            .unwrap_or_else(|| format!("{:032x}", rand::thread_rng().gen::<u128>()));
+
+        let request = KucoinOrderRequest {
+            client_order_id: client_order_id.clone(),
+            side: order.side.to_string(),
+            symbol: symbol.clone(),
+            order_type: order.order_type.to_string(),
+            price: price.map(|p| p.to_string()),
+            size: order.amount.to_string(),
+            time_in_force: time_in_force.map(ToString::to_string),
+            post_only
+        };
+
+        let placed: KucoinPlacedOrder = self
+            .signed_rest_api_request(web_client, credentials, reqwest::Method::POST, "/api/v1/orders", Some(serde_json::to_string(&request)?))
+            .await?;
+
+        Ok(NormalizedOrderAck {
+            exchange:        CexExchange::Kucoin,
+            pair:            Some(symbol.normalize()),
+            order_id:        placed.order_id,
+            client_order_id: Some(client_order_id),
+            status:          Some(NormalizedOrderStatus::New)
+        })
+    }
+
+    async fn cancel_order(
+        &self,
+        web_client: &reqwest::Client,
+        pair: NormalizedTradingPair,
+        order_id: String
+    ) -> Result<NormalizedOrderAck, RestApiError> {
+        let credentials = require_credentials(&self.credentials)?;
+        let symbol: KucoinTradingPair = pair
+            .try_into()
+            .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+
+        let canceled: KucoinCanceledOrders = self
+            .signed_rest_api_request(web_client, credentials, reqwest::Method::DELETE, &format!("/api/v1/orders/{order_id}"), None)
+            .await?;
+
+        Ok(NormalizedOrderAck {
+            exchange:        CexExchange::Kucoin,
+            pair:            Some(symbol.normalize()),
+            order_id:        canceled.order_ids.into_iter().next().unwrap_or(order_id),
+            client_order_id: None,
+            status:          Some(NormalizedOrderStatus::Canceled)
+        })
+    }
+
+    async fn cancel_all(&self, web_client: &reqwest::Client, pair: Option<NormalizedTradingPair>) -> Result<Vec<NormalizedOrderAck>, RestApiError> {
+        let credentials = require_credentials(&self.credentials)?;
+        let symbol: Option<KucoinTradingPair> = pair
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+
+        let endpoint = match &symbol {
+            Some(s) => format!("/api/v1/orders?tradeType=TRADE&symbol={}", s.0),
+            None => "/api/v1/orders?tradeType=TRADE".to_string()
+        };
+        let canceled: KucoinCanceledOrders = self
+            .signed_rest_api_request(web_client, credentials, reqwest::Method::DELETE, &endpoint, None)
+            .await?;
+
+        Ok(canceled
+            .order_ids
+            .into_iter()
+            .map(|order_id| NormalizedOrderAck {
+                exchange: CexExchange::Kucoin,
+                pair: symbol.as_ref().map(|s| s.normalize()),
+                order_id,
+                client_order_id: None,
+                status: Some(NormalizedOrderStatus::Canceled)
+            })
+            .collect())
+    }
+
+    async fn get_open_orders(
+        &self,
+        web_client: &reqwest::Client,
+        pair: Option<NormalizedTradingPair>
+    ) -> Result<Vec<NormalizedOrderUpdate>, RestApiError> {
+        let credentials = require_credentials(&self.credentials)?;
+        let symbol: Option<KucoinTradingPair> = pair
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+
+        let mut orders = Vec::new();
+        let mut current_page = 1;
+        loop {
+            let mut endpoint = format!("/api/v1/orders?status=active&tradeType=TRADE&currentPage={current_page}&pageSize={MAX_OPEN_ORDERS_PER_PAGE}");
+            if let Some(s) = &symbol {
+                endpoint.push_str(&format!("&symbol={}", s.0));
+            }
+
+            let page: KucoinOpenOrdersPage = self
+                .signed_rest_api_request(web_client, credentials, reqwest::Method::GET, &endpoint, None)
+                .await?;
+            orders.extend(page.items.into_iter().map(|o| o.normalize()));
+
+            if page.current_page >= page.total_page {
+                break
+            }
+            current_page += 1;
+        }
+
+        Ok(orders)
+    }
 }
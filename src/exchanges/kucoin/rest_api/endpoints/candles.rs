@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    kucoin::{ws::channels::KucoinCandleData, KucoinTradingPair},
+    normalized::{
+        rest_api::NormalizedRestApiDataTypes,
+        types::{NormalizedCandle, NormalizedCandleInterval}
+    }
+};
+
+/// candles of a single pair & interval from `/api/v1/market/candles`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct KucoinAllCandles {
+    pub symbol:   KucoinTradingPair,
+    pub interval: NormalizedCandleInterval,
+    pub candles:  Vec<KucoinCandleData>
+}
+
+impl KucoinAllCandles {
+    pub fn normalize(self) -> Vec<NormalizedCandle> {
+        self.candles
+            .into_iter()
+            .map(|c| c.normalize(&self.symbol, self.interval, None))
+            .collect()
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for KucoinAllCandles {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match other {
+            NormalizedRestApiDataTypes::Candles(other_candles) => self.clone().normalize() == *other_candles,
+            _ => false
+        }
+    }
+}
+
+/// a single page of candles (newest first)
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct KucoinCandlesPage {
+    pub(crate) data: Vec<KucoinCandleData>
+}
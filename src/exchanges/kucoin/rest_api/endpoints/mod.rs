@@ -3,3 +3,12 @@ pub use currencies::*;
 
 mod symbols;
 pub use symbols::*;
+
+mod candles;
+pub use candles::*;
+
+mod trade_histories;
+pub use trade_histories::*;
+
+mod orders;
+pub use orders::*;
@@ -0,0 +1,107 @@
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr, NoneAsEmptyString};
+
+use crate::{
+    kucoin::KucoinTradingPair,
+    normalized::types::{NormalizedNumber, NormalizedOrderSide, NormalizedOrderStatus, NormalizedOrderUpdate},
+    CexExchange
+};
+
+/// response of the private endpoints, `code` is `200000` on success
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct KucoinTradeResponse<T> {
+    pub code: String,
+    pub data: Option<T>,
+    pub msg:  Option<String>
+}
+
+/// body of `POST /api/v1/orders`
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct KucoinOrderRequest {
+    /// required by kucoin
+    #[serde(rename = "clientOid")]
+    pub client_order_id: String,
+    pub side:            String,
+    pub symbol:          KucoinTradingPair,
+    #[serde(rename = "type")]
+    pub order_type:      String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price:           Option<String>,
+    pub size:            String,
+    #[serde(rename = "timeInForce", skip_serializing_if = "Option::is_none")]
+    pub time_in_force:   Option<String>,
+    #[serde(rename = "postOnly", skip_serializing_if = "Option::is_none")]
+    pub post_only:       Option<bool>
+}
+
+/// data of `POST /api/v1/orders`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct KucoinPlacedOrder {
+    #[serde(rename = "orderId")]
+    pub order_id: String
+}
+
+/// data of `DELETE /api/v1/orders/{orderId}` & `DELETE /api/v1/orders`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct KucoinCanceledOrders {
+    #[serde(rename = "cancelledOrderIds")]
+    pub order_ids: Vec<String>
+}
+
+/// data of `GET /api/v1/orders?status=active`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct KucoinOpenOrdersPage {
+    #[serde(rename = "currentPage")]
+    pub current_page: u64,
+    #[serde(rename = "totalPage")]
+    pub total_page:   u64,
+    pub items:        Vec<KucoinOpenOrder>
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct KucoinOpenOrder {
+    pub id:              String,
+    pub symbol:          KucoinTradingPair,
+    #[serde(rename = "type")]
+    pub order_type:      String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub side:            NormalizedOrderSide,
+    /// `0` for market orders
+    #[serde_as(as = "DisplayFromStr")]
+    pub price:           NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub size:            NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "dealSize")]
+    pub filled_size:     NormalizedNumber,
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(rename = "clientOid", default)]
+    pub client_order_id: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at:      u64
+}
+
+impl KucoinOpenOrder {
+    pub fn normalize(self) -> NormalizedOrderUpdate {
+        NormalizedOrderUpdate {
+            exchange:        CexExchange::Kucoin,
+            pair:            self.symbol.normalize(),
+            order_id:        self.id,
+            client_order_id: self.client_order_id,
+            side:            self.side,
+            order_type:      self.order_type,
+            status:          if self.filled_size > NormalizedNumber::default() {
+                NormalizedOrderStatus::PartiallyFilled
+            } else {
+                NormalizedOrderStatus::New
+            },
+            price:           (self.price != NormalizedNumber::default()).then_some(self.price),
+            amount:          Some(self.size),
+            filled_amount:   Some(self.filled_size),
+            time:            DateTime::from_timestamp_millis(self.created_at as i64).unwrap(),
+            last_fill:       None
+        }
+    }
+}
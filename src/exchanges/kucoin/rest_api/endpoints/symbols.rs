@@ -105,7 +105,11 @@ impl KucoinSymbol {
             quote_asset_symbol: self.quote_currency.clone(),
             active:             self.enable_trading,
 
-            futures_expiry: None
+            futures_expiry: None,
+
+            option_strike: None,
+
+            option_type: None
         }];
 
         if self.is_margin_enabled {
@@ -117,7 +121,11 @@ impl KucoinSymbol {
                 quote_asset_symbol: self.quote_currency.clone(),
                 active:             self.enable_trading,
 
-                futures_expiry: None
+                futures_expiry: None,
+
+                option_strike: None,
+
+                option_type: None
             });
         }
 
@@ -133,7 +141,9 @@ impl PartialEq<NormalizedInstrument> for KucoinSymbol {
             && other.base_asset_symbol == *self.base_currency
             && other.quote_asset_symbol == *self.quote_currency
             && other.active == self.enable_trading
-            && other.futures_expiry.is_none();
+            && other.futures_expiry.is_none()
+            && other.option_strike.is_none()
+            && other.option_type.is_none();
 
         if !equals {
             warn!(target: "cex-exchanges::kucoin", "kucoin symbol: {:?}", self);
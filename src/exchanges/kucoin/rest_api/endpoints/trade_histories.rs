@@ -0,0 +1,74 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+
+use crate::{
+    clients::ws::received_at,
+    kucoin::KucoinTradingPair,
+    normalized::{
+        rest_api::NormalizedRestApiDataTypes,
+        types::{NormalizedNumber, NormalizedTrade, TradeSide}
+    },
+    CexExchange
+};
+
+/// trades of a single pair from `/api/v1/market/histories`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct KucoinTradeHistories {
+    pub symbol: KucoinTradingPair,
+    pub trades: Vec<KucoinTradeHistory>
+}
+
+impl KucoinTradeHistories {
+    pub fn normalize(self) -> Vec<NormalizedTrade> {
+        self.trades
+            .into_iter()
+            .map(|t| t.normalize(&self.symbol))
+            .collect()
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for KucoinTradeHistories {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match other {
+            NormalizedRestApiDataTypes::Trades(other_trades) => self.clone().normalize() == *other_trades,
+            _ => false
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct KucoinTradeHistory {
+    #[serde_as(as = "DisplayFromStr")]
+    pub sequence:    u64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub price:       NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub size:        NormalizedNumber,
+    pub side:        String,
+    /// nanoseconds
+    pub time:        u64,
+    #[serde(default = "received_at")]
+    pub received_at: DateTime<Utc>
+}
+
+impl KucoinTradeHistory {
+    pub fn normalize(self, symbol: &KucoinTradingPair) -> NormalizedTrade {
+        NormalizedTrade {
+            exchange:      CexExchange::Kucoin,
+            pair:          symbol.normalize(),
+            exchange_time: Some(DateTime::<Utc>::from_timestamp_nanos(self.time as i64)),
+            received_at:   self.received_at,
+            side:          TradeSide::from_taker_side(&self.side),
+            price:         self.price,
+            amount:        self.size,
+            trade_id:      Some(self.sequence.to_string())
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct KucoinTradeHistoriesPage {
+    pub(crate) data: Vec<KucoinTradeHistory>
+}
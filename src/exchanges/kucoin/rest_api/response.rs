@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use super::{KucoinAllCurrencies, KucoinAllSymbols, KucoinCurrency, KucoinSymbol};
+use super::{KucoinAllCandles, KucoinAllCurrencies, KucoinAllSymbols, KucoinCurrency, KucoinSymbol, KucoinTradeHistories};
 use crate::exchanges::normalized::rest_api::NormalizedRestApiDataTypes;
 
 #[serde_with::serde_as]
@@ -8,14 +8,18 @@ use crate::exchanges::normalized::rest_api::NormalizedRestApiDataTypes;
 #[serde(rename_all = "snake_case", tag = "type")]
 pub enum KucoinRestApiResponse {
     Currencies(KucoinAllCurrencies),
-    Symbols(KucoinAllSymbols)
+    Symbols(KucoinAllSymbols),
+    Candles(KucoinAllCandles),
+    TradeHistories(KucoinTradeHistories)
 }
 
 impl KucoinRestApiResponse {
     pub fn normalize(self) -> NormalizedRestApiDataTypes {
         match self {
             KucoinRestApiResponse::Currencies(v) => NormalizedRestApiDataTypes::AllCurrencies(v.normalize()),
-            KucoinRestApiResponse::Symbols(v) => NormalizedRestApiDataTypes::AllInstruments(v.normalize())
+            KucoinRestApiResponse::Symbols(v) => NormalizedRestApiDataTypes::AllInstruments(v.normalize()),
+            KucoinRestApiResponse::Candles(v) => NormalizedRestApiDataTypes::Candles(v.normalize()),
+            KucoinRestApiResponse::TradeHistories(v) => NormalizedRestApiDataTypes::Trades(v.normalize())
         }
     }
 
@@ -49,7 +53,9 @@ impl PartialEq<NormalizedRestApiDataTypes> for KucoinRestApiResponse {
     fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
         match self {
             KucoinRestApiResponse::Currencies(vals) => vals == other,
-            KucoinRestApiResponse::Symbols(vals) => vals == other
+            KucoinRestApiResponse::Symbols(vals) => vals == other,
+            KucoinRestApiResponse::Candles(vals) => vals == other,
+            KucoinRestApiResponse::TradeHistories(vals) => vals == other
         }
     }
 }
@@ -3,7 +3,7 @@ use super::{
     KucoinMultiSubscription
 };
 use crate::{
-    clients::{rest_api::ExchangeApi, ws::MutliWsStreamBuilder},
+    clients::{rest_api::ExchangeApi, ws::MutliWsStreamBuilder, ExchangeCredentials},
     kucoin::Kucoin,
     normalized::ws::NormalizedWsChannels
 };
@@ -15,7 +15,9 @@ const MAX_KUCOIN_WS_CONNS_PER_STREAM: usize = 1024;
 
 #[derive(Debug, Clone, Default)]
 pub struct KucoinWsBuilder {
-    pub channels: Vec<KucoinWsChannel>
+    pub channels:    Vec<KucoinWsChannel>,
+    /// required for [KucoinWsChannel::Account]
+    pub credentials: Option<ExchangeCredentials>
 }
 
 impl KucoinWsBuilder {
@@ -25,16 +27,52 @@ impl KucoinWsBuilder {
         self
     }
 
+    /// sets the credentials used to get the private ws token
+    pub fn with_credentials(mut self, credentials: ExchangeCredentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    fn new_exchange(&self, subscription: KucoinMultiSubscription) -> Kucoin {
+        let exchange = Kucoin::new_ws_subscription(subscription);
+        match self.credentials.clone() {
+            Some(credentials) => exchange.with_credentials(credentials),
+            None => exchange
+        }
+    }
+
+    /// the private topics need a different token, so they are split out onto
+    /// their own stream
+    fn split_private(&self) -> (Vec<KucoinWsChannel>, Option<Kucoin>) {
+        let (private, public): (Vec<_>, Vec<_>) = self
+            .channels
+            .iter()
+            .cloned()
+            .partition(|ch| ch.is_private());
+        if private.is_empty() {
+            return (public, None)
+        }
+
+        let mut subscription = KucoinMultiSubscription::default();
+        private
+            .into_iter()
+            .for_each(|ch| subscription.add_channel(ch));
+
+        (public, Some(self.new_exchange(subscription)))
+    }
+
     /// builds a single ws instance of [Kucoin], handling all channels on 1
     /// stream
+    ///
+    /// the private topics can't share the stream with public channels
     pub fn build_single(self) -> Kucoin {
         let mut subscription = KucoinMultiSubscription::default();
 
         self.channels
-            .into_iter()
-            .for_each(|c| subscription.add_channel(c));
+            .iter()
+            .for_each(|c| subscription.add_channel(c.clone()));
 
-        Kucoin::new_ws_subscription(subscription)
+        self.new_exchange(subscription)
     }
 
     /// builds many ws instances of the [Kucoin] as the inner streams of
@@ -43,9 +81,10 @@ impl KucoinWsBuilder {
     ///
     /// WARNING: too many channels may break the stream
     pub fn build_many_distributed(self) -> eyre::Result<MutliWsStreamBuilder<Kucoin>> {
-        let stream_size = if self.channels.len() <= MAX_KUCOIN_STREAMS { 1 } else { self.channels.len() / MAX_KUCOIN_STREAMS };
+        let (channels, private_exchange) = self.split_private();
+        let stream_size = if channels.len() <= MAX_KUCOIN_STREAMS { 1 } else { channels.len() / MAX_KUCOIN_STREAMS };
 
-        let chunks = self.channels.chunks(stream_size).collect::<Vec<_>>();
+        let chunks = channels.chunks(stream_size).collect::<Vec<_>>();
 
         let split_exchange = chunks
             .into_iter()
@@ -54,8 +93,9 @@ impl KucoinWsBuilder {
                 chk.iter()
                     .for_each(|ch| subscription.add_channel(ch.clone()));
 
-                Kucoin::new_ws_subscription(subscription)
+                self.new_exchange(subscription)
             })
+            .chain(private_exchange)
             .collect();
 
         Ok(MutliWsStreamBuilder::new(split_exchange))
@@ -65,8 +105,8 @@ impl KucoinWsBuilder {
     /// [MutliWsStreamBuilder], splitting the channels into different streams,
     /// each of size 1024
     pub fn build_many_packed(self, connections_per_stream: Option<usize>) -> eyre::Result<MutliWsStreamBuilder<Kucoin>> {
-        let chunks = self
-            .channels
+        let (channels, private_exchange) = self.split_private();
+        let chunks = channels
             .chunks(connections_per_stream.unwrap_or(MAX_KUCOIN_WS_CONNS_PER_STREAM))
             .collect::<Vec<_>>();
 
@@ -77,8 +117,9 @@ impl KucoinWsBuilder {
                 chk.iter()
                     .for_each(|ch| subscription.add_channel(ch.clone()));
 
-                Kucoin::new_ws_subscription(subscription)
+                self.new_exchange(subscription)
             })
+            .chain(private_exchange)
             .collect();
 
         Ok(MutliWsStreamBuilder::new(split_exchange))
@@ -90,12 +131,12 @@ impl KucoinWsBuilder {
 
         let all_streams = this
             .channels
-            .into_iter()
+            .iter()
             .map(|ch| {
                 let mut subscription = KucoinMultiSubscription::default();
-                subscription.add_channel(ch);
+                subscription.add_channel(ch.clone());
 
-                Kucoin::new_ws_subscription(subscription)
+                this.new_exchange(subscription)
             })
             .collect::<Vec<_>>();
 
@@ -122,21 +163,28 @@ impl KucoinWsBuilder {
         chunks.into_iter().for_each(|chk| {
             let all_channels = channels
                 .iter()
-                .map(|ch| match ch {
-                    KucoinWsChannelKind::Match => KucoinWsChannel::Match(chk.to_vec()),
-                    KucoinWsChannelKind::Ticker => KucoinWsChannel::Ticker(chk.to_vec())
+                .filter_map(|ch| match ch {
+                    KucoinWsChannelKind::Match => Some(KucoinWsChannel::Match(chk.to_vec())),
+                    KucoinWsChannelKind::Ticker => Some(KucoinWsChannel::Ticker(chk.to_vec())),
+                    KucoinWsChannelKind::Level2 => Some(KucoinWsChannel::Level2(chk.to_vec())),
+                    KucoinWsChannelKind::Candles(interval) => Some(KucoinWsChannel::Candles { pairs: chk.to_vec(), interval: *interval }),
+                    KucoinWsChannelKind::Account => None
                 })
                 .collect::<Vec<_>>();
 
             this.channels.extend(all_channels);
         });
 
+        if channels.contains(&KucoinWsChannelKind::Account) {
+            this.channels.push(KucoinWsChannel::Account);
+        }
+
         Ok(this)
     }
 
     /// makes the builder from the normalized builder's map
-    pub(crate) fn make_from_normalized_map(map: Vec<NormalizedWsChannels>) -> eyre::Result<Self> {
-        let mut this = Self { channels: Vec::new() };
+    pub(crate) fn make_from_normalized_map(map: Vec<NormalizedWsChannels>, credentials: Option<ExchangeCredentials>) -> eyre::Result<Self> {
+        let mut this = Self { channels: Vec::new(), credentials };
 
         map.into_iter().try_for_each(|channel| {
             let this_channel: KucoinWsChannel = channel.try_into()?;
@@ -0,0 +1,177 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use tracing::warn;
+
+use crate::{
+    kucoin::KucoinTradingPair,
+    normalized::types::{
+        NormalizedBalanceUpdate, NormalizedFill, NormalizedNumber, NormalizedOrderSide, NormalizedOrderStatus, NormalizedOrderUpdate
+    },
+    CexExchange
+};
+
+/// `/account/balance` topic
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct KucoinBalance {
+    pub topic:   String,
+    pub subject: String,
+    pub data:    KucoinBalanceData
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct KucoinBalanceData {
+    pub currency:  String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub total:     NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub available: NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub hold:      NormalizedNumber,
+    #[serde(rename = "relationEvent")]
+    pub event:     String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub time:      u64
+}
+
+impl KucoinBalance {
+    pub fn normalize(self) -> NormalizedBalanceUpdate {
+        NormalizedBalanceUpdate {
+            exchange:  CexExchange::Kucoin,
+            symbol:    self.data.currency,
+            available: self.data.available,
+            locked:    self.data.hold,
+            time:      DateTime::from_timestamp_millis(self.data.time as i64).unwrap()
+        }
+    }
+}
+
+impl PartialEq<Vec<NormalizedBalanceUpdate>> for KucoinBalance {
+    fn eq(&self, other: &Vec<NormalizedBalanceUpdate>) -> bool {
+        let equals = vec![self.clone().normalize()] == *other;
+
+        if !equals {
+            warn!(target: "cex-exchanges::kucoin", "kucoin balance: {:?}", self);
+            warn!(target: "cex-exchanges::kucoin", "normalized balance updates: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+/// `/spotMarket/tradeOrdersV2` topic
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct KucoinOrderChange {
+    pub topic:   String,
+    pub subject: String,
+    pub data:    KucoinOrderChangeData
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct KucoinOrderChangeData {
+    pub symbol:          KucoinTradingPair,
+    #[serde(rename = "orderId")]
+    pub order_id:        String,
+    #[serde(rename = "clientOid")]
+    pub client_order_id: Option<String>,
+    #[serde_as(as = "DisplayFromStr")]
+    pub side:            NormalizedOrderSide,
+    #[serde(rename = "orderType")]
+    pub order_type:      String,
+    /// received, open, match, update, filled, canceled
+    #[serde(rename = "type")]
+    pub kind:            String,
+    /// new, open, match, done
+    pub status:          String,
+    /// not set for market orders
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub price:           Option<NormalizedNumber>,
+    /// not set for market orders placed with `funds`
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(default)]
+    pub size:            Option<NormalizedNumber>,
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(rename = "filledSize", default)]
+    pub filled_size:     Option<NormalizedNumber>,
+    /// nanoseconds
+    pub ts:              u64,
+    /// `match` only
+    #[serde(rename = "tradeId", default)]
+    pub trade_id:        Option<String>,
+    /// `match` only
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(rename = "matchPrice", default)]
+    pub match_price:     Option<NormalizedNumber>,
+    /// `match` only
+    #[serde_as(as = "Option<DisplayFromStr>")]
+    #[serde(rename = "matchSize", default)]
+    pub match_size:      Option<NormalizedNumber>,
+    /// `match` only - maker or taker
+    #[serde(default)]
+    pub liquidity:       Option<String>
+}
+
+impl KucoinOrderChange {
+    pub fn normalize(self) -> NormalizedOrderUpdate {
+        let data = self.data;
+        let status = match data.kind.as_str() {
+            "filled" => NormalizedOrderStatus::Filled,
+            "canceled" => NormalizedOrderStatus::Canceled,
+            _ if data.filled_size.unwrap_or_default() > NormalizedNumber::default() => NormalizedOrderStatus::PartiallyFilled,
+            _ => NormalizedOrderStatus::New
+        };
+
+        NormalizedOrderUpdate {
+            exchange: CexExchange::Kucoin,
+            pair: data.symbol.normalize(),
+            order_id: data.order_id.clone(),
+            client_order_id: data.client_order_id.clone(),
+            side: data.side,
+            order_type: data.order_type.clone(),
+            status,
+            price: data.price,
+            amount: data.size,
+            filled_amount: data.filled_size,
+            time: DateTime::<Utc>::from_timestamp_nanos(data.ts as i64),
+            last_fill: data.fill()
+        }
+    }
+}
+
+impl KucoinOrderChangeData {
+    fn fill(&self) -> Option<NormalizedFill> {
+        if self.kind != "match" {
+            return None
+        }
+
+        Some(NormalizedFill {
+            exchange:   CexExchange::Kucoin,
+            pair:       self.symbol.normalize(),
+            order_id:   self.order_id.clone(),
+            trade_id:   self.trade_id.clone()?,
+            side:       self.side,
+            price:      self.match_price?,
+            amount:     self.match_size?,
+            fee:        None,
+            fee_symbol: None,
+            is_maker:   self.liquidity.as_ref().map(|l| l == "maker"),
+            time:       DateTime::<Utc>::from_timestamp_nanos(self.ts as i64)
+        })
+    }
+}
+
+impl PartialEq<NormalizedOrderUpdate> for KucoinOrderChange {
+    fn eq(&self, other: &NormalizedOrderUpdate) -> bool {
+        let equals = self.clone().normalize() == *other;
+
+        if !equals {
+            warn!(target: "cex-exchanges::kucoin", "kucoin order change: {:?}", self);
+            warn!(target: "cex-exchanges::kucoin", "normalized order update: {:?}", other);
+        }
+
+        equals
+    }
+}
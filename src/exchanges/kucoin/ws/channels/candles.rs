@@ -0,0 +1,175 @@
+use std::{fmt::Display, str::FromStr};
+
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use tracing::warn;
+
+use crate::{
+    kucoin::KucoinTradingPair,
+    normalized::types::{NormalizedCandle, NormalizedCandleInterval, NormalizedNumber},
+    CexExchange
+};
+
+/// candles channel (`/market/candles:<symbol>_<type>`), the interval is taken
+/// from the topic
+#[serde_as]
+#[derive(Debug, Serialize, Clone, PartialEq, PartialOrd)]
+pub struct KucoinCandles {
+    #[serde(rename = "type")]
+    pub kind:     String,
+    pub topic:    String,
+    pub subject:  String,
+    pub interval: KucoinCandleInterval,
+    pub data:     KucoinCandlesInner
+}
+
+impl KucoinCandles {
+    pub fn normalize(self) -> NormalizedCandle {
+        self.data
+            .candles
+            .normalize(&self.data.symbol, self.interval.0, None)
+    }
+}
+
+impl<'de> Deserialize<'de> for KucoinCandles {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        #[derive(Deserialize)]
+        struct KucoinCandlesUtil {
+            #[serde(rename = "type")]
+            kind:    String,
+            topic:   String,
+            subject: String,
+            data:    KucoinCandlesInner
+        }
+
+        let util = KucoinCandlesUtil::deserialize(deserializer)?;
+        let interval = util
+            .topic
+            .rsplit('_')
+            .next()
+            .unwrap_or_default()
+            .parse()
+            .map_err(serde::de::Error::custom)?;
+
+        Ok(Self { kind: util.kind, topic: util.topic, subject: util.subject, interval, data: util.data })
+    }
+}
+
+impl PartialEq<NormalizedCandle> for KucoinCandles {
+    fn eq(&self, other: &NormalizedCandle) -> bool {
+        let equals = *other
+            == self
+                .data
+                .candles
+                .clone()
+                .normalize(&self.data.symbol, self.interval.0, None);
+
+        if !equals {
+            warn!(target: "cex-exchanges::kucoin", "kucoin candles: {:?}", self);
+            warn!(target: "cex-exchanges::kucoin", "normalized candle: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
+pub struct KucoinCandlesInner {
+    pub symbol:  KucoinTradingPair,
+    pub candles: KucoinCandleData,
+    /// nanoseconds
+    pub time:    u64
+}
+
+/// a single candle, sent as an array of strings over both the ws & rest api
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
+pub struct KucoinCandleData {
+    /// seconds
+    #[serde_as(as = "DisplayFromStr")]
+    pub start_time: u64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub open:       NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub close:      NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub high:       NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub low:        NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub volume:     NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub turnover:   NormalizedNumber
+}
+
+impl KucoinCandleData {
+    pub fn normalize(self, pair: &KucoinTradingPair, interval: NormalizedCandleInterval, closed: Option<bool>) -> NormalizedCandle {
+        let start_time = DateTime::from_timestamp(self.start_time as i64, 0).unwrap();
+
+        NormalizedCandle {
+            exchange: CexExchange::Kucoin,
+            pair: pair.normalize(),
+            interval,
+            start_time,
+            end_time: interval.end_time(start_time),
+            open: self.open,
+            high: self.high,
+            low: self.low,
+            close: self.close,
+            volume: self.volume,
+            trade_count: None,
+            closed
+        }
+    }
+}
+
+/// the candle type as used in kucoin's topics & requests
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
+pub struct KucoinCandleInterval(pub NormalizedCandleInterval);
+
+impl Display for KucoinCandleInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            NormalizedCandleInterval::OneMinute => write!(f, "1min"),
+            NormalizedCandleInterval::ThreeMinutes => write!(f, "3min"),
+            NormalizedCandleInterval::FiveMinutes => write!(f, "5min"),
+            NormalizedCandleInterval::FifteenMinutes => write!(f, "15min"),
+            NormalizedCandleInterval::ThirtyMinutes => write!(f, "30min"),
+            NormalizedCandleInterval::OneHour => write!(f, "1hour"),
+            NormalizedCandleInterval::TwoHours => write!(f, "2hour"),
+            NormalizedCandleInterval::FourHours => write!(f, "4hour"),
+            NormalizedCandleInterval::SixHours => write!(f, "6hour"),
+            NormalizedCandleInterval::TwelveHours => write!(f, "12hour"),
+            NormalizedCandleInterval::OneDay => write!(f, "1day"),
+            NormalizedCandleInterval::OneWeek => write!(f, "1week")
+        }
+    }
+}
+
+impl FromStr for KucoinCandleInterval {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let interval = match s {
+            "1min" => NormalizedCandleInterval::OneMinute,
+            "3min" => NormalizedCandleInterval::ThreeMinutes,
+            "5min" => NormalizedCandleInterval::FiveMinutes,
+            "15min" => NormalizedCandleInterval::FifteenMinutes,
+            "30min" => NormalizedCandleInterval::ThirtyMinutes,
+            "1hour" => NormalizedCandleInterval::OneHour,
+            "2hour" => NormalizedCandleInterval::TwoHours,
+            "4hour" => NormalizedCandleInterval::FourHours,
+            "6hour" => NormalizedCandleInterval::SixHours,
+            "12hour" => NormalizedCandleInterval::TwelveHours,
+            "1day" => NormalizedCandleInterval::OneDay,
+            "1week" => NormalizedCandleInterval::OneWeek,
+            _ => return Err(eyre::ErrReport::msg(format!("'{s}' is not a valid kucoin candle type")))
+        };
+
+        Ok(Self(interval))
+    }
+}
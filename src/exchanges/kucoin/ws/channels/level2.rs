@@ -0,0 +1,159 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use tracing::warn;
+
+use crate::{
+    kucoin::KucoinTradingPair,
+    normalized::types::{NormalizedBookLevel, NormalizedNumber, NormalizedOrderBookUpdate, NormalizedOrderBookUpdateKind},
+    CexExchange
+};
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
+pub struct KucoinLevel2 {
+    #[serde(rename = "type")]
+    pub kind:    String,
+    pub topic:   String,
+    pub subject: String,
+    pub data:    KucoinLevel2Inner
+}
+
+impl KucoinLevel2 {
+    pub fn normalize(self) -> NormalizedOrderBookUpdate {
+        NormalizedOrderBookUpdate {
+            exchange:        CexExchange::Kucoin,
+            pair:            self.data.symbol.normalize(),
+            time:            DateTime::<Utc>::from_timestamp_millis(self.data.timestamp as i64).unwrap(),
+            kind:            NormalizedOrderBookUpdateKind::Delta,
+            bids:            KucoinLevel2Changes::normalize_side(self.data.changes.bids),
+            asks:            KucoinLevel2Changes::normalize_side(self.data.changes.asks),
+            first_update_id: Some(self.data.sequence_start),
+            last_update_id:  Some(self.data.sequence_end)
+        }
+    }
+}
+
+impl PartialEq<NormalizedOrderBookUpdate> for KucoinLevel2 {
+    fn eq(&self, other: &NormalizedOrderBookUpdate) -> bool {
+        let equals = other.exchange == CexExchange::Kucoin
+            && other.pair == self.data.symbol.normalize()
+            && other.time == DateTime::<Utc>::from_timestamp_millis(self.data.timestamp as i64).unwrap()
+            && other.kind == NormalizedOrderBookUpdateKind::Delta
+            && other.bids == KucoinLevel2Changes::normalize_side(self.data.changes.bids.clone())
+            && other.asks == KucoinLevel2Changes::normalize_side(self.data.changes.asks.clone())
+            && other.first_update_id == Some(self.data.sequence_start)
+            && other.last_update_id == Some(self.data.sequence_end);
+
+        if !equals {
+            warn!(target: "cex-exchanges::kucoin", "kucoin level2: {:?}", self);
+            warn!(target: "cex-exchanges::kucoin", "normalized order book: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
+pub struct KucoinLevel2Inner {
+    pub changes:        KucoinLevel2Changes,
+    #[serde(rename = "sequenceStart")]
+    pub sequence_start: u64,
+    #[serde(rename = "sequenceEnd")]
+    pub sequence_end:   u64,
+    pub symbol:         KucoinTradingPair,
+    #[serde(rename = "time")]
+    pub timestamp:      u64
+}
+
+/// (price, size, sequence)
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
+pub struct KucoinLevel2Changes {
+    #[serde_as(as = "Vec<(DisplayFromStr, DisplayFromStr, DisplayFromStr)>")]
+    pub asks: Vec<(NormalizedNumber, NormalizedNumber, u64)>,
+    #[serde_as(as = "Vec<(DisplayFromStr, DisplayFromStr, DisplayFromStr)>")]
+    pub bids: Vec<(NormalizedNumber, NormalizedNumber, u64)>
+}
+
+impl KucoinLevel2Changes {
+    /// a price of 0 only carries the sequence number and is skipped
+    fn normalize_side(levels: Vec<(NormalizedNumber, NormalizedNumber, u64)>) -> Vec<NormalizedBookLevel> {
+        levels
+            .into_iter()
+            .filter(|(price, ..)| *price != NormalizedNumber::default())
+            .map(|(price, amount, _)| NormalizedBookLevel::new(price, amount))
+            .collect()
+    }
+}
+
+/// order book snapshot from the rest api (`/market/orderbook/level2_100`),
+/// used to (re)sync the `level2` channel
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
+pub struct KucoinLevel2Snapshot {
+    pub symbol: KucoinTradingPair,
+    pub data:   KucoinOrderBook
+}
+
+impl KucoinLevel2Snapshot {
+    pub fn normalize(self) -> NormalizedOrderBookUpdate {
+        NormalizedOrderBookUpdate {
+            exchange:        CexExchange::Kucoin,
+            pair:            self.symbol.normalize(),
+            time:            DateTime::<Utc>::from_timestamp_millis(self.data.timestamp as i64).unwrap(),
+            kind:            NormalizedOrderBookUpdateKind::Snapshot,
+            bids:            KucoinOrderBook::normalize_side(self.data.bids),
+            asks:            KucoinOrderBook::normalize_side(self.data.asks),
+            first_update_id: None,
+            last_update_id:  Some(self.data.sequence)
+        }
+    }
+}
+
+impl PartialEq<NormalizedOrderBookUpdate> for KucoinLevel2Snapshot {
+    fn eq(&self, other: &NormalizedOrderBookUpdate) -> bool {
+        let equals = other.exchange == CexExchange::Kucoin
+            && other.pair == self.symbol.normalize()
+            && other.time == DateTime::<Utc>::from_timestamp_millis(self.data.timestamp as i64).unwrap()
+            && other.kind == NormalizedOrderBookUpdateKind::Snapshot
+            && other.bids == KucoinOrderBook::normalize_side(self.data.bids.clone())
+            && other.asks == KucoinOrderBook::normalize_side(self.data.asks.clone())
+            && other.last_update_id == Some(self.data.sequence);
+
+        if !equals {
+            warn!(target: "cex-exchanges::kucoin", "kucoin level2 snapshot: {:?}", self);
+            warn!(target: "cex-exchanges::kucoin", "normalized order book: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
+pub struct KucoinOrderBookResponse {
+    pub data: KucoinOrderBook
+}
+
+/// (price, size)
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
+pub struct KucoinOrderBook {
+    #[serde_as(as = "DisplayFromStr")]
+    pub sequence:  u64,
+    #[serde(rename = "time")]
+    pub timestamp: u64,
+    #[serde_as(as = "Vec<(DisplayFromStr, DisplayFromStr)>")]
+    pub bids:      Vec<(NormalizedNumber, NormalizedNumber)>,
+    #[serde_as(as = "Vec<(DisplayFromStr, DisplayFromStr)>")]
+    pub asks:      Vec<(NormalizedNumber, NormalizedNumber)>
+}
+
+impl KucoinOrderBook {
+    fn normalize_side(levels: Vec<(NormalizedNumber, NormalizedNumber)>) -> Vec<NormalizedBookLevel> {
+        levels
+            .into_iter()
+            .map(|(price, amount)| NormalizedBookLevel::new(price, amount))
+            .collect()
+    }
+}
@@ -3,28 +3,36 @@ use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 use tracing::warn;
 
-use crate::{kucoin::KucoinTradingPair, normalized::types::NormalizedTrade, CexExchange};
+use crate::{
+    clients::ws::received_at,
+    kucoin::KucoinTradingPair,
+    normalized::types::{NormalizedNumber, NormalizedTrade, TradeSide},
+    CexExchange
+};
 
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
 pub struct KucoinMatch {
     #[serde(rename = "type")]
-    pub kind:    String,
-    pub topic:   String,
-    pub subject: String,
-    pub data:    KucoinMatchInner
+    pub kind:        String,
+    pub topic:       String,
+    pub subject:     String,
+    pub data:        KucoinMatchInner,
+    #[serde(default = "received_at")]
+    pub received_at: DateTime<Utc>
 }
 
 impl KucoinMatch {
     pub fn normalize(self) -> NormalizedTrade {
         NormalizedTrade {
-            exchange: CexExchange::Kucoin,
-            pair:     self.data.symbol.normalize(),
-            time:     DateTime::<Utc>::from_timestamp_nanos(self.data.timestamp as i64),
-            side:     self.data.side.to_lowercase(),
-            price:    self.data.price,
-            amount:   self.data.size,
-            trade_id: Some(self.data.trade_id)
+            exchange:      CexExchange::Kucoin,
+            pair:          self.data.symbol.normalize(),
+            exchange_time: Some(DateTime::<Utc>::from_timestamp_nanos(self.data.timestamp as i64)),
+            received_at:   self.received_at,
+            side:          TradeSide::from_taker_side(&self.data.side),
+            price:         self.data.price,
+            amount:        self.data.size,
+            trade_id:      Some(self.data.trade_id)
         }
     }
 }
@@ -33,8 +41,9 @@ impl PartialEq<NormalizedTrade> for KucoinMatch {
     fn eq(&self, other: &NormalizedTrade) -> bool {
         let equals = other.exchange == CexExchange::Kucoin
             && other.pair == self.data.symbol.normalize()
-            && other.time == DateTime::<Utc>::from_timestamp_nanos(self.data.timestamp as i64)
-            && other.side == self.data.side.to_lowercase()
+            && other.exchange_time == Some(DateTime::<Utc>::from_timestamp_nanos(self.data.timestamp as i64))
+            && other.received_at == self.received_at
+            && other.side == TradeSide::from_taker_side(&self.data.side)
             && other.price == self.data.price
             && other.amount == self.data.size
             && other.trade_id.as_ref().unwrap() == &self.data.trade_id.to_string();
@@ -58,9 +67,9 @@ pub struct KucoinMatchInner {
     pub symbol:         KucoinTradingPair,
     pub side:           String,
     #[serde_as(as = "DisplayFromStr")]
-    pub price:          f64,
+    pub price:          NormalizedNumber,
     #[serde_as(as = "DisplayFromStr")]
-    pub size:           f64,
+    pub size:           NormalizedNumber,
     #[serde(rename = "tradeId")]
     pub trade_id:       String,
     #[serde(rename = "takerOrderId")]
@@ -6,11 +6,20 @@ pub use matches::*;
 mod ticker;
 pub use ticker::*;
 
+mod level2;
+pub use level2::*;
+
+mod candles;
+pub use candles::*;
+
+mod account;
+pub use account::*;
+
 use crate::{
     exchanges::{
         kucoin::pairs::KucoinTradingPair,
         normalized::{
-            types::{NormalizedTradingPair, RawTradingPair},
+            types::{NormalizedCandleInterval, NormalizedTradingPair, RawTradingPair},
             ws::NormalizedWsChannels
         }
     },
@@ -20,7 +29,15 @@ use crate::{
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum KucoinWsChannel {
     Match(Vec<KucoinTradingPair>),
-    Ticker(Vec<KucoinTradingPair>)
+    Ticker(Vec<KucoinTradingPair>),
+    Level2(Vec<KucoinTradingPair>),
+    Candles {
+        pairs:    Vec<KucoinTradingPair>,
+        interval: NormalizedCandleInterval
+    },
+    /// private `/account/balance` & `/spotMarket/tradeOrdersV2` topics,
+    /// requires credentials
+    Account
 }
 
 impl KucoinWsChannel {
@@ -46,6 +63,28 @@ impl KucoinWsChannel {
         Self::new_from_normalized(normalized, KucoinWsChannel::Ticker(Vec::new()))
     }
 
+    /// builds the level2 order book channel from a vec of raw trading
+    /// pairs return an error if the symbol is incorrectly formatted
+    pub fn new_level2(pairs: Vec<RawTradingPair>) -> eyre::Result<Self> {
+        let normalized = pairs
+            .into_iter()
+            .map(|pair| pair.get_normalized_pair(CexExchange::Kucoin))
+            .collect();
+
+        Self::new_from_normalized(normalized, KucoinWsChannel::Level2(Vec::new()))
+    }
+
+    /// builds the candles channel from a vec of raw trading pairs
+    /// return an error if the symbol is incorrectly formatted
+    pub fn new_candles(pairs: Vec<RawTradingPair>, interval: NormalizedCandleInterval) -> eyre::Result<Self> {
+        let normalized = pairs
+            .into_iter()
+            .map(|pair| pair.get_normalized_pair(CexExchange::Kucoin))
+            .collect();
+
+        Self::new_from_normalized(normalized, KucoinWsChannel::Candles { pairs: Vec::new(), interval })
+    }
+
     pub(crate) fn new_from_normalized(pairs: Vec<NormalizedTradingPair>, kind: KucoinWsChannel) -> eyre::Result<Self> {
         match kind {
             KucoinWsChannel::Match(_) => Ok(KucoinWsChannel::Match(
@@ -59,23 +98,48 @@ impl KucoinWsChannel {
                     .into_iter()
                     .map(TryInto::try_into)
                     .collect::<Result<_, _>>()?
-            ))
+            )),
+            KucoinWsChannel::Level2(_) => Ok(KucoinWsChannel::Level2(
+                pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<_, _>>()?
+            )),
+            KucoinWsChannel::Candles { interval, .. } => Ok(KucoinWsChannel::Candles {
+                pairs: pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<_, _>>()?,
+                interval
+            }),
+            KucoinWsChannel::Account => Ok(KucoinWsChannel::Account)
         }
     }
 
     pub fn count_entries(&self) -> usize {
         match self {
             KucoinWsChannel::Match(vals) => vals.len(),
-            KucoinWsChannel::Ticker(vals) => vals.len()
+            KucoinWsChannel::Ticker(vals) => vals.len(),
+            KucoinWsChannel::Level2(vals) => vals.len(),
+            KucoinWsChannel::Candles { pairs, .. } => pairs.len(),
+            KucoinWsChannel::Account => 1
         }
     }
+
+    /// if the channel requires a private token
+    pub fn is_private(&self) -> bool {
+        matches!(self, KucoinWsChannel::Account)
+    }
 }
 
 impl Display for KucoinWsChannel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             KucoinWsChannel::Match(_) => write!(f, "match"),
-            KucoinWsChannel::Ticker(_) => write!(f, "ticker")
+            KucoinWsChannel::Ticker(_) => write!(f, "ticker"),
+            KucoinWsChannel::Level2(_) => write!(f, "level2"),
+            KucoinWsChannel::Candles { .. } => write!(f, "candles"),
+            KucoinWsChannel::Account => write!(f, "account")
         }
     }
 }
@@ -87,6 +151,9 @@ impl TryFrom<String> for KucoinWsChannel {
         match value.to_lowercase().as_str() {
             "match" => Ok(Self::Match(Vec::new())),
             "ticker" => Ok(Self::Ticker(Vec::new())),
+            "level2" => Ok(Self::Level2(Vec::new())),
+            "candles" => Ok(Self::Candles { pairs: Vec::new(), interval: NormalizedCandleInterval::default() }),
+            "account" => Ok(Self::Account),
             _ => Err(eyre::ErrReport::msg(format!("channel is not valid: {value}")))
         }
     }
@@ -113,6 +180,23 @@ impl TryFrom<NormalizedWsChannels> for KucoinWsChannel {
 
                 Ok(KucoinWsChannel::Ticker(norm_pairs))
             }
+            NormalizedWsChannels::OrderBook { pairs, .. } => {
+                let norm_pairs = pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, Self::Error>>()?;
+
+                Ok(KucoinWsChannel::Level2(norm_pairs))
+            }
+            NormalizedWsChannels::Candles { pairs, interval } => {
+                let norm_pairs = pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, Self::Error>>()?;
+
+                Ok(KucoinWsChannel::Candles { pairs: norm_pairs, interval })
+            }
+            NormalizedWsChannels::Account(_) => Ok(KucoinWsChannel::Account)
         }
     }
 }
@@ -120,14 +204,20 @@ impl TryFrom<NormalizedWsChannels> for KucoinWsChannel {
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum KucoinWsChannelKind {
     Match,
-    Ticker
+    Ticker,
+    Level2,
+    Candles(NormalizedCandleInterval),
+    Account
 }
 
 impl Display for KucoinWsChannelKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             KucoinWsChannelKind::Match => write!(f, "match"),
-            KucoinWsChannelKind::Ticker => write!(f, "ticker")
+            KucoinWsChannelKind::Ticker => write!(f, "ticker"),
+            KucoinWsChannelKind::Level2 => write!(f, "level2"),
+            KucoinWsChannelKind::Candles(_) => write!(f, "candles"),
+            KucoinWsChannelKind::Account => write!(f, "account")
         }
     }
 }
@@ -136,7 +226,10 @@ impl From<&KucoinWsChannel> for KucoinWsChannelKind {
     fn from(value: &KucoinWsChannel) -> Self {
         match value {
             KucoinWsChannel::Match(_) => KucoinWsChannelKind::Match,
-            KucoinWsChannel::Ticker(_) => KucoinWsChannelKind::Ticker
+            KucoinWsChannel::Ticker(_) => KucoinWsChannelKind::Ticker,
+            KucoinWsChannel::Level2(_) => KucoinWsChannelKind::Level2,
+            KucoinWsChannel::Candles { interval, .. } => KucoinWsChannelKind::Candles(*interval),
+            KucoinWsChannel::Account => KucoinWsChannelKind::Account
         }
     }
 }
@@ -3,29 +3,37 @@ use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 use tracing::warn;
 
-use crate::{kucoin::KucoinTradingPair, normalized::types::NormalizedQuote, CexExchange};
+use crate::{
+    clients::ws::received_at,
+    kucoin::KucoinTradingPair,
+    normalized::types::{NormalizedNumber, NormalizedQuote},
+    CexExchange
+};
 
 #[serde_as]
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, PartialOrd)]
 pub struct KucoinTicker {
     #[serde(rename = "type")]
-    pub kind:    String,
-    pub topic:   KucoinTradingPair,
-    pub subject: String,
-    pub data:    KucoinTickerInner
+    pub kind:        String,
+    pub topic:       KucoinTradingPair,
+    pub subject:     String,
+    pub data:        KucoinTickerInner,
+    #[serde(default = "received_at")]
+    pub received_at: DateTime<Utc>
 }
 
 impl KucoinTicker {
     pub fn normalize(self) -> NormalizedQuote {
         NormalizedQuote {
-            exchange:   CexExchange::Kucoin,
-            pair:       self.topic.normalize(),
-            time:       DateTime::<Utc>::from_timestamp_nanos(self.data.timestamp as i64),
-            ask_amount: self.data.best_ask_size,
-            ask_price:  self.data.best_ask_price,
-            bid_amount: self.data.best_bid_size,
-            bid_price:  self.data.best_bid_price,
-            quote_id:   Some(self.data.sequence.to_string())
+            exchange:      CexExchange::Kucoin,
+            pair:          self.topic.normalize(),
+            exchange_time: Some(DateTime::<Utc>::from_timestamp_nanos(self.data.timestamp as i64)),
+            received_at:   self.received_at,
+            ask_amount:    self.data.best_ask_size,
+            ask_price:     self.data.best_ask_price,
+            bid_amount:    self.data.best_bid_size,
+            bid_price:     self.data.best_bid_price,
+            quote_id:      Some(self.data.sequence.to_string())
         }
     }
 }
@@ -34,7 +42,8 @@ impl PartialEq<NormalizedQuote> for KucoinTicker {
     fn eq(&self, other: &NormalizedQuote) -> bool {
         let equals = other.exchange == CexExchange::Kucoin
             && other.pair == self.topic.normalize()
-            && other.time == DateTime::<Utc>::from_timestamp_nanos(self.data.timestamp as i64)
+            && other.exchange_time == Some(DateTime::<Utc>::from_timestamp_nanos(self.data.timestamp as i64))
+            && other.received_at == self.received_at
             && other.ask_amount == self.data.best_ask_size
             && other.ask_price == self.data.best_ask_price
             && other.bid_amount == self.data.best_bid_size
@@ -56,21 +65,21 @@ pub struct KucoinTickerInner {
     #[serde_as(as = "DisplayFromStr")]
     pub sequence:       u64,
     #[serde_as(as = "DisplayFromStr")]
-    pub price:          f64,
+    pub price:          NormalizedNumber,
     #[serde_as(as = "DisplayFromStr")]
-    pub size:           f64,
+    pub size:           NormalizedNumber,
     #[serde(rename = "bestAsk")]
     #[serde_as(as = "DisplayFromStr")]
-    pub best_ask_price: f64,
+    pub best_ask_price: NormalizedNumber,
     #[serde(rename = "bestAskSize")]
     #[serde_as(as = "DisplayFromStr")]
-    pub best_ask_size:  f64,
+    pub best_ask_size:  NormalizedNumber,
     #[serde(rename = "bestBid")]
     #[serde_as(as = "DisplayFromStr")]
-    pub best_bid_price: f64,
+    pub best_bid_price: NormalizedNumber,
     #[serde(rename = "bestBidSize")]
     #[serde_as(as = "DisplayFromStr")]
-    pub best_bid_size:  f64,
+    pub best_bid_size:  NormalizedNumber,
     #[serde(rename = "time")]
     pub timestamp:      u64
 }
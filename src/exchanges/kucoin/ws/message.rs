@@ -2,8 +2,12 @@ use eyre::Ok;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use super::channels::{KucoinMatch, KucoinTicker};
-use crate::{clients::ws::CriticalWsMessage, exchanges::normalized::ws::NormalizedWsDataTypes, CexExchange};
+use super::channels::{KucoinBalance, KucoinCandles, KucoinLevel2, KucoinLevel2Snapshot, KucoinMatch, KucoinOrderChange, KucoinTicker};
+use crate::{
+    clients::ws::{CriticalWsMessage, SequenceKind, WsSequence},
+    exchanges::normalized::ws::NormalizedWsDataTypes,
+    CexExchange
+};
 
 #[serde_with::serde_as]
 #[derive(Debug, Clone, Serialize)]
@@ -11,11 +15,31 @@ use crate::{clients::ws::CriticalWsMessage, exchanges::normalized::ws::Normalize
 pub enum KucoinWsMessage {
     Match(KucoinMatch),
     Ticker(KucoinTicker),
-    SuscriptionResponse { id: String, msg: String }
+    Level2(KucoinLevel2),
+    Candles(KucoinCandles),
+    Balance(KucoinBalance),
+    OrderChange(KucoinOrderChange),
+    /// not sent over the ws, fetched from the rest api to resync the level2
+    /// channel
+    Level2Snapshot(KucoinLevel2Snapshot),
+    SuscriptionResponse {
+        id:  String,
+        msg: String
+    }
 }
 
 impl KucoinWsMessage {
     fn try_deserialize(value: Value) -> eyre::Result<Self> {
+        if value.get("subject").and_then(|s| s.as_str()) == Some("trade.candles.update") {
+            return Ok(Self::Candles(serde_json::from_value(value)?))
+        }
+
+        match value.get("topic").and_then(|t| t.as_str()) {
+            Some("/account/balance") => return Ok(Self::Balance(serde_json::from_value(value)?)),
+            Some("/spotMarket/tradeOrdersV2") => return Ok(Self::OrderChange(serde_json::from_value(value)?)),
+            _ => ()
+        }
+
         let try_match = serde_json::from_value(value.clone());
         if try_match.is_ok() {
             return Ok(Self::Match(try_match?))
@@ -26,6 +50,11 @@ impl KucoinWsMessage {
             return Ok(Self::Ticker(try_ticker?))
         }
 
+        let try_level2 = serde_json::from_value(value.clone());
+        if try_level2.is_ok() {
+            return Ok(Self::Level2(try_level2?))
+        }
+
         let id = value.get("id");
         let msg = value.get("type");
         if let (Some(i), Some(m)) = (id, msg) {
@@ -52,6 +81,11 @@ impl KucoinWsMessage {
         match self {
             KucoinWsMessage::Match(v) => NormalizedWsDataTypes::Trade(v.normalize()),
             KucoinWsMessage::Ticker(v) => NormalizedWsDataTypes::Quote(v.normalize()),
+            KucoinWsMessage::Level2(v) => NormalizedWsDataTypes::OrderBook(v.normalize()),
+            KucoinWsMessage::Level2Snapshot(v) => NormalizedWsDataTypes::OrderBook(v.normalize()),
+            KucoinWsMessage::Candles(v) => NormalizedWsDataTypes::Candle(v.normalize()),
+            KucoinWsMessage::Balance(v) => NormalizedWsDataTypes::BalanceUpdates(vec![v.normalize()]),
+            KucoinWsMessage::OrderChange(v) => NormalizedWsDataTypes::OrderUpdate(v.normalize()),
             KucoinWsMessage::SuscriptionResponse { id, msg } => {
                 NormalizedWsDataTypes::Other { exchange: CexExchange::Kucoin, kind: msg, value: id }
             }
@@ -64,6 +98,11 @@ impl PartialEq<NormalizedWsDataTypes> for KucoinWsMessage {
         match (self, other) {
             (KucoinWsMessage::Match(this), NormalizedWsDataTypes::Trade(that)) => this == that,
             (KucoinWsMessage::Ticker(this), NormalizedWsDataTypes::Quote(that)) => this == that,
+            (KucoinWsMessage::Level2(this), NormalizedWsDataTypes::OrderBook(that)) => this == that,
+            (KucoinWsMessage::Level2Snapshot(this), NormalizedWsDataTypes::OrderBook(that)) => this == that,
+            (KucoinWsMessage::Candles(this), NormalizedWsDataTypes::Candle(that)) => this == that,
+            (KucoinWsMessage::Balance(this), NormalizedWsDataTypes::BalanceUpdates(that)) => this == that,
+            (KucoinWsMessage::OrderChange(this), NormalizedWsDataTypes::OrderUpdate(that)) => this == that,
             (KucoinWsMessage::SuscriptionResponse { .. }, NormalizedWsDataTypes::Other { .. }) => true,
             _ => false
         }
@@ -72,4 +111,22 @@ impl PartialEq<NormalizedWsDataTypes> for KucoinWsMessage {
 
 impl CriticalWsMessage for KucoinWsMessage {
     fn make_critical(&mut self, _msg: String) {}
+
+    fn sequence(&self) -> Option<WsSequence> {
+        match self {
+            KucoinWsMessage::Level2(v) => Some(WsSequence {
+                channel: "level2",
+                pair:    v.data.symbol.normalize(),
+                kind:    SequenceKind::Contiguous { first: v.data.sequence_start, last: v.data.sequence_end },
+                resync:  true
+            }),
+            KucoinWsMessage::Level2Snapshot(v) => Some(WsSequence {
+                channel: "level2",
+                pair:    v.symbol.normalize(),
+                kind:    SequenceKind::Snapshot(Some(v.data.sequence)),
+                resync:  true
+            }),
+            _ => None
+        }
+    }
 }
@@ -1,14 +1,18 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use rand::Rng;
 use serde::{Deserialize, Serialize};
 
-use super::channels::{KucoinWsChannel, KucoinWsChannelKind};
+use super::channels::{KucoinCandleInterval, KucoinWsChannel, KucoinWsChannelKind};
 use crate::kucoin::KucoinTradingPair;
 
+const PRIVATE_ACCOUNT_TOPICS: [&str; 2] = ["/account/balance", "/spotMarket/tradeOrdersV2"];
+
 #[derive(Debug, Default, Clone)]
 pub struct KucoinMultiSubscription {
-    subscriptions: HashMap<KucoinWsChannelKind, KucoinSubscription>
+    subscriptions: HashMap<KucoinWsChannelKind, KucoinSubscription>,
+    /// the private topics aren't built from pairs
+    private:       Vec<KucoinSubscription>
 }
 
 impl KucoinMultiSubscription {
@@ -23,12 +27,33 @@ impl KucoinMultiSubscription {
                 .subscriptions
                 .entry(KucoinWsChannelKind::Ticker)
                 .or_insert(KucoinSubscription::new(KucoinWsChannelKind::Ticker))
-                .add_pairs(pairs)
+                .add_pairs(pairs),
+            KucoinWsChannel::Level2(pairs) => self
+                .subscriptions
+                .entry(KucoinWsChannelKind::Level2)
+                .or_insert(KucoinSubscription::new(KucoinWsChannelKind::Level2))
+                .add_pairs(pairs),
+            KucoinWsChannel::Candles { pairs, interval } => self
+                .subscriptions
+                .entry(KucoinWsChannelKind::Candles(interval))
+                .or_insert(KucoinSubscription::new(KucoinWsChannelKind::Candles(interval)))
+                .add_pairs(pairs),
+            KucoinWsChannel::Account => {
+                if self.private.is_empty() {
+                    self.private = PRIVATE_ACCOUNT_TOPICS
+                        .into_iter()
+                        .map(KucoinSubscription::new_private)
+                        .collect();
+                }
+            }
         }
     }
 
     pub fn all_subscriptions(self) -> Vec<KucoinSubscription> {
-        self.subscriptions.into_values().collect()
+        self.subscriptions
+            .into_values()
+            .chain(self.private)
+            .collect()
     }
 }
 
@@ -57,6 +82,82 @@ impl KucoinSubscription {
         }
     }
 
+    /// subscription to a private topic (ex: `/account/balance`)
+    pub fn new_private(topic: &str) -> Self {
+        let mut rng = rand::thread_rng();
+
+        KucoinSubscription {
+            method:          "subscribe".to_string(),
+            id:              rng.gen(),
+            topic:           KucoinSubscriptionInner {
+                channel:       KucoinWsChannelKind::Account,
+                trading_pairs: Vec::new(),
+                private_topic: Some(topic.to_string())
+            },
+            private_channel: true,
+            response:        false
+        }
+    }
+
+    pub fn is_private(&self) -> bool {
+        self.private_channel
+    }
+
+    /// the subscription to the pairs of the channel that aren't in `current`
+    /// yet, `None` if there are none
+    pub(crate) fn subscribe(current: &[KucoinSubscription], channel: KucoinWsChannel) -> Option<Self> {
+        let (kind, pairs) = Self::channel_pairs(channel);
+        let new = pairs
+            .into_iter()
+            .filter(|pair| !current.iter().any(|sub| sub.has_pair(kind, pair)))
+            .collect::<HashSet<_>>();
+
+        (!new.is_empty()).then(|| {
+            let mut sub = KucoinSubscription::new(kind);
+            sub.add_pairs(new.into_iter().collect());
+            sub
+        })
+    }
+
+    /// removes the pairs of the channel from `current`, returning the
+    /// unsubscription from the ones that were in it
+    pub(crate) fn unsubscribe(current: &mut Vec<KucoinSubscription>, channel: KucoinWsChannel) -> Option<Self> {
+        let (kind, pairs) = Self::channel_pairs(channel);
+        let removed = pairs
+            .into_iter()
+            .filter(|pair| current.iter().any(|sub| sub.has_pair(kind, pair)))
+            .collect::<HashSet<_>>();
+        if removed.is_empty() {
+            return None
+        }
+
+        current
+            .iter_mut()
+            .filter(|sub| sub.topic.channel == kind)
+            .for_each(|sub| sub.topic.trading_pairs.retain(|p| !removed.contains(p)));
+        current.retain(|sub| !sub.topic.trading_pairs.is_empty() || sub.private_channel);
+
+        let mut sub = KucoinSubscription::new(kind);
+        sub.method = "unsubscribe".to_string();
+        sub.add_pairs(removed.into_iter().collect());
+        Some(sub)
+    }
+
+    fn channel_pairs(channel: KucoinWsChannel) -> (KucoinWsChannelKind, Vec<KucoinTradingPair>) {
+        let kind = KucoinWsChannelKind::from(&channel);
+        let pairs = match channel {
+            KucoinWsChannel::Match(pairs) | KucoinWsChannel::Ticker(pairs) | KucoinWsChannel::Level2(pairs) => pairs,
+            KucoinWsChannel::Candles { pairs, .. } => pairs,
+            KucoinWsChannel::Account => Vec::new()
+        };
+
+        (kind, pairs)
+    }
+
+    fn has_pair(&self, kind: KucoinWsChannelKind, pair: &KucoinTradingPair) -> bool {
+        self.topic.channel == kind && self.topic.trading_pairs.contains(pair)
+    }
+
     pub fn add_pairs(&mut self, pairs: Vec<KucoinTradingPair>) {
         self.topic.trading_pairs.extend(pairs)
     }
@@ -64,19 +165,20 @@ impl KucoinSubscription {
     pub fn remove_pair(&mut self, pair: &KucoinTradingPair) -> bool {
         self.topic.trading_pairs.retain(|p| p != pair);
 
-        self.topic.trading_pairs.is_empty()
+        self.topic.trading_pairs.is_empty() && !self.private_channel
     }
 }
 
 #[derive(Debug, Clone)]
 struct KucoinSubscriptionInner {
     channel:       KucoinWsChannelKind,
-    trading_pairs: Vec<KucoinTradingPair>
+    trading_pairs: Vec<KucoinTradingPair>,
+    private_topic: Option<String>
 }
 
 impl KucoinSubscriptionInner {
     fn new(channel: KucoinWsChannelKind) -> Self {
-        Self { channel, trading_pairs: Vec::new() }
+        Self { channel, trading_pairs: Vec::new(), private_topic: None }
     }
 }
 
@@ -85,10 +187,17 @@ impl Serialize for KucoinSubscriptionInner {
     where
         S: serde::Serializer
     {
+        if let Some(topic) = self.private_topic.as_ref() {
+            return topic.serialize(serializer)
+        }
+
         let pairs = self
             .trading_pairs
             .iter()
-            .map(|pair| pair.0.to_uppercase())
+            .map(|pair| match self.channel {
+                KucoinWsChannelKind::Candles(interval) => format!("{}_{}", pair.0.to_uppercase(), KucoinCandleInterval(interval)),
+                _ => pair.0.to_uppercase()
+            })
             .collect::<Vec<_>>()
             .join(",");
         format!("/market/{}:{}", self.channel, pairs).serialize(serializer)
@@ -115,6 +224,15 @@ impl KucoinWsEndpointResponse {
     pub fn get_token(&self) -> String {
         self.data.token.clone()
     }
+
+    /// how often the websocket server expects a `ping`
+    pub fn get_ping_interval(&self) -> Option<u64> {
+        self.data
+            .instance_servers
+            .iter()
+            .find(|server| &server.protocol == "websocket")
+            .map(|server| server.ping_interval)
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -130,7 +248,6 @@ struct KucoinWsEndpointInstanceServersResponse {
     #[allow(unused)]
     encrypt:       bool,
     protocol:      String,
-    #[allow(unused)]
     #[serde(rename = "pingInterval")]
     ping_interval: u64,
     #[allow(unused)]
@@ -0,0 +1,127 @@
+use std::fmt::Display;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{exchanges::normalized::types::NormalizedTradingPair, CexExchange};
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, PartialOrd)]
+pub struct MexcTradingPair(pub(crate) String);
+
+impl MexcTradingPair {
+    pub fn new_checked(s: &str) -> eyre::Result<Self> {
+        s.to_string().try_into()
+    }
+
+    pub fn is_valid(s: &str) -> bool {
+        !s.contains('-') && !s.contains('_') && !s.contains('/')
+    }
+
+    pub fn normalize(&self) -> NormalizedTradingPair {
+        NormalizedTradingPair::new_no_base_quote(CexExchange::Mexc, &self.0)
+    }
+
+    pub fn normalize_with(&self, base: &str, quote: &str) -> NormalizedTradingPair {
+        NormalizedTradingPair::new_base_quote(CexExchange::Mexc, base, quote, None, None)
+    }
+
+    /// the pair of a failed subscription, ex: 'Not Subscribed successfully!
+    /// [spot@public.aggre.deals.v3.api.pb@100ms@BTCXYZ]'
+    pub fn parse_for_bad_pair(value: &str) -> Option<Self> {
+        value
+            .split([' ', '[', ']', ',', '"'])
+            .filter(|s| s.starts_with("spot@"))
+            .find_map(|channel| channel.rsplit('@').next())
+            .and_then(|pair| Self::try_from(pair).ok())
+    }
+}
+
+impl Display for MexcTradingPair {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Serialize for MexcTradingPair {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for MexcTradingPair {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        let s = String::deserialize(deserializer)?;
+
+        Ok(MexcTradingPair(s))
+    }
+}
+
+impl TryFrom<NormalizedTradingPair> for MexcTradingPair {
+    type Error = eyre::Report;
+
+    fn try_from(value: NormalizedTradingPair) -> Result<Self, Self::Error> {
+        if let Some((base, quote)) = value.base_quote() {
+            return Ok(MexcTradingPair(format!("{}{}", base, quote)))
+        }
+
+        if let (Some(raw_pair), delim) = (value.pair(), value.delimiter()) {
+            if let Ok(v) = Self::new_checked(raw_pair) {
+                return Ok(v)
+            }
+
+            if let Some(d) = delim {
+                let mut split = raw_pair.split(d);
+                return Ok(MexcTradingPair(format!("{}{}", split.next().unwrap().to_uppercase(), split.next().unwrap().to_uppercase())));
+            }
+
+            let new_str = raw_pair.replace(['_', '-', '/'], "");
+            if let Ok(this) = Self::new_checked(&new_str) {
+                return Ok(this)
+            }
+
+            return Err(eyre::ErrReport::msg(format!("INVALID Mexc trading pair '{raw_pair}'")))
+        }
+
+        Err(eyre::ErrReport::msg(format!("INVALID Mexc trading pair '{:?}'", value)))
+    }
+}
+
+impl TryFrom<&str> for MexcTradingPair {
+    type Error = eyre::Report;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if Self::is_valid(value) {
+            Ok(MexcTradingPair(value.to_uppercase()))
+        } else {
+            Err(eyre::ErrReport::msg(format!("INVALID Mexc trading pair '{value}' contains a '-', '_', or '/'")))
+        }
+    }
+}
+
+impl TryFrom<String> for MexcTradingPair {
+    type Error = eyre::Report;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.as_str().try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_for_bad_pair() {
+        let test_str =
+            r#"{"id":0,"code":0,"msg":"Not Subscribed successfully! [spot@public.aggre.deals.v3.api.pb@100ms@BTCXYZ].  Reason： Blocked! "}"#;
+
+        let calculated = MexcTradingPair::parse_for_bad_pair(test_str);
+
+        assert_eq!(calculated, Some(MexcTradingPair("BTCXYZ".to_string())))
+    }
+}
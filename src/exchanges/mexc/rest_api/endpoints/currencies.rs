@@ -0,0 +1,117 @@
+use std::collections::{BTreeMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use super::MexcInstrument;
+use crate::{
+    normalized::{rest_api::NormalizedRestApiDataTypes, types::NormalizedCurrency},
+    CexExchange
+};
+
+/// the assets of the spot symbols, the coins' networks
+/// (`/api/v3/capital/config/getall`) are only served to signed requests
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct MexcAllCurrencies {
+    pub currencies: Vec<MexcCurrency>
+}
+
+impl MexcAllCurrencies {
+    pub fn from_instruments(instruments: &[MexcInstrument]) -> Self {
+        let mut currencies = BTreeMap::<&str, MexcCurrency>::new();
+        for instr in instruments {
+            let base = currencies
+                .entry(&instr.base_asset)
+                .or_insert_with(|| MexcCurrency::new(&instr.base_asset));
+            base.full_name = base.full_name.take().or(instr.full_name.clone());
+            base.active |= instr.is_active();
+
+            let quote = currencies
+                .entry(&instr.quote_asset)
+                .or_insert_with(|| MexcCurrency::new(&instr.quote_asset));
+            quote.active |= instr.is_active();
+        }
+
+        Self { currencies: currencies.into_values().collect() }
+    }
+
+    pub fn normalize(self) -> Vec<NormalizedCurrency> {
+        self.currencies
+            .into_iter()
+            .map(MexcCurrency::normalize)
+            .collect()
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for MexcAllCurrencies {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match other {
+            NormalizedRestApiDataTypes::AllCurrencies(other_currs) => {
+                let this_currencies = self
+                    .currencies
+                    .iter()
+                    .map(|curr| &curr.symbol)
+                    .collect::<HashSet<_>>();
+
+                self.currencies.len() == other_currs.len()
+                    && other_currs
+                        .iter()
+                        .all(|curr| this_currencies.contains(&curr.symbol))
+            }
+            _ => false
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct MexcCurrency {
+    /// ex: BTC
+    pub symbol:    String,
+    /// only set for the base assets, ex: Bitcoin
+    pub full_name: Option<String>,
+    /// if any spot symbol of the asset is trading
+    pub active:    bool
+}
+
+impl MexcCurrency {
+    fn new(symbol: &str) -> Self {
+        Self { symbol: symbol.to_string(), full_name: None, active: false }
+    }
+
+    pub fn status(&self) -> &'static str {
+        if self.active {
+            "enabled"
+        } else {
+            "disabled"
+        }
+    }
+
+    pub fn normalize(self) -> NormalizedCurrency {
+        NormalizedCurrency {
+            exchange:     CexExchange::Mexc,
+            status:       self.status().to_string(),
+            name:         self.full_name.unwrap_or_else(|| self.symbol.clone()),
+            symbol:       self.symbol,
+            display_name: None,
+            blockchains:  Vec::new()
+        }
+    }
+}
+
+impl PartialEq<NormalizedCurrency> for MexcCurrency {
+    fn eq(&self, other: &NormalizedCurrency) -> bool {
+        let equals = other.exchange == CexExchange::Mexc
+            && other.symbol == self.symbol
+            && &other.name == self.full_name.as_ref().unwrap_or(&self.symbol)
+            && other.display_name.is_none()
+            && other.status == self.status()
+            && other.blockchains.is_empty();
+
+        if !equals {
+            warn!(target: "cex-exchanges::mexc", "mexc currency: {:?}", self);
+            warn!(target: "cex-exchanges::mexc", "normalized currency: {:?}", other);
+        }
+
+        equals
+    }
+}
@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use tracing::warn;
+
+use crate::{
+    mexc::MexcTradingPair,
+    normalized::{
+        rest_api::NormalizedRestApiDataTypes,
+        types::{NormalizedInstrument, NormalizedTradingType}
+    },
+    CexExchange
+};
+
+/// the status of an online symbol, older responses use 'ENABLED'
+const MEXC_ONLINE_STATUSES: [&str; 2] = ["1", "ENABLED"];
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct MexcAllInstruments {
+    #[serde(rename = "symbols")]
+    pub instruments: Vec<MexcInstrument>
+}
+
+impl MexcAllInstruments {
+    pub fn normalize(self) -> Vec<NormalizedInstrument> {
+        self.instruments
+            .into_iter()
+            .map(MexcInstrument::normalize)
+            .collect()
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for MexcAllInstruments {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match other {
+            NormalizedRestApiDataTypes::AllInstruments(other_instrs) => {
+                self.instruments.len() == other_instrs.len()
+                    && self
+                        .instruments
+                        .iter()
+                        .zip(other_instrs.iter())
+                        .all(|(this, that)| this == that)
+            }
+            _ => false
+        }
+    }
+}
+
+/// a spot symbol of `/api/v3/exchangeInfo`
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+#[serde(rename_all = "camelCase")]
+pub struct MexcInstrument {
+    pub symbol:                  MexcTradingPair,
+    /// '1' (online), '2' (paused) or '3' (offline)
+    pub status:                  String,
+    pub base_asset:              String,
+    pub base_asset_precision:    u32,
+    pub quote_asset:             String,
+    pub quote_asset_precision:   u32,
+    pub is_spot_trading_allowed: bool,
+    #[serde_as(as = "DisplayFromStr")]
+    pub maker_commission:        f64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub taker_commission:        f64,
+    /// the name of the base asset, ex: Bitcoin
+    pub full_name:               Option<String>
+}
+
+impl MexcInstrument {
+    pub fn is_active(&self) -> bool {
+        MEXC_ONLINE_STATUSES.contains(&self.status.as_str()) && self.is_spot_trading_allowed
+    }
+
+    pub fn normalize(self) -> NormalizedInstrument {
+        NormalizedInstrument {
+            exchange:           CexExchange::Mexc,
+            trading_pair:       self
+                .symbol
+                .normalize_with(&self.base_asset, &self.quote_asset),
+            trading_type:       NormalizedTradingType::Spot,
+            active:             self.is_active(),
+            base_asset_symbol:  self.base_asset,
+            quote_asset_symbol: self.quote_asset,
+            futures_expiry:     None,
+            option_strike:      None,
+            option_type:        None
+        }
+    }
+}
+
+impl PartialEq<NormalizedInstrument> for MexcInstrument {
+    fn eq(&self, other: &NormalizedInstrument) -> bool {
+        let equals = other.exchange == CexExchange::Mexc
+            && other.trading_pair
+                == self
+                    .symbol
+                    .normalize_with(&self.base_asset, &self.quote_asset)
+            && other.trading_type == NormalizedTradingType::Spot
+            && other.base_asset_symbol == self.base_asset
+            && other.quote_asset_symbol == self.quote_asset
+            && other.active == self.is_active()
+            && other.futures_expiry.is_none()
+            && other.option_strike.is_none()
+            && other.option_type.is_none();
+
+        if !equals {
+            warn!(target: "cex-exchanges::mexc", "mexc instrument: {:?}", self);
+            warn!(target: "cex-exchanges::mexc", "normalized instrument: {:?}", other);
+        }
+
+        equals
+    }
+}
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use super::{MexcAllCurrencies, MexcAllInstruments, MexcCurrency, MexcInstrument};
+use crate::normalized::rest_api::NormalizedRestApiDataTypes;
+
+#[serde_with::serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+#[serde(rename_all = "snake_case", tag = "type")]
+pub enum MexcRestApiResponse {
+    Currencies(MexcAllCurrencies),
+    Instruments(MexcAllInstruments)
+}
+
+impl MexcRestApiResponse {
+    pub fn normalize(self) -> NormalizedRestApiDataTypes {
+        match self {
+            MexcRestApiResponse::Currencies(v) => NormalizedRestApiDataTypes::AllCurrencies(v.normalize()),
+            MexcRestApiResponse::Instruments(v) => NormalizedRestApiDataTypes::AllInstruments(v.normalize())
+        }
+    }
+
+    pub fn take_currencies(self) -> Option<Vec<MexcCurrency>> {
+        match self {
+            MexcRestApiResponse::Currencies(val) => Some(val.currencies),
+            _ => None
+        }
+    }
+
+    pub fn take_instruments(self, active_only: bool) -> Option<Vec<MexcInstrument>> {
+        let instruments = match self {
+            MexcRestApiResponse::Instruments(val) => val.instruments,
+            _ => return None
+        };
+
+        if active_only {
+            Some(
+                instruments
+                    .into_iter()
+                    .filter(|instr| instr.is_active())
+                    .collect::<Vec<_>>()
+            )
+        } else {
+            Some(instruments)
+        }
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for MexcRestApiResponse {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match self {
+            MexcRestApiResponse::Currencies(vals) => vals == other,
+            MexcRestApiResponse::Instruments(vals) => vals == other
+        }
+    }
+}
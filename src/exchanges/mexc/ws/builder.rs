@@ -0,0 +1,146 @@
+use super::{
+    channels::{MexcWsChannel, MexcWsChannelKind},
+    MexcSubscription
+};
+use crate::{
+    clients::{rest_api::ExchangeApi, ws::MutliWsStreamBuilder},
+    mexc::Mexc,
+    normalized::ws::NormalizedWsChannels
+};
+
+/// max number of streams the channels are distributed over
+const MAX_MEXC_STREAMS: usize = 20;
+/// A single connection can listen to a maximum of 30 streams.
+const MAX_MEXC_WS_CONNS_PER_STREAM: usize = 30;
+
+#[derive(Debug, Clone, Default)]
+pub struct MexcWsBuilder {
+    pub channels: Vec<MexcWsChannel>
+}
+
+impl MexcWsBuilder {
+    /// adds a channel to the builder
+    pub fn add_channel(mut self, channel: MexcWsChannel) -> Self {
+        self.channels.push(channel);
+        self
+    }
+
+    /// builds a single ws instance of [Mexc], handling all channels on 1
+    /// stream
+    pub fn build_single(self) -> Mexc {
+        let mut sub = MexcSubscription::new();
+        self.channels.into_iter().for_each(|c| sub.add_channel(c));
+
+        Mexc::new_ws_subscription(sub)
+    }
+
+    /// builds many ws instances of the [Mexc] as the inner streams of
+    /// [MutliWsStreamBuilder], splitting the channels into different streams,
+    /// each with size # channels / `MAX_MEXC_STREAMS` (20),
+    ///
+    /// WARNING: too many channels may break the stream
+    pub fn build_many_distributed(self) -> eyre::Result<MutliWsStreamBuilder<Mexc>> {
+        let stream_size = if self.channels.len() <= MAX_MEXC_STREAMS { 1 } else { self.channels.len() / MAX_MEXC_STREAMS };
+
+        let chunks = self.channels.chunks(stream_size).collect::<Vec<_>>();
+
+        let split_exchange = chunks
+            .into_iter()
+            .map(|chk| {
+                let mut subscription = MexcSubscription::new();
+                chk.iter()
+                    .for_each(|ch| subscription.add_channel(ch.clone()));
+
+                Mexc::new_ws_subscription(subscription)
+            })
+            .collect();
+
+        Ok(MutliWsStreamBuilder::new(split_exchange))
+    }
+
+    /// builds many ws instances of the [Mexc] as the inner streams of
+    /// [MutliWsStreamBuilder], splitting the channels into different streams,
+    /// each of size 30
+    pub fn build_many_packed(self, connections_per_stream: Option<usize>) -> eyre::Result<MutliWsStreamBuilder<Mexc>> {
+        let chunks = self
+            .channels
+            .chunks(connections_per_stream.unwrap_or(MAX_MEXC_WS_CONNS_PER_STREAM))
+            .collect::<Vec<_>>();
+
+        let split_exchange = chunks
+            .into_iter()
+            .map(|chk| {
+                let mut subscription = MexcSubscription::new();
+                chk.iter()
+                    .for_each(|ch| subscription.add_channel(ch.clone()));
+
+                Mexc::new_ws_subscription(subscription)
+            })
+            .collect();
+
+        Ok(MutliWsStreamBuilder::new(split_exchange))
+    }
+
+    /// builds a mutlistream channel from all active instruments
+    pub async fn build_from_all_instruments(
+        channels: &[MexcWsChannelKind],
+        connections_per_stream: Option<usize>
+    ) -> eyre::Result<MutliWsStreamBuilder<Mexc>> {
+        let this = Self::build_from_all_instruments_util(channels, connections_per_stream).await?;
+
+        let all_streams = this
+            .channels
+            .into_iter()
+            .map(|ch| {
+                let mut subscription = MexcSubscription::new();
+                subscription.add_channel(ch);
+
+                Mexc::new_ws_subscription(subscription)
+            })
+            .collect::<Vec<_>>();
+
+        Ok(MutliWsStreamBuilder::new(all_streams))
+    }
+
+    async fn build_from_all_instruments_util(channels: &[MexcWsChannelKind], connections_per_stream: Option<usize>) -> eyre::Result<Self> {
+        let mut this = Self::default();
+
+        let all_symbols = ExchangeApi::new()
+            .all_instruments::<Mexc>()
+            .await?
+            .take_mexc_instruments(true)
+            .unwrap()
+            .into_iter()
+            .map(|val| val.symbol)
+            .collect::<Vec<_>>();
+
+        let chunks = all_symbols.chunks(connections_per_stream.unwrap_or(MAX_MEXC_WS_CONNS_PER_STREAM));
+
+        chunks.into_iter().for_each(|chk| {
+            let all_channels = channels
+                .iter()
+                .map(|ch| match ch {
+                    MexcWsChannelKind::Deals => MexcWsChannel::Deals(chk.to_vec()),
+                    MexcWsChannelKind::BookTicker => MexcWsChannel::BookTicker(chk.to_vec())
+                })
+                .collect::<Vec<_>>();
+
+            this.channels.extend(all_channels);
+        });
+
+        Ok(this)
+    }
+
+    /// makes the builder from the normalized builder's map
+    pub(crate) fn make_from_normalized_map(map: Vec<NormalizedWsChannels>) -> eyre::Result<Self> {
+        let mut this = Self::default();
+
+        map.into_iter().try_for_each(|channel| {
+            let this_channel: MexcWsChannel = channel.try_into()?;
+            this = this.clone().add_channel(this_channel);
+            Ok(()) as eyre::Result<()>
+        })?;
+
+        Ok(this)
+    }
+}
@@ -0,0 +1,76 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::{
+    clients::ws::received_at,
+    exchanges::{mexc::pairs::MexcTradingPair, normalized::types::NormalizedQuote},
+    mexc::ws::proto::PublicAggreBookTickerV3Api,
+    normalized::types::NormalizedNumber,
+    CexExchange
+};
+
+/// the `spot@public.aggre.bookTicker.v3.api.pb@100ms@{symbol}` channel,
+/// decoded from protobuf
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct MexcBookTicker {
+    pub channel:      String,
+    pub symbol:       MexcTradingPair,
+    pub send_time:    u64,
+    pub bid_price:    NormalizedNumber,
+    pub bid_quantity: NormalizedNumber,
+    pub ask_price:    NormalizedNumber,
+    pub ask_quantity: NormalizedNumber,
+    #[serde(default = "received_at")]
+    pub received_at:  DateTime<Utc>
+}
+
+impl MexcBookTicker {
+    pub(crate) fn from_proto(channel: String, symbol: MexcTradingPair, send_time: u64, body: PublicAggreBookTickerV3Api) -> eyre::Result<Self> {
+        Ok(Self {
+            channel,
+            symbol,
+            send_time,
+            bid_price: body.bid_price.parse()?,
+            bid_quantity: body.bid_quantity.parse()?,
+            ask_price: body.ask_price.parse()?,
+            ask_quantity: body.ask_quantity.parse()?,
+            received_at: received_at()
+        })
+    }
+
+    pub fn normalize(self) -> NormalizedQuote {
+        NormalizedQuote {
+            exchange:      CexExchange::Mexc,
+            pair:          self.symbol.normalize(),
+            exchange_time: DateTime::from_timestamp_millis(self.send_time as i64),
+            received_at:   self.received_at,
+            ask_amount:    self.ask_quantity,
+            ask_price:     self.ask_price,
+            bid_amount:    self.bid_quantity,
+            bid_price:     self.bid_price,
+            quote_id:      None
+        }
+    }
+}
+
+impl PartialEq<NormalizedQuote> for MexcBookTicker {
+    fn eq(&self, other: &NormalizedQuote) -> bool {
+        let equals = other.exchange == CexExchange::Mexc
+            && other.pair == self.symbol.normalize()
+            && other.exchange_time == DateTime::from_timestamp_millis(self.send_time as i64)
+            && other.received_at == self.received_at
+            && other.ask_amount == self.ask_quantity
+            && other.ask_price == self.ask_price
+            && other.bid_amount == self.bid_quantity
+            && other.bid_price == self.bid_price
+            && other.quote_id.is_none();
+
+        if !equals {
+            warn!(target: "cex-exchanges::mexc", "mexc book ticker: {:?}", self);
+            warn!(target: "cex-exchanges::mexc", "normalized quote: {:?}", other);
+        }
+
+        equals
+    }
+}
@@ -0,0 +1,118 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::{
+    clients::ws::received_at,
+    exchanges::{
+        mexc::pairs::MexcTradingPair,
+        normalized::types::{NormalizedTrade, TradeSide}
+    },
+    mexc::ws::proto::{PublicAggreDealsV3Api, PublicAggreDealsV3ApiItem},
+    normalized::types::NormalizedNumber,
+    CexExchange
+};
+
+/// the `spot@public.aggre.deals.v3.api.pb@100ms@{symbol}` channel, decoded
+/// from protobuf
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct MexcDeals {
+    pub channel:   String,
+    pub symbol:    MexcTradingPair,
+    pub send_time: u64,
+    pub deals:     Vec<MexcDealData>
+}
+
+impl MexcDeals {
+    pub(crate) fn from_proto(channel: String, symbol: MexcTradingPair, send_time: u64, body: PublicAggreDealsV3Api) -> eyre::Result<Self> {
+        let deals = body
+            .deals
+            .into_iter()
+            .map(MexcDealData::from_proto)
+            .collect::<eyre::Result<_>>()?;
+
+        Ok(Self { channel, symbol, send_time, deals })
+    }
+
+    pub fn normalize(self) -> Vec<NormalizedTrade> {
+        let pair = self.symbol;
+        self.deals
+            .into_iter()
+            .map(|deal| deal.normalize(&pair))
+            .collect()
+    }
+}
+
+impl PartialEq<Vec<NormalizedTrade>> for MexcDeals {
+    fn eq(&self, other: &Vec<NormalizedTrade>) -> bool {
+        let equals = self.deals.len() == other.len()
+            && self
+                .deals
+                .iter()
+                .zip(other.iter())
+                .all(|(this, that)| this.eq_with_pair(that, &self.symbol));
+
+        if !equals {
+            warn!(target: "cex-exchanges::mexc", "mexc deals: {:?}", self);
+            warn!(target: "cex-exchanges::mexc", "normalized trades: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, PartialOrd)]
+pub struct MexcDealData {
+    pub price:       NormalizedNumber,
+    pub quantity:    NormalizedNumber,
+    /// 1 (buy) or 2 (sell)
+    pub trade_type:  i32,
+    pub time:        u64,
+    #[serde(default = "received_at")]
+    pub received_at: DateTime<Utc>
+}
+
+impl MexcDealData {
+    fn from_proto(item: PublicAggreDealsV3ApiItem) -> eyre::Result<Self> {
+        Ok(Self {
+            price:       item.price.parse()?,
+            quantity:    item.quantity.parse()?,
+            trade_type:  item.trade_type,
+            time:        item.time as u64,
+            received_at: received_at()
+        })
+    }
+
+    /// the taker's side
+    pub fn side(&self) -> TradeSide {
+        match self.trade_type {
+            1 => TradeSide::Buy,
+            2 => TradeSide::Sell,
+            _ => TradeSide::Unknown
+        }
+    }
+
+    pub fn normalize(self, pair: &MexcTradingPair) -> NormalizedTrade {
+        NormalizedTrade {
+            exchange:      CexExchange::Mexc,
+            pair:          pair.normalize(),
+            exchange_time: DateTime::from_timestamp_millis(self.time as i64),
+            received_at:   self.received_at,
+            side:          self.side(),
+            price:         self.price,
+            amount:        self.quantity,
+            trade_id:      None
+        }
+    }
+
+    fn eq_with_pair(&self, other: &NormalizedTrade, pair: &MexcTradingPair) -> bool {
+        other.exchange == CexExchange::Mexc
+            && other.pair == pair.normalize()
+            && other.exchange_time == DateTime::from_timestamp_millis(self.time as i64)
+            && other.received_at == self.received_at
+            && other.side == self.side()
+            && other.price == self.price
+            && other.amount == self.quantity
+            && other.trade_id.is_none()
+    }
+}
@@ -0,0 +1,145 @@
+use std::fmt::Display;
+
+mod deals;
+pub use deals::*;
+
+mod book_ticker;
+pub use book_ticker::*;
+
+use crate::{
+    exchanges::{
+        mexc::pairs::MexcTradingPair,
+        normalized::{
+            types::{NormalizedTradingPair, RawTradingPair},
+            ws::NormalizedWsChannels
+        }
+    },
+    CexExchange
+};
+
+/// the push interval of the aggregated channels
+pub const MEXC_CHANNEL_INTERVAL: &str = "100ms";
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum MexcWsChannel {
+    Deals(Vec<MexcTradingPair>),
+    /// the best bid & ask
+    BookTicker(Vec<MexcTradingPair>)
+}
+
+impl MexcWsChannel {
+    /// builds deals (trades) channel from a vec of raw trading pairs
+    /// return an error if the symbol is incorrectly formatted
+    pub fn new_deals(pairs: Vec<RawTradingPair>) -> eyre::Result<Self> {
+        let normalized = pairs
+            .into_iter()
+            .map(|pair| pair.get_normalized_pair(CexExchange::Mexc))
+            .collect();
+
+        Self::new_from_kind(normalized, MexcWsChannel::Deals(Vec::new()))
+    }
+
+    /// builds the book ticker channel from a vec of raw trading
+    /// pairs return an error if the symbol is incorrectly formatted
+    pub fn new_book_ticker(pairs: Vec<RawTradingPair>) -> eyre::Result<Self> {
+        let normalized = pairs
+            .into_iter()
+            .map(|pair| pair.get_normalized_pair(CexExchange::Mexc))
+            .collect();
+
+        Self::new_from_kind(normalized, MexcWsChannel::BookTicker(Vec::new()))
+    }
+
+    fn new_from_kind(pairs: Vec<NormalizedTradingPair>, kind: MexcWsChannel) -> eyre::Result<Self> {
+        let pairs = pairs
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<_, _>>()?;
+
+        match kind {
+            MexcWsChannel::Deals(_) => Ok(MexcWsChannel::Deals(pairs)),
+            MexcWsChannel::BookTicker(_) => Ok(MexcWsChannel::BookTicker(pairs))
+        }
+    }
+
+    pub(crate) fn pairs(&self) -> &[MexcTradingPair] {
+        match self {
+            MexcWsChannel::Deals(pairs) => pairs,
+            MexcWsChannel::BookTicker(pairs) => pairs
+        }
+    }
+}
+
+impl Display for MexcWsChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        MexcWsChannelKind::from(self).fmt(f)
+    }
+}
+
+impl TryFrom<String> for MexcWsChannel {
+    type Error = eyre::Report;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.to_lowercase().as_str() {
+            "deals" | "trade" => Ok(Self::Deals(Vec::new())),
+            "book_ticker" | "bookticker" => Ok(Self::BookTicker(Vec::new())),
+            _ => Err(eyre::ErrReport::msg(format!("channel is not valid: {value}")))
+        }
+    }
+}
+
+impl TryFrom<NormalizedWsChannels> for MexcWsChannel {
+    type Error = eyre::ErrReport;
+
+    fn try_from(value: NormalizedWsChannels) -> Result<Self, Self::Error> {
+        match value {
+            NormalizedWsChannels::Trades(pairs) => {
+                let norm_pairs = pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, Self::Error>>()?;
+
+                Ok(MexcWsChannel::Deals(norm_pairs))
+            }
+
+            NormalizedWsChannels::Quotes(pairs) => {
+                let norm_pairs = pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, Self::Error>>()?;
+
+                Ok(MexcWsChannel::BookTicker(norm_pairs))
+            }
+
+            NormalizedWsChannels::OrderBook { .. } => Err(eyre::ErrReport::msg("mexc does not support the order book ws channel")),
+
+            NormalizedWsChannels::Candles { .. } => Err(eyre::ErrReport::msg("mexc does not support the candles ws channel")),
+
+            NormalizedWsChannels::Account(_) => Err(eyre::ErrReport::msg("mexc does not support the account ws channel"))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum MexcWsChannelKind {
+    Deals,
+    BookTicker
+}
+
+impl Display for MexcWsChannelKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MexcWsChannelKind::Deals => write!(f, "spot@public.aggre.deals.v3.api.pb"),
+            MexcWsChannelKind::BookTicker => write!(f, "spot@public.aggre.bookTicker.v3.api.pb")
+        }
+    }
+}
+
+impl From<&MexcWsChannel> for MexcWsChannelKind {
+    fn from(value: &MexcWsChannel) -> Self {
+        match value {
+            MexcWsChannel::Deals(_) => MexcWsChannelKind::Deals,
+            MexcWsChannel::BookTicker(_) => MexcWsChannelKind::BookTicker
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use super::{
+    channels::{MexcBookTicker, MexcDeals},
+    proto::{PushDataBody, PushDataV3ApiWrapper}
+};
+use crate::{
+    clients::ws::{CriticalWsMessage, WsError},
+    exchanges::normalized::ws::NormalizedWsDataTypes,
+    mexc::MexcTradingPair,
+    CexExchange
+};
+
+/// the prefix of a rejected subscription's `msg`, the `code` is still 0
+const MEXC_REJECTED_SUBSCRIPTION: &str = "Not Subscribed successfully";
+
+/// the text frames are json (subscription acks & errors), the channels' pushes
+/// are binary protobuf frames
+#[serde_with::serde_as]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case", tag = "data")]
+pub enum MexcWsMessage {
+    Deals(MexcDeals),
+    BookTicker(MexcBookTicker),
+    SubscriptionResponse { id: u64, msg: String },
+    Error { code: i64, error: String, raw_msg: String, bad_pair: Option<MexcTradingPair> }
+}
+
+impl MexcWsMessage {
+    fn try_deserialize(value: Value) -> eyre::Result<Self> {
+        let code = value
+            .get("code")
+            .and_then(|c| c.as_i64())
+            .ok_or(eyre::ErrReport::msg("Could not find 'code' field in Mexc ws message".to_string()))?;
+        let msg = value
+            .get("msg")
+            .and_then(|m| m.as_str())
+            .unwrap_or_default()
+            .to_string();
+
+        if code != 0 || msg.starts_with(MEXC_REJECTED_SUBSCRIPTION) {
+            let bad_pair = MexcTradingPair::parse_for_bad_pair(&msg);
+            return Ok(Self::Error { code, error: msg, raw_msg: String::new(), bad_pair })
+        }
+
+        let id = value.get("id").and_then(|i| i.as_u64()).unwrap_or_default();
+
+        Ok(Self::SubscriptionResponse { id, msg })
+    }
+
+    fn try_from_proto(wrapper: PushDataV3ApiWrapper) -> eyre::Result<Self> {
+        let symbol = match wrapper.symbol {
+            Some(symbol) => MexcTradingPair::new_checked(&symbol)?,
+            None => MexcTradingPair::new_checked(wrapper.channel.rsplit('@').next().unwrap_or_default())?
+        };
+        let send_time = wrapper
+            .send_time
+            .or(wrapper.create_time)
+            .unwrap_or_default() as u64;
+
+        match wrapper.body {
+            Some(PushDataBody::PublicAggreDeals(body)) => Ok(Self::Deals(MexcDeals::from_proto(wrapper.channel, symbol, send_time, body)?)),
+            Some(PushDataBody::PublicAggreBookTicker(body)) => {
+                Ok(Self::BookTicker(MexcBookTicker::from_proto(wrapper.channel, symbol, send_time, body)?))
+            }
+            None => Err(eyre::ErrReport::msg(format!("Channel '{}' cannot be decoded", wrapper.channel)))
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for MexcWsMessage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        let map = Value::deserialize(deserializer)?;
+
+        Self::try_deserialize(map).map_err(serde::de::Error::custom)
+    }
+}
+
+impl MexcWsMessage {
+    pub fn normalize(self) -> NormalizedWsDataTypes {
+        match self {
+            MexcWsMessage::Deals(v) => NormalizedWsDataTypes::Trades(v.normalize()),
+            MexcWsMessage::BookTicker(v) => NormalizedWsDataTypes::Quote(v.normalize()),
+            MexcWsMessage::SubscriptionResponse { id, msg } => NormalizedWsDataTypes::Other {
+                exchange: CexExchange::Mexc,
+                kind:     "subscribe".to_string(),
+                value:    format!("result: {} -- id: {}", msg, id)
+            },
+            MexcWsMessage::Error { code, error, raw_msg, bad_pair } => {
+                if let Some(bp) = bad_pair {
+                    NormalizedWsDataTypes::RemovedPair {
+                        exchange:    CexExchange::Mexc,
+                        bad_pair:    bp.normalize(),
+                        raw_message: format!("{code} - {error} - {raw_msg}")
+                    }
+                } else {
+                    NormalizedWsDataTypes::Other { exchange: CexExchange::Mexc, kind: format!("{code} - {error}"), value: raw_msg }
+                }
+            }
+        }
+    }
+}
+
+impl PartialEq<NormalizedWsDataTypes> for MexcWsMessage {
+    fn eq(&self, other: &NormalizedWsDataTypes) -> bool {
+        match (self, other) {
+            (MexcWsMessage::Deals(this), NormalizedWsDataTypes::Trades(that)) => this == that,
+            (MexcWsMessage::BookTicker(this), NormalizedWsDataTypes::Quote(that)) => this == that,
+            (MexcWsMessage::SubscriptionResponse { .. }, NormalizedWsDataTypes::Other { .. }) => true,
+            (MexcWsMessage::Error { .. }, NormalizedWsDataTypes::Other { .. }) => true,
+            (MexcWsMessage::Error { .. }, NormalizedWsDataTypes::RemovedPair { .. }) => true,
+            _ => false
+        }
+    }
+}
+
+impl CriticalWsMessage for MexcWsMessage {
+    fn make_critical(&mut self, msg: String) {
+        if let MexcWsMessage::Error { raw_msg, .. } = self {
+            *raw_msg = msg;
+        }
+    }
+
+    fn decode_binary(frame: &[u8]) -> Result<Self, WsError> {
+        let wrapper = PushDataV3ApiWrapper::decode(frame).map_err(|e| WsError::BinaryDecodingError(e.to_string()))?;
+
+        Self::try_from_proto(wrapper).map_err(|e| WsError::BinaryDecodingError(e.to_string()))
+    }
+}
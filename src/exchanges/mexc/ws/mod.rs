@@ -0,0 +1,12 @@
+mod builder;
+pub use builder::*;
+
+pub mod channels;
+
+mod message;
+pub use message::*;
+
+pub mod proto;
+
+mod subscription;
+pub use subscription::*;
@@ -0,0 +1,61 @@
+//! the protobuf messages of the spot ws (`*.api.pb` channels), only the
+//! bodies of the supported channels are decoded
+
+/// the envelope of every binary frame
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct PushDataV3ApiWrapper {
+    /// ex: spot@public.aggre.deals.v3.api.pb@100ms@BTCUSDT
+    #[prost(string, tag = "1")]
+    pub channel:     String,
+    #[prost(oneof = "PushDataBody", tags = "314, 315")]
+    pub body:        Option<PushDataBody>,
+    #[prost(string, optional, tag = "3")]
+    pub symbol:      Option<String>,
+    #[prost(string, optional, tag = "4")]
+    pub symbol_id:   Option<String>,
+    #[prost(int64, optional, tag = "5")]
+    pub create_time: Option<i64>,
+    #[prost(int64, optional, tag = "6")]
+    pub send_time:   Option<i64>
+}
+
+#[derive(Clone, PartialEq, prost::Oneof)]
+pub enum PushDataBody {
+    #[prost(message, tag = "314")]
+    PublicAggreDeals(PublicAggreDealsV3Api),
+    #[prost(message, tag = "315")]
+    PublicAggreBookTicker(PublicAggreBookTickerV3Api)
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct PublicAggreDealsV3Api {
+    #[prost(message, repeated, tag = "1")]
+    pub deals:      Vec<PublicAggreDealsV3ApiItem>,
+    #[prost(string, tag = "2")]
+    pub event_type: String
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct PublicAggreDealsV3ApiItem {
+    #[prost(string, tag = "1")]
+    pub price:      String,
+    #[prost(string, tag = "2")]
+    pub quantity:   String,
+    /// 1 (buy) or 2 (sell)
+    #[prost(int32, tag = "3")]
+    pub trade_type: i32,
+    #[prost(int64, tag = "4")]
+    pub time:       i64
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct PublicAggreBookTickerV3Api {
+    #[prost(string, tag = "1")]
+    pub bid_price:    String,
+    #[prost(string, tag = "2")]
+    pub bid_quantity: String,
+    #[prost(string, tag = "3")]
+    pub ask_price:    String,
+    #[prost(string, tag = "4")]
+    pub ask_quantity: String
+}
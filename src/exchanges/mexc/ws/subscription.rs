@@ -0,0 +1,87 @@
+use std::collections::HashSet;
+
+use serde::Serialize;
+
+use super::channels::{MexcWsChannel, MexcWsChannelKind, MEXC_CHANNEL_INTERVAL};
+use crate::mexc::MexcTradingPair;
+
+#[derive(Debug, Default, Clone, Serialize)]
+pub struct MexcSubscription {
+    method: String,
+    params: Vec<MexcSubscriptionInner>
+}
+
+impl MexcSubscription {
+    pub fn new() -> Self {
+        MexcSubscription { method: "SUBSCRIPTION".to_string(), params: Vec::new() }
+    }
+
+    pub fn add_channel(&mut self, channel: MexcWsChannel) {
+        let new: Vec<_> = channel.into();
+        self.params.extend(new);
+    }
+
+    /// adds the streams of the channel, returning the request to subscribe to
+    /// the new ones
+    pub(crate) fn subscribe(&mut self, channel: MexcWsChannel) -> Option<Self> {
+        let new = Vec::<MexcSubscriptionInner>::from(channel)
+            .into_iter()
+            .filter(|p| !self.params.contains(p))
+            .collect::<Vec<_>>();
+        self.params.extend(new.clone());
+
+        (!new.is_empty()).then(|| MexcSubscription { method: "SUBSCRIPTION".to_string(), params: new })
+    }
+
+    /// removes the streams of the channel, returning the request to
+    /// unsubscribe from the ones that were subscribed to
+    pub(crate) fn unsubscribe(&mut self, channel: MexcWsChannel) -> Option<Self> {
+        let removed = Vec::<MexcSubscriptionInner>::from(channel)
+            .into_iter()
+            .filter(|p| self.params.contains(p))
+            .collect::<Vec<_>>();
+        self.params.retain(|p| !removed.contains(p));
+
+        (!removed.is_empty()).then(|| MexcSubscription { method: "UNSUBSCRIPTION".to_string(), params: removed })
+    }
+
+    /// the request to unsubscribe from every stream
+    pub(crate) fn unsubscribe_all(&self) -> Option<Self> {
+        (!self.params.is_empty()).then(|| MexcSubscription { method: "UNSUBSCRIPTION".to_string(), params: self.params.clone() })
+    }
+
+    pub fn remove_pair(&mut self, pair: &MexcTradingPair) -> bool {
+        self.params.retain(|p| &p.trading_pair != pair);
+
+        self.params.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct MexcSubscriptionInner {
+    channel:      MexcWsChannelKind,
+    trading_pair: MexcTradingPair
+}
+
+impl Serialize for MexcSubscriptionInner {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer
+    {
+        format!("{}@{MEXC_CHANNEL_INTERVAL}@{}", self.channel, self.trading_pair).serialize(serializer)
+    }
+}
+
+impl From<MexcWsChannel> for Vec<MexcSubscriptionInner> {
+    fn from(val: MexcWsChannel) -> Self {
+        let kind = MexcWsChannelKind::from(&val);
+
+        val.pairs()
+            .iter()
+            .cloned()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .map(|pair| MexcSubscriptionInner { channel: kind, trading_pair: pair })
+            .collect()
+    }
+}
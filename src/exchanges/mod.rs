@@ -3,70 +3,122 @@ pub mod normalized;
 mod filter;
 pub use filter::*;
 
-#[cfg(feature = "non-us")]
+#[cfg(any(feature = "binance", feature = "bybit"))]
+mod coin_listings;
+
+#[cfg(feature = "binance")]
 pub mod binance;
 
-#[cfg(feature = "non-us")]
+#[cfg(feature = "kucoin")]
 pub mod kucoin;
 
-#[cfg(feature = "non-us")]
+#[cfg(feature = "bybit")]
 pub mod bybit;
 
-#[cfg(feature = "us")]
+#[cfg(feature = "gateio")]
+pub mod gateio;
+
+#[cfg(feature = "deribit")]
+pub mod deribit;
+
+#[cfg(feature = "bitget")]
+pub mod bitget;
+
+#[cfg(feature = "mexc")]
+pub mod mexc;
+
+#[cfg(feature = "coinbase")]
 pub mod coinbase;
 
-#[cfg(feature = "us")]
+#[cfg(feature = "okex")]
 pub mod okex;
 
+#[cfg(feature = "kraken")]
+pub mod kraken;
+
+#[cfg(feature = "bitstamp")]
+pub mod bitstamp;
+
 use std::{
     fmt::{Debug, Display},
-    str::FromStr
+    str::FromStr,
+    sync::Arc,
+    thread::JoinHandle,
+    time::Duration
 };
 
+use chrono::{DateTime, Utc};
 use clap::ValueEnum;
 use futures::Future;
 use serde::{Deserialize, Serialize};
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
-use tokio::{net::TcpStream, sync::mpsc::UnboundedReceiver};
-use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
+use tokio::net::TcpStream;
+use tokio_tungstenite::{tungstenite::Message, MaybeTlsStream, WebSocketStream};
 
+#[cfg(feature = "binance")]
+use self::binance::{ws::BinanceWsBuilder, Binance, BinanceTradingPair};
+#[cfg(feature = "bitget")]
+use self::bitget::{ws::BitgetWsBuilder, Bitget, BitgetTradingPair};
+#[cfg(feature = "bitstamp")]
+use self::bitstamp::{ws::BitstampWsBuilder, Bitstamp, BitstampTradingPair};
+#[cfg(feature = "bybit")]
+use self::bybit::{ws::BybitWsBuilder, Bybit, BybitTradingPair};
+#[cfg(feature = "coinbase")]
+use self::coinbase::{ws::CoinbaseWsBuilder, Coinbase, CoinbaseTradingPair};
+#[cfg(feature = "deribit")]
+use self::deribit::{ws::DeribitWsBuilder, Deribit, DeribitTradingPair};
+#[cfg(feature = "gateio")]
+use self::gateio::{ws::GateioWsBuilder, Gateio, GateioTradingPair};
+#[cfg(feature = "kraken")]
+use self::kraken::{ws::KrakenWsBuilder, Kraken, KrakenTradingPair};
+#[cfg(feature = "kucoin")]
+use self::kucoin::{ws::KucoinWsBuilder, Kucoin, KucoinTradingPair};
+#[cfg(feature = "mexc")]
+use self::mexc::{ws::MexcWsBuilder, Mexc, MexcTradingPair};
 use self::normalized::{
     rest_api::NormalizedRestApiRequest,
-    types::{NormalizedCurrency, NormalizedInstrument, NormalizedTradingPair},
-    ws::{CombinedWsMessage, NormalizedWsChannels}
-};
-#[cfg(feature = "non-us")]
-use self::{
-    binance::{ws::BinanceWsBuilder, Binance, BinanceTradingPair},
-    bybit::{ws::BybitWsBuilder, Bybit, BybitTradingPair},
-    kucoin::{ws::KucoinWsBuilder, Kucoin, KucoinTradingPair}
-};
-#[cfg(feature = "us")]
-use self::{
-    coinbase::{ws::CoinbaseWsBuilder, Coinbase, CoinbaseTradingPair},
-    okex::{ws::OkexWsBuilder, Okex, OkexTradingPair}
+    types::{
+        NormalizedCandle, NormalizedCandleInterval, NormalizedCurrency, NormalizedInstrument, NormalizedOrderAck, NormalizedOrderRequest,
+        NormalizedOrderUpdate, NormalizedTrade, NormalizedTradingPair
+    },
+    ws::{CombinedWsMessage, NormalizedWsChannels, WsStreamOptions}
 };
+#[cfg(feature = "okex")]
+use self::okex::{ws::OkexWsBuilder, Okex, OkexTradingPair};
 use crate::{
     clients::{
         rest_api::{ExchangeApi, RestApiError},
-        ws::{CriticalWsMessage, MutliWsStream, WsError}
+        ws::{deserialize_ws_message, CriticalWsMessage, FanOutSender, MutliWsStream, ShutdownHandle, WsError, WsSubscriptionUpdate},
+        ExchangeCredentials, ExchangeEndpoints, TokenBucket
     },
     exchanges::normalized::rest_api::CombinedRestApiResponse
 };
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize, PartialOrd, Ord, EnumIter, ValueEnum)]
 pub enum CexExchange {
-    #[cfg(feature = "us")]
+    #[cfg(feature = "coinbase")]
     Coinbase,
-    #[cfg(feature = "us")]
+    #[cfg(feature = "okex")]
     Okex,
-    #[cfg(feature = "non-us")]
+    #[cfg(feature = "kraken")]
+    Kraken,
+    #[cfg(feature = "bitstamp")]
+    Bitstamp,
+    #[cfg(feature = "binance")]
     Binance,
-    #[cfg(feature = "non-us")]
+    #[cfg(feature = "kucoin")]
     Kucoin,
-    #[cfg(feature = "non-us")]
-    Bybit
+    #[cfg(feature = "bybit")]
+    Bybit,
+    #[cfg(feature = "gateio")]
+    Gateio,
+    #[cfg(feature = "deribit")]
+    Deribit,
+    #[cfg(feature = "bitget")]
+    Bitget,
+    #[cfg(feature = "mexc")]
+    Mexc
 }
 
 impl CexExchange {
@@ -77,78 +129,125 @@ impl CexExchange {
     pub(crate) fn build_multistream_ws_from_normalized(
         self,
         map: Vec<NormalizedWsChannels>,
-        max_retries: Option<u64>,
         connections_per_stream: Option<usize>,
-        _exch_currency_proxy: Option<CexExchange>
+        _exch_currency_proxy: Option<CexExchange>,
+        _credentials: Option<ExchangeCredentials>,
+        options: WsStreamOptions
     ) -> eyre::Result<MutliWsStream> {
         let res = match self {
-            #[cfg(feature = "us")]
-            CexExchange::Coinbase => CoinbaseWsBuilder::make_from_normalized_map(map)?
-                .build_many_packed(connections_per_stream)?
-                .build_multistream_unconnected(max_retries),
-            #[cfg(feature = "us")]
-            CexExchange::Okex => OkexWsBuilder::make_from_normalized_map(
-                map,
-                #[cfg(not(feature = "non-us"))]
-                _exch_currency_proxy.unwrap_or(CexExchange::Coinbase),
-                #[cfg(feature = "non-us")]
-                _exch_currency_proxy.unwrap_or(CexExchange::Binance)
-            )?
-            .build_many_packed(connections_per_stream)?
-            .build_multistream_unconnected(max_retries),
-            #[cfg(feature = "non-us")]
-            CexExchange::Binance => BinanceWsBuilder::make_from_normalized_map(map)?
-                .build_many_packed(connections_per_stream)?
-                .build_multistream_unconnected(max_retries),
-            #[cfg(feature = "non-us")]
-            CexExchange::Kucoin => KucoinWsBuilder::make_from_normalized_map(map)?
-                .build_many_packed(connections_per_stream)?
-                .build_multistream_unconnected(max_retries),
-            #[cfg(feature = "non-us")]
-            CexExchange::Bybit => BybitWsBuilder::make_from_normalized_map(map)?
-                .build_many_packed(connections_per_stream)?
-                .build_multistream_unconnected(max_retries)
+            #[cfg(feature = "coinbase")]
+            CexExchange::Coinbase => options
+                .apply(CoinbaseWsBuilder::make_from_normalized_map(map, _credentials)?.build_many_packed(connections_per_stream)?)
+                .build_multistream_unconnected(),
+            #[cfg(feature = "okex")]
+            CexExchange::Okex => options
+                .apply(
+                    OkexWsBuilder::make_from_normalized_map(map, _exch_currency_proxy.unwrap_or(okex::DEFAULT_CURRENCY_PROXY), _credentials)?
+                        .build_many_packed(connections_per_stream)?
+                )
+                .build_multistream_unconnected(),
+            #[cfg(feature = "kraken")]
+            CexExchange::Kraken => options
+                .apply(KrakenWsBuilder::make_from_normalized_map(map)?.build_many_packed(connections_per_stream)?)
+                .build_multistream_unconnected(),
+            #[cfg(feature = "bitstamp")]
+            CexExchange::Bitstamp => options
+                .apply(BitstampWsBuilder::make_from_normalized_map(map)?.build_many_packed(connections_per_stream)?)
+                .build_multistream_unconnected(),
+            #[cfg(feature = "binance")]
+            CexExchange::Binance => options
+                .apply(BinanceWsBuilder::make_from_normalized_map(map, _credentials)?.build_many_packed(connections_per_stream)?)
+                .build_multistream_unconnected(),
+            #[cfg(feature = "kucoin")]
+            CexExchange::Kucoin => options
+                .apply(KucoinWsBuilder::make_from_normalized_map(map, _credentials)?.build_many_packed(connections_per_stream)?)
+                .build_multistream_unconnected(),
+            #[cfg(feature = "bybit")]
+            CexExchange::Bybit => options
+                .apply(BybitWsBuilder::make_from_normalized_map(map, _credentials)?.build_many_packed(connections_per_stream)?)
+                .build_multistream_unconnected(),
+            #[cfg(feature = "gateio")]
+            CexExchange::Gateio => options
+                .apply(GateioWsBuilder::make_from_normalized_map(map)?.build_many_packed(connections_per_stream)?)
+                .build_multistream_unconnected(),
+            #[cfg(feature = "deribit")]
+            CexExchange::Deribit => options
+                .apply(DeribitWsBuilder::make_from_normalized_map(map)?.build_many_packed(connections_per_stream)?)
+                .build_multistream_unconnected(),
+            #[cfg(feature = "bitget")]
+            CexExchange::Bitget => options
+                .apply(BitgetWsBuilder::make_from_normalized_map(map)?.build_many_packed(connections_per_stream)?)
+                .build_multistream_unconnected(),
+            #[cfg(feature = "mexc")]
+            CexExchange::Mexc => options
+                .apply(MexcWsBuilder::make_from_normalized_map(map)?.build_many_packed(connections_per_stream)?)
+                .build_multistream_unconnected()
         };
 
         Ok(res)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn build_multithreaded_multistream_ws_from_normalized(
         self,
         map: Vec<NormalizedWsChannels>,
         _exch_currency_proxy: Option<CexExchange>,
-        max_retries: Option<u64>,
         connections_per_stream: Option<usize>,
         handle: tokio::runtime::Handle,
-        number_threads: usize
-    ) -> eyre::Result<UnboundedReceiver<CombinedWsMessage>> {
+        number_threads: usize,
+        tx: &FanOutSender,
+        shutdown: &ShutdownHandle,
+        _credentials: Option<ExchangeCredentials>,
+        options: WsStreamOptions
+    ) -> eyre::Result<Vec<JoinHandle<()>>> {
         let res = match self {
-            #[cfg(feature = "us")]
-            CexExchange::Coinbase => CoinbaseWsBuilder::make_from_normalized_map(map)?
-                .build_many_packed(connections_per_stream)?
-                .spawn_multithreaded(number_threads, max_retries, handle),
-            #[cfg(feature = "us")]
-            CexExchange::Okex => OkexWsBuilder::make_from_normalized_map(
-                map,
-                #[cfg(not(feature = "non-us"))]
-                _exch_currency_proxy.unwrap_or(CexExchange::Coinbase),
-                #[cfg(feature = "non-us")]
-                _exch_currency_proxy.unwrap_or(CexExchange::Binance)
-            )?
-            .build_many_packed(connections_per_stream)?
-            .spawn_multithreaded(number_threads, max_retries, handle),
-            #[cfg(feature = "non-us")]
-            CexExchange::Binance => BinanceWsBuilder::make_from_normalized_map(map)?
-                .build_many_packed(connections_per_stream)?
-                .spawn_multithreaded(number_threads, max_retries, handle),
-            #[cfg(feature = "non-us")]
-            CexExchange::Kucoin => KucoinWsBuilder::make_from_normalized_map(map)?
-                .build_many_packed(connections_per_stream)?
-                .spawn_multithreaded(number_threads, max_retries, handle),
-            #[cfg(feature = "non-us")]
-            CexExchange::Bybit => BybitWsBuilder::make_from_normalized_map(map)?
-                .build_many_packed(connections_per_stream)?
-                .spawn_multithreaded(number_threads, max_retries, handle)
+            #[cfg(feature = "coinbase")]
+            CexExchange::Coinbase => options
+                .apply(CoinbaseWsBuilder::make_from_normalized_map(map, _credentials)?.build_many_packed(connections_per_stream)?)
+                .spawn_into(tx.clone(), shutdown.clone(), number_threads, handle),
+            #[cfg(feature = "okex")]
+            CexExchange::Okex => options
+                .apply(
+                    OkexWsBuilder::make_from_normalized_map(map, _exch_currency_proxy.unwrap_or(okex::DEFAULT_CURRENCY_PROXY), _credentials)?
+                        .build_many_packed(connections_per_stream)?
+                )
+                .spawn_into(tx.clone(), shutdown.clone(), number_threads, handle),
+            #[cfg(feature = "kraken")]
+            CexExchange::Kraken => options
+                .apply(KrakenWsBuilder::make_from_normalized_map(map)?.build_many_packed(connections_per_stream)?)
+                .spawn_into(tx.clone(), shutdown.clone(), number_threads, handle),
+            #[cfg(feature = "bitstamp")]
+            CexExchange::Bitstamp => options
+                .apply(BitstampWsBuilder::make_from_normalized_map(map)?.build_many_packed(connections_per_stream)?)
+                .spawn_into(tx.clone(), shutdown.clone(), number_threads, handle),
+            #[cfg(feature = "binance")]
+            CexExchange::Binance => options
+                .apply(BinanceWsBuilder::make_from_normalized_map(map, _credentials)?.build_many_packed(connections_per_stream)?)
+                .spawn_into(tx.clone(), shutdown.clone(), number_threads, handle),
+            #[cfg(feature = "kucoin")]
+            CexExchange::Kucoin => options
+                .apply(KucoinWsBuilder::make_from_normalized_map(map, _credentials)?.build_many_packed(connections_per_stream)?)
+                .spawn_into(tx.clone(), shutdown.clone(), number_threads, handle),
+            #[cfg(feature = "bybit")]
+            CexExchange::Bybit => options
+                .apply(BybitWsBuilder::make_from_normalized_map(map, _credentials)?.build_many_packed(connections_per_stream)?)
+                .spawn_into(tx.clone(), shutdown.clone(), number_threads, handle),
+            #[cfg(feature = "gateio")]
+            CexExchange::Gateio => options
+                .apply(GateioWsBuilder::make_from_normalized_map(map)?.build_many_packed(connections_per_stream)?)
+                .spawn_into(tx.clone(), shutdown.clone(), number_threads, handle),
+            #[cfg(feature = "deribit")]
+            CexExchange::Deribit => options
+                .apply(DeribitWsBuilder::make_from_normalized_map(map)?.build_many_packed(connections_per_stream)?)
+                .spawn_into(tx.clone(), shutdown.clone(), number_threads, handle),
+            #[cfg(feature = "bitget")]
+            CexExchange::Bitget => options
+                .apply(BitgetWsBuilder::make_from_normalized_map(map)?.build_many_packed(connections_per_stream)?)
+                .spawn_into(tx.clone(), shutdown.clone(), number_threads, handle),
+            #[cfg(feature = "mexc")]
+            CexExchange::Mexc => options
+                .apply(MexcWsBuilder::make_from_normalized_map(map)?.build_many_packed(connections_per_stream)?)
+                .spawn_into(tx.clone(), shutdown.clone(), number_threads, handle)
         };
 
         Ok(res)
@@ -159,48 +258,89 @@ impl CexExchange {
     ///
     /// if calling without a filter:
     /// ```
-    /// CexExchange::Okex.get_all_currencies::<EmptyFilter>(None).await;
+    /// CexExchange::Okex.get_all_currencies::<EmptyFilter>(&ExchangeApi::new(), None).await;
     /// ```
-    pub async fn get_all_currencies<F>(self, filter: Option<F>) -> Result<Vec<NormalizedCurrency>, RestApiError>
+    pub async fn get_all_currencies<F>(self, exchange_api: &ExchangeApi, filter: Option<F>) -> Result<Vec<NormalizedCurrency>, RestApiError>
     where
         F: ExchangeFilter<NormalizedCurrency>
     {
-        let exchange_api = ExchangeApi::new();
         let out = match self {
-            #[cfg(feature = "us")]
+            #[cfg(feature = "coinbase")]
             CexExchange::Coinbase => exchange_api
                 .all_currencies::<Coinbase>()
                 .await?
                 .normalize()
                 .take_currencies(filter)
                 .unwrap(),
-            #[cfg(feature = "non-us")]
+            #[cfg(feature = "binance")]
             CexExchange::Binance => exchange_api
                 .all_currencies::<Binance>()
                 .await?
                 .normalize()
                 .take_currencies(filter)
                 .unwrap(),
-            #[cfg(feature = "us")]
+            #[cfg(feature = "okex")]
             CexExchange::Okex => exchange_api
                 .all_currencies::<Okex>()
                 .await?
                 .normalize()
                 .take_currencies(filter)
                 .unwrap(),
-            #[cfg(feature = "non-us")]
+            #[cfg(feature = "kraken")]
+            CexExchange::Kraken => exchange_api
+                .all_currencies::<Kraken>()
+                .await?
+                .normalize()
+                .take_currencies(filter)
+                .unwrap(),
+            #[cfg(feature = "bitstamp")]
+            CexExchange::Bitstamp => exchange_api
+                .all_currencies::<Bitstamp>()
+                .await?
+                .normalize()
+                .take_currencies(filter)
+                .unwrap(),
+            #[cfg(feature = "kucoin")]
             CexExchange::Kucoin => exchange_api
                 .all_currencies::<Kucoin>()
                 .await?
                 .normalize()
                 .take_currencies(filter)
                 .unwrap(),
-            #[cfg(feature = "non-us")]
+            #[cfg(feature = "bybit")]
             CexExchange::Bybit => exchange_api
                 .all_currencies::<Bybit>()
                 .await?
                 .normalize()
                 .take_currencies(filter)
+                .unwrap(),
+            #[cfg(feature = "gateio")]
+            CexExchange::Gateio => exchange_api
+                .all_currencies::<Gateio>()
+                .await?
+                .normalize()
+                .take_currencies(filter)
+                .unwrap(),
+            #[cfg(feature = "deribit")]
+            CexExchange::Deribit => exchange_api
+                .all_currencies::<Deribit>()
+                .await?
+                .normalize()
+                .take_currencies(filter)
+                .unwrap(),
+            #[cfg(feature = "bitget")]
+            CexExchange::Bitget => exchange_api
+                .all_currencies::<Bitget>()
+                .await?
+                .normalize()
+                .take_currencies(filter)
+                .unwrap(),
+            #[cfg(feature = "mexc")]
+            CexExchange::Mexc => exchange_api
+                .all_currencies::<Mexc>()
+                .await?
+                .normalize()
+                .take_currencies(filter)
                 .unwrap()
         };
 
@@ -212,48 +352,362 @@ impl CexExchange {
     ///
     /// if calling without a filter:
     /// ```
-    /// CexExchange::Okex.get_all_instruments::<EmptyFilter>(None).await;
+    /// CexExchange::Okex.get_all_instruments::<EmptyFilter>(&ExchangeApi::new(), None).await;
     /// ```
-    pub async fn get_all_instruments<F>(self, filter: Option<F>) -> Result<Vec<NormalizedInstrument>, RestApiError>
+    pub async fn get_all_instruments<F>(self, exchange_api: &ExchangeApi, filter: Option<F>) -> Result<Vec<NormalizedInstrument>, RestApiError>
     where
         F: ExchangeFilter<NormalizedInstrument>
     {
-        let exchange_api = ExchangeApi::new();
         let out = match self {
-            #[cfg(feature = "us")]
+            #[cfg(feature = "coinbase")]
             CexExchange::Coinbase => exchange_api
                 .all_instruments::<Coinbase>()
                 .await?
                 .normalize()
                 .take_instruments(filter)
                 .unwrap(),
-            #[cfg(feature = "non-us")]
+            #[cfg(feature = "binance")]
             CexExchange::Binance => exchange_api
                 .all_instruments::<Binance>()
                 .await?
                 .normalize()
                 .take_instruments(filter)
                 .unwrap(),
-            #[cfg(feature = "us")]
+            #[cfg(feature = "okex")]
             CexExchange::Okex => exchange_api
                 .all_instruments::<Okex>()
                 .await?
                 .normalize()
                 .take_instruments(filter)
                 .unwrap(),
-            #[cfg(feature = "non-us")]
+            #[cfg(feature = "kraken")]
+            CexExchange::Kraken => exchange_api
+                .all_instruments::<Kraken>()
+                .await?
+                .normalize()
+                .take_instruments(filter)
+                .unwrap(),
+            #[cfg(feature = "bitstamp")]
+            CexExchange::Bitstamp => exchange_api
+                .all_instruments::<Bitstamp>()
+                .await?
+                .normalize()
+                .take_instruments(filter)
+                .unwrap(),
+            #[cfg(feature = "kucoin")]
             CexExchange::Kucoin => exchange_api
                 .all_instruments::<Kucoin>()
                 .await?
                 .normalize()
                 .take_instruments(filter)
                 .unwrap(),
-            #[cfg(feature = "non-us")]
+            #[cfg(feature = "bybit")]
             CexExchange::Bybit => exchange_api
                 .all_instruments::<Bybit>()
                 .await?
                 .normalize()
                 .take_instruments(filter)
+                .unwrap(),
+            #[cfg(feature = "gateio")]
+            CexExchange::Gateio => exchange_api
+                .all_instruments::<Gateio>()
+                .await?
+                .normalize()
+                .take_instruments(filter)
+                .unwrap(),
+            #[cfg(feature = "deribit")]
+            CexExchange::Deribit => exchange_api
+                .all_instruments::<Deribit>()
+                .await?
+                .normalize()
+                .take_instruments(filter)
+                .unwrap(),
+            #[cfg(feature = "bitget")]
+            CexExchange::Bitget => exchange_api
+                .all_instruments::<Bitget>()
+                .await?
+                .normalize()
+                .take_instruments(filter)
+                .unwrap(),
+            #[cfg(feature = "mexc")]
+            CexExchange::Mexc => exchange_api
+                .all_instruments::<Mexc>()
+                .await?
+                .normalize()
+                .take_instruments(filter)
+                .unwrap()
+        };
+
+        Ok(out)
+    }
+
+    /// gets the normalized candles of the pair starting in `start..end`
+    pub async fn get_candles(
+        self,
+        exchange_api: &ExchangeApi,
+        pair: NormalizedTradingPair,
+        interval: NormalizedCandleInterval,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>
+    ) -> Result<Vec<NormalizedCandle>, RestApiError> {
+        let out = match self {
+            #[cfg(feature = "coinbase")]
+            CexExchange::Coinbase => exchange_api
+                .candles::<Coinbase>(pair, interval, start, end)
+                .await?
+                .normalize()
+                .take_candles()
+                .unwrap(),
+            #[cfg(feature = "binance")]
+            CexExchange::Binance => exchange_api
+                .candles::<Binance>(pair, interval, start, end)
+                .await?
+                .normalize()
+                .take_candles()
+                .unwrap(),
+            #[cfg(feature = "okex")]
+            CexExchange::Okex => exchange_api
+                .candles::<Okex>(pair, interval, start, end)
+                .await?
+                .normalize()
+                .take_candles()
+                .unwrap(),
+            #[cfg(feature = "kraken")]
+            CexExchange::Kraken => exchange_api
+                .candles::<Kraken>(pair, interval, start, end)
+                .await?
+                .normalize()
+                .take_candles()
+                .unwrap(),
+            #[cfg(feature = "bitstamp")]
+            CexExchange::Bitstamp => exchange_api
+                .candles::<Bitstamp>(pair, interval, start, end)
+                .await?
+                .normalize()
+                .take_candles()
+                .unwrap(),
+            #[cfg(feature = "kucoin")]
+            CexExchange::Kucoin => exchange_api
+                .candles::<Kucoin>(pair, interval, start, end)
+                .await?
+                .normalize()
+                .take_candles()
+                .unwrap(),
+            #[cfg(feature = "bybit")]
+            CexExchange::Bybit => exchange_api
+                .candles::<Bybit>(pair, interval, start, end)
+                .await?
+                .normalize()
+                .take_candles()
+                .unwrap(),
+            #[cfg(feature = "gateio")]
+            CexExchange::Gateio => exchange_api
+                .candles::<Gateio>(pair, interval, start, end)
+                .await?
+                .normalize()
+                .take_candles()
+                .unwrap(),
+            #[cfg(feature = "deribit")]
+            CexExchange::Deribit => exchange_api
+                .candles::<Deribit>(pair, interval, start, end)
+                .await?
+                .normalize()
+                .take_candles()
+                .unwrap(),
+            #[cfg(feature = "bitget")]
+            CexExchange::Bitget => exchange_api
+                .candles::<Bitget>(pair, interval, start, end)
+                .await?
+                .normalize()
+                .take_candles()
+                .unwrap(),
+            #[cfg(feature = "mexc")]
+            CexExchange::Mexc => exchange_api
+                .candles::<Mexc>(pair, interval, start, end)
+                .await?
+                .normalize()
+                .take_candles()
+                .unwrap()
+        };
+
+        Ok(out)
+    }
+
+    /// gets the latest `limit` normalized trades of the pair
+    pub async fn get_recent_trades(
+        self,
+        exchange_api: &ExchangeApi,
+        pair: NormalizedTradingPair,
+        limit: usize
+    ) -> Result<Vec<NormalizedTrade>, RestApiError> {
+        let out = match self {
+            #[cfg(feature = "coinbase")]
+            CexExchange::Coinbase => exchange_api
+                .recent_trades::<Coinbase>(pair, limit)
+                .await?
+                .normalize()
+                .take_trades()
+                .unwrap(),
+            #[cfg(feature = "binance")]
+            CexExchange::Binance => exchange_api
+                .recent_trades::<Binance>(pair, limit)
+                .await?
+                .normalize()
+                .take_trades()
+                .unwrap(),
+            #[cfg(feature = "okex")]
+            CexExchange::Okex => exchange_api
+                .recent_trades::<Okex>(pair, limit)
+                .await?
+                .normalize()
+                .take_trades()
+                .unwrap(),
+            #[cfg(feature = "kraken")]
+            CexExchange::Kraken => exchange_api
+                .recent_trades::<Kraken>(pair, limit)
+                .await?
+                .normalize()
+                .take_trades()
+                .unwrap(),
+            #[cfg(feature = "bitstamp")]
+            CexExchange::Bitstamp => exchange_api
+                .recent_trades::<Bitstamp>(pair, limit)
+                .await?
+                .normalize()
+                .take_trades()
+                .unwrap(),
+            #[cfg(feature = "kucoin")]
+            CexExchange::Kucoin => exchange_api
+                .recent_trades::<Kucoin>(pair, limit)
+                .await?
+                .normalize()
+                .take_trades()
+                .unwrap(),
+            #[cfg(feature = "bybit")]
+            CexExchange::Bybit => exchange_api
+                .recent_trades::<Bybit>(pair, limit)
+                .await?
+                .normalize()
+                .take_trades()
+                .unwrap(),
+            #[cfg(feature = "gateio")]
+            CexExchange::Gateio => exchange_api
+                .recent_trades::<Gateio>(pair, limit)
+                .await?
+                .normalize()
+                .take_trades()
+                .unwrap(),
+            #[cfg(feature = "deribit")]
+            CexExchange::Deribit => exchange_api
+                .recent_trades::<Deribit>(pair, limit)
+                .await?
+                .normalize()
+                .take_trades()
+                .unwrap(),
+            #[cfg(feature = "bitget")]
+            CexExchange::Bitget => exchange_api
+                .recent_trades::<Bitget>(pair, limit)
+                .await?
+                .normalize()
+                .take_trades()
+                .unwrap(),
+            #[cfg(feature = "mexc")]
+            CexExchange::Mexc => exchange_api
+                .recent_trades::<Mexc>(pair, limit)
+                .await?
+                .normalize()
+                .take_trades()
+                .unwrap()
+        };
+
+        Ok(out)
+    }
+
+    /// gets all normalized trades of the pair in `from..to`
+    pub async fn get_historical_trades(
+        self,
+        exchange_api: &ExchangeApi,
+        pair: NormalizedTradingPair,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>
+    ) -> Result<Vec<NormalizedTrade>, RestApiError> {
+        let out = match self {
+            #[cfg(feature = "coinbase")]
+            CexExchange::Coinbase => exchange_api
+                .historical_trades::<Coinbase>(pair, from, to)
+                .await?
+                .normalize()
+                .take_trades()
+                .unwrap(),
+            #[cfg(feature = "binance")]
+            CexExchange::Binance => exchange_api
+                .historical_trades::<Binance>(pair, from, to)
+                .await?
+                .normalize()
+                .take_trades()
+                .unwrap(),
+            #[cfg(feature = "okex")]
+            CexExchange::Okex => exchange_api
+                .historical_trades::<Okex>(pair, from, to)
+                .await?
+                .normalize()
+                .take_trades()
+                .unwrap(),
+            #[cfg(feature = "kraken")]
+            CexExchange::Kraken => exchange_api
+                .historical_trades::<Kraken>(pair, from, to)
+                .await?
+                .normalize()
+                .take_trades()
+                .unwrap(),
+            #[cfg(feature = "bitstamp")]
+            CexExchange::Bitstamp => exchange_api
+                .historical_trades::<Bitstamp>(pair, from, to)
+                .await?
+                .normalize()
+                .take_trades()
+                .unwrap(),
+            #[cfg(feature = "kucoin")]
+            CexExchange::Kucoin => exchange_api
+                .historical_trades::<Kucoin>(pair, from, to)
+                .await?
+                .normalize()
+                .take_trades()
+                .unwrap(),
+            #[cfg(feature = "bybit")]
+            CexExchange::Bybit => exchange_api
+                .historical_trades::<Bybit>(pair, from, to)
+                .await?
+                .normalize()
+                .take_trades()
+                .unwrap(),
+            #[cfg(feature = "gateio")]
+            CexExchange::Gateio => exchange_api
+                .historical_trades::<Gateio>(pair, from, to)
+                .await?
+                .normalize()
+                .take_trades()
+                .unwrap(),
+            #[cfg(feature = "deribit")]
+            CexExchange::Deribit => exchange_api
+                .historical_trades::<Deribit>(pair, from, to)
+                .await?
+                .normalize()
+                .take_trades()
+                .unwrap(),
+            #[cfg(feature = "bitget")]
+            CexExchange::Bitget => exchange_api
+                .historical_trades::<Bitget>(pair, from, to)
+                .await?
+                .normalize()
+                .take_trades()
+                .unwrap(),
+            #[cfg(feature = "mexc")]
+            CexExchange::Mexc => exchange_api
+                .historical_trades::<Mexc>(pair, from, to)
+                .await?
+                .normalize()
+                .take_trades()
                 .unwrap()
         };
 
@@ -263,41 +717,116 @@ impl CexExchange {
     /// converts a normalized trading pair back into the native exchange's pair
     pub fn denormalize_raw_trading_pair(self, pair: NormalizedTradingPair) -> eyre::Result<String> {
         let out = match self {
-            #[cfg(feature = "us")]
+            #[cfg(feature = "coinbase")]
             CexExchange::Coinbase => {
                 let denorm_pair: CoinbaseTradingPair = pair.try_into()?;
                 denorm_pair.0
             }
-            #[cfg(feature = "non-us")]
+            #[cfg(feature = "binance")]
             CexExchange::Binance => {
                 let denorm_pair: BinanceTradingPair = pair.try_into()?;
                 denorm_pair.0
             }
-            #[cfg(feature = "us")]
+            #[cfg(feature = "okex")]
             CexExchange::Okex => {
                 let denorm_pair: OkexTradingPair = pair.try_into()?;
                 denorm_pair.0
             }
-            #[cfg(feature = "non-us")]
+            #[cfg(feature = "kraken")]
+            CexExchange::Kraken => {
+                let denorm_pair: KrakenTradingPair = pair.try_into()?;
+                denorm_pair.0
+            }
+            #[cfg(feature = "bitstamp")]
+            CexExchange::Bitstamp => {
+                let denorm_pair: BitstampTradingPair = pair.try_into()?;
+                denorm_pair.0
+            }
+            #[cfg(feature = "kucoin")]
             CexExchange::Kucoin => {
                 let denorm_pair: KucoinTradingPair = pair.try_into()?;
                 denorm_pair.0
             }
-            #[cfg(feature = "non-us")]
+            #[cfg(feature = "bybit")]
             CexExchange::Bybit => {
                 let denorm_pair: BybitTradingPair = pair.try_into()?;
                 denorm_pair.0
             }
+            #[cfg(feature = "gateio")]
+            CexExchange::Gateio => {
+                let denorm_pair: GateioTradingPair = pair.try_into()?;
+                denorm_pair.0
+            }
+            #[cfg(feature = "deribit")]
+            CexExchange::Deribit => {
+                let denorm_pair: DeribitTradingPair = pair.try_into()?;
+                denorm_pair.0
+            }
+            #[cfg(feature = "bitget")]
+            CexExchange::Bitget => {
+                let denorm_pair: BitgetTradingPair = pair.try_into()?;
+                denorm_pair.0
+            }
+            #[cfg(feature = "mexc")]
+            CexExchange::Mexc => {
+                let denorm_pair: MexcTradingPair = pair.try_into()?;
+                denorm_pair.0
+            }
         };
 
         Ok(out)
     }
 
+    /// deserializes a raw ws frame of the exchange the same way as the live
+    /// stream, an error is returned as a disconnect message
+    pub(crate) fn deserialize_ws_message(self, frame: Message) -> CombinedWsMessage {
+        let msg: Result<CombinedWsMessage, (WsError, String)> = match self {
+            #[cfg(feature = "coinbase")]
+            CexExchange::Coinbase => deserialize_ws_message::<Coinbase>(frame).map(Into::into),
+            #[cfg(feature = "binance")]
+            CexExchange::Binance => deserialize_ws_message::<Binance>(frame).map(Into::into),
+            #[cfg(feature = "okex")]
+            CexExchange::Okex => deserialize_ws_message::<Okex>(frame).map(Into::into),
+            #[cfg(feature = "kraken")]
+            CexExchange::Kraken => deserialize_ws_message::<Kraken>(frame).map(Into::into),
+            #[cfg(feature = "bitstamp")]
+            CexExchange::Bitstamp => deserialize_ws_message::<Bitstamp>(frame).map(Into::into),
+            #[cfg(feature = "kucoin")]
+            CexExchange::Kucoin => deserialize_ws_message::<Kucoin>(frame).map(Into::into),
+            #[cfg(feature = "bybit")]
+            CexExchange::Bybit => deserialize_ws_message::<Bybit>(frame).map(Into::into),
+            #[cfg(feature = "gateio")]
+            CexExchange::Gateio => deserialize_ws_message::<Gateio>(frame).map(Into::into),
+            #[cfg(feature = "deribit")]
+            CexExchange::Deribit => deserialize_ws_message::<Deribit>(frame).map(Into::into),
+            #[cfg(feature = "bitget")]
+            CexExchange::Bitget => deserialize_ws_message::<Bitget>(frame).map(Into::into),
+            #[cfg(feature = "mexc")]
+            CexExchange::Mexc => deserialize_ws_message::<Mexc>(frame).map(Into::into)
+        };
+
+        msg.unwrap_or_else(|(e, raw_message)| e.normalized_with_exchange(self, Some(raw_message)))
+    }
+
     #[allow(unreachable_patterns)]
-    pub fn bad_pair(self, msg: String) -> Option<NormalizedTradingPair> {
+    pub fn bad_pair(self, _msg: String) -> Option<NormalizedTradingPair> {
         match self {
-            CexExchange::Coinbase => CoinbaseTradingPair::parse_for_bad_pair(&msg).map(|p| p.normalize()),
-            CexExchange::Okex => OkexTradingPair::parse_for_bad_pair(&msg).map(|p| p.normalize()),
+            #[cfg(feature = "coinbase")]
+            CexExchange::Coinbase => CoinbaseTradingPair::parse_for_bad_pair(&_msg).map(|p| p.normalize()),
+            #[cfg(feature = "okex")]
+            CexExchange::Okex => OkexTradingPair::parse_for_bad_pair(&_msg).map(|p| p.normalize()),
+            #[cfg(feature = "kraken")]
+            CexExchange::Kraken => KrakenTradingPair::parse_for_bad_pair(&_msg).map(|p| p.normalize()),
+            #[cfg(feature = "bitstamp")]
+            CexExchange::Bitstamp => BitstampTradingPair::parse_for_bad_pair(&_msg).map(|p| p.normalize()),
+            #[cfg(feature = "gateio")]
+            CexExchange::Gateio => GateioTradingPair::parse_for_bad_pair(&_msg).map(|p| p.normalize()),
+            #[cfg(feature = "deribit")]
+            CexExchange::Deribit => DeribitTradingPair::parse_for_bad_pair(&_msg).map(|p| p.normalize()),
+            #[cfg(feature = "bitget")]
+            CexExchange::Bitget => BitgetTradingPair::parse_for_bad_pair(&_msg).map(|p| p.normalize()),
+            #[cfg(feature = "mexc")]
+            CexExchange::Mexc => MexcTradingPair::parse_for_bad_pair(&_msg).map(|p| p.normalize()),
             _ => None
         }
     }
@@ -306,16 +835,28 @@ impl CexExchange {
 impl Display for CexExchange {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            #[cfg(feature = "us")]
+            #[cfg(feature = "coinbase")]
             CexExchange::Coinbase => write!(f, "coinbase"),
-            #[cfg(feature = "us")]
+            #[cfg(feature = "okex")]
             CexExchange::Okex => write!(f, "okex"),
-            #[cfg(feature = "non-us")]
+            #[cfg(feature = "kraken")]
+            CexExchange::Kraken => write!(f, "kraken"),
+            #[cfg(feature = "bitstamp")]
+            CexExchange::Bitstamp => write!(f, "bitstamp"),
+            #[cfg(feature = "binance")]
             CexExchange::Binance => write!(f, "binance"),
-            #[cfg(feature = "non-us")]
+            #[cfg(feature = "kucoin")]
             CexExchange::Kucoin => write!(f, "kucoin"),
-            #[cfg(feature = "non-us")]
-            CexExchange::Bybit => write!(f, "bybit")
+            #[cfg(feature = "bybit")]
+            CexExchange::Bybit => write!(f, "bybit"),
+            #[cfg(feature = "gateio")]
+            CexExchange::Gateio => write!(f, "gateio"),
+            #[cfg(feature = "deribit")]
+            CexExchange::Deribit => write!(f, "deribit"),
+            #[cfg(feature = "bitget")]
+            CexExchange::Bitget => write!(f, "bitget"),
+            #[cfg(feature = "mexc")]
+            CexExchange::Mexc => write!(f, "mexc")
         }
     }
 }
@@ -327,16 +868,28 @@ impl FromStr for CexExchange {
         let str = s.to_lowercase();
 
         match str.as_str() {
-            #[cfg(feature = "us")]
+            #[cfg(feature = "coinbase")]
             "coinbase" => Ok(CexExchange::Coinbase),
-            #[cfg(feature = "us")]
+            #[cfg(feature = "okex")]
             "okex" => Ok(CexExchange::Okex),
-            #[cfg(feature = "non-us")]
+            #[cfg(feature = "kraken")]
+            "kraken" => Ok(CexExchange::Kraken),
+            #[cfg(feature = "bitstamp")]
+            "bitstamp" => Ok(CexExchange::Bitstamp),
+            #[cfg(feature = "binance")]
             "binance" => Ok(CexExchange::Binance),
-            #[cfg(feature = "non-us")]
+            #[cfg(feature = "kucoin")]
             "kucoin" => Ok(CexExchange::Kucoin),
-            #[cfg(feature = "non-us")]
+            #[cfg(feature = "bybit")]
             "bybit" => Ok(CexExchange::Bybit),
+            #[cfg(feature = "gateio")]
+            "gateio" => Ok(CexExchange::Gateio),
+            #[cfg(feature = "deribit")]
+            "deribit" => Ok(CexExchange::Deribit),
+            #[cfg(feature = "bitget")]
+            "bitget" => Ok(CexExchange::Bitget),
+            #[cfg(feature = "mexc")]
+            "mexc" => Ok(CexExchange::Mexc),
             _ => Err(eyre::ErrReport::msg(format!("'{s}' is not a valid exchange")))
         }
     }
@@ -344,15 +897,33 @@ impl FromStr for CexExchange {
 
 pub trait Exchange: Clone + Default + Send {
     const EXCHANGE: CexExchange;
-    type WsMessage: CriticalWsMessage + Send;
+    type WsMessage: CriticalWsMessage + Send + Unpin;
     type RestApiResult: for<'de> Deserialize<'de> + Into<CombinedRestApiResponse> + Debug + Send;
 
+    /// sets the urls the exchange's ws & rest requests are sent to (i.e. a
+    /// testnet or a proxy)
+    fn with_endpoints(self, endpoints: ExchangeEndpoints) -> Self;
+
+    /// takes the urls of the exchanges its rest calls are proxied to (i.e.
+    /// okx's currency proxy) from `api`
+    fn with_proxy_endpoints(self, _api: &ExchangeApi) -> Self {
+        self
+    }
+
     fn remove_bad_pair(&mut self, bad_pair: NormalizedTradingPair) -> bool;
 
-    fn make_ws_connection(&self) -> impl Future<Output = Result<WebSocketStream<MaybeTlsStream<TcpStream>>, WsError>> + Send;
+    /// connects & sends the subscription, the frames sent take tokens from
+    /// the connection's `subscribe_limiter`
+    fn make_ws_connection(
+        &self,
+        subscribe_limiter: &TokenBucket
+    ) -> impl Future<Output = Result<WebSocketStream<MaybeTlsStream<TcpStream>>, WsError>> + Send;
 
-    fn make_owned_ws_connection(self) -> impl Future<Output = Result<WebSocketStream<MaybeTlsStream<TcpStream>>, WsError>> + Send {
-        async move { Box::pin(self.make_ws_connection()).await }
+    fn make_owned_ws_connection(
+        self,
+        subscribe_limiter: Arc<TokenBucket>
+    ) -> impl Future<Output = Result<WebSocketStream<MaybeTlsStream<TcpStream>>, WsError>> + Send {
+        async move { Box::pin(self.make_ws_connection(&subscribe_limiter)).await }
     }
 
     fn rest_api_call(
@@ -360,4 +931,80 @@ pub trait Exchange: Clone + Default + Send {
         web_client: &reqwest::Client,
         api_channel: NormalizedRestApiRequest
     ) -> impl Future<Output = Result<Self::RestApiResult, RestApiError>> + Send;
+
+    /// fetches a snapshot of the pair's order book from the rest api, used by
+    /// the stream to resync a depth channel after a sequence gap
+    ///
+    /// `None` if the exchange's depth channels don't need a resync
+    fn order_book_snapshot(self, _pair: NormalizedTradingPair) -> impl Future<Output = Result<Option<Self::WsMessage>, RestApiError>> + Send {
+        async { Ok(None) }
+    }
+
+    /// the frames resubscribing to the depth channel of the pair, for
+    /// exchanges that push a new snapshot on subscribe instead of serving
+    /// one with a sequence id from the rest api
+    ///
+    /// `None` if the stream resyncs with [Exchange::order_book_snapshot]
+    fn resubscribe_depth(&self, _channel: &'static str, _pair: &NormalizedTradingPair) -> Option<Vec<Message>> {
+        None
+    }
+
+    /// applies the update to the subscription (so a reconnect keeps it),
+    /// returning the frames to send on the live socket
+    #[allow(clippy::result_large_err)]
+    fn update_subscription(&mut self, _update: WsSubscriptionUpdate) -> Result<Vec<Message>, WsError> {
+        Err(WsError::InvalidSubscriptionUpdate(format!("{} doesn't support changing the subscription of a running stream", Self::EXCHANGE)))
+    }
+
+    /// the frames unsubscribing from every channel of the stream, sent before
+    /// the close frame on shutdown
+    fn unsubscribe_all(&self) -> Vec<Message> {
+        Vec::new()
+    }
+
+    /// the application level ping the exchange expects (ex: okx's text `ping`)
+    /// & how often the stream sends it, `None` if the exchange is kept alive
+    /// by the protocol pings (answered by the stream)
+    ///
+    /// it's read after every (re)connection
+    fn heartbeat(&self) -> Option<(Duration, Message)> {
+        None
+    }
+
+    /// the exchange's reply to [Exchange::heartbeat], it's dropped by the
+    /// stream
+    fn is_heartbeat_reply(&self, _frame: &str) -> bool {
+        false
+    }
+}
+
+/// order placement & cancellation through the exchange's rest api, the
+/// exchange must be built with credentials
+pub trait ExchangeTrading: Exchange {
+    fn place_order(
+        &self,
+        web_client: &reqwest::Client,
+        order: NormalizedOrderRequest
+    ) -> impl Future<Output = Result<NormalizedOrderAck, RestApiError>> + Send;
+
+    fn cancel_order(
+        &self,
+        web_client: &reqwest::Client,
+        pair: NormalizedTradingPair,
+        order_id: String
+    ) -> impl Future<Output = Result<NormalizedOrderAck, RestApiError>> + Send;
+
+    /// cancels all open orders, only of `pair` if set
+    fn cancel_all(
+        &self,
+        web_client: &reqwest::Client,
+        pair: Option<NormalizedTradingPair>
+    ) -> impl Future<Output = Result<Vec<NormalizedOrderAck>, RestApiError>> + Send;
+
+    /// all open orders, only of `pair` if set
+    fn get_open_orders(
+        &self,
+        web_client: &reqwest::Client,
+        pair: Option<NormalizedTradingPair>
+    ) -> impl Future<Output = Result<Vec<NormalizedOrderUpdate>, RestApiError>> + Send;
 }
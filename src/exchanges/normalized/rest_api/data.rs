@@ -1,14 +1,16 @@
 use serde::Serialize;
 
 use crate::{
-    normalized::types::{NormalizedCurrency, NormalizedInstrument},
+    normalized::types::{NormalizedCandle, NormalizedCurrency, NormalizedInstrument, NormalizedTrade},
     ExchangeFilter
 };
 
 #[derive(Debug, Clone, Serialize)]
 pub enum NormalizedRestApiDataTypes {
     AllCurrencies(Vec<NormalizedCurrency>),
-    AllInstruments(Vec<NormalizedInstrument>)
+    AllInstruments(Vec<NormalizedInstrument>),
+    Candles(Vec<NormalizedCandle>),
+    Trades(Vec<NormalizedTrade>)
 }
 
 impl NormalizedRestApiDataTypes {
@@ -41,4 +43,18 @@ impl NormalizedRestApiDataTypes {
             _ => None
         }
     }
+
+    pub fn take_candles(self) -> Option<Vec<NormalizedCandle>> {
+        match self {
+            NormalizedRestApiDataTypes::Candles(vals) => Some(vals),
+            _ => None
+        }
+    }
+
+    pub fn take_trades(self) -> Option<Vec<NormalizedTrade>> {
+        match self {
+            NormalizedRestApiDataTypes::Trades(vals) => Some(vals),
+            _ => None
+        }
+    }
 }
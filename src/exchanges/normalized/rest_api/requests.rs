@@ -1,7 +1,31 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::normalized::types::{NormalizedCandleInterval, NormalizedTradingPair};
+
 #[derive(Debug, Clone, Serialize, Deserialize, Hash, PartialEq, Eq)]
 pub enum NormalizedRestApiRequest {
     AllCurrencies,
-    AllInstruments
+    AllInstruments,
+    /// all candles of the pair starting in `start..end`, paging through the
+    /// exchange's kline endpoint
+    Candles {
+        pair:     NormalizedTradingPair,
+        interval: NormalizedCandleInterval,
+        start:    DateTime<Utc>,
+        end:      DateTime<Utc>
+    },
+    /// the latest `limit` trades of the pair (capped by the exchange's max
+    /// page size), oldest first
+    RecentTrades {
+        pair:  NormalizedTradingPair,
+        limit: usize
+    },
+    /// all trades of the pair in `from..to` (oldest first), paging through the
+    /// exchange's trade history endpoint
+    HistoricalTrades {
+        pair: NormalizedTradingPair,
+        from: DateTime<Utc>,
+        to:   DateTime<Utc>
+    }
 }
@@ -1,45 +1,80 @@
 use serde::Serialize;
 
 use super::NormalizedRestApiDataTypes;
-#[cfg(feature = "non-us")]
-use crate::{
-    binance::rest_api::{BinanceInstrument, BinanceRestApiResponse, BinanceSymbol},
-    bybit::rest_api::{BybitCoin, BybitInstrument, BybitRestApiResponse},
-    kucoin::rest_api::{KucoinCurrency, KucoinRestApiResponse, KucoinSymbol}
-};
-#[cfg(feature = "us")]
-use crate::{
-    coinbase::rest_api::{CoinbaseCurrency, CoinbaseProduct, CoinbaseRestApiResponse},
-    okex::rest_api::{OkexCurrency, OkexInstrument, OkexRestApiResponse}
-};
+#[cfg(feature = "binance")]
+use crate::binance::rest_api::{BinanceInstrument, BinanceRestApiResponse, BinanceSymbol};
+#[cfg(feature = "bitget")]
+use crate::bitget::rest_api::{BitgetCurrency, BitgetInstrument, BitgetRestApiResponse};
+#[cfg(feature = "bitstamp")]
+use crate::bitstamp::rest_api::{BitstampCurrency, BitstampInstrument, BitstampRestApiResponse};
+#[cfg(feature = "bybit")]
+use crate::bybit::rest_api::{BybitCoin, BybitInstrument, BybitRestApiResponse};
+#[cfg(feature = "coinbase")]
+use crate::coinbase::rest_api::{CoinbaseCurrency, CoinbaseProduct, CoinbaseRestApiResponse};
+#[cfg(feature = "deribit")]
+use crate::deribit::rest_api::{DeribitCurrency, DeribitInstrument, DeribitRestApiResponse};
+#[cfg(feature = "gateio")]
+use crate::gateio::rest_api::{GateioCurrency, GateioInstrument, GateioRestApiResponse};
+#[cfg(feature = "kraken")]
+use crate::kraken::rest_api::{KrakenCurrency, KrakenInstrument, KrakenRestApiResponse};
+#[cfg(feature = "kucoin")]
+use crate::kucoin::rest_api::{KucoinCurrency, KucoinRestApiResponse, KucoinSymbol};
+#[cfg(feature = "mexc")]
+use crate::mexc::rest_api::{MexcCurrency, MexcInstrument, MexcRestApiResponse};
+#[cfg(feature = "okex")]
+use crate::okex::rest_api::{OkexCurrency, OkexInstrument, OkexRestApiResponse};
 
 #[derive(Debug, Clone, Serialize)]
 pub enum CombinedRestApiResponse {
-    #[cfg(feature = "us")]
+    #[cfg(feature = "coinbase")]
     Coinbase(CoinbaseRestApiResponse),
-    #[cfg(feature = "us")]
+    #[cfg(feature = "okex")]
     Okex(OkexRestApiResponse),
-    #[cfg(feature = "non-us")]
+    #[cfg(feature = "kraken")]
+    Kraken(KrakenRestApiResponse),
+    #[cfg(feature = "bitstamp")]
+    Bitstamp(BitstampRestApiResponse),
+    #[cfg(feature = "binance")]
     Binance(BinanceRestApiResponse),
-    #[cfg(feature = "non-us")]
+    #[cfg(feature = "kucoin")]
     Kucoin(KucoinRestApiResponse),
-    #[cfg(feature = "non-us")]
-    Bybit(BybitRestApiResponse)
+    #[cfg(feature = "bybit")]
+    Bybit(BybitRestApiResponse),
+    #[cfg(feature = "gateio")]
+    Gateio(GateioRestApiResponse),
+    #[cfg(feature = "deribit")]
+    Deribit(DeribitRestApiResponse),
+    #[cfg(feature = "bitget")]
+    Bitget(BitgetRestApiResponse),
+    #[cfg(feature = "mexc")]
+    Mexc(MexcRestApiResponse)
 }
 
 impl CombinedRestApiResponse {
     pub fn normalize(self) -> NormalizedRestApiDataTypes {
         match self {
-            #[cfg(feature = "us")]
+            #[cfg(feature = "coinbase")]
             CombinedRestApiResponse::Coinbase(c) => c.normalize(),
-            #[cfg(feature = "us")]
+            #[cfg(feature = "okex")]
             CombinedRestApiResponse::Okex(c) => c.normalize(),
-            #[cfg(feature = "non-us")]
+            #[cfg(feature = "kraken")]
+            CombinedRestApiResponse::Kraken(c) => c.normalize(),
+            #[cfg(feature = "bitstamp")]
+            CombinedRestApiResponse::Bitstamp(c) => c.normalize(),
+            #[cfg(feature = "binance")]
             CombinedRestApiResponse::Binance(c) => c.normalize(),
-            #[cfg(feature = "non-us")]
+            #[cfg(feature = "kucoin")]
             CombinedRestApiResponse::Kucoin(c) => c.normalize(),
-            #[cfg(feature = "non-us")]
-            CombinedRestApiResponse::Bybit(c) => c.normalize()
+            #[cfg(feature = "bybit")]
+            CombinedRestApiResponse::Bybit(c) => c.normalize(),
+            #[cfg(feature = "gateio")]
+            CombinedRestApiResponse::Gateio(c) => c.normalize(),
+            #[cfg(feature = "deribit")]
+            CombinedRestApiResponse::Deribit(c) => c.normalize(),
+            #[cfg(feature = "bitget")]
+            CombinedRestApiResponse::Bitget(c) => c.normalize(),
+            #[cfg(feature = "mexc")]
+            CombinedRestApiResponse::Mexc(c) => c.normalize()
         }
     }
 }
@@ -47,16 +82,28 @@ impl CombinedRestApiResponse {
 impl PartialEq<NormalizedRestApiDataTypes> for CombinedRestApiResponse {
     fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
         match self {
-            #[cfg(feature = "us")]
+            #[cfg(feature = "coinbase")]
             CombinedRestApiResponse::Coinbase(vals) => vals == other,
-            #[cfg(feature = "us")]
+            #[cfg(feature = "okex")]
             CombinedRestApiResponse::Okex(vals) => vals == other,
-            #[cfg(feature = "non-us")]
+            #[cfg(feature = "kraken")]
+            CombinedRestApiResponse::Kraken(vals) => vals == other,
+            #[cfg(feature = "bitstamp")]
+            CombinedRestApiResponse::Bitstamp(vals) => vals == other,
+            #[cfg(feature = "binance")]
             CombinedRestApiResponse::Binance(vals) => vals == other,
-            #[cfg(feature = "non-us")]
+            #[cfg(feature = "kucoin")]
             CombinedRestApiResponse::Kucoin(vals) => vals == other,
-            #[cfg(feature = "non-us")]
-            CombinedRestApiResponse::Bybit(vals) => vals == other
+            #[cfg(feature = "bybit")]
+            CombinedRestApiResponse::Bybit(vals) => vals == other,
+            #[cfg(feature = "gateio")]
+            CombinedRestApiResponse::Gateio(vals) => vals == other,
+            #[cfg(feature = "deribit")]
+            CombinedRestApiResponse::Deribit(vals) => vals == other,
+            #[cfg(feature = "bitget")]
+            CombinedRestApiResponse::Bitget(vals) => vals == other,
+            #[cfg(feature = "mexc")]
+            CombinedRestApiResponse::Mexc(vals) => vals == other
         }
     }
 }
@@ -79,6 +126,7 @@ macro_rules! combined_exchange {
                     self.[<take_ $exchange:lower>]().and_then(|v| v.[<take_ $instrument:lower s>](active_only))
                 }
 
+                #[allow(unreachable_patterns)]
                 pub fn[<take_ $exchange:lower>](self) -> Option<[<$exchange RestApiResponse>]> {
                     match self {
                         CombinedRestApiResponse::$exchange(val) => Some(val),
@@ -106,6 +154,7 @@ macro_rules! combined_exchange {
                     self.[<take_ $exchange:lower>]().and_then(|v| v.[<take_ $instrument:lower s>](active_only))
                 }
 
+                #[allow(unreachable_patterns)]
                 pub fn[<take_ $exchange:lower>](self) -> Option<[<$exchange RestApiResponse>]> {
                     match self {
                         CombinedRestApiResponse::$exchange(val) => Some(val),
@@ -117,17 +166,35 @@ macro_rules! combined_exchange {
     };
 }
 
-#[cfg(feature = "us")]
+#[cfg(feature = "coinbase")]
 combined_exchange!(Coinbase, (CURRENCY), Product);
 
-#[cfg(feature = "us")]
+#[cfg(feature = "okex")]
 combined_exchange!(Okex, (CURRENCY), Instrument);
 
-#[cfg(feature = "non-us")]
+#[cfg(feature = "kraken")]
+combined_exchange!(Kraken, (CURRENCY), Instrument);
+
+#[cfg(feature = "bitstamp")]
+combined_exchange!(Bitstamp, (CURRENCY), Instrument);
+
+#[cfg(feature = "kucoin")]
 combined_exchange!(Kucoin, (CURRENCY), Symbol);
 
-#[cfg(feature = "non-us")]
+#[cfg(feature = "binance")]
 combined_exchange!(Binance, Symbol, Instrument);
 
-#[cfg(feature = "non-us")]
+#[cfg(feature = "bybit")]
 combined_exchange!(Bybit, Coin, Instrument);
+
+#[cfg(feature = "gateio")]
+combined_exchange!(Gateio, (CURRENCY), Instrument);
+
+#[cfg(feature = "deribit")]
+combined_exchange!(Deribit, (CURRENCY), Instrument);
+
+#[cfg(feature = "bitget")]
+combined_exchange!(Bitget, (CURRENCY), Instrument);
+
+#[cfg(feature = "mexc")]
+combined_exchange!(Mexc, (CURRENCY), Instrument);
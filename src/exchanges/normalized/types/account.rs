@@ -0,0 +1,107 @@
+use std::{fmt::Display, str::FromStr};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{pairs::NormalizedTradingPair, NormalizedNumber, NormalizedOrderSide};
+use crate::CexExchange;
+
+/// new balance of a single asset in the account
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NormalizedBalanceUpdate {
+    pub exchange:  CexExchange,
+    /// ex: BTC
+    pub symbol:    String,
+    pub available: NormalizedNumber,
+    /// amount held in open orders
+    pub locked:    NormalizedNumber,
+    pub time:      DateTime<Utc>
+}
+
+/// new state of an order in the account
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NormalizedOrderUpdate {
+    pub exchange:        CexExchange,
+    pub pair:            NormalizedTradingPair,
+    pub order_id:        String,
+    pub client_order_id: Option<String>,
+    pub side:            NormalizedOrderSide,
+    /// ex: limit, market
+    pub order_type:      String,
+    pub status:          NormalizedOrderStatus,
+    /// `None` for market orders
+    pub price:           Option<NormalizedNumber>,
+    /// `None` if the exchange doesn't send it with the update (coinbase)
+    pub amount:          Option<NormalizedNumber>,
+    /// cumulative filled amount, `None` if the exchange doesn't send it with
+    /// the update (coinbase)
+    pub filled_amount:   Option<NormalizedNumber>,
+    pub time:            DateTime<Utc>,
+    /// the fill that triggered the update (if any)
+    pub last_fill:       Option<NormalizedFill>
+}
+
+/// a single execution of an order in the account
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NormalizedFill {
+    pub exchange:   CexExchange,
+    pub pair:       NormalizedTradingPair,
+    pub order_id:   String,
+    pub trade_id:   String,
+    /// the side of the account's order
+    pub side:       NormalizedOrderSide,
+    pub price:      NormalizedNumber,
+    pub amount:     NormalizedNumber,
+    /// fee paid (negative for rebates), `None` if the exchange doesn't send
+    /// the fee with the fill
+    pub fee:        Option<NormalizedNumber>,
+    pub fee_symbol: Option<String>,
+    pub is_maker:   Option<bool>,
+    pub time:       DateTime<Utc>
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NormalizedOrderStatus {
+    New,
+    PartiallyFilled,
+    Filled,
+    Canceled,
+    Rejected,
+    Expired
+}
+
+impl NormalizedOrderStatus {
+    /// if the order can't change anymore
+    pub fn is_closed(&self) -> bool {
+        !matches!(self, NormalizedOrderStatus::New | NormalizedOrderStatus::PartiallyFilled)
+    }
+}
+
+impl Display for NormalizedOrderStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NormalizedOrderStatus::New => write!(f, "new"),
+            NormalizedOrderStatus::PartiallyFilled => write!(f, "partially-filled"),
+            NormalizedOrderStatus::Filled => write!(f, "filled"),
+            NormalizedOrderStatus::Canceled => write!(f, "canceled"),
+            NormalizedOrderStatus::Rejected => write!(f, "rejected"),
+            NormalizedOrderStatus::Expired => write!(f, "expired")
+        }
+    }
+}
+
+impl FromStr for NormalizedOrderStatus {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "new" => Ok(NormalizedOrderStatus::New),
+            "partially-filled" => Ok(NormalizedOrderStatus::PartiallyFilled),
+            "filled" => Ok(NormalizedOrderStatus::Filled),
+            "canceled" => Ok(NormalizedOrderStatus::Canceled),
+            "rejected" => Ok(NormalizedOrderStatus::Rejected),
+            "expired" => Ok(NormalizedOrderStatus::Expired),
+            _ => Err(eyre::ErrReport::msg(format!("'{s}' is not a valid order status")))
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use std::{fmt::Display, str::FromStr};
+
+use chrono::{DateTime, Duration, Utc};
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+
+use super::{pairs::NormalizedTradingPair, NormalizedNumber};
+use crate::CexExchange;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NormalizedCandle {
+    pub exchange:    CexExchange,
+    pub pair:        NormalizedTradingPair,
+    pub interval:    NormalizedCandleInterval,
+    pub start_time:  DateTime<Utc>,
+    /// end of the candle's interval (exclusive)
+    pub end_time:    DateTime<Utc>,
+    pub open:        NormalizedNumber,
+    pub high:        NormalizedNumber,
+    pub low:         NormalizedNumber,
+    pub close:       NormalizedNumber,
+    /// volume in the base asset
+    pub volume:      NormalizedNumber,
+    /// number of trades in the candle (if the exchange provides it)
+    pub trade_count: Option<u64>,
+    /// if the candle is final, `None` if the exchange doesn't say
+    pub closed:      Option<bool>
+}
+
+#[derive(Debug, Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, ValueEnum)]
+pub enum NormalizedCandleInterval {
+    #[default]
+    OneMinute,
+    ThreeMinutes,
+    FiveMinutes,
+    FifteenMinutes,
+    ThirtyMinutes,
+    OneHour,
+    TwoHours,
+    FourHours,
+    SixHours,
+    TwelveHours,
+    OneDay,
+    OneWeek
+}
+
+impl NormalizedCandleInterval {
+    pub fn duration(&self) -> Duration {
+        Duration::seconds(self.as_secs() as i64)
+    }
+
+    pub fn as_secs(&self) -> u64 {
+        match self {
+            NormalizedCandleInterval::OneMinute => 60,
+            NormalizedCandleInterval::ThreeMinutes => 3 * 60,
+            NormalizedCandleInterval::FiveMinutes => 5 * 60,
+            NormalizedCandleInterval::FifteenMinutes => 15 * 60,
+            NormalizedCandleInterval::ThirtyMinutes => 30 * 60,
+            NormalizedCandleInterval::OneHour => 60 * 60,
+            NormalizedCandleInterval::TwoHours => 2 * 60 * 60,
+            NormalizedCandleInterval::FourHours => 4 * 60 * 60,
+            NormalizedCandleInterval::SixHours => 6 * 60 * 60,
+            NormalizedCandleInterval::TwelveHours => 12 * 60 * 60,
+            NormalizedCandleInterval::OneDay => 24 * 60 * 60,
+            NormalizedCandleInterval::OneWeek => 7 * 24 * 60 * 60
+        }
+    }
+
+    /// end of a candle starting at `start_time` (exclusive)
+    pub fn end_time(&self, start_time: DateTime<Utc>) -> DateTime<Utc> {
+        start_time + self.duration()
+    }
+}
+
+impl Display for NormalizedCandleInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NormalizedCandleInterval::OneMinute => write!(f, "1m"),
+            NormalizedCandleInterval::ThreeMinutes => write!(f, "3m"),
+            NormalizedCandleInterval::FiveMinutes => write!(f, "5m"),
+            NormalizedCandleInterval::FifteenMinutes => write!(f, "15m"),
+            NormalizedCandleInterval::ThirtyMinutes => write!(f, "30m"),
+            NormalizedCandleInterval::OneHour => write!(f, "1h"),
+            NormalizedCandleInterval::TwoHours => write!(f, "2h"),
+            NormalizedCandleInterval::FourHours => write!(f, "4h"),
+            NormalizedCandleInterval::SixHours => write!(f, "6h"),
+            NormalizedCandleInterval::TwelveHours => write!(f, "12h"),
+            NormalizedCandleInterval::OneDay => write!(f, "1d"),
+            NormalizedCandleInterval::OneWeek => write!(f, "1w")
+        }
+    }
+}
+
+impl FromStr for NormalizedCandleInterval {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1m" => Ok(NormalizedCandleInterval::OneMinute),
+            "3m" => Ok(NormalizedCandleInterval::ThreeMinutes),
+            "5m" => Ok(NormalizedCandleInterval::FiveMinutes),
+            "15m" => Ok(NormalizedCandleInterval::FifteenMinutes),
+            "30m" => Ok(NormalizedCandleInterval::ThirtyMinutes),
+            "1h" => Ok(NormalizedCandleInterval::OneHour),
+            "2h" => Ok(NormalizedCandleInterval::TwoHours),
+            "4h" => Ok(NormalizedCandleInterval::FourHours),
+            "6h" => Ok(NormalizedCandleInterval::SixHours),
+            "12h" => Ok(NormalizedCandleInterval::TwelveHours),
+            "1d" => Ok(NormalizedCandleInterval::OneDay),
+            "1w" => Ok(NormalizedCandleInterval::OneWeek),
+            _ => Err(eyre::ErrReport::msg(format!("'{s}' is not a valid candle interval")))
+        }
+    }
+}
@@ -16,6 +16,7 @@ pub struct NormalizedCurrency {
 impl NormalizedCurrency {
     /// takes a vector of [NormalizedCurrency] and deduplicates itself by
     /// combining wrapped and unwrapped assets
+    #[cfg(any(feature = "binance", feature = "bybit", feature = "coinbase", feature = "kucoin", feature = "okex"))]
     pub(crate) fn handle_unwrapped(mut normalized: Vec<Self>) -> Vec<Self> {
         let unwrapped = normalized
             .iter()
@@ -54,6 +55,7 @@ impl NormalizedCurrency {
     ///     - an unwrapped currency with the `blockchains.wrapped_currency`
     ///       field updated + the old value
     ///     - itself if no associated unwrapped currencies were found + none
+    #[cfg(any(feature = "binance", feature = "bybit", feature = "coinbase", feature = "kucoin", feature = "okex"))]
     fn combine_wrapped_assets(&self, unwrapped_currencies: &[NormalizedCurrency]) -> (Self, Option<Self>) {
         let un = self.name.to_lowercase().replace("wrapped", "");
         let unwrapped_name = un.trim();
@@ -106,19 +108,19 @@ pub struct WrappedCurrency {
     pub name:   String
 }
 
-#[cfg(test)]
+#[cfg(all(test, any(feature = "binance", feature = "bybit", feature = "coinbase", feature = "kucoin", feature = "okex")))]
 mod tests {
     use super::*;
 
-    #[cfg(feature = "non-us")]
-    const TEST_EXCHANGE: CexExchange = CexExchange::Binance;
-    #[cfg(not(feature = "non-us"))]
-    const TEST_EXCHANGE: CexExchange = CexExchange::Coinbase;
+    /// any enabled exchange, the wrapped asset handling doesn't depend on it
+    fn test_exchange() -> CexExchange {
+        CexExchange::vec_all()[0]
+    }
 
     #[test]
     fn test_combine_wrapped_assets_basic() {
         let wrapped = NormalizedCurrency {
-            exchange:     TEST_EXCHANGE,
+            exchange:     test_exchange(),
             symbol:       "WETH".to_string(),
             name:         "Wrapped Ethereum".to_string(),
             display_name: None,
@@ -132,7 +134,7 @@ mod tests {
         };
 
         let unwrapped = NormalizedCurrency {
-            exchange:     TEST_EXCHANGE,
+            exchange:     test_exchange(),
             symbol:       "ETH".to_string(),
             name:         "Ethereum".to_string(),
             display_name: None,
@@ -141,10 +143,10 @@ mod tests {
         };
 
         let to_remove = unwrapped.clone();
-        let combined = wrapped.combine_wrapped_assets(&vec![unwrapped]);
+        let combined = wrapped.combine_wrapped_assets(&[unwrapped]);
 
         let expected = NormalizedCurrency {
-            exchange:     TEST_EXCHANGE,
+            exchange:     test_exchange(),
             symbol:       "ETH".to_string(),
             name:         "Ethereum".to_string(),
             display_name: None,
@@ -163,7 +165,7 @@ mod tests {
     #[test]
     fn test_combine_wrapped_assets_2chain_address() {
         let wrapped = NormalizedCurrency {
-            exchange:     TEST_EXCHANGE,
+            exchange:     test_exchange(),
             symbol:       "WETH".to_string(),
             name:         "Wrapped Ethereum".to_string(),
             display_name: None,
@@ -177,7 +179,7 @@ mod tests {
         };
 
         let unwrapped = NormalizedCurrency {
-            exchange:     TEST_EXCHANGE,
+            exchange:     test_exchange(),
             symbol:       "ETH".to_string(),
             name:         "Ethereum".to_string(),
             display_name: None,
@@ -191,10 +193,10 @@ mod tests {
         };
 
         let to_remove = unwrapped.clone();
-        let combined = wrapped.combine_wrapped_assets(&vec![unwrapped]);
+        let combined = wrapped.combine_wrapped_assets(&[unwrapped]);
 
         let expected = NormalizedCurrency {
-            exchange:     TEST_EXCHANGE,
+            exchange:     test_exchange(),
             symbol:       "ETH".to_string(),
             name:         "Ethereum".to_string(),
             display_name: None,
@@ -221,7 +223,7 @@ mod tests {
     #[test]
     fn test_deduplicate_vec_self() {
         let wrapped = NormalizedCurrency {
-            exchange:     TEST_EXCHANGE,
+            exchange:     test_exchange(),
             symbol:       "WETH".to_string(),
             name:         "Wrapped Ethereum".to_string(),
             display_name: None,
@@ -235,7 +237,7 @@ mod tests {
         };
 
         let unwrapped = NormalizedCurrency {
-            exchange:     TEST_EXCHANGE,
+            exchange:     test_exchange(),
             symbol:       "ETH".to_string(),
             name:         "Ethereum".to_string(),
             display_name: None,
@@ -251,7 +253,7 @@ mod tests {
         let combined = NormalizedCurrency::handle_unwrapped(vec![wrapped.clone(), unwrapped.clone()]);
 
         let expected = NormalizedCurrency {
-            exchange:     TEST_EXCHANGE,
+            exchange:     test_exchange(),
             symbol:       "ETH".to_string(),
             name:         "Ethereum".to_string(),
             display_name: None,
@@ -4,7 +4,7 @@ use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use strum_macros::EnumIter;
 
-use super::NormalizedTradingPair;
+use super::{NormalizedNumber, NormalizedTradingPair};
 use crate::{exchanges::CexExchange, ExchangeFilter};
 
 #[derive(Debug, Clone, Serialize, PartialEq, PartialOrd)]
@@ -15,7 +15,11 @@ pub struct NormalizedInstrument {
     pub base_asset_symbol:  String,
     pub quote_asset_symbol: String,
     pub active:             bool,
-    pub futures_expiry:     Option<NaiveDate>
+    pub futures_expiry:     Option<NaiveDate>,
+    /// strike price of an option
+    pub option_strike:      Option<NormalizedNumber>,
+    /// call or put of an option
+    pub option_type:        Option<NormalizedOptionType>
 }
 
 #[derive(Debug, Default, Clone, Copy, Serialize, PartialEq, Eq, Hash, EnumIter, PartialOrd, Ord)]
@@ -87,6 +91,44 @@ impl Display for NormalizedTradingType {
     }
 }
 
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash, EnumIter, PartialOrd, Ord)]
+pub enum NormalizedOptionType {
+    Call,
+    Put
+}
+
+impl<'de> Deserialize<'de> for NormalizedOptionType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>
+    {
+        let s = String::deserialize(deserializer)?;
+
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl FromStr for NormalizedOptionType {
+    type Err = eyre::Report;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let s = value.to_lowercase();
+
+        match s.as_str() {
+            "call" | "c" => Ok(NormalizedOptionType::Call),
+            "put" | "p" => Ok(NormalizedOptionType::Put),
+            _ => Err(eyre::ErrReport::msg(format!("'{value}' is not a valid option type")))
+        }
+    }
+}
+
+impl Display for NormalizedOptionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = format!("{:?}", self).to_uppercase();
+        write!(f, "{}", s)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, PartialEq)]
 pub enum InstrumentFilter {
     Pair(String),
@@ -7,6 +7,9 @@ pub use currencies::*;
 mod pairs;
 pub use pairs::*;
 
+mod number;
+pub use number::*;
+
 mod trades;
 pub use trades::*;
 
@@ -15,3 +18,15 @@ pub use quotes::*;
 
 mod instruments;
 pub use instruments::*;
+
+mod orderbook;
+pub use orderbook::*;
+
+mod candles;
+pub use candles::*;
+
+mod account;
+pub use account::*;
+
+mod orders;
+pub use orders::*;
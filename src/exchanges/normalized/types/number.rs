@@ -0,0 +1,42 @@
+use std::cmp::Ordering;
+
+/// a price or amount of the trades, quotes, order books, candles, orders &
+/// account updates
+///
+/// an exact decimal (keeping the scale of the string or json number it was
+/// parsed from) with the `decimal` feature, a float otherwise
+#[cfg(not(feature = "decimal"))]
+pub type NormalizedNumber = f64;
+
+/// a price or amount of the trades, quotes, order books, candles, orders &
+/// account updates
+///
+/// an exact decimal (keeping the scale of the string or json number it was
+/// parsed from) with the `decimal` feature, a float otherwise
+#[cfg(feature = "decimal")]
+pub type NormalizedNumber = rust_decimal::Decimal;
+
+/// total ordering of the numbers
+#[cfg(not(feature = "decimal"))]
+pub(crate) fn cmp_numbers(a: &NormalizedNumber, b: &NormalizedNumber) -> Ordering {
+    a.total_cmp(b)
+}
+
+/// total ordering of the numbers
+#[cfg(feature = "decimal")]
+pub(crate) fn cmp_numbers(a: &NormalizedNumber, b: &NormalizedNumber) -> Ordering {
+    a.cmp(b)
+}
+
+#[cfg(all(test, feature = "decimal"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keeps_string_scale() {
+        let price: NormalizedNumber = "0.000012300".parse().unwrap();
+        assert_eq!(price.to_string(), "0.000012300");
+        assert_eq!(price, "0.0000123".parse().unwrap());
+        assert_eq!(cmp_numbers(&price, &"0.00001".parse().unwrap()), Ordering::Greater);
+    }
+}
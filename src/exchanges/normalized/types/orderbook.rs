@@ -0,0 +1,260 @@
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, HashMap}
+};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use super::{cmp_numbers, pairs::NormalizedTradingPair, NormalizedNumber};
+use crate::CexExchange;
+
+/// a single price level of an order book
+///
+/// an `amount` of 0 in a delta removes the level from the book
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Serialize, Deserialize)]
+pub struct NormalizedBookLevel {
+    pub price:  NormalizedNumber,
+    pub amount: NormalizedNumber
+}
+
+impl NormalizedBookLevel {
+    pub fn new(price: NormalizedNumber, amount: NormalizedNumber) -> Self {
+        Self { price, amount }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NormalizedOrderBookUpdateKind {
+    /// replaces the entire book
+    Snapshot,
+    /// changes the levels it contains, leaving the rest of the book untouched
+    Delta
+}
+
+/// a snapshot or delta of an order book as sent by an exchange
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NormalizedOrderBookUpdate {
+    pub exchange:        CexExchange,
+    pub pair:            NormalizedTradingPair,
+    pub time:            DateTime<Utc>,
+    pub kind:            NormalizedOrderBookUpdateKind,
+    pub bids:            Vec<NormalizedBookLevel>,
+    pub asks:            Vec<NormalizedBookLevel>,
+    /// first exchange sequence id covered by this update (if the exchange
+    /// provides one)
+    pub first_update_id: Option<u64>,
+    /// last exchange sequence id covered by this update (if the exchange
+    /// provides one)
+    pub last_update_id:  Option<u64>
+}
+
+impl NormalizedOrderBookUpdate {
+    pub fn is_snapshot(&self) -> bool {
+        self.kind == NormalizedOrderBookUpdateKind::Snapshot
+    }
+}
+
+/// a local order book for a single pair, built by applying
+/// [NormalizedOrderBookUpdate]s in order
+#[derive(Debug, Clone, PartialEq)]
+pub struct NormalizedOrderBook {
+    pub exchange:       CexExchange,
+    pub pair:           NormalizedTradingPair,
+    pub time:           Option<DateTime<Utc>>,
+    pub last_update_id: Option<u64>,
+    /// max number of levels kept per side
+    depth:              Option<usize>,
+    bids:               BTreeMap<BookPrice, NormalizedNumber>,
+    asks:               BTreeMap<BookPrice, NormalizedNumber>
+}
+
+impl NormalizedOrderBook {
+    pub fn new(exchange: CexExchange, pair: NormalizedTradingPair, depth: Option<usize>) -> Self {
+        Self { exchange, pair, time: None, last_update_id: None, depth, bids: BTreeMap::new(), asks: BTreeMap::new() }
+    }
+
+    /// applies an update to the book, returns false if the update was stale
+    /// (already covered by a previous update) and was skipped
+    pub fn apply(&mut self, update: &NormalizedOrderBookUpdate) -> bool {
+        if !update.is_snapshot() {
+            if let (Some(curr), Some(new)) = (self.last_update_id, update.last_update_id) {
+                if new <= curr {
+                    return false
+                }
+            }
+        } else {
+            self.bids.clear();
+            self.asks.clear();
+        }
+
+        Self::apply_levels(&mut self.bids, &update.bids);
+        Self::apply_levels(&mut self.asks, &update.asks);
+
+        if let Some(depth) = self.depth {
+            while self.bids.len() > depth {
+                self.bids.pop_first();
+            }
+            while self.asks.len() > depth {
+                self.asks.pop_last();
+            }
+        }
+
+        self.time = Some(update.time);
+        if update.last_update_id.is_some() {
+            self.last_update_id = update.last_update_id;
+        }
+
+        true
+    }
+
+    fn apply_levels(side: &mut BTreeMap<BookPrice, NormalizedNumber>, levels: &[NormalizedBookLevel]) {
+        levels.iter().for_each(|level| {
+            if level.amount == NormalizedNumber::default() {
+                side.remove(&BookPrice(level.price));
+            } else {
+                side.insert(BookPrice(level.price), level.amount);
+            }
+        });
+    }
+
+    /// clears the book, the next update applied should be a snapshot
+    pub fn clear(&mut self) {
+        self.bids.clear();
+        self.asks.clear();
+        self.time = None;
+        self.last_update_id = None;
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bids.is_empty() && self.asks.is_empty()
+    }
+
+    pub fn best_bid(&self) -> Option<NormalizedBookLevel> {
+        self.bids
+            .iter()
+            .next_back()
+            .map(|(p, a)| NormalizedBookLevel::new(p.0, *a))
+    }
+
+    pub fn best_ask(&self) -> Option<NormalizedBookLevel> {
+        self.asks
+            .iter()
+            .next()
+            .map(|(p, a)| NormalizedBookLevel::new(p.0, *a))
+    }
+
+    /// bids, ordered from best (highest) to worst
+    pub fn bids(&self) -> impl Iterator<Item = NormalizedBookLevel> + '_ {
+        self.bids
+            .iter()
+            .rev()
+            .map(|(p, a)| NormalizedBookLevel::new(p.0, *a))
+    }
+
+    /// asks, ordered from best (lowest) to worst
+    pub fn asks(&self) -> impl Iterator<Item = NormalizedBookLevel> + '_ {
+        self.asks
+            .iter()
+            .map(|(p, a)| NormalizedBookLevel::new(p.0, *a))
+    }
+}
+
+/// local order books for many exchanges & pairs
+#[derive(Debug, Clone, Default)]
+pub struct NormalizedOrderBooks {
+    /// max number of levels kept per side of each book
+    depth: Option<usize>,
+    books: HashMap<(CexExchange, NormalizedTradingPair), NormalizedOrderBook>
+}
+
+impl NormalizedOrderBooks {
+    pub fn new(depth: Option<usize>) -> Self {
+        Self { depth, books: HashMap::new() }
+    }
+
+    /// applies the update to the book of it's pair, creating the book if it
+    /// doesn't exist
+    pub fn apply(&mut self, update: &NormalizedOrderBookUpdate) -> &NormalizedOrderBook {
+        let book = self
+            .books
+            .entry((update.exchange, update.pair.clone()))
+            .or_insert_with(|| NormalizedOrderBook::new(update.exchange, update.pair.clone(), self.depth));
+        book.apply(update);
+
+        book
+    }
+
+    pub fn get(&self, exchange: CexExchange, pair: &NormalizedTradingPair) -> Option<&NormalizedOrderBook> {
+        self.books.get(&(exchange, pair.clone()))
+    }
+
+    pub fn remove(&mut self, exchange: CexExchange, pair: &NormalizedTradingPair) -> Option<NormalizedOrderBook> {
+        self.books.remove(&(exchange, pair.clone()))
+    }
+
+    pub fn books(&self) -> impl Iterator<Item = &NormalizedOrderBook> {
+        self.books.values()
+    }
+}
+
+/// total ordering over the level prices
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct BookPrice(NormalizedNumber);
+
+impl Eq for BookPrice {}
+
+impl PartialOrd for BookPrice {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BookPrice {
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_numbers(&self.0, &other.0)
+    }
+}
+
+#[cfg(all(test, feature = "bybit"))]
+mod tests {
+    use super::*;
+
+    fn level(price: f64, amount: f64) -> NormalizedBookLevel {
+        NormalizedBookLevel::new(price.to_string().parse().unwrap(), amount.to_string().parse().unwrap())
+    }
+
+    fn update(kind: NormalizedOrderBookUpdateKind, bids: &[(f64, f64)], asks: &[(f64, f64)], update_id: u64) -> NormalizedOrderBookUpdate {
+        NormalizedOrderBookUpdate {
+            exchange: CexExchange::Bybit,
+            pair: NormalizedTradingPair::new_base_quote(CexExchange::Bybit, "ETH", "USDT", None, None),
+            time: Utc::now(),
+            kind,
+            bids: bids.iter().map(|(p, a)| level(*p, *a)).collect(),
+            asks: asks.iter().map(|(p, a)| level(*p, *a)).collect(),
+            first_update_id: Some(update_id),
+            last_update_id: Some(update_id)
+        }
+    }
+
+    #[test]
+    fn test_apply_snapshot_and_deltas() {
+        let mut books = NormalizedOrderBooks::new(Some(2));
+
+        let snapshot = update(NormalizedOrderBookUpdateKind::Snapshot, &[(10.0, 1.0), (9.0, 2.0), (8.0, 3.0)], &[(11.0, 1.0), (12.0, 2.0)], 1);
+        let book = books.apply(&snapshot);
+        assert_eq!(book.best_bid(), Some(level(10.0, 1.0)));
+        assert_eq!(book.bids().count(), 2);
+
+        let delta = update(NormalizedOrderBookUpdateKind::Delta, &[(10.0, 0.0), (9.5, 4.0)], &[(11.0, 5.0)], 2);
+        let book = books.apply(&delta);
+        assert_eq!(book.bids().collect::<Vec<_>>(), vec![level(9.5, 4.0), level(9.0, 2.0)]);
+        assert_eq!(book.best_ask(), Some(level(11.0, 5.0)));
+
+        let stale = update(NormalizedOrderBookUpdateKind::Delta, &[(20.0, 1.0)], &[], 2);
+        let book = books.apply(&stale);
+        assert_eq!(book.best_bid(), Some(level(9.5, 4.0)));
+        assert_eq!(book.last_update_id, Some(2));
+    }
+}
@@ -0,0 +1,192 @@
+use std::{fmt::Display, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use super::{pairs::NormalizedTradingPair, NormalizedNumber, NormalizedOrderStatus};
+use crate::CexExchange;
+
+/// an order to place through [crate::ExchangeTrading]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NormalizedOrderRequest {
+    pub pair:            NormalizedTradingPair,
+    pub side:            NormalizedOrderSide,
+    pub order_type:      NormalizedOrderType,
+    /// ignored for market orders, defaults to
+    /// [NormalizedTimeInForce::GoodTilCanceled] for limit orders
+    pub time_in_force:   Option<NormalizedTimeInForce>,
+    /// required for limit orders
+    pub price:           Option<NormalizedNumber>,
+    /// in the base asset
+    pub amount:          NormalizedNumber,
+    pub client_order_id: Option<String>
+}
+
+impl NormalizedOrderRequest {
+    pub fn new_limit(pair: NormalizedTradingPair, side: NormalizedOrderSide, price: NormalizedNumber, amount: NormalizedNumber) -> Self {
+        Self { pair, side, order_type: NormalizedOrderType::Limit, time_in_force: None, price: Some(price), amount, client_order_id: None }
+    }
+
+    pub fn new_market(pair: NormalizedTradingPair, side: NormalizedOrderSide, amount: NormalizedNumber) -> Self {
+        Self { pair, side, order_type: NormalizedOrderType::Market, time_in_force: None, price: None, amount, client_order_id: None }
+    }
+
+    pub fn with_time_in_force(mut self, time_in_force: NormalizedTimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    pub fn with_client_order_id(mut self, client_order_id: impl ToString) -> Self {
+        self.client_order_id = Some(client_order_id.to_string());
+        self
+    }
+
+    /// the time in force of a limit order, `None` for market orders
+    #[cfg(any(feature = "binance", feature = "bybit", feature = "coinbase", feature = "kucoin", feature = "okex"))]
+    pub(crate) fn limit_time_in_force(&self) -> Option<NormalizedTimeInForce> {
+        (self.order_type == NormalizedOrderType::Limit).then(|| self.time_in_force.unwrap_or_default())
+    }
+
+    /// the price, erroring if a limit order doesn't have one
+    #[cfg(any(feature = "binance", feature = "bybit", feature = "coinbase", feature = "kucoin", feature = "okex"))]
+    pub(crate) fn limit_price(&self) -> eyre::Result<Option<NormalizedNumber>> {
+        match (self.order_type, self.price) {
+            (NormalizedOrderType::Limit, None) => Err(eyre::ErrReport::msg("limit orders require a price".to_string())),
+            (NormalizedOrderType::Limit, price) => Ok(price),
+            (NormalizedOrderType::Market, _) => Ok(None)
+        }
+    }
+}
+
+/// response of the exchange to placing or canceling an order
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct NormalizedOrderAck {
+    pub exchange:        CexExchange,
+    /// `None` if the exchange doesn't send it with the response
+    pub pair:            Option<NormalizedTradingPair>,
+    pub order_id:        String,
+    pub client_order_id: Option<String>,
+    /// `None` if the exchange doesn't send it with the response
+    pub status:          Option<NormalizedOrderStatus>
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NormalizedOrderSide {
+    Buy,
+    Sell
+}
+
+impl NormalizedOrderSide {
+    pub fn opposite(self) -> Self {
+        match self {
+            NormalizedOrderSide::Buy => NormalizedOrderSide::Sell,
+            NormalizedOrderSide::Sell => NormalizedOrderSide::Buy
+        }
+    }
+}
+
+impl Display for NormalizedOrderSide {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NormalizedOrderSide::Buy => write!(f, "buy"),
+            NormalizedOrderSide::Sell => write!(f, "sell")
+        }
+    }
+}
+
+impl FromStr for NormalizedOrderSide {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "buy" => Ok(NormalizedOrderSide::Buy),
+            "sell" => Ok(NormalizedOrderSide::Sell),
+            _ => Err(eyre::ErrReport::msg(format!("'{s}' is not a valid order side")))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NormalizedOrderType {
+    Limit,
+    Market
+}
+
+impl Display for NormalizedOrderType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NormalizedOrderType::Limit => write!(f, "limit"),
+            NormalizedOrderType::Market => write!(f, "market")
+        }
+    }
+}
+
+impl FromStr for NormalizedOrderType {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "limit" => Ok(NormalizedOrderType::Limit),
+            "market" => Ok(NormalizedOrderType::Market),
+            _ => Err(eyre::ErrReport::msg(format!("'{s}' is not a valid order type")))
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NormalizedTimeInForce {
+    #[default]
+    GoodTilCanceled,
+    ImmediateOrCancel,
+    FillOrKill,
+    /// rejected if any part of it would fill immediately
+    PostOnly
+}
+
+impl Display for NormalizedTimeInForce {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NormalizedTimeInForce::GoodTilCanceled => write!(f, "gtc"),
+            NormalizedTimeInForce::ImmediateOrCancel => write!(f, "ioc"),
+            NormalizedTimeInForce::FillOrKill => write!(f, "fok"),
+            NormalizedTimeInForce::PostOnly => write!(f, "post-only")
+        }
+    }
+}
+
+impl FromStr for NormalizedTimeInForce {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "gtc" => Ok(NormalizedTimeInForce::GoodTilCanceled),
+            "ioc" => Ok(NormalizedTimeInForce::ImmediateOrCancel),
+            "fok" => Ok(NormalizedTimeInForce::FillOrKill),
+            "post-only" => Ok(NormalizedTimeInForce::PostOnly),
+            _ => Err(eyre::ErrReport::msg(format!("'{s}' is not a valid time in force")))
+        }
+    }
+}
+
+#[cfg(all(test, feature = "binance"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_limit_defaults() {
+        let pair = NormalizedTradingPair::new_base_quote(CexExchange::Binance, "ETH", "USDT", None, None);
+        let (price, amount): (NormalizedNumber, NormalizedNumber) = ("3000.0".parse().unwrap(), "0.1".parse().unwrap());
+
+        let limit = NormalizedOrderRequest::new_limit(pair.clone(), NormalizedOrderSide::Buy, price, amount);
+        assert_eq!(limit.limit_time_in_force(), Some(NormalizedTimeInForce::GoodTilCanceled));
+        assert_eq!(limit.limit_price().unwrap(), Some(price));
+
+        let market =
+            NormalizedOrderRequest::new_market(pair.clone(), NormalizedOrderSide::Sell, amount).with_time_in_force(NormalizedTimeInForce::FillOrKill);
+        assert_eq!(market.limit_time_in_force(), None);
+        assert_eq!(market.limit_price().unwrap(), None);
+
+        let mut no_price = NormalizedOrderRequest::new_limit(pair, NormalizedOrderSide::Buy, price, amount);
+        no_price.price = None;
+        assert!(no_price.limit_price().is_err());
+    }
+}
@@ -1,17 +1,21 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use super::pairs::NormalizedTradingPair;
+use super::{pairs::NormalizedTradingPair, NormalizedNumber};
 use crate::CexExchange;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NormalizedQuote {
-    pub exchange:   CexExchange,
-    pub pair:       NormalizedTradingPair,
-    pub time:       DateTime<Utc>,
-    pub ask_amount: f64,
-    pub ask_price:  f64,
-    pub bid_amount: f64,
-    pub bid_price:  f64,
-    pub quote_id:   Option<String>
+    pub exchange:      CexExchange,
+    pub pair:          NormalizedTradingPair,
+    /// the exchange's timestamp of the event, `None` if the exchange doesn't
+    /// send one
+    pub exchange_time: Option<DateTime<Utc>>,
+    /// when the frame (or rest response) was received, before it was parsed
+    pub received_at:   DateTime<Utc>,
+    pub ask_amount:    NormalizedNumber,
+    pub ask_price:     NormalizedNumber,
+    pub bid_amount:    NormalizedNumber,
+    pub bid_price:     NormalizedNumber,
+    pub quote_id:      Option<String>
 }
@@ -1,16 +1,79 @@
+use std::fmt::Display;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
-use super::pairs::NormalizedTradingPair;
+use super::{pairs::NormalizedTradingPair, NormalizedNumber, NormalizedOrderSide};
 use crate::CexExchange;
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct NormalizedTrade {
-    pub exchange: CexExchange,
-    pub pair:     NormalizedTradingPair,
-    pub time:     DateTime<Utc>,
-    pub side:     String,
-    pub price:    f64,
-    pub amount:   f64,
-    pub trade_id: Option<String>
+    pub exchange:      CexExchange,
+    pub pair:          NormalizedTradingPair,
+    /// the exchange's timestamp of the event, `None` if the exchange doesn't
+    /// send one
+    pub exchange_time: Option<DateTime<Utc>>,
+    /// when the frame (or rest response) was received, before it was parsed
+    pub received_at:   DateTime<Utc>,
+    /// the side of the taker
+    pub side:          TradeSide,
+    pub price:         NormalizedNumber,
+    pub amount:        NormalizedNumber,
+    pub trade_id:      Option<String>
+}
+
+/// the side of the taker (the aggressor) of a trade, `Buy` if it lifted an
+/// ask & `Sell` if it hit a bid
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TradeSide {
+    Buy,
+    Sell,
+    Unknown
+}
+
+impl TradeSide {
+    /// the side from the taker's 'buy' or 'sell' (in any case)
+    pub fn from_taker_side(side: &str) -> Self {
+        if side.eq_ignore_ascii_case("buy") {
+            TradeSide::Buy
+        } else if side.eq_ignore_ascii_case("sell") {
+            TradeSide::Sell
+        } else {
+            TradeSide::Unknown
+        }
+    }
+
+    /// the side from the maker's 'buy' or 'sell' (in any case), the taker
+    /// being on the other side
+    pub fn from_maker_side(side: &str) -> Self {
+        Self::from_taker_side(side).opposite()
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            TradeSide::Buy => TradeSide::Sell,
+            TradeSide::Sell => TradeSide::Buy,
+            TradeSide::Unknown => TradeSide::Unknown
+        }
+    }
+}
+
+impl From<NormalizedOrderSide> for TradeSide {
+    fn from(value: NormalizedOrderSide) -> Self {
+        match value {
+            NormalizedOrderSide::Buy => TradeSide::Buy,
+            NormalizedOrderSide::Sell => TradeSide::Sell
+        }
+    }
+}
+
+impl Display for TradeSide {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TradeSide::Buy => write!(f, "buy"),
+            TradeSide::Sell => write!(f, "sell"),
+            TradeSide::Unknown => write!(f, "unknown")
+        }
+    }
 }
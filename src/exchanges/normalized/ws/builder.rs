@@ -1,14 +1,19 @@
-use std::{collections::HashMap, pin::Pin};
+use std::{collections::HashMap, fmt::Debug, time::Duration};
 
-use futures::{stream::select_all, Stream};
-use tokio_stream::wrappers::UnboundedReceiverStream;
-
-use super::CombinedWsMessage;
 use crate::{
-    clients::ws::MutliWsStream,
-    exchanges::normalized::{
-        types::RawTradingPair,
-        ws::channels::{NormalizedWsChannelKinds, NormalizedWsChannels}
+    clients::{
+        ws::{
+            fan_out_channel, FanOutConfig, FrameRecorder, MultithreadedWsStream, MutliWsStream, MutliWsStreamBuilder, OverflowPolicy,
+            ReconnectPolicy, ShutdownHandle
+        },
+        ExchangeCredentials, ExchangeEndpoints
+    },
+    exchanges::{
+        normalized::{
+            types::{NormalizedCandleInterval, RawTradingPair},
+            ws::channels::{NormalizedWsChannelKinds, NormalizedWsChannels}
+        },
+        Exchange
     },
     CexExchange
 };
@@ -18,7 +23,18 @@ pub struct NormalizedExchangeBuilder {
     pub(crate) ws_exchanges: HashMap<CexExchange, HashMap<NormalizedWsChannelKinds, NormalizedWsChannels>>,
     /// proxy exchange to get symbols for exchanges that don't have a direct api
     /// link
-    exch_currency_proxy:     Option<CexExchange>
+    exch_currency_proxy:     Option<CexExchange>,
+    /// credentials for the exchanges with the account channel
+    credentials:             HashMap<CexExchange, ExchangeCredentials>,
+    /// urls to connect to instead of the exchanges' defaults
+    endpoints:               HashMap<CexExchange, ExchangeEndpoints>,
+    /// records the raw frames of every stream
+    recorder:                Option<FrameRecorder>,
+    /// reconnects a stream if it receives nothing for this long
+    idle_timeout:            Option<Duration>,
+    reconnect_policy:        ReconnectPolicy,
+    /// the channel every thread of the multithreaded client sends through
+    fan_out:                 FanOutConfig
 }
 
 impl NormalizedExchangeBuilder {
@@ -62,6 +78,46 @@ impl NormalizedExchangeBuilder {
         self
     }
 
+    /// sets the credentials used to authenticate the account channel of an
+    /// exchange
+    pub fn with_credentials(mut self, exchange: CexExchange, credentials: ExchangeCredentials) -> Self {
+        self.credentials.insert(exchange, credentials);
+        self
+    }
+
+    /// sets the urls an exchange's streams are connected through (i.e. a
+    /// testnet or a proxy)
+    pub fn with_endpoints(mut self, exchange: CexExchange, endpoints: ExchangeEndpoints) -> Self {
+        self.endpoints.insert(exchange, endpoints);
+        self
+    }
+
+    /// records every raw text & binary frame received by the streams
+    pub fn with_recorder(mut self, recorder: FrameRecorder) -> Self {
+        self.recorder = Some(recorder);
+        self
+    }
+
+    /// reconnects a stream if it receives nothing (including pings) for
+    /// `timeout`
+    pub fn with_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.idle_timeout = Some(timeout);
+        self
+    }
+
+    /// how the streams reconnect after they're dropped
+    pub fn with_reconnect_policy(mut self, reconnect_policy: ReconnectPolicy) -> Self {
+        self.reconnect_policy = reconnect_policy;
+        self
+    }
+
+    /// bounds the channel of the multithreaded client to `capacity` messages,
+    /// handling the overflow with `overflow`
+    pub fn with_bounded_channel(mut self, capacity: usize, overflow: OverflowPolicy) -> Self {
+        self.fan_out = FanOutConfig::bounded(capacity, overflow);
+        self
+    }
+
     /// adds trading pairs to all channels for all exchanges
     pub fn add_pairs_all_channels_all_exchanges<S>(
         &mut self,
@@ -105,17 +161,43 @@ impl NormalizedExchangeBuilder {
             .add_pairs(exchange, pairs);
     }
 
+    /// sets the interval of the candles channel
+    pub fn set_candle_interval(&mut self, exchange: CexExchange, interval: NormalizedCandleInterval) {
+        self.ws_exchanges
+            .entry(exchange)
+            .or_default()
+            .entry(NormalizedWsChannelKinds::Candles)
+            .or_insert(NormalizedWsChannels::new_default(NormalizedWsChannelKinds::Candles))
+            .set_interval(interval);
+    }
+
+    fn stream_options(&self, exchange: CexExchange) -> WsStreamOptions {
+        WsStreamOptions {
+            endpoints:        self.endpoints.get(&exchange).cloned().unwrap_or_default(),
+            recorder:         self.recorder.clone(),
+            idle_timeout:     self.idle_timeout,
+            reconnect_policy: self.reconnect_policy
+        }
+    }
+
     /// builds the multistream ws client
-    pub fn build_all_multistream(self, max_retries: Option<u64>, connections_per_stream: Option<usize>) -> eyre::Result<Option<MutliWsStream>> {
+    pub fn build_all_multistream(mut self, connections_per_stream: Option<usize>) -> eyre::Result<Option<MutliWsStream>> {
         let mut multistream_ws: Option<MutliWsStream> = None;
 
-        self.ws_exchanges.into_iter().try_for_each(|(exch, map)| {
+        let ws_exchanges = std::mem::take(&mut self.ws_exchanges);
+        ws_exchanges.into_iter().try_for_each(|(exch, map)| {
             let channel_map = map
                 .into_values()
                 .flat_map(|channel| channel.make_many_single())
                 .collect::<Vec<_>>();
 
-            let new_stream = exch.build_multistream_ws_from_normalized(channel_map, max_retries, connections_per_stream, self.exch_currency_proxy)?;
+            let new_stream = exch.build_multistream_ws_from_normalized(
+                channel_map,
+                connections_per_stream,
+                self.exch_currency_proxy,
+                self.credentials.get(&exch).cloned(),
+                self.stream_options(exch)
+            )?;
             if let Some(ws) = multistream_ws.take() {
                 multistream_ws = Some(ws.combine_other(new_stream))
             } else {
@@ -128,16 +210,22 @@ impl NormalizedExchangeBuilder {
         Ok(multistream_ws)
     }
 
-    /// builds the multithreaded multistream ws client
+    /// builds the multithreaded multistream ws client, every exchange sending
+    /// through the same channel
     pub fn build_all_multithreaded(
-        self,
+        mut self,
         handle: tokio::runtime::Handle,
         number_threads: usize,
-        max_retries: Option<u64>,
         connections_per_stream: Option<usize>
-    ) -> eyre::Result<Option<Pin<Box<dyn Stream<Item = CombinedWsMessage> + Send>>>> {
-        let all_streams = self
-            .ws_exchanges
+    ) -> eyre::Result<Option<MultithreadedWsStream>> {
+        if self.ws_exchanges.is_empty() {
+            return Ok(None)
+        }
+
+        let (tx, rx) = fan_out_channel(self.fan_out);
+        let shutdown = ShutdownHandle::new();
+        let ws_exchanges = std::mem::take(&mut self.ws_exchanges);
+        let threads = ws_exchanges
             .into_iter()
             .map(|(exch, map)| {
                 let channel_map = map
@@ -145,22 +233,49 @@ impl NormalizedExchangeBuilder {
                     .flat_map(|channel| channel.make_many_single())
                     .collect::<Vec<_>>();
 
-                let new_stream = exch.build_multithreaded_multistream_ws_from_normalized(
+                exch.build_multithreaded_multistream_ws_from_normalized(
                     channel_map,
                     self.exch_currency_proxy,
-                    max_retries,
                     connections_per_stream,
                     handle.clone(),
-                    number_threads
-                )?;
-                Ok::<_, eyre::Report>(UnboundedReceiverStream::new(new_stream))
+                    number_threads,
+                    &tx,
+                    &shutdown,
+                    self.credentials.get(&exch).cloned(),
+                    self.stream_options(exch)
+                )
             })
             .collect::<Result<Vec<_>, _>>()?;
 
-        if all_streams.is_empty() {
-            Ok(None)
-        } else {
-            Ok(Some(Box::pin(select_all(all_streams))))
+        Ok(Some(MultithreadedWsStream::new(rx, threads.into_iter().flatten().collect(), shutdown)))
+    }
+}
+
+/// the options of [NormalizedExchangeBuilder] applied to every stream of an
+/// exchange
+#[derive(Debug, Default, Clone)]
+pub(crate) struct WsStreamOptions {
+    endpoints:        ExchangeEndpoints,
+    recorder:         Option<FrameRecorder>,
+    idle_timeout:     Option<Duration>,
+    reconnect_policy: ReconnectPolicy
+}
+
+impl WsStreamOptions {
+    pub(crate) fn apply<T>(self, builder: MutliWsStreamBuilder<T>) -> MutliWsStreamBuilder<T>
+    where
+        T: Exchange + Unpin + Debug + Send + 'static
+    {
+        let mut builder = builder
+            .with_endpoints(self.endpoints)
+            .with_reconnect_policy(self.reconnect_policy);
+        if let Some(recorder) = self.recorder {
+            builder = builder.with_recorder(recorder);
         }
+        if let Some(timeout) = self.idle_timeout {
+            builder = builder.with_idle_timeout(timeout);
+        }
+
+        builder
     }
 }
@@ -1,21 +1,43 @@
 use clap::ValueEnum;
 
 use crate::{
-    exchanges::normalized::types::{NormalizedTradingPair, RawTradingPair},
+    exchanges::normalized::types::{NormalizedCandleInterval, NormalizedTradingPair, RawTradingPair},
     CexExchange
 };
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum NormalizedWsChannels {
     Trades(Vec<NormalizedTradingPair>),
-    Quotes(Vec<NormalizedTradingPair>)
+    Quotes(Vec<NormalizedTradingPair>),
+    /// full depth L2 book, `depth` is the max number of levels kept per side
+    /// of the local book (all levels if `None`)
+    OrderBook {
+        pairs: Vec<NormalizedTradingPair>,
+        depth: Option<usize>
+    },
+    /// klines/candlesticks of a single `interval`
+    Candles {
+        pairs:    Vec<NormalizedTradingPair>,
+        interval: NormalizedCandleInterval
+    },
+    /// private balance, order & fill updates, requires credentials to be set
+    /// for the exchange.
+    ///
+    /// the pairs only scope the stream on exchanges that subscribe by pair
+    /// (coinbase), all other exchanges stream the whole account
+    Account(Vec<NormalizedTradingPair>)
 }
 
 impl NormalizedWsChannels {
     pub fn new_default(kind: NormalizedWsChannelKinds) -> Self {
         match kind {
             NormalizedWsChannelKinds::Trades => NormalizedWsChannels::Trades(Vec::new()),
-            NormalizedWsChannelKinds::Quotes => NormalizedWsChannels::Quotes(Vec::new())
+            NormalizedWsChannelKinds::Quotes => NormalizedWsChannels::Quotes(Vec::new()),
+            NormalizedWsChannelKinds::OrderBook => NormalizedWsChannels::OrderBook { pairs: Vec::new(), depth: None },
+            NormalizedWsChannelKinds::Candles => {
+                NormalizedWsChannels::Candles { pairs: Vec::new(), interval: NormalizedCandleInterval::default() }
+            }
+            NormalizedWsChannelKinds::Account => NormalizedWsChannels::Account(Vec::new())
         }
     }
 
@@ -23,7 +45,10 @@ impl NormalizedWsChannels {
     pub fn new_with_pairs(exchange: CexExchange, kind: NormalizedWsChannelKinds, pairs: &[RawTradingPair]) -> Self {
         match kind {
             NormalizedWsChannelKinds::Trades => Self::new_with_pairs_util(exchange, NormalizedWsChannelKinds::Trades, pairs),
-            NormalizedWsChannelKinds::Quotes => Self::new_with_pairs_util(exchange, NormalizedWsChannelKinds::Quotes, pairs)
+            NormalizedWsChannelKinds::Quotes => Self::new_with_pairs_util(exchange, NormalizedWsChannelKinds::Quotes, pairs),
+            NormalizedWsChannelKinds::OrderBook => Self::new_with_pairs_util(exchange, NormalizedWsChannelKinds::OrderBook, pairs),
+            NormalizedWsChannelKinds::Candles => Self::new_with_pairs_util(exchange, NormalizedWsChannelKinds::Candles, pairs),
+            NormalizedWsChannelKinds::Account => Self::new_with_pairs_util(exchange, NormalizedWsChannelKinds::Account, pairs)
         }
     }
 
@@ -36,7 +61,17 @@ impl NormalizedWsChannels {
             NormalizedWsChannels::Quotes(vals) => vals
                 .into_iter()
                 .map(|v| NormalizedWsChannels::Quotes(vec![v]))
-                .collect()
+                .collect(),
+            NormalizedWsChannels::OrderBook { pairs, depth } => pairs
+                .into_iter()
+                .map(|v| NormalizedWsChannels::OrderBook { pairs: vec![v], depth })
+                .collect(),
+            NormalizedWsChannels::Candles { pairs, interval } => pairs
+                .into_iter()
+                .map(|v| NormalizedWsChannels::Candles { pairs: vec![v], interval })
+                .collect(),
+            // the account is a single private stream
+            NormalizedWsChannels::Account(vals) => vec![NormalizedWsChannels::Account(vals)]
         }
     }
 
@@ -49,7 +84,12 @@ impl NormalizedWsChannels {
 
         match kind {
             NormalizedWsChannelKinds::Trades => NormalizedWsChannels::Trades(split_pairs),
-            NormalizedWsChannelKinds::Quotes => NormalizedWsChannels::Quotes(split_pairs)
+            NormalizedWsChannelKinds::Quotes => NormalizedWsChannels::Quotes(split_pairs),
+            NormalizedWsChannelKinds::OrderBook => NormalizedWsChannels::OrderBook { pairs: split_pairs, depth: None },
+            NormalizedWsChannelKinds::Candles => {
+                NormalizedWsChannels::Candles { pairs: split_pairs, interval: NormalizedCandleInterval::default() }
+            }
+            NormalizedWsChannelKinds::Account => NormalizedWsChannels::Account(split_pairs)
         }
     }
 
@@ -61,7 +101,50 @@ impl NormalizedWsChannels {
 
         match self {
             NormalizedWsChannels::Trades(p) => p.extend(split_pairs),
-            NormalizedWsChannels::Quotes(p) => p.extend(split_pairs)
+            NormalizedWsChannels::Quotes(p) => p.extend(split_pairs),
+            NormalizedWsChannels::OrderBook { pairs: p, .. } => p.extend(split_pairs),
+            NormalizedWsChannels::Candles { pairs: p, .. } => p.extend(split_pairs),
+            NormalizedWsChannels::Account(p) => p.extend(split_pairs)
+        }
+    }
+
+    pub fn pairs(&self) -> &[NormalizedTradingPair] {
+        match self {
+            NormalizedWsChannels::Trades(p) => p,
+            NormalizedWsChannels::Quotes(p) => p,
+            NormalizedWsChannels::OrderBook { pairs, .. } => pairs,
+            NormalizedWsChannels::Candles { pairs, .. } => pairs,
+            NormalizedWsChannels::Account(p) => p
+        }
+    }
+
+    /// sets the max number of levels kept per side of the local book, only
+    /// applies to [NormalizedWsChannels::OrderBook]
+    pub fn set_depth(&mut self, new_depth: Option<usize>) {
+        if let NormalizedWsChannels::OrderBook { depth, .. } = self {
+            *depth = new_depth;
+        }
+    }
+
+    pub fn depth(&self) -> Option<usize> {
+        match self {
+            NormalizedWsChannels::OrderBook { depth, .. } => *depth,
+            _ => None
+        }
+    }
+
+    /// sets the candle interval, only applies to
+    /// [NormalizedWsChannels::Candles]
+    pub fn set_interval(&mut self, new_interval: NormalizedCandleInterval) {
+        if let NormalizedWsChannels::Candles { interval, .. } = self {
+            *interval = new_interval;
+        }
+    }
+
+    pub fn interval(&self) -> Option<NormalizedCandleInterval> {
+        match self {
+            NormalizedWsChannels::Candles { interval, .. } => Some(*interval),
+            _ => None
         }
     }
 }
@@ -69,14 +152,20 @@ impl NormalizedWsChannels {
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, ValueEnum)]
 pub enum NormalizedWsChannelKinds {
     Trades,
-    Quotes
+    Quotes,
+    OrderBook,
+    Candles,
+    Account
 }
 
 impl From<NormalizedWsChannels> for NormalizedWsChannelKinds {
     fn from(value: NormalizedWsChannels) -> Self {
         match value {
             NormalizedWsChannels::Trades(_) => NormalizedWsChannelKinds::Trades,
-            NormalizedWsChannels::Quotes(_) => NormalizedWsChannelKinds::Quotes
+            NormalizedWsChannels::Quotes(_) => NormalizedWsChannelKinds::Quotes,
+            NormalizedWsChannels::OrderBook { .. } => NormalizedWsChannelKinds::OrderBook,
+            NormalizedWsChannels::Candles { .. } => NormalizedWsChannelKinds::Candles,
+            NormalizedWsChannels::Account(_) => NormalizedWsChannelKinds::Account
         }
     }
 }
@@ -1,22 +1,76 @@
+use chrono::{DateTime, Utc};
+
 use crate::{
-    normalized::types::{NormalizedQuote, NormalizedTrade, NormalizedTradingPair},
+    normalized::types::{
+        NormalizedBalanceUpdate, NormalizedCandle, NormalizedFill, NormalizedOrderBookUpdate, NormalizedOrderUpdate, NormalizedQuote,
+        NormalizedTrade, NormalizedTradingPair
+    },
     CexExchange, Exchange
 };
 
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 pub enum NormalizedWsDataTypes {
     Trade(NormalizedTrade),
     Trades(Vec<NormalizedTrade>),
     Quote(NormalizedQuote),
     Quotes(Vec<NormalizedQuote>),
+    OrderBook(NormalizedOrderBookUpdate),
+    Candle(NormalizedCandle),
+    Candles(Vec<NormalizedCandle>),
+    BalanceUpdates(Vec<NormalizedBalanceUpdate>),
+    OrderUpdate(NormalizedOrderUpdate),
+    OrderUpdates(Vec<NormalizedOrderUpdate>),
+    Fill(NormalizedFill),
+    Fills(Vec<NormalizedFill>),
     Disconnect { exchange: CexExchange, message: String, raw_message: String },
     RemovedPair { exchange: CexExchange, bad_pair: NormalizedTradingPair, raw_message: String },
+    SequenceGap { exchange: CexExchange, pair: NormalizedTradingPair, channel: String, expected: u64, received: u64 },
     Other { exchange: CexExchange, kind: String, value: String }
 }
 
+impl NormalizedWsDataTypes {
+    /// the name of the data type, used as the channel label of the metrics
+    pub fn channel(&self) -> &'static str {
+        match self {
+            NormalizedWsDataTypes::Trade(_) | NormalizedWsDataTypes::Trades(_) => "trades",
+            NormalizedWsDataTypes::Quote(_) | NormalizedWsDataTypes::Quotes(_) => "quotes",
+            NormalizedWsDataTypes::OrderBook(_) => "orderbook",
+            NormalizedWsDataTypes::Candle(_) | NormalizedWsDataTypes::Candles(_) => "candles",
+            NormalizedWsDataTypes::BalanceUpdates(_) => "balances",
+            NormalizedWsDataTypes::OrderUpdate(_) | NormalizedWsDataTypes::OrderUpdates(_) => "orders",
+            NormalizedWsDataTypes::Fill(_) | NormalizedWsDataTypes::Fills(_) => "fills",
+            NormalizedWsDataTypes::Disconnect { .. } => "disconnect",
+            NormalizedWsDataTypes::RemovedPair { .. } => "removed_pair",
+            NormalizedWsDataTypes::SequenceGap { .. } => "sequence_gap",
+            NormalizedWsDataTypes::Other { .. } => "other"
+        }
+    }
+
+    /// the latest time of the exchange's events in the message, `None` for
+    /// the candles, status messages & events the exchange doesn't timestamp
+    pub fn exchange_time(&self) -> Option<DateTime<Utc>> {
+        match self {
+            NormalizedWsDataTypes::Trade(trade) => trade.exchange_time,
+            NormalizedWsDataTypes::Trades(trades) => trades.iter().filter_map(|trade| trade.exchange_time).max(),
+            NormalizedWsDataTypes::Quote(quote) => quote.exchange_time,
+            NormalizedWsDataTypes::Quotes(quotes) => quotes.iter().filter_map(|quote| quote.exchange_time).max(),
+            NormalizedWsDataTypes::OrderBook(book) => Some(book.time),
+            NormalizedWsDataTypes::BalanceUpdates(balances) => balances.iter().map(|balance| balance.time).max(),
+            NormalizedWsDataTypes::OrderUpdate(order) => Some(order.time),
+            NormalizedWsDataTypes::OrderUpdates(orders) => orders.iter().map(|order| order.time).max(),
+            NormalizedWsDataTypes::Fill(fill) => Some(fill.time),
+            NormalizedWsDataTypes::Fills(fills) => fills.iter().map(|fill| fill.time).max(),
+            _ => None
+        }
+    }
+}
+
 pub(crate) enum MessageOrPing<T: Exchange> {
     Message(T::WsMessage),
     Ping,
+    /// a reply to a protocol ping, it only resets the idle timeout
+    Pong,
     Close
 }
 
@@ -29,6 +83,10 @@ impl<T: Exchange> MessageOrPing<T> {
         MessageOrPing::Ping
     }
 
+    pub(crate) fn new_pong() -> Self {
+        MessageOrPing::Pong
+    }
+
     pub(crate) fn new_close() -> Self {
         MessageOrPing::Close
     }
@@ -1,22 +1,53 @@
 use super::NormalizedWsDataTypes;
-#[cfg(feature = "non-us")]
-use crate::{binance::ws::BinanceWsMessage, bybit::ws::BybitWsMessage, kucoin::ws::KucoinWsMessage};
-#[cfg(feature = "us")]
-use crate::{exchanges::coinbase::ws::CoinbaseWsMessage, exchanges::okex::ws::OkexWsMessage};
+#[cfg(feature = "binance")]
+use crate::binance::ws::BinanceWsMessage;
+#[cfg(feature = "bitget")]
+use crate::bitget::ws::BitgetWsMessage;
+#[cfg(feature = "bitstamp")]
+use crate::bitstamp::ws::BitstampWsMessage;
+#[cfg(feature = "bybit")]
+use crate::bybit::ws::BybitWsMessage;
+#[cfg(feature = "coinbase")]
+use crate::coinbase::ws::CoinbaseWsMessage;
+#[cfg(feature = "deribit")]
+use crate::deribit::ws::DeribitWsMessage;
+#[cfg(feature = "gateio")]
+use crate::gateio::ws::GateioWsMessage;
+#[cfg(feature = "kraken")]
+use crate::kraken::ws::KrakenWsMessage;
+#[cfg(feature = "kucoin")]
+use crate::kucoin::ws::KucoinWsMessage;
+#[cfg(feature = "mexc")]
+use crate::mexc::ws::MexcWsMessage;
+#[cfg(feature = "okex")]
+use crate::okex::ws::OkexWsMessage;
 use crate::{normalized::types::NormalizedTradingPair, CexExchange};
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug, Clone)]
 pub enum CombinedWsMessage {
-    #[cfg(feature = "us")]
+    #[cfg(feature = "coinbase")]
     Coinbase(CoinbaseWsMessage),
-    #[cfg(feature = "us")]
+    #[cfg(feature = "okex")]
     Okex(OkexWsMessage),
-    #[cfg(feature = "non-us")]
+    #[cfg(feature = "kraken")]
+    Kraken(KrakenWsMessage),
+    #[cfg(feature = "bitstamp")]
+    Bitstamp(BitstampWsMessage),
+    #[cfg(feature = "binance")]
     Binance(BinanceWsMessage),
-    #[cfg(feature = "non-us")]
+    #[cfg(feature = "kucoin")]
     Kucoin(KucoinWsMessage),
-    #[cfg(feature = "non-us")]
+    #[cfg(feature = "bybit")]
     Bybit(BybitWsMessage),
+    #[cfg(feature = "gateio")]
+    Gateio(GateioWsMessage),
+    #[cfg(feature = "deribit")]
+    Deribit(DeribitWsMessage),
+    #[cfg(feature = "bitget")]
+    Bitget(BitgetWsMessage),
+    #[cfg(feature = "mexc")]
+    Mexc(MexcWsMessage),
     Disconnect {
         exchange:    CexExchange,
         message:     String,
@@ -27,26 +58,49 @@ pub enum CombinedWsMessage {
         exchange:    CexExchange,
         raw_message: String,
         bad_pair:    NormalizedTradingPair
+    },
+    /// an update was dropped or arrived out of order
+    SequenceGap {
+        exchange: CexExchange,
+        pair:     NormalizedTradingPair,
+        channel:  String,
+        expected: u64,
+        received: u64
     }
 }
 
 impl CombinedWsMessage {
     pub fn normalize(self) -> NormalizedWsDataTypes {
         match self {
-            #[cfg(feature = "us")]
+            #[cfg(feature = "coinbase")]
             CombinedWsMessage::Coinbase(c) => c.normalize(),
-            #[cfg(feature = "us")]
+            #[cfg(feature = "okex")]
             CombinedWsMessage::Okex(c) => c.normalize(),
-            #[cfg(feature = "non-us")]
+            #[cfg(feature = "kraken")]
+            CombinedWsMessage::Kraken(c) => c.normalize(),
+            #[cfg(feature = "bitstamp")]
+            CombinedWsMessage::Bitstamp(c) => c.normalize(),
+            #[cfg(feature = "binance")]
             CombinedWsMessage::Binance(c) => c.normalize(),
-            #[cfg(feature = "non-us")]
+            #[cfg(feature = "kucoin")]
             CombinedWsMessage::Kucoin(c) => c.normalize(),
-            #[cfg(feature = "non-us")]
+            #[cfg(feature = "bybit")]
             CombinedWsMessage::Bybit(c) => c.normalize(),
+            #[cfg(feature = "gateio")]
+            CombinedWsMessage::Gateio(c) => c.normalize(),
+            #[cfg(feature = "deribit")]
+            CombinedWsMessage::Deribit(c) => c.normalize(),
+            #[cfg(feature = "bitget")]
+            CombinedWsMessage::Bitget(c) => c.normalize(),
+            #[cfg(feature = "mexc")]
+            CombinedWsMessage::Mexc(c) => c.normalize(),
             CombinedWsMessage::Disconnect { exchange, message, raw_message, .. } => {
                 NormalizedWsDataTypes::Disconnect { exchange, message, raw_message }
             }
-            CombinedWsMessage::BadPair { exchange, raw_message, bad_pair } => NormalizedWsDataTypes::RemovedPair { exchange, raw_message, bad_pair }
+            CombinedWsMessage::BadPair { exchange, raw_message, bad_pair } => NormalizedWsDataTypes::RemovedPair { exchange, raw_message, bad_pair },
+            CombinedWsMessage::SequenceGap { exchange, pair, channel, expected, received } => {
+                NormalizedWsDataTypes::SequenceGap { exchange, pair, channel, expected, received }
+            }
         }
     }
 
@@ -78,36 +132,67 @@ macro_rules! combined_ws {
     };
 }
 
-#[cfg(feature = "us")]
+#[cfg(feature = "coinbase")]
 combined_ws!(Coinbase);
 
-#[cfg(feature = "us")]
+#[cfg(feature = "okex")]
 combined_ws!(Okex);
 
-#[cfg(feature = "non-us")]
+#[cfg(feature = "kraken")]
+combined_ws!(Kraken);
+
+#[cfg(feature = "bitstamp")]
+combined_ws!(Bitstamp);
+
+#[cfg(feature = "binance")]
 combined_ws!(Binance);
 
-#[cfg(feature = "non-us")]
+#[cfg(feature = "kucoin")]
 combined_ws!(Kucoin);
 
-#[cfg(feature = "non-us")]
+#[cfg(feature = "bybit")]
 combined_ws!(Bybit);
 
+#[cfg(feature = "gateio")]
+combined_ws!(Gateio);
+
+#[cfg(feature = "deribit")]
+combined_ws!(Deribit);
+
+#[cfg(feature = "bitget")]
+combined_ws!(Bitget);
+
+#[cfg(feature = "mexc")]
+combined_ws!(Mexc);
+
 impl PartialEq<NormalizedWsDataTypes> for CombinedWsMessage {
     fn eq(&self, other: &NormalizedWsDataTypes) -> bool {
         match self {
-            #[cfg(feature = "us")]
+            #[cfg(feature = "coinbase")]
             CombinedWsMessage::Coinbase(vals) => vals == other,
-            #[cfg(feature = "us")]
+            #[cfg(feature = "okex")]
             CombinedWsMessage::Okex(vals) => vals == other,
-            #[cfg(feature = "non-us")]
+            #[cfg(feature = "kraken")]
+            CombinedWsMessage::Kraken(vals) => vals == other,
+            #[cfg(feature = "bitstamp")]
+            CombinedWsMessage::Bitstamp(vals) => vals == other,
+            #[cfg(feature = "binance")]
             CombinedWsMessage::Binance(vals) => vals == other,
-            #[cfg(feature = "non-us")]
+            #[cfg(feature = "kucoin")]
             CombinedWsMessage::Kucoin(vals) => vals == other,
-            #[cfg(feature = "non-us")]
+            #[cfg(feature = "bybit")]
             CombinedWsMessage::Bybit(vals) => vals == other,
+            #[cfg(feature = "gateio")]
+            CombinedWsMessage::Gateio(vals) => vals == other,
+            #[cfg(feature = "deribit")]
+            CombinedWsMessage::Deribit(vals) => vals == other,
+            #[cfg(feature = "bitget")]
+            CombinedWsMessage::Bitget(vals) => vals == other,
+            #[cfg(feature = "mexc")]
+            CombinedWsMessage::Mexc(vals) => vals == other,
             CombinedWsMessage::Disconnect { .. } => true,
-            CombinedWsMessage::BadPair { .. } => true
+            CombinedWsMessage::BadPair { .. } => true,
+            CombinedWsMessage::SequenceGap { .. } => true
         }
     }
 }
@@ -1,45 +1,125 @@
 mod pairs;
-use std::pin::Pin;
+use std::{pin::Pin, time::Duration};
 
 pub use pairs::*;
 
 pub mod rest_api;
 pub mod ws;
 
-use futures::{future::join_all, Future, SinkExt};
+use chrono::{DateTime, SecondsFormat, Utc};
+use futures::{future::join_all, Future, SinkExt, StreamExt};
 use serde::Deserialize;
 use strum::IntoEnumIterator;
 use tokio::net::TcpStream;
 use tokio_tungstenite::{tungstenite::Message, MaybeTlsStream, WebSocketStream};
 
 use self::{
-    rest_api::{OkexAllInstruments, OkexAllSymbols, OkexRestApiResponse},
-    ws::{OkexSubscription, OkexWsMessage}
+    rest_api::{
+        OkexAllCandles, OkexAllInstruments, OkexAllSymbols, OkexAllTrades, OkexCancelRequest, OkexCandlesPage, OkexOrderAck, OkexOrderRequest,
+        OkexRestApiResponse, OkexTradeResponse, OkexTradesPage
+    },
+    ws::{
+        channels::{OkexBookData, OkexBooksResponse, OkexCandleBar, OkexOrder, OkexWsChannel},
+        OkexLogin, OkexSubscription, OkexWsMessage
+    }
 };
 use crate::{
-    clients::{rest_api::RestApiError, ws::WsError},
+    clients::{
+        rate_limiter, require_credentials,
+        rest_api::{signed_response, ExchangeApi, RestApiError},
+        ws::{WsError, WsSubscriptionUpdate},
+        EndpointKind, ExchangeCredentials, ExchangeEndpoints, RateLimitKind, TokenBucket
+    },
     normalized::{
         rest_api::NormalizedRestApiRequest,
-        types::{NormalizedTradingPair, NormalizedTradingType}
+        types::{
+            NormalizedCandleInterval, NormalizedOrderAck, NormalizedOrderRequest, NormalizedOrderStatus, NormalizedOrderType, NormalizedOrderUpdate,
+            NormalizedTimeInForce, NormalizedTradingPair, NormalizedTradingType
+        }
     },
-    CexExchange, EmptyFilter, Exchange
+    CexExchange, EmptyFilter, Exchange, ExchangeTrading
 };
 
 const WSS_PUBLIC_URL: &str = "wss://ws.okx.com:8443/ws/v5/public";
 const WSS_BUSINESS_URL: &str = "wss://ws.okx.com:8443/ws/v5/business";
+const WSS_PRIVATE_URL: &str = "wss://ws.okx.com:8443/ws/v5/private";
 const BASE_REST_API_URL: &str = "https://www.okx.com";
+/// the connection is closed if nothing is sent or received for 30s
+/// (https://www.okx.com/docs-v5/en/#overview-websocket-connect)
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(20);
+const MAX_CANDLES_PER_REQUEST: usize = 100;
+const MAX_RECENT_TRADES: usize = 500;
+const MAX_HISTORY_TRADES_PER_REQUEST: usize = 100;
+const MAX_BATCH_CANCELS: usize = 20;
+
+/// the default exchange to get the symbols from, binance if it's enabled then
+/// coinbase
+#[cfg(feature = "binance")]
+pub const DEFAULT_CURRENCY_PROXY: CexExchange = CexExchange::Binance;
+#[cfg(all(not(feature = "binance"), feature = "coinbase"))]
+pub const DEFAULT_CURRENCY_PROXY: CexExchange = CexExchange::Coinbase;
+/// there is no other exchange to proxy, [Okex::get_all_symbols] errors
+#[cfg(not(any(feature = "binance", feature = "coinbase")))]
+pub const DEFAULT_CURRENCY_PROXY: CexExchange = CexExchange::Okex;
 
 #[derive(Debug, Clone)]
 pub struct Okex {
     subscription:        OkexSubscription,
     /// exchange to use to get the symbols (since there is no direct symbols
-    /// api) - default is binance
-    exch_currency_proxy: CexExchange
+    /// api) - default is [DEFAULT_CURRENCY_PROXY]
+    exch_currency_proxy: CexExchange,
+    credentials:         Option<ExchangeCredentials>,
+    endpoints:           ExchangeEndpoints,
+    /// the urls the currency proxy is called through
+    proxy_endpoints:     ExchangeEndpoints
 }
 
 impl Okex {
+    fn rest_url(&self) -> String {
+        self.endpoints.url(EndpointKind::Rest, BASE_REST_API_URL)
+    }
+
     pub fn new_ws_subscription(subscription: OkexSubscription, exch_currency_proxy: CexExchange) -> Self {
-        Self { subscription, exch_currency_proxy }
+        Self {
+            subscription,
+            exch_currency_proxy,
+            credentials: None,
+            endpoints: ExchangeEndpoints::default(),
+            proxy_endpoints: ExchangeEndpoints::default()
+        }
+    }
+
+    /// sets the credentials used to login to the private ws
+    pub fn with_credentials(mut self, credentials: ExchangeCredentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    /// logs in to the private ws, waiting for the `login` event before the
+    /// subscription can be sent
+    async fn login(&self, ws: &mut WebSocketStream<MaybeTlsStream<TcpStream>>, subscribe_limiter: &TokenBucket) -> Result<(), WsError> {
+        let credentials = self
+            .credentials
+            .as_ref()
+            .ok_or(WsError::AuthenticationError("credentials are required for the private channels".to_string()))?;
+
+        let login = OkexLogin::new(credentials, Utc::now().timestamp()).map_err(|e| WsError::AuthenticationError(e.to_string()))?;
+        subscribe_limiter.acquire().await;
+        ws.send(Message::Text(serde_json::to_string(&login)?))
+            .await?;
+
+        while let Some(msg) = ws.next().await {
+            let Message::Text(msg) = msg? else { continue };
+
+            let response: serde_json::Value = serde_json::from_str(&msg)?;
+            match response.get("event").and_then(|e| e.as_str()) {
+                Some("login") => return Ok(()),
+                Some("error") => return Err(WsError::AuthenticationError(msg)),
+                _ => ()
+            }
+        }
+
+        Err(WsError::StreamTerminated)
     }
 
     pub fn get_all_symbols<'a>(
@@ -47,9 +127,14 @@ impl Okex {
         web_client: &'a reqwest::Client
     ) -> Pin<Box<dyn Future<Output = Result<OkexAllSymbols, RestApiError>> + Send + 'a>> {
         Box::pin(async {
+            if self.exch_currency_proxy == CexExchange::Okex {
+                return Err(RestApiError::UnsupportedRequest("okex cannot be its own currency proxy".to_string()))
+            }
+
+            let proxy_api = ExchangeApi::new().with_endpoints(self.exch_currency_proxy, self.proxy_endpoints.clone());
             let proxy_symbols = self
                 .exch_currency_proxy
-                .get_all_currencies::<EmptyFilter>(None)
+                .get_all_currencies::<EmptyFilter>(&proxy_api, None)
                 .await?;
             let instruments = self.get_all_instruments(web_client).await?;
 
@@ -61,7 +146,7 @@ impl Okex {
         let complete_instruments = join_all(NormalizedTradingType::iter().map(|t| async move {
             if t != NormalizedTradingType::Rfq {
                 let instruments_with_type: OkexAllInstruments =
-                    Self::simple_rest_api_request(web_client, format!("{BASE_REST_API_URL}/api/v5/public/instruments?instType={t}")).await?;
+                    Self::simple_rest_api_request(web_client, format!("{}/api/v5/public/instruments?instType={t}", self.rest_url())).await?;
 
                 Ok(instruments_with_type)
             } else {
@@ -78,10 +163,155 @@ impl Okex {
         Ok(OkexAllInstruments { instruments: complete_instruments })
     }
 
+    pub async fn get_books(&self, web_client: &reqwest::Client, pair: &OkexTradingPair) -> Result<Option<OkexBookData>, RestApiError> {
+        let url = format!("{}/api/v5/market/books?instId={pair}&sz=400", self.rest_url());
+        let response: OkexBooksResponse = Self::simple_rest_api_request(web_client, url).await?;
+
+        Ok(response.data.into_iter().next())
+    }
+
+    /// pages (backwards) through `/api/v5/market/history-candles` for all
+    /// candles starting in `start..end`
+    pub async fn get_candles(
+        &self,
+        web_client: &reqwest::Client,
+        pair: OkexTradingPair,
+        interval: NormalizedCandleInterval,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>
+    ) -> Result<OkexAllCandles, RestApiError> {
+        let mut candles = Vec::new();
+        // `after` returns the candles strictly older than the timestamp
+        let mut after = end.timestamp_millis();
+        while after > start.timestamp_millis() {
+            let url = format!(
+                "{}/api/v5/market/history-candles?instId={pair}&bar={}&after={after}&limit={MAX_CANDLES_PER_REQUEST}",
+                self.rest_url(),
+                OkexCandleBar(interval)
+            );
+            let page: OkexCandlesPage = Self::simple_rest_api_request(web_client, url).await?;
+
+            let Some(oldest) = page.data.last() else { break };
+            after = oldest.start_time as i64;
+
+            let is_last_page = page.data.len() < MAX_CANDLES_PER_REQUEST;
+            candles.extend(page.data);
+            if is_last_page {
+                break
+            }
+        }
+
+        candles.retain(|c| (start.timestamp_millis()..end.timestamp_millis()).contains(&(c.start_time as i64)));
+        candles.sort_by_key(|c| c.start_time);
+        candles.dedup_by_key(|c| c.start_time);
+
+        Ok(OkexAllCandles { pair, interval, candles })
+    }
+
+    /// the latest `limit` trades (max 500)
+    pub async fn get_recent_trades(&self, web_client: &reqwest::Client, pair: OkexTradingPair, limit: usize) -> Result<OkexAllTrades, RestApiError> {
+        let url = format!("{}/api/v5/market/trades?instId={pair}&limit={}", self.rest_url(), limit.min(MAX_RECENT_TRADES));
+        let page: OkexTradesPage = Self::simple_rest_api_request(web_client, url).await?;
+
+        let mut trades = page.data;
+        trades.reverse();
+
+        Ok(OkexAllTrades { pair, trades })
+    }
+
+    /// pages (backwards) through `/api/v5/market/history-trades` for all
+    /// trades in `from..to`, the first page is found by timestamp and the
+    /// rest by trade id so trades sharing a timestamp aren't skipped
+    pub async fn get_historical_trades(
+        &self,
+        web_client: &reqwest::Client,
+        pair: OkexTradingPair,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>
+    ) -> Result<OkexAllTrades, RestApiError> {
+        let mut trades = Vec::new();
+        let mut url = format!(
+            "{}/api/v5/market/history-trades?instId={pair}&type=2&after={}&limit={MAX_HISTORY_TRADES_PER_REQUEST}",
+            self.rest_url(),
+            to.timestamp_millis()
+        );
+        loop {
+            let page: OkexTradesPage = Self::simple_rest_api_request(web_client, url).await?;
+
+            let Some(oldest) = page.data.last() else { break };
+            url = format!(
+                "{}/api/v5/market/history-trades?instId={pair}&type=1&after={}&limit={MAX_HISTORY_TRADES_PER_REQUEST}",
+                self.rest_url(),
+                oldest.trade_id
+            );
+
+            let is_last_page = page.data.len() < MAX_HISTORY_TRADES_PER_REQUEST || (oldest.trade_time as i64) < from.timestamp_millis();
+            trades.extend(page.data);
+            if is_last_page {
+                break
+            }
+        }
+
+        trades.retain(|t| (from.timestamp_millis()..to.timestamp_millis()).contains(&(t.trade_time as i64)));
+        trades.reverse();
+
+        Ok(OkexAllTrades { pair, trades })
+    }
+
+    /// sends a request signed with the `HMAC-SHA256` of
+    /// `timestamp + method + path + body`, erroring if the response's `code`
+    /// isn't `0`
+    pub async fn signed_rest_api_request<T>(
+        &self,
+        web_client: &reqwest::Client,
+        credentials: &ExchangeCredentials,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<String>
+    ) -> Result<Vec<T>, RestApiError>
+    where
+        T: for<'de> Deserialize<'de>
+    {
+        rate_limiter(CexExchange::Okex, RateLimitKind::Rest)
+            .acquire()
+            .await;
+        let timestamp = Utc::now().to_rfc3339_opts(SecondsFormat::Millis, true);
+        let body = body.unwrap_or_default();
+        let signed = || -> eyre::Result<(String, &str)> {
+            Ok((credentials.sign_base64(&format!("{timestamp}{method}{path}{body}"))?, credentials.passphrase()?))
+        };
+        let (signature, passphrase) = signed().map_err(|e| RestApiError::AuthenticationError(e.to_string()))?;
+
+        let response = web_client
+            .request(method.clone(), format!("{}{path}", self.rest_url()))
+            .header("Content-Type", "application/json")
+            .header("OK-ACCESS-KEY", &credentials.api_key)
+            .header("OK-ACCESS-SIGN", signature)
+            .header("OK-ACCESS-TIMESTAMP", &timestamp)
+            .header("OK-ACCESS-PASSPHRASE", passphrase)
+            .body(body)
+            .send()
+            .await?;
+
+        let response: OkexTradeResponse<serde_json::Value> = signed_response(response).await?;
+        if response.code != "0" {
+            return Err(RestApiError::ExchangeError(format!("{} - {}: {:?}", response.code, response.msg, response.data)))
+        }
+
+        Ok(response
+            .data
+            .into_iter()
+            .map(serde_json::from_value)
+            .collect::<Result<_, _>>()?)
+    }
+
     pub async fn simple_rest_api_request<T>(web_client: &reqwest::Client, url: String) -> Result<T, RestApiError>
     where
         T: for<'de> Deserialize<'de>
     {
+        rate_limiter(CexExchange::Okex, RateLimitKind::Rest)
+            .acquire()
+            .await;
         let data = web_client.get(&url).send().await?.json().await?;
 
         Ok(data)
@@ -94,17 +324,43 @@ impl Exchange for Okex {
 
     const EXCHANGE: CexExchange = CexExchange::Okex;
 
+    fn with_endpoints(mut self, endpoints: ExchangeEndpoints) -> Self {
+        self.endpoints = endpoints;
+        self
+    }
+
+    fn with_proxy_endpoints(mut self, api: &ExchangeApi) -> Self {
+        self.proxy_endpoints = api.endpoints(self.exch_currency_proxy);
+        self
+    }
+
     fn remove_bad_pair(&mut self, bad_pair: NormalizedTradingPair) -> bool {
         let pair: OkexTradingPair = bad_pair.try_into().unwrap();
         self.subscription.remove_pair(&pair)
     }
 
-    async fn make_ws_connection(&self) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, WsError> {
-        let url = if self.subscription.needs_business_ws() { WSS_BUSINESS_URL } else { WSS_PUBLIC_URL };
+    async fn make_ws_connection(&self, subscribe_limiter: &TokenBucket) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, WsError> {
+        let is_private = self.subscription.has_private_channels();
+        if is_private && self.subscription.has_public_channels() {
+            return Err(WsError::AuthenticationError("the private channels can't share a connection with public channels".to_string()))
+        }
+
+        let url = if is_private {
+            self.endpoints.url(EndpointKind::Private, WSS_PRIVATE_URL)
+        } else if self.subscription.needs_business_ws() {
+            self.endpoints.url(EndpointKind::Business, WSS_BUSINESS_URL)
+        } else {
+            self.endpoints.url(EndpointKind::Ws, WSS_PUBLIC_URL)
+        };
 
         let (mut ws, _) = tokio_tungstenite::connect_async(url).await?;
 
+        if is_private {
+            self.login(&mut ws, subscribe_limiter).await?;
+        }
+
         let sub_message = serde_json::to_string(&self.subscription)?;
+        subscribe_limiter.acquire().await;
         ws.send(Message::Text(sub_message)).await?;
 
         Ok(ws)
@@ -113,21 +369,233 @@ impl Exchange for Okex {
     async fn rest_api_call(&self, web_client: &reqwest::Client, api_channel: NormalizedRestApiRequest) -> Result<OkexRestApiResponse, RestApiError> {
         let call_result = match api_channel {
             NormalizedRestApiRequest::AllCurrencies => OkexRestApiResponse::Symbols(self.get_all_symbols(web_client).await?),
-            NormalizedRestApiRequest::AllInstruments => OkexRestApiResponse::Instruments(self.get_all_instruments(web_client).await?)
+            NormalizedRestApiRequest::AllInstruments => OkexRestApiResponse::Instruments(self.get_all_instruments(web_client).await?),
+            NormalizedRestApiRequest::Candles { pair, interval, start, end } => {
+                let pair: OkexTradingPair = pair
+                    .try_into()
+                    .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+                OkexRestApiResponse::Candles(
+                    self.get_candles(web_client, pair, interval, start, end)
+                        .await?
+                )
+            }
+            NormalizedRestApiRequest::RecentTrades { pair, limit } => {
+                let pair: OkexTradingPair = pair
+                    .try_into()
+                    .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+                OkexRestApiResponse::Trades(self.get_recent_trades(web_client, pair, limit).await?)
+            }
+            NormalizedRestApiRequest::HistoricalTrades { pair, from, to } => {
+                let pair: OkexTradingPair = pair
+                    .try_into()
+                    .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+                OkexRestApiResponse::Trades(
+                    self.get_historical_trades(web_client, pair, from, to)
+                        .await?
+                )
+            }
         };
 
         Ok(call_result)
     }
+
+    /// the rest api's books don't carry a `seqId`, so the books channel is
+    /// resubscribed to get a new snapshot
+    fn resubscribe_depth(&self, channel: &'static str, pair: &NormalizedTradingPair) -> Option<Vec<Message>> {
+        if channel != "books" {
+            return None
+        }
+
+        let pair: OkexTradingPair = pair.clone().try_into().ok()?;
+        self.subscription
+            .resubscribe(OkexWsChannel::Books(vec![pair]))?
+            .into_iter()
+            .map(|request| serde_json::to_string(&request).map(Message::Text))
+            .collect::<Result<_, _>>()
+            .ok()
+    }
+
+    fn update_subscription(&mut self, update: WsSubscriptionUpdate) -> Result<Vec<Message>, WsError> {
+        if self.subscription.has_private_channels() {
+            return Err(WsError::InvalidSubscriptionUpdate("the public channels can't share a connection with private channels".to_string()))
+        }
+
+        let channel: OkexWsChannel = update.exchange_channel()?;
+        // the trades & candles are on the business ws, the other channels on the public
+        // ws
+        if OkexSubscription::new_single_channel(channel.clone()).needs_business_ws() != self.subscription.needs_business_ws() {
+            return Err(WsError::InvalidSubscriptionUpdate(format!("the {channel} channel isn't on the same ws as the stream's channels")))
+        }
+
+        let requests = if update.is_subscribe() { self.subscription.subscribe(channel) } else { self.subscription.unsubscribe(channel) };
+
+        let messages = requests
+            .into_iter()
+            .map(|request| serde_json::to_string(&request).map(Message::Text))
+            .collect::<Result<_, _>>()?;
+
+        Ok(messages)
+    }
+
+    fn unsubscribe_all(&self) -> Vec<Message> {
+        self.subscription
+            .unsubscribe_all()
+            .and_then(|request| serde_json::to_string(&request).ok())
+            .map(Message::Text)
+            .into_iter()
+            .collect()
+    }
+
+    fn heartbeat(&self) -> Option<(Duration, Message)> {
+        Some((HEARTBEAT_INTERVAL, Message::Text("ping".to_string())))
+    }
+
+    fn is_heartbeat_reply(&self, frame: &str) -> bool {
+        frame == "pong"
+    }
 }
 
 impl Default for Okex {
     fn default() -> Self {
         Self {
-            subscription: Default::default(),
-            #[cfg(feature = "non-us")]
-            exch_currency_proxy: CexExchange::Binance,
-            #[cfg(not(feature = "non-us"))]
-            exch_currency_proxy: CexExchange::Coinbase
+            subscription:        Default::default(),
+            exch_currency_proxy: DEFAULT_CURRENCY_PROXY,
+            credentials:         None,
+            endpoints:           ExchangeEndpoints::default(),
+            proxy_endpoints:     ExchangeEndpoints::default()
         }
     }
 }
+
+impl ExchangeTrading for Okex {
+    async fn place_order(&self, web_client: &reqwest::Client, order: NormalizedOrderRequest) -> Result<NormalizedOrderAck, RestApiError> {
+        let credentials = require_credentials(&self.credentials)?;
+        let pair: OkexTradingPair = order
+            .pair
+            .clone()
+            .try_into()
+            .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+        let price = order
+            .limit_price()
+            .map_err(|e| RestApiError::UnsupportedRequest(e.to_string()))?;
+
+        let order_type = match (order.order_type, order.limit_time_in_force()) {
+            (NormalizedOrderType::Market, _) => "market",
+            (_, Some(NormalizedTimeInForce::ImmediateOrCancel)) => "ioc",
+            (_, Some(NormalizedTimeInForce::FillOrKill)) => "fok",
+            (_, Some(NormalizedTimeInForce::PostOnly)) => "post_only",
+            _ => "limit"
+        };
+
+        let request = OkexOrderRequest {
+            pair:            pair.clone(),
+            trade_mode:      "cash".to_string(),
+            side:            order.side.to_string(),
+            order_type:      order_type.to_string(),
+            size:            order.amount.to_string(),
+            target_currency: "base_ccy".to_string(),
+            price:           price.map(|p| p.to_string()),
+            client_order_id: order.client_order_id
+        };
+
+        let ack = self
+            .signed_rest_api_request::<OkexOrderAck>(
+                web_client,
+                credentials,
+                reqwest::Method::POST,
+                "/api/v5/trade/order",
+                Some(serde_json::to_string(&request)?)
+            )
+            .await?
+            .pop()
+            .ok_or(RestApiError::ExchangeError("okex didn't acknowledge the order".to_string()))?;
+
+        Ok(ack.normalize(pair.normalize(), Some(NormalizedOrderStatus::New)))
+    }
+
+    async fn cancel_order(
+        &self,
+        web_client: &reqwest::Client,
+        pair: NormalizedTradingPair,
+        order_id: String
+    ) -> Result<NormalizedOrderAck, RestApiError> {
+        let credentials = require_credentials(&self.credentials)?;
+        let pair: OkexTradingPair = pair
+            .try_into()
+            .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+
+        let request = OkexCancelRequest { pair: pair.clone(), order_id };
+        let ack = self
+            .signed_rest_api_request::<OkexOrderAck>(
+                web_client,
+                credentials,
+                reqwest::Method::POST,
+                "/api/v5/trade/cancel-order",
+                Some(serde_json::to_string(&request)?)
+            )
+            .await?
+            .pop()
+            .ok_or(RestApiError::ExchangeError("okex didn't acknowledge the cancel".to_string()))?;
+
+        Ok(ack.normalize(pair.normalize(), Some(NormalizedOrderStatus::Canceled)))
+    }
+
+    async fn cancel_all(&self, web_client: &reqwest::Client, pair: Option<NormalizedTradingPair>) -> Result<Vec<NormalizedOrderAck>, RestApiError> {
+        let credentials = require_credentials(&self.credentials)?;
+
+        // okex has no cancel all endpoint, so the open orders are canceled in
+        // batches
+        let requests = self
+            .get_open_orders(web_client, pair)
+            .await?
+            .into_iter()
+            .map(|o| Ok(OkexCancelRequest { pair: o.pair.try_into()?, order_id: o.order_id }))
+            .collect::<Result<Vec<_>, eyre::Report>>()
+            .map_err(|e| RestApiError::InvalidPair(e.to_string()))?;
+
+        let mut acks = Vec::new();
+        for batch in requests.chunks(MAX_BATCH_CANCELS) {
+            let batch_acks = self
+                .signed_rest_api_request::<OkexOrderAck>(
+                    web_client,
+                    credentials,
+                    reqwest::Method::POST,
+                    "/api/v5/trade/cancel-batch-orders",
+                    Some(serde_json::to_string(batch)?)
+                )
+                .await?;
+
+            acks.extend(
+                batch
+                    .iter()
+                    .zip(batch_acks)
+                    .filter(|(_, ack)| ack.is_success())
+                    .map(|(req, ack)| ack.normalize(req.pair.normalize(), Some(NormalizedOrderStatus::Canceled)))
+            );
+        }
+
+        Ok(acks)
+    }
+
+    async fn get_open_orders(
+        &self,
+        web_client: &reqwest::Client,
+        pair: Option<NormalizedTradingPair>
+    ) -> Result<Vec<NormalizedOrderUpdate>, RestApiError> {
+        let credentials = require_credentials(&self.credentials)?;
+
+        let mut path = "/api/v5/trade/orders-pending?instType=SPOT".to_string();
+        if let Some(pair) = pair {
+            let pair: OkexTradingPair = pair
+                .try_into()
+                .map_err(|e: eyre::Report| RestApiError::InvalidPair(e.to_string()))?;
+            path.push_str(&format!("&instId={pair}"));
+        }
+
+        let orders = self
+            .signed_rest_api_request::<OkexOrder>(web_client, credentials, reqwest::Method::GET, &path, None)
+            .await?;
+
+        Ok(orders.into_iter().map(|o| o.normalize()).collect())
+    }
+}
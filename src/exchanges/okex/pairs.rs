@@ -174,7 +174,7 @@ mod tests {
     fn test_parse_for_bad_pair() {
         let test_str = r#"failed to deserialize the message: Could not find 'arg' field in Okex ws message - {"event":"error","msg":"Wrong URL or channel:tickers,instId:NMR-USDT doesn't exist. Please use the correct URL, channel and parameters referring to API document.","code":"60018","connId":"0d0c61a5"}"#;
 
-        let calculated = OkexTradingPair::parse_for_bad_pair(&test_str);
+        let calculated = OkexTradingPair::parse_for_bad_pair(test_str);
 
         assert_eq!(calculated, Some(OkexTradingPair("NMR-USDT".to_string())))
     }
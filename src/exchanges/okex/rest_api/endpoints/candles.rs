@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    normalized::{
+        rest_api::NormalizedRestApiDataTypes,
+        types::{NormalizedCandle, NormalizedCandleInterval}
+    },
+    okex::{ws::channels::OkexCandleData, OkexTradingPair}
+};
+
+/// candles of a single pair & bar from `/api/v5/market/history-candles`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OkexAllCandles {
+    pub pair:     OkexTradingPair,
+    pub interval: NormalizedCandleInterval,
+    pub candles:  Vec<OkexCandleData>
+}
+
+impl OkexAllCandles {
+    pub fn normalize(self) -> Vec<NormalizedCandle> {
+        self.candles
+            .into_iter()
+            .map(|c| c.normalize(&self.pair, self.interval))
+            .collect()
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for OkexAllCandles {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match other {
+            NormalizedRestApiDataTypes::Candles(other_candles) => self.clone().normalize() == *other_candles,
+            _ => false
+        }
+    }
+}
+
+/// a single page of candles (newest first)
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct OkexCandlesPage {
+    pub(crate) data: Vec<OkexCandleData>
+}
@@ -167,7 +167,9 @@ impl OkexInstrument {
             active:             &self.state == "live",
             futures_expiry:     self
                 .expiry_time
-                .map(|t| Utc.timestamp_millis_opt(t as i64).unwrap().date_naive())
+                .map(|t| Utc.timestamp_millis_opt(t as i64).unwrap().date_naive()),
+            option_strike:      self.strike_price.and_then(|s| s.parse().ok()),
+            option_type:        self.option_type.and_then(|t| t.parse().ok())
         }
     }
 }
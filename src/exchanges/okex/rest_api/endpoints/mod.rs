@@ -3,3 +3,12 @@ pub use symbols::*;
 
 mod instruments;
 pub use instruments::*;
+
+mod candles;
+pub use candles::*;
+
+mod trades;
+pub use trades::*;
+
+mod orders;
+pub use orders::*;
@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, NoneAsEmptyString};
+
+use crate::{
+    normalized::types::{NormalizedOrderAck, NormalizedOrderStatus, NormalizedTradingPair},
+    okex::OkexTradingPair,
+    CexExchange
+};
+
+/// response of the `/api/v5/trade` endpoints, `code` is `0` on success
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OkexTradeResponse<T> {
+    pub code: String,
+    pub msg:  String,
+    pub data: Vec<T>
+}
+
+/// body of `POST /api/v5/trade/order`
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct OkexOrderRequest {
+    #[serde(rename = "instId")]
+    pub pair:            OkexTradingPair,
+    /// always `cash` (spot)
+    #[serde(rename = "tdMode")]
+    pub trade_mode:      String,
+    pub side:            String,
+    /// market, limit, post_only, fok or ioc
+    #[serde(rename = "ordType")]
+    pub order_type:      String,
+    #[serde(rename = "sz")]
+    pub size:            String,
+    /// the unit of `sz` for market orders
+    #[serde(rename = "tgtCcy")]
+    pub target_currency: String,
+    #[serde(rename = "px", skip_serializing_if = "Option::is_none")]
+    pub price:           Option<String>,
+    #[serde(rename = "clOrdId", skip_serializing_if = "Option::is_none")]
+    pub client_order_id: Option<String>
+}
+
+/// body of `POST /api/v5/trade/cancel-order`
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct OkexCancelRequest {
+    #[serde(rename = "instId")]
+    pub pair:     OkexTradingPair,
+    #[serde(rename = "ordId")]
+    pub order_id: String
+}
+
+/// result of a single order in the `/api/v5/trade` responses, `sCode` is
+/// `0` on success
+#[serde_as]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OkexOrderAck {
+    #[serde(rename = "ordId")]
+    pub order_id:        String,
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(rename = "clOrdId", default)]
+    pub client_order_id: Option<String>,
+    #[serde(rename = "sCode")]
+    pub code:            String,
+    #[serde(rename = "sMsg")]
+    pub msg:             String
+}
+
+impl OkexOrderAck {
+    pub fn is_success(&self) -> bool {
+        self.code == "0"
+    }
+
+    pub fn normalize(self, pair: NormalizedTradingPair, status: Option<NormalizedOrderStatus>) -> NormalizedOrderAck {
+        NormalizedOrderAck { exchange: CexExchange::Okex, pair: Some(pair), order_id: self.order_id, client_order_id: self.client_order_id, status }
+    }
+}
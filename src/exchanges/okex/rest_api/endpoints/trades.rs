@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    normalized::{rest_api::NormalizedRestApiDataTypes, types::NormalizedTrade},
+    okex::{ws::channels::OkexTrade, OkexTradingPair}
+};
+
+/// trades of a single pair from `/api/v5/market/trades` or
+/// `/api/v5/market/history-trades`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct OkexAllTrades {
+    pub pair:   OkexTradingPair,
+    pub trades: Vec<OkexTrade>
+}
+
+impl OkexAllTrades {
+    pub fn normalize(self) -> Vec<NormalizedTrade> {
+        self.trades.into_iter().map(OkexTrade::normalize).collect()
+    }
+}
+
+impl PartialEq<NormalizedRestApiDataTypes> for OkexAllTrades {
+    fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
+        match other {
+            NormalizedRestApiDataTypes::Trades(other_trades) => self.clone().normalize() == *other_trades,
+            _ => false
+        }
+    }
+}
+
+/// a single page of trades (newest first)
+#[derive(Debug, Clone, Deserialize)]
+pub(crate) struct OkexTradesPage {
+    pub(crate) data: Vec<OkexTrade>
+}
@@ -1,4 +1,4 @@
-use super::{OkexAllInstruments, OkexAllSymbols, OkexCurrency, OkexInstrument};
+use super::{OkexAllCandles, OkexAllInstruments, OkexAllSymbols, OkexAllTrades, OkexCurrency, OkexInstrument};
 use crate::normalized::rest_api::NormalizedRestApiDataTypes;
 
 #[serde_with::serde_as]
@@ -6,14 +6,18 @@ use crate::normalized::rest_api::NormalizedRestApiDataTypes;
 #[serde(rename_all = "snake_case", tag = "type")]
 pub enum OkexRestApiResponse {
     Symbols(OkexAllSymbols),
-    Instruments(OkexAllInstruments)
+    Instruments(OkexAllInstruments),
+    Candles(OkexAllCandles),
+    Trades(OkexAllTrades)
 }
 
 impl OkexRestApiResponse {
     pub fn normalize(self) -> NormalizedRestApiDataTypes {
         match self {
             OkexRestApiResponse::Symbols(v) => NormalizedRestApiDataTypes::AllCurrencies(v.normalize()),
-            OkexRestApiResponse::Instruments(v) => NormalizedRestApiDataTypes::AllInstruments(v.normalize())
+            OkexRestApiResponse::Instruments(v) => NormalizedRestApiDataTypes::AllInstruments(v.normalize()),
+            OkexRestApiResponse::Candles(v) => NormalizedRestApiDataTypes::Candles(v.normalize()),
+            OkexRestApiResponse::Trades(v) => NormalizedRestApiDataTypes::Trades(v.normalize())
         }
     }
 
@@ -47,7 +51,9 @@ impl PartialEq<NormalizedRestApiDataTypes> for OkexRestApiResponse {
     fn eq(&self, other: &NormalizedRestApiDataTypes) -> bool {
         match self {
             OkexRestApiResponse::Symbols(vals) => vals == other,
-            OkexRestApiResponse::Instruments(vals) => vals == other
+            OkexRestApiResponse::Instruments(vals) => vals == other,
+            OkexRestApiResponse::Candles(vals) => vals == other,
+            OkexRestApiResponse::Trades(vals) => vals == other
         }
     }
 }
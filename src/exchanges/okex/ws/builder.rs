@@ -3,7 +3,7 @@ use super::{
     OkexSubscription
 };
 use crate::{
-    clients::ws::MutliWsStreamBuilder,
+    clients::{rest_api::ExchangeApi, ws::MutliWsStreamBuilder, ExchangeCredentials},
     normalized::{types::InstrumentFilter, ws::NormalizedWsChannels},
     okex::Okex,
     CexExchange
@@ -19,13 +19,15 @@ const MAX_OKEX_WS_CONNS_PER_STREAM: usize = 1024;
 pub struct OkexWsBuilder {
     pub channels:            Vec<OkexWsChannel>,
     /// proxy exchange to get on-chain addresses
-    pub exch_currency_proxy: CexExchange
+    pub exch_currency_proxy: CexExchange,
+    /// required for [OkexWsChannel::Account]
+    pub credentials:         Option<ExchangeCredentials>
 }
 
 impl OkexWsBuilder {
     /// the default proxy exchange is [CexExchange::Okex]
     pub fn new(proxy: Option<CexExchange>) -> Self {
-        Self { channels: Vec::new(), exch_currency_proxy: proxy.unwrap_or(CexExchange::Okex) }
+        Self { channels: Vec::new(), exch_currency_proxy: proxy.unwrap_or(CexExchange::Okex), credentials: None }
     }
 
     /// adds a channel to the builder
@@ -34,13 +36,51 @@ impl OkexWsBuilder {
         self
     }
 
+    /// sets the credentials used to login to the private ws
+    pub fn with_credentials(mut self, credentials: ExchangeCredentials) -> Self {
+        self.credentials = Some(credentials);
+        self
+    }
+
+    fn new_exchange(&self, subscription: OkexSubscription) -> Okex {
+        let exchange = Okex::new_ws_subscription(subscription, self.exch_currency_proxy);
+        match self.credentials.clone() {
+            Some(credentials) => exchange.with_credentials(credentials),
+            None => exchange
+        }
+    }
+
+    /// the private channels are on a different url, so they are split out
+    /// onto their own stream
+    fn split_private(&self) -> (Vec<OkexWsChannel>, Option<Okex>) {
+        let (private, public): (Vec<_>, Vec<_>) = self
+            .channels
+            .iter()
+            .cloned()
+            .partition(|ch| ch.is_private());
+        if private.is_empty() {
+            return (public, None)
+        }
+
+        let mut subscription = OkexSubscription::new();
+        private
+            .into_iter()
+            .for_each(|ch| subscription.add_channel(ch));
+
+        (public, Some(self.new_exchange(subscription)))
+    }
+
     /// builds a single ws instance of [Okex], handling all channels on 1
     /// stream
+    ///
+    /// the private channels can't share the stream with public channels
     pub fn build_single(self) -> Okex {
         let mut sub = OkexSubscription::new();
-        self.channels.into_iter().for_each(|c| sub.add_channel(c));
+        self.channels
+            .iter()
+            .for_each(|c| sub.add_channel(c.clone()));
 
-        Okex::new_ws_subscription(sub, self.exch_currency_proxy)
+        self.new_exchange(sub)
     }
 
     /// builds many ws instances of the [Okex] as the inner streams of
@@ -49,9 +89,10 @@ impl OkexWsBuilder {
     ///
     /// WARNING: too many channels may break the stream
     pub fn build_many_distributed(self) -> eyre::Result<MutliWsStreamBuilder<Okex>> {
-        let stream_size = if self.channels.len() <= MAX_OKEX_STREAMS { 1 } else { self.channels.len() / MAX_OKEX_STREAMS };
+        let (channels, private_exchange) = self.split_private();
+        let stream_size = if channels.len() <= MAX_OKEX_STREAMS { 1 } else { channels.len() / MAX_OKEX_STREAMS };
 
-        let chunks = self.channels.chunks(stream_size).collect::<Vec<_>>();
+        let chunks = channels.chunks(stream_size).collect::<Vec<_>>();
 
         let split_exchange = chunks
             .into_iter()
@@ -60,8 +101,9 @@ impl OkexWsBuilder {
                 chk.iter()
                     .for_each(|ch| subscription.add_channel(ch.clone()));
 
-                Okex::new_ws_subscription(subscription, self.exch_currency_proxy)
+                self.new_exchange(subscription)
             })
+            .chain(private_exchange)
             .collect();
 
         Ok(MutliWsStreamBuilder::new(split_exchange))
@@ -71,8 +113,8 @@ impl OkexWsBuilder {
     /// [MutliWsStreamBuilder], splitting the channels into different streams,
     /// each of size 1024
     pub fn build_many_packed(self, connections_per_stream: Option<usize>) -> eyre::Result<MutliWsStreamBuilder<Okex>> {
-        let chunks = self
-            .channels
+        let (channels, private_exchange) = self.split_private();
+        let chunks = channels
             .chunks(connections_per_stream.unwrap_or(MAX_OKEX_WS_CONNS_PER_STREAM))
             .collect::<Vec<_>>();
 
@@ -83,8 +125,9 @@ impl OkexWsBuilder {
                 chk.iter()
                     .for_each(|ch| subscription.add_channel(ch.clone()));
 
-                Okex::new_ws_subscription(subscription, self.exch_currency_proxy)
+                self.new_exchange(subscription)
             })
+            .chain(private_exchange)
             .collect();
 
         Ok(MutliWsStreamBuilder::new(split_exchange))
@@ -100,12 +143,12 @@ impl OkexWsBuilder {
 
         let all_streams = this
             .channels
-            .into_iter()
+            .iter()
             .map(|ch| {
                 let mut subscription = OkexSubscription::new();
-                subscription.add_channel(ch);
+                subscription.add_channel(ch.clone());
 
-                Okex::new_ws_subscription(subscription, this.exch_currency_proxy)
+                this.new_exchange(subscription)
             })
             .collect::<Vec<_>>();
 
@@ -121,7 +164,7 @@ impl OkexWsBuilder {
 
         let all_symbols = this
             .exch_currency_proxy
-            .get_all_instruments(Some(InstrumentFilter::Active))
+            .get_all_instruments(&ExchangeApi::new(), Some(InstrumentFilter::Active))
             .await?;
 
         let rest = all_symbols
@@ -134,21 +177,32 @@ impl OkexWsBuilder {
         chunks.into_iter().for_each(|chk| {
             let all_channels = channels
                 .iter()
-                .map(|ch| match ch {
-                    OkexWsChannelKind::TradesAll => OkexWsChannel::TradesAll(chk.to_vec()),
-                    OkexWsChannelKind::BookTicker => OkexWsChannel::BookTicker(chk.to_vec())
+                .filter_map(|ch| match ch {
+                    OkexWsChannelKind::TradesAll => Some(OkexWsChannel::TradesAll(chk.to_vec())),
+                    OkexWsChannelKind::BookTicker => Some(OkexWsChannel::BookTicker(chk.to_vec())),
+                    OkexWsChannelKind::Books => Some(OkexWsChannel::Books(chk.to_vec())),
+                    OkexWsChannelKind::Candle(interval) => Some(OkexWsChannel::Candle { pairs: chk.to_vec(), interval: *interval }),
+                    OkexWsChannelKind::Account => None
                 })
                 .collect::<Vec<_>>();
 
             this.channels.extend(all_channels);
         });
 
+        if channels.contains(&OkexWsChannelKind::Account) {
+            this.channels.push(OkexWsChannel::Account);
+        }
+
         Ok(this)
     }
 
     /// makes the builder from the normalized builder's map
-    pub(crate) fn make_from_normalized_map(map: Vec<NormalizedWsChannels>, exch_currency_proxy: CexExchange) -> eyre::Result<Self> {
-        let mut this = Self { channels: Vec::new(), exch_currency_proxy };
+    pub(crate) fn make_from_normalized_map(
+        map: Vec<NormalizedWsChannels>,
+        exch_currency_proxy: CexExchange,
+        credentials: Option<ExchangeCredentials>
+    ) -> eyre::Result<Self> {
+        let mut this = Self { channels: Vec::new(), exch_currency_proxy, credentials };
 
         map.into_iter().try_for_each(|channel| {
             let this_channel: OkexWsChannel = channel.try_into()?;
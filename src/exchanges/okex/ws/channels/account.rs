@@ -0,0 +1,170 @@
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr, NoneAsEmptyString};
+use tracing::warn;
+
+use crate::{
+    exchanges::{
+        normalized::types::{NormalizedBalanceUpdate, NormalizedFill, NormalizedOrderSide, NormalizedOrderStatus, NormalizedOrderUpdate},
+        okex::pairs::OkexTradingPair
+    },
+    normalized::types::NormalizedNumber,
+    CexExchange
+};
+
+/// `account` channel, sent with the balances of the currencies that changed
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct OkexAccount {
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "uTime")]
+    pub update_time: u64,
+    pub details:     Vec<OkexAccountDetail>
+}
+
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct OkexAccountDetail {
+    #[serde(rename = "ccy")]
+    pub currency:  String,
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "availBal")]
+    pub available: NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "frozenBal")]
+    pub frozen:    NormalizedNumber
+}
+
+impl OkexAccount {
+    pub fn normalize(self) -> Vec<NormalizedBalanceUpdate> {
+        let time = DateTime::from_timestamp_millis(self.update_time as i64).unwrap();
+
+        self.details
+            .into_iter()
+            .map(|d| NormalizedBalanceUpdate { exchange: CexExchange::Okex, symbol: d.currency, available: d.available, locked: d.frozen, time })
+            .collect()
+    }
+}
+
+impl PartialEq<Vec<NormalizedBalanceUpdate>> for OkexAccount {
+    fn eq(&self, other: &Vec<NormalizedBalanceUpdate>) -> bool {
+        let equals = self.clone().normalize() == *other;
+
+        if !equals {
+            warn!(target: "cex-exchanges::okex", "okex account: {:?}", self);
+            warn!(target: "cex-exchanges::okex", "normalized balance updates: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+/// `orders` channel & `GET /api/v5/trade/orders-pending`, the fill fields are
+/// empty if the update isn't a fill
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct OkexOrder {
+    #[serde(rename = "instId")]
+    pub pair:            OkexTradingPair,
+    #[serde(rename = "ordId")]
+    pub order_id:        String,
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(rename = "clOrdId")]
+    pub client_order_id: Option<String>,
+    #[serde_as(as = "DisplayFromStr")]
+    pub side:            NormalizedOrderSide,
+    #[serde(rename = "ordType")]
+    pub order_type:      String,
+    /// live, partially_filled, filled, canceled, mmp_canceled
+    pub state:           String,
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(rename = "px")]
+    pub price:           Option<NormalizedNumber>,
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "sz")]
+    pub quantity:        NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "accFillSz")]
+    pub filled_quantity: NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "uTime")]
+    pub update_time:     u64,
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(rename = "tradeId")]
+    pub trade_id:        Option<String>,
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(rename = "fillPx")]
+    pub fill_price:      Option<NormalizedNumber>,
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(rename = "fillSz")]
+    pub fill_quantity:   Option<NormalizedNumber>,
+    /// negative for fees charged, positive for rebates
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(rename = "fillFee", default)]
+    pub fill_fee:        Option<NormalizedNumber>,
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(rename = "fillFeeCcy", default)]
+    pub fill_fee_ccy:    Option<String>,
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(rename = "fillTime")]
+    pub fill_time:       Option<u64>,
+    /// T: taker, M: maker - not sent by the rest api
+    #[serde_as(as = "NoneAsEmptyString")]
+    #[serde(rename = "execType", default)]
+    pub exec_type:       Option<String>
+}
+
+impl OkexOrder {
+    pub fn normalize(self) -> NormalizedOrderUpdate {
+        let status = match self.state.as_str() {
+            "partially_filled" => NormalizedOrderStatus::PartiallyFilled,
+            "filled" => NormalizedOrderStatus::Filled,
+            "canceled" | "mmp_canceled" => NormalizedOrderStatus::Canceled,
+            _ => NormalizedOrderStatus::New
+        };
+
+        NormalizedOrderUpdate {
+            exchange: CexExchange::Okex,
+            pair: self.pair.normalize(),
+            order_id: self.order_id.clone(),
+            client_order_id: self.client_order_id.clone(),
+            side: self.side,
+            order_type: self.order_type.clone(),
+            status,
+            price: self.price,
+            amount: Some(self.quantity),
+            filled_amount: Some(self.filled_quantity),
+            time: DateTime::from_timestamp_millis(self.update_time as i64).unwrap(),
+            last_fill: self.fill()
+        }
+    }
+
+    fn fill(&self) -> Option<NormalizedFill> {
+        Some(NormalizedFill {
+            exchange:   CexExchange::Okex,
+            pair:       self.pair.normalize(),
+            order_id:   self.order_id.clone(),
+            trade_id:   self.trade_id.clone()?,
+            side:       self.side,
+            price:      self.fill_price?,
+            amount:     self.fill_quantity?,
+            fee:        self.fill_fee.map(|fee| -fee),
+            fee_symbol: self.fill_fee_ccy.clone(),
+            is_maker:   self.exec_type.as_ref().map(|t| t == "M"),
+            time:       DateTime::from_timestamp_millis(self.fill_time? as i64).unwrap()
+        })
+    }
+}
+
+impl PartialEq<NormalizedOrderUpdate> for OkexOrder {
+    fn eq(&self, other: &NormalizedOrderUpdate) -> bool {
+        let equals = self.clone().normalize() == *other;
+
+        if !equals {
+            warn!(target: "cex-exchanges::okex", "okex order: {:?}", self);
+            warn!(target: "cex-exchanges::okex", "normalized order update: {:?}", other);
+        }
+
+        equals
+    }
+}
@@ -0,0 +1,99 @@
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use tracing::warn;
+
+use crate::{
+    exchanges::{
+        normalized::types::{NormalizedBookLevel, NormalizedOrderBookUpdate, NormalizedOrderBookUpdateKind},
+        okex::pairs::OkexTradingPair
+    },
+    normalized::types::NormalizedNumber,
+    CexExchange
+};
+
+/// the `books` channel, the pair and action are taken from the outer message
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct OkexBook {
+    pub pair:   OkexTradingPair,
+    /// 'snapshot' or 'update'
+    pub action: String,
+    pub data:   OkexBookData
+}
+
+impl OkexBook {
+    pub fn normalize(self) -> NormalizedOrderBookUpdate {
+        NormalizedOrderBookUpdate {
+            exchange:        CexExchange::Okex,
+            pair:            self.pair.normalize(),
+            time:            DateTime::from_timestamp_millis(self.data.timestamp as i64).unwrap(),
+            kind:            self.update_kind(),
+            bids:            OkexBookData::normalize_side(&self.data.bids),
+            asks:            OkexBookData::normalize_side(&self.data.asks),
+            first_update_id: None,
+            last_update_id:  self.data.seq_id.map(|s| s as u64)
+        }
+    }
+
+    fn update_kind(&self) -> NormalizedOrderBookUpdateKind {
+        if &self.action == "snapshot" {
+            NormalizedOrderBookUpdateKind::Snapshot
+        } else {
+            NormalizedOrderBookUpdateKind::Delta
+        }
+    }
+}
+
+impl PartialEq<NormalizedOrderBookUpdate> for OkexBook {
+    fn eq(&self, other: &NormalizedOrderBookUpdate) -> bool {
+        let equals = other.exchange == CexExchange::Okex
+            && other.pair == self.pair.normalize()
+            && other.time == DateTime::from_timestamp_millis(self.data.timestamp as i64).unwrap()
+            && other.kind == self.update_kind()
+            && other.bids == OkexBookData::normalize_side(&self.data.bids)
+            && other.asks == OkexBookData::normalize_side(&self.data.asks)
+            && other.last_update_id == self.data.seq_id.map(|s| s as u64);
+
+        if !equals {
+            warn!(target: "cex-exchanges::okex", "okex book: {:?}", self);
+            warn!(target: "cex-exchanges::okex", "normalized order book: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+/// order book snapshot from the rest api (`/api/v5/market/books`), it has no
+/// `seqId` so the `books` channel is resynced by resubscribing
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct OkexBooksResponse {
+    pub data: Vec<OkexBookData>
+}
+
+/// each level is (price, size, deprecated, # orders)
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct OkexBookData {
+    #[serde_as(as = "Vec<(DisplayFromStr, DisplayFromStr, _, DisplayFromStr)>")]
+    pub asks:        Vec<(NormalizedNumber, NormalizedNumber, String, u64)>,
+    #[serde_as(as = "Vec<(DisplayFromStr, DisplayFromStr, _, DisplayFromStr)>")]
+    pub bids:        Vec<(NormalizedNumber, NormalizedNumber, String, u64)>,
+    #[serde_as(as = "DisplayFromStr")]
+    #[serde(rename = "ts")]
+    pub timestamp:   u64,
+    #[serde(default)]
+    pub checksum:    Option<i64>,
+    #[serde(rename = "prevSeqId", default)]
+    pub prev_seq_id: Option<i64>,
+    #[serde(rename = "seqId", default)]
+    pub seq_id:      Option<i64>
+}
+
+impl OkexBookData {
+    fn normalize_side(levels: &[(NormalizedNumber, NormalizedNumber, String, u64)]) -> Vec<NormalizedBookLevel> {
+        levels
+            .iter()
+            .map(|(price, amount, ..)| NormalizedBookLevel::new(*price, *amount))
+            .collect()
+    }
+}
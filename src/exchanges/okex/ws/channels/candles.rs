@@ -0,0 +1,138 @@
+use std::{fmt::Display, str::FromStr};
+
+use chrono::DateTime;
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use tracing::warn;
+
+use crate::{
+    exchanges::{
+        normalized::types::{NormalizedCandle, NormalizedCandleInterval, NormalizedNumber},
+        okex::pairs::OkexTradingPair
+    },
+    CexExchange
+};
+
+/// candle channel (`candle<bar>`), the pair & bar are taken from the `arg`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct OkexCandle {
+    pub pair:     OkexTradingPair,
+    pub interval: OkexCandleBar,
+    pub data:     Vec<OkexCandleData>
+}
+
+impl OkexCandle {
+    pub fn normalize(self) -> Vec<NormalizedCandle> {
+        self.data
+            .into_iter()
+            .map(|c| c.normalize(&self.pair, self.interval.0))
+            .collect()
+    }
+}
+
+impl PartialEq<Vec<NormalizedCandle>> for OkexCandle {
+    fn eq(&self, other: &Vec<NormalizedCandle>) -> bool {
+        let equals = self.clone().normalize() == *other;
+
+        if !equals {
+            warn!(target: "cex-exchanges::okex", "okex candle: {:?}", self);
+            warn!(target: "cex-exchanges::okex", "normalized candles: {:?}", other);
+        }
+
+        equals
+    }
+}
+
+/// a single candle, sent as an array of strings over both the ws & rest api
+#[serde_as]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct OkexCandleData {
+    #[serde_as(as = "DisplayFromStr")]
+    pub start_time:       u64,
+    #[serde_as(as = "DisplayFromStr")]
+    pub open:             NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub high:             NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub low:              NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub close:            NormalizedNumber,
+    /// volume in the base asset
+    #[serde_as(as = "DisplayFromStr")]
+    pub volume:           NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub volume_ccy:       NormalizedNumber,
+    #[serde_as(as = "DisplayFromStr")]
+    pub volume_ccy_quote: NormalizedNumber,
+    /// "0" if the candle is uncompleted, "1" if it's completed
+    pub confirm:          String
+}
+
+impl OkexCandleData {
+    pub fn normalize(self, pair: &OkexTradingPair, interval: NormalizedCandleInterval) -> NormalizedCandle {
+        let start_time = DateTime::from_timestamp_millis(self.start_time as i64).unwrap();
+
+        NormalizedCandle {
+            exchange: CexExchange::Okex,
+            pair: pair.normalize(),
+            interval,
+            start_time,
+            end_time: interval.end_time(start_time),
+            open: self.open,
+            high: self.high,
+            low: self.low,
+            close: self.close,
+            volume: self.volume,
+            trade_count: None,
+            closed: Some(&self.confirm == "1")
+        }
+    }
+}
+
+/// the bar as used in okex's channels & requests (utc aligned for 6h and
+/// above)
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Serialize, Deserialize)]
+pub struct OkexCandleBar(pub NormalizedCandleInterval);
+
+impl Display for OkexCandleBar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            NormalizedCandleInterval::OneMinute => write!(f, "1m"),
+            NormalizedCandleInterval::ThreeMinutes => write!(f, "3m"),
+            NormalizedCandleInterval::FiveMinutes => write!(f, "5m"),
+            NormalizedCandleInterval::FifteenMinutes => write!(f, "15m"),
+            NormalizedCandleInterval::ThirtyMinutes => write!(f, "30m"),
+            NormalizedCandleInterval::OneHour => write!(f, "1H"),
+            NormalizedCandleInterval::TwoHours => write!(f, "2H"),
+            NormalizedCandleInterval::FourHours => write!(f, "4H"),
+            NormalizedCandleInterval::SixHours => write!(f, "6Hutc"),
+            NormalizedCandleInterval::TwelveHours => write!(f, "12Hutc"),
+            NormalizedCandleInterval::OneDay => write!(f, "1Dutc"),
+            NormalizedCandleInterval::OneWeek => write!(f, "1Wutc")
+        }
+    }
+}
+
+impl FromStr for OkexCandleBar {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let interval = match s {
+            "1m" => NormalizedCandleInterval::OneMinute,
+            "3m" => NormalizedCandleInterval::ThreeMinutes,
+            "5m" => NormalizedCandleInterval::FiveMinutes,
+            "15m" => NormalizedCandleInterval::FifteenMinutes,
+            "30m" => NormalizedCandleInterval::ThirtyMinutes,
+            "1H" => NormalizedCandleInterval::OneHour,
+            "2H" => NormalizedCandleInterval::TwoHours,
+            "4H" => NormalizedCandleInterval::FourHours,
+            "6Hutc" | "6H" => NormalizedCandleInterval::SixHours,
+            "12Hutc" | "12H" => NormalizedCandleInterval::TwelveHours,
+            "1Dutc" | "1D" => NormalizedCandleInterval::OneDay,
+            "1Wutc" | "1W" => NormalizedCandleInterval::OneWeek,
+            _ => return Err(eyre::ErrReport::msg(format!("'{s}' is not a valid okex candle bar")))
+        };
+
+        Ok(Self(interval))
+    }
+}
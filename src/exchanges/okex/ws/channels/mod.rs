@@ -4,10 +4,19 @@ pub use tickers::*;
 mod trades;
 pub use trades::*;
 
+mod books;
+pub use books::*;
+
+mod candles;
+pub use candles::*;
+
+mod account;
+pub use account::*;
+
 use crate::{
     exchanges::{
         normalized::{
-            types::{NormalizedTradingPair, RawTradingPair},
+            types::{NormalizedCandleInterval, NormalizedTradingPair, RawTradingPair},
             ws::NormalizedWsChannels
         },
         okex::pairs::OkexTradingPair
@@ -18,7 +27,15 @@ use crate::{
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum OkexWsChannel {
     TradesAll(Vec<OkexTradingPair>),
-    BookTicker(Vec<OkexTradingPair>)
+    BookTicker(Vec<OkexTradingPair>),
+    Books(Vec<OkexTradingPair>),
+    Candle {
+        pairs:    Vec<OkexTradingPair>,
+        interval: NormalizedCandleInterval
+    },
+    /// private `account` (balances) & spot `orders` channels, requires
+    /// credentials
+    Account
 }
 
 impl OkexWsChannel {
@@ -44,6 +61,33 @@ impl OkexWsChannel {
         Self::new_from_kind(normalized, OkexWsChannel::BookTicker(Vec::new()))
     }
 
+    /// builds the order book channel from a vec of raw trading pairs
+    /// return an error if the symbol is incorrectly formatted
+    pub fn new_books(pairs: Vec<RawTradingPair>) -> eyre::Result<Self> {
+        let normalized = pairs
+            .into_iter()
+            .map(|pair| pair.get_normalized_pair(CexExchange::Okex))
+            .collect();
+
+        Self::new_from_kind(normalized, OkexWsChannel::Books(Vec::new()))
+    }
+
+    /// builds the candle channel from a vec of raw trading pairs
+    /// return an error if the symbol is incorrectly formatted
+    pub fn new_candle(pairs: Vec<RawTradingPair>, interval: NormalizedCandleInterval) -> eyre::Result<Self> {
+        let normalized = pairs
+            .into_iter()
+            .map(|pair| pair.get_normalized_pair(CexExchange::Okex))
+            .collect();
+
+        Self::new_from_kind(normalized, OkexWsChannel::Candle { pairs: Vec::new(), interval })
+    }
+
+    /// if the channel requires a login
+    pub fn is_private(&self) -> bool {
+        matches!(self, OkexWsChannel::Account)
+    }
+
     fn new_from_kind(pairs: Vec<NormalizedTradingPair>, kind: OkexWsChannel) -> eyre::Result<Self> {
         match kind {
             OkexWsChannel::TradesAll(_) => Ok(OkexWsChannel::TradesAll(
@@ -57,7 +101,21 @@ impl OkexWsChannel {
                     .into_iter()
                     .map(TryInto::try_into)
                     .collect::<Result<_, _>>()?
-            ))
+            )),
+            OkexWsChannel::Books(_) => Ok(OkexWsChannel::Books(
+                pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<_, _>>()?
+            )),
+            OkexWsChannel::Candle { interval, .. } => Ok(OkexWsChannel::Candle {
+                pairs: pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<_, _>>()?,
+                interval
+            }),
+            OkexWsChannel::Account => Ok(OkexWsChannel::Account)
         }
     }
 }
@@ -66,7 +124,10 @@ impl std::fmt::Display for OkexWsChannel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             OkexWsChannel::TradesAll(_) => write!(f, "trades-all"),
-            OkexWsChannel::BookTicker(_) => write!(f, "tickers")
+            OkexWsChannel::BookTicker(_) => write!(f, "tickers"),
+            OkexWsChannel::Books(_) => write!(f, "books"),
+            OkexWsChannel::Candle { interval, .. } => write!(f, "candle{}", OkexCandleBar(*interval)),
+            OkexWsChannel::Account => write!(f, "account")
         }
     }
 }
@@ -75,9 +136,16 @@ impl TryFrom<String> for OkexWsChannel {
     type Error = eyre::Report;
 
     fn try_from(value: String) -> Result<Self, Self::Error> {
+        if let Some(bar) = value.strip_prefix("candle") {
+            let bar: OkexCandleBar = bar.parse()?;
+            return Ok(Self::Candle { pairs: Vec::new(), interval: bar.0 })
+        }
+
         match value.to_lowercase().as_str() {
             "trades-all" => Ok(Self::TradesAll(Vec::new())),
             "tickers" => Ok(Self::BookTicker(Vec::new())),
+            "books" => Ok(Self::Books(Vec::new())),
+            "account" => Ok(Self::Account),
             _ => Err(eyre::ErrReport::msg(format!("channel is not valid: {value}")))
         }
     }
@@ -104,6 +172,23 @@ impl TryFrom<NormalizedWsChannels> for OkexWsChannel {
 
                 Ok(OkexWsChannel::BookTicker(norm_pairs))
             }
+            NormalizedWsChannels::OrderBook { pairs, .. } => {
+                let norm_pairs = pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, Self::Error>>()?;
+
+                Ok(OkexWsChannel::Books(norm_pairs))
+            }
+            NormalizedWsChannels::Candles { pairs, interval } => {
+                let norm_pairs = pairs
+                    .into_iter()
+                    .map(TryInto::try_into)
+                    .collect::<Result<Vec<_>, Self::Error>>()?;
+
+                Ok(OkexWsChannel::Candle { pairs: norm_pairs, interval })
+            }
+            NormalizedWsChannels::Account(_) => Ok(OkexWsChannel::Account)
         }
     }
 }
@@ -111,14 +196,20 @@ impl TryFrom<NormalizedWsChannels> for OkexWsChannel {
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum OkexWsChannelKind {
     TradesAll,
-    BookTicker
+    BookTicker,
+    Books,
+    Candle(NormalizedCandleInterval),
+    Account
 }
 
 impl std::fmt::Display for OkexWsChannelKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             OkexWsChannelKind::TradesAll => write!(f, "trades-all"),
-            OkexWsChannelKind::BookTicker => write!(f, "bookTicker")
+            OkexWsChannelKind::BookTicker => write!(f, "bookTicker"),
+            OkexWsChannelKind::Books => write!(f, "books"),
+            OkexWsChannelKind::Candle(interval) => write!(f, "candle{}", OkexCandleBar(*interval)),
+            OkexWsChannelKind::Account => write!(f, "account")
         }
     }
 }
@@ -127,7 +218,10 @@ impl From<&OkexWsChannel> for OkexWsChannelKind {
     fn from(value: &OkexWsChannel) -> Self {
         match value {
             OkexWsChannel::TradesAll(_) => OkexWsChannelKind::TradesAll,
-            OkexWsChannel::BookTicker(_) => OkexWsChannelKind::BookTicker
+            OkexWsChannel::BookTicker(_) => OkexWsChannelKind::BookTicker,
+            OkexWsChannel::Books(_) => OkexWsChannelKind::Books,
+            OkexWsChannel::Candle { interval, .. } => OkexWsChannelKind::Candle(*interval),
+            OkexWsChannel::Account => OkexWsChannelKind::Account
         }
     }
 }
@@ -1,10 +1,12 @@
-use chrono::DateTime;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 use tracing::warn;
 
 use crate::{
+    clients::ws::received_at,
     exchanges::{normalized::types::NormalizedQuote, okex::pairs::OkexTradingPair},
+    normalized::types::NormalizedNumber,
     CexExchange
 };
 
@@ -18,66 +20,69 @@ pub struct OkexTicker {
     pub pair:              OkexTradingPair,
     #[serde_as(as = "DisplayFromStr")]
     #[serde(rename = "last")]
-    pub last_price:        f64,
+    pub last_price:        NormalizedNumber,
     #[serde_as(as = "DisplayFromStr")]
     #[serde(rename = "lastSz")]
-    pub last_size:         f64,
+    pub last_size:         NormalizedNumber,
     #[serde_as(as = "DisplayFromStr")]
     #[serde(rename = "askPx")]
-    pub ask_price:         f64,
+    pub ask_price:         NormalizedNumber,
     #[serde_as(as = "DisplayFromStr")]
     #[serde(rename = "askSz")]
-    pub ask_amt:           f64,
+    pub ask_amt:           NormalizedNumber,
     #[serde_as(as = "DisplayFromStr")]
     #[serde(rename = "bidPx")]
-    pub bid_price:         f64,
+    pub bid_price:         NormalizedNumber,
     #[serde_as(as = "DisplayFromStr")]
     #[serde(rename = "bidSz")]
-    pub bid_amt:           f64,
+    pub bid_amt:           NormalizedNumber,
     #[serde_as(as = "DisplayFromStr")]
     #[serde(rename = "open24h")]
-    pub open_price_24hr:   f64,
+    pub open_price_24hr:   NormalizedNumber,
     #[serde_as(as = "DisplayFromStr")]
     #[serde(rename = "high24h")]
-    pub high_price_24h:    f64,
+    pub high_price_24h:    NormalizedNumber,
     #[serde_as(as = "DisplayFromStr")]
     #[serde(rename = "low24h")]
-    pub low_price_24h:     f64,
+    pub low_price_24h:     NormalizedNumber,
     /// 24h trading volume, with a unit of currency.
     /// If it is a derivatives contract, the value is the number of base
     /// currency. If it is SPOT/MARGIN, the value is the quantity in quote
     /// currency.
     #[serde_as(as = "DisplayFromStr")]
     #[serde(rename = "volCcy24h")]
-    pub vol_currency_24hr: f64,
+    pub vol_currency_24hr: NormalizedNumber,
     /// 24h trading volume, with a unit of contract.
     /// If it is a derivatives contract, the value is the number of contracts.
     /// If it is SPOT/MARGIN, the value is the quantity in base currency.
     #[serde_as(as = "DisplayFromStr")]
     #[serde(rename = "vol24h")]
-    pub vol_contract_24hr: f64,
+    pub vol_contract_24hr: NormalizedNumber,
     #[serde_as(as = "DisplayFromStr")]
     #[serde(rename = "sodUtc0")]
-    pub open_price_utc0:   f64,
+    pub open_price_utc0:   NormalizedNumber,
     #[serde_as(as = "DisplayFromStr")]
     #[serde(rename = "sodUtc8")]
-    pub open_price_utc8:   f64,
+    pub open_price_utc8:   NormalizedNumber,
     #[serde_as(as = "DisplayFromStr")]
     #[serde(rename = "ts")]
-    pub timestamp:         u64
+    pub timestamp:         u64,
+    #[serde(default = "received_at")]
+    pub received_at:       DateTime<Utc>
 }
 
 impl OkexTicker {
     pub fn normalize(self) -> NormalizedQuote {
         NormalizedQuote {
-            exchange:   CexExchange::Okex,
-            pair:       self.pair.normalize(),
-            time:       DateTime::from_timestamp_millis(self.timestamp as i64).unwrap(),
-            ask_amount: self.ask_amt,
-            ask_price:  self.ask_price,
-            bid_amount: self.bid_amt,
-            bid_price:  self.bid_price,
-            quote_id:   None
+            exchange:      CexExchange::Okex,
+            pair:          self.pair.normalize(),
+            exchange_time: DateTime::from_timestamp_millis(self.timestamp as i64),
+            received_at:   self.received_at,
+            ask_amount:    self.ask_amt,
+            ask_price:     self.ask_price,
+            bid_amount:    self.bid_amt,
+            bid_price:     self.bid_price,
+            quote_id:      None
         }
     }
 }
@@ -86,7 +91,8 @@ impl PartialEq<NormalizedQuote> for OkexTicker {
     fn eq(&self, other: &NormalizedQuote) -> bool {
         let equals = other.exchange == CexExchange::Okex
             && other.pair == self.pair.normalize()
-            && other.time == DateTime::from_timestamp_millis(self.timestamp as i64).unwrap()
+            && other.exchange_time == DateTime::from_timestamp_millis(self.timestamp as i64)
+            && other.received_at == self.received_at
             && other.bid_amount == self.bid_amt
             && other.bid_price == self.bid_price
             && other.ask_amount == self.ask_amt
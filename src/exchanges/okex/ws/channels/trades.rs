@@ -1,10 +1,15 @@
-use chrono::DateTime;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_with::{serde_as, DisplayFromStr};
 use tracing::warn;
 
 use crate::{
-    exchanges::{normalized::types::NormalizedTrade, okex::pairs::OkexTradingPair},
+    clients::ws::received_at,
+    exchanges::{
+        normalized::types::{NormalizedTrade, TradeSide},
+        okex::pairs::OkexTradingPair
+    },
+    normalized::types::NormalizedNumber,
     CexExchange
 };
 
@@ -12,31 +17,34 @@ use crate::{
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct OkexTrade {
     #[serde(rename = "instId")]
-    pub pair:       OkexTradingPair,
+    pub pair:        OkexTradingPair,
     #[serde_as(as = "DisplayFromStr")]
     #[serde(rename = "px")]
-    pub price:      f64,
+    pub price:       NormalizedNumber,
     #[serde_as(as = "DisplayFromStr")]
     #[serde(rename = "sz")]
-    pub quantity:   f64,
+    pub quantity:    NormalizedNumber,
     #[serde(rename = "tradeId")]
-    pub trade_id:   String,
-    pub side:       String,
+    pub trade_id:    String,
+    pub side:        String,
     #[serde_as(as = "DisplayFromStr")]
     #[serde(rename = "ts")]
-    pub trade_time: u64
+    pub trade_time:  u64,
+    #[serde(default = "received_at")]
+    pub received_at: DateTime<Utc>
 }
 
 impl OkexTrade {
     pub fn normalize(self) -> NormalizedTrade {
         NormalizedTrade {
-            exchange: CexExchange::Okex,
-            pair:     self.pair.normalize(),
-            time:     DateTime::from_timestamp_millis(self.trade_time as i64).unwrap(),
-            side:     self.side.to_lowercase(),
-            price:    self.price,
-            amount:   self.quantity,
-            trade_id: Some(self.trade_id.to_string())
+            exchange:      CexExchange::Okex,
+            pair:          self.pair.normalize(),
+            exchange_time: DateTime::from_timestamp_millis(self.trade_time as i64),
+            received_at:   self.received_at,
+            side:          TradeSide::from_taker_side(&self.side),
+            price:         self.price,
+            amount:        self.quantity,
+            trade_id:      Some(self.trade_id.to_string())
         }
     }
 }
@@ -45,8 +53,9 @@ impl PartialEq<NormalizedTrade> for OkexTrade {
     fn eq(&self, other: &NormalizedTrade) -> bool {
         let equals = other.exchange == CexExchange::Okex
             && other.pair == self.pair.normalize()
-            && other.time == DateTime::from_timestamp_millis(self.trade_time as i64).unwrap()
-            && other.side == self.side.to_lowercase()
+            && other.exchange_time == DateTime::from_timestamp_millis(self.trade_time as i64)
+            && other.received_at == self.received_at
+            && other.side == TradeSide::from_taker_side(&self.side)
             && other.price == self.price
             && other.amount == self.quantity
             && other.trade_id.as_ref().unwrap() == &self.trade_id.to_string();
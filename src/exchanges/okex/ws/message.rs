@@ -1,8 +1,15 @@
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
-use super::channels::{OkexTicker, OkexTrade};
-use crate::{clients::ws::CriticalWsMessage, exchanges::normalized::ws::NormalizedWsDataTypes, okex::OkexTradingPair, CexExchange};
+use super::channels::{OkexAccount, OkexBook, OkexBookData, OkexCandle, OkexCandleBar, OkexCandleData, OkexOrder, OkexTicker, OkexTrade};
+use crate::{
+    clients::ws::{CriticalWsMessage, SequenceKind, WsSequence},
+    exchanges::normalized::ws::NormalizedWsDataTypes,
+    okex::OkexTradingPair,
+    CexExchange
+};
 
 #[serde_with::serde_as]
 #[derive(Debug, Clone, Serialize, PartialEq)]
@@ -10,8 +17,18 @@ use crate::{clients::ws::CriticalWsMessage, exchanges::normalized::ws::Normalize
 pub enum OkexWsMessage {
     TradesAll(OkexTrade),
     Tickers(OkexTicker),
+    Books(OkexBook),
+    Candle(OkexCandle),
+    Account(Vec<OkexAccount>),
+    Orders(Vec<OkexOrder>),
     Subscribe(serde_json::Value),
-    Error { error: String, raw_msg: String, bad_pair: Option<OkexTradingPair> }
+    /// other events of the private ws (ex: `login`, `channel-conn-count`)
+    Event(serde_json::Value),
+    Error {
+        error:    String,
+        raw_msg:  String,
+        bad_pair: Option<OkexTradingPair>
+    }
 }
 
 impl OkexWsMessage {
@@ -30,6 +47,35 @@ impl OkexWsMessage {
             } else if channel == "tickers" {
                 let data: Vec<OkexTicker> = serde_json::from_value(data.clone())?;
                 Ok(Self::Tickers(data.first().unwrap().clone()))
+            } else if channel == "books" {
+                let pair = value
+                    .get("arg")
+                    .and_then(|arg| arg.get("instId"))
+                    .ok_or(eyre::ErrReport::msg("Could not find nested 'instId' field in Okex ws message".to_string()))?;
+                let action = value
+                    .get("action")
+                    .ok_or(eyre::ErrReport::msg("Could not find 'action' field in Okex ws message".to_string()))?
+                    .as_str()
+                    .ok_or(eyre::ErrReport::msg("Could not convert 'action' field in Okex ws message to &str".to_string()))?;
+                let data: Vec<OkexBookData> = serde_json::from_value(data.clone())?;
+
+                Ok(Self::Books(OkexBook {
+                    pair:   serde_json::from_value(pair.clone())?,
+                    action: action.to_string(),
+                    data:   data.first().unwrap().clone()
+                }))
+            } else if channel == "account" {
+                Ok(Self::Account(serde_json::from_value(data.clone())?))
+            } else if channel == "orders" {
+                Ok(Self::Orders(serde_json::from_value(data.clone())?))
+            } else if let Some(bar) = channel.strip_prefix("candle") {
+                let pair = value
+                    .get("arg")
+                    .and_then(|arg| arg.get("instId"))
+                    .ok_or(eyre::ErrReport::msg("Could not find nested 'instId' field in Okex ws message".to_string()))?;
+                let data: Vec<OkexCandleData> = serde_json::from_value(data.clone())?;
+
+                Ok(Self::Candle(OkexCandle { pair: serde_json::from_value(pair.clone())?, interval: OkexCandleBar::from_str(bar)?, data }))
             } else {
                 Err(eyre::ErrReport::msg(format!("Channel type '{channel}' cannot be deserialized")))
             }
@@ -40,8 +86,10 @@ impl OkexWsMessage {
                 .as_str()
                 .ok_or(eyre::ErrReport::msg("Could not convert 'event' field in Okex ws message to &str".to_string()))?;
 
-            if event == "subscribe" {
+            if event == "subscribe" || event == "unsubscribe" {
                 Ok(Self::Subscribe(value))
+            } else if event == "login" || event == "channel-conn-count" {
+                Ok(Self::Event(value))
             } else if event == "error" {
                 let msg = value
                     .get("msg")
@@ -73,9 +121,16 @@ impl OkexWsMessage {
         match self {
             OkexWsMessage::TradesAll(v) => NormalizedWsDataTypes::Trade(v.normalize()),
             OkexWsMessage::Tickers(v) => NormalizedWsDataTypes::Quote(v.normalize()),
+            OkexWsMessage::Books(v) => NormalizedWsDataTypes::OrderBook(v.normalize()),
+            OkexWsMessage::Candle(v) => NormalizedWsDataTypes::Candles(v.normalize()),
+            OkexWsMessage::Account(v) => NormalizedWsDataTypes::BalanceUpdates(v.into_iter().flat_map(|a| a.normalize()).collect()),
+            OkexWsMessage::Orders(v) => NormalizedWsDataTypes::OrderUpdates(v.into_iter().map(|o| o.normalize()).collect()),
             OkexWsMessage::Subscribe(v) => {
                 NormalizedWsDataTypes::Other { exchange: CexExchange::Okex, kind: "Subscribe".to_string(), value: format!("{:?}", v) }
             }
+            OkexWsMessage::Event(v) => {
+                NormalizedWsDataTypes::Other { exchange: CexExchange::Okex, kind: "Event".to_string(), value: format!("{:?}", v) }
+            }
             OkexWsMessage::Error { error, raw_msg, bad_pair } => {
                 if let Some(bp) = bad_pair {
                     NormalizedWsDataTypes::RemovedPair {
@@ -96,7 +151,19 @@ impl PartialEq<NormalizedWsDataTypes> for OkexWsMessage {
         match (self, other) {
             (OkexWsMessage::TradesAll(this), NormalizedWsDataTypes::Trade(that)) => this == that,
             (OkexWsMessage::Tickers(this), NormalizedWsDataTypes::Quote(that)) => this == that,
+            (OkexWsMessage::Books(this), NormalizedWsDataTypes::OrderBook(that)) => this == that,
+            (OkexWsMessage::Candle(this), NormalizedWsDataTypes::Candles(that)) => this == that,
+            (OkexWsMessage::Account(this), NormalizedWsDataTypes::BalanceUpdates(that)) => {
+                this.iter()
+                    .flat_map(|a| a.clone().normalize())
+                    .collect::<Vec<_>>()
+                    == *that
+            }
+            (OkexWsMessage::Orders(this), NormalizedWsDataTypes::OrderUpdates(that)) => {
+                this.len() == that.len() && this.iter().zip(that.iter()).all(|(a, b)| a == b)
+            }
             (OkexWsMessage::Subscribe(_), NormalizedWsDataTypes::Other { .. }) => true,
+            (OkexWsMessage::Event(_), NormalizedWsDataTypes::Other { .. }) => true,
             (OkexWsMessage::Error { .. }, NormalizedWsDataTypes::Other { .. }) => true,
             (OkexWsMessage::Error { .. }, NormalizedWsDataTypes::RemovedPair { .. }) => true,
             _ => false
@@ -114,4 +181,19 @@ impl CriticalWsMessage for OkexWsMessage {
             *raw_msg = msg;
         }
     }
+
+    fn sequence(&self) -> Option<WsSequence> {
+        if let OkexWsMessage::Books(book) = self {
+            let seq_id = book.data.seq_id.and_then(|s| u64::try_from(s).ok());
+            let kind = if &book.action == "snapshot" {
+                SequenceKind::Snapshot(seq_id)
+            } else {
+                SequenceKind::Linked { prev: book.data.prev_seq_id.and_then(|s| u64::try_from(s).ok())?, id: seq_id? }
+            };
+
+            return Some(WsSequence { channel: "books", pair: book.pair.normalize(), kind, resync: true })
+        }
+
+        None
+    }
 }
@@ -3,7 +3,9 @@ use std::collections::HashSet;
 use serde::Serialize;
 
 use super::channels::OkexWsChannel;
-use crate::okex::OkexTradingPair;
+use crate::{clients::ExchangeCredentials, okex::OkexTradingPair};
+
+const PRIVATE_CHANNELS: [&str; 2] = ["account", "orders"];
 
 #[derive(Debug, Clone, Serialize)]
 pub struct OkexSubscription {
@@ -13,11 +15,25 @@ pub struct OkexSubscription {
 
 impl OkexSubscription {
     pub(crate) fn needs_business_ws(&self) -> bool {
-        self.args.iter().any(|arg| arg.channel == "trades-all")
+        self.args
+            .iter()
+            .any(|arg| arg.channel == "trades-all" || arg.channel.starts_with("candle"))
+    }
+
+    pub(crate) fn has_private_channels(&self) -> bool {
+        self.args
+            .iter()
+            .any(|arg| PRIVATE_CHANNELS.contains(&arg.channel.as_str()))
+    }
+
+    pub(crate) fn has_public_channels(&self) -> bool {
+        self.args
+            .iter()
+            .any(|arg| !PRIVATE_CHANNELS.contains(&arg.channel.as_str()))
     }
 
     pub fn remove_pair(&mut self, pair: &OkexTradingPair) -> bool {
-        self.args.retain(|p| &p.trading_pair != pair);
+        self.args.retain(|p| p.trading_pair.as_ref() != Some(pair));
 
         self.args.is_empty()
     }
@@ -42,19 +58,73 @@ impl OkexSubscription {
         let new: Vec<_> = channel.into();
         self.args.extend(new);
     }
+
+    /// adds the channels of the channel, returning the request to subscribe to
+    /// the new ones
+    pub(crate) fn subscribe(&mut self, channel: OkexWsChannel) -> Option<Self> {
+        let new = Vec::<OkexSubscriptionInner>::from(channel)
+            .into_iter()
+            .filter(|p| !self.args.contains(p))
+            .collect::<Vec<_>>();
+        self.args.extend(new.clone());
+
+        (!new.is_empty()).then(|| OkexSubscription { op: "subscribe".to_string(), args: new })
+    }
+
+    /// removes the channels of the channel, returning the request to
+    /// unsubscribe from the ones that were subscribed to
+    pub(crate) fn unsubscribe(&mut self, channel: OkexWsChannel) -> Option<Self> {
+        let removed = Vec::<OkexSubscriptionInner>::from(channel)
+            .into_iter()
+            .filter(|p| self.args.contains(p))
+            .collect::<Vec<_>>();
+        self.args.retain(|p| !removed.contains(p));
+
+        (!removed.is_empty()).then(|| OkexSubscription { op: "unsubscribe".to_string(), args: removed })
+    }
+
+    /// the requests to unsubscribe from & resubscribe to the subscribed
+    /// channels of the channel, so the exchange pushes new snapshots
+    pub(crate) fn resubscribe(&self, channel: OkexWsChannel) -> Option<[Self; 2]> {
+        let args = Vec::<OkexSubscriptionInner>::from(channel)
+            .into_iter()
+            .filter(|p| self.args.contains(p))
+            .collect::<Vec<_>>();
+
+        (!args.is_empty()).then(|| {
+            [
+                OkexSubscription { op: "unsubscribe".to_string(), args: args.clone() },
+                OkexSubscription { op: "subscribe".to_string(), args }
+            ]
+        })
+    }
+
+    /// the request to unsubscribe from every channel
+    pub(crate) fn unsubscribe_all(&self) -> Option<Self> {
+        (!self.args.is_empty()).then(|| OkexSubscription { op: "unsubscribe".to_string(), args: self.args.clone() })
+    }
 }
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
 struct OkexSubscriptionInner {
     channel:      String,
-    #[serde(rename = "instId")]
-    trading_pair: OkexTradingPair
+    #[serde(rename = "instId", skip_serializing_if = "Option::is_none")]
+    trading_pair: Option<OkexTradingPair>,
+    #[serde(rename = "instType", skip_serializing_if = "Option::is_none")]
+    inst_type:    Option<String>
 }
 
 impl From<OkexWsChannel> for Vec<OkexSubscriptionInner> {
     fn from(val: OkexWsChannel) -> Self {
         let name = val.to_string();
 
+        if val == OkexWsChannel::Account {
+            return vec![
+                OkexSubscriptionInner { channel: "account".to_string(), trading_pair: None, inst_type: None },
+                OkexSubscriptionInner { channel: "orders".to_string(), trading_pair: None, inst_type: Some("SPOT".to_string()) },
+            ]
+        }
+
         let all_pairs: Vec<_> = match val {
             OkexWsChannel::TradesAll(pairs) => pairs
                 .into_iter()
@@ -65,12 +135,53 @@ impl From<OkexWsChannel> for Vec<OkexSubscriptionInner> {
                 .into_iter()
                 .collect::<HashSet<_>>()
                 .into_iter()
-                .collect()
+                .collect(),
+            OkexWsChannel::Books(pairs) => pairs
+                .into_iter()
+                .collect::<HashSet<_>>()
+                .into_iter()
+                .collect(),
+            OkexWsChannel::Candle { pairs, .. } => pairs
+                .into_iter()
+                .collect::<HashSet<_>>()
+                .into_iter()
+                .collect(),
+            OkexWsChannel::Account => Vec::new()
         };
 
         all_pairs
             .into_iter()
-            .map(|p| OkexSubscriptionInner { channel: name.clone(), trading_pair: p })
+            .map(|p| OkexSubscriptionInner { channel: name.clone(), trading_pair: Some(p), inst_type: None })
             .collect()
     }
 }
+
+/// login request of the private ws, the signature is over
+/// `timestamp + GET + /users/self/verify`
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct OkexLogin {
+    op:   String,
+    args: Vec<OkexLoginArgs>
+}
+
+impl OkexLogin {
+    pub(crate) fn new(credentials: &ExchangeCredentials, timestamp: i64) -> eyre::Result<Self> {
+        let args = OkexLoginArgs {
+            api_key:    credentials.api_key.clone(),
+            passphrase: credentials.passphrase()?.to_string(),
+            timestamp:  timestamp.to_string(),
+            sign:       credentials.sign_base64(&format!("{timestamp}GET/users/self/verify"))?
+        };
+
+        Ok(Self { op: "login".to_string(), args: vec![args] })
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+struct OkexLoginArgs {
+    #[serde(rename = "apiKey")]
+    api_key:    String,
+    passphrase: String,
+    timestamp:  String,
+    sign:       String
+}
@@ -1,5 +1,23 @@
+#[cfg(not(any(
+    feature = "coinbase",
+    feature = "okex",
+    feature = "kraken",
+    feature = "bitstamp",
+    feature = "binance",
+    feature = "kucoin",
+    feature = "bybit",
+    feature = "gateio",
+    feature = "deribit",
+    feature = "bitget",
+    feature = "mexc"
+)))]
+compile_error!("at least one exchange feature (ex: `binance`) must be enabled");
+
 mod exchanges;
 pub use exchanges::*;
 
 pub mod clients;
 pub mod tracing;
+
+#[cfg(feature = "testing")]
+pub mod testing;
@@ -0,0 +1,5 @@
+//! an in-process exchange to run the clients against without connecting to
+//! the live exchanges
+
+mod server;
+pub use server::*;
@@ -0,0 +1,313 @@
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    path::Path,
+    sync::{Arc, Mutex},
+    time::Duration
+};
+
+use futures::{SinkExt, StreamExt};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+    task::JoinHandle
+};
+use tokio_tungstenite::tungstenite::Message;
+use tracing::{debug, warn};
+
+use crate::{
+    clients::{clear_rate_limits, set_rate_limit, ws::RecordedFrame, ExchangeEndpoints, RateLimit, RateLimitKind},
+    CexExchange
+};
+
+/// how long a ws connection waits for the client's first message before
+/// replaying the frames anyway
+const SUBSCRIPTION_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// builds a [MockExchangeServer]
+#[derive(Debug, Default, Clone)]
+pub struct MockExchangeServerBuilder {
+    ws_frames:      Vec<Message>,
+    frame_interval: Option<Duration>,
+    /// path (with or without the query) -> json body
+    rest_responses: HashMap<String, String>
+}
+
+impl MockExchangeServerBuilder {
+    /// frames sent (in order) to every ws connection
+    pub fn with_ws_frames(mut self, frames: Vec<String>) -> Self {
+        self.ws_frames = frames.into_iter().map(Message::Text).collect();
+        self
+    }
+
+    pub fn add_ws_frame(mut self, frame: impl ToString) -> Self {
+        self.ws_frames.push(Message::Text(frame.to_string()));
+        self
+    }
+
+    /// a binary (ex: protobuf) frame
+    pub fn add_ws_binary_frame(mut self, frame: impl Into<Vec<u8>>) -> Self {
+        self.ws_frames.push(Message::Binary(frame.into()));
+        self
+    }
+
+    /// a raw frame (ex: a protocol pong)
+    pub fn add_ws_message(mut self, frame: Message) -> Self {
+        self.ws_frames.push(frame);
+        self
+    }
+
+    /// frames recorded by a [crate::clients::ws::FrameRecorder]
+    pub fn with_recorded_frames(mut self, frames: impl IntoIterator<Item = RecordedFrame>) -> Self {
+        self.ws_frames.extend(
+            frames
+                .into_iter()
+                .map(|recorded| recorded.frame.into())
+        );
+        self
+    }
+
+    /// reads the frames from a file with a frame on each line
+    pub fn with_ws_frames_from_file(mut self, path: impl AsRef<Path>) -> std::io::Result<Self> {
+        let frames = std::fs::read_to_string(path)?;
+        self.ws_frames.extend(
+            frames
+                .lines()
+                .map(str::trim)
+                .filter(|line| !line.is_empty())
+                .map(|line| Message::Text(line.to_string()))
+        );
+        Ok(self)
+    }
+
+    /// waits between each frame sent, they're sent as fast as possible
+    /// otherwise
+    pub fn with_frame_interval(mut self, interval: Duration) -> Self {
+        self.frame_interval = Some(interval);
+        self
+    }
+
+    /// serves `body` for requests to `path`, a path with a query only
+    /// matches requests with the exact same query
+    pub fn with_rest_response(mut self, path: impl ToString, body: impl ToString) -> Self {
+        self.rest_responses
+            .insert(path.to_string(), body.to_string());
+        self
+    }
+
+    /// binds the ws & rest servers to random local ports
+    pub async fn spawn(mut self) -> std::io::Result<MockExchangeServer> {
+        let ws_listener = TcpListener::bind("127.0.0.1:0").await?;
+        let rest_listener = TcpListener::bind("127.0.0.1:0").await?;
+        let ws_addr = ws_listener.local_addr()?;
+        let rest_addr = rest_listener.local_addr()?;
+
+        // kucoin gets the ws endpoint from the rest api
+        let bullet = kucoin_bullet_response(&format!("ws://{ws_addr}/"));
+        for path in ["/api/v1/bullet-public", "/api/v1/bullet-private"] {
+            self.rest_responses
+                .entry(path.to_string())
+                .or_insert_with(|| bullet.clone());
+        }
+
+        let received_requests = Arc::new(Mutex::new(Vec::new()));
+        let received_ws_messages = Arc::new(Mutex::new(Vec::new()));
+
+        let ws_task = tokio::spawn(run_ws_server(ws_listener, Arc::new(self.ws_frames), self.frame_interval, received_ws_messages.clone()));
+        let rest_task = tokio::spawn(run_rest_server(rest_listener, Arc::new(self.rest_responses), received_requests.clone()));
+
+        Ok(MockExchangeServer {
+            ws_addr,
+            rest_addr,
+            received_requests,
+            received_ws_messages,
+            mocked_exchanges: Vec::new(),
+            tasks: vec![ws_task, rest_task]
+        })
+    }
+}
+
+/// an in-process exchange that replays ws frames & serves canned rest
+/// responses, the servers are stopped when it's dropped
+#[derive(Debug)]
+pub struct MockExchangeServer {
+    ws_addr:              SocketAddr,
+    rest_addr:            SocketAddr,
+    received_requests:    Arc<Mutex<Vec<String>>>,
+    received_ws_messages: Arc<Mutex<Vec<String>>>,
+    mocked_exchanges:     Vec<CexExchange>,
+    tasks:                Vec<JoinHandle<()>>
+}
+
+impl MockExchangeServer {
+    pub fn builder() -> MockExchangeServerBuilder {
+        MockExchangeServerBuilder::default()
+    }
+
+    pub fn ws_url(&self) -> String {
+        format!("ws://{}", self.ws_addr)
+    }
+
+    pub fn rest_url(&self) -> String {
+        format!("http://{}", self.rest_addr)
+    }
+
+    /// lifts the rate limits of `exchange` until the server is dropped, the
+    /// clients are pointed at the server with [Self::endpoints]
+    pub fn mock_exchange(&mut self, exchange: CexExchange) {
+        [RateLimitKind::Connection, RateLimitKind::Subscribe, RateLimitKind::Rest]
+            .into_iter()
+            .for_each(|kind| set_rate_limit(exchange, kind, RateLimit::unlimited()));
+        self.mocked_exchanges.push(exchange);
+    }
+
+    /// endpoints pointing every url at this server
+    pub fn endpoints(&self) -> ExchangeEndpoints {
+        ExchangeEndpoints::new()
+            .with_rest(self.rest_url())
+            .with_web(self.rest_url())
+            .with_ws(self.ws_url())
+            .with_private_ws(self.ws_url())
+            .with_business_ws(self.ws_url())
+    }
+
+    /// `METHOD path` of every rest request received
+    pub fn received_requests(&self) -> Vec<String> {
+        self.received_requests.lock().unwrap().clone()
+    }
+
+    /// text messages (i.e. subscriptions) received on every ws connection
+    pub fn received_ws_messages(&self) -> Vec<String> {
+        self.received_ws_messages.lock().unwrap().clone()
+    }
+}
+
+impl Drop for MockExchangeServer {
+    fn drop(&mut self) {
+        self.tasks.iter().for_each(|task| task.abort());
+        self.mocked_exchanges
+            .iter()
+            .for_each(|exchange| clear_rate_limits(*exchange));
+    }
+}
+
+async fn run_ws_server(listener: TcpListener, frames: Arc<Vec<Message>>, frame_interval: Option<Duration>, received: Arc<Mutex<Vec<String>>>) {
+    while let Ok((stream, addr)) = listener.accept().await {
+        debug!(target: "cex-exchanges::testing", "ws connection from {addr}");
+        tokio::spawn(handle_ws_connection(stream, frames.clone(), frame_interval, received.clone()));
+    }
+}
+
+async fn handle_ws_connection(stream: TcpStream, frames: Arc<Vec<Message>>, frame_interval: Option<Duration>, received: Arc<Mutex<Vec<String>>>) {
+    let ws = match tokio_tungstenite::accept_async(stream).await {
+        Ok(ws) => ws,
+        Err(e) => {
+            warn!(target: "cex-exchanges::testing", "failed ws handshake -- {:?}", e);
+            return
+        }
+    };
+    let (mut sink, mut stream) = ws.split();
+
+    // the exchanges only start streaming once they're subscribed to
+    if let Ok(Some(Ok(Message::Text(msg)))) = tokio::time::timeout(SUBSCRIPTION_TIMEOUT, stream.next()).await {
+        received.lock().unwrap().push(msg);
+    }
+
+    let reader = tokio::spawn(async move {
+        while let Some(Ok(msg)) = stream.next().await {
+            if let Message::Text(msg) = msg {
+                received.lock().unwrap().push(msg);
+            }
+        }
+    });
+
+    for frame in frames.iter() {
+        if let Some(interval) = frame_interval {
+            tokio::time::sleep(interval).await;
+        }
+
+        if sink.send(frame.clone()).await.is_err() {
+            break
+        }
+    }
+
+    // keeps the connection open (without sending anything else) until the
+    // client disconnects
+    let _ = reader.await;
+}
+
+async fn run_rest_server(listener: TcpListener, responses: Arc<HashMap<String, String>>, received: Arc<Mutex<Vec<String>>>) {
+    while let Ok((stream, _)) = listener.accept().await {
+        tokio::spawn(handle_rest_connection(stream, responses.clone(), received.clone()));
+    }
+}
+
+/// a single http/1.1 request per connection, the body of the request is
+/// read but ignored
+async fn handle_rest_connection(mut stream: TcpStream, responses: Arc<HashMap<String, String>>, received: Arc<Mutex<Vec<String>>>) {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+
+    let header_end = loop {
+        match stream.read(&mut chunk).await {
+            Ok(0) | Err(_) => return,
+            Ok(n) => buf.extend_from_slice(&chunk[..n])
+        }
+
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buf[..header_end]).to_string();
+    let content_length = head
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("content-length"))
+        .and_then(|(_, len)| len.trim().parse::<usize>().ok())
+        .unwrap_or_default();
+    while buf.len() < header_end + content_length {
+        match stream.read(&mut chunk).await {
+            Ok(0) | Err(_) => break,
+            Ok(n) => buf.extend_from_slice(&chunk[..n])
+        }
+    }
+
+    let mut request_line = head.lines().next().unwrap_or_default().split_whitespace();
+    let method = request_line.next().unwrap_or_default();
+    let target = request_line.next().unwrap_or_default();
+    received.lock().unwrap().push(format!("{method} {target}"));
+
+    let path = target.split('?').next().unwrap_or_default();
+    let response = match responses.get(target).or_else(|| responses.get(path)) {
+        Some(body) => http_response("200 OK", body),
+        None => {
+            warn!(target: "cex-exchanges::testing", "no mock response for {method} {target}");
+            http_response("404 Not Found", &format!("{{\"error\":\"no mock response for {path}\"}}"))
+        }
+    };
+
+    let _ = stream.write_all(response.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
+
+fn http_response(status: &str, body: &str) -> String {
+    format!("HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}", body.len())
+}
+
+fn kucoin_bullet_response(ws_url: &str) -> String {
+    serde_json::json!({
+        "code": "200000",
+        "data": {
+            "token": "mock-token",
+            "instanceServers": [{
+                "endpoint": ws_url,
+                "encrypt": false,
+                "protocol": "websocket",
+                "pingInterval": 18000,
+                "pingTimeout": 10000
+            }]
+        }
+    })
+    .to_string()
+}
@@ -0,0 +1,1578 @@
+mod utils;
+#[allow(unused_imports)]
+use utils::*;
+
+#[cfg(feature = "binance")]
+#[cfg(test)]
+mod binance_tests {
+    use std::{
+        sync::{Arc, Mutex},
+        time::Duration
+    };
+
+    use cex_exchanges::{
+        binance::{
+            ws::{channels::BinanceWsChannel, BinanceWsBuilder},
+            Binance
+        },
+        clients::{
+            rate_limiter,
+            rest_api::ExchangeApi,
+            set_rate_limit,
+            ws::{
+                clear_metrics_recorder, set_metrics_recorder, FrameRecorder, OverflowPolicy, ReconnectPolicy, RecordedFrame, ReplayStream, WsMetrics,
+                WsStream
+            },
+            ExchangeEndpoints, RateLimit, RateLimitKind
+        },
+        normalized::{
+            types::{NormalizedNumber, RawTradingPair},
+            ws::{CombinedWsMessage, NormalizedWsChannelKinds, NormalizedWsDataTypes}
+        },
+        testing::{MockExchangeServer, MockExchangeServerBuilder},
+        CexExchange, Exchange
+    };
+    use futures::StreamExt;
+    use serial_test::serial;
+    use tokio_tungstenite::tungstenite::Message;
+
+    use super::*;
+
+    fn trade_frames() -> MockExchangeServerBuilder {
+        let mut builder = MockExchangeServer::builder().add_ws_frame(r#"{"result":null,"id":1}"#);
+        for i in 0..10u64 {
+            let pair = if i % 2 == 0 { "ETHUSDT" } else { "BTCUSDC" };
+            builder = builder.add_ws_frame(format!(
+                r#"{{"stream":"{}@trade","data":{{"e":"trade","E":{},"s":"{pair}","t":{},"p":"3000.{i}","q":"0.5","b":{},"a":{},"T":{},"m":{},"M":true}}}}"#,
+                pair.to_lowercase(),
+                1717000000000 + i,
+                100 + i,
+                200 + i,
+                300 + i,
+                1717000000000 + i,
+                i % 3 == 0
+            ));
+        }
+        builder
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_trade() {
+        init_test_tracing();
+        let mut server = trade_frames().spawn().await.unwrap();
+        server.mock_exchange(CexExchange::Binance);
+
+        let builder = BinanceWsBuilder::default().add_channel(
+            BinanceWsChannel::new_trade(vec![RawTradingPair::new_raw("ETH_USDT", '_'), RawTradingPair::new_raw("BTC_USDC", '_')]).unwrap()
+        );
+        stream_util(builder.build_single().with_endpoints(server.endpoints()), 10).await;
+
+        assert!(server
+            .received_ws_messages()
+            .iter()
+            .any(|msg| msg.contains("SUBSCRIBE") && msg.contains("ethusdt@trade")));
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_trade_timestamps() {
+        init_test_tracing();
+        let mut server = trade_frames().spawn().await.unwrap();
+        server.mock_exchange(CexExchange::Binance);
+
+        let builder = BinanceWsBuilder::default().add_channel(BinanceWsChannel::new_trade(vec![RawTradingPair::new_raw("ETH_USDT", '_')]).unwrap());
+        let mut stream = WsStream::new(builder.build_single().with_endpoints(server.endpoints()));
+        stream.connect().await.unwrap();
+        let connected_at = chrono::Utc::now();
+
+        // skips the subscription response
+        stream.next().await.unwrap();
+        let NormalizedWsDataTypes::Trade(trade) = stream.next().await.unwrap().normalize() else { panic!("expected a trade") };
+        assert_eq!(trade.exchange_time, chrono::DateTime::from_timestamp_millis(1717000000000));
+        assert!(trade.received_at >= connected_at && trade.received_at <= chrono::Utc::now());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_pong_ignored() {
+        init_test_tracing();
+        let mut server = MockExchangeServer::builder()
+            .add_ws_frame(r#"{"result":null,"id":1}"#)
+            .add_ws_message(Message::Pong(Vec::new()))
+            .add_ws_frame(
+                r#"{"stream":"ethusdt@trade","data":{"e":"trade","E":1717000000000,"s":"ETHUSDT","t":100,"p":"3000.0","q":"0.5","b":200,"a":300,"T":1717000000000,"m":true,"M":true}}"#
+            )
+            .spawn()
+            .await
+            .unwrap();
+        server.mock_exchange(CexExchange::Binance);
+
+        let builder = BinanceWsBuilder::default().add_channel(BinanceWsChannel::new_trade(vec![RawTradingPair::new_raw("ETH_USDT", '_')]).unwrap());
+        let mut stream = WsStream::new(builder.build_single().with_endpoints(server.endpoints()));
+        stream.connect().await.unwrap();
+
+        // the pong between the subscription response & the trade is dropped
+        assert!(stream.next().await.unwrap().is_ok());
+        let NormalizedWsDataTypes::Trade(trade) = stream.next().await.unwrap().normalize() else { panic!("expected a trade") };
+        assert_eq!(trade.price, "3000.0".parse::<NormalizedNumber>().unwrap());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_record_replay() {
+        init_test_tracing();
+        let mut server = trade_frames().spawn().await.unwrap();
+        server.mock_exchange(CexExchange::Binance);
+
+        let path = std::env::temp_dir().join(format!("cex-exchanges-record-replay-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let builder = BinanceWsBuilder::default().add_channel(
+            BinanceWsChannel::new_trade(vec![RawTradingPair::new_raw("ETH_USDT", '_'), RawTradingPair::new_raw("BTC_USDC", '_')]).unwrap()
+        );
+        let mut stream = WsStream::new(builder.build_single().with_endpoints(server.endpoints())).with_recorder(FrameRecorder::new(&path).unwrap());
+        stream.connect().await.unwrap();
+        let live = stream.by_ref().take(11).collect::<Vec<_>>().await;
+        drop(stream);
+
+        let recorded = RecordedFrame::read_file(&path).unwrap();
+        assert_eq!(recorded.len(), 11);
+        assert!(recorded
+            .iter()
+            .all(|frame| frame.exchange == CexExchange::Binance && frame.connection_id == 0));
+
+        let replayed = ReplayStream::from_file(&path)
+            .unwrap()
+            .at_original_pace()
+            .collect::<Vec<_>>()
+            .await;
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(live.len(), replayed.len());
+        live.iter()
+            .zip(replayed)
+            .for_each(|(live, replayed)| assert_eq!(*live, replayed.normalize()));
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_subscription_handle() {
+        init_test_tracing();
+        let mut server = trade_frames().spawn().await.unwrap();
+        server.mock_exchange(CexExchange::Binance);
+
+        let builder = BinanceWsBuilder::default().add_channel(BinanceWsChannel::new_trade(vec![RawTradingPair::new_raw("ETH_USDT", '_')]).unwrap());
+        let mut stream = WsStream::new(builder.build_single().with_endpoints(server.endpoints()));
+        stream.connect().await.unwrap();
+        let handle = stream.subscription_handle();
+
+        // the updates are applied while the stream is polled
+        stream.next().await.unwrap();
+        let (res, _) = tokio::join!(
+            handle.add_pairs(CexExchange::Binance, NormalizedWsChannelKinds::Trades, &[RawTradingPair::new_raw("BTC_USDC", '_')]),
+            stream.next()
+        );
+        res.unwrap();
+        let (res, _) = tokio::join!(
+            handle.remove_pairs(CexExchange::Binance, NormalizedWsChannelKinds::Trades, &[RawTradingPair::new_raw("ETH_USDT", '_')]),
+            stream.next()
+        );
+        res.unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+        let received = server.received_ws_messages();
+        assert!(received
+            .iter()
+            .any(|msg| msg.contains(r#""SUBSCRIBE""#) && msg.contains("btcusdc@trade") && !msg.contains("ethusdt@trade")));
+        assert!(received
+            .iter()
+            .any(|msg| msg.contains("UNSUBSCRIBE") && msg.contains("ethusdt@trade") && !msg.contains("btcusdc@trade")));
+
+        drop(stream);
+        assert!(handle
+            .add_pairs(CexExchange::Binance, NormalizedWsChannelKinds::Trades, &[RawTradingPair::new_raw("SOL_USDT", '_')])
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_reconnect_policy_max_attempts() {
+        init_test_tracing();
+        let mut server = MockExchangeServer::builder()
+            .add_ws_frame(r#"{"result":null,"id":1}"#)
+            .spawn()
+            .await
+            .unwrap();
+        server.mock_exchange(CexExchange::Binance);
+
+        let builder = BinanceWsBuilder::default().add_channel(BinanceWsChannel::new_trade(vec![RawTradingPair::new_raw("ETH_USDT", '_')]).unwrap());
+        let policy = ReconnectPolicy::new()
+            .with_initial_delay(Duration::from_millis(10))
+            .with_max_attempts(3);
+        let mut stream = WsStream::new(builder.build_single().with_endpoints(server.endpoints()))
+            .with_reconnect_policy(policy)
+            .with_idle_timeout(Duration::from_millis(100));
+        stream.connect().await.unwrap();
+        stream.next().await.unwrap();
+        assert_eq!(server.received_ws_messages().len(), 1);
+
+        // the silent connection is dropped & every reconnect fails
+        drop(server);
+        let messages = tokio::time::timeout(Duration::from_secs(5), stream.collect::<Vec<_>>())
+            .await
+            .unwrap();
+
+        assert_eq!(messages.len(), 4);
+        assert!(messages
+            .iter()
+            .all(|msg| matches!(msg, CombinedWsMessage::Disconnect { .. })));
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_multi_distributed() {
+        init_test_tracing();
+        let mut server = trade_frames().spawn().await.unwrap();
+        server.mock_exchange(CexExchange::Binance);
+
+        let builder = BinanceWsBuilder::default()
+            .add_channel(BinanceWsChannel::new_trade(vec![RawTradingPair::new_raw("ETH_USDT", '_')]).unwrap())
+            .add_channel(BinanceWsChannel::new_trade(vec![RawTradingPair::new_raw("BTC_USDC", '_')]).unwrap())
+            .build_many_distributed()
+            .unwrap()
+            .with_endpoints(server.endpoints());
+        mutlistream_util(builder, 20).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_shutdown() {
+        init_test_tracing();
+        let mut server = trade_frames().spawn().await.unwrap();
+        server.mock_exchange(CexExchange::Binance);
+
+        let builder = BinanceWsBuilder::default().add_channel(BinanceWsChannel::new_trade(vec![RawTradingPair::new_raw("ETH_USDT", '_')]).unwrap());
+        let mut stream = WsStream::new(builder.build_single().with_endpoints(server.endpoints()));
+        stream.connect().await.unwrap();
+        stream.next().await.unwrap();
+
+        stream.shutdown_handle().shutdown();
+        let remaining = tokio::time::timeout(Duration::from_secs(2), stream.by_ref().collect::<Vec<_>>())
+            .await
+            .unwrap();
+        assert!(remaining.iter().all(CombinedWsMessage::is_ok));
+        assert!(stream.next().await.is_none());
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert!(server
+            .received_ws_messages()
+            .iter()
+            .any(|msg| msg.contains("UNSUBSCRIBE") && msg.contains("ethusdt@trade")));
+    }
+
+    #[derive(Debug, Default)]
+    struct CountingMetrics {
+        messages:  Mutex<Vec<(CexExchange, &'static str)>>,
+        latencies: Mutex<Vec<Duration>>
+    }
+
+    impl WsMetrics for CountingMetrics {
+        fn message_received(&self, exchange: CexExchange, channel: &'static str) {
+            self.messages.lock().unwrap().push((exchange, channel));
+        }
+
+        fn latency(&self, _exchange: CexExchange, _channel: &'static str, latency: Duration) {
+            self.latencies.lock().unwrap().push(latency);
+        }
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_metrics_recorder() {
+        init_test_tracing();
+        let mut server = trade_frames().spawn().await.unwrap();
+        server.mock_exchange(CexExchange::Binance);
+
+        let metrics = Arc::new(CountingMetrics::default());
+        set_metrics_recorder(metrics.clone());
+
+        let builder = BinanceWsBuilder::default().add_channel(BinanceWsChannel::new_trade(vec![RawTradingPair::new_raw("ETH_USDT", '_')]).unwrap());
+        let mut stream = WsStream::new(builder.build_single().with_endpoints(server.endpoints()));
+        stream.connect().await.unwrap();
+        let received = stream.by_ref().take(3).collect::<Vec<_>>().await;
+        clear_metrics_recorder();
+
+        let trades = received
+            .iter()
+            .filter_map(|msg| match msg.clone().normalize() {
+                NormalizedWsDataTypes::Trade(trade) => Some(trade),
+                _ => None
+            })
+            .collect::<Vec<_>>();
+        let messages = metrics.messages.lock().unwrap();
+        assert_eq!(messages.len(), 3);
+        assert_eq!(
+            messages
+                .iter()
+                .filter(|(_, channel)| *channel == "trades")
+                .count(),
+            trades.len()
+        );
+        assert!(messages
+            .iter()
+            .all(|(exchange, _)| *exchange == CexExchange::Binance));
+
+        // measured from when the frame was received, not when it was handled
+        let latencies = trades
+            .iter()
+            .map(|trade| {
+                (trade.received_at - trade.exchange_time.unwrap())
+                    .to_std()
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(*metrics.latencies.lock().unwrap(), latencies);
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[serial]
+    async fn test_multithreaded_shutdown() {
+        init_test_tracing();
+        let mut server = trade_frames().spawn().await.unwrap();
+        server.mock_exchange(CexExchange::Binance);
+
+        let mut stream = BinanceWsBuilder::default()
+            .add_channel(BinanceWsChannel::new_trade(vec![RawTradingPair::new_raw("ETH_USDT", '_')]).unwrap())
+            .add_channel(BinanceWsChannel::new_trade(vec![RawTradingPair::new_raw("BTC_USDC", '_')]).unwrap())
+            .build_many_distributed()
+            .unwrap()
+            .with_endpoints(server.endpoints())
+            .spawn_multithreaded(2, tokio::runtime::Handle::current());
+        stream.recv().await.unwrap();
+
+        let remaining = tokio::time::timeout(Duration::from_secs(2), stream.shutdown())
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(remaining.iter().all(CombinedWsMessage::is_ok));
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    #[serial]
+    async fn test_bounded_coalesce_quotes() {
+        init_test_tracing();
+        let mut builder = MockExchangeServer::builder().add_ws_frame(r#"{"result":null,"id":1}"#);
+        for i in 0..10u64 {
+            builder = builder.add_ws_frame(format!(
+                r#"{{"stream":"ethusdt@bookTicker","data":{{"u":{},"s":"ETHUSDT","b":"3000.{i}","B":"1.0","a":"3001.{i}","A":"2.0"}}}}"#,
+                100 + i
+            ));
+        }
+        let mut server = builder.spawn().await.unwrap();
+        server.mock_exchange(CexExchange::Binance);
+
+        let mut stream = BinanceWsBuilder::default()
+            .add_channel(BinanceWsChannel::new_book_ticker(vec![RawTradingPair::new_raw("ETH_USDT", '_')]).unwrap())
+            .build_many_distributed()
+            .unwrap()
+            .with_endpoints(server.endpoints())
+            .with_bounded_channel(2, OverflowPolicy::CoalesceQuotes)
+            .spawn_multithreaded(1, tokio::runtime::Handle::current());
+
+        // lets every frame queue up behind the full channel
+        tokio::time::sleep(Duration::from_millis(500)).await;
+        // the subscription response & the first quote fill the channel, every
+        // later quote replaces the queued one
+        assert_eq!(stream.overflow_counts().coalesced, 9);
+
+        let mut quotes = Vec::new();
+        for _ in 0..2 {
+            if let NormalizedWsDataTypes::Quote(quote) = stream.recv().await.unwrap().normalize() {
+                quotes.push(quote.bid_price);
+            }
+        }
+        assert_eq!(quotes, vec!["3000.9".parse::<NormalizedNumber>().unwrap()]);
+        assert!(stream.thread_errors().is_empty());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_connection_rate_limit() {
+        init_test_tracing();
+        let mut server = trade_frames().spawn().await.unwrap();
+        server.mock_exchange(CexExchange::Binance);
+        set_rate_limit(CexExchange::Binance, RateLimitKind::Connection, RateLimit::new(1, Duration::from_millis(200)));
+
+        let builder = BinanceWsBuilder::default()
+            .add_channel(BinanceWsChannel::new_trade(vec![RawTradingPair::new_raw("ETH_USDT", '_')]).unwrap())
+            .add_channel(BinanceWsChannel::new_trade(vec![RawTradingPair::new_raw("BTC_USDC", '_')]).unwrap())
+            .add_channel(BinanceWsChannel::new_trade(vec![RawTradingPair::new_raw("SOL_USDT", '_')]).unwrap())
+            .build_many_distributed()
+            .unwrap()
+            .with_endpoints(server.endpoints());
+
+        let start = std::time::Instant::now();
+        let _stream = builder.build_multistream().await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(400));
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_subscribe_rate_limit_per_connection() {
+        init_test_tracing();
+        let mut server = trade_frames().spawn().await.unwrap();
+        server.mock_exchange(CexExchange::Binance);
+        set_rate_limit(CexExchange::Binance, RateLimitKind::Subscribe, RateLimit::new(1, Duration::from_secs(2)));
+
+        let pairs = ["ETH_USDT", "BTC_USDC", "SOL_USDT", "XRP_USDT", "ADA_USDT", "DOGE_USDT"];
+        let builder = pairs
+            .iter()
+            .fold(BinanceWsBuilder::default(), |builder, pair| {
+                builder.add_channel(BinanceWsChannel::new_trade(vec![RawTradingPair::new_raw(pair, '_')]).unwrap())
+            })
+            .build_many_distributed()
+            .unwrap()
+            .with_endpoints(server.endpoints());
+
+        // every connection subscribes with its own bucket, a shared one would
+        // take 10s to let the 6 subscriptions through
+        let start = std::time::Instant::now();
+        let _stream = builder.build_multistream().await.unwrap();
+        assert!(start.elapsed() < Duration::from_secs(2));
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(
+            server
+                .received_ws_messages()
+                .iter()
+                .filter(|msg| msg.contains("SUBSCRIBE"))
+                .count(),
+            pairs.len()
+        );
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_recent_trades() {
+        init_test_tracing();
+        let mut server = MockExchangeServer::builder()
+            .with_rest_response(
+                "/api/v3/aggTrades",
+                r#"[{"a":1,"p":"3000.1","q":"0.5","f":10,"l":11,"T":1717000000000,"m":true,"M":true},{"a":2,"p":"3000.2","q":"1.5","f":12,"l":12,"T":1717000000001,"m":false,"M":true}]"#
+            )
+            .spawn()
+            .await
+            .unwrap();
+        server.mock_exchange(CexExchange::Binance);
+
+        let pair = RawTradingPair::new_raw("ETH_USDT", '_').get_normalized_pair(CexExchange::Binance);
+        let trades = ExchangeApi::new()
+            .with_endpoints(CexExchange::Binance, server.endpoints())
+            .recent_trades::<Binance>(pair, 2)
+            .await
+            .unwrap();
+        let normalized = trades.clone().normalize();
+        assert_eq!(trades, normalized);
+
+        let all_trades = normalized.take_trades().unwrap();
+        assert_eq!(all_trades.len(), 2);
+        assert_eq!(all_trades[1].price, "3000.2".parse::<NormalizedNumber>().unwrap());
+
+        assert_eq!(server.received_requests(), vec!["GET /api/v3/aggTrades?symbol=ETHUSDT&limit=2".to_string()]);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_paged_rest_rate_limit() {
+        init_test_tracing();
+        let mut server = MockExchangeServer::builder()
+            .with_rest_response("/api/v3/aggTrades", "[]")
+            .spawn()
+            .await
+            .unwrap();
+        server.mock_exchange(CexExchange::Binance);
+        set_rate_limit(CexExchange::Binance, RateLimitKind::Rest, RateLimit::new(10, Duration::from_secs(3600)));
+
+        // an empty hour window per request
+        let pair = RawTradingPair::new_raw("ETH_USDT", '_').get_normalized_pair(CexExchange::Binance);
+        let from = chrono::DateTime::from_timestamp_millis(1717000000000).unwrap();
+        ExchangeApi::new()
+            .with_endpoints(CexExchange::Binance, server.endpoints())
+            .historical_trades::<Binance>(pair, from, from + chrono::Duration::hours(3))
+            .await
+            .unwrap();
+        assert_eq!(server.received_requests().len(), 3);
+
+        let limiter = rate_limiter(CexExchange::Binance, RateLimitKind::Rest);
+        let remaining = (0..10)
+            .take_while(|_| limiter.try_acquire().is_ok())
+            .count();
+        assert_eq!(remaining, 7);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_rest_endpoint() {
+        init_test_tracing();
+        let mut servers = Vec::new();
+        for _ in 0..2 {
+            servers.push(
+                MockExchangeServer::builder()
+                    .with_rest_response("/api/v3/aggTrades", "[]")
+                    .spawn()
+                    .await
+                    .unwrap()
+            );
+        }
+
+        // each client only calls its own endpoints
+        let pair = RawTradingPair::new_raw("ETH_USDT", '_').get_normalized_pair(CexExchange::Binance);
+        for (i, server) in servers.iter().enumerate() {
+            let exchange_api = ExchangeApi::new().with_endpoints(CexExchange::Binance, ExchangeEndpoints::new().with_rest(server.rest_url()));
+            for _ in 0..=i {
+                let trades = exchange_api
+                    .recent_trades::<Binance>(pair.clone(), 10)
+                    .await;
+                assert!(trades
+                    .unwrap()
+                    .normalize()
+                    .take_trades()
+                    .unwrap()
+                    .is_empty());
+            }
+        }
+
+        assert_eq!(servers[0].received_requests().len(), 1);
+        assert_eq!(servers[1].received_requests().len(), 2);
+    }
+}
+
+#[cfg(feature = "kucoin")]
+#[cfg(test)]
+mod kucoin_tests {
+    use cex_exchanges::{
+        kucoin::ws::{channels::KucoinWsChannel, KucoinWsBuilder},
+        normalized::types::RawTradingPair,
+        testing::MockExchangeServer,
+        CexExchange, Exchange
+    };
+    use serial_test::serial;
+
+    use super::*;
+
+    #[tokio::test]
+    #[serial]
+    async fn test_match() {
+        init_test_tracing();
+        let mut builder = MockExchangeServer::builder().add_ws_frame(r#"{"id":"1","type":"ack"}"#);
+        for i in 0..5u64 {
+            builder = builder.add_ws_frame(format!(
+                r#"{{"type":"message","topic":"/market/match:ETH-USDT","subject":"trade.l3match","data":{{"sequence":"{}","type":"match","symbol":"ETH-USDT","side":"buy","price":"3000.{i}","size":"0.1","tradeId":"{}","takerOrderId":"a","makerOrderId":"b","time":"{}"}}}}"#,
+                1000 + i,
+                2000 + i,
+                1717000000000000000u64 + i
+            ));
+        }
+        let mut server = builder.spawn().await.unwrap();
+        server.mock_exchange(CexExchange::Kucoin);
+
+        let builder = KucoinWsBuilder::default().add_channel(KucoinWsChannel::new_match(vec![RawTradingPair::new_raw("ETH_USDT", '_')]).unwrap());
+        stream_util(builder.build_single().with_endpoints(server.endpoints()), 5).await;
+
+        assert_eq!(server.received_requests(), vec!["POST /api/v1/bullet-public".to_string()]);
+    }
+}
+
+#[cfg(feature = "kraken")]
+#[cfg(test)]
+mod kraken_tests {
+    use cex_exchanges::{
+        clients::ws::WsStream,
+        kraken::ws::{channels::KrakenWsChannel, KrakenWsBuilder},
+        normalized::{
+            types::{NormalizedNumber, RawTradingPair},
+            ws::NormalizedWsDataTypes
+        },
+        testing::MockExchangeServer,
+        CexExchange, Exchange
+    };
+    use futures::StreamExt;
+    use serial_test::serial;
+
+    use super::*;
+
+    #[tokio::test]
+    #[serial]
+    async fn test_trade() {
+        init_test_tracing();
+        let mut builder = MockExchangeServer::builder().add_ws_frame(
+            r#"{"method":"subscribe","result":{"channel":"trade","snapshot":true,"symbol":"ETH/USD"},"success":true,"time_in":"2024-06-01T00:00:00.000000Z","time_out":"2024-06-01T00:00:00.000100Z"}"#
+        );
+        for i in 0..5u64 {
+            builder = builder.add_ws_frame(format!(
+                r#"{{"channel":"trade","type":"update","data":[{{"symbol":"ETH/USD","side":"{}","price":3000.{i},"qty":0.5,"ord_type":"market","trade_id":{},"timestamp":"2024-06-01T00:00:0{i}.000000Z"}}]}}"#,
+                if i % 2 == 0 { "buy" } else { "sell" },
+                100 + i
+            ));
+        }
+        let mut server = builder.spawn().await.unwrap();
+        server.mock_exchange(CexExchange::Kraken);
+
+        let builder = KrakenWsBuilder::default().add_channel(KrakenWsChannel::new_trade(vec![RawTradingPair::new_raw("ETH_USD", '_')]).unwrap());
+        stream_util(builder.build_single().with_endpoints(server.endpoints()), 5).await;
+
+        assert!(server
+            .received_ws_messages()
+            .iter()
+            .any(|msg| msg.contains(r#""method":"subscribe""#) && msg.contains(r#""channel":"trade""#) && msg.contains("ETH/USD")));
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_numeric_literal_trade() {
+        init_test_tracing();
+        let mut server = MockExchangeServer::builder()
+            .add_ws_frame(
+                r#"{"method":"subscribe","result":{"channel":"trade","snapshot":true,"symbol":"ETH/USD"},"success":true,"time_in":"2024-06-01T00:00:00.000000Z","time_out":"2024-06-01T00:00:00.000100Z"}"#
+            )
+            .add_ws_frame(
+                r#"{"channel":"trade","type":"update","data":[{"symbol":"ETH/USD","side":"buy","price":3000.10,"qty":0.123456789012345678,"ord_type":"market","trade_id":100,"timestamp":"2024-06-01T00:00:00.000000Z"}]}"#
+            )
+            .spawn()
+            .await
+            .unwrap();
+        server.mock_exchange(CexExchange::Kraken);
+
+        let builder = KrakenWsBuilder::default().add_channel(KrakenWsChannel::new_trade(vec![RawTradingPair::new_raw("ETH_USD", '_')]).unwrap());
+        let mut stream = WsStream::new(builder.build_single().with_endpoints(server.endpoints()));
+        stream.connect().await.unwrap();
+
+        // skips the subscription response
+        stream.next().await.unwrap();
+        let NormalizedWsDataTypes::Trades(trades) = stream.next().await.unwrap().normalize() else { panic!("expected trades") };
+        assert_eq!(trades[0].price, "3000.10".parse::<NormalizedNumber>().unwrap());
+        assert_eq!(trades[0].amount, "0.123456789012345678".parse::<NormalizedNumber>().unwrap());
+
+        // the json numbers are parsed from their text, not through a float
+        #[cfg(feature = "decimal")]
+        {
+            assert_eq!(trades[0].price.to_string(), "3000.10");
+            assert_eq!(trades[0].amount.to_string(), "0.123456789012345678");
+        }
+    }
+}
+
+#[cfg(feature = "bitstamp")]
+#[cfg(test)]
+mod bitstamp_tests {
+    use cex_exchanges::{
+        bitstamp::ws::{channels::BitstampWsChannel, BitstampWsBuilder},
+        normalized::types::RawTradingPair,
+        testing::MockExchangeServer,
+        CexExchange, Exchange
+    };
+    use serial_test::serial;
+
+    use super::*;
+
+    #[tokio::test]
+    #[serial]
+    async fn test_live_trades_reconnect() {
+        init_test_tracing();
+        let mut builder =
+            MockExchangeServer::builder().add_ws_frame(r#"{"event":"bts:subscription_succeeded","channel":"live_trades_ethusd","data":{}}"#);
+        for i in 0..2u64 {
+            builder = builder.add_ws_frame(format!(
+                r#"{{"event":"trade","channel":"live_trades_ethusd","data":{{"id":{},"timestamp":"1717000000","amount":0.5,"amount_str":"0.5","price":3000.{i},"price_str":"3000.{i}","type":{},"microtimestamp":"171700000000000{i}","buy_order_id":1,"sell_order_id":2}}}}"#,
+                100 + i,
+                i % 2
+            ));
+        }
+        // every (re)connection replays the same frames, so the second subscription is
+        // only sent if the stream reconnected on the request
+        builder = builder.add_ws_frame(r#"{"event":"bts:request_reconnect","channel":"","data":""}"#);
+        let mut server = builder.spawn().await.unwrap();
+        server.mock_exchange(CexExchange::Bitstamp);
+
+        let builder =
+            BitstampWsBuilder::default().add_channel(BitstampWsChannel::new_live_trades(vec![RawTradingPair::new_raw("ETH_USD", '_')]).unwrap());
+        stream_util(builder.build_single().with_endpoints(server.endpoints()), 6).await;
+
+        let subscriptions = server
+            .received_ws_messages()
+            .into_iter()
+            .filter(|msg| msg.contains(r#""event":"bts:subscribe""#) && msg.contains("live_trades_ethusd"))
+            .count();
+        assert_eq!(subscriptions, 2);
+    }
+}
+
+#[cfg(feature = "gateio")]
+#[cfg(test)]
+mod gateio_tests {
+    use cex_exchanges::{
+        clients::rest_api::ExchangeApi,
+        gateio::{
+            ws::{channels::GateioWsChannel, GateioWsBuilder},
+            Gateio
+        },
+        normalized::types::RawTradingPair,
+        testing::MockExchangeServer,
+        CexExchange, Exchange
+    };
+    use serial_test::serial;
+
+    use super::*;
+
+    #[tokio::test]
+    #[serial]
+    async fn test_trades() {
+        init_test_tracing();
+        let mut builder = MockExchangeServer::builder()
+            .add_ws_frame(r#"{"time":1717000000,"time_ms":1717000000000,"channel":"spot.trades","event":"subscribe","result":{"status":"success"}}"#);
+        for i in 0..5u64 {
+            builder = builder.add_ws_frame(format!(
+                r#"{{"time":1717000000,"time_ms":{},"channel":"spot.trades","event":"update","result":{{"id":{},"create_time":1717000000,"create_time_ms":"{}.1234","side":"sell","currency_pair":"ETH_USDT","amount":"0.5","price":"3000.{i}","range":"1-1"}}}}"#,
+                1717000000000u64 + i,
+                100 + i,
+                1717000000000u64 + i
+            ));
+        }
+        let mut server = builder.spawn().await.unwrap();
+        server.mock_exchange(CexExchange::Gateio);
+
+        let builder = GateioWsBuilder::default().add_channel(GateioWsChannel::new_trades(vec![RawTradingPair::new_raw("ETH_USDT", '_')]).unwrap());
+        stream_util(builder.build_single().with_endpoints(server.endpoints()), 5).await;
+
+        assert!(server
+            .received_ws_messages()
+            .iter()
+            .any(|msg| msg.contains(r#""channel":"spot.trades""#) && msg.contains(r#""event":"subscribe""#) && msg.contains("ETH_USDT")));
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_all_instruments() {
+        init_test_tracing();
+        let mut server = MockExchangeServer::builder()
+            .with_rest_response(
+                "/spot/currency_pairs",
+                r#"[{"id":"ETH_USDT","base":"ETH","base_name":"Ethereum","quote":"USDT","quote_name":"Tether","fee":"0.2","min_base_amount":"0.001","min_quote_amount":"1.0","amount_precision":4,"precision":2,"trade_status":"tradable","sell_start":1516378650,"buy_start":1516378650,"type":"normal"},{"id":"OLD_USDT","base":"OLD","quote":"USDT","fee":"0.2","amount_precision":2,"precision":6,"trade_status":"untradable","sell_start":0,"buy_start":0}]"#
+            )
+            .spawn()
+            .await
+            .unwrap();
+        server.mock_exchange(CexExchange::Gateio);
+
+        let instruments = ExchangeApi::new()
+            .with_endpoints(CexExchange::Gateio, server.endpoints())
+            .all_instruments::<Gateio>()
+            .await
+            .unwrap();
+        let normalized = instruments.clone().normalize();
+        assert_eq!(instruments, normalized);
+
+        let active = instruments.take_gateio_instruments(true).unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].id.normalize(), RawTradingPair::new_raw("ETH_USDT", '_').get_normalized_pair(CexExchange::Gateio));
+
+        assert_eq!(server.received_requests(), vec!["GET /spot/currency_pairs".to_string()]);
+    }
+}
+
+#[cfg(feature = "okex")]
+#[cfg(test)]
+mod okex_tests {
+    use std::time::Duration;
+
+    use cex_exchanges::{
+        clients::ws::{FrameRecorder, RecordedFrame, ReplayStream, WsStream},
+        normalized::{
+            types::RawTradingPair,
+            ws::{NormalizedWsChannelKinds, NormalizedWsDataTypes}
+        },
+        okex::ws::{channels::OkexWsChannel, OkexWsBuilder},
+        testing::MockExchangeServer,
+        CexExchange, Exchange
+    };
+    use futures::StreamExt;
+    use serial_test::serial;
+
+    use super::*;
+
+    fn book_frame(action: &str, prev: i64, id: i64) -> String {
+        format!(
+            r#"{{"arg":{{"channel":"books","instId":"BTC-USDT"}},"action":"{action}","data":[{{"asks":[["67001.0","0.5","0","1"]],"bids":[["67000.0","1.0","0","2"]],"ts":"{}","checksum":0,"prevSeqId":{prev},"seqId":{id}}}]}}"#,
+            1717000000000i64 + id
+        )
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_book_resubscribe() {
+        init_test_tracing();
+        let mut server = MockExchangeServer::builder()
+            .add_ws_frame(r#"{"event":"subscribe","arg":{"channel":"books","instId":"BTC-USDT"},"connId":"1"}"#)
+            .add_ws_frame(book_frame("snapshot", -1, 10))
+            .add_ws_frame(book_frame("update", 10, 11))
+            // update 12 was dropped
+            .add_ws_frame(book_frame("update", 12, 13))
+            .add_ws_frame(book_frame("update", 13, 14))
+            // the snapshot pushed after resubscribing
+            .add_ws_frame(r#"{"event":"unsubscribe","arg":{"channel":"books","instId":"BTC-USDT"},"connId":"1"}"#)
+            .add_ws_frame(r#"{"event":"subscribe","arg":{"channel":"books","instId":"BTC-USDT"},"connId":"1"}"#)
+            .add_ws_frame(book_frame("snapshot", -1, 20))
+            .add_ws_frame(book_frame("update", 20, 21))
+            .with_frame_interval(Duration::from_millis(50))
+            .spawn()
+            .await
+            .unwrap();
+        server.mock_exchange(CexExchange::Okex);
+
+        let builder = OkexWsBuilder::new(None).add_channel(OkexWsChannel::new_books(vec![RawTradingPair::new_raw("BTC_USDT", '_')]).unwrap());
+        let mut stream = WsStream::new(builder.build_single().with_endpoints(server.endpoints()));
+        stream.connect().await.unwrap();
+
+        let mut updates = Vec::new();
+        let mut gaps = Vec::new();
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while let Some(msg) = stream.next().await {
+                match msg.normalize() {
+                    NormalizedWsDataTypes::OrderBook(update) => {
+                        let done = update.last_update_id == Some(21);
+                        updates.push((update.is_snapshot(), update.last_update_id));
+                        if done {
+                            break
+                        }
+                    }
+                    NormalizedWsDataTypes::SequenceGap { expected, received, .. } => gaps.push((expected, received)),
+                    _ => ()
+                }
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(gaps, vec![(11, 12)]);
+        assert_eq!(updates, vec![(true, Some(10)), (false, Some(11)), (true, Some(20)), (false, Some(21))]);
+
+        let resubscribe = server
+            .received_ws_messages()
+            .into_iter()
+            .skip(1)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            resubscribe,
+            vec![
+                r#"{"op":"unsubscribe","args":[{"channel":"books","instId":"BTC-USDT"}]}"#.to_string(),
+                r#"{"op":"subscribe","args":[{"channel":"books","instId":"BTC-USDT"}]}"#.to_string()
+            ]
+        );
+        assert!(server.received_requests().is_empty());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_record_replay_skips_pong() {
+        init_test_tracing();
+        let mut server = MockExchangeServer::builder()
+            .add_ws_frame(r#"{"event":"subscribe","arg":{"channel":"books","instId":"BTC-USDT"},"connId":"1"}"#)
+            .add_ws_frame("pong")
+            .add_ws_frame(book_frame("snapshot", -1, 10))
+            .spawn()
+            .await
+            .unwrap();
+        server.mock_exchange(CexExchange::Okex);
+
+        let path = std::env::temp_dir().join(format!("cex-exchanges-record-pong-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let builder = OkexWsBuilder::new(None).add_channel(OkexWsChannel::new_books(vec![RawTradingPair::new_raw("BTC_USDT", '_')]).unwrap());
+        let mut stream = WsStream::new(builder.build_single().with_endpoints(server.endpoints())).with_recorder(FrameRecorder::new(&path).unwrap());
+        stream.connect().await.unwrap();
+        let live = stream.by_ref().take(2).collect::<Vec<_>>().await;
+        drop(stream);
+
+        let recorded = RecordedFrame::read_file(&path).unwrap();
+        assert_eq!(recorded.len(), 2);
+
+        let replayed = ReplayStream::from_file(&path)
+            .unwrap()
+            .collect::<Vec<_>>()
+            .await;
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(replayed[1].clone().normalize(), NormalizedWsDataTypes::OrderBook(_)));
+        assert_eq!(live.len(), replayed.len());
+        live.iter()
+            .zip(replayed)
+            .for_each(|(live, replayed)| assert_eq!(*live, replayed.normalize()));
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_subscription_routing() {
+        init_test_tracing();
+        let mut server = MockExchangeServer::builder()
+            .add_ws_frame(r#"{"event":"subscribe","arg":{"channel":"books","instId":"BTC-USDT"},"connId":"1"}"#)
+            .spawn()
+            .await
+            .unwrap();
+        server.mock_exchange(CexExchange::Okex);
+
+        // the trades are on the business ws & the books on the public ws
+        let mut stream = OkexWsBuilder::new(None)
+            .add_channel(OkexWsChannel::new_trade(vec![RawTradingPair::new_raw("BTC_USDT", '_')]).unwrap())
+            .add_channel(OkexWsChannel::new_books(vec![RawTradingPair::new_raw("BTC_USDT", '_')]).unwrap())
+            .build_many_distributed()
+            .unwrap()
+            .with_endpoints(server.endpoints())
+            .build_multistream()
+            .await
+            .unwrap();
+        assert_eq!(stream.stream_count(), 2);
+        let handle = stream.subscription_handle();
+        tokio::spawn(async move { while stream.next().await.is_some() {} });
+
+        // every new book pair goes to the public ws, whichever stream is next
+        for pair in ["ETH_USDT", "SOL_USDT"] {
+            handle
+                .add_pairs(CexExchange::Okex, NormalizedWsChannelKinds::OrderBook, &[RawTradingPair::new_raw(pair, '_')])
+                .await
+                .unwrap();
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let received = server.received_ws_messages();
+        for pair in ["ETH-USDT", "SOL-USDT"] {
+            assert!(received
+                .iter()
+                .any(|msg| msg.contains(r#""op":"subscribe""#) && msg.contains(&format!(r#"{{"channel":"books","instId":"{pair}"}}"#))));
+        }
+    }
+}
+
+#[cfg(all(feature = "okex", feature = "binance"))]
+#[cfg(test)]
+mod okex_proxy_tests {
+    use cex_exchanges::{clients::rest_api::ExchangeApi, testing::MockExchangeServer, CexExchange, EmptyFilter};
+    use serial_test::serial;
+
+    use super::*;
+
+    #[tokio::test]
+    #[serial]
+    async fn test_currency_proxy_endpoints() {
+        init_test_tracing();
+        let mut server = MockExchangeServer::builder()
+            .with_rest_response("/api/v5/public/instruments", r#"{"code":"0","msg":"","data":[]}"#)
+            .with_rest_response("/api/v3/exchangeInfo", r#"{"symbols":[]}"#)
+            .with_rest_response("/bapi/composite/v1/public/promo/cmc/cryptocurrency/listings/latest", r#"{"data":{"body":{"data":[]}}}"#)
+            .spawn()
+            .await
+            .unwrap();
+        server.mock_exchange(CexExchange::Okex);
+        server.mock_exchange(CexExchange::Binance);
+
+        // the symbols come from binance, called through its endpoints
+        let exchange_api = ExchangeApi::new()
+            .with_endpoints(CexExchange::Okex, server.endpoints())
+            .with_endpoints(CexExchange::Binance, server.endpoints());
+        let currencies = CexExchange::Okex
+            .get_all_currencies::<EmptyFilter>(&exchange_api, None)
+            .await
+            .unwrap();
+        assert!(currencies.is_empty());
+
+        let requests = server.received_requests();
+        assert!(requests.contains(&"GET /api/v3/exchangeInfo".to_string()));
+        assert!(requests
+            .iter()
+            .any(|req| req.starts_with("GET /bapi/composite/v1/public/promo/cmc/cryptocurrency/listings/latest")));
+        assert!(requests
+            .iter()
+            .any(|req| req.starts_with("GET /api/v5/public/instruments")));
+    }
+}
+
+#[cfg(feature = "deribit")]
+#[cfg(test)]
+mod deribit_tests {
+    use std::time::Duration;
+
+    use cex_exchanges::{
+        clients::{rest_api::ExchangeApi, ws::WsStream},
+        deribit::{
+            ws::{channels::DeribitWsChannel, DeribitWsBuilder},
+            Deribit
+        },
+        normalized::{
+            types::{InstrumentFilter, NormalizedOptionType, NormalizedTradingType, RawTradingPair},
+            ws::NormalizedWsDataTypes
+        },
+        testing::MockExchangeServer,
+        CexExchange, Exchange
+    };
+    use futures::StreamExt;
+    use serial_test::serial;
+
+    use super::*;
+
+    fn book_frame(kind: &str, prev: Option<u64>, id: u64, bids: &str) -> String {
+        let prev = prev
+            .map(|p| format!(r#""prev_change_id":{p},"#))
+            .unwrap_or_default();
+        format!(
+            r#"{{"jsonrpc":"2.0","method":"subscription","params":{{"channel":"book.BTC-PERPETUAL.100ms","data":{{"type":"{kind}","timestamp":{},"instrument_name":"BTC-PERPETUAL",{prev}"change_id":{id},"bids":{bids},"asks":[["new",67001.0,500.0]]}}}}}}"#,
+            1717000000000u64 + id
+        )
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_book_resync() {
+        init_test_tracing();
+        let mut server = MockExchangeServer::builder()
+            .add_ws_frame(r#"{"jsonrpc":"2.0","id":1,"result":["book.BTC-PERPETUAL.100ms"],"usIn":1,"usOut":2,"usDiff":1,"testnet":false}"#)
+            .add_ws_frame(book_frame("snapshot", None, 10, r#"[["new",67000.0,1000.0]]"#))
+            .add_ws_frame(book_frame("change", Some(10), 11, r#"[["change",67000.0,900.0]]"#))
+            // change 12 was dropped
+            .add_ws_frame(book_frame("change", Some(12), 13, r#"[["delete",67000.0,0.0]]"#))
+            // buffered until the snapshot is fetched, which already covers it
+            .add_ws_frame(book_frame("change", Some(13), 14, r#"[["new",66999.5,700.0]]"#))
+            .with_rest_response(
+                "/api/v2/public/get_order_book",
+                r#"{"jsonrpc":"2.0","result":{"timestamp":1717000000020,"instrument_name":"BTC-PERPETUAL","change_id":20,"state":"open","bids":[[66999.5,700.0]],"asks":[[67001.0,500.0]]},"usIn":1,"usOut":2,"usDiff":1,"testnet":false}"#
+            )
+            .spawn()
+            .await
+            .unwrap();
+        server.mock_exchange(CexExchange::Deribit);
+
+        let builder =
+            DeribitWsBuilder::default().add_channel(DeribitWsChannel::new_book(vec![RawTradingPair::new_no_delim("BTC-PERPETUAL")]).unwrap());
+        let mut stream = WsStream::new(builder.build_single().with_endpoints(server.endpoints()));
+        stream.connect().await.unwrap();
+
+        let mut updates = Vec::new();
+        let mut gaps = Vec::new();
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while let Some(msg) = stream.next().await {
+                match msg.normalize() {
+                    NormalizedWsDataTypes::OrderBook(update) => {
+                        let resynced = update.is_snapshot() && update.last_update_id == Some(20);
+                        updates.push(update);
+                        if resynced {
+                            break
+                        }
+                    }
+                    NormalizedWsDataTypes::SequenceGap { expected, received, .. } => gaps.push((expected, received)),
+                    _ => ()
+                }
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(gaps, vec![(11, 12)]);
+        assert_eq!(
+            updates
+                .iter()
+                .map(|u| (u.is_snapshot(), u.last_update_id))
+                .collect::<Vec<_>>(),
+            vec![(true, Some(10)), (false, Some(11)), (true, Some(20))]
+        );
+        assert!(server
+            .received_ws_messages()
+            .iter()
+            .any(|msg| msg.contains(r#""method":"public/subscribe""#) && msg.contains("book.BTC-PERPETUAL.100ms")));
+        assert!(server
+            .received_requests()
+            .contains(&"GET /api/v2/public/get_order_book?instrument_name=BTC-PERPETUAL&depth=1000".to_string()));
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_all_instruments() {
+        init_test_tracing();
+        let mut server = MockExchangeServer::builder()
+            .with_rest_response(
+                "/api/v2/public/get_instruments?currency=BTC&kind=option&expired=false",
+                r#"{"jsonrpc":"2.0","result":[{"instrument_name":"BTC-27DEC24-100000-C","instrument_id":1,"kind":"option","base_currency":"BTC","quote_currency":"BTC","counter_currency":"USD","settlement_currency":"BTC","settlement_period":"month","is_active":true,"creation_timestamp":1700000000000,"expiration_timestamp":1735286400000,"strike":100000.0,"option_type":"call","tick_size":0.0005,"min_trade_amount":0.1,"contract_size":1.0,"maker_commission":0.0003,"taker_commission":0.0003}]}"#
+            )
+            .with_rest_response(
+                "/api/v2/public/get_instruments?currency=BTC&kind=future&expired=false",
+                r#"{"jsonrpc":"2.0","result":[{"instrument_name":"BTC-PERPETUAL","instrument_id":2,"kind":"future","base_currency":"BTC","quote_currency":"USD","counter_currency":"USD","settlement_currency":"BTC","settlement_period":"perpetual","is_active":true,"creation_timestamp":1534167754000,"expiration_timestamp":32503708800000,"tick_size":0.5,"min_trade_amount":10.0,"contract_size":10.0,"maker_commission":0.0,"taker_commission":0.0005},{"instrument_name":"BTC-27DEC24","instrument_id":3,"kind":"future","base_currency":"BTC","quote_currency":"USD","counter_currency":"USD","settlement_currency":"BTC","settlement_period":"month","is_active":true,"creation_timestamp":1700000000000,"expiration_timestamp":1735286400000,"tick_size":2.5,"min_trade_amount":10.0,"contract_size":10.0,"maker_commission":-0.0001,"taker_commission":0.0005}]}"#
+            )
+            .with_rest_response("/api/v2/public/get_instruments", r#"{"jsonrpc":"2.0","result":[]}"#)
+            .spawn()
+            .await
+            .unwrap();
+        server.mock_exchange(CexExchange::Deribit);
+
+        let instruments = ExchangeApi::new()
+            .with_endpoints(CexExchange::Deribit, server.endpoints())
+            .all_instruments::<Deribit>()
+            .await
+            .unwrap();
+        let normalized = instruments.clone().normalize();
+        assert_eq!(instruments, normalized);
+
+        let normalized = normalized
+            .take_instruments::<InstrumentFilter>(None)
+            .unwrap();
+        assert_eq!(
+            normalized
+                .iter()
+                .map(|i| i.trading_type)
+                .collect::<Vec<_>>(),
+            vec![NormalizedTradingType::Perpetual, NormalizedTradingType::Futures, NormalizedTradingType::Option]
+        );
+
+        let perpetual = &normalized[0];
+        assert_eq!(perpetual.futures_expiry, None);
+
+        let option = &normalized[2];
+        assert_eq!(option.trading_pair, RawTradingPair::new_no_delim("BTC-27DEC24-100000-C").get_normalized_pair(CexExchange::Deribit));
+        assert_eq!(option.futures_expiry, chrono::NaiveDate::from_ymd_opt(2024, 12, 27));
+        assert_eq!(option.option_strike, Some("100000".parse().unwrap()));
+        assert_eq!(option.option_type, Some(NormalizedOptionType::Call));
+
+        assert_eq!(server.received_requests().len(), 4);
+    }
+}
+
+#[cfg(feature = "bitget")]
+#[cfg(test)]
+mod bitget_tests {
+    use std::time::Duration;
+
+    use cex_exchanges::{
+        bitget::{
+            ws::{channels::BitgetWsChannel, BitgetWsBuilder},
+            Bitget
+        },
+        clients::{rest_api::ExchangeApi, ws::WsStream},
+        normalized::{types::RawTradingPair, ws::CombinedWsMessage},
+        testing::MockExchangeServer,
+        CexExchange, Exchange
+    };
+    use futures::StreamExt;
+    use serial_test::serial;
+
+    use super::*;
+
+    #[tokio::test]
+    #[serial]
+    async fn test_trades_and_book_ticker() {
+        init_test_tracing();
+        let mut builder = MockExchangeServer::builder()
+            .add_ws_frame(r#"{"event":"subscribe","arg":{"instType":"SPOT","channel":"trade","instId":"BTCUSDT"}}"#)
+            .add_ws_frame(r#"{"event":"subscribe","arg":{"instType":"SPOT","channel":"books1","instId":"BTCUSDT"}}"#);
+        for i in 0..3u64 {
+            builder = builder
+                .add_ws_frame(format!(
+                    r#"{{"action":"update","arg":{{"instType":"SPOT","channel":"trade","instId":"BTCUSDT"}},"data":[{{"ts":"{}","price":"67000.{i}","size":"0.01","side":"buy","tradeId":"{}"}}],"ts":{}}}"#,
+                    1717000000000u64 + i,
+                    100 + i,
+                    1717000000000u64 + i
+                ))
+                .add_ws_frame(format!(
+                    r#"{{"action":"snapshot","arg":{{"instType":"SPOT","channel":"books1","instId":"BTCUSDT"}},"data":[{{"asks":[["67001.{i}","0.5"]],"bids":[["67000.{i}","0.7"]],"checksum":0,"seq":{},"ts":"{}"}}],"ts":{}}}"#,
+                    200 + i,
+                    1717000000000u64 + i,
+                    1717000000000u64 + i
+                ));
+        }
+        let mut server = builder.spawn().await.unwrap();
+        server.mock_exchange(CexExchange::Bitget);
+
+        let builder = BitgetWsBuilder::default()
+            .add_channel(BitgetWsChannel::new_trade(vec![RawTradingPair::new_no_delim("BTCUSDT")]).unwrap())
+            .add_channel(BitgetWsChannel::new_book_ticker(vec![RawTradingPair::new_no_delim("BTCUSDT")]).unwrap());
+        stream_util(builder.build_single().with_endpoints(server.endpoints()), 7).await;
+
+        assert!(server
+            .received_ws_messages()
+            .iter()
+            .any(|msg| msg.contains(r#""op":"subscribe""#)
+                && msg.contains(r#""channel":"trade""#)
+                && msg.contains(r#""channel":"books1""#)
+                && msg.contains(r#""instId":"BTCUSDT""#)));
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_idle_timeout_reconnect() {
+        init_test_tracing();
+        // the heartbeat reply is dropped by the stream, then the connection goes silent
+        let mut server = MockExchangeServer::builder()
+            .add_ws_frame(r#"{"event":"subscribe","arg":{"instType":"SPOT","channel":"trade","instId":"BTCUSDT"}}"#)
+            .add_ws_frame("pong")
+            .spawn()
+            .await
+            .unwrap();
+        server.mock_exchange(CexExchange::Bitget);
+
+        let builder = BitgetWsBuilder::default().add_channel(BitgetWsChannel::new_trade(vec![RawTradingPair::new_no_delim("BTCUSDT")]).unwrap());
+        let mut stream = WsStream::new(builder.build_single().with_endpoints(server.endpoints())).with_idle_timeout(Duration::from_millis(200));
+        stream.connect().await.unwrap();
+
+        let messages = tokio::time::timeout(Duration::from_secs(5), stream.by_ref().take(3).collect::<Vec<_>>())
+            .await
+            .unwrap();
+        assert!(!matches!(messages[0], CombinedWsMessage::Disconnect { .. }));
+        assert!(matches!(&messages[1], CombinedWsMessage::Disconnect { message, .. } if message.contains("no message received")));
+        assert!(!matches!(messages[2], CombinedWsMessage::Disconnect { .. }));
+
+        let subscriptions = server
+            .received_ws_messages()
+            .into_iter()
+            .filter(|msg| msg.contains(r#""op":"subscribe""#))
+            .count();
+        assert_eq!(subscriptions, 2);
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_all_instruments() {
+        init_test_tracing();
+        let mut server = MockExchangeServer::builder()
+            .with_rest_response(
+                "/api/v2/spot/public/symbols",
+                r#"{"code":"00000","msg":"success","requestTime":1717000000000,"data":[{"symbol":"BTCUSDT","baseCoin":"BTC","quoteCoin":"USDT","minTradeAmount":"0","maxTradeAmount":"10000000000","takerFeeRate":"0.001","makerFeeRate":"0.001","pricePrecision":"2","quantityPrecision":"6","quotePrecision":"8","status":"online","minTradeUSDT":"1","buyLimitPriceRatio":"0.05","sellLimitPriceRatio":"0.05","areaSymbol":"no"},{"symbol":"OLDUSDT","baseCoin":"OLD","quoteCoin":"USDT","minTradeAmount":"0","maxTradeAmount":"10000000000","takerFeeRate":"0.001","makerFeeRate":"0.001","pricePrecision":"4","quantityPrecision":"2","quotePrecision":"6","status":"offline","minTradeUSDT":"1","buyLimitPriceRatio":"0.05","sellLimitPriceRatio":"0.05","areaSymbol":"no"}]}"#
+            )
+            .spawn()
+            .await
+            .unwrap();
+        server.mock_exchange(CexExchange::Bitget);
+
+        let instruments = ExchangeApi::new()
+            .with_endpoints(CexExchange::Bitget, server.endpoints())
+            .all_instruments::<Bitget>()
+            .await
+            .unwrap();
+        let normalized = instruments.clone().normalize();
+        assert_eq!(instruments, normalized);
+
+        let active = instruments.take_bitget_instruments(true).unwrap();
+        assert_eq!(active.len(), 1);
+        assert_eq!(
+            active[0].symbol.normalize_with("BTC", "USDT"),
+            RawTradingPair::new_base_quote("BTC", "USDT", None).get_normalized_pair(CexExchange::Bitget)
+        );
+
+        assert_eq!(server.received_requests(), vec!["GET /api/v2/spot/public/symbols".to_string()]);
+    }
+}
+
+#[cfg(feature = "mexc")]
+#[cfg(test)]
+mod mexc_tests {
+    use std::time::Duration;
+
+    use cex_exchanges::{
+        clients::{
+            rest_api::ExchangeApi,
+            ws::{FrameRecorder, RecordedFrame, RecordedFrameData, ReplayStream, WsStream}
+        },
+        mexc::{
+            ws::{
+                channels::MexcWsChannel,
+                proto::{PublicAggreBookTickerV3Api, PublicAggreDealsV3Api, PublicAggreDealsV3ApiItem, PushDataBody, PushDataV3ApiWrapper},
+                MexcWsBuilder
+            },
+            Mexc
+        },
+        normalized::{
+            types::{RawTradingPair, TradeSide},
+            ws::NormalizedWsDataTypes
+        },
+        testing::MockExchangeServer,
+        CexExchange, Exchange
+    };
+    use futures::StreamExt;
+    use prost::Message;
+    use serial_test::serial;
+
+    use super::*;
+
+    fn push_frame(channel: &str, body: PushDataBody) -> Vec<u8> {
+        PushDataV3ApiWrapper {
+            channel:     format!("{channel}@100ms@BTCUSDT"),
+            body:        Some(body),
+            symbol:      Some("BTCUSDT".to_string()),
+            symbol_id:   None,
+            create_time: Some(1717000000000),
+            send_time:   Some(1717000000005)
+        }
+        .encode_to_vec()
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_binary_deals_and_book_ticker() {
+        init_test_tracing();
+        let deals = PublicAggreDealsV3Api {
+            deals:      vec![
+                PublicAggreDealsV3ApiItem {
+                    price:      "67000.1".to_string(),
+                    quantity:   "0.01".to_string(),
+                    trade_type: 1,
+                    time:       1717000000001
+                },
+                PublicAggreDealsV3ApiItem {
+                    price:      "67000.2".to_string(),
+                    quantity:   "0.02".to_string(),
+                    trade_type: 2,
+                    time:       1717000000002
+                },
+            ],
+            event_type: "spot@public.aggre.deals.v3.api.pb@100ms".to_string()
+        };
+        let book_ticker = PublicAggreBookTickerV3Api {
+            bid_price:    "67000.0".to_string(),
+            bid_quantity: "1.5".to_string(),
+            ask_price:    "67000.5".to_string(),
+            ask_quantity: "2.5".to_string()
+        };
+        let mut server = MockExchangeServer::builder()
+            .add_ws_frame(
+                r#"{"id":0,"code":0,"msg":"spot@public.aggre.deals.v3.api.pb@100ms@BTCUSDT,spot@public.aggre.bookTicker.v3.api.pb@100ms@BTCUSDT"}"#
+            )
+            .add_ws_binary_frame(push_frame("spot@public.aggre.deals.v3.api.pb", PushDataBody::PublicAggreDeals(deals)))
+            .add_ws_binary_frame(push_frame("spot@public.aggre.bookTicker.v3.api.pb", PushDataBody::PublicAggreBookTicker(book_ticker)))
+            .spawn()
+            .await
+            .unwrap();
+        server.mock_exchange(CexExchange::Mexc);
+
+        let builder = MexcWsBuilder::default()
+            .add_channel(MexcWsChannel::new_deals(vec![RawTradingPair::new_no_delim("BTCUSDT")]).unwrap())
+            .add_channel(MexcWsChannel::new_book_ticker(vec![RawTradingPair::new_no_delim("BTCUSDT")]).unwrap());
+        let mut stream = WsStream::new(builder.build_single().with_endpoints(server.endpoints()));
+        stream.connect().await.unwrap();
+
+        let mut messages = Vec::new();
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while let Some(msg) = stream.next().await {
+                assert!(msg.is_ok());
+                assert_eq!(msg, msg.clone().normalize());
+
+                messages.push(msg.normalize());
+                if messages.len() == 3 {
+                    break
+                }
+            }
+        })
+        .await
+        .unwrap();
+
+        let NormalizedWsDataTypes::Trades(trades) = &messages[1] else { panic!("expected trades, got {:?}", messages[1]) };
+        assert_eq!(
+            trades
+                .iter()
+                .map(|t| (t.side, t.price, t.amount))
+                .collect::<Vec<_>>(),
+            vec![
+                (TradeSide::Buy, "67000.1".parse().unwrap(), "0.01".parse().unwrap()),
+                (TradeSide::Sell, "67000.2".parse().unwrap(), "0.02".parse().unwrap())
+            ]
+        );
+
+        let NormalizedWsDataTypes::Quote(quote) = &messages[2] else { panic!("expected a quote, got {:?}", messages[2]) };
+        assert_eq!(quote.pair, RawTradingPair::new_no_delim("BTCUSDT").get_normalized_pair(CexExchange::Mexc));
+        assert_eq!((quote.bid_price, quote.ask_price), ("67000.0".parse().unwrap(), "67000.5".parse().unwrap()));
+
+        assert!(server
+            .received_ws_messages()
+            .iter()
+            .any(|msg| msg.contains(r#""method":"SUBSCRIPTION""#)
+                && msg.contains("spot@public.aggre.deals.v3.api.pb@100ms@BTCUSDT")
+                && msg.contains("spot@public.aggre.bookTicker.v3.api.pb@100ms@BTCUSDT")));
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_binary_record_replay() {
+        init_test_tracing();
+        let book_ticker = push_frame(
+            "spot@public.aggre.bookTicker.v3.api.pb",
+            PushDataBody::PublicAggreBookTicker(PublicAggreBookTickerV3Api {
+                bid_price:    "67000.0".to_string(),
+                bid_quantity: "1.5".to_string(),
+                ask_price:    "67000.5".to_string(),
+                ask_quantity: "2.5".to_string()
+            })
+        );
+        let mut server = MockExchangeServer::builder()
+            .add_ws_frame(r#"{"id":0,"code":0,"msg":"spot@public.aggre.bookTicker.v3.api.pb@100ms@BTCUSDT"}"#)
+            .add_ws_binary_frame(book_ticker.clone())
+            .spawn()
+            .await
+            .unwrap();
+        server.mock_exchange(CexExchange::Mexc);
+
+        let path = std::env::temp_dir().join(format!("cex-exchanges-binary-record-replay-{}.jsonl", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let builder = MexcWsBuilder::default().add_channel(MexcWsChannel::new_book_ticker(vec![RawTradingPair::new_no_delim("BTCUSDT")]).unwrap());
+        let mut stream = WsStream::new(builder.build_single().with_endpoints(server.endpoints())).with_recorder(FrameRecorder::new(&path).unwrap());
+        stream.connect().await.unwrap();
+        let live = stream.by_ref().take(2).collect::<Vec<_>>().await;
+        drop(stream);
+
+        let lines = std::fs::read_to_string(&path).unwrap();
+        assert!(lines
+            .lines()
+            .nth(1)
+            .unwrap()
+            .contains(r#""frame":{"kind":"binary","data":""#));
+
+        let recorded = RecordedFrame::read_file(&path).unwrap();
+        assert!(matches!(&recorded[0].frame, RecordedFrameData::Text(_)));
+        assert_eq!(recorded[1].frame, RecordedFrameData::Binary(book_ticker));
+
+        let replayed = ReplayStream::from_file(&path)
+            .unwrap()
+            .collect::<Vec<_>>()
+            .await;
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(replayed[1].clone().normalize(), NormalizedWsDataTypes::Quote(_)));
+        assert_eq!(live.len(), replayed.len());
+        live.iter()
+            .zip(replayed)
+            .for_each(|(live, replayed)| assert_eq!(*live, replayed.normalize()));
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_all_currencies() {
+        init_test_tracing();
+        let mut server = MockExchangeServer::builder()
+            .with_rest_response(
+                "/api/v3/exchangeInfo",
+                r#"{"timezone":"CST","serverTime":1717000000000,"rateLimits":[],"exchangeFilters":[],"symbols":[{"symbol":"BTCUSDT","status":"1","baseAsset":"BTC","baseAssetPrecision":6,"quoteAsset":"USDT","quotePrecision":2,"quoteAssetPrecision":2,"isSpotTradingAllowed":true,"isMarginTradingAllowed":false,"permissions":["SPOT"],"makerCommission":"0","takerCommission":"0.0005","fullName":"Bitcoin"},{"symbol":"OLDUSDT","status":"3","baseAsset":"OLD","baseAssetPrecision":2,"quoteAsset":"USDT","quotePrecision":6,"quoteAssetPrecision":6,"isSpotTradingAllowed":false,"isMarginTradingAllowed":false,"permissions":["SPOT"],"makerCommission":"0","takerCommission":"0.0005"}]}"#
+            )
+            .spawn()
+            .await
+            .unwrap();
+        server.mock_exchange(CexExchange::Mexc);
+
+        let currencies = ExchangeApi::new()
+            .with_endpoints(CexExchange::Mexc, server.endpoints())
+            .all_currencies::<Mexc>()
+            .await
+            .unwrap();
+        let normalized = currencies.clone().normalize();
+        assert_eq!(currencies, normalized);
+
+        let currencies = currencies.take_mexc_currencies().unwrap();
+        assert_eq!(
+            currencies
+                .iter()
+                .map(|c| (c.symbol.as_str(), c.full_name.as_deref(), c.active))
+                .collect::<Vec<_>>(),
+            vec![("BTC", Some("Bitcoin"), true), ("OLD", None, false), ("USDT", None, true)]
+        );
+
+        assert_eq!(server.received_requests(), vec!["GET /api/v3/exchangeInfo".to_string()]);
+    }
+}
+
+#[cfg(all(feature = "binance", feature = "coinbase", feature = "okex", feature = "kucoin", feature = "bybit"))]
+#[cfg(test)]
+mod trade_side_tests {
+    use cex_exchanges::{
+        clients::ws::{RecordedFrame, RecordedFrameData, ReplayStream},
+        normalized::{types::TradeSide, ws::NormalizedWsDataTypes},
+        CexExchange
+    };
+    use futures::StreamExt;
+
+    /// the taker sides of the trades in the replayed frames
+    async fn replayed_sides(frames: Vec<(CexExchange, &str)>) -> Vec<TradeSide> {
+        let frames = frames
+            .into_iter()
+            .map(|(exchange, frame)| RecordedFrame {
+                exchange,
+                connection_id: 0,
+                received_at: chrono::Utc::now(),
+                frame: RecordedFrameData::Text(frame.to_string())
+            })
+            .collect();
+
+        ReplayStream::new(frames)
+            .map(|msg| match msg.normalize() {
+                NormalizedWsDataTypes::Trade(trade) => vec![trade.side],
+                NormalizedWsDataTypes::Trades(trades) => trades.into_iter().map(|t| t.side).collect(),
+                other => panic!("expected trades, got {other:?}")
+            })
+            .concat()
+            .await
+    }
+
+    #[tokio::test]
+    async fn test_binance_buyer_maker() {
+        let sides = replayed_sides(vec![
+            (
+                CexExchange::Binance,
+                r#"{"stream":"ethusdt@trade","data":{"e":"trade","E":1717000000000,"s":"ETHUSDT","t":100,"p":"3000.0","q":"0.5","b":200,"a":300,"T":1717000000000,"m":true,"M":true}}"#
+            ),
+            (
+                CexExchange::Binance,
+                r#"{"stream":"ethusdt@trade","data":{"e":"trade","E":1717000000001,"s":"ETHUSDT","t":101,"p":"3000.1","q":"0.5","b":201,"a":301,"T":1717000000001,"m":false,"M":true}}"#
+            ),
+        ])
+        .await;
+
+        // the buyer being the maker, the taker sold
+        assert_eq!(sides, vec![TradeSide::Sell, TradeSide::Buy]);
+    }
+
+    #[tokio::test]
+    async fn test_coinbase_maker_side() {
+        let sides = replayed_sides(vec![
+            (
+                CexExchange::Coinbase,
+                r#"{"type":"match","trade_id":10,"sequence":50,"maker_order_id":"ac928c66-ca53-498f-9c13-a110027a60e8","taker_order_id":"132fb6ae-456b-4654-b4e0-d681ac05cea1","time":"2024-06-01T00:00:00.000000Z","product_id":"ETH-USD","size":"0.5","price":"3000.0","side":"sell"}"#
+            ),
+            (
+                CexExchange::Coinbase,
+                r#"{"type":"match","trade_id":11,"sequence":51,"maker_order_id":"ac928c66-ca53-498f-9c13-a110027a60e9","taker_order_id":"132fb6ae-456b-4654-b4e0-d681ac05cea2","time":"2024-06-01T00:00:01.000000Z","product_id":"ETH-USD","size":"0.5","price":"3000.1","side":"buy"}"#
+            ),
+        ])
+        .await;
+
+        // the side is the maker's
+        assert_eq!(sides, vec![TradeSide::Buy, TradeSide::Sell]);
+    }
+
+    #[tokio::test]
+    async fn test_taker_side() {
+        let sides = replayed_sides(vec![
+            (
+                CexExchange::Okex,
+                r#"{"arg":{"channel":"trades-all","instId":"ETH-USDT"},"data":[{"instId":"ETH-USDT","tradeId":"130639474","px":"3000.0","sz":"0.5","side":"buy","ts":"1717000000000"}]}"#
+            ),
+            (
+                CexExchange::Okex,
+                r#"{"arg":{"channel":"trades-all","instId":"ETH-USDT"},"data":[{"instId":"ETH-USDT","tradeId":"130639475","px":"3000.1","sz":"0.5","side":"sell","ts":"1717000000001"}]}"#
+            ),
+            (
+                CexExchange::Kucoin,
+                r#"{"type":"message","topic":"/market/match:ETH-USDT","subject":"trade.l3match","data":{"sequence":"1000","type":"match","symbol":"ETH-USDT","side":"sell","price":"3000.0","size":"0.1","tradeId":"2000","takerOrderId":"a","makerOrderId":"b","time":"1717000000000000000"}}"#
+            ),
+            (
+                CexExchange::Bybit,
+                r#"{"topic":"publicTrade.ETHUSDT","type":"snapshot","ts":1717000000002,"data":[{"T":1717000000001,"s":"ETHUSDT","S":"Buy","v":"0.5","p":"3000.0","L":"PlusTick","i":"2290000000061666327","BT":false},{"T":1717000000002,"s":"ETHUSDT","S":"Sell","v":"0.5","p":"2999.9","L":"MinusTick","i":"2290000000061666328","BT":false}]}"#
+            ),
+        ])
+        .await;
+
+        assert_eq!(sides, vec![TradeSide::Buy, TradeSide::Sell, TradeSide::Sell, TradeSide::Buy, TradeSide::Sell]);
+    }
+}
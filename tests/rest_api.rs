@@ -1,10 +1,16 @@
 mod utils;
 use utils::init_test_tracing;
 
-#[cfg(feature = "us")]
+#[cfg(feature = "coinbase")]
 #[cfg(test)]
 mod coinbase_tests {
-    use cex_exchanges::{clients::rest_api::ExchangeApi, coinbase::Coinbase, EmptyFilter};
+    use cex_exchanges::{
+        clients::rest_api::ExchangeApi,
+        coinbase::Coinbase,
+        normalized::types::{NormalizedCandleInterval, RawTradingPair},
+        CexExchange, EmptyFilter
+    };
+    use chrono::{Duration, Utc};
     use serial_test::serial;
 
     use super::init_test_tracing;
@@ -54,12 +60,104 @@ mod coinbase_tests {
             assert_eq!(all_instruments, normalized);
         }
     }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_candles() {
+        init_test_tracing();
+        let exchange_api = ExchangeApi::new();
+        let end = Utc::now();
+        let start = end - Duration::hours(30);
+        let pair = RawTradingPair::new_raw("ETH_USD", '_').get_normalized_pair(CexExchange::Coinbase);
+        let candles = exchange_api
+            .candles::<Coinbase>(pair, NormalizedCandleInterval::OneMinute, start, end)
+            .await;
+        candles.as_ref().unwrap();
+        assert!(candles.is_ok());
+
+        {
+            let candles = candles.unwrap();
+            let normalized = candles.clone().normalize();
+
+            let all_candles = normalized.clone().take_candles().unwrap();
+            assert!(all_candles.len() > 1000);
+            assert!(all_candles
+                .iter()
+                .all(|c| c.start_time >= start && c.start_time < end));
+            assert!(all_candles
+                .windows(2)
+                .all(|w| w[0].start_time < w[1].start_time));
+
+            assert_eq!(candles, normalized);
+        }
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_recent_trades() {
+        init_test_tracing();
+        let exchange_api = ExchangeApi::new();
+        let pair = RawTradingPair::new_raw("ETH_USD", '_').get_normalized_pair(CexExchange::Coinbase);
+        let trades = exchange_api.recent_trades::<Coinbase>(pair, 500).await;
+        trades.as_ref().unwrap();
+        assert!(trades.is_ok());
+
+        {
+            let trades = trades.unwrap();
+            let normalized = trades.clone().normalize();
+
+            let all_trades = normalized.clone().take_trades().unwrap();
+            assert!(all_trades.len() >= 100);
+            assert!(all_trades
+                .windows(2)
+                .all(|w| w[0].exchange_time <= w[1].exchange_time));
+
+            assert_eq!(trades, normalized);
+        }
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_historical_trades() {
+        init_test_tracing();
+        let exchange_api = ExchangeApi::new();
+        let to = Utc::now() - Duration::minutes(5);
+        let from = to - Duration::minutes(10);
+        let pair = RawTradingPair::new_raw("ETH_USD", '_').get_normalized_pair(CexExchange::Coinbase);
+        let trades = exchange_api
+            .historical_trades::<Coinbase>(pair, from, to)
+            .await;
+        trades.as_ref().unwrap();
+        assert!(trades.is_ok());
+
+        {
+            let trades = trades.unwrap();
+            let normalized = trades.clone().normalize();
+
+            let all_trades = normalized.clone().take_trades().unwrap();
+            assert!(!all_trades.is_empty());
+            assert!(all_trades.iter().all(|t| t
+                .exchange_time
+                .is_some_and(|time| time >= from && time < to)));
+            assert!(all_trades
+                .windows(2)
+                .all(|w| w[0].exchange_time <= w[1].exchange_time));
+
+            assert_eq!(trades, normalized);
+        }
+    }
 }
 
-#[cfg(feature = "non-us")]
+#[cfg(feature = "binance")]
 #[cfg(test)]
 mod binance_tests {
-    use cex_exchanges::{binance::Binance, clients::rest_api::ExchangeApi, normalized::types::InstrumentFilter, EmptyFilter};
+    use cex_exchanges::{
+        binance::Binance,
+        clients::rest_api::ExchangeApi,
+        normalized::types::{InstrumentFilter, NormalizedCandleInterval, RawTradingPair},
+        CexExchange, EmptyFilter
+    };
+    use chrono::{Duration, Utc};
     use serial_test::serial;
 
     use super::init_test_tracing;
@@ -120,12 +218,104 @@ mod binance_tests {
             assert_eq!(all_instruments, normalized);
         }
     }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_candles() {
+        init_test_tracing();
+        let exchange_api = ExchangeApi::new();
+        let end = Utc::now();
+        let start = end - Duration::hours(30);
+        let pair = RawTradingPair::new_raw("ETH_USDT", '_').get_normalized_pair(CexExchange::Binance);
+        let candles = exchange_api
+            .candles::<Binance>(pair, NormalizedCandleInterval::OneMinute, start, end)
+            .await;
+        candles.as_ref().unwrap();
+        assert!(candles.is_ok());
+
+        {
+            let candles = candles.unwrap();
+            let normalized = candles.clone().normalize();
+
+            let all_candles = normalized.clone().take_candles().unwrap();
+            assert!(all_candles.len() > 1000);
+            assert!(all_candles
+                .iter()
+                .all(|c| c.start_time >= start && c.start_time < end));
+            assert!(all_candles
+                .windows(2)
+                .all(|w| w[0].start_time < w[1].start_time));
+
+            assert_eq!(candles, normalized);
+        }
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_recent_trades() {
+        init_test_tracing();
+        let exchange_api = ExchangeApi::new();
+        let pair = RawTradingPair::new_raw("ETH_USDT", '_').get_normalized_pair(CexExchange::Binance);
+        let trades = exchange_api.recent_trades::<Binance>(pair, 500).await;
+        trades.as_ref().unwrap();
+        assert!(trades.is_ok());
+
+        {
+            let trades = trades.unwrap();
+            let normalized = trades.clone().normalize();
+
+            let all_trades = normalized.clone().take_trades().unwrap();
+            assert!(all_trades.len() >= 100);
+            assert!(all_trades
+                .windows(2)
+                .all(|w| w[0].exchange_time <= w[1].exchange_time));
+
+            assert_eq!(trades, normalized);
+        }
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_historical_trades() {
+        init_test_tracing();
+        let exchange_api = ExchangeApi::new();
+        let to = Utc::now() - Duration::minutes(5);
+        let from = to - Duration::minutes(10);
+        let pair = RawTradingPair::new_raw("ETH_USDT", '_').get_normalized_pair(CexExchange::Binance);
+        let trades = exchange_api
+            .historical_trades::<Binance>(pair, from, to)
+            .await;
+        trades.as_ref().unwrap();
+        assert!(trades.is_ok());
+
+        {
+            let trades = trades.unwrap();
+            let normalized = trades.clone().normalize();
+
+            let all_trades = normalized.clone().take_trades().unwrap();
+            assert!(!all_trades.is_empty());
+            assert!(all_trades.iter().all(|t| t
+                .exchange_time
+                .is_some_and(|time| time >= from && time < to)));
+            assert!(all_trades
+                .windows(2)
+                .all(|w| w[0].exchange_time <= w[1].exchange_time));
+
+            assert_eq!(trades, normalized);
+        }
+    }
 }
 
-#[cfg(feature = "us")]
+#[cfg(feature = "okex")]
 #[cfg(test)]
 mod okex_tests {
-    use cex_exchanges::{clients::rest_api::ExchangeApi, normalized::types::InstrumentFilter, okex::Okex, EmptyFilter};
+    use cex_exchanges::{
+        clients::rest_api::ExchangeApi,
+        normalized::types::{InstrumentFilter, NormalizedCandleInterval, RawTradingPair},
+        okex::Okex,
+        CexExchange, EmptyFilter
+    };
+    use chrono::{Duration, Utc};
     use serial_test::serial;
 
     use super::init_test_tracing;
@@ -182,12 +372,102 @@ mod okex_tests {
             assert!(test_length > 10);
         }
     }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_candles() {
+        init_test_tracing();
+        let exchange_api = ExchangeApi::new();
+        let end = Utc::now();
+        let start = end - Duration::hours(30);
+        let pair = RawTradingPair::new_raw("ETH_USDT", '_').get_normalized_pair(CexExchange::Okex);
+        let candles = exchange_api
+            .candles::<Okex>(pair, NormalizedCandleInterval::FiveMinutes, start, end)
+            .await;
+        candles.as_ref().unwrap();
+        assert!(candles.is_ok());
+
+        {
+            let candles = candles.unwrap();
+            let normalized = candles.clone().normalize();
+
+            let all_candles = normalized.clone().take_candles().unwrap();
+            assert!(all_candles.len() > 300);
+            assert!(all_candles
+                .iter()
+                .all(|c| c.start_time >= start && c.start_time < end));
+            assert!(all_candles
+                .windows(2)
+                .all(|w| w[0].start_time < w[1].start_time));
+
+            assert_eq!(candles, normalized);
+        }
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_recent_trades() {
+        init_test_tracing();
+        let exchange_api = ExchangeApi::new();
+        let pair = RawTradingPair::new_raw("ETH_USDT", '_').get_normalized_pair(CexExchange::Okex);
+        let trades = exchange_api.recent_trades::<Okex>(pair, 200).await;
+        trades.as_ref().unwrap();
+        assert!(trades.is_ok());
+
+        {
+            let trades = trades.unwrap();
+            let normalized = trades.clone().normalize();
+
+            let all_trades = normalized.clone().take_trades().unwrap();
+            assert!(all_trades.len() >= 100);
+            assert!(all_trades
+                .windows(2)
+                .all(|w| w[0].exchange_time <= w[1].exchange_time));
+
+            assert_eq!(trades, normalized);
+        }
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_historical_trades() {
+        init_test_tracing();
+        let exchange_api = ExchangeApi::new();
+        let to = Utc::now() - Duration::minutes(5);
+        let from = to - Duration::minutes(10);
+        let pair = RawTradingPair::new_raw("ETH_USDT", '_').get_normalized_pair(CexExchange::Okex);
+        let trades = exchange_api.historical_trades::<Okex>(pair, from, to).await;
+        trades.as_ref().unwrap();
+        assert!(trades.is_ok());
+
+        {
+            let trades = trades.unwrap();
+            let normalized = trades.clone().normalize();
+
+            let all_trades = normalized.clone().take_trades().unwrap();
+            assert!(!all_trades.is_empty());
+            assert!(all_trades.iter().all(|t| t
+                .exchange_time
+                .is_some_and(|time| time >= from && time < to)));
+            assert!(all_trades
+                .windows(2)
+                .all(|w| w[0].exchange_time <= w[1].exchange_time));
+
+            assert_eq!(trades, normalized);
+        }
+    }
 }
 
-#[cfg(feature = "non-us")]
+#[cfg(feature = "kucoin")]
 #[cfg(test)]
 mod kucoin_tests {
-    use cex_exchanges::{clients::rest_api::ExchangeApi, kucoin::Kucoin, normalized::types::InstrumentFilter, EmptyFilter};
+    use cex_exchanges::{
+        clients::rest_api::ExchangeApi,
+        kucoin::Kucoin,
+        normalized::types::{InstrumentFilter, NormalizedCandleInterval, RawTradingPair},
+        CexExchange, EmptyFilter
+    };
+    use chrono::{Duration, Utc};
     use serial_test::serial;
 
     use super::init_test_tracing;
@@ -249,13 +529,74 @@ mod kucoin_tests {
             assert!(test_length > 10);
         }
     }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_candles() {
+        init_test_tracing();
+        let exchange_api = ExchangeApi::new();
+        let end = Utc::now();
+        let start = end - Duration::hours(30);
+        let pair = RawTradingPair::new_raw("ETH_USDT", '_').get_normalized_pair(CexExchange::Kucoin);
+        let candles = exchange_api
+            .candles::<Kucoin>(pair, NormalizedCandleInterval::OneMinute, start, end)
+            .await;
+        candles.as_ref().unwrap();
+        assert!(candles.is_ok());
+
+        {
+            let candles = candles.unwrap();
+            let normalized = candles.clone().normalize();
+
+            let all_candles = normalized.clone().take_candles().unwrap();
+            assert!(all_candles.len() > 1500);
+            assert!(all_candles
+                .iter()
+                .all(|c| c.start_time >= start && c.start_time < end));
+            assert!(all_candles
+                .windows(2)
+                .all(|w| w[0].start_time < w[1].start_time));
+
+            assert_eq!(candles, normalized);
+        }
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_recent_trades() {
+        init_test_tracing();
+        let exchange_api = ExchangeApi::new();
+        let pair = RawTradingPair::new_raw("ETH_USDT", '_').get_normalized_pair(CexExchange::Kucoin);
+        let trades = exchange_api.recent_trades::<Kucoin>(pair, 50).await;
+        trades.as_ref().unwrap();
+        assert!(trades.is_ok());
+
+        {
+            let trades = trades.unwrap();
+            let normalized = trades.clone().normalize();
+
+            let all_trades = normalized.clone().take_trades().unwrap();
+            assert!(all_trades.len() >= 10);
+            assert!(all_trades
+                .windows(2)
+                .all(|w| w[0].exchange_time <= w[1].exchange_time));
+
+            assert_eq!(trades, normalized);
+        }
+    }
 }
 
-#[cfg(feature = "non-us")]
+#[cfg(feature = "bybit")]
 #[cfg(test)]
 mod bybit_tests {
 
-    use cex_exchanges::{bybit::Bybit, clients::rest_api::ExchangeApi, normalized::types::InstrumentFilter, EmptyFilter};
+    use cex_exchanges::{
+        bybit::Bybit,
+        clients::rest_api::ExchangeApi,
+        normalized::types::{InstrumentFilter, NormalizedCandleInterval, RawTradingPair},
+        CexExchange, EmptyFilter
+    };
+    use chrono::{Duration, Utc};
     use serial_test::serial;
 
     use super::init_test_tracing;
@@ -313,4 +654,489 @@ mod bybit_tests {
             assert!(test_length > 10);
         }
     }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_candles() {
+        init_test_tracing();
+        let exchange_api = ExchangeApi::new();
+        let end = Utc::now();
+        let start = end - Duration::hours(30);
+        let pair = RawTradingPair::new_raw("ETH_USDT", '_').get_normalized_pair(CexExchange::Bybit);
+        let candles = exchange_api
+            .candles::<Bybit>(pair, NormalizedCandleInterval::OneMinute, start, end)
+            .await;
+        candles.as_ref().unwrap();
+        assert!(candles.is_ok());
+
+        {
+            let candles = candles.unwrap();
+            let normalized = candles.clone().normalize();
+
+            let all_candles = normalized.clone().take_candles().unwrap();
+            assert!(all_candles.len() > 1000);
+            assert!(all_candles
+                .iter()
+                .all(|c| c.start_time >= start && c.start_time < end));
+            assert!(all_candles
+                .windows(2)
+                .all(|w| w[0].start_time < w[1].start_time));
+
+            assert_eq!(candles, normalized);
+        }
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_recent_trades() {
+        init_test_tracing();
+        let exchange_api = ExchangeApi::new();
+        let pair = RawTradingPair::new_raw("ETH_USDT", '_').get_normalized_pair(CexExchange::Bybit);
+        let trades = exchange_api.recent_trades::<Bybit>(pair, 50).await;
+        trades.as_ref().unwrap();
+        assert!(trades.is_ok());
+
+        {
+            let trades = trades.unwrap();
+            let normalized = trades.clone().normalize();
+
+            let all_trades = normalized.clone().take_trades().unwrap();
+            assert!(all_trades.len() >= 10);
+            assert!(all_trades
+                .windows(2)
+                .all(|w| w[0].exchange_time <= w[1].exchange_time));
+
+            assert_eq!(trades, normalized);
+        }
+    }
+}
+
+#[cfg(feature = "kraken")]
+#[cfg(test)]
+mod kraken_tests {
+
+    use cex_exchanges::{
+        clients::rest_api::ExchangeApi,
+        kraken::Kraken,
+        normalized::types::{InstrumentFilter, RawTradingPair},
+        CexExchange, EmptyFilter
+    };
+    use serial_test::serial;
+
+    use super::init_test_tracing;
+
+    #[tokio::test]
+    #[serial]
+    async fn test_all_currencies() {
+        init_test_tracing();
+        let exchange_api = ExchangeApi::new();
+        let all_currencies = exchange_api.all_currencies::<Kraken>().await;
+        all_currencies.as_ref().unwrap();
+        assert!(all_currencies.is_ok());
+
+        {
+            let all_currencies = all_currencies.unwrap();
+            let test_length = all_currencies
+                .clone()
+                .take_kraken_currencies()
+                .unwrap()
+                .len();
+            assert!(test_length > 10);
+
+            let normalized = all_currencies.clone().normalize();
+            let test_length = normalized
+                .clone()
+                .take_currencies::<EmptyFilter>(None)
+                .unwrap()
+                .len();
+            assert!(test_length > 10);
+
+            assert_eq!(all_currencies, normalized);
+        }
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_all_instruments() {
+        init_test_tracing();
+        let exchange_api = ExchangeApi::new();
+        let all_instruments = exchange_api.all_instruments::<Kraken>().await;
+        all_instruments.as_ref().unwrap();
+        assert!(all_instruments.is_ok());
+
+        {
+            let all_instruments = all_instruments.unwrap();
+            let test_length = all_instruments
+                .clone()
+                .take_kraken_instruments(true)
+                .unwrap()
+                .len();
+            assert!(test_length > 10);
+
+            let normalized = all_instruments.clone().normalize();
+            let test_length = normalized
+                .clone()
+                .take_instruments(Some(InstrumentFilter::Active))
+                .unwrap()
+                .len();
+            assert!(test_length > 10);
+
+            assert_eq!(all_instruments, normalized);
+        }
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_recent_trades_unsupported() {
+        init_test_tracing();
+        let pair = RawTradingPair::new_raw("ETH_USD", '_').get_normalized_pair(CexExchange::Kraken);
+        let trades = ExchangeApi::new().recent_trades::<Kraken>(pair, 50).await;
+        assert!(trades.is_err());
+    }
+}
+
+#[cfg(feature = "bitstamp")]
+#[cfg(test)]
+mod bitstamp_tests {
+
+    use cex_exchanges::{bitstamp::Bitstamp, clients::rest_api::ExchangeApi, normalized::types::InstrumentFilter, EmptyFilter};
+    use serial_test::serial;
+
+    use super::init_test_tracing;
+
+    #[tokio::test]
+    #[serial]
+    async fn test_all_currencies() {
+        init_test_tracing();
+        let exchange_api = ExchangeApi::new();
+        let all_currencies = exchange_api.all_currencies::<Bitstamp>().await;
+        all_currencies.as_ref().unwrap();
+        assert!(all_currencies.is_ok());
+
+        {
+            let all_currencies = all_currencies.unwrap();
+            let test_length = all_currencies
+                .clone()
+                .take_bitstamp_currencies()
+                .unwrap()
+                .len();
+            assert!(test_length > 10);
+
+            let normalized = all_currencies.clone().normalize();
+            let test_length = normalized
+                .clone()
+                .take_currencies::<EmptyFilter>(None)
+                .unwrap()
+                .len();
+            assert!(test_length > 10);
+
+            assert_eq!(all_currencies, normalized);
+        }
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_all_instruments() {
+        init_test_tracing();
+        let exchange_api = ExchangeApi::new();
+        let all_instruments = exchange_api.all_instruments::<Bitstamp>().await;
+        all_instruments.as_ref().unwrap();
+        assert!(all_instruments.is_ok());
+
+        {
+            let all_instruments = all_instruments.unwrap();
+            let test_length = all_instruments
+                .clone()
+                .take_bitstamp_instruments(true)
+                .unwrap()
+                .len();
+            assert!(test_length > 10);
+
+            let normalized = all_instruments.clone().normalize();
+            let test_length = normalized
+                .clone()
+                .take_instruments(Some(InstrumentFilter::Active))
+                .unwrap()
+                .len();
+            assert!(test_length > 10);
+
+            assert_eq!(all_instruments, normalized);
+        }
+    }
+}
+
+#[cfg(feature = "gateio")]
+#[cfg(test)]
+mod gateio_tests {
+
+    use cex_exchanges::{clients::rest_api::ExchangeApi, gateio::Gateio, normalized::types::InstrumentFilter, EmptyFilter};
+    use serial_test::serial;
+
+    use super::init_test_tracing;
+
+    #[tokio::test]
+    #[serial]
+    async fn test_all_currencies() {
+        init_test_tracing();
+        let exchange_api = ExchangeApi::new();
+        let all_currencies = exchange_api.all_currencies::<Gateio>().await;
+        all_currencies.as_ref().unwrap();
+        assert!(all_currencies.is_ok());
+
+        {
+            let all_currencies = all_currencies.unwrap();
+            let test_length = all_currencies
+                .clone()
+                .take_gateio_currencies()
+                .unwrap()
+                .len();
+            assert!(test_length > 10);
+
+            let normalized = all_currencies.clone().normalize();
+            let test_length = normalized
+                .clone()
+                .take_currencies::<EmptyFilter>(None)
+                .unwrap()
+                .len();
+            assert!(test_length > 10);
+
+            assert_eq!(all_currencies, normalized);
+        }
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_all_instruments() {
+        init_test_tracing();
+        let exchange_api = ExchangeApi::new();
+        let all_instruments = exchange_api.all_instruments::<Gateio>().await;
+        all_instruments.as_ref().unwrap();
+        assert!(all_instruments.is_ok());
+
+        {
+            let all_instruments = all_instruments.unwrap();
+            let test_length = all_instruments
+                .clone()
+                .take_gateio_instruments(true)
+                .unwrap()
+                .len();
+            assert!(test_length > 10);
+
+            let normalized = all_instruments.clone().normalize();
+            let test_length = normalized
+                .clone()
+                .take_instruments(Some(InstrumentFilter::Active))
+                .unwrap()
+                .len();
+            assert!(test_length > 10);
+
+            assert_eq!(all_instruments, normalized);
+        }
+    }
+}
+
+#[cfg(feature = "deribit")]
+#[cfg(test)]
+mod deribit_tests {
+
+    use cex_exchanges::{clients::rest_api::ExchangeApi, deribit::Deribit, normalized::types::InstrumentFilter, EmptyFilter};
+    use serial_test::serial;
+
+    use super::init_test_tracing;
+
+    #[tokio::test]
+    #[serial]
+    async fn test_all_currencies() {
+        init_test_tracing();
+        let exchange_api = ExchangeApi::new();
+        let all_currencies = exchange_api.all_currencies::<Deribit>().await;
+        all_currencies.as_ref().unwrap();
+        assert!(all_currencies.is_ok());
+
+        {
+            let all_currencies = all_currencies.unwrap();
+            let test_length = all_currencies
+                .clone()
+                .take_deribit_currencies()
+                .unwrap()
+                .len();
+            assert!(test_length > 3);
+
+            let normalized = all_currencies.clone().normalize();
+            let test_length = normalized
+                .clone()
+                .take_currencies::<EmptyFilter>(None)
+                .unwrap()
+                .len();
+            assert!(test_length > 3);
+
+            assert_eq!(all_currencies, normalized);
+        }
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_all_instruments() {
+        init_test_tracing();
+        let exchange_api = ExchangeApi::new();
+        let all_instruments = exchange_api.all_instruments::<Deribit>().await;
+        all_instruments.as_ref().unwrap();
+        assert!(all_instruments.is_ok());
+
+        {
+            let all_instruments = all_instruments.unwrap();
+            let test_length = all_instruments
+                .clone()
+                .take_deribit_instruments(true)
+                .unwrap()
+                .len();
+            assert!(test_length > 10);
+
+            let normalized = all_instruments.clone().normalize();
+            let test_length = normalized
+                .clone()
+                .take_instruments(Some(InstrumentFilter::Active))
+                .unwrap()
+                .len();
+            assert!(test_length > 10);
+
+            assert_eq!(all_instruments, normalized);
+        }
+    }
+}
+
+#[cfg(feature = "bitget")]
+#[cfg(test)]
+mod bitget_tests {
+
+    use cex_exchanges::{bitget::Bitget, clients::rest_api::ExchangeApi, normalized::types::InstrumentFilter, EmptyFilter};
+    use serial_test::serial;
+
+    use super::init_test_tracing;
+
+    #[tokio::test]
+    #[serial]
+    async fn test_all_currencies() {
+        init_test_tracing();
+        let exchange_api = ExchangeApi::new();
+        let all_currencies = exchange_api.all_currencies::<Bitget>().await;
+        all_currencies.as_ref().unwrap();
+        assert!(all_currencies.is_ok());
+
+        {
+            let all_currencies = all_currencies.unwrap();
+            let test_length = all_currencies
+                .clone()
+                .take_bitget_currencies()
+                .unwrap()
+                .len();
+            assert!(test_length > 3);
+
+            let normalized = all_currencies.clone().normalize();
+            let test_length = normalized
+                .clone()
+                .take_currencies::<EmptyFilter>(None)
+                .unwrap()
+                .len();
+            assert!(test_length > 3);
+
+            assert_eq!(all_currencies, normalized);
+        }
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_all_instruments() {
+        init_test_tracing();
+        let exchange_api = ExchangeApi::new();
+        let all_instruments = exchange_api.all_instruments::<Bitget>().await;
+        all_instruments.as_ref().unwrap();
+        assert!(all_instruments.is_ok());
+
+        {
+            let all_instruments = all_instruments.unwrap();
+            let test_length = all_instruments
+                .clone()
+                .take_bitget_instruments(true)
+                .unwrap()
+                .len();
+            assert!(test_length > 10);
+
+            let normalized = all_instruments.clone().normalize();
+            let test_length = normalized
+                .clone()
+                .take_instruments(Some(InstrumentFilter::Active))
+                .unwrap()
+                .len();
+            assert!(test_length > 10);
+
+            assert_eq!(all_instruments, normalized);
+        }
+    }
+}
+
+#[cfg(feature = "mexc")]
+#[cfg(test)]
+mod mexc_tests {
+
+    use cex_exchanges::{clients::rest_api::ExchangeApi, mexc::Mexc, normalized::types::InstrumentFilter, EmptyFilter};
+    use serial_test::serial;
+
+    use super::init_test_tracing;
+
+    #[tokio::test]
+    #[serial]
+    async fn test_all_currencies() {
+        init_test_tracing();
+        let exchange_api = ExchangeApi::new();
+        let all_currencies = exchange_api.all_currencies::<Mexc>().await;
+        all_currencies.as_ref().unwrap();
+        assert!(all_currencies.is_ok());
+
+        {
+            let all_currencies = all_currencies.unwrap();
+            let test_length = all_currencies.clone().take_mexc_currencies().unwrap().len();
+            assert!(test_length > 3);
+
+            let normalized = all_currencies.clone().normalize();
+            let test_length = normalized
+                .clone()
+                .take_currencies::<EmptyFilter>(None)
+                .unwrap()
+                .len();
+            assert!(test_length > 3);
+
+            assert_eq!(all_currencies, normalized);
+        }
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_all_instruments() {
+        init_test_tracing();
+        let exchange_api = ExchangeApi::new();
+        let all_instruments = exchange_api.all_instruments::<Mexc>().await;
+        all_instruments.as_ref().unwrap();
+        assert!(all_instruments.is_ok());
+
+        {
+            let all_instruments = all_instruments.unwrap();
+            let test_length = all_instruments
+                .clone()
+                .take_mexc_instruments(true)
+                .unwrap()
+                .len();
+            assert!(test_length > 10);
+
+            let normalized = all_instruments.clone().normalize();
+            let test_length = normalized
+                .clone()
+                .take_instruments(Some(InstrumentFilter::Active))
+                .unwrap()
+                .len();
+            assert!(test_length > 10);
+
+            assert_eq!(all_instruments, normalized);
+        }
+    }
 }
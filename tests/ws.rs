@@ -1,10 +1,11 @@
 mod utils;
 use utils::*;
 
-#[cfg(feature = "us")]
+#[cfg(feature = "coinbase")]
 #[cfg(test)]
 mod coinbase_tests {
     use cex_exchanges::{
+        clients::rest_api::ExchangeApi,
         coinbase::ws::{
             channels::{CoinbaseWsChannel, CoinbaseWsChannelKind},
             CoinbaseWsBuilder
@@ -43,6 +44,16 @@ mod coinbase_tests {
         coinbase_util(builder, 5).await;
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn test_level2() {
+        init_test_tracing();
+        let builder = CoinbaseWsBuilder::default().add_channel(
+            CoinbaseWsChannel::new_level2(vec![RawTradingPair::new_raw("ETH_USD", '_'), RawTradingPair::new_no_delim("BTC-USD")]).unwrap()
+        );
+        coinbase_util(builder, 5).await;
+    }
+
     #[tokio::test]
     #[serial]
     async fn test_multi_distributed() {
@@ -91,7 +102,7 @@ mod coinbase_tests {
         let channels = vec![NormalizedWsChannelKinds::Trades, NormalizedWsChannelKinds::Quotes];
 
         let normalized_symbols = CexExchange::Coinbase
-            .get_all_instruments(Some(InstrumentFilter::Active))
+            .get_all_instruments(&ExchangeApi::new(), Some(InstrumentFilter::Active))
             .await
             .unwrap();
         let mut builder = NormalizedExchangeBuilder::new();
@@ -108,12 +119,13 @@ mod coinbase_tests {
     }
 }
 
-#[cfg(feature = "us")]
+#[cfg(feature = "okex")]
 #[cfg(test)]
 mod okex_tests {
     use cex_exchanges::{
+        clients::rest_api::ExchangeApi,
         normalized::{
-            types::{InstrumentFilter, RawTradingPair},
+            types::{InstrumentFilter, NormalizedCandleInterval, RawTradingPair},
             ws::{NormalizedExchangeBuilder, NormalizedWsChannelKinds}
         },
         okex::ws::{
@@ -149,6 +161,29 @@ mod okex_tests {
         okex_util(builder, 5).await;
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn test_books() {
+        init_test_tracing();
+        let builder = OkexWsBuilder::new(None)
+            .add_channel(OkexWsChannel::new_books(vec![RawTradingPair::new_raw("ETH_USDt", '_'), RawTradingPair::new_no_delim("BTC-USdc")]).unwrap());
+        okex_util(builder, 5).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_candle() {
+        init_test_tracing();
+        let builder = OkexWsBuilder::new(None).add_channel(
+            OkexWsChannel::new_candle(
+                vec![RawTradingPair::new_raw("ETH_USDt", '_'), RawTradingPair::new_no_delim("BTC-USdc")],
+                NormalizedCandleInterval::OneMinute
+            )
+            .unwrap()
+        );
+        okex_util(builder, 5).await;
+    }
+
     #[tokio::test]
     #[serial]
     async fn test_multi_distributed() {
@@ -200,7 +235,7 @@ mod okex_tests {
         let channels = vec![NormalizedWsChannelKinds::Trades, NormalizedWsChannelKinds::Quotes];
 
         let normalized_symbols = CexExchange::Okex
-            .get_all_instruments(Some(InstrumentFilter::Active))
+            .get_all_instruments(&ExchangeApi::new(), Some(InstrumentFilter::Active))
             .await
             .unwrap();
         let mut builder = NormalizedExchangeBuilder::new();
@@ -217,7 +252,7 @@ mod okex_tests {
     }
 }
 
-#[cfg(feature = "non-us")]
+#[cfg(feature = "binance")]
 #[cfg(test)]
 mod binance_tests {
     use cex_exchanges::{
@@ -225,7 +260,7 @@ mod binance_tests {
             channels::{BinanceWsChannel, BinanceWsChannelKind},
             BinanceWsBuilder
         },
-        normalized::types::RawTradingPair
+        normalized::types::{NormalizedCandleInterval, RawTradingPair}
     };
     use serial_test::serial;
 
@@ -255,6 +290,30 @@ mod binance_tests {
         binance_util(builder, 5).await;
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn test_depth() {
+        init_test_tracing();
+        let builder = BinanceWsBuilder::default().add_channel(
+            BinanceWsChannel::new_depth(vec![RawTradingPair::new_raw("ETH_USDt", '_'), RawTradingPair::new_no_delim("BTC-USdc")]).unwrap()
+        );
+        binance_util(builder, 5).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_kline() {
+        init_test_tracing();
+        let builder = BinanceWsBuilder::default().add_channel(
+            BinanceWsChannel::new_kline(
+                vec![RawTradingPair::new_raw("ETH_USDt", '_'), RawTradingPair::new_no_delim("BTC-USdc")],
+                NormalizedCandleInterval::OneMinute
+            )
+            .unwrap()
+        );
+        binance_util(builder, 5).await;
+    }
+
     #[tokio::test]
     #[serial]
     async fn test_multi_distributed() {
@@ -300,7 +359,7 @@ mod binance_tests {
     }
 }
 
-#[cfg(feature = "non-us")]
+#[cfg(feature = "kucoin")]
 #[cfg(test)]
 mod kucoin_tests {
     use cex_exchanges::{
@@ -308,7 +367,7 @@ mod kucoin_tests {
             channels::{KucoinWsChannel, KucoinWsChannelKind},
             KucoinWsBuilder
         },
-        normalized::types::RawTradingPair
+        normalized::types::{NormalizedCandleInterval, RawTradingPair}
     };
     use serial_test::serial;
 
@@ -338,6 +397,30 @@ mod kucoin_tests {
         kucoin_util(builder, 5).await;
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn test_level2() {
+        init_test_tracing();
+        let builder = KucoinWsBuilder::default().add_channel(
+            KucoinWsChannel::new_level2(vec![RawTradingPair::new_raw("ETH_USDt", '_'), RawTradingPair::new_no_delim("BTC-USdc")]).unwrap()
+        );
+        kucoin_util(builder, 5).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_candles() {
+        init_test_tracing();
+        let builder = KucoinWsBuilder::default().add_channel(
+            KucoinWsChannel::new_candles(
+                vec![RawTradingPair::new_raw("ETH_USDt", '_'), RawTradingPair::new_no_delim("BTC-USdc")],
+                NormalizedCandleInterval::OneMinute
+            )
+            .unwrap()
+        );
+        kucoin_util(builder, 5).await;
+    }
+
     #[tokio::test]
     #[serial]
     async fn test_multi_distributed() {
@@ -381,7 +464,7 @@ mod kucoin_tests {
     }
 }
 
-#[cfg(feature = "non-us")]
+#[cfg(feature = "bybit")]
 #[cfg(test)]
 mod bybit_tests {
     use cex_exchanges::{
@@ -389,7 +472,7 @@ mod bybit_tests {
             channels::{BybitWsChannel, BybitWsChannelKind},
             BybitWsBuilder
         },
-        normalized::types::RawTradingPair
+        normalized::types::{NormalizedCandleInterval, RawTradingPair}
     };
     use serial_test::serial;
 
@@ -419,6 +502,30 @@ mod bybit_tests {
         bybit_util(builder, 5).await;
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn test_orderbook() {
+        init_test_tracing();
+        let builder = BybitWsBuilder::default().add_channel(
+            BybitWsChannel::new_orderbook(vec![RawTradingPair::new_raw("ETH_USDt", '_'), RawTradingPair::new_no_delim("BTC-USdc")]).unwrap()
+        );
+        bybit_util(builder, 5).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_kline() {
+        init_test_tracing();
+        let builder = BybitWsBuilder::default().add_channel(
+            BybitWsChannel::new_kline(
+                vec![RawTradingPair::new_raw("ETH_USDt", '_'), RawTradingPair::new_no_delim("BTC-USdc")],
+                NormalizedCandleInterval::OneMinute
+            )
+            .unwrap()
+        );
+        bybit_util(builder, 5).await;
+    }
+
     #[tokio::test]
     #[serial]
     async fn test_multi_distributed() {
@@ -459,3 +566,427 @@ mod bybit_tests {
         mutlithreaded_util(builder, 1000).await;
     }
 }
+
+#[cfg(feature = "kraken")]
+#[cfg(test)]
+mod kraken_tests {
+    use cex_exchanges::{
+        kraken::ws::{
+            channels::{KrakenWsChannel, KrakenWsChannelKind},
+            KrakenWsBuilder
+        },
+        normalized::types::RawTradingPair
+    };
+    use serial_test::serial;
+
+    use super::*;
+
+    async fn kraken_util(builder: KrakenWsBuilder, iterations: usize) {
+        stream_util(builder.build_single(), iterations).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_trade() {
+        init_test_tracing();
+        let builder = KrakenWsBuilder::default()
+            .add_channel(KrakenWsChannel::new_trade(vec![RawTradingPair::new_raw("ETH_USD", '_'), RawTradingPair::new_no_delim("XBT/USD")]).unwrap());
+        kraken_util(builder, 5).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_ticker() {
+        init_test_tracing();
+        let builder = KrakenWsBuilder::default().add_channel(
+            KrakenWsChannel::new_ticker(vec![RawTradingPair::new_raw("ETH_USD", '_'), RawTradingPair::new_no_delim("XBT/USD")]).unwrap()
+        );
+        kraken_util(builder, 5).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_book() {
+        init_test_tracing();
+        let builder = KrakenWsBuilder::default()
+            .add_channel(KrakenWsChannel::new_book(vec![RawTradingPair::new_raw("ETH_USD", '_'), RawTradingPair::new_no_delim("XBT/USD")]).unwrap());
+        kraken_util(builder, 5).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_multi_distributed() {
+        init_test_tracing();
+        let builder = KrakenWsBuilder::default()
+            .add_channel(KrakenWsChannel::new_trade(vec![RawTradingPair::new_raw("ETH_USD", '_'), RawTradingPair::new_no_delim("XBT/USD")]).unwrap())
+            .add_channel(KrakenWsChannel::new_ticker(vec![RawTradingPair::new_raw("ETH_USD", '_'), RawTradingPair::new_no_delim("XBT/USD")]).unwrap())
+            .build_many_distributed()
+            .unwrap();
+
+        mutlistream_util(builder, 50).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 3)]
+    #[serial]
+    async fn test_multi_all_instruments() {
+        init_test_tracing();
+        let channels = vec![KrakenWsChannelKind::Trade, KrakenWsChannelKind::Ticker];
+
+        let builder = KrakenWsBuilder::build_from_all_instruments(&channels, None)
+            .await
+            .unwrap();
+
+        mutlistream_util(builder, 1000).await;
+    }
+}
+
+#[cfg(feature = "bitstamp")]
+#[cfg(test)]
+mod bitstamp_tests {
+    use cex_exchanges::{
+        bitstamp::ws::{
+            channels::{BitstampWsChannel, BitstampWsChannelKind},
+            BitstampWsBuilder
+        },
+        normalized::types::RawTradingPair
+    };
+    use serial_test::serial;
+
+    use super::*;
+
+    async fn bitstamp_util(builder: BitstampWsBuilder, iterations: usize) {
+        stream_util(builder.build_single(), iterations).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_live_trades() {
+        init_test_tracing();
+        let builder = BitstampWsBuilder::default().add_channel(
+            BitstampWsChannel::new_live_trades(vec![RawTradingPair::new_raw("ETH_USD", '_'), RawTradingPair::new_no_delim("BTC-USD")]).unwrap()
+        );
+        bitstamp_util(builder, 5).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_order_book() {
+        init_test_tracing();
+        let builder = BitstampWsBuilder::default().add_channel(
+            BitstampWsChannel::new_order_book(vec![RawTradingPair::new_raw("ETH_USD", '_'), RawTradingPair::new_no_delim("BTC-USD")]).unwrap()
+        );
+        bitstamp_util(builder, 5).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_multi_distributed() {
+        init_test_tracing();
+        let builder = BitstampWsBuilder::default()
+            .add_channel(
+                BitstampWsChannel::new_live_trades(vec![RawTradingPair::new_raw("ETH_USD", '_'), RawTradingPair::new_no_delim("BTC-USD")]).unwrap()
+            )
+            .add_channel(
+                BitstampWsChannel::new_order_book(vec![RawTradingPair::new_raw("ETH_USD", '_'), RawTradingPair::new_no_delim("BTC-USD")]).unwrap()
+            )
+            .build_many_distributed()
+            .unwrap();
+
+        mutlistream_util(builder, 50).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 3)]
+    #[serial]
+    async fn test_multi_all_instruments() {
+        init_test_tracing();
+        let channels = vec![BitstampWsChannelKind::LiveTrades, BitstampWsChannelKind::OrderBook];
+
+        let builder = BitstampWsBuilder::build_from_all_instruments(&channels, None)
+            .await
+            .unwrap();
+
+        mutlistream_util(builder, 1000).await;
+    }
+}
+
+#[cfg(feature = "gateio")]
+#[cfg(test)]
+mod gateio_tests {
+    use cex_exchanges::{
+        gateio::ws::{
+            channels::{GateioWsChannel, GateioWsChannelKind},
+            GateioWsBuilder
+        },
+        normalized::types::RawTradingPair
+    };
+    use serial_test::serial;
+
+    use super::*;
+
+    async fn gateio_util(builder: GateioWsBuilder, iterations: usize) {
+        stream_util(builder.build_single(), iterations).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_trades() {
+        init_test_tracing();
+        let builder = GateioWsBuilder::default().add_channel(
+            GateioWsChannel::new_trades(vec![RawTradingPair::new_raw("ETH_USDT", '_'), RawTradingPair::new_no_delim("BTC-USDT")]).unwrap()
+        );
+        gateio_util(builder, 5).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_book_ticker() {
+        init_test_tracing();
+        let builder = GateioWsBuilder::default().add_channel(
+            GateioWsChannel::new_book_ticker(vec![RawTradingPair::new_raw("ETH_USDT", '_'), RawTradingPair::new_no_delim("BTC-USDT")]).unwrap()
+        );
+        gateio_util(builder, 5).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_multi_distributed() {
+        init_test_tracing();
+        let builder = GateioWsBuilder::default()
+            .add_channel(
+                GateioWsChannel::new_trades(vec![RawTradingPair::new_raw("ETH_USDT", '_'), RawTradingPair::new_no_delim("BTC-USDT")]).unwrap()
+            )
+            .add_channel(
+                GateioWsChannel::new_book_ticker(vec![RawTradingPair::new_raw("ETH_USDT", '_'), RawTradingPair::new_no_delim("BTC-USDT")]).unwrap()
+            )
+            .build_many_distributed()
+            .unwrap();
+
+        mutlistream_util(builder, 50).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 3)]
+    #[serial]
+    async fn test_multi_all_instruments() {
+        init_test_tracing();
+        let channels = vec![GateioWsChannelKind::Trades, GateioWsChannelKind::BookTicker];
+
+        let builder = GateioWsBuilder::build_from_all_instruments(&channels, None)
+            .await
+            .unwrap();
+
+        mutlistream_util(builder, 1000).await;
+    }
+}
+
+#[cfg(feature = "deribit")]
+#[cfg(test)]
+mod deribit_tests {
+    use cex_exchanges::{
+        deribit::ws::{
+            channels::{DeribitWsChannel, DeribitWsChannelKind},
+            DeribitWsBuilder
+        },
+        normalized::types::RawTradingPair
+    };
+    use serial_test::serial;
+
+    use super::*;
+
+    async fn deribit_util(builder: DeribitWsBuilder, iterations: usize) {
+        stream_util(builder.build_single(), iterations).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_trades() {
+        init_test_tracing();
+        let builder = DeribitWsBuilder::default().add_channel(
+            DeribitWsChannel::new_trades(vec![RawTradingPair::new_no_delim("BTC-PERPETUAL"), RawTradingPair::new_no_delim("ETH-PERPETUAL")]).unwrap()
+        );
+        deribit_util(builder, 5).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_ticker() {
+        init_test_tracing();
+        let builder = DeribitWsBuilder::default().add_channel(
+            DeribitWsChannel::new_ticker(vec![RawTradingPair::new_no_delim("BTC-PERPETUAL"), RawTradingPair::new_no_delim("ETH-PERPETUAL")]).unwrap()
+        );
+        deribit_util(builder, 5).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_book() {
+        init_test_tracing();
+        let builder = DeribitWsBuilder::default().add_channel(
+            DeribitWsChannel::new_book(vec![RawTradingPair::new_no_delim("BTC-PERPETUAL"), RawTradingPair::new_no_delim("ETH-PERPETUAL")]).unwrap()
+        );
+        deribit_util(builder, 5).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_multi_distributed() {
+        init_test_tracing();
+        let builder = DeribitWsBuilder::default()
+            .add_channel(
+                DeribitWsChannel::new_trades(vec![RawTradingPair::new_no_delim("BTC-PERPETUAL"), RawTradingPair::new_no_delim("ETH-PERPETUAL")])
+                    .unwrap()
+            )
+            .add_channel(
+                DeribitWsChannel::new_ticker(vec![RawTradingPair::new_no_delim("BTC-PERPETUAL"), RawTradingPair::new_no_delim("ETH-PERPETUAL")])
+                    .unwrap()
+            )
+            .build_many_distributed()
+            .unwrap();
+
+        mutlistream_util(builder, 50).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 3)]
+    #[serial]
+    async fn test_multi_all_instruments() {
+        init_test_tracing();
+        let channels = vec![DeribitWsChannelKind::Trades, DeribitWsChannelKind::Ticker];
+
+        let builder = DeribitWsBuilder::build_from_all_instruments(&channels, None)
+            .await
+            .unwrap();
+
+        mutlistream_util(builder, 1000).await;
+    }
+}
+
+#[cfg(feature = "bitget")]
+#[cfg(test)]
+mod bitget_tests {
+    use cex_exchanges::{
+        bitget::ws::{
+            channels::{BitgetWsChannel, BitgetWsChannelKind},
+            BitgetWsBuilder
+        },
+        normalized::types::RawTradingPair
+    };
+    use serial_test::serial;
+
+    use super::*;
+
+    async fn bitget_util(builder: BitgetWsBuilder, iterations: usize) {
+        stream_util(builder.build_single(), iterations).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_trade() {
+        init_test_tracing();
+        let builder = BitgetWsBuilder::default()
+            .add_channel(BitgetWsChannel::new_trade(vec![RawTradingPair::new_no_delim("BTCUSDT"), RawTradingPair::new_no_delim("ETHUSDT")]).unwrap());
+        bitget_util(builder, 5).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_book_ticker() {
+        init_test_tracing();
+        let builder = BitgetWsBuilder::default().add_channel(
+            BitgetWsChannel::new_book_ticker(vec![RawTradingPair::new_no_delim("BTCUSDT"), RawTradingPair::new_no_delim("ETHUSDT")]).unwrap()
+        );
+        bitget_util(builder, 5).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_multi_distributed() {
+        init_test_tracing();
+        let builder = BitgetWsBuilder::default()
+            .add_channel(BitgetWsChannel::new_trade(vec![RawTradingPair::new_no_delim("BTCUSDT"), RawTradingPair::new_no_delim("ETHUSDT")]).unwrap())
+            .add_channel(
+                BitgetWsChannel::new_book_ticker(vec![RawTradingPair::new_no_delim("BTCUSDT"), RawTradingPair::new_no_delim("ETHUSDT")]).unwrap()
+            )
+            .build_many_distributed()
+            .unwrap();
+
+        mutlistream_util(builder, 50).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 3)]
+    #[serial]
+    async fn test_multi_all_instruments() {
+        init_test_tracing();
+        let channels = vec![BitgetWsChannelKind::Trade, BitgetWsChannelKind::BookTicker];
+
+        let builder = BitgetWsBuilder::build_from_all_instruments(&channels, None)
+            .await
+            .unwrap();
+
+        mutlistream_util(builder, 1000).await;
+    }
+}
+
+#[cfg(feature = "mexc")]
+#[cfg(test)]
+mod mexc_tests {
+    use cex_exchanges::{
+        mexc::ws::{
+            channels::{MexcWsChannel, MexcWsChannelKind},
+            MexcWsBuilder
+        },
+        normalized::types::RawTradingPair
+    };
+    use serial_test::serial;
+
+    use super::*;
+
+    async fn mexc_util(builder: MexcWsBuilder, iterations: usize) {
+        stream_util(builder.build_single(), iterations).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_deals() {
+        init_test_tracing();
+        let builder = MexcWsBuilder::default()
+            .add_channel(MexcWsChannel::new_deals(vec![RawTradingPair::new_no_delim("BTCUSDT"), RawTradingPair::new_no_delim("ETHUSDT")]).unwrap());
+        mexc_util(builder, 5).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_book_ticker() {
+        init_test_tracing();
+        let builder = MexcWsBuilder::default().add_channel(
+            MexcWsChannel::new_book_ticker(vec![RawTradingPair::new_no_delim("BTCUSDT"), RawTradingPair::new_no_delim("ETHUSDT")]).unwrap()
+        );
+        mexc_util(builder, 5).await;
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_multi_distributed() {
+        init_test_tracing();
+        let builder = MexcWsBuilder::default()
+            .add_channel(MexcWsChannel::new_deals(vec![RawTradingPair::new_no_delim("BTCUSDT"), RawTradingPair::new_no_delim("ETHUSDT")]).unwrap())
+            .add_channel(
+                MexcWsChannel::new_book_ticker(vec![RawTradingPair::new_no_delim("BTCUSDT"), RawTradingPair::new_no_delim("ETHUSDT")]).unwrap()
+            )
+            .build_many_distributed()
+            .unwrap();
+
+        mutlistream_util(builder, 50).await;
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 3)]
+    #[serial]
+    async fn test_multi_all_instruments() {
+        init_test_tracing();
+        let channels = vec![MexcWsChannelKind::Deals, MexcWsChannelKind::BookTicker];
+
+        let builder = MexcWsBuilder::build_from_all_instruments(&channels, None)
+            .await
+            .unwrap();
+
+        mutlistream_util(builder, 1000).await;
+    }
+}